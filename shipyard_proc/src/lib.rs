@@ -0,0 +1,355 @@
+//! Procedural macros for [shipyard](https://github.com/leudz/shipyard), use them through shipyard's **proc** feature.
+
+extern crate proc_macro;
+
+use proc_macro2::{Span, TokenStream};
+use quote::{format_ident, quote, ToTokens};
+use syn::{
+    parse_macro_input, spanned::Spanned, Data, DataStruct, DeriveInput, Error, Fields,
+    GenericParam, Lifetime, LifetimeDef,
+};
+
+/// Maximum number of fields, `Borrow` is implemented for tuples up to this size.
+#[cfg(not(feature = "extended_tuple"))]
+const MAX_FIELDS: usize = 10;
+/// Maximum number of fields, `Borrow` is implemented for tuples up to this size.
+#[cfg(feature = "extended_tuple")]
+const MAX_FIELDS: usize = 16;
+
+/// Implements `BorrowBundle` for a struct whose fields are all views.
+/// The struct can then be used as a system parameter, each field is borrowed like the other parameters.
+///
+/// The struct can have at most one lifetime, shared by all views, and 10 fields, 16 with shipyard's **extended_tuple** feature.
+#[proc_macro_derive(Borrow)]
+pub fn borrow(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    expand_borrow(input)
+        .unwrap_or_else(|error| error.to_compile_error())
+        .into()
+}
+
+fn expand_borrow(input: DeriveInput) -> Result<TokenStream, Error> {
+    let name = &input.ident;
+
+    let fields = match &input.data {
+        Data::Struct(data) => &data.fields,
+        _ => {
+            return Err(Error::new(
+                input.span(),
+                "Borrow can only be derived for structs",
+            ))
+        }
+    };
+
+    if fields.len() > MAX_FIELDS {
+        return Err(Error::new(
+            fields.span(),
+            format!("Borrow can only be derived for structs with at most {} fields, group some of them in another bundle", MAX_FIELDS),
+        ));
+    }
+
+    let mut lifetimes = input.generics.lifetimes();
+    let lifetime = match (lifetimes.next(), lifetimes.next()) {
+        (Some(def), None) => def.lifetime.clone(),
+        (None, None) => Lifetime::new("'__shipyard", Span::call_site()),
+        (_, Some(def)) => {
+            return Err(Error::new(
+                def.span(),
+                "Borrow can only be derived for structs with at most one lifetime",
+            ))
+        }
+    };
+
+    // the lifetime has to be part of the impl but not necessarily of the struct
+    let mut impl_generics = input.generics.clone();
+    if input.generics.lifetimes().next().is_none() {
+        impl_generics.params.insert(
+            0,
+            GenericParam::Lifetime(LifetimeDef::new(lifetime.clone())),
+        );
+    }
+    let (impl_generics, _, _) = impl_generics.split_for_impl();
+    let (_, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let types = fields.iter().map(|field| &field.ty);
+    let bindings: Vec<_> = fields
+        .iter()
+        .enumerate()
+        .map(|(index, field)| match &field.ident {
+            Some(ident) => ident.clone(),
+            None => format_ident!("view_{}", index),
+        })
+        .collect();
+    let constructor = match fields {
+        Fields::Named(_) => quote!(#name { #(#bindings),* }),
+        Fields::Unnamed(_) => quote!(#name(#(#bindings),*)),
+        Fields::Unit => quote!(#name),
+    };
+
+    Ok(quote! {
+        impl #impl_generics ::shipyard::BorrowBundle<#lifetime> for #name #ty_generics #where_clause {
+            type Views = (#(#types,)*);
+
+            fn from_views((#(#bindings,)*): Self::Views) -> Self {
+                #constructor
+            }
+        }
+    })
+}
+
+/// Stores each field of a struct in the storage of its type, hot fields can then be iterated without dragging cold ones through the cache.
+///
+/// For a `Body` struct, generates `BodyView` and `BodyViewMut` bundling the views of all fields, they can be used as system parameters.
+/// `BodyRef` and `BodyMut` hold references to the fields of a single entity.
+///
+/// The struct needs named fields of different types, between 2 and 10 of them, 16 with shipyard's **extended_tuple** feature, and can't be generic.
+#[proc_macro_derive(Split)]
+pub fn split(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    expand_split(input)
+        .unwrap_or_else(|error| error.to_compile_error())
+        .into()
+}
+
+fn expand_split(input: DeriveInput) -> Result<TokenStream, Error> {
+    let name = &input.ident;
+    let vis = &input.vis;
+
+    let fields = match &input.data {
+        Data::Struct(DataStruct {
+            fields: Fields::Named(fields),
+            ..
+        }) => &fields.named,
+        _ => {
+            return Err(Error::new(
+                input.span(),
+                "Split can only be derived for structs with named fields",
+            ))
+        }
+    };
+
+    if !input.generics.params.is_empty() {
+        return Err(Error::new(
+            input.generics.span(),
+            "Split can't be derived for generic structs",
+        ));
+    }
+
+    if fields.len() < 2 || fields.len() > MAX_FIELDS {
+        return Err(Error::new(
+            fields.span(),
+            format!(
+                "Split can only be derived for structs with 2 to {} fields",
+                MAX_FIELDS
+            ),
+        ));
+    }
+
+    // fields are stored in the storage of their type, two fields of the same type would share it
+    let mut seen = Vec::with_capacity(fields.len());
+    for field in fields {
+        let ty = field.ty.to_token_stream().to_string();
+        if seen.contains(&ty) {
+            return Err(Error::new(
+                field.ty.span(),
+                "Split can only be derived for structs whose fields have different types",
+            ));
+        }
+        seen.push(ty);
+    }
+
+    let view = format_ident!("{}View", name);
+    let view_mut = format_ident!("{}ViewMut", name);
+    let reference = format_ident!("{}Ref", name);
+    let mutable = format_ident!("{}Mut", name);
+
+    let view_doc = format!("Views over the storages of `{}`'s fields.", name);
+    let view_mut_doc = format!("Exclusive views over the storages of `{}`'s fields.", name);
+    let reference_doc = format!("Shared references to the fields of a `{}`.", name);
+    let mutable_doc = format!("Exclusive references to the fields of a `{}`.", name);
+
+    let idents: Vec<_> = fields.iter().map(|field| &field.ident).collect();
+    let field_vis: Vec<_> = fields.iter().map(|field| &field.vis).collect();
+    let types: Vec<_> = fields.iter().map(|field| &field.ty).collect();
+
+    Ok(quote! {
+        #[doc = #view_doc]
+        #vis struct #view<'v> {
+            #(#field_vis #idents: ::shipyard::View<'v, #types>,)*
+        }
+
+        impl<'v> ::shipyard::BorrowBundle<'v> for #view<'v> {
+            type Views = (#(::shipyard::View<'v, #types>,)*);
+
+            fn from_views((#(#idents,)*): Self::Views) -> Self {
+                #view { #(#idents),* }
+            }
+        }
+
+        impl<'v> #view<'v> {
+            /// Returns the fields of `entity`.
+            #vis fn get(
+                &self,
+                entity: ::shipyard::EntityId,
+            ) -> ::core::result::Result<#reference<'_>, ::shipyard::error::MissingComponent> {
+                let (#(#idents,)*) = ::shipyard::Get::get((#(&self.#idents,)*), entity)?;
+
+                ::core::result::Result::Ok(#reference { #(#idents),* })
+            }
+            /// Iterates the entities having all fields.
+            #vis fn iter<'s>(
+                &'s self,
+            ) -> ::shipyard::Map<
+                <(#(&'s ::shipyard::View<'v, #types>,)*) as ::shipyard::IntoIter>::IntoIter,
+                fn((#(&'s #types,)*)) -> #reference<'s>,
+            > {
+                let to_reference: fn((#(&'s #types,)*)) -> #reference<'s> =
+                    |(#(#idents,)*)| #reference { #(#idents),* };
+
+                ::shipyard::Shiperator::map(
+                    ::shipyard::IntoIter::iter((#(&self.#idents,)*)),
+                    to_reference,
+                )
+            }
+        }
+
+        #[doc = #view_mut_doc]
+        #vis struct #view_mut<'v> {
+            #(#field_vis #idents: ::shipyard::ViewMut<'v, #types>,)*
+        }
+
+        impl<'v> ::shipyard::BorrowBundle<'v> for #view_mut<'v> {
+            type Views = (#(::shipyard::ViewMut<'v, #types>,)*);
+
+            fn from_views((#(#idents,)*): Self::Views) -> Self {
+                #view_mut { #(#idents),* }
+            }
+        }
+
+        impl<'v> #view_mut<'v> {
+            /// Creates a new entity with the fields of `component`.
+            #vis fn add_entity(
+                &mut self,
+                entities: &mut ::shipyard::Entities,
+                component: #name,
+            ) -> ::shipyard::EntityId {
+                entities.add_entity((#(&mut self.#idents,)*), (#(component.#idents,)*))
+            }
+            /// Adds the fields of `component` to `entity`.
+            #vis fn try_add_component(
+                &mut self,
+                component: #name,
+                entity: ::shipyard::EntityId,
+                entities: &::shipyard::Entities,
+            ) -> ::core::result::Result<(), ::shipyard::error::AddComponent> {
+                entities.try_add_component((#(&mut self.#idents,)*), (#(component.#idents,)*), entity)
+            }
+            /// Deletes the fields of `entity`.
+            #vis fn try_delete(
+                &mut self,
+                entity: ::shipyard::EntityId,
+            ) -> ::core::result::Result<(), ::shipyard::error::Remove> {
+                ::shipyard::Delete::<(#(#types,)*)>::try_delete((#(&mut self.#idents,)*), entity)
+            }
+            /// Returns the fields of `entity`.
+            #vis fn get(
+                &self,
+                entity: ::shipyard::EntityId,
+            ) -> ::core::result::Result<#reference<'_>, ::shipyard::error::MissingComponent> {
+                let (#(#idents,)*) = ::shipyard::Get::get((#(&self.#idents,)*), entity)?;
+
+                ::core::result::Result::Ok(#reference { #(#idents),* })
+            }
+            /// Returns the fields of `entity`, they're flagged as modified if tracked.
+            #vis fn get_mut(
+                &mut self,
+                entity: ::shipyard::EntityId,
+            ) -> ::core::result::Result<#mutable<'_>, ::shipyard::error::MissingComponent> {
+                let (#(#idents,)*) = ::shipyard::Get::get((#(&mut self.#idents,)*), entity)?;
+
+                ::core::result::Result::Ok(#mutable { #(#idents),* })
+            }
+            /// Iterates the entities having all fields.
+            #vis fn iter<'s>(
+                &'s self,
+            ) -> ::shipyard::Map<
+                <(#(&'s ::shipyard::ViewMut<'v, #types>,)*) as ::shipyard::IntoIter>::IntoIter,
+                fn((#(&'s #types,)*)) -> #reference<'s>,
+            > {
+                let to_reference: fn((#(&'s #types,)*)) -> #reference<'s> =
+                    |(#(#idents,)*)| #reference { #(#idents),* };
+
+                ::shipyard::Shiperator::map(
+                    ::shipyard::IntoIter::iter((#(&self.#idents,)*)),
+                    to_reference,
+                )
+            }
+            /// Iterates the entities having all fields, they're flagged as modified if tracked.
+            #vis fn iter_mut<'s>(
+                &'s mut self,
+            ) -> ::shipyard::Map<
+                <(#(&'s mut ::shipyard::ViewMut<'v, #types>,)*) as ::shipyard::IntoIter>::IntoIter,
+                fn((#(&'s mut #types,)*)) -> #mutable<'s>,
+            > {
+                let to_mutable: fn((#(&'s mut #types,)*)) -> #mutable<'s> =
+                    |(#(#idents,)*)| #mutable { #(#idents),* };
+
+                ::shipyard::Shiperator::map(
+                    ::shipyard::IntoIter::iter((#(&mut self.#idents,)*)),
+                    to_mutable,
+                )
+            }
+        }
+
+        #[doc = #reference_doc]
+        #vis struct #reference<'a> {
+            #(#field_vis #idents: &'a #types,)*
+        }
+
+        #[doc = #mutable_doc]
+        #vis struct #mutable<'a> {
+            #(#field_vis #idents: &'a mut #types,)*
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Returns a struct with `count` fields of different types.
+    fn with_fields(count: usize) -> DeriveInput {
+        let idents = (0..count).map(|i| format_ident!("field{}", i));
+        let types = (0..count).map(|i| format_ident!("Type{}", i));
+
+        syn::parse2(quote! {
+            struct Big {
+                #(#idents: #types,)*
+            }
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn max_fields() {
+        assert!(expand_split(with_fields(10)).is_ok());
+        assert_eq!(
+            expand_split(with_fields(11)).is_ok(),
+            cfg!(feature = "extended_tuple")
+        );
+        assert_eq!(
+            expand_split(with_fields(16)).is_ok(),
+            cfg!(feature = "extended_tuple")
+        );
+        assert!(expand_split(with_fields(17)).is_err());
+
+        assert!(expand_borrow(with_fields(10)).is_ok());
+        assert_eq!(
+            expand_borrow(with_fields(16)).is_ok(),
+            cfg!(feature = "extended_tuple")
+        );
+        assert!(expand_borrow(with_fields(17)).is_err());
+    }
+}
@@ -56,21 +56,25 @@ impl TryUniqueAdder for World {
 
 #[cfg(feature = "panic")]
 impl UniqueAdder for World {
+    #[track_caller]
     fn add_unique<T: 'static + Send + Sync>(&self, unique: T) {
         self.try_add_unique(unique).unwrap()
     }
 
     #[cfg(feature = "non_send")]
+    #[track_caller]
     fn add_unique_non_send<T: 'static + Sync>(&self, unique: T) {
         self.try_add_unique_non_send(unique).unwrap()
     }
 
     #[cfg(feature = "non_sync")]
+    #[track_caller]
     fn add_unique_non_sync<T: 'static + Send>(&self, unique: T) {
         self.try_add_unique_non_sync(unique).unwrap()
     }
 
     #[cfg(all(feature = "non_send", feature = "non_sync"))]
+    #[track_caller]
     fn add_unique_non_send_sync<T: 'static>(&self, unique: T) {
         self.try_add_unique_non_send_sync(unique).unwrap()
     }
@@ -1,15 +1,21 @@
+#[cfg(feature = "borrow_owner")]
+use crate::borrow_owner::Owner;
 use crate::error;
+#[cfg(feature = "std")]
+use crate::sync::thread::{self, ThreadId};
+use crate::sync::{AtomicUsize, Ordering};
 use alloc::boxed::Box;
 use core::cell::UnsafeCell;
 use core::mem::ManuallyDrop;
-use core::sync::atomic::{AtomicUsize, Ordering};
-#[cfg(feature = "std")]
-use std::thread;
-#[cfg(feature = "std")]
-use thread::ThreadId;
 
 /// Threadsafe `RefCell`-like container.
-#[doc(hidden)]
+///
+/// [World] stores [AllStorages] in one, [Borrow] implementations get their views out of it.
+/// It can only be accessed through the [Borrow] implementations provided by shipyard.
+///
+/// [World]: struct.World.html
+/// [AllStorages]: struct.AllStorages.html
+/// [Borrow]: trait.Borrow.html
 pub struct AtomicRefCell<T: ?Sized> {
     borrow_state: BorrowState,
     #[cfg(feature = "std")]
@@ -18,6 +24,12 @@ pub struct AtomicRefCell<T: ?Sized> {
     is_sync: bool,
     _non_send: core::marker::PhantomData<*const ()>,
     taken: bool,
+    // number of successful unique borrows, used to know if the value could have been modified
+    unique_borrows: AtomicUsize,
+    #[cfg(feature = "stats")]
+    shared_borrows: AtomicUsize,
+    #[cfg(feature = "stats")]
+    failed_borrows: AtomicUsize,
     inner: ManuallyDrop<UnsafeCell<T>>,
 }
 
@@ -56,9 +68,32 @@ impl<T: ?Sized> AtomicRefCell<T> {
             is_sync,
             _non_send: core::marker::PhantomData,
             taken: false,
+            unique_borrows: AtomicUsize::new(0),
+            #[cfg(feature = "stats")]
+            shared_borrows: AtomicUsize::new(0),
+            #[cfg(feature = "stats")]
+            failed_borrows: AtomicUsize::new(0),
             inner: ManuallyDrop::new(UnsafeCell::new(value)),
         }
     }
+    /// Returns a mutable reference to the wrapped value.  
+    /// No borrow is needed, the exclusive access to the `AtomicRefCell` guarantees nobody else is using it.
+    pub(crate) fn get_mut(&mut self) -> &mut T {
+        self.inner.get_mut()
+    }
+    /// Returns `true` if the value is `!Send` or `!Sync`.
+    #[cfg(all(feature = "serde1", any(feature = "non_send", feature = "non_sync")))]
+    pub(crate) fn is_thread_local(&self) -> bool {
+        self.send.is_some() || !self.is_sync
+    }
+    /// Returns `true` if the value can't be accessed from the current thread.
+    #[cfg(all(feature = "serde1", any(feature = "non_send", feature = "non_sync")))]
+    pub(crate) fn is_wrong_thread(&self) -> bool {
+        match self.send {
+            Some(thread_id) => thread_id != thread::current().id(),
+            None => false,
+        }
+    }
     /// Immutably borrows the wrapped value, returning an error if the value is currently mutably
     /// borrowed.
     ///
@@ -66,21 +101,24 @@ impl<T: ?Sized> AtomicRefCell<T> {
     /// taken out at the same time.
     pub(crate) fn try_borrow(&self) -> Result<Ref<'_, T>, error::Borrow> {
         #[cfg(feature = "std")]
-        {
-            Ok(Ref {
-                borrow: self.borrow_state.try_borrow(self.send, self.is_sync)?,
-                // SAFE we have the lock
-                inner: unsafe { &*self.inner.get() },
-            })
-        }
+        let borrow = self.borrow_state.try_borrow(self.send, self.is_sync);
         #[cfg(not(feature = "std"))]
-        {
-            Ok(Ref {
-                borrow: self.borrow_state.try_borrow()?,
-                // SAFE we have the lock
-                inner: unsafe { &*self.inner.get() },
-            })
-        }
+        let borrow = self.borrow_state.try_borrow();
+
+        #[cfg(feature = "borrow_owner")]
+        let borrow = self.borrow_state.track(borrow, false);
+
+        #[cfg(feature = "stats")]
+        match borrow {
+            Ok(_) => self.shared_borrows.fetch_add(1, Ordering::Relaxed),
+            Err(_) => self.failed_borrows.fetch_add(1, Ordering::Relaxed),
+        };
+
+        Ok(Ref {
+            borrow: borrow?,
+            // SAFE we have the lock
+            inner: unsafe { &*self.inner.get() },
+        })
     }
     /// Mutably borrows the wrapped value, returning an error if the value is currently borrowed.
     ///
@@ -88,21 +126,39 @@ impl<T: ?Sized> AtomicRefCell<T> {
     /// active.
     pub(crate) fn try_borrow_mut(&self) -> Result<RefMut<'_, T>, error::Borrow> {
         #[cfg(feature = "std")]
-        {
-            Ok(RefMut {
-                borrow: self.borrow_state.try_borrow_mut(self.send, self.is_sync)?,
-                // SAFE we have the lock
-                inner: unsafe { &mut *self.inner.get() },
-            })
-        }
+        let borrow = self.borrow_state.try_borrow_mut(self.send, self.is_sync);
         #[cfg(not(feature = "std"))]
-        {
-            Ok(RefMut {
-                borrow: self.borrow_state.try_borrow_mut()?,
-                // SAFE we have the lock
-                inner: unsafe { &mut *self.inner.get() },
-            })
+        let borrow = self.borrow_state.try_borrow_mut();
+
+        #[cfg(feature = "borrow_owner")]
+        let borrow = self.borrow_state.track(borrow, true);
+
+        #[cfg(feature = "stats")]
+        if borrow.is_err() {
+            self.failed_borrows.fetch_add(1, Ordering::Relaxed);
         }
+
+        let borrow = borrow?;
+        self.unique_borrows.fetch_add(1, Ordering::Relaxed);
+
+        Ok(RefMut {
+            borrow,
+            // SAFE we have the lock
+            inner: unsafe { &mut *self.inner.get() },
+        })
+    }
+    /// Returns how many times the value was mutably borrowed.  
+    /// If it didn't change, the value wasn't modified.
+    pub(crate) fn unique_borrows(&self) -> usize {
+        self.unique_borrows.load(Ordering::Relaxed)
+    }
+    /// Returns how many times the value was immutably borrowed and how many borrows failed.
+    #[cfg(feature = "stats")]
+    pub(crate) fn borrow_stats(&self) -> (usize, usize) {
+        (
+            self.shared_borrows.load(Ordering::Relaxed),
+            self.failed_borrows.load(Ordering::Relaxed),
+        )
     }
 }
 
@@ -111,7 +167,7 @@ impl AtomicRefCell<dyn crate::unknown_storage::UnknownStorage> {
     ///
     /// `T` has to be a unique storage of the right type.
     #[allow(clippy::boxed_local)]
-    pub unsafe fn into_unique<T: 'static>(mut this: Box<Self>) -> T {
+    pub(crate) unsafe fn into_unique<T: 'static>(mut this: Box<Self>) -> T {
         use core::mem::MaybeUninit;
 
         let mut tmp: MaybeUninit<T> = MaybeUninit::uninit();
@@ -129,7 +185,11 @@ impl AtomicRefCell<dyn crate::unknown_storage::UnknownStorage> {
 /// `BorrowState` keeps track of which borrow is currently active.
 // If `HIGH_BIT` is set, it is a unique borrow, in all other cases it is a shared borrowed
 #[doc(hidden)]
-pub struct BorrowState(AtomicUsize);
+pub struct BorrowState(
+    AtomicUsize,
+    // holder of the borrow, shared borrows only keep the last one
+    #[cfg(feature = "borrow_owner")] parking_lot::Mutex<Option<Owner>>,
+);
 
 const HIGH_BIT: usize = !(core::usize::MAX >> 1);
 const MAX_FAILED_BORROWS: usize = HIGH_BIT + (HIGH_BIT >> 1);
@@ -219,7 +279,7 @@ impl BorrowState {
                         .compare_exchange(0, HIGH_BIT, Ordering::Acquire, Ordering::Relaxed)
                     {
                         Ok(_) => Ok(Borrow::Unique(self)),
-                        _ => Err(error::Borrow::Unique),
+                        _ => Err(error::Borrow::Unique(Default::default())),
                     }
                 }
                 (Some(thread_id), true) | (Some(thread_id), false) => {
@@ -232,7 +292,7 @@ impl BorrowState {
                             Ordering::Relaxed,
                         ) {
                             Ok(_) => Ok(Borrow::Unique(self)),
-                            _ => Err(error::Borrow::Unique),
+                            _ => Err(error::Borrow::Unique(Default::default())),
                         }
                     } else {
                         Err(error::Borrow::WrongThread)
@@ -247,7 +307,7 @@ impl BorrowState {
                 .compare_exchange(0, HIGH_BIT, Ordering::Acquire, Ordering::Relaxed)
             {
                 Ok(_) => Ok(Borrow::Unique(self)),
-                _ => Err(error::Borrow::Unique),
+                _ => Err(error::Borrow::Unique(Default::default())),
             }
         }
     }
@@ -276,14 +336,43 @@ impl BorrowState {
             let _ = self
                 .0
                 .compare_exchange(new, new - 1, Ordering::Release, Ordering::Relaxed);
-            error::Borrow::Shared
+            error::Borrow::Shared(Default::default())
+        }
+    }
+}
+
+#[cfg(feature = "borrow_owner")]
+impl BorrowState {
+    /// Records the new holder or adds who prevented the borrow to the error.
+    fn track<'a>(
+        &self,
+        borrow: Result<Borrow<'a>, error::Borrow>,
+        exclusive: bool,
+    ) -> Result<Borrow<'a>, error::Borrow> {
+        match borrow {
+            Ok(borrow) => {
+                // the lock is taken after the borrow, the drop of a previous borrow can't clear it
+                *self.1.lock() = Some(Owner::current(exclusive));
+                Ok(borrow)
+            }
+            Err(error::Borrow::Unique(_)) => {
+                Err(error::Borrow::Unique(error::BorrowOwner(*self.1.lock())))
+            }
+            Err(error::Borrow::Shared(_)) => {
+                Err(error::Borrow::Shared(error::BorrowOwner(*self.1.lock())))
+            }
+            Err(err) => Err(err),
         }
     }
 }
 
 impl Default for BorrowState {
     fn default() -> Self {
-        BorrowState(AtomicUsize::new(0))
+        BorrowState(
+            AtomicUsize::new(0),
+            #[cfg(feature = "borrow_owner")]
+            parking_lot::Mutex::new(None),
+        )
     }
 }
 
@@ -317,10 +406,25 @@ impl<'a> Drop for Borrow<'a> {
     fn drop(&mut self) {
         match self {
             Borrow::Shared(borrow) => {
+                #[cfg(feature = "borrow_owner")]
+                let mut owner = borrow.1.lock();
+
                 let old = borrow.0.fetch_sub(1, Ordering::Release);
                 debug_assert!(old & HIGH_BIT == 0);
+
+                #[cfg(feature = "borrow_owner")]
+                {
+                    if old == 1 {
+                        *owner = None;
+                    }
+                }
             }
             Borrow::Unique(borrow) => {
+                #[cfg(feature = "borrow_owner")]
+                {
+                    *borrow.1.lock() = None;
+                }
+
                 borrow.0.store(0, Ordering::Release);
             }
             Borrow::None => {}
@@ -460,7 +564,7 @@ fn reborrow() {
     assert!(refcell.try_borrow().is_ok());
     assert_eq!(
         std::mem::discriminant(&refcell.try_borrow_mut().err().unwrap()),
-        std::mem::discriminant(&error::Borrow::Unique)
+        std::mem::discriminant(&error::Borrow::Unique(Default::default()))
     );
 }
 #[test]
@@ -479,11 +583,11 @@ fn unique_reborrow() {
 
     assert_eq!(
         std::mem::discriminant(&refcell.try_borrow().err().unwrap()),
-        std::mem::discriminant(&error::Borrow::Shared)
+        std::mem::discriminant(&error::Borrow::Shared(Default::default()))
     );
     assert_eq!(
         std::mem::discriminant(&refcell.try_borrow_mut().err().unwrap()),
-        std::mem::discriminant(&error::Borrow::Unique)
+        std::mem::discriminant(&error::Borrow::Unique(Default::default()))
     );
 }
 
@@ -526,7 +630,10 @@ fn non_sync_send() {
         );
     });
     rayon::scope(|_| {
-        assert_eq!(refcell.try_borrow_mut().err(), Some(error::Borrow::Unique));
+        assert_eq!(
+            refcell.try_borrow_mut().err(),
+            Some(error::Borrow::Unique(Default::default()))
+        );
     });
     drop(borrow);
     rayon::scope(|_| {
@@ -1,7 +1,10 @@
 //use super::FakeBorrow;
 use crate::error;
 use crate::storage::AllStorages;
-use crate::view::{EntitiesView, EntitiesViewMut, UniqueView, UniqueViewMut, View, ViewMut};
+use crate::view::{
+    EntitiesView, EntitiesViewMut, MaskView, RelationView, RelationViewMut, TagsView, TagsViewMut,
+    UniqueView, UniqueViewMut, View, ViewMut,
+};
 #[cfg(feature = "non_send")]
 use crate::NonSend;
 #[cfg(all(feature = "non_send", feature = "non_sync"))]
@@ -10,7 +13,14 @@ use crate::NonSendSync;
 use crate::NonSync;
 use core::convert::TryInto;
 
+/// Allows a type to be borrowed with [AllStorages::borrow].
+///
+/// Custom views can implement it the same way as [Borrow], by delegating to shipyard's views.
+///
+/// [AllStorages::borrow]: struct.AllStorages.html#method.borrow
+/// [Borrow]: trait.Borrow.html
 pub trait AllStoragesBorrow<'a> {
+    /// Borrows the view from `all_storages`.
     fn try_borrow(all_storages: &'a AllStorages) -> Result<Self, error::GetStorage>
     where
         Self: Sized;
@@ -61,6 +71,36 @@ impl<'a, T: 'static + Send + Sync> AllStoragesBorrow<'a> for UniqueViewMut<'a, T
     }
 }
 
+impl<'a, T: 'static + Send + Sync> AllStoragesBorrow<'a> for RelationView<'a, T> {
+    fn try_borrow(all_storages: &'a AllStorages) -> Result<Self, error::GetStorage> {
+        all_storages.try_into()
+    }
+}
+
+impl<'a, T: 'static + Send + Sync> AllStoragesBorrow<'a> for RelationViewMut<'a, T> {
+    fn try_borrow(all_storages: &'a AllStorages) -> Result<Self, error::GetStorage> {
+        all_storages.try_into()
+    }
+}
+
+impl<'a> AllStoragesBorrow<'a> for MaskView<'a> {
+    fn try_borrow(all_storages: &'a AllStorages) -> Result<Self, error::GetStorage> {
+        all_storages.try_into()
+    }
+}
+
+impl<'a> AllStoragesBorrow<'a> for TagsView<'a> {
+    fn try_borrow(all_storages: &'a AllStorages) -> Result<Self, error::GetStorage> {
+        all_storages.try_into()
+    }
+}
+
+impl<'a> AllStoragesBorrow<'a> for TagsViewMut<'a> {
+    fn try_borrow(all_storages: &'a AllStorages) -> Result<Self, error::GetStorage> {
+        all_storages.try_into()
+    }
+}
+
 #[cfg(feature = "non_send")]
 impl<'a, T: 'static + Sync> AllStoragesBorrow<'a> for NonSend<View<'a, T>> {
     fn try_borrow(all_storages: &'a AllStorages) -> Result<Self, error::GetStorage> {
@@ -145,6 +185,8 @@ impl<'a, T: 'static> AllStoragesBorrow<'a> for NonSendSync<UniqueViewMut<'a, T>>
     }
 }
 
+/// Returns `None` instead of an error when `T` can't be borrowed,
+/// for example if it's a unique that was never added.
 impl<'a, T: AllStoragesBorrow<'a>> AllStoragesBorrow<'a> for Option<T> {
     fn try_borrow(all_storages: &'a AllStorages) -> Result<Self, error::GetStorage> {
         Ok(T::try_borrow(all_storages).ok())
@@ -175,4 +217,7 @@ macro_rules! all_storages_borrow {
     }
 }
 
+#[cfg(not(feature = "extended_tuple"))]
 all_storages_borrow![(A, 0); (B, 1) (C, 2) (D, 3) (E, 4) (F, 5) (G, 6) (H, 7) (I, 8) (J, 9)];
+#[cfg(feature = "extended_tuple")]
+all_storages_borrow![(A, 0); (B, 1) (C, 2) (D, 3) (E, 4) (F, 5) (G, 6) (H, 7) (I, 8) (J, 9) (K, 10) (L, 11) (M, 12) (N, 13) (O, 14) (P, 15)];
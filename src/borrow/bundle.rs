@@ -0,0 +1,93 @@
+use super::{AllStoragesBorrow, Borrow, Mutation};
+use crate::atomic_refcell::AtomicRefCell;
+use crate::error;
+use crate::storage::AllStorages;
+use crate::type_id::TypeId;
+use alloc::vec::Vec;
+
+/// Groups multiple views in a single struct that can be used as a system parameter.
+///
+/// The views are borrowed as a tuple, `Views`, then moved in the struct.
+/// With the **proc** feature this trait can be derived.
+///
+/// ### Example
+/// ```
+/// use shipyard::{BorrowBundle, IntoIter, Shiperator, View, ViewMut, World};
+///
+/// struct Pos(f32);
+/// struct Vel(f32);
+///
+/// struct Physics<'a> {
+///     pos: ViewMut<'a, Pos>,
+///     vel: View<'a, Vel>,
+/// }
+///
+/// impl<'a> BorrowBundle<'a> for Physics<'a> {
+///     type Views = (ViewMut<'a, Pos>, View<'a, Vel>);
+///
+///     fn from_views((pos, vel): Self::Views) -> Self {
+///         Physics { pos, vel }
+///     }
+/// }
+///
+/// fn movement(mut physics: Physics) {
+///     for (pos, vel) in (&mut physics.pos, &physics.vel).iter() {
+///         pos.0 += vel.0;
+///     }
+/// }
+///
+/// let world = World::new();
+///
+/// world.run(movement);
+/// ```
+pub trait BorrowBundle<'a> {
+    /// Views borrowed to make the bundle, usually a tuple.
+    type Views;
+
+    fn from_views(views: Self::Views) -> Self;
+}
+
+impl<'a, T: BorrowBundle<'a>> Borrow<'a> for T
+where
+    T::Views: Borrow<'a>,
+{
+    fn try_borrow(
+        all_storages: &'a AtomicRefCell<AllStorages>,
+        #[cfg(feature = "parallel")] thread_pool: &'a rayon::ThreadPool,
+    ) -> Result<Self, error::GetStorage> {
+        #[cfg(feature = "parallel")]
+        {
+            Ok(T::from_views(<T::Views as Borrow<'a>>::try_borrow(
+                all_storages,
+                thread_pool,
+            )?))
+        }
+        #[cfg(not(feature = "parallel"))]
+        {
+            Ok(T::from_views(<T::Views as Borrow<'a>>::try_borrow(
+                all_storages,
+            )?))
+        }
+    }
+
+    fn borrow_infos(infos: &mut Vec<(TypeId, Mutation)>) {
+        T::Views::borrow_infos(infos);
+    }
+
+    fn is_send_sync() -> bool {
+        T::Views::is_send_sync()
+    }
+
+    fn create_storages(all_storages: &AllStorages) {
+        T::Views::create_storages(all_storages);
+    }
+}
+
+impl<'a, T: BorrowBundle<'a>> AllStoragesBorrow<'a> for T
+where
+    T::Views: AllStoragesBorrow<'a>,
+{
+    fn try_borrow(all_storages: &'a AllStorages) -> Result<Self, error::GetStorage> {
+        Ok(T::from_views(T::Views::try_borrow(all_storages)?))
+    }
+}
@@ -1,4 +1,5 @@
 mod all_storages;
+mod bundle;
 mod fake_borrow;
 #[cfg(feature = "non_send")]
 mod non_send;
@@ -8,6 +9,7 @@ mod non_send_sync;
 mod non_sync;
 
 pub use all_storages::AllStoragesBorrow;
+pub use bundle::BorrowBundle;
 pub use fake_borrow::FakeBorrow;
 #[cfg(feature = "non_send")]
 pub use non_send::NonSend;
@@ -18,23 +20,90 @@ pub use non_sync::NonSync;
 
 use crate::atomic_refcell::AtomicRefCell;
 use crate::error;
+use crate::relation::Relation;
 use crate::storage::{AllStorages, Entities};
+use crate::tags::Tags;
 use crate::type_id::TypeId;
 #[cfg(feature = "parallel")]
+use crate::view::Tasks;
+#[cfg(feature = "parallel")]
+#[allow(deprecated)]
 use crate::view::ThreadPoolView;
 use crate::view::{
-    AllStoragesViewMut, EntitiesView, EntitiesViewMut, UniqueView, UniqueViewMut, View, ViewMut,
+    AllStoragesView, AllStoragesViewMut, EntitiesView, EntitiesViewMut, MaskView, RelationView,
+    RelationViewMut, TagsView, TagsViewMut, UniqueView, UniqueViewMut, View, ViewMut,
 };
 use alloc::vec::Vec;
 use core::convert::TryInto;
 
+/// How a storage is borrowed, the scheduler uses it to know which systems can run in parallel.
 #[derive(Clone, Copy, PartialEq, Eq, Debug)]
 pub enum Mutation {
+    /// Multiple systems can borrow the storage at the same time.
     Shared,
+    /// Only this system can borrow the storage.
     Unique,
 }
 
+/// Allows a type to be used as a system parameter or borrowed with [World::borrow].
+///
+/// Custom views are usually built on top of shipyard's views, delegating all their methods.
+/// If a view is built from multiple ones, [BorrowBundle] is simpler to implement.
+///
+/// ### Example
+/// ```
+/// use shipyard::error::GetStorage;
+/// use shipyard::{AllStorages, AtomicRefCell, Borrow, Mutation, TypeId, UniqueView, World};
+///
+/// struct Assets<T>(Vec<T>);
+///
+/// /// Read-only access to the `Assets<T>` unique storage.
+/// struct AssetsView<'a, T: 'static + Send + Sync>(UniqueView<'a, Assets<T>>);
+///
+/// impl<T: 'static + Send + Sync> AssetsView<'_, T> {
+///     fn get(&self, handle: usize) -> Option<&T> {
+///         self.0 .0.get(handle)
+///     }
+/// }
+///
+/// impl<'a, T: 'static + Send + Sync> Borrow<'a> for AssetsView<'a, T> {
+///     fn try_borrow(
+///         all_storages: &'a AtomicRefCell<AllStorages>,
+///         # #[cfg(feature = "parallel")]
+///         thread_pool: &'a rayon::ThreadPool,
+///     ) -> Result<Self, GetStorage> {
+///         Ok(AssetsView(UniqueView::try_borrow(
+///             all_storages,
+///             # #[cfg(feature = "parallel")]
+///             thread_pool,
+///         )?))
+///     }
+///
+///     fn borrow_infos(infos: &mut Vec<(TypeId, Mutation)>) {
+///         infos.push((TypeId::of::<Assets<T>>(), Mutation::Shared));
+///     }
+///
+///     fn is_send_sync() -> bool {
+///         true
+///     }
+/// }
+///
+/// let world = World::new();
+///
+/// world.add_unique(Assets(vec!["grass.png"]));
+///
+/// world.run(|textures: AssetsView<&str>| {
+///     assert_eq!(textures.get(0), Some(&"grass.png"));
+/// });
+/// ```
+///
+/// [World::borrow]: struct.World.html#method.borrow
+/// [BorrowBundle]: trait.BorrowBundle.html
 pub trait Borrow<'a> {
+    /// Borrows the view from `all_storages`.  
+    /// The **parallel** feature adds `thread_pool`, the pool used by the [World].
+    ///
+    /// [World]: struct.World.html
     fn try_borrow(
         all_storages: &'a AtomicRefCell<AllStorages>,
         #[cfg(feature = "parallel")] thread_pool: &'a rayon::ThreadPool,
@@ -42,9 +111,21 @@ pub trait Borrow<'a> {
     where
         Self: Sized;
 
+    /// Pushes the `TypeId` of each storage borrowed and how it's borrowed.  
+    /// The scheduler will not run two systems in parallel if one borrows a storage uniquely and the other borrows it too.
+    /// Use `TypeId::of::<AllStorages>()` for views borrowing all storages.
     fn borrow_infos(infos: &mut Vec<(TypeId, Mutation)>);
 
+    /// Returns `false` if the view borrows `!Send` or `!Sync` storages, the system then has to run on the [World]'s thread.
+    ///
+    /// [World]: struct.World.html
     fn is_send_sync() -> bool;
+
+    /// Creates the storages the view borrows if they don't exist, used by [World::prepare_workload].  
+    /// By default nothing is created ahead of time, storages are then created the first time the view is borrowed.
+    ///
+    /// [World::prepare_workload]: struct.World.html#method.prepare_workload
+    fn create_storages(_all_storages: &AllStorages) {}
 }
 
 impl<'a> Borrow<'a> for () {
@@ -65,6 +146,23 @@ impl<'a> Borrow<'a> for () {
     }
 }
 
+impl<'a> Borrow<'a> for AllStoragesView<'a> {
+    fn try_borrow(
+        all_storages: &'a AtomicRefCell<AllStorages>,
+        #[cfg(feature = "parallel")] _: &'a rayon::ThreadPool,
+    ) -> Result<Self, error::GetStorage> {
+        all_storages.try_into()
+    }
+
+    fn borrow_infos(infos: &mut Vec<(TypeId, Mutation)>) {
+        infos.push((TypeId::of::<AllStorages>(), Mutation::Shared));
+    }
+
+    fn is_send_sync() -> bool {
+        true
+    }
+}
+
 impl<'a> Borrow<'a> for AllStoragesViewMut<'a> {
     fn try_borrow(
         all_storages: &'a AtomicRefCell<AllStorages>,
@@ -123,6 +221,7 @@ impl<'a> Borrow<'a> for EntitiesViewMut<'a> {
 }
 
 #[cfg(feature = "parallel")]
+#[allow(deprecated)]
 impl<'a> Borrow<'a> for ThreadPoolView<'a> {
     fn try_borrow(
         _: &'a AtomicRefCell<AllStorages>,
@@ -138,6 +237,22 @@ impl<'a> Borrow<'a> for ThreadPoolView<'a> {
     }
 }
 
+#[cfg(feature = "parallel")]
+impl<'a> Borrow<'a> for Tasks<'a> {
+    fn try_borrow(
+        _: &'a AtomicRefCell<AllStorages>,
+        thread_pool: &'a rayon::ThreadPool,
+    ) -> Result<Self, error::GetStorage> {
+        Ok(Tasks(thread_pool))
+    }
+
+    fn borrow_infos(_: &mut Vec<(TypeId, Mutation)>) {}
+
+    fn is_send_sync() -> bool {
+        true
+    }
+}
+
 impl<'a, T: 'static + Send + Sync> Borrow<'a> for View<'a, T> {
     fn try_borrow(
         all_storages: &'a AtomicRefCell<AllStorages>,
@@ -156,6 +271,10 @@ impl<'a, T: 'static + Send + Sync> Borrow<'a> for View<'a, T> {
     fn is_send_sync() -> bool {
         true
     }
+
+    fn create_storages(all_storages: &AllStorages) {
+        let _ = all_storages.sparse_set::<T>();
+    }
 }
 
 impl<'a, T: 'static + Send + Sync> Borrow<'a> for ViewMut<'a, T> {
@@ -176,6 +295,10 @@ impl<'a, T: 'static + Send + Sync> Borrow<'a> for ViewMut<'a, T> {
     fn is_send_sync() -> bool {
         true
     }
+
+    fn create_storages(all_storages: &AllStorages) {
+        let _ = all_storages.sparse_set::<T>();
+    }
 }
 
 impl<'a, T: 'static + Send + Sync> Borrow<'a> for UniqueView<'a, T> {
@@ -218,6 +341,122 @@ impl<'a, T: 'static + Send + Sync> Borrow<'a> for UniqueViewMut<'a, T> {
     }
 }
 
+impl<'a, T: 'static + Send + Sync> Borrow<'a> for RelationView<'a, T> {
+    fn try_borrow(
+        all_storages: &'a AtomicRefCell<AllStorages>,
+        #[cfg(feature = "parallel")] _: &'a rayon::ThreadPool,
+    ) -> Result<Self, error::GetStorage> {
+        all_storages
+            .try_borrow()
+            .map_err(error::GetStorage::AllStoragesBorrow)?
+            .try_into()
+    }
+
+    fn borrow_infos(infos: &mut Vec<(TypeId, Mutation)>) {
+        infos.push((TypeId::of::<Relation<T>>(), Mutation::Shared));
+    }
+
+    fn is_send_sync() -> bool {
+        true
+    }
+
+    fn create_storages(all_storages: &AllStorages) {
+        let _ = all_storages.relation::<T>();
+    }
+}
+
+impl<'a, T: 'static + Send + Sync> Borrow<'a> for RelationViewMut<'a, T> {
+    fn try_borrow(
+        all_storages: &'a AtomicRefCell<AllStorages>,
+        #[cfg(feature = "parallel")] _: &'a rayon::ThreadPool,
+    ) -> Result<Self, error::GetStorage> {
+        all_storages
+            .try_borrow()
+            .map_err(error::GetStorage::AllStoragesBorrow)?
+            .try_into()
+    }
+
+    fn borrow_infos(infos: &mut Vec<(TypeId, Mutation)>) {
+        infos.push((TypeId::of::<Relation<T>>(), Mutation::Unique));
+    }
+
+    fn is_send_sync() -> bool {
+        true
+    }
+
+    fn create_storages(all_storages: &AllStorages) {
+        let _ = all_storages.relation::<T>();
+    }
+}
+
+impl<'a> Borrow<'a> for MaskView<'a> {
+    fn try_borrow(
+        all_storages: &'a AtomicRefCell<AllStorages>,
+        #[cfg(feature = "parallel")] _: &'a rayon::ThreadPool,
+    ) -> Result<Self, error::GetStorage> {
+        all_storages
+            .try_borrow()
+            .map_err(error::GetStorage::AllStoragesBorrow)?
+            .try_into()
+    }
+
+    fn borrow_infos(infos: &mut Vec<(TypeId, Mutation)>) {
+        infos.push((TypeId::of::<Entities>(), Mutation::Shared));
+    }
+
+    fn is_send_sync() -> bool {
+        true
+    }
+}
+
+impl<'a> Borrow<'a> for TagsView<'a> {
+    fn try_borrow(
+        all_storages: &'a AtomicRefCell<AllStorages>,
+        #[cfg(feature = "parallel")] _: &'a rayon::ThreadPool,
+    ) -> Result<Self, error::GetStorage> {
+        all_storages
+            .try_borrow()
+            .map_err(error::GetStorage::AllStoragesBorrow)?
+            .try_into()
+    }
+
+    fn borrow_infos(infos: &mut Vec<(TypeId, Mutation)>) {
+        infos.push((TypeId::of::<Tags>(), Mutation::Shared));
+    }
+
+    fn is_send_sync() -> bool {
+        true
+    }
+
+    fn create_storages(all_storages: &AllStorages) {
+        let _ = all_storages.tags();
+    }
+}
+
+impl<'a> Borrow<'a> for TagsViewMut<'a> {
+    fn try_borrow(
+        all_storages: &'a AtomicRefCell<AllStorages>,
+        #[cfg(feature = "parallel")] _: &'a rayon::ThreadPool,
+    ) -> Result<Self, error::GetStorage> {
+        all_storages
+            .try_borrow()
+            .map_err(error::GetStorage::AllStoragesBorrow)?
+            .try_into()
+    }
+
+    fn borrow_infos(infos: &mut Vec<(TypeId, Mutation)>) {
+        infos.push((TypeId::of::<Tags>(), Mutation::Unique));
+    }
+
+    fn is_send_sync() -> bool {
+        true
+    }
+
+    fn create_storages(all_storages: &AllStorages) {
+        let _ = all_storages.tags();
+    }
+}
+
 #[cfg(feature = "non_send")]
 impl<'a, T: 'static + Sync> Borrow<'a> for NonSend<View<'a, T>> {
     fn try_borrow(
@@ -239,6 +478,10 @@ impl<'a, T: 'static + Sync> Borrow<'a> for NonSend<View<'a, T>> {
     fn is_send_sync() -> bool {
         false
     }
+
+    fn create_storages(all_storages: &AllStorages) {
+        let _ = all_storages.sparse_set_non_send::<T>();
+    }
 }
 
 #[cfg(feature = "non_send")]
@@ -262,6 +505,10 @@ impl<'a, T: 'static + Sync> Borrow<'a> for NonSend<ViewMut<'a, T>> {
     fn is_send_sync() -> bool {
         false
     }
+
+    fn create_storages(all_storages: &AllStorages) {
+        let _ = all_storages.sparse_set_non_send::<T>();
+    }
 }
 
 #[cfg(feature = "non_send")]
@@ -329,6 +576,10 @@ impl<'a, T: 'static + Send> Borrow<'a> for NonSync<View<'a, T>> {
     fn is_send_sync() -> bool {
         false
     }
+
+    fn create_storages(all_storages: &AllStorages) {
+        let _ = all_storages.sparse_set_non_sync::<T>();
+    }
 }
 
 #[cfg(feature = "non_sync")]
@@ -352,6 +603,10 @@ impl<'a, T: 'static + Send> Borrow<'a> for NonSync<ViewMut<'a, T>> {
     fn is_send_sync() -> bool {
         false
     }
+
+    fn create_storages(all_storages: &AllStorages) {
+        let _ = all_storages.sparse_set_non_sync::<T>();
+    }
 }
 
 #[cfg(feature = "non_sync")]
@@ -419,6 +674,10 @@ impl<'a, T: 'static> Borrow<'a> for NonSendSync<View<'a, T>> {
     fn is_send_sync() -> bool {
         false
     }
+
+    fn create_storages(all_storages: &AllStorages) {
+        let _ = all_storages.sparse_set_non_send_sync::<T>();
+    }
 }
 
 #[cfg(all(feature = "non_send", feature = "non_sync"))]
@@ -442,6 +701,10 @@ impl<'a, T: 'static> Borrow<'a> for NonSendSync<ViewMut<'a, T>> {
     fn is_send_sync() -> bool {
         false
     }
+
+    fn create_storages(all_storages: &AllStorages) {
+        let _ = all_storages.sparse_set_non_send_sync::<T>();
+    }
 }
 
 #[cfg(all(feature = "non_send", feature = "non_sync"))]
@@ -504,6 +767,8 @@ impl<'a, T: 'static> Borrow<'a> for FakeBorrow<T> {
     }
 }
 
+/// Returns `None` instead of an error when `T` can't be borrowed,
+/// for example if it's a unique that was never added.
 impl<'a, T: Borrow<'a>> Borrow<'a> for Option<T> {
     fn try_borrow(
         all_storages: &'a AtomicRefCell<AllStorages>,
@@ -526,6 +791,10 @@ impl<'a, T: Borrow<'a>> Borrow<'a> for Option<T> {
     fn is_send_sync() -> bool {
         T::is_send_sync()
     }
+
+    fn create_storages(all_storages: &AllStorages) {
+        T::create_storages(all_storages);
+    }
 }
 
 macro_rules! impl_borrow {
@@ -558,6 +827,12 @@ macro_rules! impl_borrow {
             fn is_send_sync() -> bool {
                 $($type::is_send_sync())&&+
             }
+
+            fn create_storages(all_storages: &AllStorages) {
+                $(
+                    $type::create_storages(all_storages);
+                )+
+            }
         }
     }
 }
@@ -572,4 +847,7 @@ macro_rules! borrow {
     }
 }
 
+#[cfg(not(feature = "extended_tuple"))]
 borrow![(A, 0); (B, 1) (C, 2) (D, 3) (E, 4) (F, 5) (G, 6) (H, 7) (I, 8) (J, 9)];
+#[cfg(feature = "extended_tuple")]
+borrow![(A, 0); (B, 1) (C, 2) (D, 3) (E, 4) (F, 5) (G, 6) (H, 7) (I, 8) (J, 9) (K, 10) (L, 11) (M, 12) (N, 13) (O, 14) (P, 15)];
@@ -0,0 +1,59 @@
+use core::cell::Cell;
+use core::fmt::{Display, Formatter};
+use std::thread::{self, ThreadId};
+
+std::thread_local! {
+    // system running on this thread, if any
+    static CURRENT_SYSTEM: Cell<Option<&'static str>> = const { Cell::new(None) };
+}
+
+/// System and thread holding a borrow.
+#[derive(Clone, Copy)]
+pub(crate) struct Owner {
+    exclusive: bool,
+    system: Option<&'static str>,
+    thread: ThreadId,
+}
+
+impl Owner {
+    /// Returns an `Owner` for a borrow made by the current system on this thread.
+    pub(crate) fn current(exclusive: bool) -> Self {
+        Owner {
+            exclusive,
+            system: CURRENT_SYSTEM.with(Cell::get),
+            thread: thread::current().id(),
+        }
+    }
+}
+
+impl Display for Owner {
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
+        if self.exclusive {
+            fmt.write_str("exclusively borrowed")?;
+        } else {
+            fmt.write_str("borrowed")?;
+        }
+
+        if let Some(system) = self.system {
+            fmt.write_fmt(format_args!(" by system '{}'", system))?;
+        }
+
+        fmt.write_fmt(format_args!(" on thread {:?}", self.thread))
+    }
+}
+
+/// Runs `f` as `system`, borrows it makes will be attributed to it.
+pub(crate) fn run_as<R>(system: &'static str, f: impl FnOnce() -> R) -> R {
+    // restores the previous system even if `f` panics
+    struct Restore(Option<&'static str>);
+
+    impl Drop for Restore {
+        fn drop(&mut self) {
+            CURRENT_SYSTEM.with(|current| current.set(self.0));
+        }
+    }
+
+    let _restore = Restore(CURRENT_SYSTEM.with(|current| current.replace(Some(system))));
+
+    f()
+}
@@ -0,0 +1,141 @@
+use crate::error;
+use crate::spawn::Spawn;
+use crate::storage::{AllStorages, Entities, RegisterStorages};
+use alloc::boxed::Box;
+#[cfg(feature = "parallel")]
+use core::cell::Cell;
+
+/// Entity waiting for the next sync point to be created.
+pub(crate) type DeferredEntity = Box<dyn FnOnce(&AllStorages) -> Result<(), error::GetStorage> + Send>;
+
+#[cfg(feature = "parallel")]
+std::thread_local! {
+    // position in its batch of the system running on this thread
+    static CURRENT_POSITION: Cell<usize> = const { Cell::new(0) };
+}
+
+/// Runs `f` as the system at `position` in a parallel batch.
+/// Entities it defers are ordered by this position once the batch is over.
+#[cfg(feature = "parallel")]
+pub(crate) fn run_at<R>(position: usize, f: impl FnOnce() -> R) -> R {
+    // restores the previous position even if `f` panics
+    struct Restore(usize);
+
+    impl Drop for Restore {
+        fn drop(&mut self) {
+            CURRENT_POSITION.with(|current| current.set(self.0));
+        }
+    }
+
+    let _restore = Restore(CURRENT_POSITION.with(|current| current.replace(position)));
+
+    f()
+}
+
+/// Returns the position in its batch of the system running on this thread, 0 outside of parallel batches.
+pub(crate) fn current_position() -> usize {
+    #[cfg(feature = "parallel")]
+    {
+        CURRENT_POSITION.with(Cell::get)
+    }
+    #[cfg(not(feature = "parallel"))]
+    {
+        0
+    }
+}
+
+/// Records the components of an entity created at the next [World::maintain].
+/// Only needs a shared borrow of `Entities`, systems running in parallel can use it to spawn entities.
+///
+/// In strict mode the storages of the components are registered when the entity is created.
+///
+/// ### Example
+/// ```
+/// use shipyard::{EntitiesView, IntoIter, Shiperator, View, World};
+///
+/// let world = World::new();
+///
+/// world.run(|entities: EntitiesView| {
+///     entities
+///         .deferred_entity_builder()
+///         .with(0usize)
+///         .with(1u32)
+///         .build();
+/// });
+///
+/// world.run(|usizes: View<usize>| assert_eq!(usizes.len(), 0));
+///
+/// world.maintain();
+///
+/// world.run(|usizes: View<usize>, u32s: View<u32>| {
+///     assert_eq!((&usizes, &u32s).iter().collect::<Vec<_>>(), vec![(&0, &1)]);
+/// });
+/// ```
+///
+/// [World::maintain]: struct.World.html#method.maintain
+pub struct DeferredEntityBuilder<'a, C> {
+    entities: &'a Entities,
+    components: C,
+}
+
+impl<'a> DeferredEntityBuilder<'a, ()> {
+    pub(crate) fn new(entities: &'a Entities) -> Self {
+        DeferredEntityBuilder {
+            entities,
+            components: (),
+        }
+    }
+    /// Adds a component to the future entity.
+    /// Nothing is borrowed until the entity is created.
+    pub fn with<T: 'static + Send + Sync>(self, component: T) -> DeferredEntityBuilder<'a, (T,)> {
+        DeferredEntityBuilder {
+            entities: self.entities,
+            components: (component,),
+        }
+    }
+}
+
+impl<C: 'static + Spawn + RegisterStorages + Send> DeferredEntityBuilder<'_, C> {
+    /// Queues the entity, it will be created at the next [World::try_maintain].  
+    /// If the entity can't be created, the error is returned by [World::try_maintain].
+    ///
+    /// [World::try_maintain]: struct.World.html#method.try_maintain
+    pub fn build(self) {
+        let components = self.components;
+
+        self.entities
+            .defer(Box::new(move |all_storages: &AllStorages| {
+                all_storages.register_storages::<C>();
+
+                components.try_spawn(all_storages).map(drop)
+            }));
+    }
+}
+
+macro_rules! impl_deferred_entity_builder {
+    ($(($type: ident, $index: tt))+) => {
+        impl<'a, $($type: 'static + Send + Sync),+> DeferredEntityBuilder<'a, ($($type,)+)> {
+            /// Adds a component to the future entity.
+            /// Nothing is borrowed until the entity is created.
+            pub fn with<T: 'static + Send + Sync>(self, component: T) -> DeferredEntityBuilder<'a, ($($type,)+ T,)> {
+                DeferredEntityBuilder {
+                    entities: self.entities,
+                    components: ($(self.components.$index,)+ component,),
+                }
+            }
+        }
+    }
+}
+
+macro_rules! deferred_entity_builder {
+    ($(($type: ident, $index: tt))+; ($type1: ident, $index1: tt) $(($queue_type: ident, $queue_index: tt))*) => {
+        impl_deferred_entity_builder![$(($type, $index))*];
+        deferred_entity_builder![$(($type, $index))* ($type1, $index1); $(($queue_type, $queue_index))*];
+    };
+    ($(($type: ident, $index: tt))+;) => {}
+}
+
+#[cfg(not(feature = "extended_tuple"))]
+deferred_entity_builder![(A, 0); (B, 1) (C, 2) (D, 3) (E, 4) (F, 5) (G, 6) (H, 7) (I, 8) (J, 9)];
+#[cfg(feature = "extended_tuple")]
+deferred_entity_builder![(A, 0); (B, 1) (C, 2) (D, 3) (E, 4) (F, 5) (G, 6) (H, 7) (I, 8) (J, 9) (K, 10) (L, 11) (M, 12) (N, 13) (O, 14) (P, 15)];
@@ -95,6 +95,7 @@ macro_rules! impl_delete {
                 Ok(())
             }
             #[cfg(feature = "panic")]
+            #[track_caller]
             fn delete(self, entity: EntityId) {
                 Delete::<($($type,)+)>::try_delete(self, entity).unwrap()
             }
@@ -117,4 +118,7 @@ macro_rules! delete {
     }
 }
 
+#[cfg(not(feature = "extended_tuple"))]
 delete![(A, 0);; (B, 1) (C, 2) (D, 3) (E, 4) (F, 5) (G, 6) (H, 7) (I, 8) (J, 9)];
+#[cfg(feature = "extended_tuple")]
+delete![(A, 0);; (B, 1) (C, 2) (D, 3) (E, 4) (F, 5) (G, 6) (H, 7) (I, 8) (J, 9) (K, 10) (L, 11) (M, 12) (N, 13) (O, 14) (P, 15)];
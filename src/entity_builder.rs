@@ -47,6 +47,7 @@ impl<'a> EntityBuilder<'a, (), ()> {
     /// Borrows the storage associated with it panics if its already borrowed.
     #[cfg(feature = "panic")]
     #[cfg_attr(docsrs, doc(cfg(feature = "panic")))]
+    #[track_caller]
     pub fn with<T: 'static + Send + Sync>(
         self,
         component: T,
@@ -87,6 +88,7 @@ impl<'a> EntityBuilder<'a, (), ()> {
     /// Borrows the storage associated with it panics if its already borrowed.
     #[cfg(all(feature = "non_send", feature = "panic"))]
     #[cfg_attr(docsrs, doc(cfg(all(feature = "non_send", feature = "panic"))))]
+    #[track_caller]
     pub fn with_non_send<T: 'static + Sync>(
         self,
         component: T,
@@ -127,6 +129,7 @@ impl<'a> EntityBuilder<'a, (), ()> {
     /// Borrows the storage associated with it panics if its already borrowed.
     #[cfg(all(feature = "non_sync", feature = "panic"))]
     #[cfg_attr(docsrs, doc(cfg(all(feature = "non_sync", feature = "panic"))))]
+    #[track_caller]
     pub fn with_non_sync<T: 'static + Send>(
         self,
         component: T,
@@ -199,6 +202,7 @@ impl<'a> EntityBuilder<'a, (), ()> {
     /// Borrows the `Entities` storage, panics if its already borrowed.
     #[cfg(feature = "panic")]
     #[cfg_attr(docsrs, doc(cfg(feature = "panic")))]
+    #[track_caller]
     pub fn build(self) -> EntityId {
         self.try_build().unwrap()
     }
@@ -231,6 +235,7 @@ macro_rules! impl_entity_builder {
             /// Borrows the storage associated with it panics if its already borrowed.
             #[cfg(feature = "panic")]
             #[cfg_attr(docsrs, doc(cfg(feature = "panic")))]
+            #[track_caller]
             pub fn with<T: 'static + Send + Sync>(self, component: T) -> EntityBuilder<'a, ($($type,)+ T,), ($($storage_type,)+ ViewMut<'a, T>,)> {
                 let storage = self.all_storages.clone().try_into().unwrap();
 
@@ -268,6 +273,7 @@ macro_rules! impl_entity_builder {
             /// Borrows the storage associated with it panics if its already borrowed.
             #[cfg(all(feature = "non_send", feature = "panic"))]
             #[cfg_attr(docsrs, doc(cfg(all(feature = "non_send", feature = "panic"))))]
+            #[track_caller]
             pub fn with_non_send<T: 'static + Sync>(self, component: T) -> EntityBuilder<'a, ($($type,)+ T,), ($($storage_type,)+ ViewMut<'a, T>,)> {
                 let storage = ViewMut::try_from_non_send(self.all_storages.clone()).unwrap();
 
@@ -305,6 +311,7 @@ macro_rules! impl_entity_builder {
             /// Borrows the storage associated with it panics if its already borrowed.
             #[cfg(all(feature = "non_sync", feature = "panic"))]
             #[cfg_attr(docsrs, doc(cfg(all(feature = "non_sync", feature = "panic"))))]
+            #[track_caller]
             pub fn with_non_sync<T: 'static + Send>(self, component: T) -> EntityBuilder<'a, ($($type,)+ T,), ($($storage_type,)+ ViewMut<'a, T>,)> {
                 let storage = ViewMut::try_from_non_sync(self.all_storages.clone()).unwrap();
 
@@ -342,6 +349,7 @@ macro_rules! impl_entity_builder {
             /// Borrows the storage associated with it, panics if its already borrowed.
             #[cfg(all(feature = "non_send", feature = "non_sync", feature = "panic"))]
             #[cfg_attr(docsrs, doc(cfg(all(feature = "non_send", feature = "non_sync", feature = "panic"))))]
+            #[track_caller]
             pub fn with_non_send_sync<T: 'static>(self, component: T) -> EntityBuilder<'a, ($($type,)+ T,), ($($storage_type,)+ ViewMut<'a, T>,)> {
                 let storage = ViewMut::try_from_non_send_sync(self.all_storages.clone()).unwrap();
 
@@ -367,6 +375,7 @@ macro_rules! impl_entity_builder {
             /// Borrows the `Entities` storage, panics if its already borrowed.
             #[cfg(feature = "panic")]
             #[cfg_attr(docsrs, doc(cfg(feature = "panic")))]
+            #[track_caller]
             pub fn build(self) -> EntityId where ($($storage_type,)+): ViewAddEntity<Component = ($($type,)+)> {
                 self.try_build().unwrap()
             }
@@ -390,6 +399,7 @@ macro_rules! entity_builder {
 
             #[cfg(feature = "panic")]
             #[cfg_attr(docsrs, doc(cfg(feature = "panic")))]
+            #[track_caller]
             pub fn build(self) -> EntityId where ($($storage_type,)+): ViewAddEntity<Component = ($($type,)+)> {
                 self.try_build().unwrap()
             }
@@ -397,4 +407,7 @@ macro_rules! entity_builder {
     }
 }
 
+#[cfg(not(feature = "extended_tuple"))]
 entity_builder![(A, AS, 0); (B, BS, 1) (C, CS, 2) (D, DS, 3) (E, ES, 4) (F, FS, 5) (G, GS, 6) (H, HS, 7) (I, IS, 8) (J, JS, 9)];
+#[cfg(feature = "extended_tuple")]
+entity_builder![(A, AS, 0); (B, BS, 1) (C, CS, 2) (D, DS, 3) (E, ES, 4) (F, FS, 5) (G, GS, 6) (H, HS, 7) (I, IS, 8) (J, JS, 9) (K, KS, 10) (L, LS, 11) (M, MS, 12) (N, NS, 13) (O, OS, 14) (P, PS, 15)];
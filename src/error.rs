@@ -13,23 +13,86 @@ use std::error::Error;
 /// WrongThread is linked to !Send, when trying to access them from an other thread.
 ///
 /// MultipleThreads is when !Send types are accessed from multiple threads.
+///
+/// Unique and Shared keep who held the storage when the borrow failed, see [BorrowOwner].
+///
+/// [BorrowOwner]: struct.BorrowOwner.html
 #[derive(Clone, Copy, PartialEq, Eq)]
 pub enum Borrow {
-    Unique,
-    Shared,
+    Unique(BorrowOwner),
+    Shared(BorrowOwner),
     WrongThread,
     MultipleThreads,
 }
 
+impl Borrow {
+    /// Writes who held the storage when the borrow failed, if known.
+    fn write_owner(&self, fmt: &mut Formatter<'_>, name: &str) -> Result<(), core::fmt::Error> {
+        match self {
+            Self::Unique(owner) | Self::Shared(owner) => owner.write(fmt, name),
+            Self::WrongThread | Self::MultipleThreads => Ok(()),
+        }
+    }
+}
+
+/// System and thread holding a storage when a borrow failed, captured when the failure happened.  
+/// Only known with the `borrow_owner` feature, it isn't taken into account when comparing errors.
+#[derive(Clone, Copy, Default)]
+pub struct BorrowOwner(
+    #[cfg(feature = "borrow_owner")] pub(crate) Option<crate::borrow_owner::Owner>,
+);
+
+impl BorrowOwner {
+    fn write(&self, fmt: &mut Formatter<'_>, name: &str) -> Result<(), core::fmt::Error> {
+        #[cfg(feature = "borrow_owner")]
+        {
+            if let Some(owner) = &self.0 {
+                return fmt.write_fmt(format_args!(" {} is {}.", name, owner));
+            }
+        }
+        #[cfg(not(feature = "borrow_owner"))]
+        {
+            let _ = (fmt, name);
+        }
+
+        Ok(())
+    }
+}
+
+impl PartialEq for BorrowOwner {
+    fn eq(&self, _: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for BorrowOwner {}
+
+impl Debug for BorrowOwner {
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
+        #[cfg(feature = "borrow_owner")]
+        {
+            if let Some(owner) = &self.0 {
+                return Display::fmt(owner, fmt);
+            }
+        }
+
+        fmt.write_str("unknown")
+    }
+}
+
 #[cfg(feature = "std")]
 impl Error for Borrow {}
 
 impl Debug for Borrow {
     fn fmt(&self, fmt: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
         match self {
-            Self::Unique => fmt.write_str("Cannot mutably borrow while already borrowed."),
-            Self::Shared => {
-                fmt.write_str("Cannot immutably borrow while already mutably borrowed.")
+            Self::Unique(owner) => {
+                fmt.write_str("Cannot mutably borrow while already borrowed.")?;
+                owner.write(fmt, "It")
+            }
+            Self::Shared(owner) => {
+                fmt.write_str("Cannot immutably borrow while already mutably borrowed.")?;
+                owner.write(fmt, "It")
             }
             Self::WrongThread => {
                 fmt.write_str("Can't access from another thread because it's !Send and !Sync.")
@@ -53,7 +116,10 @@ pub enum GetStorage {
     AllStoragesBorrow(Borrow),
     StorageBorrow((&'static str, Borrow)),
     MissingUnique(&'static str),
+    MissingStorage(&'static str),
     Entities(Borrow),
+    MissingRng,
+    CreationInBatch(&'static str),
 }
 
 #[cfg(feature = "std")]
@@ -63,28 +129,38 @@ impl Debug for GetStorage {
     fn fmt(&self, fmt: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
         match self {
             Self::AllStoragesBorrow(borrow) => match borrow {
-                Borrow::Unique => fmt.write_str("Cannot mutably borrow AllStorages while it's already borrowed (AllStorages is borrowed to access any storage)."),
-                Borrow::Shared => {
+                Borrow::Unique(_) => fmt.write_str("Cannot mutably borrow AllStorages while it's already borrowed (AllStorages is borrowed to access any storage)."),
+                Borrow::Shared(_) => {
                     fmt.write_str("Cannot immutably borrow AllStorages while it's already mutably borrowed.")
                 },
                 _ => unreachable!(),
             },
             Self::StorageBorrow((name, borrow)) => match borrow {
-                Borrow::Unique => fmt.write_fmt(format_args!("Cannot mutably borrow {} storage while it's already borrowed.", name)),
-                Borrow::Shared => {
+                Borrow::Unique(_) => fmt.write_fmt(format_args!("Cannot mutably borrow {} storage while it's already borrowed.", name)),
+                Borrow::Shared(_) => {
                     fmt.write_fmt(format_args!("Cannot immutably borrow {} storage while it's already mutably borrowed.", name))
                 },
                 Borrow::MultipleThreads => fmt.write_fmt(format_args!("Cannot borrow {} storage from multiple thread at the same time because it's !Sync.", name)),
                 Borrow::WrongThread => fmt.write_fmt(format_args!("Cannot borrow {} storage from other thread than the one it was created in because it's !Send and !Sync.", name)),
             },
             Self::MissingUnique(name) => fmt.write_fmt(format_args!("No unique storage exists for {}.\nYou can register it with: world.add_unique(/* your_unique */);", name)),
+            Self::MissingStorage(name) => fmt.write_fmt(format_args!("No storage exists for {0} and the World is strict.\nYou can register it with: world.register_storage::<{0}>();", name)),
             Self::Entities(borrow) => match borrow {
-                Borrow::Unique => fmt.write_str("Cannot mutably borrow Entities storage while it's already borrowed."),
-                Borrow::Shared => {
+                Borrow::Unique(_) => fmt.write_str("Cannot mutably borrow Entities storage while it's already borrowed."),
+                Borrow::Shared(_) => {
                     fmt.write_str("Cannot immutably borrow Entities storage while it's already mutably borrowed.")
                 },
                 _ => unreachable!(),
             },
+            Self::MissingRng => fmt.write_str("Rng can only be borrowed by a system running in a workload, once at a time."),
+            Self::CreationInBatch(name) => fmt.write_fmt(format_args!("Cannot create {0} storage while systems run in parallel.\nYou can register it before running the workload with: world.register_storage::<{0}>();", name)),
+        }?;
+
+        match self {
+            Self::AllStoragesBorrow(borrow) => borrow.write_owner(fmt, "AllStorages"),
+            Self::StorageBorrow((name, borrow)) => borrow.write_owner(fmt, name),
+            Self::Entities(borrow) => borrow.write_owner(fmt, "Entities"),
+            _ => Ok(()),
         }
     }
 }
@@ -113,14 +189,14 @@ impl Debug for NewEntity {
     fn fmt(&self, fmt: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
         match self {
             Self::AllStoragesBorrow(borrow) => match borrow {
-                Borrow::Unique => fmt.write_str("Cannot mutably borrow all storages while it's already borrowed (this include component storage)."),
-                Borrow::Shared => {
+                Borrow::Unique(_) => fmt.write_str("Cannot mutably borrow all storages while it's already borrowed (this include component storage)."),
+                Borrow::Shared(_) => {
                     fmt.write_str("Cannot immutably borrow all storages while it's already mutably borrowed.")
                 },
                 _ => unreachable!(),
             },
             Self::Entities(borrow) => match borrow {
-                Borrow::Unique => fmt.write_str("Cannot mutably borrow entities while it's already borrowed."),
+                Borrow::Unique(_) => fmt.write_str("Cannot mutably borrow entities while it's already borrowed."),
                 _ => unreachable!(),
             },
         }
@@ -139,6 +215,14 @@ impl Display for NewEntity {
 pub enum AddComponent {
     MissingPackStorage(&'static str),
     EntityIsNotAlive,
+    Entities(Borrow),
+    /// Batch insertion got a different number of entities and components.
+    LengthMismatch {
+        entities: usize,
+        components: usize,
+    },
+    /// The storage has a fixed capacity and can't hold the new components.
+    StorageFull(&'static str),
 }
 
 #[cfg(feature = "std")]
@@ -149,6 +233,12 @@ impl Debug for AddComponent {
         match self {
             Self::MissingPackStorage(type_id) => fmt.write_fmt(format_args!("Missing {} storage, to add a packed component you have to pass all storages packed with it. Even if you just add one component.", type_id)),
             Self::EntityIsNotAlive => fmt.write_str("Entity has to be alive to add component to it."),
+            Self::Entities(borrow) => match borrow {
+                Borrow::Shared(_) => fmt.write_str("Cannot immutably borrow Entities storage while it's already mutably borrowed."),
+                _ => unreachable!(),
+            },
+            Self::LengthMismatch { entities, components } => fmt.write_fmt(format_args!("{} entities were given {} components, both have to be the same length.", entities, components)),
+            Self::StorageFull(name) => fmt.write_fmt(format_args!("{} storage doesn't have enough room left in its fixed capacity.", name)),
         }
     }
 }
@@ -159,6 +249,37 @@ impl Display for AddComponent {
     }
 }
 
+/// Error occuring when tagging an entity.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum AddTag {
+    GetStorage(GetStorage),
+    EntityIsNotAlive,
+}
+
+#[cfg(feature = "std")]
+impl Error for AddTag {}
+
+impl From<GetStorage> for AddTag {
+    fn from(get_storage: GetStorage) -> Self {
+        AddTag::GetStorage(get_storage)
+    }
+}
+
+impl Debug for AddTag {
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
+        match self {
+            Self::GetStorage(get_storage) => Debug::fmt(get_storage, fmt),
+            Self::EntityIsNotAlive => fmt.write_str("Entity has to be alive to add a tag to it."),
+        }
+    }
+}
+
+impl Display for AddTag {
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
+        Debug::fmt(self, fmt)
+    }
+}
+
 /// Error occuring when a pack can't be made.  
 /// It could be a borrow issue or one of the storage could already have
 /// an incompatible pack or the storage could be unique.
@@ -284,6 +405,33 @@ impl Display for SetDefaultWorkload {
     }
 }
 
+/// Trying to enable or disable a non existant workload will result in this error.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum SetWorkloadEnabled {
+    Borrow,
+    MissingWorkload,
+}
+
+#[cfg(feature = "std")]
+impl Error for SetWorkloadEnabled {}
+
+impl Debug for SetWorkloadEnabled {
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
+        match self {
+            Self::Borrow => {
+                fmt.write_str("Cannot mutably borrow scheduler while it's already borrowed.")
+            }
+            Self::MissingWorkload => fmt.write_str("No workload with this name exists."),
+        }
+    }
+}
+
+impl Display for SetWorkloadEnabled {
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
+        Debug::fmt(self, fmt)
+    }
+}
+
 /// Error related to `run_default` and `run_workload`.  
 /// The error can be a storage error, problem with the scheduler's borrowing, a non existant workload or a custom error.
 pub enum RunWorkload {
@@ -294,7 +442,7 @@ pub enum RunWorkload {
 
 impl RunWorkload {
     #[cfg(feature = "std")]
-    pub fn custom_error(self) -> Option<Box<dyn Error + Send>> {
+    pub fn custom_error(self) -> Option<Box<dyn Error + Send + Sync>> {
         match self {
             Self::Run((_, Run::Custom(error))) => Some(error),
             _ => None,
@@ -334,10 +482,13 @@ impl Display for RunWorkload {
 
 /// Error returned by `World::try_run` and `AllStorages::try_run`.  
 /// Can refer to an invalid storage borrow or a custom error.
+///
+/// With the std feature custom errors are `Error + Send + Sync`, errors that aren't `Sync` can't be used.
 pub enum Run {
     GetStorage(GetStorage),
+    Reentrant(&'static str),
     #[cfg(feature = "std")]
-    Custom(Box<dyn Error + Send>),
+    Custom(Box<dyn Error + Send + Sync>),
     #[cfg(not(feature = "std"))]
     Custom(Box<dyn core::any::Any + Send>),
 }
@@ -349,9 +500,10 @@ impl From<GetStorage> for Run {
 }
 
 impl Run {
+    /// Wraps a system's error, it can be any `Error + Send + Sync` type, a `String` or a `&str`.
     #[cfg(feature = "std")]
-    pub fn from_custom<E: Error + Send + 'static>(error: E) -> Self {
-        Run::Custom(Box::new(error))
+    pub fn from_custom<E: Into<Box<dyn Error + Send + Sync>>>(error: E) -> Self {
+        Run::Custom(error.into())
     }
     #[cfg(not(feature = "std"))]
     pub fn from_custom<E: core::any::Any + Send>(error: E) -> Self {
@@ -366,6 +518,7 @@ impl Debug for Run {
     fn fmt(&self, fmt: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
         match self {
             Self::GetStorage(get_storage) => Debug::fmt(&get_storage, fmt),
+            Self::Reentrant(system) => fmt.write_fmt(format_args!("System {} can't run a workload or borrow storages the running systems conflict with while it's running.\nYou can split it into multiple systems instead.", system)),
             Self::Custom(_) => fmt.write_fmt(format_args!("run failed with a custom error.")),
         }
     }
@@ -421,6 +574,25 @@ impl Display for NotUpdatePack {
     }
 }
 
+/// Error when trying to access the tightly packed components of a storage that isn't tightly packed.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct NotTightPack;
+
+#[cfg(feature = "std")]
+impl Error for NotTightPack {}
+
+impl Debug for NotTightPack {
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
+        fmt.write_str("The storage isn't tightly packed. Use `(&mut view1, &mut view2).tight_pack()` to pack it.")
+    }
+}
+
+impl Display for NotTightPack {
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
+        Debug::fmt(self, fmt)
+    }
+}
+
 /// Error when trying to access the *inserted* section of an update packed storage but the storage isn't update packed or the section isn't present in the window.
 #[derive(Clone, Copy, PartialEq, Eq)]
 pub enum UpdateWindow {
@@ -505,6 +677,163 @@ impl Display for NotInbound {
     }
 }
 
+/// Error returned by `prepare_workload`.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum PrepareWorkload {
+    Scheduler,
+    AllStoragesBorrow(Borrow),
+    MissingWorkload,
+}
+
+#[cfg(feature = "std")]
+impl Error for PrepareWorkload {}
+
+impl Debug for PrepareWorkload {
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
+        match self {
+            Self::Scheduler => {
+                fmt.write_str("Cannot borrow the scheduler while it's already mutably borrowed.")
+            }
+            Self::AllStoragesBorrow(borrow) => match borrow {
+                Borrow::Shared(_) => fmt.write_str(
+                    "Cannot immutably borrow AllStorages while it's already mutably borrowed.",
+                ),
+                _ => unreachable!(),
+            },
+            Self::MissingWorkload => fmt.write_str("No workload with this name exists."),
+        }
+    }
+}
+
+impl Display for PrepareWorkload {
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
+        Debug::fmt(self, fmt)
+    }
+}
+
+/// Error returned by `workload_dot`.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum WorkloadDot {
+    Scheduler,
+    AllStoragesBorrow(Borrow),
+    MissingWorkload,
+}
+
+#[cfg(feature = "std")]
+impl Error for WorkloadDot {}
+
+impl Debug for WorkloadDot {
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
+        match self {
+            Self::Scheduler => {
+                fmt.write_str("Cannot borrow the scheduler while it's already mutably borrowed.")
+            }
+            Self::AllStoragesBorrow(borrow) => match borrow {
+                Borrow::Unique(_) => fmt.write_str("Cannot mutably borrow AllStorages while it's already borrowed (AllStorages is borrowed to access Entities and Uniques)."),
+                _ => unreachable!(),
+            },
+            Self::MissingWorkload => fmt.write_str("No workload with this name exists."),
+        }
+    }
+}
+
+impl Display for WorkloadDot {
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
+        Debug::fmt(self, fmt)
+    }
+}
+
+/// Error returned by `write_trace`.
+#[cfg(feature = "trace")]
+#[cfg_attr(docsrs, doc(cfg(feature = "trace")))]
+pub enum WriteTrace {
+    Scheduler,
+    Io(std::io::Error),
+}
+
+#[cfg(feature = "trace")]
+impl Error for WriteTrace {}
+
+#[cfg(feature = "trace")]
+impl Debug for WriteTrace {
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
+        match self {
+            Self::Scheduler => {
+                fmt.write_str("Cannot borrow the scheduler while it's already mutably borrowed.")
+            }
+            Self::Io(err) => fmt.write_fmt(format_args!("Writing the trace failed: {}", err)),
+        }
+    }
+}
+
+#[cfg(feature = "trace")]
+impl Display for WriteTrace {
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
+        Debug::fmt(self, fmt)
+    }
+}
+
+/// Error returned by `World::try_save_to` and `World::try_load_from`.
+#[cfg(feature = "savegame")]
+#[cfg_attr(docsrs, doc(cfg(feature = "savegame")))]
+pub enum Savegame {
+    Io(std::io::Error),
+    Bincode(bincode::Error),
+    Compression(lz4_flex::frame::Error),
+}
+
+#[cfg(feature = "savegame")]
+impl Error for Savegame {}
+
+#[cfg(feature = "savegame")]
+impl Debug for Savegame {
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
+        match self {
+            Self::Io(err) => fmt.write_fmt(format_args!("Accessing the file failed: {}", err)),
+            Self::Bincode(err) => {
+                fmt.write_fmt(format_args!("(De)serializing the World failed: {}", err))
+            }
+            Self::Compression(err) => {
+                fmt.write_fmt(format_args!("Compressing the World failed: {}", err))
+            }
+        }
+    }
+}
+
+#[cfg(feature = "savegame")]
+impl Display for Savegame {
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
+        Debug::fmt(self, fmt)
+    }
+}
+
+/// Error returned by `World::try_new` and the other fallible `World` constructors.
+pub enum NewWorld {
+    #[cfg(feature = "parallel")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "parallel")))]
+    ThreadPool(rayon::ThreadPoolBuildError),
+}
+
+#[cfg(feature = "std")]
+impl Error for NewWorld {}
+
+impl Debug for NewWorld {
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
+        match *self {
+            #[cfg(feature = "parallel")]
+            Self::ThreadPool(ref err) => {
+                fmt.write_fmt(format_args!("Creating the thread pool failed: {}", err))
+            }
+        }
+    }
+}
+
+impl Display for NewWorld {
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
+        Debug::fmt(self, fmt)
+    }
+}
+
 /// Trying to add an invalid system to a workload will return this error.
 #[derive(Clone, Copy, PartialEq, Eq)]
 pub enum InvalidSystem {
@@ -548,7 +877,7 @@ impl Debug for UniqueRemove {
             Self::AllStorages => fmt.write_str("Cannot borrow AllStorages while it's already exclusively borrowed."),
             Self::MissingUnique(name) => fmt.write_fmt(format_args!("No unique storage exists for {}.\n", name)),
             Self::StorageBorrow((name, borrow)) => match borrow {
-                Borrow::Unique => fmt.write_fmt(format_args!("Cannot mutably borrow {} storage while it's already borrowed.", name)),
+                Borrow::Unique(_) => fmt.write_fmt(format_args!("Cannot mutably borrow {} storage while it's already borrowed.", name)),
                 Borrow::WrongThread => fmt.write_fmt(format_args!("Cannot borrow {} storage from other thread than the one it was created in because it's !Send and !Sync.", name)),
                 _ => unreachable!()
             }
@@ -624,3 +953,131 @@ impl Display for Apply {
         Debug::fmt(self, f)
     }
 }
+
+/// Error returned when resolving a `GlobalEntityId`.
+///
+/// WrongWorld means the entity belongs to another `World`.
+///
+/// DeadEntity means the entity isn't alive anymore.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum Resolve {
+    WrongWorld,
+    AllStoragesBorrow(Borrow),
+    Entities(Borrow),
+    DeadEntity,
+}
+
+#[cfg(feature = "std")]
+impl Error for Resolve {}
+
+impl Debug for Resolve {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
+        match self {
+            Self::WrongWorld => f.write_str("The entity belongs to another World."),
+            Self::AllStoragesBorrow(borrow) => match borrow {
+                Borrow::Shared(_) => f.write_str(
+                    "Cannot immutably borrow all storages while it's already mutably borrowed.",
+                ),
+                _ => unreachable!(),
+            },
+            Self::Entities(borrow) => match borrow {
+                Borrow::Shared(_) => f.write_str(
+                    "Cannot immutably borrow entities while it's already mutably borrowed.",
+                ),
+                _ => unreachable!(),
+            },
+            Self::DeadEntity => f.write_str("The entity isn't alive."),
+        }
+    }
+}
+
+impl Display for Resolve {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
+        Debug::fmt(self, f)
+    }
+}
+
+/// Error returned when applying an `Operation`.
+///
+/// GetStorage means `Entities` or the component storage couldn't be borrowed.
+///
+/// AddComponent and Remove mean the component storage is packed.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum ApplyOperation {
+    GetStorage(GetStorage),
+    AddComponent(AddComponent),
+    Remove(Remove),
+}
+
+#[cfg(feature = "std")]
+impl Error for ApplyOperation {}
+
+impl From<GetStorage> for ApplyOperation {
+    fn from(get_storage: GetStorage) -> Self {
+        ApplyOperation::GetStorage(get_storage)
+    }
+}
+
+impl From<AddComponent> for ApplyOperation {
+    fn from(add_component: AddComponent) -> Self {
+        ApplyOperation::AddComponent(add_component)
+    }
+}
+
+impl From<Remove> for ApplyOperation {
+    fn from(remove: Remove) -> Self {
+        ApplyOperation::Remove(remove)
+    }
+}
+
+impl Debug for ApplyOperation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
+        match self {
+            Self::GetStorage(get_storage) => Debug::fmt(get_storage, f),
+            Self::AddComponent(add_component) => Debug::fmt(add_component, f),
+            Self::Remove(remove) => Debug::fmt(remove, f),
+        }
+    }
+}
+
+impl Display for ApplyOperation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
+        Debug::fmt(self, f)
+    }
+}
+
+/// Error returned by `World::try_set_field` and `World::try_field`.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum Reflect {
+    AllStoragesBorrow(Borrow),
+    /// No storage set up with `SparseSet::setup_reflect` is named like the first part of the path.
+    MissingStorage,
+    MissingComponent(EntityId),
+    MissingField,
+    /// The field doesn't have the requested type.
+    WrongType(&'static str),
+}
+
+#[cfg(feature = "std")]
+impl Error for Reflect {}
+
+impl Debug for Reflect {
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
+        match self {
+            Self::AllStoragesBorrow(borrow) => match borrow {
+                Borrow::Unique(_) => fmt.write_str("Cannot mutably borrow AllStorages while it's already borrowed (AllStorages is borrowed to access any storage)."),
+                _ => unreachable!(),
+            },
+            Self::MissingStorage => fmt.write_str("No storage set up with SparseSet::setup_reflect matches the path."),
+            Self::MissingComponent(id) => fmt.write_fmt(format_args!("Entity {:?} does not have any component in this storage.", id)),
+            Self::MissingField => fmt.write_str("The component does not have a field at this path."),
+            Self::WrongType(name) => fmt.write_fmt(format_args!("The field is not a {}.", name)),
+        }
+    }
+}
+
+impl Display for Reflect {
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
+        Debug::fmt(self, fmt)
+    }
+}
@@ -0,0 +1,22 @@
+use crate::storage::{EntityId, StorageId};
+use core::sync::atomic::{AtomicUsize as StaticAtomicUsize, Ordering as StaticOrdering};
+
+/// Orders events logged by different storages, even when they're modified in parallel.
+static NEXT_INDEX: StaticAtomicUsize = StaticAtomicUsize::new(0);
+
+pub(crate) fn next_index() -> usize {
+    NEXT_INDEX.fetch_add(1, StaticOrdering::Relaxed)
+}
+
+/// Structural change logged once [World::enable_event_log] is called, returned by [World::drain_events].
+///
+/// [World::drain_events]: struct.World.html#method.drain_events
+/// [World::enable_event_log]: struct.World.html#method.enable_event_log
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum StructuralEvent {
+    EntityCreated(EntityId),
+    EntityDeleted(EntityId),
+    /// `entity` didn't have a component in the storage, replacing a component isn't logged.
+    ComponentAdded(EntityId, StorageId),
+    ComponentRemoved(EntityId, StorageId),
+}
@@ -0,0 +1,210 @@
+use alloc::boxed::Box;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::mem::{align_of, size_of, size_of_val, MaybeUninit};
+use core::ptr::{self, NonNull};
+use parking_lot::Mutex;
+
+/// Size of the first chunk when none is specified.
+const DEFAULT_CAPACITY: usize = 4096;
+
+/// Bump allocator for scratch data, meant to be used as a unique storage.
+/// Systems can allocate from it with a [UniqueView], allocations live until the end of the workload.
+/// When a workload ends or `World::maintain` is called, the `World` resets it, keeping its largest chunk to avoid allocating next time.
+/// It isn't reset at the end of a workload if it's still borrowed at that point.
+///
+/// Only `Copy` types can be allocated since destructors are never run.
+///
+/// ### Example
+/// ```
+/// use shipyard::{system, FrameAlloc, UniqueView, World};
+///
+/// fn sum(frame_alloc: UniqueView<FrameAlloc>) {
+///     let squares = frame_alloc.alloc_slice_fill(10, 0u32);
+///     for (i, square) in squares.iter_mut().enumerate() {
+///         *square = (i * i) as u32;
+///     }
+///
+///     assert_eq!(squares.iter().sum::<u32>(), 285);
+/// }
+///
+/// let world = World::new();
+/// world.add_unique(FrameAlloc::new());
+///
+/// world.add_workload("Sum").with_system(system!(sum)).build();
+/// world.run_default();
+///
+/// world.run(|frame_alloc: UniqueView<FrameAlloc>| assert_eq!(frame_alloc.allocated_bytes(), 0));
+/// ```
+///
+/// [UniqueView]: struct.UniqueView.html
+pub struct FrameAlloc {
+    inner: Mutex<Chunks>,
+}
+
+struct Chunks {
+    // chunks are heap allocated so allocations don't move when the Vec reallocates
+    chunks: Vec<Chunk>,
+    // offset in the last chunk
+    offset: usize,
+    // bytes allocated in all but the last chunk
+    previous_chunks: usize,
+}
+
+/// Chunk of memory owned through a raw pointer.
+/// All allocations are derived from `ptr`, the chunk is never reborrowed while they're alive.
+struct Chunk {
+    ptr: NonNull<u8>,
+    len: usize,
+}
+
+// SAFE Chunk owns its memory like a Box<[MaybeUninit<u8>]> would
+unsafe impl Send for Chunk {}
+
+impl Chunk {
+    fn new(capacity: usize) -> Self {
+        let mut chunk = Vec::<MaybeUninit<u8>>::with_capacity(capacity);
+        // SAFE MaybeUninit doesn't require initialization
+        unsafe { chunk.set_len(capacity) };
+        let chunk = Box::into_raw(chunk.into_boxed_slice());
+
+        Chunk {
+            // SAFE Box::into_raw never returns a null pointer
+            ptr: unsafe { NonNull::new_unchecked(chunk as *mut u8) },
+            len: capacity,
+        }
+    }
+}
+
+impl Drop for Chunk {
+    fn drop(&mut self) {
+        // SAFE ptr and len come from Box::into_raw in Chunk::new
+        unsafe {
+            drop(Box::from_raw(ptr::slice_from_raw_parts_mut(
+                self.ptr.as_ptr() as *mut MaybeUninit<u8>,
+                self.len,
+            )));
+        }
+    }
+}
+
+impl Default for FrameAlloc {
+    fn default() -> Self {
+        FrameAlloc::with_capacity(DEFAULT_CAPACITY)
+    }
+}
+
+impl FrameAlloc {
+    /// Creates a new `FrameAlloc` with a first chunk of 4KiB.
+    pub fn new() -> Self {
+        Default::default()
+    }
+    /// Creates a new `FrameAlloc` with a first chunk of `capacity` bytes.
+    pub fn with_capacity(capacity: usize) -> Self {
+        FrameAlloc {
+            inner: Mutex::new(Chunks {
+                chunks: vec![Chunk::new(capacity)],
+                offset: 0,
+                previous_chunks: 0,
+            }),
+        }
+    }
+    /// Moves `value` in the allocator and returns a reference to it.
+    #[allow(clippy::mut_from_ref)]
+    pub fn alloc<T: Copy>(&self, value: T) -> &mut T {
+        let ptr = self.alloc_raw(size_of::<T>(), align_of::<T>()) as *mut T;
+
+        // SAFE ptr is aligned, large enough and not shared with another allocation
+        // it'll stay valid until reset which requires an exclusive access
+        unsafe {
+            ptr.write(value);
+            &mut *ptr
+        }
+    }
+    /// Copies `slice` in the allocator and returns a reference to the copy.
+    #[allow(clippy::mut_from_ref)]
+    pub fn alloc_slice_copy<T: Copy>(&self, slice: &[T]) -> &mut [T] {
+        let ptr = self.alloc_raw(size_of_val(slice), align_of::<T>()) as *mut T;
+
+        // SAFE same as alloc
+        unsafe {
+            ptr.copy_from_nonoverlapping(slice.as_ptr(), slice.len());
+            core::slice::from_raw_parts_mut(ptr, slice.len())
+        }
+    }
+    /// Allocates a slice of `len` elements, all set to `value`.
+    ///
+    /// ### Panics
+    ///
+    /// - The size of the slice overflows `usize`.
+    #[allow(clippy::mut_from_ref)]
+    pub fn alloc_slice_fill<T: Copy>(&self, len: usize, value: T) -> &mut [T] {
+        let size = size_of::<T>()
+            .checked_mul(len)
+            .expect("FrameAlloc allocation size overflow");
+        let ptr = self.alloc_raw(size, align_of::<T>()) as *mut T;
+
+        // SAFE same as alloc
+        unsafe {
+            for i in 0..len {
+                ptr.add(i).write(value);
+            }
+            core::slice::from_raw_parts_mut(ptr, len)
+        }
+    }
+    /// Returns the number of bytes allocated since the last reset, padding included.
+    pub fn allocated_bytes(&self) -> usize {
+        let inner = self.inner.lock();
+
+        inner.previous_chunks + inner.offset
+    }
+    /// Frees all allocations, only the largest chunk is kept.
+    pub fn reset(&mut self) {
+        let inner = self.inner.get_mut();
+
+        // chunks are allocated in increasing size
+        let last = inner.chunks.pop().unwrap();
+        inner.chunks.clear();
+        inner.chunks.push(last);
+        inner.offset = 0;
+        inner.previous_chunks = 0;
+    }
+    fn alloc_raw(&self, size: usize, align: usize) -> *mut u8 {
+        let mut inner = self.inner.lock();
+        let inner = &mut *inner;
+
+        let chunk = inner.chunks.last().unwrap();
+        let base = chunk.ptr.as_ptr() as usize;
+        let start = align_up(base + inner.offset, align) - base;
+        let end = start
+            .checked_add(size)
+            .expect("FrameAlloc allocation size overflow");
+
+        if end <= chunk.len {
+            inner.offset = end;
+
+            // SAFE start is within the chunk
+            return unsafe { chunk.ptr.as_ptr().add(start) };
+        }
+
+        // the new chunk is large enough for the allocation whatever its alignment
+        let capacity = (chunk.len * 2).max(
+            size.checked_add(align)
+                .expect("FrameAlloc allocation size overflow"),
+        );
+        inner.previous_chunks += inner.offset;
+        inner.chunks.push(Chunk::new(capacity));
+
+        let chunk = inner.chunks.last().unwrap();
+        let base = chunk.ptr.as_ptr() as usize;
+        let start = align_up(base, align) - base;
+        inner.offset = start + size;
+
+        // SAFE start is within the chunk
+        unsafe { chunk.ptr.as_ptr().add(start) }
+    }
+}
+
+fn align_up(address: usize, align: usize) -> usize {
+    (address + align - 1) & !(align - 1)
+}
@@ -108,4 +108,7 @@ macro_rules! get_component {
     }
 }
 
+#[cfg(not(feature = "extended_tuple"))]
 get_component![(A, 0); (B, 1) (C, 2) (D, 3) (E, 4) (F, 5) (G, 6) (H, 7) (I, 8) (J, 9)];
+#[cfg(feature = "extended_tuple")]
+get_component![(A, 0); (B, 1) (C, 2) (D, 3) (E, 4) (F, 5) (G, 6) (H, 7) (I, 8) (J, 9) (K, 10) (L, 11) (M, 12) (N, 13) (O, 14) (P, 15)];
@@ -0,0 +1,130 @@
+use crate::storage::EntityId;
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+use core::any::Any;
+use core::hash::Hash;
+use hashbrown::HashMap;
+
+/// Entities of a storage grouped by a key derived from their component.
+/// Created with [SparseSet::setup_index] and accessed with [SparseSet::index].
+///
+/// Only owned components are indexed, entities sharing a component aren't part of the index.
+///
+/// [SparseSet::setup_index]: struct.SparseSet.html#method.setup_index
+/// [SparseSet::index]: struct.SparseSet.html#method.index
+pub struct Index<K> {
+    entities: HashMap<K, Vec<EntityId>>,
+    // key each entity is indexed under, its component might have been modified since
+    keys: HashMap<EntityId, K>,
+}
+
+impl<K: Hash + Eq> Index<K> {
+    fn new() -> Self {
+        Index {
+            entities: HashMap::new(),
+            keys: HashMap::new(),
+        }
+    }
+    /// Returns the entities whose component has `key`, in no particular order.
+    pub fn get(&self, key: &K) -> &[EntityId] {
+        self.entities.get(key).map(Vec::as_slice).unwrap_or(&[])
+    }
+    /// Returns `true` if at least one entity's component has `key`.
+    pub fn contains_key(&self, key: &K) -> bool {
+        self.entities.contains_key(key)
+    }
+    /// Returns the key `entity` is indexed under.
+    pub fn key_of(&self, entity: EntityId) -> Option<&K> {
+        self.keys.get(&entity)
+    }
+    /// Returns all keys present in the index.
+    pub fn keys(&self) -> impl Iterator<Item = &K> {
+        self.entities.keys()
+    }
+    /// Returns the number of different keys.
+    pub fn len(&self) -> usize {
+        self.entities.len()
+    }
+    /// Returns `true` if no entity is indexed.
+    pub fn is_empty(&self) -> bool {
+        self.entities.is_empty()
+    }
+    fn insert(&mut self, entity: EntityId, key: K)
+    where
+        K: Clone,
+    {
+        self.remove(entity);
+
+        self.entities.entry(key.clone()).or_default().push(entity);
+        self.keys.insert(entity, key);
+    }
+    fn remove(&mut self, entity: EntityId) {
+        if let Some(key) = self.keys.remove(&entity) {
+            if let Some(entities) = self.entities.get_mut(&key) {
+                if let Some(position) = entities.iter().position(|&id| id == entity) {
+                    entities.swap_remove(position);
+                }
+
+                if entities.is_empty() {
+                    self.entities.remove(&key);
+                }
+            }
+        }
+    }
+    fn clear(&mut self) {
+        self.entities.clear();
+        self.keys.clear();
+    }
+}
+
+/// Abstracts the key type away from the storage.
+pub(crate) trait UnknownIndex<T>: Send + Sync {
+    fn insert(&mut self, entity: EntityId, component: &T);
+    fn remove(&mut self, entity: EntityId);
+    fn clear(&mut self);
+    /// Indexes all components again.
+    fn rebuild(&mut self, dense: &[EntityId], data: &[T]) {
+        self.clear();
+
+        for (&entity, component) in dense.iter().zip(data) {
+            self.insert(entity, component);
+        }
+    }
+    /// Returns an empty index with the same key.
+    fn empty(&self) -> Box<dyn UnknownIndex<T>>;
+    fn as_any(&self) -> &dyn Any;
+}
+
+pub(crate) struct IndexInfos<T, K> {
+    pub(crate) key: fn(&T) -> K,
+    pub(crate) index: Index<K>,
+}
+
+impl<T, K: Hash + Eq> IndexInfos<T, K> {
+    pub(crate) fn new(key: fn(&T) -> K) -> Self {
+        IndexInfos {
+            key,
+            index: Index::new(),
+        }
+    }
+}
+
+impl<T: 'static, K: Hash + Eq + Clone + Send + Sync + 'static> UnknownIndex<T>
+    for IndexInfos<T, K>
+{
+    fn insert(&mut self, entity: EntityId, component: &T) {
+        self.index.insert(entity, (self.key)(component));
+    }
+    fn remove(&mut self, entity: EntityId) {
+        self.index.remove(entity);
+    }
+    fn clear(&mut self) {
+        self.index.clear();
+    }
+    fn empty(&self) -> Box<dyn UnknownIndex<T>> {
+        Box::new(IndexInfos::new(self.key))
+    }
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
@@ -4,6 +4,8 @@ mod single;
 use super::abstract_mut::AbstractMut;
 use super::into_abstract::IntoAbstract;
 use super::iterators::*;
+#[cfg(feature = "parallel")]
+use rayon::iter::ParallelIterator;
 
 /// Trait used to create iterators.
 ///
@@ -62,6 +64,61 @@ pub trait IntoIter {
     /// [run]: ../struct.World.html#method.run
     #[cfg(feature = "parallel")]
     fn par_iter(self) -> Self::IntoParIter;
+    /// Searches the storages in parallel and returns components matching `predicate`, if any.  
+    /// The search stops on all threads as soon as a match is found, it's not necessarily the first one in iteration order.
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{EntitiesViewMut, IntoIter, ViewMut, World};
+    ///
+    /// let world = World::new();
+    ///
+    /// world.run(
+    ///     |mut entities: EntitiesViewMut, mut usizes: ViewMut<usize>, mut u32s: ViewMut<u32>| {
+    ///         entities.add_entity((&mut usizes, &mut u32s), (0usize, 1u32));
+    ///         entities.add_entity((&mut usizes, &mut u32s), (2usize, 3u32));
+    ///
+    ///         assert_eq!((&usizes, &u32s).par_find(|&(&x, _)| x > 1), Some((&2, &3)));
+    ///     },
+    /// );
+    /// ```
+    #[cfg(feature = "parallel")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "parallel")))]
+    fn par_find<P>(self, predicate: P) -> Option<<Self::IntoParIter as ParallelIterator>::Item>
+    where
+        Self: Sized,
+        Self::IntoParIter: ParallelIterator,
+        P: Fn(&<Self::IntoParIter as ParallelIterator>::Item) -> bool + Sync + Send,
+    {
+        self.par_iter().find_any(predicate)
+    }
+    /// Returns `true` if any components of the storages match `predicate`, searching in parallel.  
+    /// The search stops on all threads as soon as a match is found.
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{EntitiesViewMut, IntoIter, ViewMut, World};
+    ///
+    /// let world = World::new();
+    ///
+    /// world.run(|mut entities: EntitiesViewMut, mut positions: ViewMut<f32>| {
+    ///     entities.add_entity(&mut positions, 12.0);
+    ///     entities.add_entity(&mut positions, 40.0);
+    ///
+    ///     assert!((&positions).par_any(|&x| x < 20.0));
+    ///     assert!(!(&positions).par_any(|&x| x < 10.0));
+    /// });
+    /// ```
+    #[cfg(feature = "parallel")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "parallel")))]
+    fn par_any<P>(self, predicate: P) -> bool
+    where
+        Self: Sized,
+        Self::IntoParIter: ParallelIterator,
+        P: Fn(<Self::IntoParIter as ParallelIterator>::Item) -> bool + Sync + Send,
+    {
+        self.par_iter().any(predicate)
+    }
 }
 
 /// Shorthand for a Shiperator only yielding ids.
@@ -1,4 +1,6 @@
 use super::*;
+#[cfg(all(feature = "parallel", feature = "stats"))]
+use crate::iter::Shiperator;
 use crate::sparse_set::Pack;
 use crate::EntityId;
 use core::ptr;
@@ -70,7 +72,8 @@ macro_rules! impl_iterators {
                                     }
                                 }
                             }
-                            (Pack::Update(_), _) => {
+                            // storages not tracking modification are iterated like regular storages
+                            (Pack::Update(pack), _) if pack.tracking.modification() => {
                                 pack_iter = PackIter::Update;
                                 if let Some(len) = self.$index.len() {
                                     if len < smallest {
@@ -102,6 +105,7 @@ macro_rules! impl_iterators {
                             data: ($(self.$index.into_abstract(),)+),
                             current: 0,
                             end: smallest,
+                            min_len: 1,
                         })
                     }
                     PackIter::Loose => {
@@ -127,6 +131,7 @@ macro_rules! impl_iterators {
                             end: smallest,
                             array,
                             indices: indices.unwrap(),
+                            min_len: 1,
                         })
                     }
                     PackIter::Update => {
@@ -172,13 +177,20 @@ macro_rules! impl_iterators {
                             current: 0,
                             end: smallest,
                             array: smallest_index,
+                            min_len: 1,
                         })
                     }
                 }
             }
             #[cfg(feature = "parallel")]
             fn par_iter(self) -> Self::IntoParIter {
-                match self.iter() {
+                let iter = self.iter();
+
+                // rayon's threads can't add to this system's count, count every entity the iterator might yield
+                #[cfg(feature = "stats")]
+                crate::stats::iterated(iter.size_hint().1.unwrap_or(0));
+
+                match iter {
                     $iter::Tight(tight) => $par_iter::Tight(tight.into()),
                     $iter::Loose(loose) => $par_iter::Loose(loose.into()),
                     $iter::Update(update) => $par_iter::NonPacked(update.into()),
@@ -223,6 +235,7 @@ macro_rules! iterators {
     }
 }
 
+#[cfg(not(feature = "extended_tuple"))]
 iterators![
     ;Iter2 Iter3 Iter4 Iter5 Iter6 Iter7 Iter8 Iter9 Iter10;
     ;ParIter2 ParIter3 ParIter4 ParIter5 ParIter6 ParIter7 ParIter8 ParIter9 ParIter10;
@@ -232,3 +245,13 @@ iterators![
     ;Update2 Update3 Update4 Update5 Update6 Update7 Update8 Update9 Update10;
     (A, 0) (B, 1); (C, 2) (D, 3) (E, 4) (F, 5) (G, 6) (H, 7) (I, 8) (J, 9)
 ];
+#[cfg(feature = "extended_tuple")]
+iterators![
+    ;Iter2 Iter3 Iter4 Iter5 Iter6 Iter7 Iter8 Iter9 Iter10 Iter11 Iter12 Iter13 Iter14 Iter15 Iter16;
+    ;ParIter2 ParIter3 ParIter4 ParIter5 ParIter6 ParIter7 ParIter8 ParIter9 ParIter10 ParIter11 ParIter12 ParIter13 ParIter14 ParIter15 ParIter16;
+    ;Tight2 Tight3 Tight4 Tight5 Tight6 Tight7 Tight8 Tight9 Tight10 Tight11 Tight12 Tight13 Tight14 Tight15 Tight16;
+    ;Loose2 Loose3 Loose4 Loose5 Loose6 Loose7 Loose8 Loose9 Loose10 Loose11 Loose12 Loose13 Loose14 Loose15 Loose16;
+    ;NonPacked2 NonPacked3 NonPacked4 NonPacked5 NonPacked6 NonPacked7 NonPacked8 NonPacked9 NonPacked10 NonPacked11 NonPacked12 NonPacked13 NonPacked14 NonPacked15 NonPacked16;
+    ;Update2 Update3 Update4 Update5 Update6 Update7 Update8 Update9 Update10 Update11 Update12 Update13 Update14 Update15 Update16;
+    (A, 0) (B, 1); (C, 2) (D, 3) (E, 4) (F, 5) (G, 6) (H, 7) (I, 8) (J, 9) (K, 10) (L, 11) (M, 12) (N, 13) (O, 14) (P, 15)
+];
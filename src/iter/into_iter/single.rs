@@ -9,12 +9,16 @@ impl<T: IntoAbstract> IntoIter for T {
     type IntoParIter = ParIter1<Self>;
     fn iter(self) -> Self::IntoIter {
         match &self.metadata().pack {
-            Pack::Update(_) => Iter1::Update(Update1::new(self)),
+            Pack::Update(pack) if pack.tracking.modification() => Iter1::Update(Update1::new(self)),
             _ => Iter1::Tight(Tight1::new(self)),
         }
     }
     #[cfg(feature = "parallel")]
     fn par_iter(self) -> Self::IntoParIter {
+        // rayon's threads can't add to this system's count, count every entity the iterator might yield
+        #[cfg(feature = "stats")]
+        crate::stats::iterated(self.len().unwrap_or(0));
+
         match self.iter() {
             Iter1::Tight(tight) => ParTight1::from(tight).into(),
             Iter1::Update(update) => ParUpdate1::from(update).into(),
@@ -69,12 +69,17 @@ This enum allows to abstract away what kind of iterator you really get. That doe
             type Item = ($(<$type::AbsView as AbstractMut>::Out,)+);
 
             fn first_pass(&mut self) -> Option<Self::Item> {
-                match self {
+                let item = match self {
                     Self::Tight(tight) => tight.first_pass(),
                     Self::Loose(loose) => loose.first_pass(),
                     Self::Update(update) => update.first_pass(),
                     Self::NonPacked(non_packed) => non_packed.first_pass(),
-                }
+                }?;
+
+                #[cfg(feature = "stats")]
+                crate::stats::iterated(1);
+
+                Some(item)
             }
             fn post_process(&mut self) {
                 match self {
@@ -156,6 +161,7 @@ macro_rules! iterators {
     }
 }
 
+#[cfg(not(feature = "extended_tuple"))]
 iterators![
     ;"2" "3" "4" "5" "6" "7" "8" "9" "10";
     ;Iter2 Iter3 Iter4 Iter5 Iter6 Iter7 Iter8 Iter9 Iter10;
@@ -167,3 +173,15 @@ iterators![
     ;Update2 Update3 Update4 Update5 Update6 Update7 Update8 Update9 Update10;
     (A, 0) (B, 1); (C, 2) (D, 3) (E, 4) (F, 5) (G, 6) (H, 7) (I, 8) (J, 9)
 ];
+#[cfg(feature = "extended_tuple")]
+iterators![
+    ;"2" "3" "4" "5" "6" "7" "8" "9" "10" "11" "12" "13" "14" "15" "16";
+    ;Iter2 Iter3 Iter4 Iter5 Iter6 Iter7 Iter8 Iter9 Iter10 Iter11 Iter12 Iter13 Iter14 Iter15 Iter16;
+    ;Tight2 Tight3 Tight4 Tight5 Tight6 Tight7 Tight8 Tight9 Tight10 Tight11 Tight12 Tight13 Tight14 Tight15 Tight16;
+    ;Chunk2 Chunk3 Chunk4 Chunk5 Chunk6 Chunk7 Chunk8 Chunk9 Chunk10 Chunk11 Chunk12 Chunk13 Chunk14 Chunk15 Chunk16;
+    ;ChunkExact2 ChunkExact3 ChunkExact4 ChunkExact5 ChunkExact6 ChunkExact7 ChunkExact8 ChunkExact9 ChunkExact10 ChunkExact11 ChunkExact12 ChunkExact13 ChunkExact14 ChunkExact15 ChunkExact16;
+    ;Loose2 Loose3 Loose4 Loose5 Loose6 Loose7 Loose8 Loose9 Loose10 Loose11 Loose12 Loose13 Loose14 Loose15 Loose16;
+    ;NonPacked2 NonPacked3 NonPacked4 NonPacked5 NonPacked6 NonPacked7 NonPacked8 NonPacked9 NonPacked10 NonPacked11 NonPacked12 NonPacked13 NonPacked14 NonPacked15 NonPacked16;
+    ;Update2 Update3 Update4 Update5 Update6 Update7 Update8 Update9 Update10 Update11 Update12 Update13 Update14 Update15 Update16;
+    (A, 0) (B, 1); (C, 2) (D, 3) (E, 4) (F, 5) (G, 6) (H, 7) (I, 8) (J, 9) (K, 10) (L, 11) (M, 12) (N, 13) (O, 14) (P, 15)
+];
@@ -14,13 +14,26 @@ macro_rules! impl_iterators {
         #[doc = "Parallel iterator over"]
         #[doc = $number]
         #[doc = "components.  
-This enum allows to abstract away what kind of iterator you really get. That doesn't mean the performance will suffer, the compiler will (almost) always optimize it away."]
+This enum allows to abstract away what kind of iterator you really get. That doesn't mean the performance will suffer, the compiler will (almost) always optimize it away.  
+Non packed iterators can't know their length, match on `Tight` or `Loose` to use `IndexedParallelIterator` methods like `collect_into_vec`."]
         pub enum $iter<$($type: IntoAbstract),+> {
             Tight($tight<$($type),+>),
             Loose($loose<$($type),+>),
             NonPacked($non_packed<$($type),+>),
         }
 
+        impl<$($type: IntoAbstract),+> $iter<$($type),+> {
+            /// Sets the minimum number of components each parallel task iterates, `1` by default.
+            /// Rayon can split iterators in many small tasks, when the work done per component is tiny bigger batches are faster.
+            pub fn with_min_batch_size(self, size: usize) -> Self {
+                match self {
+                    Self::Tight(tight) => Self::Tight(tight.with_min_batch_size(size)),
+                    Self::Loose(loose) => Self::Loose(loose.with_min_batch_size(size)),
+                    Self::NonPacked(non_packed) => Self::NonPacked(non_packed.with_min_batch_size(size)),
+                }
+            }
+        }
+
         impl<$($type: IntoAbstract),+> ParallelIterator for $iter<$($type),+>
         where $($type::AbsView: Clone + Send,)+ $(<$type::AbsView as AbstractMut>::Out: Send),+ {
             type Item = ($(<$type::AbsView as AbstractMut>::Out,)+);
@@ -73,6 +86,7 @@ macro_rules! iterators {
     }
 }
 
+#[cfg(not(feature = "extended_tuple"))]
 iterators![
     ;"2" "3" "4" "5" "6" "7" "8" "9" "10";
     ;ParIter2 ParIter3 ParIter4 ParIter5 ParIter6 ParIter7 ParIter8 ParIter9 ParIter10;
@@ -81,3 +95,12 @@ iterators![
     ;ParNonPacked2 ParNonPacked3 ParNonPacked4 ParNonPacked5 ParNonPacked6 ParNonPacked7 ParNonPacked8 ParNonPacked9 ParNonPacked10;
     (A, 0) (B, 1); (C, 2) (D, 3) (E, 4) (F, 5) (G, 6) (H, 7) (I, 8) (J, 9)
 ];
+#[cfg(feature = "extended_tuple")]
+iterators![
+    ;"2" "3" "4" "5" "6" "7" "8" "9" "10" "11" "12" "13" "14" "15" "16";
+    ;ParIter2 ParIter3 ParIter4 ParIter5 ParIter6 ParIter7 ParIter8 ParIter9 ParIter10 ParIter11 ParIter12 ParIter13 ParIter14 ParIter15 ParIter16;
+    ;ParTight2 ParTight3 ParTight4 ParTight5 ParTight6 ParTight7 ParTight8 ParTight9 ParTight10 ParTight11 ParTight12 ParTight13 ParTight14 ParTight15 ParTight16;
+    ;ParLoose2 ParLoose3 ParLoose4 ParLoose5 ParLoose6 ParLoose7 ParLoose8 ParLoose9 ParLoose10 ParLoose11 ParLoose12 ParLoose13 ParLoose14 ParLoose15 ParLoose16;
+    ;ParNonPacked2 ParNonPacked3 ParNonPacked4 ParNonPacked5 ParNonPacked6 ParNonPacked7 ParNonPacked8 ParNonPacked9 ParNonPacked10 ParNonPacked11 ParNonPacked12 ParNonPacked13 ParNonPacked14 ParNonPacked15 ParNonPacked16;
+    (A, 0) (B, 1); (C, 2) (D, 3) (E, 4) (F, 5) (G, 6) (H, 7) (I, 8) (J, 9) (K, 10) (L, 11) (M, 12) (N, 13) (O, 14) (P, 15)
+];
@@ -20,6 +20,17 @@ impl<T: IntoAbstract> From<ParUpdate1<T>> for ParIter1<T> {
     }
 }
 
+impl<T: IntoAbstract> ParIter1<T> {
+    /// Sets the minimum number of components each parallel task iterates, `1` by default.
+    /// Rayon can split iterators in many small tasks, when the work done per component is tiny bigger batches are faster.
+    pub fn with_min_batch_size(self, size: usize) -> Self {
+        match self {
+            Self::Tight(tight) => Self::Tight(tight.with_min_batch_size(size)),
+            Self::Update(update) => Self::Update(update.with_min_batch_size(size)),
+        }
+    }
+}
+
 impl<T: IntoAbstract> ParallelIterator for ParIter1<T>
 where
     T::AbsView: Clone + Send,
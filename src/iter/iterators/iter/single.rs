@@ -35,10 +35,15 @@ impl<T: IntoAbstract> Shiperator for Iter1<T> {
     type Item = <T::AbsView as AbstractMut>::Out;
 
     fn first_pass(&mut self) -> Option<Self::Item> {
-        match self {
+        let item = match self {
             Self::Tight(tight) => tight.first_pass(),
             Self::Update(update) => update.first_pass(),
-        }
+        }?;
+
+        #[cfg(feature = "stats")]
+        crate::stats::iterated(1);
+
+        Some(item)
     }
     fn post_process(&mut self) {
         match self {
@@ -22,6 +22,7 @@ macro_rules! impl_iterators {
             pub(crate) current: usize,
             pub(crate) end: usize,
             pub(crate) array: u32,
+            pub(crate) min_len: usize,
         }
 
         unsafe impl<$($type: IntoAbstract),+> Send for $loose<$($type),+>
@@ -35,6 +36,7 @@ macro_rules! impl_iterators {
                     current: self.current,
                     end: self.end,
                     array: self.array,
+                    min_len: self.min_len,
                 }
             }
         }
@@ -121,10 +123,14 @@ macro_rules! impl_iterators {
                     current: self.current + index,
                     end: self.end,
                     array: self.array,
+                    min_len: self.min_len,
                 };
                 self.end = clone.current;
                 (self, clone)
             }
+            fn min_len(&self) -> usize {
+                self.min_len
+            }
         }
 
         impl<$($type: IntoAbstract),+> core::iter::IntoIterator for $loose<$($type),+> {
@@ -159,8 +165,15 @@ macro_rules! iterators {
     }
 }
 
+#[cfg(not(feature = "extended_tuple"))]
 iterators![
     ;"2" "3" "4" "5" "6" "7" "8" "9" "10";
     ;Loose2 Loose3 Loose4 Loose5 Loose6 Loose7 Loose8 Loose9 Loose10;
     (A, 0) (B, 1); (C, 2) (D, 3) (E, 4) (F, 5) (G, 6) (H, 7) (I, 8) (J, 9)
 ];
+#[cfg(feature = "extended_tuple")]
+iterators![
+    ;"2" "3" "4" "5" "6" "7" "8" "9" "10" "11" "12" "13" "14" "15" "16";
+    ;Loose2 Loose3 Loose4 Loose5 Loose6 Loose7 Loose8 Loose9 Loose10 Loose11 Loose12 Loose13 Loose14 Loose15 Loose16;
+    (A, 0) (B, 1); (C, 2) (D, 3) (E, 4) (F, 5) (G, 6) (H, 7) (I, 8) (J, 9) (K, 10) (L, 11) (M, 12) (N, 13) (O, 14) (P, 15)
+];
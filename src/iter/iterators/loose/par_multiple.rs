@@ -21,6 +21,15 @@ macro_rules! impl_iterators {
             }
         }
 
+        impl<$($type: IntoAbstract),+> $loose<$($type),+> {
+            /// Sets the minimum number of components each parallel task iterates, `1` by default.
+            /// Rayon can split iterators in many small tasks, when the work done per component is tiny bigger batches are faster.
+            pub fn with_min_batch_size(mut self, size: usize) -> Self {
+                self.0.min_len = size.max(1);
+                self
+            }
+        }
+
         impl<$($type: IntoAbstract),+> ParallelIterator for $loose<$($type),+>
         where $($type::AbsView: Clone + Send,)+ $(<$type::AbsView as AbstractMut>::Out: Send),+ {
             type Item = ($(<$type::AbsView as AbstractMut>::Out),+);
@@ -72,9 +81,17 @@ macro_rules! iterators {
     }
 }
 
+#[cfg(not(feature = "extended_tuple"))]
 iterators![
     ;"2" "3" "4" "5" "6" "7" "8" "9" "10";
     ;ParLoose2 ParLoose3 ParLoose4 ParLoose5 ParLoose6 ParLoose7 ParLoose8 ParLoose9 ParLoose10;
     ;Loose2 Loose3 Loose4 Loose5 Loose6 Loose7 Loose8 Loose9 Loose10;
     (A, 0) (B, 1); (C, 2) (D, 3) (E, 4) (F, 5) (G, 6) (H, 7) (I, 8) (J, 9)
 ];
+#[cfg(feature = "extended_tuple")]
+iterators![
+    ;"2" "3" "4" "5" "6" "7" "8" "9" "10" "11" "12" "13" "14" "15" "16";
+    ;ParLoose2 ParLoose3 ParLoose4 ParLoose5 ParLoose6 ParLoose7 ParLoose8 ParLoose9 ParLoose10 ParLoose11 ParLoose12 ParLoose13 ParLoose14 ParLoose15 ParLoose16;
+    ;Loose2 Loose3 Loose4 Loose5 Loose6 Loose7 Loose8 Loose9 Loose10 Loose11 Loose12 Loose13 Loose14 Loose15 Loose16;
+    (A, 0) (B, 1); (C, 2) (D, 3) (E, 4) (F, 5) (G, 6) (H, 7) (I, 8) (J, 9) (K, 10) (L, 11) (M, 12) (N, 13) (O, 14) (P, 15)
+];
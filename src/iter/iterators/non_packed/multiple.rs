@@ -21,6 +21,7 @@ macro_rules! impl_iterators {
             pub(crate) current: usize,
             pub(crate) end: usize,
             pub(crate) array: usize,
+            pub(crate) min_len: usize,
         }
 
         unsafe impl<$($type: IntoAbstract),+> Send for $non_packed<$($type),+>
@@ -34,6 +35,7 @@ macro_rules! impl_iterators {
                     current: self.current,
                     end: self.end,
                     array: self.array,
+                    min_len: self.min_len,
                 }
             }
         }
@@ -48,6 +50,7 @@ macro_rules! impl_iterators {
                     current: update.current,
                     end: update.end,
                     array: update.array,
+                    min_len: 1,
                 }
             }
         }
@@ -96,13 +99,15 @@ macro_rules! impl_iterators {
             type Item = ($(<$type::AbsView as AbstractMut>::Out,)+);
             fn split(mut self) -> (Self, Option<Self>) {
                 let len = self.end - self.current;
-                if len >= 2 {
+                // both halves have to be at least min_len long
+                if len / 2 >= self.min_len {
                     let clone = $non_packed {
                         data: ($(self.data.$index.clone(),)+),
                         indices: self.indices,
                         current: self.current + (len / 2),
                         end: self.end,
                         array: self.array,
+                        min_len: self.min_len,
                     };
                     self.end = clone.current;
                     (self, Some(clone))
@@ -150,9 +155,17 @@ macro_rules! iterators {
     }
 }
 
+#[cfg(not(feature = "extended_tuple"))]
 iterators![
     ;"2" "3" "4" "5" "6" "7" "8" "9" "10";
     ;NonPacked2 NonPacked3 NonPacked4 NonPacked5 NonPacked6 NonPacked7 NonPacked8 NonPacked9 NonPacked10;
     ;Update2 Update3 Update4 Update5 Update6 Update7 Update8 Update9 Update10;
     (A, 0) (B, 1); (C, 2) (D, 3) (E, 4) (F, 5) (G, 6) (H, 7) (I, 8) (J, 9)
 ];
+#[cfg(feature = "extended_tuple")]
+iterators![
+    ;"2" "3" "4" "5" "6" "7" "8" "9" "10" "11" "12" "13" "14" "15" "16";
+    ;NonPacked2 NonPacked3 NonPacked4 NonPacked5 NonPacked6 NonPacked7 NonPacked8 NonPacked9 NonPacked10 NonPacked11 NonPacked12 NonPacked13 NonPacked14 NonPacked15 NonPacked16;
+    ;Update2 Update3 Update4 Update5 Update6 Update7 Update8 Update9 Update10 Update11 Update12 Update13 Update14 Update15 Update16;
+    (A, 0) (B, 1); (C, 2) (D, 3) (E, 4) (F, 5) (G, 6) (H, 7) (I, 8) (J, 9) (K, 10) (L, 11) (M, 12) (N, 13) (O, 14) (P, 15)
+];
@@ -29,6 +29,15 @@ macro_rules! impl_iterators {
             }
         }
 
+        impl<$($type: IntoAbstract),+> $non_packed<$($type),+> {
+            /// Sets the minimum number of components each parallel task iterates, `1` by default.
+            /// Rayon can split iterators in many small tasks, when the work done per component is tiny bigger batches are faster.
+            pub fn with_min_batch_size(mut self, size: usize) -> Self {
+                self.0.min_len = size.max(1);
+                self
+            }
+        }
+
         impl<$($type: IntoAbstract),+> ParallelIterator for $non_packed<$($type),+>
         where $($type::AbsView: Clone + Send,)+ $(<$type::AbsView as AbstractMut>::Out: Send),+
         {
@@ -72,6 +81,7 @@ macro_rules! iterators {
     }
 }
 
+#[cfg(not(feature = "extended_tuple"))]
 iterators![
     ;"2" "3" "4" "5" "6" "7" "8" "9" "10";
     ;ParNonPacked2 ParNonPacked3 ParNonPacked4 ParNonPacked5 ParNonPacked6 ParNonPacked7 ParNonPacked8 ParNonPacked9 ParNonPacked10;
@@ -79,3 +89,11 @@ iterators![
     ;Update2 Update3 Update4 Update5 Update6 Update7 Update8 Update9 Update10;
     (A, 0) (B, 1); (C, 2) (D, 3) (E, 4) (F, 5) (G, 6) (H, 7) (I, 8) (J, 9)
 ];
+#[cfg(feature = "extended_tuple")]
+iterators![
+    ;"2" "3" "4" "5" "6" "7" "8" "9" "10" "11" "12" "13" "14" "15" "16";
+    ;ParNonPacked2 ParNonPacked3 ParNonPacked4 ParNonPacked5 ParNonPacked6 ParNonPacked7 ParNonPacked8 ParNonPacked9 ParNonPacked10 ParNonPacked11 ParNonPacked12 ParNonPacked13 ParNonPacked14 ParNonPacked15 ParNonPacked16;
+    ;NonPacked2 NonPacked3 NonPacked4 NonPacked5 NonPacked6 NonPacked7 NonPacked8 NonPacked9 NonPacked10 NonPacked11 NonPacked12 NonPacked13 NonPacked14 NonPacked15 NonPacked16;
+    ;Update2 Update3 Update4 Update5 Update6 Update7 Update8 Update9 Update10 Update11 Update12 Update13 Update14 Update15 Update16;
+    (A, 0) (B, 1); (C, 2) (D, 3) (E, 4) (F, 5) (G, 6) (H, 7) (I, 8) (J, 9) (K, 10) (L, 11) (M, 12) (N, 13) (O, 14) (P, 15)
+];
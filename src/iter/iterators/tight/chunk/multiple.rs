@@ -25,10 +25,14 @@ The last chunk's length will be smaller than `size` if `size` does not divide th
                 let current = self.current;
                 if current + self.step <= self.end {
                     self.current += self.step;
+                    #[cfg(feature = "stats")]
+                    crate::stats::iterated(self.step);
                     // SAFE we checked for OOB and the lifetime is ok
                     Some(unsafe {($(self.data.$index.get_data_slice(current..(current + self.step)),)+)})
                 } else if current < self.end {
                     self.current = self.end;
+                    #[cfg(feature = "stats")]
+                    crate::stats::iterated(self.end - current);
                     // SAFE we checked for OOB and the lifetime is ok
                     Some(unsafe {($(self.data.$index.get_data_slice(current..self.end),)+)})
                 } else {
@@ -76,8 +80,15 @@ macro_rules! iterators {
     }
 }
 
+#[cfg(not(feature = "extended_tuple"))]
 iterators![
     ;"2" "3" "4" "5" "6" "7" "8" "9" "10";
     ;Chunk2 Chunk3 Chunk4 Chunk5 Chunk6 Chunk7 Chunk8 Chunk9 Chunk10;
     (A, 0) (B, 1); (C, 2) (D, 3) (E, 4) (F, 5) (G, 6) (H, 7) (I, 8) (J, 9)
 ];
+#[cfg(feature = "extended_tuple")]
+iterators![
+    ;"2" "3" "4" "5" "6" "7" "8" "9" "10" "11" "12" "13" "14" "15" "16";
+    ;Chunk2 Chunk3 Chunk4 Chunk5 Chunk6 Chunk7 Chunk8 Chunk9 Chunk10 Chunk11 Chunk12 Chunk13 Chunk14 Chunk15 Chunk16;
+    (A, 0) (B, 1); (C, 2) (D, 3) (E, 4) (F, 5) (G, 6) (H, 7) (I, 8) (J, 9) (K, 10) (L, 11) (M, 12) (N, 13) (O, 14) (P, 15)
+];
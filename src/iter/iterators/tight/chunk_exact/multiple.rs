@@ -25,6 +25,8 @@ To get the remaining items (if any) use the `remainder` method."]
                 let current = self.current;
                 if current + self.step <= self.end {
                     self.current += self.step;
+                    #[cfg(feature = "stats")]
+                    crate::stats::iterated(self.step);
                     // SAFE we checked for OOB and the lifetime is ok
                     Some(unsafe {($(self.data.$index.get_data_slice(current..(current + self.step)),)+)})
                 } else {
@@ -45,6 +47,8 @@ To get the remaining items (if any) use the `remainder` method."]
                 let end = self.end;
                 let remainder = core::cmp::min(self.end - self.current, self.end % self.step);
                 self.end -= remainder;
+                #[cfg(feature = "stats")]
+                crate::stats::iterated(remainder);
                 ($(
                     // SAFE we checked for OOB and the lifetime is ok
                     unsafe { self.data.$index.get_data_slice(self.end..end) },
@@ -84,8 +88,15 @@ macro_rules! iterators {
     }
 }
 
+#[cfg(not(feature = "extended_tuple"))]
 iterators![
     ;"2" "3" "4" "5" "6" "7" "8" "9" "10";
     ;ChunkExact2 ChunkExact3 ChunkExact4 ChunkExact5 ChunkExact6 ChunkExact7 ChunkExact8 ChunkExact9 ChunkExact10;
     (A, 0) (B, 1); (C, 2) (D, 3) (E, 4) (F, 5) (G, 6) (H, 7) (I, 8) (J, 9)
 ];
+#[cfg(feature = "extended_tuple")]
+iterators![
+    ;"2" "3" "4" "5" "6" "7" "8" "9" "10" "11" "12" "13" "14" "15" "16";
+    ;ChunkExact2 ChunkExact3 ChunkExact4 ChunkExact5 ChunkExact6 ChunkExact7 ChunkExact8 ChunkExact9 ChunkExact10 ChunkExact11 ChunkExact12 ChunkExact13 ChunkExact14 ChunkExact15 ChunkExact16;
+    (A, 0) (B, 1); (C, 2) (D, 3) (E, 4) (F, 5) (G, 6) (H, 7) (I, 8) (J, 9) (K, 10) (L, 11) (M, 12) (N, 13) (O, 14) (P, 15)
+];
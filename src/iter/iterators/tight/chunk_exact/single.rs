@@ -14,6 +14,8 @@ impl<T: IntoAbstract> ChunkExact1<T> {
         let remainder = core::cmp::min(self.end - self.current, self.end % self.step);
         let old_end = self.end;
         self.end -= remainder;
+        #[cfg(feature = "stats")]
+        crate::stats::iterated(remainder);
         // SAFE we checked for OOB and the lifetime is ok
         unsafe { self.data.get_data_slice(self.end..old_end) }
     }
@@ -26,6 +28,8 @@ impl<T: IntoAbstract> Shiperator for ChunkExact1<T> {
         let current = self.current;
         if current + self.step <= self.end {
             self.current += self.step;
+            #[cfg(feature = "stats")]
+            crate::stats::iterated(self.step);
             // SAFE we checked for OOB and the lifetime is ok
             Some(unsafe { self.data.get_data_slice(current..self.current) })
         } else {
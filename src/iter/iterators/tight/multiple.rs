@@ -24,6 +24,7 @@ Tight iterators are fast but are limited to components tightly packed together."
             pub(crate) data: ($($type::AbsView,)+),
             pub(crate) current: usize,
             pub(crate) end: usize,
+            pub(crate) min_len: usize,
         }
 
         impl<$($type: IntoAbstract),+> $tight<$($type),+> {
@@ -58,6 +59,7 @@ Tight iterators are fast but are limited to components tightly packed together."
                     data: self.data,
                     current: self.current,
                     end: self.end,
+                    min_len: self.min_len,
                 }
             }
         }
@@ -119,10 +121,14 @@ Tight iterators are fast but are limited to components tightly packed together."
                     data: ($(self.data.$index.clone(),)+),
                     current: self.current + index,
                     end: self.end,
+                    min_len: self.min_len,
                 };
                 self.end = clone.current;
                 (self, clone)
             }
+            fn min_len(&self) -> usize {
+                self.min_len
+            }
         }
 
         impl<$($type: IntoAbstract),+> core::iter::IntoIterator for $tight<$($type),+> {
@@ -163,6 +169,7 @@ macro_rules! iterators {
     }
 }
 
+#[cfg(not(feature = "extended_tuple"))]
 iterators![
     ;"2" "3" "4" "5" "6" "7" "8" "9" "10";
     ;Tight2 Tight3 Tight4 Tight5 Tight6 Tight7 Tight8 Tight9 Tight10;
@@ -170,3 +177,11 @@ iterators![
     ;ChunkExact2 ChunkExact3 ChunkExact4 ChunkExact5 ChunkExact6 ChunkExact7 ChunkExact8 ChunkExact9 ChunkExact10;
     (A, 0) (B, 1); (C, 2) (D, 3) (E, 4) (F, 5) (G, 6) (H, 7) (I, 8) (J, 9)
 ];
+#[cfg(feature = "extended_tuple")]
+iterators![
+    ;"2" "3" "4" "5" "6" "7" "8" "9" "10" "11" "12" "13" "14" "15" "16";
+    ;Tight2 Tight3 Tight4 Tight5 Tight6 Tight7 Tight8 Tight9 Tight10 Tight11 Tight12 Tight13 Tight14 Tight15 Tight16;
+    ;Chunk2 Chunk3 Chunk4 Chunk5 Chunk6 Chunk7 Chunk8 Chunk9 Chunk10 Chunk11 Chunk12 Chunk13 Chunk14 Chunk15 Chunk16;
+    ;ChunkExact2 ChunkExact3 ChunkExact4 ChunkExact5 ChunkExact6 ChunkExact7 ChunkExact8 ChunkExact9 ChunkExact10 ChunkExact11 ChunkExact12 ChunkExact13 ChunkExact14 ChunkExact15 ChunkExact16;
+    (A, 0) (B, 1); (C, 2) (D, 3) (E, 4) (F, 5) (G, 6) (H, 7) (I, 8) (J, 9) (K, 10) (L, 11) (M, 12) (N, 13) (O, 14) (P, 15)
+];
@@ -22,6 +22,15 @@ Tight iterators are fast but are limited to components tightly packed together."
             }
         }
 
+        impl<$($type: IntoAbstract),+> $tight<$($type),+> {
+            /// Sets the minimum number of components each parallel task iterates, `1` by default.
+            /// Rayon can split iterators in many small tasks, when the work done per component is tiny bigger batches are faster.
+            pub fn with_min_batch_size(mut self, size: usize) -> Self {
+                self.0.min_len = size.max(1);
+                self
+            }
+        }
+
         impl<$($type: IntoAbstract),+> ParallelIterator for $tight<$($type),+>
         where $($type::AbsView: Clone + Send,)+ $(<$type::AbsView as AbstractMut>::Out: Send),+ {
             type Item = ($(<$type::AbsView as AbstractMut>::Out),+);
@@ -73,9 +82,17 @@ macro_rules! iterators {
     }
 }
 
+#[cfg(not(feature = "extended_tuple"))]
 iterators![
     ;"2" "3" "4" "5" "6" "7" "8" "9" "10";
     ;ParTight2 ParTight3 ParTight4 ParTight5 ParTight6 ParTight7 ParTight8 ParTight9 ParTight10;
     ;Tight2 Tight3 Tight4 Tight5 Tight6 Tight7 Tight8 Tight9 Tight10;
     (A, 0) (B, 1); (C, 2) (D, 3) (E, 4) (F, 5) (G, 6) (H, 7) (I, 8) (J, 9)
 ];
+#[cfg(feature = "extended_tuple")]
+iterators![
+    ;"2" "3" "4" "5" "6" "7" "8" "9" "10" "11" "12" "13" "14" "15" "16";
+    ;ParTight2 ParTight3 ParTight4 ParTight5 ParTight6 ParTight7 ParTight8 ParTight9 ParTight10 ParTight11 ParTight12 ParTight13 ParTight14 ParTight15 ParTight16;
+    ;Tight2 Tight3 Tight4 Tight5 Tight6 Tight7 Tight8 Tight9 Tight10 Tight11 Tight12 Tight13 Tight14 Tight15 Tight16;
+    (A, 0) (B, 1); (C, 2) (D, 3) (E, 4) (F, 5) (G, 6) (H, 7) (I, 8) (J, 9) (K, 10) (L, 11) (M, 12) (N, 13) (O, 14) (P, 15)
+];
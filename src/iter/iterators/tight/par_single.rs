@@ -12,6 +12,15 @@ impl<T: IntoAbstract> From<Tight1<T>> for ParTight1<T> {
     }
 }
 
+impl<T: IntoAbstract> ParTight1<T> {
+    /// Sets the minimum number of components each parallel task iterates, `1` by default.
+    /// Rayon can split iterators in many small tasks, when the work done per component is tiny bigger batches are faster.
+    pub fn with_min_batch_size(mut self, size: usize) -> Self {
+        self.0.min_len = size.max(1);
+        self
+    }
+}
+
 impl<T: IntoAbstract> ParallelIterator for ParTight1<T>
 where
     T::AbsView: Clone + Send,
@@ -11,6 +11,7 @@ pub struct Tight1<T: IntoAbstract> {
     data: T::AbsView,
     current: usize,
     end: usize,
+    pub(crate) min_len: usize,
 }
 
 impl<T: IntoAbstract> Tight1<T> {
@@ -19,6 +20,7 @@ impl<T: IntoAbstract> Tight1<T> {
             current: 0,
             end: data.len().unwrap_or(0),
             data: data.into_abstract(),
+            min_len: 1,
         }
     }
     /// Return a chunk iterator over `step` component at a time.  
@@ -52,6 +54,7 @@ where
             data: self.data,
             current: self.current,
             end: self.end,
+            min_len: self.min_len,
         }
     }
 }
@@ -118,10 +121,14 @@ where
             data: self.data.clone(),
             current: self.current + index,
             end: self.end,
+            min_len: self.min_len,
         };
         self.end = clone.current;
         (self, clone)
     }
+    fn min_len(&self) -> usize {
+        self.min_len
+    }
 }
 
 impl<I: IntoAbstract> core::iter::IntoIterator for Tight1<I> {
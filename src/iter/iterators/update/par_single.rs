@@ -8,6 +8,7 @@ pub struct ParUpdate1<T: IntoAbstract> {
     data: T::AbsView,
     current: usize,
     end: usize,
+    pub(crate) min_len: usize,
 }
 
 impl<T: IntoAbstract> From<Update1<T>> for ParUpdate1<T> {
@@ -16,10 +17,20 @@ impl<T: IntoAbstract> From<Update1<T>> for ParUpdate1<T> {
             data: update.data,
             current: update.current,
             end: update.end,
+            min_len: 1,
         }
     }
 }
 
+impl<T: IntoAbstract> ParUpdate1<T> {
+    /// Sets the minimum number of components each parallel task iterates, `1` by default.
+    /// Rayon can split iterators in many small tasks, when the work done per component is tiny bigger batches are faster.
+    pub fn with_min_batch_size(mut self, size: usize) -> Self {
+        self.min_len = size.max(1);
+        self
+    }
+}
+
 impl<T: IntoAbstract> Iterator for IntoIterator<ParUpdate1<T>> {
     type Item = <T::AbsView as AbstractMut>::Out;
 
@@ -71,10 +82,14 @@ where
             data: self.data.clone(),
             current: self.current + index,
             end: self.end,
+            min_len: self.min_len,
         };
         self.end = clone.current;
         (self, clone)
     }
+    fn min_len(&self) -> usize {
+        self.min_len
+    }
 }
 
 impl<T: IntoAbstract> ParallelIterator for ParUpdate1<T>
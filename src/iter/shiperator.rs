@@ -136,6 +136,48 @@ pub trait Shiperator {
             Err(item) => Some(item),
         }
     }
+    /// Applies `f` to component(s) and returns the first non-`None` result.
+    fn find_map<B, F>(&mut self, f: F) -> Option<B>
+    where
+        Self: Sized,
+        F: FnMut(Self::Item) -> Option<B>,
+    {
+        #[inline]
+        fn check<T, B>(mut f: impl FnMut(T) -> Option<B>) -> impl FnMut(T) -> Result<(), B> {
+            move |x| match f(x) {
+                Some(x) => Err(x),
+                None => Ok(()),
+            }
+        }
+
+        self.try_for_each(check(f)).err()
+    }
+    /// Tests if any component(s) matches `pred`, stops at the first one that does.
+    fn any<P>(&mut self, pred: P) -> bool
+    where
+        Self: Sized,
+        P: FnMut(Self::Item) -> bool,
+    {
+        #[inline]
+        fn check<T>(mut predicate: impl FnMut(T) -> bool) -> impl FnMut(T) -> Result<(), ()> {
+            move |x| if predicate(x) { Err(()) } else { Ok(()) }
+        }
+
+        self.try_for_each(check(pred)).is_err()
+    }
+    /// Tests if all component(s) match `pred`, stops at the first one that doesn't.
+    fn all<P>(&mut self, pred: P) -> bool
+    where
+        Self: Sized,
+        P: FnMut(Self::Item) -> bool,
+    {
+        #[inline]
+        fn check<T>(mut predicate: impl FnMut(T) -> bool) -> impl FnMut(T) -> Result<(), ()> {
+            move |x| if predicate(x) { Ok(()) } else { Err(()) }
+        }
+
+        self.try_for_each(check(pred)).is_ok()
+    }
     /// Transforms a shiperator into an iterator, allowing the use of for loop and crates such as itertools.  
     /// Iterator doesn't know about update pack so it'll flag everything it visits.
     fn into_iterator(self) -> IntoIterator<Self>
@@ -208,6 +250,10 @@ impl<S: DoubleEndedShiperator> DoubleEndedShiperator for &mut S {
     }
 }
 
+/// Standard `Iterator` over a shiperator, made with [Shiperator::into_iterator] or `into_iter`.  
+/// It can be returned as `impl Iterator` and used with any crate expecting iterators.
+///
+/// [Shiperator::into_iterator]: trait.Shiperator.html#method.into_iterator
 pub struct IntoIterator<S: ?Sized>(pub(crate) S);
 
 impl<S: Shiperator + ?Sized> Iterator for IntoIterator<S> {
@@ -74,13 +74,15 @@
 //!     }
 //! }
 //!
-//! fn age(mut healths: ViewMut<Health>, thread_pool: ThreadPoolView) {
-//!     use rayon::prelude::ParallelIterator;
-//!
-//!     thread_pool.install(|| {
-//!         (&mut healths).par_iter().for_each(|health| {
-//!             health.0 -= 4.0;
-//!         });
+//! fn age(mut healths: ViewMut<Health>, tasks: Tasks) {
+//!     tasks.scope(|scope| {
+//!         for slice in (&mut healths).iter().into_chunk(32).ok().unwrap() {
+//!             scope.spawn(move |_| {
+//!                 for health in slice {
+//!                     health.0 -= 4.0;
+//!                 }
+//!             });
+//!         }
 //!     });
 //! }
 //!
@@ -120,12 +122,21 @@
 //!
 //! ## Features
 //!
-//! - **panic** *(default)* adds panicking functions
+//! - **panic** *(default)* adds panicking functions, without it use `World::try_new` to create a `World` without panicking
 //! - **parallel** *(default)* &mdash; adds parallel iterators and dispatch
 //! - **serde1** &mdash; adds (de)serialization support with [serde](https://github.com/serde-rs/serde)
 //! - **non_send** &mdash; add methods and types required to work with `!Send` components
 //! - **non_sync** &mdash; add methods and types required to work with `!Sync` components
-//! - **std** *(default)* &mdash; let shipyard use the standard library
+//! - **borrow_owner** &mdash; borrow errors tell which system and thread hold the storage
+//! - **arbitrary1** &mdash; implements [arbitrary](https://github.com/rust-fuzz/arbitrary)'s `Arbitrary` for `EntityId` and `Operation`
+//! - **proptest1** &mdash; implements [proptest](https://github.com/proptest-rs/proptest)'s `Arbitrary` for `EntityId` and `Operation`
+//! - **bytemuck1** &mdash; views storages of [bytemuck](https://github.com/Lokathor/bytemuck)'s `Pod` components as bytes
+//! - **extended_tuple** &mdash; extends tuple implementations from 10 to 16 elements, for systems, views, iterators and the fields of `#[derive(Borrow)]` and `#[derive(Split)]`, at the cost of compile time
+//! - **proc** &mdash; adds `#[derive(Borrow)]` to use structs grouping views as system parameters and `#[derive(Split)]` to store each field of a struct in its own storage
+//! - **stats** &mdash; counts storage borrows and entities iterated by each system, see `World::stats` and `AllStorages::delete_with_stats`
+//! - **std** *(default)* &mdash; let shipyard use the standard library, without it workloads still run with their systems one after the other
+//! - **trace** &mdash; records when and on which thread each system runs, see `World::write_trace`
+//! - **async** &mdash; runs workloads as futures yielding between batches, see `World::run_workload_async`
 //!
 //! ## Unsafe
 //!
@@ -140,29 +151,59 @@
 #![deny(unused_qualifications)]
 #![cfg_attr(not(any(feature = "std", test)), no_std)]
 #![cfg_attr(docsrs, feature(doc_cfg))]
+#![cfg_attr(feature = "extended_tuple", recursion_limit = "256")]
 
 extern crate alloc;
 
 mod add_unique_macro;
 mod atomic_refcell;
 mod borrow;
+#[cfg(feature = "borrow_owner")]
+mod borrow_owner;
+mod deferred_entity_builder;
 mod delete;
 mod entity_builder;
 #[cfg(feature = "serde1")]
 mod erased_serde;
 /// Contains all error types.
 pub mod error;
+mod event_log;
+mod frame_alloc;
 mod get;
+mod index;
 mod iter;
+mod messages;
 mod not;
+mod operation;
 mod pack;
+#[cfg(feature = "serde1")]
+mod recording;
+#[cfg(feature = "std")]
+mod reentrancy;
+mod reflect;
+mod relation;
 mod remove;
+#[cfg(feature = "std")]
+mod rng;
+#[cfg(feature = "serde1")]
+mod scene;
 #[cfg(feature = "serde1")]
 mod serde_setup;
+mod snapshot;
 mod sparse_set;
+mod spawn;
+#[cfg(feature = "stats")]
+mod stats;
 mod storage;
+#[cfg(feature = "std")]
+mod storage_cache;
+mod sync;
 mod system;
 mod system_macro;
+mod tags;
+pub mod testing;
+#[cfg(feature = "trace")]
+mod trace;
 mod type_id;
 mod unknown_storage;
 mod view;
@@ -175,33 +216,77 @@ pub use crate::borrow::NonSendSync;
 #[cfg(feature = "non_sync")]
 #[cfg_attr(docsrs, doc(cfg(feature = "non_sync")))]
 pub use crate::borrow::NonSync;
-#[doc(hidden)]
-pub use crate::borrow::{AllStoragesBorrow, Borrow};
+pub use crate::borrow::{AllStoragesBorrow, Borrow, Mutation};
 #[doc(hidden)]
 pub use add_unique_macro::{AddUnique, Wrap};
-pub use borrow::FakeBorrow;
+pub use atomic_refcell::AtomicRefCell;
+pub use borrow::{BorrowBundle, FakeBorrow};
+pub use deferred_entity_builder::DeferredEntityBuilder;
 pub use delete::Delete;
 pub use entity_builder::EntityBuilder;
+pub use event_log::StructuralEvent;
+pub use frame_alloc::FrameAlloc;
 pub use get::Get;
+pub use index::Index;
 pub use iter::{
     iterators, CurrentId, Enumerate, ExactSizeShiperator, Filter, IntoIter, IntoIterIds, Map,
     Shiperator, WithId,
 };
+pub use messages::{MessageReceiver, MessageSender};
 pub use not::Not;
+pub use operation::Operation;
 pub use pack::{LoosePack, TightPack};
+#[cfg(feature = "serde1")]
+pub use recording::Recording;
+pub use reflect::Reflect;
+pub use relation::Relation;
 pub use remove::Remove;
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub use rng::Rng;
+#[cfg(feature = "savegame")]
+#[cfg_attr(docsrs, doc(cfg(feature = "savegame")))]
+pub use serde_setup::SerFormat;
 #[cfg(feature = "serde1")]
-pub use serde_setup::{GlobalDeConfig, GlobalSerConfig, SerConfig};
+pub use serde_setup::{
+    GlobalDeConfig, GlobalSerConfig, Identifier, MapEntityIds, Migrate, SerConfig,
+    ThreadLocalStorages, WithShared,
+};
+#[cfg(feature = "proc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "proc")))]
+pub use shipyard_proc::{Borrow, Split};
+#[cfg(feature = "serde1")]
+pub use sparse_set::SerializeChunk;
 pub use sparse_set::{
-    sort, sort::IntoSortable, AddComponentUnchecked, Contains, OldComponent, SparseSet, Window,
-    WindowMut,
+    sort, sort::IntoSortable, AddComponentUnchecked, Contains, OldComponent, SparseSet, Tracking,
+    Window, WindowMut,
+};
+pub use spawn::Spawn;
+#[cfg(feature = "stats")]
+pub use stats::{DeleteStats, StorageStats, SystemStats, WorldStats};
+pub use storage::{
+    AllStorages, DeleteAny, Entities, EntityId, ErasedStorage, Recycling, RegisterStorages,
+    Signature, StorageId, TakeEntity, WeakEntity,
 };
-pub use storage::{AllStorages, DeleteAny, Entities, EntityId, StorageId};
 #[doc(hidden)]
 pub use system::{AllSystem, Nothing, System};
+pub use tags::Tags;
+pub use type_id::TypeId;
 #[cfg(feature = "parallel")]
+pub use view::Tasks;
+#[cfg(feature = "parallel")]
+#[allow(deprecated)]
 pub use view::ThreadPoolView;
 pub use view::{
-    AllStoragesViewMut, EntitiesView, EntitiesViewMut, UniqueView, UniqueViewMut, View, ViewMut,
+    AllStoragesView, AllStoragesViewMut, EntitiesView, EntitiesViewMut, MaskView, RelationView,
+    RelationViewMut, TagsView, TagsViewMut, UniqueView, UniqueViewMut, View, ViewMut,
+};
+#[cfg(feature = "async")]
+#[cfg_attr(docsrs, doc(cfg(feature = "async")))]
+pub use world::RunWorkloadAsync;
+pub use world::{
+    CancelToken, GlobalEntityId, SystemInfo, WorkloadBuilder, WorkloadCursor, WorkloadDiagnostic,
+    WorkloadSystem, World, WorldId,
 };
-pub use world::{WorkloadBuilder, World};
+#[cfg(feature = "std")]
+pub use world::{SystemTiming, WorkloadReport};
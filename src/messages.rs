@@ -0,0 +1,296 @@
+use crate::atomic_refcell::{AtomicRefCell, Borrow as AllBorrow, Ref, RefMut};
+use crate::borrow::{AllStoragesBorrow, Borrow, Mutation};
+use crate::deferred_entity_builder::current_position;
+use crate::error;
+use crate::storage::{AllStorages, EntityId};
+use crate::type_id::TypeId;
+use crate::unknown_storage::UnknownStorage;
+use alloc::vec::Vec;
+use core::any::Any;
+use core::convert::{TryFrom, TryInto};
+use core::mem;
+use parking_lot::Mutex;
+
+/// Storage behind [MessageSender] and [MessageReceiver].
+///
+/// [MessageSender]: struct.MessageSender.html
+/// [MessageReceiver]: struct.MessageReceiver.html
+pub(crate) struct Messages<T> {
+    buffers: Mutex<Buffers<T>>,
+}
+
+struct Buffers<T> {
+    // messages sent during the previous workload, next to the position of their sender in its batch
+    previous: Vec<(usize, T)>,
+    // messages sent during the current workload, next to the position of their sender in its batch
+    current: Vec<(usize, T)>,
+    // messages of `current` before this index are already in batch order
+    sorted: usize,
+    // workloads completed when `current` started
+    workload: usize,
+}
+
+impl<T> Buffers<T> {
+    /// Moves the buffers forward to `workload`, messages older than the previous workload are dropped.
+    fn update(&mut self, workload: usize) {
+        if workload != self.workload {
+            let current = mem::take(&mut self.current);
+
+            self.previous = if workload == self.workload.wrapping_add(1) {
+                current
+            } else {
+                Vec::new()
+            };
+            self.sorted = 0;
+            self.workload = workload;
+        }
+    }
+}
+
+impl<T> Messages<T> {
+    pub(crate) fn new(workload: usize) -> Self {
+        Messages {
+            buffers: Mutex::new(Buffers {
+                previous: Vec::new(),
+                current: Vec::new(),
+                sorted: 0,
+                workload,
+            }),
+        }
+    }
+}
+
+impl<T: 'static> UnknownStorage for Messages<T> {
+    fn delete(&mut self, _: EntityId, _: &mut Vec<TypeId>) {}
+    fn clear(&mut self) {
+        let buffers = self.buffers.get_mut();
+        buffers.previous.clear();
+        buffers.current.clear();
+        buffers.sorted = 0;
+    }
+    fn unpack(&mut self, _: EntityId) {}
+    fn any(&self) -> &dyn Any {
+        self
+    }
+    fn any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+    fn name(&self) -> &'static str {
+        core::any::type_name::<Self>()
+    }
+    #[cfg(feature = "parallel")]
+    fn sort_messages(&self) {
+        let mut buffers = self.buffers.lock();
+        let buffers = &mut *buffers;
+
+        // the sort is stable, messages of a system stay in the order they were sent
+        buffers.current[buffers.sorted..].sort_by_key(|&(position, _)| position);
+        buffers.sorted = buffers.current.len();
+    }
+}
+
+/// Sends `T` messages to the system borrowing [MessageReceiver]`<T>`.
+/// The sender only borrows the messages storage immutably, systems sending the same messages can run in parallel
+/// and `send` can be called from parallel iterators.
+///
+/// Messages are kept until the end of the workload following the one they were sent in,
+/// the receiver can run before or after the senders in the workload without losing any.
+/// Messages not received by then are dropped.
+///
+/// ### Example
+/// ```
+/// use shipyard::{system, MessageReceiver, MessageSender, World};
+///
+/// struct Damage(u32);
+///
+/// fn attack(damages: MessageSender<Damage>) {
+///     damages.send(Damage(3));
+///     damages.send(Damage(4));
+/// }
+///
+/// fn apply_damage(mut damages: MessageReceiver<Damage>) {
+///     assert_eq!(damages.drain().map(|Damage(damage)| damage).sum::<u32>(), 7);
+/// }
+///
+/// let world = World::new();
+///
+/// world
+///     .add_workload("Combat")
+///     .with_system(system!(attack))
+///     .with_system(system!(apply_damage))
+///     .build();
+///
+/// world.run_default();
+/// ```
+///
+/// [MessageReceiver]: struct.MessageReceiver.html
+pub struct MessageSender<'a, T> {
+    messages: Ref<'a, Messages<T>>,
+    workload: usize,
+    _all_borrow: AllBorrow<'a>,
+}
+
+impl<T> MessageSender<'_, T> {
+    /// Sends `message`.
+    pub fn send(&self, message: T) {
+        let mut buffers = self.messages.buffers.lock();
+        buffers.update(self.workload);
+        buffers.current.push((current_position(), message));
+    }
+    /// Sends all `messages`, locking the storage once.
+    pub fn send_all(&self, messages: impl IntoIterator<Item = T>) {
+        let mut buffers = self.messages.buffers.lock();
+        buffers.update(self.workload);
+        let position = current_position();
+        buffers
+            .current
+            .extend(messages.into_iter().map(|message| (position, message)));
+    }
+}
+
+impl<'a, T: 'static + Send + Sync> TryFrom<Ref<'a, AllStorages>> for MessageSender<'a, T> {
+    type Error = error::GetStorage;
+    fn try_from(all_storages: Ref<'a, AllStorages>) -> Result<Self, Self::Error> {
+        // SAFE all_storages and messages are dropped before all_borrow
+        let (all_storages, all_borrow) = unsafe { Ref::destructure(all_storages) };
+
+        Ok(MessageSender {
+            messages: all_storages.messages::<T>()?,
+            workload: all_storages.workloads(),
+            _all_borrow: all_borrow,
+        })
+    }
+}
+
+impl<'a, T: 'static + Send + Sync> TryFrom<&'a AllStorages> for MessageSender<'a, T> {
+    type Error = error::GetStorage;
+    fn try_from(all_storages: &'a AllStorages) -> Result<Self, Self::Error> {
+        Ok(MessageSender {
+            messages: all_storages.messages::<T>()?,
+            workload: all_storages.workloads(),
+            _all_borrow: AllBorrow::None,
+        })
+    }
+}
+
+/// Receives the `T` messages sent with [MessageSender]`<T>`.
+/// The receiver borrows the messages storage exclusively, only one system can receive `T` messages at a time.
+///
+/// [MessageSender]: struct.MessageSender.html
+pub struct MessageReceiver<'a, T> {
+    messages: RefMut<'a, Messages<T>>,
+    _all_borrow: AllBorrow<'a>,
+}
+
+impl<T> MessageReceiver<'_, T> {
+    /// Removes all messages, oldest first.
+    pub fn drain(&mut self) -> impl Iterator<Item = T> + '_ {
+        let buffers = self.messages.buffers.get_mut();
+        buffers.sorted = 0;
+
+        buffers
+            .previous
+            .drain(..)
+            .chain(buffers.current.drain(..))
+            .map(|(_, message)| message)
+    }
+    /// Returns the number of messages waiting.
+    pub fn len(&self) -> usize {
+        let buffers = self.messages.buffers.lock();
+
+        buffers.previous.len() + buffers.current.len()
+    }
+    /// Returns `true` if no message is waiting.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl<'a, T: 'static + Send + Sync> TryFrom<Ref<'a, AllStorages>> for MessageReceiver<'a, T> {
+    type Error = error::GetStorage;
+    fn try_from(all_storages: Ref<'a, AllStorages>) -> Result<Self, Self::Error> {
+        // SAFE all_storages and messages are dropped before all_borrow
+        let (all_storages, all_borrow) = unsafe { Ref::destructure(all_storages) };
+
+        let mut messages = all_storages.messages_mut::<T>()?;
+        messages.buffers.get_mut().update(all_storages.workloads());
+
+        Ok(MessageReceiver {
+            messages,
+            _all_borrow: all_borrow,
+        })
+    }
+}
+
+impl<'a, T: 'static + Send + Sync> TryFrom<&'a AllStorages> for MessageReceiver<'a, T> {
+    type Error = error::GetStorage;
+    fn try_from(all_storages: &'a AllStorages) -> Result<Self, Self::Error> {
+        let mut messages = all_storages.messages_mut::<T>()?;
+        messages.buffers.get_mut().update(all_storages.workloads());
+
+        Ok(MessageReceiver {
+            messages,
+            _all_borrow: AllBorrow::None,
+        })
+    }
+}
+
+impl<'a, T: 'static + Send + Sync> Borrow<'a> for MessageSender<'a, T> {
+    fn try_borrow(
+        all_storages: &'a AtomicRefCell<AllStorages>,
+        #[cfg(feature = "parallel")] _: &'a rayon::ThreadPool,
+    ) -> Result<Self, error::GetStorage> {
+        all_storages
+            .try_borrow()
+            .map_err(error::GetStorage::AllStoragesBorrow)?
+            .try_into()
+    }
+
+    fn borrow_infos(infos: &mut Vec<(TypeId, Mutation)>) {
+        infos.push((TypeId::of::<Messages<T>>(), Mutation::Shared));
+    }
+
+    fn is_send_sync() -> bool {
+        true
+    }
+
+    fn create_storages(all_storages: &AllStorages) {
+        let _ = all_storages.messages::<T>();
+    }
+}
+
+impl<'a, T: 'static + Send + Sync> Borrow<'a> for MessageReceiver<'a, T> {
+    fn try_borrow(
+        all_storages: &'a AtomicRefCell<AllStorages>,
+        #[cfg(feature = "parallel")] _: &'a rayon::ThreadPool,
+    ) -> Result<Self, error::GetStorage> {
+        all_storages
+            .try_borrow()
+            .map_err(error::GetStorage::AllStoragesBorrow)?
+            .try_into()
+    }
+
+    fn borrow_infos(infos: &mut Vec<(TypeId, Mutation)>) {
+        infos.push((TypeId::of::<Messages<T>>(), Mutation::Unique));
+    }
+
+    fn is_send_sync() -> bool {
+        true
+    }
+
+    fn create_storages(all_storages: &AllStorages) {
+        let _ = all_storages.messages::<T>();
+    }
+}
+
+impl<'a, T: 'static + Send + Sync> AllStoragesBorrow<'a> for MessageSender<'a, T> {
+    fn try_borrow(all_storages: &'a AllStorages) -> Result<Self, error::GetStorage> {
+        all_storages.try_into()
+    }
+}
+
+impl<'a, T: 'static + Send + Sync> AllStoragesBorrow<'a> for MessageReceiver<'a, T> {
+    fn try_borrow(all_storages: &'a AllStorages) -> Result<Self, error::GetStorage> {
+        all_storages.try_into()
+    }
+}
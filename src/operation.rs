@@ -0,0 +1,166 @@
+use crate::error;
+use crate::spawn::Spawn;
+use crate::storage::{AllStorages, EntityId};
+use crate::view::{EntitiesView, ViewMut};
+use alloc::vec::Vec;
+
+/// Structural change to a `World`, sequences of them can be generated with the
+/// **arbitrary1** or **proptest1** features to property-test systems against realistic mutations.
+///
+/// Entities are refered to by their position among the entities spawned by the sequence,
+/// wrapping around when it's too large.
+/// Operations refering to an entity do nothing if none was spawned yet or if it was despawned.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum Operation<T> {
+    /// Creates an entity with a `T` component.
+    Spawn(T),
+    /// Deletes an entity and all its components.
+    Despawn(usize),
+    /// Adds or replaces an entity's `T` component.
+    Add(usize, T),
+    /// Removes an entity's `T` component.
+    Remove(usize),
+}
+
+impl<T: 'static + Send + Sync> Operation<T> {
+    /// Applies `operations` in order and returns all entities they spawned, despawned ones included.
+    ///
+    /// ### Borrows
+    ///
+    /// - [Entities] (exclusive when spawning)
+    /// - `T` storage (exclusive)
+    ///
+    /// ### Errors
+    ///
+    /// - `T` storage is packed.
+    /// - `T` storage doesn't exist in strict mode.
+    ///
+    /// [Entities]: struct.Entities.html
+    pub fn try_apply_all<I: IntoIterator<Item = Self>>(
+        operations: I,
+        all_storages: &mut AllStorages,
+    ) -> Result<Vec<EntityId>, error::ApplyOperation> {
+        let mut spawned = Vec::new();
+
+        for operation in operations {
+            match operation {
+                Operation::Spawn(component) => spawned.push((component,).try_spawn(all_storages)?),
+                Operation::Despawn(index) => {
+                    if let Some(entity) = pick(&spawned, index) {
+                        all_storages.delete(entity);
+                    }
+                }
+                Operation::Add(index, component) => {
+                    if let Some(entity) = pick(&spawned, index) {
+                        let (entities, mut storage) =
+                            all_storages.try_borrow::<(EntitiesView<'_>, ViewMut<'_, T>)>()?;
+
+                        if entities.is_alive(entity) {
+                            entities.try_add_component(&mut storage, component, entity)?;
+                        }
+                    }
+                }
+                Operation::Remove(index) => {
+                    if let Some(entity) = pick(&spawned, index) {
+                        all_storages
+                            .try_borrow::<ViewMut<'_, T>>()?
+                            .try_remove(entity)?;
+                    }
+                }
+            }
+        }
+
+        Ok(spawned)
+    }
+    /// Applies `operations` in order and returns all entities they spawned, despawned ones included.
+    /// Unwraps errors.
+    ///
+    /// ### Borrows
+    ///
+    /// - [Entities] (exclusive when spawning)
+    /// - `T` storage (exclusive)
+    ///
+    /// ### Errors
+    ///
+    /// - `T` storage is packed.
+    /// - `T` storage doesn't exist in strict mode.
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{AllStoragesViewMut, Get, Operation, View, World};
+    ///
+    /// let world = World::new();
+    ///
+    /// let operations = vec![
+    ///     Operation::Spawn(0u32),
+    ///     Operation::Spawn(1),
+    ///     Operation::Despawn(0),
+    ///     Operation::Add(3, 2),
+    /// ];
+    ///
+    /// let spawned = world.run(|mut all_storages: AllStoragesViewMut| {
+    ///     Operation::apply_all(operations, &mut all_storages)
+    /// });
+    ///
+    /// world.run(|u32s: View<u32>| {
+    ///     assert!(u32s.get(spawned[0]).is_err());
+    ///     assert_eq!(u32s.get(spawned[1]), Ok(&2));
+    /// });
+    /// ```
+    ///
+    /// [Entities]: struct.Entities.html
+    #[cfg(feature = "panic")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "panic")))]
+    #[track_caller]
+    pub fn apply_all<I: IntoIterator<Item = Self>>(
+        operations: I,
+        all_storages: &mut AllStorages,
+    ) -> Vec<EntityId> {
+        Self::try_apply_all(operations, all_storages).unwrap()
+    }
+}
+
+/// Returns the entity `index` refers to, if any entity was spawned.
+fn pick(spawned: &[EntityId], index: usize) -> Option<EntityId> {
+    if spawned.is_empty() {
+        None
+    } else {
+        Some(spawned[index % spawned.len()])
+    }
+}
+
+#[cfg(feature = "arbitrary1")]
+impl<'a, T: arbitrary::Arbitrary<'a>> arbitrary::Arbitrary<'a> for Operation<T> {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(match u.int_in_range(0..=3u8)? {
+            0 => Operation::Spawn(T::arbitrary(u)?),
+            1 => Operation::Despawn(usize::arbitrary(u)?),
+            2 => Operation::Add(usize::arbitrary(u)?, T::arbitrary(u)?),
+            _ => Operation::Remove(usize::arbitrary(u)?),
+        })
+    }
+}
+
+#[cfg(feature = "proptest1")]
+impl<T> proptest::arbitrary::Arbitrary for Operation<T>
+where
+    T: proptest::arbitrary::Arbitrary + 'static,
+    T::Parameters: Clone,
+{
+    type Parameters = T::Parameters;
+    type Strategy = proptest::strategy::BoxedStrategy<Self>;
+
+    fn arbitrary_with(args: Self::Parameters) -> Self::Strategy {
+        use proptest::arbitrary::{any, any_with};
+        use proptest::strategy::Strategy;
+
+        proptest::prop_oneof![
+            any_with::<T>(args.clone()).prop_map(Operation::Spawn),
+            any::<usize>().prop_map(Operation::Despawn),
+            (any::<usize>(), any_with::<T>(args))
+                .prop_map(|(index, component)| Operation::Add(index, component)),
+            any::<usize>().prop_map(Operation::Remove),
+        ]
+        .boxed()
+    }
+}
@@ -113,6 +113,7 @@ macro_rules! impl_loose_pack {
                 Ok(())
             }
             #[cfg(feature = "panic")]
+            #[track_caller]
             fn loose_pack(self) {
                 LoosePack::<($($tight,)+)>::try_loose_pack(self).unwrap()
             }
@@ -135,4 +136,7 @@ macro_rules! loose_pack {
     ($(($tight: ident, $tight_index: tt))+;;) => {}
 }
 
+#[cfg(not(feature = "extended_tuple"))]
 loose_pack![(A, 0); (B, 1); (C, 2) (D, 3) (E, 4) (F, 5) (G, 6) (H, 7) (I, 8) (J, 9)];
+#[cfg(feature = "extended_tuple")]
+loose_pack![(A, 0); (B, 1); (C, 2) (D, 3) (E, 4) (F, 5) (G, 6) (H, 7) (I, 8) (J, 9) (K, 10) (L, 11) (M, 12) (N, 13) (O, 14) (P, 15)];
@@ -106,6 +106,7 @@ macro_rules! impl_tight_pack {
                 Ok(())
             }
                 #[cfg(feature = "panic")]
+                #[track_caller]
                 fn tight_pack(self) {
                 self.try_tight_pack().unwrap()
             }
@@ -115,6 +116,7 @@ macro_rules! impl_tight_pack {
                 ($(&mut self.$index,)+).try_tight_pack()
             }
                 #[cfg(feature = "panic")]
+                #[track_caller]
                 fn tight_pack(self) {
                 self.try_tight_pack().unwrap()
             }
@@ -132,4 +134,7 @@ macro_rules! tight_pack {
     }
 }
 
+#[cfg(not(feature = "extended_tuple"))]
 tight_pack![(A, 0) (B, 1); (C, 2) (D, 3) (E, 4) (F, 5) (G, 6) (H, 7) (I, 8) (J, 9)];
+#[cfg(feature = "extended_tuple")]
+tight_pack![(A, 0) (B, 1); (C, 2) (D, 3) (E, 4) (F, 5) (G, 6) (H, 7) (I, 8) (J, 9) (K, 10) (L, 11) (M, 12) (N, 13) (O, 14) (P, 15)];
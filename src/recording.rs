@@ -0,0 +1,346 @@
+use crate::serde_setup::{GlobalDeConfig, Identifier};
+use crate::storage::{AllStorages, EntityId};
+use crate::type_id::TypeId;
+use alloc::boxed::Box;
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::sync::atomic::{AtomicUsize, Ordering};
+use hashbrown::HashMap;
+
+/// Orders commands recorded by different storages, even when they're modified in parallel.
+// AtomicU64 isn't available on all targets
+static NEXT_INDEX: AtomicUsize = AtomicUsize::new(0);
+
+pub(crate) fn next_index() -> u64 {
+    NEXT_INDEX.fetch_add(1, Ordering::Relaxed) as u64
+}
+
+/// Command recorded by a storage, the component is kept as is until the recording stops.
+pub(crate) enum Command<T> {
+    Add(EntityId, T),
+    Remove(EntityId),
+}
+
+pub(crate) enum RecordedCommand {
+    Spawn(EntityId),
+    Despawn(EntityId),
+    Add(
+        EntityId,
+        Identifier,
+        Box<dyn crate::erased_serde::Serialize + Send + Sync>,
+    ),
+    Remove(EntityId, Identifier),
+}
+
+impl<T: serde::Serialize + Send + Sync + 'static> Command<T> {
+    fn into_recorded(self, identifier: &Identifier) -> RecordedCommand {
+        match self {
+            Command::Add(entity, component) => {
+                RecordedCommand::Add(entity, identifier.clone(), Box::new(component))
+            }
+            Command::Remove(entity) => RecordedCommand::Remove(entity, identifier.clone()),
+        }
+    }
+}
+
+/// Functions needing more bounds than `SerdeInfos` requires.
+pub(crate) struct RecordInfos<T> {
+    pub(crate) clone: fn(&T) -> T,
+    pub(crate) into_recorded: fn(Command<T>, &Identifier) -> RecordedCommand,
+}
+
+impl<T: Clone + serde::Serialize + Send + Sync + 'static> RecordInfos<T> {
+    pub(crate) fn new() -> Self {
+        RecordInfos {
+            clone: T::clone,
+            into_recorded: Command::into_recorded,
+        }
+    }
+}
+
+const SPAWN: &str = "spawn";
+const DESPAWN: &str = "despawn";
+const ADD: &str = "add";
+const REMOVE: &str = "remove";
+
+/// All structural operations done on a `World` between [World::start_recording] and [World::stop_recording].
+/// Only storages set up with [SparseSet::setup_recording] are recorded.
+///
+/// Each command is serialized as a sequence:
+/// - `["spawn", id]`
+/// - `["despawn", id]`
+/// - `["add", id, identifier, component]`
+/// - `["remove", id, identifier]`
+///
+/// [SparseSet::setup_recording]: struct.SparseSet.html#method.setup_recording
+/// [World::start_recording]: struct.World.html#method.start_recording
+/// [World::stop_recording]: struct.World.html#method.stop_recording
+pub struct Recording {
+    pub(crate) commands: Vec<(u64, RecordedCommand)>,
+}
+
+impl Recording {
+    /// Returns the number of commands recorded.
+    pub fn len(&self) -> usize {
+        self.commands.len()
+    }
+    /// Returns `true` if no command was recorded.
+    pub fn is_empty(&self) -> bool {
+        self.commands.is_empty()
+    }
+}
+
+impl serde::Serialize for Recording {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeSeq;
+
+        let mut seq = serializer.serialize_seq(Some(self.commands.len()))?;
+        for (_, command) in &self.commands {
+            seq.serialize_element(command)?;
+        }
+        seq.end()
+    }
+}
+
+impl serde::Serialize for RecordedCommand {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeSeq;
+
+        match self {
+            RecordedCommand::Spawn(entity) => {
+                let mut seq = serializer.serialize_seq(Some(2))?;
+                seq.serialize_element(SPAWN)?;
+                seq.serialize_element(entity)?;
+                seq.end()
+            }
+            RecordedCommand::Despawn(entity) => {
+                let mut seq = serializer.serialize_seq(Some(2))?;
+                seq.serialize_element(DESPAWN)?;
+                seq.serialize_element(entity)?;
+                seq.end()
+            }
+            RecordedCommand::Add(entity, identifier, component) => {
+                let mut seq = serializer.serialize_seq(Some(4))?;
+                seq.serialize_element(ADD)?;
+                seq.serialize_element(entity)?;
+                seq.serialize_element(identifier.as_str())?;
+                seq.serialize_element(&**component)?;
+                seq.end()
+            }
+            RecordedCommand::Remove(entity, identifier) => {
+                let mut seq = serializer.serialize_seq(Some(3))?;
+                seq.serialize_element(REMOVE)?;
+                seq.serialize_element(entity)?;
+                seq.serialize_element(identifier.as_str())?;
+                seq.end()
+            }
+        }
+    }
+}
+
+/// Applies a serialized [Recording] to a `World`.
+/// Returns the recorded and new `EntityId` of each spawned entity, in spawn order.
+///
+/// [Recording]: struct.Recording.html
+pub(crate) struct ReplayDeserializer<'a> {
+    pub(crate) all_storages: &'a mut AllStorages,
+    pub(crate) de_config: GlobalDeConfig,
+}
+
+impl<'de> serde::de::DeserializeSeed<'de> for ReplayDeserializer<'_> {
+    type Value = Vec<(EntityId, EntityId)>;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_seq(self)
+    }
+}
+
+impl<'de> serde::de::Visitor<'de> for ReplayDeserializer<'_> {
+    type Value = Vec<(EntityId, EntityId)>;
+
+    fn expecting(&self, formatter: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        formatter.write_str("a sequence of commands")
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: serde::de::SeqAccess<'de>,
+    {
+        let mut mapping = Vec::new();
+        let mut lookup = HashMap::new();
+
+        while let Some(()) = seq.next_element_seed(CommandDeserializer {
+            all_storages: self.all_storages,
+            mapping: &mut mapping,
+            lookup: &mut lookup,
+        })? {}
+
+        // components can refer to entities spawned after them
+        // only the components of entities the replay spawned are mapped, the others weren't replayed
+        let entities = {
+            let entities = self
+                .all_storages
+                .entities()
+                .map_err(serde::de::Error::custom)?;
+
+            mapping
+                .iter()
+                .map(|&(_, new_id)| new_id)
+                .filter(|&new_id| entities.is_alive(new_id))
+                .collect::<Vec<_>>()
+        };
+        let map = |id| lookup.get(&id).copied().unwrap_or(id);
+        for storage in self.all_storages.storages().values_mut() {
+            storage
+                .0
+                .try_borrow_mut()
+                .map_err(serde::de::Error::custom)?
+                .map_entity_ids(&entities, &map);
+        }
+
+        if let Some(entity_mapping) = &self.de_config.entity_mapping {
+            for &(old_id, new_id) in &mapping {
+                entity_mapping(old_id, new_id);
+            }
+        }
+
+        Ok(mapping)
+    }
+}
+
+struct CommandDeserializer<'a> {
+    all_storages: &'a mut AllStorages,
+    mapping: &'a mut Vec<(EntityId, EntityId)>,
+    lookup: &'a mut HashMap<EntityId, EntityId>,
+}
+
+impl<'de> serde::de::DeserializeSeed<'de> for CommandDeserializer<'_> {
+    type Value = ();
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_seq(self)
+    }
+}
+
+impl<'de> serde::de::Visitor<'de> for CommandDeserializer<'_> {
+    type Value = ();
+
+    fn expecting(&self, formatter: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        formatter.write_str("a command")
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: serde::de::SeqAccess<'de>,
+    {
+        let kind: String = seq.next_element()?.ok_or_else(|| {
+            serde::de::Error::invalid_length(0, &"a command with at least 2 elements")
+        })?;
+        let old_id: EntityId = seq.next_element()?.ok_or_else(|| {
+            serde::de::Error::invalid_length(1, &"a command with at least 2 elements")
+        })?;
+
+        // commands can only target entities spawned by the recording
+        let entity = match &*kind {
+            DESPAWN | ADD | REMOVE => Some(self.lookup.get(&old_id).copied().ok_or_else(|| {
+                serde::de::Error::custom(format!("{:?} wasn't spawned by the recording.", old_id))
+            })?),
+            _ => None,
+        };
+        let is_alive = match entity {
+            Some(entity) => self
+                .all_storages
+                .entities()
+                .map_err(serde::de::Error::custom)?
+                .is_alive(entity),
+            None => false,
+        };
+
+        match &*kind {
+            SPAWN => {
+                let new_id = self
+                    .all_storages
+                    .entities_mut()
+                    .map_err(serde::de::Error::custom)?
+                    .add_entity((), ());
+
+                self.mapping.push((old_id, new_id));
+                self.lookup.insert(old_id, new_id);
+            }
+            DESPAWN | ADD if !is_alive => {
+                return Err(serde::de::Error::custom(format!(
+                    "{:?} was despawned earlier in the recording.",
+                    old_id
+                )));
+            }
+            DESPAWN => {
+                self.all_storages.delete(entity.unwrap());
+            }
+            // deleting an entity records the removal of its components after its despawn
+            REMOVE if !is_alive => {
+                let _: Option<serde::de::IgnoredAny> = seq.next_element()?;
+            }
+            ADD | REMOVE => {
+                let entity = entity.unwrap();
+                let identifier: String = seq.next_element()?.ok_or_else(|| {
+                    serde::de::Error::invalid_length(2, &"a command with 3 or 4 elements")
+                })?;
+
+                let storages = self.all_storages.storages();
+                let mut storage = storages
+                    .values()
+                    .filter_map(|storage| storage.0.try_borrow_mut().ok())
+                    .find(|storage| storage.identifier() == Some(&*identifier))
+                    .ok_or_else(|| {
+                        serde::de::Error::custom(format!(
+                            "No storage with identifier \"{}\".",
+                            identifier
+                        ))
+                    })?;
+
+                if kind == ADD {
+                    seq.next_element_seed(crate::scene::ComponentDeserializer {
+                        entity,
+                        storage: &mut *storage,
+                    })?
+                    .ok_or_else(|| {
+                        serde::de::Error::invalid_length(3, &"an add command with 4 elements")
+                    })?;
+                } else {
+                    let mut storage_to_unpack: Vec<TypeId> = Vec::new();
+                    storage.delete(entity, &mut storage_to_unpack);
+                    drop(storage);
+
+                    for storage in storage_to_unpack {
+                        storages
+                            .get_mut(&crate::storage::StorageId::TypeId(storage))
+                            .unwrap()
+                            .unpack(entity)
+                            .map_err(serde::de::Error::custom)?;
+                    }
+                }
+            }
+            _ => {
+                return Err(serde::de::Error::unknown_variant(
+                    &kind,
+                    &[SPAWN, DESPAWN, ADD, REMOVE],
+                ))
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,30 @@
+//! Keeps track of the system running on each thread, to report systems running the `World` re-entrantly.
+
+use core::cell::Cell;
+
+std::thread_local! {
+    // system running on this thread, if any
+    #[allow(clippy::missing_const_for_thread_local)]
+    static CURRENT_SYSTEM: Cell<Option<&'static str>> = Cell::new(None);
+}
+
+/// Runs `f` as `system`.
+pub(crate) fn run_as<R>(system: &'static str, f: impl FnOnce() -> R) -> R {
+    // restores the previous system even if `f` panics
+    struct Restore(Option<&'static str>);
+
+    impl Drop for Restore {
+        fn drop(&mut self) {
+            CURRENT_SYSTEM.with(|current| current.set(self.0));
+        }
+    }
+
+    let _restore = Restore(CURRENT_SYSTEM.with(|current| current.replace(Some(system))));
+
+    f()
+}
+
+/// Returns the name of the system running on this thread, if any.
+pub(crate) fn current_system() -> Option<&'static str> {
+    CURRENT_SYSTEM.with(Cell::get)
+}
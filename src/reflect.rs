@@ -0,0 +1,114 @@
+use alloc::string::String;
+use core::any::Any;
+
+/// Component whose fields can be read and modified by name, with [World::set_field] and [World::field].
+/// Storages have to be set up with [SparseSet::setup_reflect] to be reachable this way.
+///
+/// Leaf values only need [Reflect::as_any] and [Reflect::as_any_mut], structs also return their fields by name.
+///
+/// ### Example
+/// ```
+/// use core::any::Any;
+/// use shipyard::Reflect;
+///
+/// struct Position {
+///     x: f32,
+///     y: f32,
+/// }
+///
+/// impl Reflect for Position {
+///     fn field(&self, name: &str) -> Option<&dyn Reflect> {
+///         match name {
+///             "x" => Some(&self.x),
+///             "y" => Some(&self.y),
+///             _ => None,
+///         }
+///     }
+///     fn field_mut(&mut self, name: &str) -> Option<&mut dyn Reflect> {
+///         match name {
+///             "x" => Some(&mut self.x),
+///             "y" => Some(&mut self.y),
+///             _ => None,
+///         }
+///     }
+///     fn as_any(&self) -> &dyn Any {
+///         self
+///     }
+///     fn as_any_mut(&mut self) -> &mut dyn Any {
+///         self
+///     }
+/// }
+/// ```
+///
+/// [World::set_field]: struct.World.html#method.set_field
+/// [World::field]: struct.World.html#method.field
+/// [SparseSet::setup_reflect]: struct.SparseSet.html#method.setup_reflect
+/// [Reflect::as_any]: trait.Reflect.html#tymethod.as_any
+/// [Reflect::as_any_mut]: trait.Reflect.html#tymethod.as_any_mut
+pub trait Reflect: Any {
+    /// Returns the field called `name`, `None` if there isn't any.
+    fn field(&self, _name: &str) -> Option<&dyn Reflect> {
+        None
+    }
+    /// Returns the field called `name`, `None` if there isn't any.
+    fn field_mut(&mut self, _name: &str) -> Option<&mut dyn Reflect> {
+        None
+    }
+    fn as_any(&self) -> &dyn Any;
+    fn as_any_mut(&mut self) -> &mut dyn Any;
+}
+
+impl dyn Reflect {
+    /// Follows `path`, a list of field names separated by dots.
+    pub fn path(&self, path: &str) -> Option<&dyn Reflect> {
+        path.split('.')
+            .filter(|name| !name.is_empty())
+            .try_fold(self, |value, name| value.field(name))
+    }
+    /// Follows `path`, a list of field names separated by dots.
+    pub fn path_mut(&mut self, path: &str) -> Option<&mut dyn Reflect> {
+        path.split('.')
+            .filter(|name| !name.is_empty())
+            .try_fold(self, |value, name| value.field_mut(name))
+    }
+}
+
+macro_rules! leaf {
+    ($($type: ty),+) => {
+        $(
+            impl Reflect for $type {
+                fn as_any(&self) -> &dyn Any {
+                    self
+                }
+                fn as_any_mut(&mut self) -> &mut dyn Any {
+                    self
+                }
+            }
+        )+
+    };
+}
+
+leaf![bool, char, f32, f64, i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize, String];
+
+/// Functions requiring `T: Reflect`, set up by `SparseSet::setup_reflect`.
+pub(crate) struct ReflectInfos<T> {
+    pub(crate) get: fn(&T) -> &dyn Reflect,
+    pub(crate) get_mut: fn(&mut T) -> &mut dyn Reflect,
+}
+
+impl<T> Clone for ReflectInfos<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for ReflectInfos<T> {}
+
+impl<T: Reflect> ReflectInfos<T> {
+    pub(crate) fn new() -> Self {
+        ReflectInfos {
+            get: |component| component,
+            get_mut: |component| component,
+        }
+    }
+}
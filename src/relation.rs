@@ -0,0 +1,240 @@
+use crate::storage::{EntityId, Members, Signatures, StorageId};
+use crate::type_id::TypeId;
+use crate::unknown_storage::UnknownStorage;
+use alloc::sync::Arc;
+use alloc::vec::Vec;
+use core::any::Any;
+use hashbrown::{HashMap, HashSet};
+
+/// Many-to-many relation between entities, each `source`→`target` pair carries a `T` payload.
+/// Borrowed with [RelationView] and [RelationViewMut], the storage is created the first time it's borrowed.
+///
+/// Pairs can be looked up from either side.
+/// When an entity is deleted with [AllStorages::delete] all pairs it's part of are removed, as source or target.
+///
+/// ### Example
+/// ```
+/// use shipyard::{AllStoragesViewMut, RelationView, RelationViewMut, World};
+///
+/// struct Likes;
+///
+/// let world = World::new();
+/// let [alice, bob, carol] = [world.spawn(()), world.spawn(()), world.spawn(())];
+///
+/// world.run(|mut likes: RelationViewMut<Likes>| {
+///     likes.insert(alice, bob, Likes);
+///     likes.insert(carol, bob, Likes);
+/// });
+///
+/// world.run(|mut all_storages: AllStoragesViewMut| {
+///     all_storages.delete(carol);
+/// });
+///
+/// world.run(|likes: RelationView<Likes>| {
+///     assert!(likes.contains(alice, bob));
+///     assert_eq!(likes.sources(bob).map(|(source, _)| source).collect::<Vec<_>>(), vec![alice]);
+/// });
+/// ```
+///
+/// [RelationView]: struct.RelationView.html
+/// [RelationViewMut]: struct.RelationViewMut.html
+/// [AllStorages::delete]: struct.AllStorages.html#method.delete
+pub struct Relation<T> {
+    // source -> target -> payload
+    targets: HashMap<EntityId, HashMap<EntityId, T>>,
+    // target -> sources, payloads are stored in `targets`
+    sources: HashMap<EntityId, Vec<EntityId>>,
+    // entities this storage references in the table of the entities' storages, see AllStorages::delete
+    signatures: Option<Arc<Members>>,
+}
+
+impl<T> Relation<T> {
+    pub(crate) fn new() -> Self {
+        Relation {
+            targets: HashMap::new(),
+            sources: HashMap::new(),
+            signatures: None,
+        }
+    }
+    /// Relates `source` to `target` with `payload`.
+    /// Returns the previous payload if the pair already existed.
+    pub fn insert(&mut self, source: EntityId, target: EntityId, payload: T) -> Option<T> {
+        let previous = self
+            .targets
+            .entry(source)
+            .or_default()
+            .insert(target, payload);
+
+        if previous.is_none() {
+            self.sources.entry(target).or_default().push(source);
+            self.update_signature(source);
+            self.update_signature(target);
+        }
+
+        previous
+    }
+    /// Removes the `source`→`target` pair and returns its payload.
+    pub fn remove(&mut self, source: EntityId, target: EntityId) -> Option<T> {
+        let targets = self.targets.get_mut(&source)?;
+        let payload = targets.remove(&target)?;
+
+        if targets.is_empty() {
+            self.targets.remove(&source);
+        }
+
+        remove_from(&mut self.sources, target, source);
+        self.update_signature(source);
+        self.update_signature(target);
+
+        Some(payload)
+    }
+    /// Removes all pairs `entity` is part of, as source or target.
+    pub fn remove_entity(&mut self, entity: EntityId) {
+        if let Some(targets) = self.targets.remove(&entity) {
+            for target in targets.keys() {
+                remove_from(&mut self.sources, *target, entity);
+                self.update_signature(*target);
+            }
+        }
+
+        if let Some(sources) = self.sources.remove(&entity) {
+            for source in sources {
+                if let Some(targets) = self.targets.get_mut(&source) {
+                    targets.remove(&entity);
+
+                    if targets.is_empty() {
+                        self.targets.remove(&source);
+                    }
+                }
+
+                self.update_signature(source);
+            }
+        }
+
+        self.update_signature(entity);
+    }
+    /// Returns the payload of the `source`→`target` pair.
+    pub fn get(&self, source: EntityId, target: EntityId) -> Option<&T> {
+        self.targets.get(&source)?.get(&target)
+    }
+    /// Returns the payload of the `source`→`target` pair mutably.
+    pub fn get_mut(&mut self, source: EntityId, target: EntityId) -> Option<&mut T> {
+        self.targets.get_mut(&source)?.get_mut(&target)
+    }
+    /// Returns `true` if `source` is related to `target`.
+    pub fn contains(&self, source: EntityId, target: EntityId) -> bool {
+        self.get(source, target).is_some()
+    }
+    /// Returns the entities `source` is related to and their payload, in no particular order.
+    pub fn targets(&self, source: EntityId) -> impl Iterator<Item = (EntityId, &T)> {
+        self.targets
+            .get(&source)
+            .into_iter()
+            .flat_map(|targets| targets.iter().map(|(&target, payload)| (target, payload)))
+    }
+    /// Returns the entities related to `target` and their payload, in no particular order.
+    pub fn sources(&self, target: EntityId) -> impl Iterator<Item = (EntityId, &T)> {
+        self.sources
+            .get(&target)
+            .into_iter()
+            .flatten()
+            .map(move |&source| (source, &self.targets[&source][&target]))
+    }
+    /// Returns all `(source, target, payload)` triples, in no particular order.
+    pub fn iter(&self) -> impl Iterator<Item = (EntityId, EntityId, &T)> {
+        self.targets.iter().flat_map(|(&source, targets)| {
+            targets
+                .iter()
+                .map(move |(&target, payload)| (source, target, payload))
+        })
+    }
+    /// Returns the number of pairs.
+    pub fn len(&self) -> usize {
+        self.targets.values().map(HashMap::len).sum()
+    }
+    /// Returns `true` if there is no pair.
+    pub fn is_empty(&self) -> bool {
+        self.targets.is_empty()
+    }
+    /// Removes all pairs.
+    pub fn clear(&mut self) {
+        self.targets.clear();
+        self.sources.clear();
+
+        if let Some(members) = &self.signatures {
+            members.reset(None);
+        }
+    }
+    /// Returns `true` if `entity` is part of a pair, as source or target.
+    fn is_related(&self, entity: EntityId) -> bool {
+        self.targets.contains_key(&entity) || self.sources.contains_key(&entity)
+    }
+    fn update_signature(&self, entity: EntityId) {
+        if let Some(members) = &self.signatures {
+            let related = self.is_related(entity);
+            members.set(
+                entity.index() as usize,
+                if related { Some(false) } else { None },
+            );
+        }
+    }
+}
+
+/// Removes `entity` from `key`'s list, the list is dropped once empty.
+fn remove_from(map: &mut HashMap<EntityId, Vec<EntityId>>, key: EntityId, entity: EntityId) {
+    if let Some(entities) = map.get_mut(&key) {
+        if let Some(position) = entities.iter().position(|&id| id == entity) {
+            entities.swap_remove(position);
+        }
+
+        if entities.is_empty() {
+            map.remove(&key);
+        }
+    }
+}
+
+impl<T: 'static> UnknownStorage for Relation<T> {
+    fn delete(&mut self, entity: EntityId, _: &mut Vec<TypeId>) {
+        self.remove_entity(entity);
+    }
+    fn clear(&mut self) {
+        Relation::clear(self);
+    }
+    fn unpack(&mut self, _: EntityId) {}
+    fn any(&self) -> &dyn Any {
+        self
+    }
+    fn any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+    fn name(&self) -> &'static str {
+        core::any::type_name::<Self>()
+    }
+    fn references(&self, entity: EntityId) -> bool {
+        self.is_related(entity)
+    }
+    fn track_signatures(&mut self, storage_id: StorageId, signatures: Arc<Signatures>) {
+        let members = signatures.members(storage_id);
+
+        // a storage created at the same time by another thread mustn't erase the entities of the one kept
+        if self.is_empty() {
+            self.signatures = Some(members);
+            return;
+        }
+
+        let related = self
+            .targets
+            .keys()
+            .chain(self.sources.keys())
+            .copied()
+            .collect::<HashSet<_>>();
+
+        members.reset(
+            related
+                .into_iter()
+                .map(|entity| (entity.index() as usize, false)),
+        );
+
+        self.signatures = Some(members);
+    }
+}
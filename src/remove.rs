@@ -144,6 +144,7 @@ macro_rules! impl_remove {
                 )+))
             }
             #[cfg(feature = "panic")]
+            #[track_caller]
             fn remove(self, entity: EntityId) -> <($($type,)+) as Removable>::Out {
                 Remove::<($($type,)+)>::try_remove(self, entity).unwrap()
             }
@@ -168,4 +169,7 @@ macro_rules! remove {
     }
 }
 
+#[cfg(not(feature = "extended_tuple"))]
 remove![(A, 0);; (B, 1) (C, 2) (D, 3) (E, 4) (F, 5) (G, 6) (H, 7) (I, 8) (J, 9)];
+#[cfg(feature = "extended_tuple")]
+remove![(A, 0);; (B, 1) (C, 2) (D, 3) (E, 4) (F, 5) (G, 6) (H, 7) (I, 8) (J, 9) (K, 10) (L, 11) (M, 12) (N, 13) (O, 14) (P, 15)];
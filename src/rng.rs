@@ -0,0 +1,183 @@
+use crate::atomic_refcell::AtomicRefCell;
+use crate::borrow::{Borrow, Mutation};
+use crate::error;
+use crate::storage::AllStorages;
+use crate::type_id::TypeId;
+use alloc::vec::Vec;
+use core::cell::Cell;
+use core::marker::PhantomData;
+use core::ops::Range;
+use core::ptr;
+use parking_lot::Mutex;
+
+std::thread_local! {
+    // random state of the system running on this thread, if any
+    #[allow(clippy::missing_const_for_thread_local)]
+    static CURRENT_RNG: Cell<*mut u64> = Cell::new(ptr::null_mut());
+}
+
+/// Random state of a system, derived from the `World`'s seed and the system's name.
+pub(crate) struct SystemRng(Mutex<u64>);
+
+impl SystemRng {
+    pub(crate) fn new(seed: u64, name: &str) -> Self {
+        SystemRng(Mutex::new(derive_state(seed, name)))
+    }
+    /// Restarts the sequence from `seed`.
+    pub(crate) fn reseed(&self, seed: u64, name: &str) {
+        *self.0.lock() = derive_state(seed, name);
+    }
+    /// Runs `f` with this state, [Rng] borrowed during `f` draws from it.
+    /// If the system is already running on another thread `f` runs without state.
+    ///
+    /// [Rng]: struct.Rng.html
+    pub(crate) fn run<R>(&self, f: impl FnOnce() -> R) -> R {
+        // restores the previous state even if `f` panics
+        struct Restore(*mut u64);
+
+        impl Drop for Restore {
+            fn drop(&mut self) {
+                CURRENT_RNG.with(|current| current.set(self.0));
+            }
+        }
+
+        match self.0.try_lock() {
+            Some(mut state) => {
+                let state: *mut u64 = &mut *state;
+                let _restore = Restore(CURRENT_RNG.with(|current| current.replace(state)));
+
+                f()
+            }
+            None => f(),
+        }
+    }
+}
+
+/// Mixes the name into the seed so each system gets its own sequence.
+fn derive_state(seed: u64, name: &str) -> u64 {
+    // FNV-1a, stable across runs and platforms unlike the std hasher
+    let hash = name.bytes().fold(0xcbf2_9ce4_8422_2325, |hash, byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3)
+    });
+
+    let mut state = seed ^ hash;
+    splitmix64(&mut state);
+    state
+}
+
+/// Advances `state` and returns the next value of the sequence.
+fn splitmix64(state: &mut u64) -> u64 {
+    *state = state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+
+    let mut z = *state;
+    z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    z ^ (z >> 31)
+}
+
+/// Deterministic random numbers for the system borrowing it.
+/// Each system has its own sequence, derived from the `World`'s seed and the system's name.
+/// The sequence continues from one run to the next and doesn't depend on which systems run in parallel.
+///
+/// The seed is set with [World::set_seed], it's 0 by default.
+/// `Rng` can only be borrowed by a system running in a workload.
+///
+/// ### Example
+/// ```
+/// use shipyard::{system, Rng, World};
+///
+/// fn roll(mut rng: Rng) {
+///     let dice = rng.gen_range(1..7);
+///     assert!(dice >= 1 && dice < 7);
+/// }
+///
+/// let world = World::new();
+/// world.set_seed(42);
+///
+/// world.add_workload("Roll").with_system(system!(roll)).build();
+/// world.run_default();
+/// ```
+///
+/// [World::set_seed]: struct.World.html#method.set_seed
+pub struct Rng<'a> {
+    state: *mut u64,
+    _phantom: PhantomData<&'a mut u64>,
+}
+
+impl Rng<'_> {
+    /// Returns a random `u64`.
+    pub fn next_u64(&mut self) -> u64 {
+        // SAFE the state is locked by the system running on this thread and was taken out of CURRENT_RNG
+        splitmix64(unsafe { &mut *self.state })
+    }
+    /// Returns a random `u32`.
+    pub fn next_u32(&mut self) -> u32 {
+        (self.next_u64() >> 32) as u32
+    }
+    /// Returns a random `f64` in `[0, 1)`.
+    pub fn next_f64(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+    }
+    /// Returns a random `f32` in `[0, 1)`.
+    pub fn next_f32(&mut self) -> f32 {
+        (self.next_u64() >> 40) as f32 / (1u32 << 24) as f32
+    }
+    /// Returns `true` with a probability of `p`.
+    pub fn gen_bool(&mut self, p: f64) -> bool {
+        self.next_f64() < p
+    }
+    /// Returns a random `u64` in `range`.
+    ///
+    /// ### Panics
+    ///
+    /// - `range` is empty.
+    #[track_caller]
+    pub fn gen_range(&mut self, range: Range<u64>) -> u64 {
+        assert!(
+            range.start < range.end,
+            "Cannot pick a number in an empty range."
+        );
+
+        let span = range.end - range.start;
+        // rejects the values that would make the low numbers more likely
+        let zone = u64::MAX - (u64::MAX - span + 1) % span;
+
+        loop {
+            let value = self.next_u64();
+            if value <= zone {
+                return range.start + value % span;
+            }
+        }
+    }
+}
+
+impl Drop for Rng<'_> {
+    fn drop(&mut self) {
+        CURRENT_RNG.with(|current| current.set(self.state));
+    }
+}
+
+impl<'a> Borrow<'a> for Rng<'a> {
+    fn try_borrow(
+        _: &'a AtomicRefCell<AllStorages>,
+        #[cfg(feature = "parallel")] _: &'a rayon::ThreadPool,
+    ) -> Result<Self, error::GetStorage> {
+        // takes the state so it can't be borrowed twice
+        let state = CURRENT_RNG.with(|current| current.replace(ptr::null_mut()));
+
+        if state.is_null() {
+            Err(error::GetStorage::MissingRng)
+        } else {
+            Ok(Rng {
+                state,
+                _phantom: PhantomData,
+            })
+        }
+    }
+
+    fn borrow_infos(_: &mut Vec<(TypeId, Mutation)>) {}
+
+    fn is_send_sync() -> bool {
+        true
+    }
+}
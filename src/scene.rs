@@ -0,0 +1,244 @@
+use crate::atomic_refcell::{Ref, RefMut};
+use crate::serde_setup::GlobalDeConfig;
+use crate::storage::{Entities, EntityId};
+use crate::unknown_storage::UnknownStorage;
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+use hashbrown::HashMap;
+
+/// Key used for the entity's `EntityId` in a scene, no storage can use it as identifier.
+const ID_KEY: &str = "id";
+
+/// Serializes all entities owning at least one component from a storage with an identifier.
+/// Each entity is a map from its components' identifier to their value, plus its `EntityId`.
+pub(crate) struct SceneSerializer<'a> {
+    pub(crate) entities: Vec<EntityId>,
+    pub(crate) storages: Vec<Ref<'a, dyn UnknownStorage>>,
+}
+
+impl serde::Serialize for SceneSerializer<'_> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeSeq;
+
+        let entities = self
+            .entities
+            .iter()
+            .filter(|&&entity| {
+                self.storages
+                    .iter()
+                    .any(|storage| storage.has_component(entity))
+            })
+            .collect::<Vec<_>>();
+
+        let mut seq = serializer.serialize_seq(Some(entities.len()))?;
+        for &entity in entities {
+            seq.serialize_element(&EntitySerializer {
+                entity,
+                storages: &self.storages,
+            })?;
+        }
+        seq.end()
+    }
+}
+
+struct EntitySerializer<'a, 'b> {
+    entity: EntityId,
+    storages: &'a [Ref<'b, dyn UnknownStorage>],
+}
+
+impl serde::Serialize for EntitySerializer<'_, '_> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeMap;
+
+        let storages = self
+            .storages
+            .iter()
+            .filter(|storage| storage.has_component(self.entity))
+            .collect::<Vec<_>>();
+
+        let mut map = serializer.serialize_map(Some(storages.len() + 1))?;
+        map.serialize_entry(ID_KEY, &self.entity)?;
+        for storage in storages {
+            // storages without identifier are never part of a scene
+            map.serialize_entry(
+                storage.identifier().unwrap(),
+                &ComponentSerializer {
+                    entity: self.entity,
+                    storage: &**storage,
+                },
+            )?;
+        }
+        map.end()
+    }
+}
+
+struct ComponentSerializer<'a> {
+    entity: EntityId,
+    storage: &'a dyn UnknownStorage,
+}
+
+impl serde::Serialize for ComponentSerializer<'_> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        self.storage
+            .serialize_component(
+                self.entity,
+                &mut <dyn crate::erased_serde::Serializer>::erase(serializer),
+            )
+            .map(crate::erased_serde::Ok::take)
+            .map_err(serde::ser::Error::custom)
+    }
+}
+
+/// Adds each entity of the scene to the `World` with a new `EntityId`.
+/// Returns the `"id"` and new `EntityId` of each entity in the same order as the scene.
+pub(crate) struct SceneDeserializer<'a> {
+    pub(crate) entities: RefMut<'a, Entities>,
+    pub(crate) storages: Vec<RefMut<'a, dyn UnknownStorage>>,
+    pub(crate) de_config: GlobalDeConfig,
+}
+
+impl<'de> serde::de::DeserializeSeed<'de> for SceneDeserializer<'_> {
+    type Value = Vec<(Option<EntityId>, EntityId)>;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_seq(self)
+    }
+}
+
+impl<'de> serde::de::Visitor<'de> for SceneDeserializer<'_> {
+    type Value = Vec<(Option<EntityId>, EntityId)>;
+
+    fn expecting(&self, formatter: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        formatter.write_str("a sequence of entities")
+    }
+
+    fn visit_seq<A>(mut self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: serde::de::SeqAccess<'de>,
+    {
+        let mut entities = Vec::with_capacity(seq.size_hint().unwrap_or(0));
+
+        while let Some(entity) = seq.next_element_seed(EntityDeserializer {
+            entities: &mut self.entities,
+            storages: &mut self.storages,
+        })? {
+            entities.push(entity);
+        }
+
+        // the whole scene has to be loaded before updating ids to include forward references
+        let ids: Vec<EntityId> = entities.iter().map(|&(_, new_id)| new_id).collect();
+        let lookup: HashMap<EntityId, EntityId> = entities
+            .iter()
+            .filter_map(|&(old_id, new_id)| Some((old_id?, new_id)))
+            .collect();
+        let map = |id| lookup.get(&id).copied().unwrap_or(id);
+        for storage in &mut self.storages {
+            storage.map_entity_ids(&ids, &map);
+        }
+
+        if let Some(entity_mapping) = &self.de_config.entity_mapping {
+            for &(old_id, new_id) in &entities {
+                if let Some(old_id) = old_id {
+                    entity_mapping(old_id, new_id);
+                }
+            }
+        }
+
+        Ok(entities)
+    }
+}
+
+struct EntityDeserializer<'a, 'b> {
+    entities: &'a mut Entities,
+    storages: &'a mut [RefMut<'b, dyn UnknownStorage>],
+}
+
+impl<'de> serde::de::DeserializeSeed<'de> for EntityDeserializer<'_, '_> {
+    type Value = (Option<EntityId>, EntityId);
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_map(self)
+    }
+}
+
+impl<'de> serde::de::Visitor<'de> for EntityDeserializer<'_, '_> {
+    type Value = (Option<EntityId>, EntityId);
+
+    fn expecting(&self, formatter: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        formatter.write_str("a map of components")
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: serde::de::MapAccess<'de>,
+    {
+        let entity = self.entities.add_entity((), ());
+        let mut old_id = None;
+
+        while let Some(identifier) = map.next_key::<String>()? {
+            if identifier == ID_KEY {
+                if old_id.is_some() {
+                    return Err(serde::de::Error::duplicate_field(ID_KEY));
+                }
+
+                old_id = Some(map.next_value()?);
+                continue;
+            }
+
+            let storage = self
+                .storages
+                .iter_mut()
+                .find(|storage| storage.identifier() == Some(&*identifier))
+                .ok_or_else(|| {
+                    serde::de::Error::custom(format!(
+                        "No storage with identifier \"{}\".",
+                        identifier
+                    ))
+                })?;
+
+            map.next_value_seed(ComponentDeserializer {
+                entity,
+                storage: &mut **storage,
+            })?;
+        }
+
+        Ok((old_id, entity))
+    }
+}
+
+pub(crate) struct ComponentDeserializer<'a> {
+    pub(crate) entity: EntityId,
+    pub(crate) storage: &'a mut dyn UnknownStorage,
+}
+
+impl<'de> serde::de::DeserializeSeed<'de> for ComponentDeserializer<'_> {
+    type Value = ();
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        self.storage
+            .deserialize_component(
+                self.entity,
+                &mut <dyn crate::erased_serde::Deserializer<'_>>::erase(deserializer),
+            )
+            .map_err(serde::de::Error::custom)
+    }
+}
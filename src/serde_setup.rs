@@ -1,4 +1,6 @@
+use crate::storage::EntityId;
 use alloc::borrow::Cow;
+use alloc::sync::Arc;
 
 pub(crate) static ANCHOR: () = ();
 
@@ -8,6 +10,7 @@ pub struct GlobalSerConfig {
     pub same_binary: bool,
     pub with_entities: bool,
     pub with_shared: WithShared,
+    pub thread_local: ThreadLocalStorages,
 }
 
 impl Default for GlobalSerConfig {
@@ -16,15 +19,20 @@ impl Default for GlobalSerConfig {
             same_binary: true,
             with_entities: true,
             with_shared: WithShared::PerStorage,
+            thread_local: ThreadLocalStorages::Skip,
         }
     }
 }
 
 /// Defines how the `World` should be deserialized.
-#[derive(Clone, Copy)]
+///
+/// `entity_mapping` is called with the old and new `EntityId` of each deserialized entity.  
+/// Entities keeping their `EntityId`, like with `World::new_deserialized`, are reported with the same old and new `EntityId`.
+#[derive(Clone)]
 pub struct GlobalDeConfig {
     pub existing_entities: ExistingEntities,
     pub with_shared: WithShared,
+    pub entity_mapping: Option<Arc<dyn Fn(EntityId, EntityId) + Send + Sync>>,
 }
 
 impl Default for GlobalDeConfig {
@@ -32,6 +40,7 @@ impl Default for GlobalDeConfig {
         GlobalDeConfig {
             existing_entities: ExistingEntities::AsNew,
             with_shared: WithShared::PerStorage,
+            entity_mapping: None,
         }
     }
 }
@@ -53,6 +62,9 @@ pub enum ExistingEntities {
 /// - All will (de)serialize shared component for all storages
 /// - None will not (de)serialize shared components for any storage
 /// - PerStorage will (de)serailize shared components following each storage's config
+///
+/// Only the link between the shared entity and the entity it points to is saved, the component is saved with its owner.  
+/// When deserializing, All and PerStorage restore the links present in the save.
 #[derive(Clone, Copy, PartialEq, Eq)]
 pub enum WithShared {
     All,
@@ -60,18 +72,52 @@ pub enum WithShared {
     PerStorage,
 }
 
+/// Describes how `NonSend`, `NonSync` and `NonSendSync` storages should be serialized.
+/// - Skip will leave them out of the serialization
+/// - OwningThread will serialize them, `NonSend` and `NonSendSync` storages can then only be serialized from the thread owning the `World`
+///
+/// With OwningThread, serializing from another thread returns an error naming the storage instead of panicking.  
+/// The storages are deserialized as regular storages.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum ThreadLocalStorages {
+    Skip,
+    OwningThread,
+}
+
+/// Format used by `World::save_to`.
+/// - Bincode writes the `World` as is
+/// - BincodeLz4 compresses the bincode output with lz4
+#[cfg(feature = "savegame")]
+#[cfg_attr(docsrs, doc(cfg(feature = "savegame")))]
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum SerFormat {
+    Bincode,
+    BincodeLz4,
+}
+
+/// Name given to a storage, scenes use it to refer to the storage's component type.
+#[derive(Clone)]
 pub struct Identifier(Cow<'static, str>);
 
 impl Identifier {
     pub fn new<I: Into<Cow<'static, str>>>(identifier: I) -> Self {
         Identifier(identifier.into())
     }
+    pub(crate) fn as_str(&self) -> &str {
+        &self.0
+    }
 }
 
 /// Defines how a storage should be serialized.
+///
+/// `with_shared` saves which entities share a component with [SparseSet::share], used when the `World`'s config is `WithShared::PerStorage`.  
+/// A `transient` storage is serialized without its components, it's recreated empty when the `World` is loaded.
+///
+/// [SparseSet::share]: struct.SparseSet.html#method.share
 pub struct SerConfig {
     pub identifier: Option<Identifier>,
     pub with_shared: bool,
+    pub transient: bool,
 }
 
 impl Default for SerConfig {
@@ -79,6 +125,84 @@ impl Default for SerConfig {
         SerConfig {
             identifier: None,
             with_shared: false,
+            transient: false,
         }
     }
 }
+
+/// Components holding `EntityId`s implement this trait to keep them valid
+/// when the entities they refer to get a new `EntityId` during deserialization.  
+/// The storage has to be set up with [SparseSet::setup_serde_with_entity_ids].
+///
+/// ### Example
+/// ```
+/// use shipyard::{EntityId, MapEntityIds};
+///
+/// struct Parent(EntityId);
+///
+/// impl MapEntityIds for Parent {
+///     fn map_entity_ids(&mut self, map: &dyn Fn(EntityId) -> EntityId) {
+///         self.0 = map(self.0);
+///     }
+/// }
+/// ```
+///
+/// [SparseSet::setup_serde_with_entity_ids]: struct.SparseSet.html#method.setup_serde_with_entity_ids
+pub trait MapEntityIds {
+    /// Replaces all `EntityId`s inside the component by the result of `map`.
+    fn map_entity_ids(&mut self, map: &dyn Fn(EntityId) -> EntityId);
+}
+
+/// Components whose serialized layout changed implement this trait to still load saves made with an older layout.  
+/// The storage has to be set up with [SparseSet::setup_serde_with_migration].
+///
+/// `VERSION` is saved next to the storage, components saved with another version are deserialized with `migrate`.  
+/// Saves made before storages had a version are version 0.
+///
+/// ### Example
+/// ```
+/// use serde_derive::{Deserialize, Serialize};
+/// use shipyard::Migrate;
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct Health {
+///     current: u32,
+///     max: u32,
+/// }
+///
+/// // the first layout only had the current health
+/// #[derive(Deserialize)]
+/// struct HealthV0(u32);
+///
+/// impl Migrate for Health {
+///     const VERSION: u32 = 1;
+///
+///     fn migrate<'de, D: serde::Deserializer<'de>>(
+///         version: u32,
+///         deserializer: D,
+///     ) -> Result<Self, D::Error> {
+///         match version {
+///             0 => {
+///                 let HealthV0(current) = serde::Deserialize::deserialize(deserializer)?;
+///
+///                 Ok(Health {
+///                     current,
+///                     max: current,
+///                 })
+///             }
+///             _ => Err(serde::de::Error::custom("unknown Health version")),
+///         }
+///     }
+/// }
+/// ```
+///
+/// [SparseSet::setup_serde_with_migration]: struct.SparseSet.html#method.setup_serde_with_migration
+pub trait Migrate: Sized {
+    /// Version of the current layout.
+    const VERSION: u32;
+    /// Deserializes a component saved with the layout of `version`.
+    fn migrate<'de, D: serde::Deserializer<'de>>(
+        version: u32,
+        deserializer: D,
+    ) -> Result<Self, D::Error>;
+}
@@ -0,0 +1,564 @@
+use crate::atomic_refcell::AtomicRefCell;
+use crate::error;
+use crate::event_log::StructuralEvent;
+#[cfg(feature = "serde1")]
+use crate::recording::RecordedCommand;
+use crate::reflect::Reflect;
+#[cfg(feature = "serde1")]
+use crate::serde_setup::{GlobalDeConfig, GlobalSerConfig};
+use crate::sparse_set::{Pack, SparseSet};
+use crate::storage::{AllStorages, EntityId, Signatures, Storage, StorageId};
+use crate::type_id::TypeId;
+use crate::unknown_storage::UnknownStorage;
+use alloc::boxed::Box;
+use alloc::collections::VecDeque;
+use alloc::sync::Arc;
+use alloc::vec::Vec;
+use core::any::Any;
+use hashbrown::HashMap;
+
+/// Default number of snapshots kept by a `World`.
+pub(crate) const DEFAULT_CAPACITY: usize = 32;
+
+pub(crate) type StorageSnapshot = Arc<dyn StorageState>;
+
+/// State of a storage at the time of a snapshot.
+pub(crate) trait StorageState: Send + Sync {
+    fn as_any(&self) -> &dyn Any;
+    /// Creates a new storage with this state, used by forks.  
+    /// Component storages keep reading the state until they're modified.
+    fn fork(self: Arc<Self>) -> Storage;
+    /// Returns the type name of the storage this state was taken from.
+    fn name(&self) -> &'static str;
+}
+
+/// Functions requiring `T: Clone`, set up by `SparseSet::setup_snapshot`.
+pub(crate) struct SnapshotInfos<T> {
+    pub(crate) take: fn(&SparseSet<T>) -> StorageSnapshot,
+    pub(crate) restore: fn(&mut SparseSet<T>, &StorageSnapshot),
+}
+
+impl<T: Clone + Send + Sync + 'static> SnapshotInfos<T> {
+    pub(crate) fn new() -> Self {
+        SnapshotInfos {
+            take: |sparse_set| {
+                let mut copy = SparseSet::new();
+                copy.setup_snapshot();
+                copy.sparse = sparse_set.sparse.clone();
+                copy.dense = sparse_set.dense.clone();
+                copy.data = sparse_set.data.clone();
+
+                Arc::new(SparseSetSnapshot {
+                    sparse_set: copy,
+                    pack_len: match &sparse_set.metadata.pack {
+                        Pack::Tight(tight) => (tight.len, 0),
+                        Pack::Loose(loose) => (loose.len, 0),
+                        Pack::Update(update) => (update.inserted, update.modified),
+                        Pack::NoPack => (0, 0),
+                    },
+                })
+            },
+            restore: |sparse_set, snapshot| {
+                // snapshots are always stored under the StorageId of their storage
+                let snapshot = snapshot
+                    .as_any()
+                    .downcast_ref::<SparseSetSnapshot<T>>()
+                    .unwrap();
+
+                sparse_set.sparse = snapshot.sparse_set.sparse.clone();
+                sparse_set.dense = snapshot.sparse_set.dense.clone();
+                sparse_set.data = snapshot.sparse_set.data.clone();
+                match &mut sparse_set.metadata.pack {
+                    Pack::Tight(tight) => tight.len = snapshot.pack_len.0,
+                    Pack::Loose(loose) => loose.len = snapshot.pack_len.0,
+                    Pack::Update(update) => {
+                        update.inserted = snapshot.pack_len.0;
+                        update.modified = snapshot.pack_len.1;
+                    }
+                    Pack::NoPack => {}
+                }
+            },
+        }
+    }
+}
+
+struct SparseSetSnapshot<T> {
+    // only the components, packs refer to other storages
+    sparse_set: SparseSet<T>,
+    pack_len: (usize, usize),
+}
+
+impl<T: Clone + Send + Sync + 'static> SparseSetSnapshot<T> {
+    /// Returns a new storage with the components of this snapshot.
+    fn to_sparse_set(&self) -> SparseSet<T> {
+        let mut sparse_set = SparseSet::new();
+        sparse_set.setup_snapshot();
+        sparse_set.sparse = self.sparse_set.sparse.clone();
+        sparse_set.dense = self.sparse_set.dense.clone();
+        sparse_set.data = self.sparse_set.data.clone();
+
+        sparse_set
+    }
+}
+
+impl<T: Clone + Send + Sync + 'static> StorageState for SparseSetSnapshot<T> {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+    fn fork(self: Arc<Self>) -> Storage {
+        let forked = ForkedSparseSet {
+            snapshot: self,
+            owned: None,
+            signatures: None,
+        };
+
+        #[cfg(feature = "std")]
+        {
+            Storage(Box::new(AtomicRefCell::new(forked, None, true)))
+        }
+        #[cfg(not(feature = "std"))]
+        {
+            Storage(Box::new(AtomicRefCell::new(forked)))
+        }
+    }
+    fn name(&self) -> &'static str {
+        core::any::type_name::<T>()
+    }
+}
+
+/// Component storage of a fork.  
+/// Shared borrows read the snapshot it was forked from, it's only cloned the first time it's modified.
+struct ForkedSparseSet<T> {
+    snapshot: Arc<SparseSetSnapshot<T>>,
+    owned: Option<SparseSet<T>>,
+    // handed to `owned` once it's created
+    signatures: Option<(StorageId, Arc<Signatures>)>,
+}
+
+impl<T: Clone + Send + Sync + 'static> ForkedSparseSet<T> {
+    fn get(&self) -> &SparseSet<T> {
+        self.owned.as_ref().unwrap_or(&self.snapshot.sparse_set)
+    }
+    fn get_mut(&mut self) -> &mut SparseSet<T> {
+        let snapshot = &self.snapshot;
+        let signatures = &self.signatures;
+
+        self.owned.get_or_insert_with(|| {
+            let mut sparse_set = snapshot.to_sparse_set();
+            // same entities as the snapshot, the table is already up to date
+            sparse_set.metadata.signatures = signatures
+                .as_ref()
+                .map(|(storage_id, signatures)| signatures.members(*storage_id));
+            sparse_set
+        })
+    }
+}
+
+impl<T: Clone + Send + Sync + 'static> UnknownStorage for ForkedSparseSet<T> {
+    fn delete(&mut self, entity: EntityId, storage_to_unpack: &mut Vec<TypeId>) {
+        if self.get().references(entity) {
+            UnknownStorage::delete(self.get_mut(), entity, storage_to_unpack);
+        }
+    }
+    fn clear(&mut self) {
+        match &mut self.owned {
+            Some(owned) => UnknownStorage::clear(owned),
+            None => {
+                let mut sparse_set = SparseSet::new();
+                sparse_set.setup_snapshot();
+                if let Some((storage_id, signatures)) = self.signatures.clone() {
+                    sparse_set.track_signatures(storage_id, signatures);
+                }
+                self.owned = Some(sparse_set);
+            }
+        }
+    }
+    fn unpack(&mut self, entity: EntityId) {
+        // forks start without packs
+        if let Some(owned) = &mut self.owned {
+            UnknownStorage::unpack(owned, entity);
+        }
+    }
+    fn any(&self) -> &dyn Any {
+        self.get()
+    }
+    fn any_mut(&mut self) -> &mut dyn Any {
+        self.get_mut()
+    }
+    fn name(&self) -> &'static str {
+        core::any::type_name::<T>()
+    }
+    fn set_fixed_capacity(&mut self, entity_capacity: usize, capacity: usize) {
+        self.get_mut().set_fixed_capacity(entity_capacity, capacity);
+    }
+    fn compact(&mut self) {
+        if let Some(owned) = &mut self.owned {
+            owned.compact();
+        }
+    }
+    fn snapshot(&self) -> Option<StorageSnapshot> {
+        match &self.owned {
+            Some(owned) => owned.snapshot(),
+            None => Some(self.snapshot.clone()),
+        }
+    }
+    fn restore(&mut self, storage_snapshot: Option<&StorageSnapshot>) {
+        self.get_mut().restore(storage_snapshot);
+    }
+    fn clone_storage(&self) -> Option<Storage> {
+        match &self.owned {
+            Some(owned) => owned.clone_storage(),
+            None => Some(self.snapshot.clone().fork()),
+        }
+    }
+    #[cfg(feature = "serde1")]
+    fn is_serializable(&self) -> bool {
+        self.get().is_serializable()
+    }
+    #[cfg(feature = "serde1")]
+    fn skip_serialization(&self, ser_config: GlobalSerConfig) -> bool {
+        self.get().skip_serialization(ser_config)
+    }
+    #[cfg(feature = "serde1")]
+    fn serialize(
+        &self,
+        ser_config: GlobalSerConfig,
+        serializer: &mut dyn crate::erased_serde::Serializer,
+    ) -> crate::erased_serde::Result<crate::erased_serde::Ok> {
+        self.get().serialize(ser_config, serializer)
+    }
+    #[cfg(feature = "serde1")]
+    fn deserialize(
+        &self,
+    ) -> Option<
+        fn(
+            GlobalDeConfig,
+            u32,
+            &mut dyn crate::erased_serde::Deserializer<'_>,
+        ) -> Result<Storage, crate::erased_serde::Error>,
+    > {
+        self.get().deserialize()
+    }
+    #[cfg(feature = "serde1")]
+    fn serde_version(&self) -> u32 {
+        self.get().serde_version()
+    }
+    #[cfg(feature = "serde1")]
+    fn identifier(&self) -> Option<&str> {
+        self.get().identifier()
+    }
+    fn has_component(&self, entity: EntityId) -> bool {
+        self.get().has_component(entity)
+    }
+    fn references(&self, entity: EntityId) -> bool {
+        self.get().references(entity)
+    }
+    fn set_event_log(&mut self, enabled: bool) {
+        if enabled || self.owned.is_some() {
+            self.get_mut().set_event_log(enabled);
+        }
+    }
+    fn drain_events(&mut self, events: &mut Vec<(usize, StructuralEvent)>) {
+        if let Some(owned) = &mut self.owned {
+            owned.drain_events(events);
+        }
+    }
+    fn track_signatures(&mut self, storage_id: StorageId, signatures: Arc<Signatures>) {
+        match &mut self.owned {
+            Some(owned) => owned.track_signatures(storage_id, signatures.clone()),
+            None => {
+                let sparse_set = &self.snapshot.sparse_set;
+
+                if !sparse_set.is_empty() || !sparse_set.shared_ids().is_empty() {
+                    sparse_set.reset_signatures(&signatures.members(storage_id));
+                }
+            }
+        }
+
+        self.signatures = Some((storage_id, signatures));
+    }
+    fn component(&self, entity: EntityId) -> Option<&dyn Any> {
+        self.get().component(entity)
+    }
+    fn component_entities(&self) -> &[EntityId] {
+        self.get().component_entities()
+    }
+    fn pack_types(&self) -> Vec<TypeId> {
+        self.get().pack_types()
+    }
+    fn observer_types(&self) -> &[TypeId] {
+        self.get().observer_types()
+    }
+    fn repack(&mut self, entities: &[EntityId]) {
+        // forks start without packs
+        if let Some(owned) = &mut self.owned {
+            owned.repack(entities);
+        }
+    }
+    fn is_reflected(&self) -> bool {
+        self.get().is_reflected()
+    }
+    fn reflect(&self, entity: EntityId) -> Option<&dyn Reflect> {
+        self.get().reflect(entity)
+    }
+    fn reflect_mut(&mut self, entity: EntityId) -> Option<&mut dyn Reflect> {
+        self.get_mut().reflect_mut(entity)
+    }
+    #[cfg(feature = "serde1")]
+    fn serialize_component(
+        &self,
+        entity: EntityId,
+        serializer: &mut dyn crate::erased_serde::Serializer,
+    ) -> crate::erased_serde::Result<crate::erased_serde::Ok> {
+        self.get().serialize_component(entity, serializer)
+    }
+    #[cfg(feature = "serde1")]
+    fn deserialize_component(
+        &mut self,
+        entity: EntityId,
+        deserializer: &mut dyn crate::erased_serde::Deserializer<'_>,
+    ) -> Result<(), crate::erased_serde::Error> {
+        self.get_mut().deserialize_component(entity, deserializer)
+    }
+    #[cfg(feature = "serde1")]
+    fn map_entity_ids(&mut self, entities: &[EntityId], map: &dyn Fn(EntityId) -> EntityId) {
+        self.get_mut().map_entity_ids(entities, map);
+    }
+    #[cfg(feature = "serde1")]
+    fn remap_entities(&mut self, map: &dyn Fn(EntityId) -> EntityId) {
+        self.get_mut().remap_entities(map);
+    }
+    #[cfg(feature = "serde1")]
+    fn append(&mut self, other: &mut dyn UnknownStorage) {
+        self.get_mut().append(other);
+    }
+    #[cfg(feature = "serde1")]
+    fn start_recording(&mut self) {
+        // forks start without serde setup
+        if let Some(owned) = &mut self.owned {
+            owned.start_recording();
+        }
+    }
+    #[cfg(feature = "serde1")]
+    fn stop_recording(&mut self) -> Vec<(u64, RecordedCommand)> {
+        match &mut self.owned {
+            Some(owned) => owned.stop_recording(),
+            None => Vec::new(),
+        }
+    }
+}
+
+/// State of all storages supporting snapshots at a given time.
+// Each storage's unique borrow count is kept to share its snapshot with the next one if it wasn't modified.
+struct Snapshot(HashMap<StorageId, (usize, StorageSnapshot)>);
+
+impl Snapshot {
+    fn take(
+        all_storages: &mut AllStorages,
+        previous: Option<&Snapshot>,
+    ) -> Result<Self, error::Borrow> {
+        let mut snapshot = HashMap::new();
+
+        for (&storage_id, storage) in all_storages.storages().iter() {
+            let unique_borrows = storage.0.unique_borrows();
+
+            match previous.and_then(|previous| previous.0.get(&storage_id)) {
+                Some((previous_borrows, previous)) if *previous_borrows == unique_borrows => {
+                    snapshot.insert(storage_id, (unique_borrows, previous.clone()));
+                }
+                _ => {
+                    if let Some(storage_snapshot) = storage.0.try_borrow()?.snapshot() {
+                        snapshot.insert(storage_id, (unique_borrows, storage_snapshot));
+                    }
+                }
+            }
+        }
+
+        Ok(Snapshot(snapshot))
+    }
+    fn restore(&self, all_storages: &mut AllStorages) -> Result<(), error::Borrow> {
+        let mut restored = Vec::new();
+
+        for (&storage_id, storage) in all_storages.storages().iter() {
+            match self.0.get(&storage_id) {
+                // the storage wasn't modified since the snapshot
+                Some((unique_borrows, _)) if *unique_borrows == storage.0.unique_borrows() => {}
+                Some((_, storage_snapshot)) => {
+                    storage.0.try_borrow_mut()?.restore(Some(storage_snapshot));
+                    restored.push(storage_id);
+                }
+                // storage created after the snapshot
+                None => {
+                    storage.0.try_borrow_mut()?.restore(None);
+                    restored.push(storage_id);
+                }
+            }
+        }
+
+        for pack in self.broken_packs(all_storages, restored)? {
+            repack(all_storages, &pack)?;
+        }
+
+        Ok(())
+    }
+    /// Returns the packs of `restored` storages that include a storage outside this snapshot.  
+    /// That storage kept its current state, the pack has to be rebuilt to match it.
+    fn broken_packs(
+        &self,
+        all_storages: &mut AllStorages,
+        restored: Vec<StorageId>,
+    ) -> Result<Vec<Vec<TypeId>>, error::Borrow> {
+        let storages = all_storages.storages();
+        let mut pack_owners = Vec::new();
+
+        for storage_id in restored {
+            if let Some(storage) = storages.get_mut(&storage_id) {
+                let storage = storage.0.try_borrow()?;
+
+                pack_owners.extend(
+                    storage
+                        .observer_types()
+                        .iter()
+                        .map(|&type_id| StorageId::from(type_id)),
+                );
+                pack_owners.push(storage_id);
+            }
+        }
+
+        let mut packs: Vec<Vec<TypeId>> = Vec::new();
+
+        for storage_id in pack_owners {
+            if let Some(storage) = storages.get_mut(&storage_id) {
+                let pack = storage.0.try_borrow()?.pack_types();
+
+                if !packs.contains(&pack)
+                    && pack
+                        .iter()
+                        .any(|&type_id| !self.0.contains_key(&type_id.into()))
+                {
+                    packs.push(pack);
+                }
+            }
+        }
+
+        Ok(packs)
+    }
+}
+
+/// Packs all entities with a component in every storage of `pack`.
+fn repack(all_storages: &mut AllStorages, pack: &[TypeId]) -> Result<(), error::Borrow> {
+    let storages = all_storages.storages();
+    let mut entities: Option<Vec<EntityId>> = None;
+
+    for &type_id in pack {
+        if let Some(storage) = storages.get_mut(&type_id.into()) {
+            let storage = storage.0.try_borrow()?;
+
+            entities = Some(match entities {
+                Some(entities) => entities
+                    .into_iter()
+                    .filter(|&entity| storage.has_component(entity))
+                    .collect(),
+                None => storage.component_entities().to_vec(),
+            });
+        }
+    }
+
+    let entities = entities.unwrap_or_default();
+
+    for &type_id in pack {
+        if let Some(storage) = storages.get_mut(&type_id.into()) {
+            storage.0.try_borrow_mut()?.repack(&entities);
+        }
+    }
+
+    Ok(())
+}
+
+/// Keeps the snapshots to undo and redo.
+pub(crate) struct Snapshots {
+    undo: VecDeque<Snapshot>,
+    redo: Vec<Snapshot>,
+    capacity: usize,
+    // last fork, storages not modified since then are shared with the next one
+    fork: Option<Snapshot>,
+}
+
+impl Default for Snapshots {
+    fn default() -> Self {
+        Snapshots {
+            undo: VecDeque::new(),
+            redo: Vec::new(),
+            capacity: DEFAULT_CAPACITY,
+            fork: None,
+        }
+    }
+}
+
+impl Snapshots {
+    /// Returns the most recent snapshot, to share its unmodified storages.
+    fn last(&self) -> Option<&Snapshot> {
+        self.redo.last().or_else(|| self.undo.back())
+    }
+    pub(crate) fn push(&mut self, all_storages: &mut AllStorages) -> Result<(), error::Borrow> {
+        let snapshot = Snapshot::take(all_storages, self.last())?;
+
+        self.redo.clear();
+        self.undo.push_back(snapshot);
+        self.truncate();
+
+        Ok(())
+    }
+    pub(crate) fn undo(&mut self, all_storages: &mut AllStorages) -> Result<bool, error::Borrow> {
+        if self.undo.is_empty() {
+            return Ok(false);
+        }
+
+        let current = Snapshot::take(all_storages, self.last())?;
+        self.undo.pop_back().unwrap().restore(all_storages)?;
+        self.redo.push(current);
+
+        Ok(true)
+    }
+    pub(crate) fn redo(&mut self, all_storages: &mut AllStorages) -> Result<bool, error::Borrow> {
+        if self.redo.is_empty() {
+            return Ok(false);
+        }
+
+        let current = Snapshot::take(all_storages, self.last())?;
+        self.redo.pop().unwrap().restore(all_storages)?;
+        self.undo.push_back(current);
+        self.truncate();
+
+        Ok(true)
+    }
+    /// Returns a new `AllStorages` sharing the state of all storages supporting snapshots.
+    pub(crate) fn fork(
+        &mut self,
+        all_storages: &mut AllStorages,
+    ) -> Result<AllStorages, error::Borrow> {
+        let previous = self.fork.as_ref().or_else(|| self.last());
+        let snapshot = Snapshot::take(all_storages, previous)?;
+
+        let fork = AllStorages::from_shared(
+            snapshot
+                .0
+                .iter()
+                .map(|(&storage_id, (_, storage_snapshot))| (storage_id, storage_snapshot.clone()))
+                .collect(),
+        );
+
+        self.fork = Some(snapshot);
+
+        Ok(fork)
+    }
+    pub(crate) fn set_capacity(&mut self, capacity: usize) {
+        self.capacity = capacity;
+        self.truncate();
+    }
+    fn truncate(&mut self) {
+        while self.undo.len() > self.capacity {
+            self.undo.pop_front();
+        }
+    }
+}
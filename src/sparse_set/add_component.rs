@@ -83,6 +83,7 @@ impl<T: 'static> AddComponentUnchecked<T> for &mut ViewMut<'_, T> {
         }
     }
     #[cfg(feature = "panic")]
+    #[track_caller]
     fn add_component_unchecked(self, component: T, entity: EntityId) {
         self.try_add_component_unchecked(component, entity).unwrap();
     }
@@ -148,6 +149,7 @@ macro_rules! impl_add_component_unchecked {
                     Ok(())
             }
             #[cfg(feature = "panic")]
+            #[track_caller]
             fn add_component_unchecked(self, component: ($($type,)+), entity: EntityId) {
                 self.try_add_component_unchecked(component, entity).unwrap();
             }
@@ -170,4 +172,7 @@ macro_rules! add_component_unchecked {
     }
 }
 
+#[cfg(not(feature = "extended_tuple"))]
 add_component_unchecked![(A, 0);; (B, 1) (C, 2) (D, 3) (E, 4) (F, 5) (G, 6) (H, 7) (I, 8) (J, 9)];
+#[cfg(feature = "extended_tuple")]
+add_component_unchecked![(A, 0);; (B, 1) (C, 2) (D, 3) (E, 4) (F, 5) (G, 6) (H, 7) (I, 8) (J, 9) (K, 10) (L, 11) (M, 12) (N, 13) (O, 14) (P, 15)];
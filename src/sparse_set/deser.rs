@@ -1,8 +1,14 @@
 use super::{Metadata, SparseArray, SparseSet};
-use crate::serde_setup::{GlobalDeConfig, GlobalSerConfig};
+use crate::serde_setup::{GlobalDeConfig, GlobalSerConfig, WithShared};
 use crate::storage::EntityId;
 use core::marker::PhantomData;
 
+/// Deserializes a component saved with the layout of an older version.
+pub(super) type Migration<T> = fn(
+    u32,
+    &mut dyn crate::erased_serde::Deserializer<'_>,
+) -> Result<T, crate::erased_serde::Error>;
+
 #[allow(unused)]
 pub(crate) struct SparseSetSerializer<'a, T> {
     pub(crate) sparse_set: &'a SparseSet<T>,
@@ -10,6 +16,61 @@ pub(crate) struct SparseSetSerializer<'a, T> {
 }
 
 impl<T> serde::Serialize for SparseSetSerializer<'_, T>
+where
+    T: serde::Serialize,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::{SerializeStruct, SerializeTuple};
+
+        let (ids, data): (&[EntityId], &[T]) = match &self.sparse_set.metadata.serde {
+            Some(serde_infos) if serde_infos.transient => (&[], &[]),
+            _ => (&self.sparse_set.dense, &self.sparse_set.data),
+        };
+        let with_shared = match (self.ser_config.with_shared, &self.sparse_set.metadata.serde) {
+            (_, Some(serde_infos)) if serde_infos.transient => false,
+            (WithShared::All, _) => true,
+            (WithShared::None, _) => false,
+            (WithShared::PerStorage, Some(serde_infos)) => serde_infos.with_shared,
+            (WithShared::PerStorage, None) => false,
+        };
+        let shared = if with_shared {
+            self.sparse_set.shared_ids()
+        } else {
+            Vec::new()
+        };
+
+        // named fields are easier to read, binary formats don't need them
+        if serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("SparseSet", 3)?;
+            state.serialize_field("ids", ids)?;
+            state.serialize_field("data", data)?;
+            state.serialize_field("shared", &shared)?;
+            state.end()
+        } else {
+            let mut state = serializer.serialize_tuple(3)?;
+            state.serialize_element(ids)?;
+            state.serialize_element(data)?;
+            state.serialize_element(&shared)?;
+            state.end()
+        }
+    }
+}
+
+/// Part of a storage returned by [Window::serialize_chunks], `data[i]` is the component of `ids[i]`.  
+/// Serialized as a struct with `ids` and `data` fields.
+///
+/// [Window::serialize_chunks]: struct.Window.html#method.serialize_chunks
+#[cfg_attr(docsrs, doc(cfg(feature = "serde1")))]
+#[derive(Clone, Copy)]
+pub struct SerializeChunk<'a, T> {
+    pub ids: &'a [EntityId],
+    pub data: &'a [T],
+}
+
+impl<T> serde::Serialize for SerializeChunk<'_, T>
 where
     T: serde::Serialize,
 {
@@ -19,15 +80,17 @@ where
     {
         use serde::ser::SerializeStruct;
 
-        let mut state = serializer.serialize_struct("SparseSet", 2)?;
-        state.serialize_field("ids", &self.sparse_set.dense)?;
-        state.serialize_field("data", &self.sparse_set.data)?;
+        let mut state = serializer.serialize_struct("SerializeChunk", 2)?;
+        state.serialize_field("ids", self.ids)?;
+        state.serialize_field("data", self.data)?;
         state.end()
     }
 }
 
 pub(super) struct SparseSetDeserializer<T> {
     pub(super) de_config: GlobalDeConfig,
+    // version the components were saved with and the function upgrading them
+    pub(super) migration: Option<(u32, Migration<T>)>,
     pub(super) _phantom: PhantomData<T>,
 }
 
@@ -41,11 +104,12 @@ where
     where
         D: serde::Deserializer<'de>,
     {
-        const FIELDS: &'static [&'static str] = &["ids", "data"];
+        const FIELDS: &'static [&'static str] = &["ids", "data", "shared"];
 
         enum Field {
             Ids,
             Data,
+            Shared,
         }
 
         struct FieldVisitor;
@@ -63,9 +127,10 @@ where
                 match value {
                     0u64 => Ok(Field::Ids),
                     1u64 => Ok(Field::Data),
+                    2u64 => Ok(Field::Shared),
                     _ => Err(serde::de::Error::invalid_value(
                         serde::de::Unexpected::Unsigned(value),
-                        &"field index 0 <= i < 2",
+                        &"field index 0 <= i < 3",
                     )),
                 }
             }
@@ -77,6 +142,7 @@ where
                 match value {
                     "ids" => Ok(Field::Ids),
                     "data" => Ok(Field::Data),
+                    "shared" => Ok(Field::Shared),
                     _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                 }
             }
@@ -88,9 +154,10 @@ where
                 match value {
                     b"ids" => Ok(Field::Ids),
                     b"data" => Ok(Field::Data),
+                    b"shared" => Ok(Field::Shared),
                     _ => Err(serde::de::Error::invalid_value(
                         serde::de::Unexpected::Bytes(value),
-                        &"field are `ids` and `data`",
+                        &"field are `ids`, `data` and `shared`",
                     )),
                 }
             }
@@ -114,6 +181,7 @@ where
             marker: PhantomData<SparseSet<T>>,
             lifetime: PhantomData<&'de ()>,
             de_config: GlobalDeConfig,
+            migration: Option<(u32, Migration<T>)>,
         }
 
         impl<'de, T> serde::de::Visitor<'de> for Visitor<'de, T>
@@ -131,21 +199,32 @@ where
             where
                 A: serde::de::SeqAccess<'de>,
             {
-                let sparse = SparseArray::new();
-                let dense = seq.next_element()?.ok_or_else(|| {
-                    serde::de::Error::invalid_length(0, &"struct SparseSet with 2 elements")
+                let dense: Vec<EntityId> = seq.next_element()?.ok_or_else(|| {
+                    serde::de::Error::invalid_length(0, &"struct SparseSet with 3 elements")
                 })?;
-                let data = seq.next_element()?.ok_or_else(|| {
-                    serde::de::Error::invalid_length(1usize, &"struct SparseSet with 2 elements")
+                let data = match self.migration {
+                    Some((version, migrate)) => {
+                        seq.next_element_seed(MigratedData { version, migrate })?
+                    }
+                    None => seq.next_element()?,
+                }
+                .ok_or_else(|| {
+                    serde::de::Error::invalid_length(1usize, &"struct SparseSet with 3 elements")
                 })?;
+                // saves made before shared components were serialized don't have them
+                let shared = seq.next_element()?.unwrap_or_default();
                 let metadata = Metadata::default();
 
-                Ok(SparseSet {
-                    sparse,
-                    dense,
-                    data,
-                    metadata,
-                })
+                Ok(share_all(
+                    SparseSet {
+                        sparse: sparse_from_dense(&dense),
+                        dense,
+                        data,
+                        metadata,
+                    },
+                    shared,
+                    self.de_config,
+                ))
             }
 
             #[inline]
@@ -155,6 +234,7 @@ where
             {
                 let mut dense: Option<Vec<EntityId>> = None;
                 let mut data: Option<Vec<T>> = None;
+                let mut shared: Option<Vec<(EntityId, EntityId)>> = None;
 
                 while let Some(key) = map.next_key::<Field>()? {
                     match key {
@@ -168,38 +248,139 @@ where
                             if data.is_some() {
                                 return Err(serde::de::Error::duplicate_field("data"));
                             }
-                            data = Some(map.next_value()?);
+                            data = Some(match self.migration {
+                                Some((version, migrate)) => {
+                                    map.next_value_seed(MigratedData { version, migrate })?
+                                }
+                                None => map.next_value()?,
+                            });
+                        }
+                        Field::Shared => {
+                            if shared.is_some() {
+                                return Err(serde::de::Error::duplicate_field("shared"));
+                            }
+                            shared = Some(map.next_value()?);
                         }
                     }
                 }
                 let dense = dense.ok_or_else(|| serde::de::Error::missing_field("dense"))?;
                 let data = data.ok_or_else(|| serde::de::Error::missing_field("data"))?;
 
-                let mut sparse: SparseArray<[usize; super::BUCKET_SIZE]> = SparseArray::new();
-                for (i, &id) in dense.iter().enumerate() {
-                    sparse.allocate_at(id);
-                    unsafe {
-                        sparse.set_sparse_index_unchecked(id, i);
-                    }
-                }
-
-                Ok(SparseSet {
-                    sparse,
-                    dense,
-                    data,
-                    metadata: Default::default(),
-                })
+                Ok(share_all(
+                    SparseSet {
+                        sparse: sparse_from_dense(&dense),
+                        dense,
+                        data,
+                        metadata: Default::default(),
+                    },
+                    shared.unwrap_or_default(),
+                    self.de_config,
+                ))
             }
         }
 
-        deserializer.deserialize_struct(
-            "SparseSet",
-            FIELDS,
-            Visitor {
-                marker: PhantomData::<SparseSet<T>>,
-                lifetime: PhantomData,
-                de_config: self.de_config,
-            },
+        let visitor = Visitor {
+            marker: PhantomData::<SparseSet<T>>,
+            lifetime: PhantomData,
+            de_config: self.de_config,
+            migration: self.migration,
+        };
+
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_struct("SparseSet", FIELDS, visitor)
+        } else {
+            deserializer.deserialize_tuple(3, visitor)
+        }
+    }
+}
+
+/// Shares the components listed in `shared` unless `de_config` leaves them out.
+fn share_all<T>(
+    mut sparse_set: SparseSet<T>,
+    shared: Vec<(EntityId, EntityId)>,
+    de_config: GlobalDeConfig,
+) -> SparseSet<T> {
+    if de_config.with_shared != WithShared::None {
+        for (shared, owned) in shared {
+            // an entity owning a component can't share another one
+            let _ = sparse_set.try_share(owned, shared);
+        }
+    }
+
+    sparse_set
+}
+
+/// Deserializes components saved with an older layout, `migrate` upgrades each of them.
+struct MigratedData<T> {
+    version: u32,
+    migrate: Migration<T>,
+}
+
+impl<'de, T> serde::de::DeserializeSeed<'de> for MigratedData<T> {
+    type Value = Vec<T>;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_seq(self)
+    }
+}
+
+impl<'de, T> serde::de::Visitor<'de> for MigratedData<T> {
+    type Value = Vec<T>;
+
+    fn expecting(&self, formatter: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        formatter.write_str("a sequence of components")
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: serde::de::SeqAccess<'de>,
+    {
+        let mut data = Vec::with_capacity(seq.size_hint().unwrap_or(0));
+
+        while let Some(component) = seq.next_element_seed(MigratedComponent {
+            version: self.version,
+            migrate: self.migrate,
+        })? {
+            data.push(component);
+        }
+
+        Ok(data)
+    }
+}
+
+struct MigratedComponent<T> {
+    version: u32,
+    migrate: Migration<T>,
+}
+
+impl<'de, T> serde::de::DeserializeSeed<'de> for MigratedComponent<T> {
+    type Value = T;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        (self.migrate)(
+            self.version,
+            &mut <dyn crate::erased_serde::Deserializer<'_>>::erase(deserializer),
         )
+        .map_err(serde::de::Error::custom)
+    }
+}
+
+/// Returns a sparse array pointing to each entity's index in `dense`.
+pub(super) fn sparse_from_dense(dense: &[EntityId]) -> SparseArray<[usize; super::BUCKET_SIZE]> {
+    let mut sparse: SparseArray<[usize; super::BUCKET_SIZE]> = SparseArray::new();
+
+    for (i, &id) in dense.iter().enumerate() {
+        sparse.allocate_at(id);
+        unsafe {
+            sparse.set_sparse_index_unchecked(id, i);
+        }
     }
+
+    sparse
 }
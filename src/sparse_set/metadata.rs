@@ -1,14 +1,27 @@
 #[cfg(feature = "serde1")]
-use super::{SparseSet, SparseSetDeserializer};
+use super::deser::Migration;
+use super::SparseSet;
+#[cfg(feature = "serde1")]
+use super::SparseSetDeserializer;
 #[cfg(feature = "serde1")]
 use crate::atomic_refcell::AtomicRefCell;
+use crate::event_log::StructuralEvent;
+use crate::index::{Index, IndexInfos, UnknownIndex};
+#[cfg(feature = "serde1")]
+use crate::recording::{Command, RecordInfos};
+use crate::reflect::ReflectInfos;
 #[cfg(feature = "serde1")]
-use crate::serde_setup::{GlobalDeConfig, GlobalSerConfig};
+use crate::serde_setup::{
+    GlobalDeConfig, GlobalSerConfig, Identifier, MapEntityIds, Migrate, SerConfig,
+};
+use crate::snapshot::SnapshotInfos;
 use crate::sparse_set::SparseArray;
 use crate::storage::EntityId;
-#[cfg(feature = "serde1")]
+use crate::storage::Members;
 use crate::storage::Storage;
+use crate::storage::StorageId;
 use crate::type_id::TypeId;
+use alloc::boxed::Box;
 use alloc::sync::Arc;
 use alloc::vec::Vec;
 
@@ -29,14 +42,38 @@ impl<T> Pack<T> {
             _ => false,
         }
     }
+    /// Returns the update pack if it keeps track of modified components.
+    pub(crate) fn modification_tracking_mut(&mut self) -> Option<&mut UpdatePack<T>> {
+        match self {
+            Pack::Update(pack) if pack.tracking.modification() => Some(pack),
+            _ => None,
+        }
+    }
 }
 
 pub struct Metadata<T> {
     pub(crate) pack: Pack<T>,
     pub(crate) observer_types: Vec<TypeId>,
     pub(crate) shared: SparseArray<[EntityId; BUCKET_SIZE]>,
+    pub(crate) preserve_order: bool,
+    // maximum number of components, set when the World has a fixed capacity
+    pub(crate) fixed_capacity: Option<usize>,
+    pub(crate) snapshot: Option<SnapshotInfos<T>>,
+    // copies the storage for World::deep_clone, requires T: Clone
+    pub(crate) clone: Option<fn(&SparseSet<T>) -> Storage>,
+    // field access by name for World::set_field, requires T: Reflect
+    pub(crate) reflect: Option<ReflectInfos<T>>,
+    // entities grouped by a key derived from their component, see SparseSet::setup_index
+    pub(crate) index: Option<Box<dyn UnknownIndex<T>>>,
     #[cfg(feature = "serde1")]
     pub(crate) serde: Option<SerdeInfos<T>>,
+    // commands recorded since the recording started
+    #[cfg(feature = "serde1")]
+    pub(crate) record: Option<Vec<(u64, Command<T>)>>,
+    // id of this storage and events logged since the last drain, see World::enable_event_log
+    pub(crate) events: Option<(StorageId, Vec<(usize, StructuralEvent)>)>,
+    // entities this storage references in the table of the entities' storages, see AllStorages::delete
+    pub(crate) signatures: Option<Arc<Members>>,
 }
 
 impl<T> Default for Metadata<T> {
@@ -45,12 +82,33 @@ impl<T> Default for Metadata<T> {
             pack: Pack::NoPack,
             observer_types: Vec::new(),
             shared: SparseArray::new(),
+            preserve_order: false,
+            fixed_capacity: None,
+            snapshot: None,
+            clone: None,
+            reflect: None,
+            index: None,
             #[cfg(feature = "serde1")]
             serde: None,
+            #[cfg(feature = "serde1")]
+            record: None,
+            events: None,
+            signatures: None,
         }
     }
 }
 
+impl<T: 'static> Metadata<T> {
+    /// Returns the index if its key is `K`.
+    pub(crate) fn index<K: 'static>(&self) -> Option<&Index<K>> {
+        self.index
+            .as_ref()?
+            .as_any()
+            .downcast_ref::<IndexInfos<T, K>>()
+            .map(|infos| &infos.index)
+    }
+}
+
 impl<T> Metadata<T> {
     /// Returns `true` if enough storages were passed in
     pub(crate) fn has_all_storages(&self, components: &[TypeId], additionals: &[TypeId]) -> bool {
@@ -618,6 +676,7 @@ pub(crate) struct UpdatePack<T> {
     pub(crate) modified: usize,
     pub(crate) removed: Vec<EntityId>,
     pub(crate) deleted: Vec<(EntityId, T)>,
+    pub(crate) tracking: Tracking,
 }
 
 impl<T> UpdatePack<T> {
@@ -626,6 +685,33 @@ impl<T> UpdatePack<T> {
     }
 }
 
+/// What an update packed storage keeps track of, chosen with [SparseSet::update_pack_with] or [World::register_storage_tracked].  
+/// Each level adds bookkeeping to the previous one, storages that never need to react to changes shouldn't be update packed at all.
+///
+/// [SparseSet::update_pack_with]: struct.SparseSet.html#method.update_pack_with
+/// [World::register_storage_tracked]: struct.World.html#method.register_storage_tracked
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Tracking {
+    /// Only *inserted* components are tracked.
+    Insertion,
+    /// *Inserted* and *modified* components are tracked.
+    Modification,
+    /// *Inserted*, *modified*, *removed* and *deleted* components are tracked, deleted components are kept by value.
+    All,
+}
+
+impl Tracking {
+    pub(crate) fn modification(self) -> bool {
+        self != Tracking::Insertion
+    }
+    pub(crate) fn removal(self) -> bool {
+        self == Tracking::All
+    }
+}
+
+#[cfg(feature = "serde1")]
+type MapEntityIdsFn<T> = fn(&mut T, &dyn Fn(EntityId) -> EntityId);
+
 #[cfg(feature = "serde1")]
 #[allow(unused)]
 pub(crate) struct SerdeInfos<T> {
@@ -637,14 +723,29 @@ pub(crate) struct SerdeInfos<T> {
         -> Result<crate::erased_serde::Ok, crate::erased_serde::Error>,
     pub(crate) deserialization: fn(
         GlobalDeConfig,
+        u32,
         &mut dyn crate::erased_serde::Deserializer<'_>,
     ) -> Result<Storage, crate::erased_serde::Error>,
     pub(crate) with_shared: bool,
+    pub(crate) identifier: Option<Identifier>,
+    // components are left out of World::serialize
+    pub(crate) transient: bool,
+    // layout of the components, saved next to the storage by World::serialize
+    pub(crate) version: u32,
+    pub(crate) component_serialization:
+        fn(
+            &T,
+            &mut dyn crate::erased_serde::Serializer,
+        ) -> Result<crate::erased_serde::Ok, crate::erased_serde::Error>,
+    pub(crate) component_deserialization:
+        fn(&mut dyn crate::erased_serde::Deserializer<'_>) -> Result<T, crate::erased_serde::Error>,
+    pub(crate) map_entity_ids: Option<MapEntityIdsFn<T>>,
+    pub(crate) recording: Option<RecordInfos<T>>,
 }
 
 #[cfg(feature = "serde1")]
 impl<T: serde::Serialize + for<'de> serde::Deserialize<'de> + 'static> SerdeInfos<T> {
-    pub(super) fn new() -> Self {
+    pub(crate) fn new(ser_config: SerConfig) -> Self {
         SerdeInfos {
             serialization:
                 |sparse_set: &SparseSet<T>,
@@ -660,35 +761,119 @@ impl<T: serde::Serialize + for<'de> serde::Deserialize<'de> + 'static> SerdeInfo
                 },
             deserialization:
                 |de_config: GlobalDeConfig,
+                 _version: u32,
                  deserializer: &mut dyn crate::erased_serde::Deserializer<'_>| {
-                    #[cfg(feature = "std")]
-                    {
-                        Ok(Storage(Box::new(AtomicRefCell::new(
-                            serde::de::DeserializeSeed::deserialize(
-                                SparseSetDeserializer::<T> {
-                                    de_config,
-                                    _phantom: core::marker::PhantomData,
-                                },
-                                deserializer,
-                            )?,
-                            None,
-                            true,
-                        ))))
-                    }
-                    #[cfg(not(feature = "std"))]
-                    {
-                        Ok(Storage(Box::new(AtomicRefCell::new(
-                            serde::de::DeserializeSeed::deserialize(
-                                SparseSetDeserializer::<T> {
-                                    de_config,
-                                    _phantom: core::marker::PhantomData,
-                                },
-                                deserializer,
-                            )?,
-                        ))))
-                    }
+                    deserialize_storage(
+                        SparseSetDeserializer::<T> {
+                            de_config,
+                            migration: None,
+                            _phantom: core::marker::PhantomData,
+                        },
+                        None,
+                        deserializer,
+                    )
+                },
+            version: 0,
+            with_shared: ser_config.with_shared,
+            identifier: ser_config.identifier,
+            transient: ser_config.transient,
+            component_serialization:
+                |component: &T, serializer: &mut dyn crate::erased_serde::Serializer| {
+                    crate::erased_serde::Serialize::erased_serialize(component, serializer)
+                },
+            component_deserialization:
+                |deserializer: &mut dyn crate::erased_serde::Deserializer<'_>| {
+                    crate::erased_serde::deserialize(deserializer)
+                },
+            map_entity_ids: None,
+            recording: None,
+        }
+    }
+}
+
+#[cfg(feature = "serde1")]
+impl<T: Migrate + serde::Serialize + for<'de> serde::Deserialize<'de> + 'static> SerdeInfos<T> {
+    pub(crate) fn with_migration(ser_config: SerConfig) -> Self {
+        SerdeInfos {
+            deserialization:
+                |de_config: GlobalDeConfig,
+                 version: u32,
+                 deserializer: &mut dyn crate::erased_serde::Deserializer<'_>| {
+                    let migrate: Migration<T> =
+                        |version, deserializer| T::migrate(version, deserializer);
+
+                    deserialize_storage(
+                        SparseSetDeserializer::<T> {
+                            de_config,
+                            migration: if version == T::VERSION {
+                                None
+                            } else {
+                                Some((version, migrate))
+                            },
+                            _phantom: core::marker::PhantomData,
+                        },
+                        None,
+                        deserializer,
+                    )
                 },
-            with_shared: true,
+            version: T::VERSION,
+            ..SerdeInfos::new(ser_config)
         }
     }
 }
+
+#[cfg(feature = "serde1")]
+impl<T: MapEntityIds + serde::Serialize + for<'de> serde::Deserialize<'de> + 'static>
+    SerdeInfos<T>
+{
+    pub(crate) fn with_entity_ids(ser_config: SerConfig) -> Self {
+        SerdeInfos {
+            deserialization:
+                |de_config: GlobalDeConfig,
+                 _version: u32,
+                 deserializer: &mut dyn crate::erased_serde::Deserializer<'_>| {
+                    deserialize_storage(
+                        SparseSetDeserializer::<T> {
+                            de_config,
+                            migration: None,
+                            _phantom: core::marker::PhantomData,
+                        },
+                        Some(|component: &mut T, map| component.map_entity_ids(map)),
+                        deserializer,
+                    )
+                },
+            map_entity_ids: Some(|component: &mut T, map| component.map_entity_ids(map)),
+            ..SerdeInfos::new(ser_config)
+        }
+    }
+}
+
+/// Deserializes a `SparseSet<T>` and wraps it in a `Storage`.  
+/// With `map_entity_ids` the loaded components can still be updated when their entities get a new `EntityId`.
+#[cfg(feature = "serde1")]
+fn deserialize_storage<T: serde::Serialize + for<'de> serde::Deserialize<'de> + 'static>(
+    sparse_set_deserializer: SparseSetDeserializer<T>,
+    map_entity_ids: Option<MapEntityIdsFn<T>>,
+    deserializer: &mut dyn crate::erased_serde::Deserializer<'_>,
+) -> Result<Storage, crate::erased_serde::Error> {
+    let mut sparse_set =
+        serde::de::DeserializeSeed::deserialize(sparse_set_deserializer, deserializer)?;
+
+    if map_entity_ids.is_some() {
+        sparse_set.metadata.serde = Some(SerdeInfos {
+            map_entity_ids,
+            ..SerdeInfos::new(SerConfig::default())
+        });
+    }
+
+    #[cfg(feature = "std")]
+    {
+        Ok(Storage(Box::new(AtomicRefCell::new(
+            sparse_set, None, true,
+        ))))
+    }
+    #[cfg(not(feature = "std"))]
+    {
+        Ok(Storage(Box::new(AtomicRefCell::new(sparse_set))))
+    }
+}
@@ -10,8 +10,11 @@ mod windows;
 
 pub use add_component::AddComponentUnchecked;
 pub use contains::Contains;
+pub use metadata::Tracking;
 pub use windows::{Window, WindowMut, WindowSort1};
 
+#[cfg(feature = "serde1")]
+pub use deser::SerializeChunk;
 #[cfg(feature = "serde1")]
 pub(crate) use deser::SparseSetSerializer;
 #[cfg(feature = "serde1")]
@@ -23,19 +26,33 @@ pub(crate) use view_add_entity::ViewAddEntity;
 pub(crate) use windows::RawWindowMut;
 
 use crate::error;
+use crate::event_log::StructuralEvent;
+use crate::index::{Index, IndexInfos};
+#[cfg(feature = "serde1")]
+use crate::recording::{Command, RecordInfos, RecordedCommand};
+use crate::reflect::{Reflect, ReflectInfos};
 #[cfg(feature = "serde1")]
-use crate::serde_setup::{GlobalDeConfig, GlobalSerConfig, SerConfig};
-use crate::storage::EntityId;
+use crate::serde_setup::{
+    GlobalDeConfig, GlobalSerConfig, Identifier, MapEntityIds, Migrate, SerConfig,
+};
+use crate::snapshot::{SnapshotInfos, StorageSnapshot};
+use crate::storage::{EntityId, Members, Signatures, Storage, StorageId};
 use crate::type_id::TypeId;
 use crate::unknown_storage::UnknownStorage;
+use alloc::boxed::Box;
 #[cfg(all(not(feature = "std"), feature = "serde1"))]
 use alloc::string::ToString;
+use alloc::sync::Arc;
 use alloc::vec::Vec;
 use core::any::{type_name, Any};
 use core::ptr;
 #[cfg(feature = "serde1")]
-use deser::SparseSetDeserializer;
-use sparse_array::{SparseArray, SparseSlice, SparseSliceMut};
+use deser::{sparse_from_dense, SparseSetDeserializer};
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+use sort::IntoSortable;
+pub(crate) use sparse_array::SparseArray;
+use sparse_array::{SparseSlice, SparseSliceMut};
 
 pub(crate) const BUCKET_SIZE: usize = 256 / core::mem::size_of::<usize>();
 
@@ -59,6 +76,14 @@ pub struct SparseSet<T> {
 }
 
 impl<T> SparseSet<T> {
+    /// Creates a `SparseSet` able to hold the ids of `entity_capacity` entities without reallocating its sparse array.
+    pub(crate) fn with_entity_capacity(entity_capacity: usize) -> Self {
+        let mut sparse_set = SparseSet::new();
+        sparse_set
+            .sparse
+            .reserve(entity_capacity.div_ceil(BUCKET_SIZE));
+        sparse_set
+    }
     pub(crate) fn new() -> Self {
         SparseSet {
             sparse: SparseArray::new(),
@@ -80,6 +105,132 @@ impl<T> SparseSet<T> {
     pub fn as_slice(&self) -> &[T] {
         &self.data
     }
+    /// Returns the ids of the entities owning the components of this storage, in the same order as [as_slice].
+    ///
+    /// [as_slice]: struct.SparseSet.html#method.as_slice
+    pub fn ids(&self) -> &[EntityId] {
+        &self.dense
+    }
+    /// Returns a parallel iterator over chunks of `size` components and the ids of their entities, the last chunk might be smaller.  
+    /// The chunks are split directly from the dense storage, it's the fastest way to modify all components of a single storage.  
+    /// When the storage tracks modification, all its components are flagged as modified.
+    ///
+    /// ### Panics
+    ///
+    /// - `size` is 0.
+    ///
+    /// ### Example
+    /// ```
+    /// use rayon::prelude::*;
+    /// use shipyard::{ViewMut, World};
+    ///
+    /// let world = World::new();
+    /// let entity = world.spawn((0u32,));
+    /// world.spawn((1u32,));
+    /// world.spawn((2u32,));
+    ///
+    /// world.run(|mut u32s: ViewMut<u32>| {
+    ///     u32s.par_slices(2).for_each(|(ids, u32s)| {
+    ///         assert_eq!(ids.len(), u32s.len());
+    ///
+    ///         for x in u32s {
+    ///             *x += 10;
+    ///         }
+    ///     });
+    ///
+    ///     assert_eq!(u32s[entity], 10);
+    /// });
+    /// ```
+    #[cfg(feature = "parallel")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "parallel")))]
+    pub fn par_slices(
+        &mut self,
+        size: usize,
+    ) -> impl IndexedParallelIterator<Item = (&[EntityId], &mut [T])> + '_
+    where
+        T: Send,
+    {
+        let len = self.len();
+        if let Some(pack) = self.metadata.pack.modification_tracking_mut() {
+            pack.modified = len - pack.inserted;
+        }
+
+        self.dense
+            .par_chunks(size)
+            .zip(self.data.par_chunks_mut(size))
+    }
+    /// Returns the components of this storage as bytes, without copying them.
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{EntitiesViewMut, ViewMut, World};
+    ///
+    /// let world = World::new();
+    ///
+    /// world.run(|mut entities: EntitiesViewMut, mut colors: ViewMut<[u8; 4]>| {
+    ///     entities.add_entity(&mut colors, [255, 0, 0, 255]);
+    ///     entities.add_entity(&mut colors, [0, 0, 255, 255]);
+    ///
+    ///     assert_eq!(colors.as_bytes(), &[255, 0, 0, 255, 0, 0, 255, 255]);
+    /// });
+    /// ```
+    #[cfg(feature = "bytemuck1")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "bytemuck1")))]
+    pub fn as_bytes(&self) -> &[u8]
+    where
+        T: bytemuck::Pod,
+    {
+        bytemuck::cast_slice(&self.data)
+    }
+    /// Returns the components owned by entities having all components of the storage's tight pack.  
+    /// They're in the same order in every storage of the pack, the matching ids are the start of [ids].  
+    /// Each slice can be copied as is to a GPU buffer for example.
+    ///
+    /// ### Errors
+    ///
+    /// - Storage isn't tightly packed.
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{EntitiesViewMut, TightPack, ViewMut, World};
+    ///
+    /// let world = World::new();
+    ///
+    /// world.run(
+    ///     |mut entities: EntitiesViewMut, mut positions: ViewMut<[f32; 2]>, mut colors: ViewMut<u32>| {
+    ///         (&mut positions, &mut colors).tight_pack();
+    ///
+    ///         let entity0 = entities.add_entity(&mut positions, [1.0, 2.0]);
+    ///         let entity1 = entities.add_entity((&mut positions, &mut colors), ([3.0, 4.0], 0xff));
+    ///
+    ///         assert_eq!(positions.try_tight_packed().unwrap(), &[[3.0, 4.0]]);
+    ///         assert_eq!(colors.try_tight_packed().unwrap(), &[0xff]);
+    ///         assert_eq!(&positions.ids()[..1], &[entity1]);
+    ///         assert_eq!(positions.ids()[1], entity0);
+    ///     },
+    /// );
+    /// ```
+    ///
+    /// [ids]: struct.SparseSet.html#method.ids
+    pub fn try_tight_packed(&self) -> Result<&[T], error::NotTightPack> {
+        self.window().try_tight_packed()
+    }
+    /// Returns the components owned by entities having all components of the storage's tight pack.  
+    /// They're in the same order in every storage of the pack, the matching ids are the start of [ids].  
+    /// Each slice can be copied as is to a GPU buffer for example.  
+    /// Unwraps errors.
+    ///
+    /// ### Errors
+    ///
+    /// - Storage isn't tightly packed.
+    ///
+    /// [ids]: struct.SparseSet.html#method.ids
+    #[cfg(feature = "panic")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "panic")))]
+    #[track_caller]
+    pub fn tight_packed(&self) -> &[T] {
+        self.try_tight_packed().unwrap()
+    }
     /// Returns a window over `range`.
     ///
     /// ### Errors
@@ -118,6 +269,7 @@ impl<T> SparseSet<T> {
     /// - `range` was out of bounds.
     #[cfg(feature = "panic")]
     #[cfg_attr(docsrs, doc(cfg(feature = "panic")))]
+    #[track_caller]
     pub fn as_window<R: core::ops::RangeBounds<usize>>(&self, range: R) -> Window<'_, T> {
         self.try_as_window(range).unwrap()
     }
@@ -165,6 +317,7 @@ impl<T> SparseSet<T> {
     /// - `range` was out of bounds.
     #[cfg(feature = "panic")]
     #[cfg_attr(docsrs, doc(cfg(feature = "panic")))]
+    #[track_caller]
     pub fn as_window_mut<R: core::ops::RangeBounds<usize>>(
         &mut self,
         range: R,
@@ -256,6 +409,7 @@ impl<T> SparseSet<T> {
     /// Unwraps errors.
     #[cfg(feature = "panic")]
     #[cfg_attr(docsrs, doc(cfg(feature = "panic")))]
+    #[track_caller]
     pub fn id_at(&self, index: usize) -> EntityId {
         self.try_id_at(index).unwrap()
     }
@@ -266,7 +420,7 @@ impl<T> SparseSet<T> {
     pub(crate) fn get_mut(&mut self, entity: EntityId) -> Option<&mut T> {
         match self.index_of(entity) {
             Some(mut index) => {
-                if let Pack::Update(pack) = &mut self.metadata.pack {
+                if let Some(pack) = self.metadata.pack.modification_tracking_mut() {
                     // index of the first element non modified
                     let non_mod = pack.inserted + pack.modified;
 
@@ -300,6 +454,37 @@ impl<T> SparseSet<T> {
             None => None,
         }
     }
+    /// Same as `get_mut` but doesn't flag the component as modified.
+    pub(crate) fn get_mut_untracked(&mut self, entity: EntityId) -> Option<&mut T> {
+        let index = self.index_of(entity)?;
+        Some(unsafe { self.data.get_unchecked_mut(index) })
+    }
+    /// Returns each entity sharing a component in this storage with the `EntityId` it points to.
+    pub fn shared_ids(&self) -> Vec<(EntityId, EntityId)> {
+        self.metadata
+            .shared
+            .iter_shared()
+            .filter_map(|(index, owned)| {
+                // the sparse array stores the generation of shared entities
+                let gen = self
+                    .sparse
+                    .sparse_index(EntityId::new_from_pair_unchecked(index, 0))?;
+
+                // an entity with this index owns a component
+                if self.dense.get(gen).map(|id| id.index()) == Some(index) {
+                    return None;
+                }
+
+                let shared = EntityId::new_from_pair_unchecked(index, gen as u16);
+
+                if self.shared_id(shared) == Some(owned) {
+                    Some((shared, owned))
+                } else {
+                    None
+                }
+            })
+            .collect()
+    }
     /// Returns the `EntityId` `shared` entity points to.
     ///
     /// Returns `None` if the entity isn't shared.
@@ -318,6 +503,37 @@ impl<T> SparseSet<T> {
 }
 
 impl<T> SparseSet<T> {
+    /// Logs an event if the event log is enabled.
+    fn log_event(&mut self, f: impl FnOnce(StorageId) -> StructuralEvent) {
+        if let Some((storage_id, events)) = &mut self.metadata.events {
+            events.push((crate::event_log::next_index(), f(*storage_id)));
+        }
+    }
+    /// Updates what this storage references at `index` in the signature table, if it's tracked.
+    fn update_signature(&self, index: u64, entry: Option<bool>) {
+        if let Some(members) = &self.metadata.signatures {
+            members.set(index as usize, entry);
+        }
+    }
+    /// Updates all entities this storage references in the signature table, if it's tracked.
+    fn sync_signatures(&self) {
+        if let Some(members) = &self.metadata.signatures {
+            self.reset_signatures(members);
+        }
+    }
+    /// Replaces everything `members` references with the entities of this storage.
+    pub(crate) fn reset_signatures(&self, members: &Members) {
+        let owned = self
+            .dense
+            .iter()
+            .map(|&entity| (entity.index() as usize, true));
+        let shared = self
+            .shared_ids()
+            .into_iter()
+            .map(|(shared, _)| (shared.index() as usize, false));
+
+        members.reset(owned.chain(shared));
+    }
     /// Inserts `value` in the `SparseSet`.
     ///
     /// If an `entity` with the same index but a greater generation already has a component of this type, does nothing and returns `None`.
@@ -334,6 +550,9 @@ impl<T> SparseSet<T> {
     /// In case `entity` had a component of this type, the new component will be considered `modified`.
     /// In all other cases it'll be considered `inserted`.
     pub(crate) fn insert(&mut self, value: T, entity: EntityId) -> Option<OldComponent<T>> {
+        #[cfg(feature = "serde1")]
+        self.record(|clone| Command::Add(entity, clone(&value)));
+
         self.sparse.allocate_at(entity);
 
         // at this point there can't be nothing at the sparse index
@@ -341,6 +560,8 @@ impl<T> SparseSet<T> {
 
         let (old_component, dense_index) = match old_index {
             core::usize::MAX => {
+                self.assert_capacity();
+
                 unsafe {
                     self.sparse
                         .set_sparse_index_unchecked(entity, self.dense.len());
@@ -381,6 +602,8 @@ impl<T> SparseSet<T> {
                         return None;
                     }
                 } else if entity.gen() >= dense_index as u64 {
+                    self.assert_capacity();
+
                     unsafe {
                         self.metadata
                             .shared
@@ -403,8 +626,18 @@ impl<T> SparseSet<T> {
             }
         };
 
+        if !matches!(old_component, Some(OldComponent::Owned(_))) {
+            self.log_event(|storage_id| StructuralEvent::ComponentAdded(entity, storage_id));
+            self.update_signature(entity.index(), Some(true));
+        }
+
         if let Pack::Update(pack) = &mut self.metadata.pack {
-            if dense_index >= pack.inserted + pack.modified {
+            let replaced = matches!(old_component, Some(OldComponent::Owned(_)));
+
+            // replaced components stay in place when modifications aren't tracked
+            if dense_index >= pack.inserted + pack.modified
+                && (!replaced || pack.tracking.modification())
+            {
                 self.dense.swap(pack.inserted + pack.modified, dense_index);
                 self.data.swap(pack.inserted + pack.modified, dense_index);
 
@@ -444,6 +677,12 @@ impl<T> SparseSet<T> {
             }
         }
 
+        if let Some(index) = &mut self.metadata.index {
+            // the component might have been moved by the update pack
+            let dense_index = self.sparse.sparse_index(entity).unwrap();
+            index.insert(entity, &self.data[dense_index]);
+        }
+
         old_component
     }
 }
@@ -467,7 +706,9 @@ impl<T> SparseSet<T> {
 
                     if let Some(OldComponent::Owned(_)) = &component {
                         if let Pack::Update(update) = &mut self.metadata.pack {
-                            update.removed.push(entity);
+                            if update.tracking.removal() {
+                                update.removed.push(entity);
+                            }
                         } else {
                             unreachable!()
                         }
@@ -489,12 +730,114 @@ impl<T> SparseSet<T> {
     /// - Storage is tightly or loosly packed.
     #[cfg(feature = "panic")]
     #[cfg_attr(docsrs, doc(cfg(feature = "panic")))]
+    #[track_caller]
     pub fn remove(&mut self, entity: EntityId) -> Option<OldComponent<T>>
     where
         T: 'static,
     {
         self.try_remove(entity).unwrap()
     }
+    /// Removes the component of each entity in `entities` from this storage.  
+    /// The removed components are returned in the same order as `entities`.
+    ///
+    /// ### Errors
+    ///
+    /// - Storage is tightly or loosly packed.
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{EntitiesViewMut, OldComponent, ViewMut, World};
+    ///
+    /// let world = World::new();
+    ///
+    /// world.run(|mut entities: EntitiesViewMut, mut u32s: ViewMut<u32>| {
+    ///     let entity0 = entities.add_entity(&mut u32s, 0);
+    ///     let entity1 = entities.add_entity(&mut u32s, 1);
+    ///     let entity2 = entities.add_entity((), ());
+    ///
+    ///     assert_eq!(
+    ///         u32s.try_remove_many(&[entity1, entity2, entity0]).unwrap(),
+    ///         vec![Some(OldComponent::Owned(1)), None, Some(OldComponent::Owned(0))]
+    ///     );
+    ///     assert!(u32s.is_empty());
+    /// });
+    /// ```
+    pub fn try_remove_many(
+        &mut self,
+        entities: &[EntityId],
+    ) -> Result<Vec<Option<OldComponent<T>>>, error::Remove>
+    where
+        T: 'static,
+    {
+        if self.metadata.observer_types.is_empty() {
+            match self.metadata.pack {
+                Pack::Tight(_) => Err(error::Remove::MissingPackStorage(type_name::<T>())),
+                Pack::Loose(_) => Err(error::Remove::MissingPackStorage(type_name::<T>())),
+                Pack::Update(_) => {
+                    let mut components = Vec::with_capacity(entities.len());
+
+                    for &entity in entities {
+                        let component = self.actual_remove(entity);
+
+                        if let Some(OldComponent::Owned(_)) = &component {
+                            if let Pack::Update(update) = &mut self.metadata.pack {
+                                if update.tracking.removal() {
+                                    update.removed.push(entity);
+                                }
+                            } else {
+                                unreachable!()
+                            }
+                        }
+
+                        components.push(component);
+                    }
+
+                    Ok(components)
+                }
+                Pack::NoPack => Ok(entities
+                    .iter()
+                    .map(|&entity| self.actual_remove(entity))
+                    .collect()),
+            }
+        } else {
+            Err(error::Remove::MissingPackStorage(type_name::<T>()))
+        }
+    }
+    /// Removes the component of each entity in `entities` from this storage.  
+    /// The removed components are returned in the same order as `entities`.  
+    /// Unwraps errors.
+    ///
+    /// ### Errors
+    ///
+    /// - Storage is tightly or loosly packed.
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{EntitiesViewMut, OldComponent, ViewMut, World};
+    ///
+    /// let world = World::new();
+    ///
+    /// world.run(|mut entities: EntitiesViewMut, mut u32s: ViewMut<u32>| {
+    ///     let entity0 = entities.add_entity(&mut u32s, 0);
+    ///     let entity1 = entities.add_entity(&mut u32s, 1);
+    ///     let entity2 = entities.add_entity((), ());
+    ///
+    ///     assert_eq!(
+    ///         u32s.remove_many(&[entity1, entity2, entity0]),
+    ///         vec![Some(OldComponent::Owned(1)), None, Some(OldComponent::Owned(0))]
+    ///     );
+    ///     assert!(u32s.is_empty());
+    /// });
+    /// ```
+    #[cfg(feature = "panic")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "panic")))]
+    #[track_caller]
+    pub fn remove_many(&mut self, entities: &[EntityId]) -> Vec<Option<OldComponent<T>>>
+    where
+        T: 'static,
+    {
+        self.try_remove_many(entities).unwrap()
+    }
     pub(crate) fn actual_remove(&mut self, entity: EntityId) -> Option<OldComponent<T>> {
         match self.sparse.sparse_index(entity) {
             Some(mut dense_index) if dense_index != core::usize::MAX => {
@@ -574,18 +917,47 @@ impl<T> SparseSet<T> {
                         Pack::NoPack => {}
                     }
 
-                    unsafe {
-                        // SAFE we're in bound
-                        let last = *self.dense.get_unchecked(self.dense.len() - 1);
-                        // SAFE dense can always index into sparse
-                        self.sparse.set_sparse_index_unchecked(last, dense_index);
-                        // SAFE we checked for OOB
-                        self.sparse
-                            .set_sparse_index_unchecked(entity, core::usize::MAX);
+                    let old_component = if self.metadata.preserve_order {
+                        // shifts all following components to keep their relative order
+                        for (i, &id) in self.dense[dense_index + 1..].iter().enumerate() {
+                            unsafe {
+                                // SAFE dense can always index into sparse
+                                self.sparse.set_sparse_index_unchecked(id, dense_index + i);
+                            }
+                        }
+
+                        unsafe {
+                            // SAFE we checked for OOB
+                            self.sparse.set_sparse_index_unchecked(entity, usize::MAX);
+                        }
+
+                        self.dense.remove(dense_index);
+                        self.data.remove(dense_index)
+                    } else {
+                        unsafe {
+                            // SAFE we're in bound
+                            let last = *self.dense.get_unchecked(self.dense.len() - 1);
+                            // SAFE dense can always index into sparse
+                            self.sparse.set_sparse_index_unchecked(last, dense_index);
+                            // SAFE we checked for OOB
+                            self.sparse.set_sparse_index_unchecked(entity, usize::MAX);
+                        }
+
+                        self.dense.swap_remove(dense_index);
+                        self.data.swap_remove(dense_index)
+                    };
+
+                    if let Some(index) = &mut self.metadata.index {
+                        index.remove(dense_id);
                     }
 
-                    self.dense.swap_remove(dense_index);
-                    let old_component = self.data.swap_remove(dense_index);
+                    #[cfg(feature = "serde1")]
+                    self.record(|_| Command::Remove(dense_id));
+
+                    self.log_event(|storage_id| {
+                        StructuralEvent::ComponentRemoved(dense_id, storage_id)
+                    });
+                    self.update_signature(dense_id.index(), None);
 
                     if dense_id == entity {
                         Some(OldComponent::Owned(old_component))
@@ -601,6 +973,8 @@ impl<T> SparseSet<T> {
                             .set_sparse_index_unchecked(entity, EntityId::dead());
                     }
 
+                    self.update_signature(entity.index(), None);
+
                     if dense_index == entity.gen() as usize {
                         Some(OldComponent::Shared)
                     } else {
@@ -643,6 +1017,7 @@ impl<T> SparseSet<T> {
     /// - Storage is tightly or loosly packed.
     #[cfg(feature = "panic")]
     #[cfg_attr(docsrs, doc(cfg(feature = "panic")))]
+    #[track_caller]
     pub fn delete(&mut self, entity: EntityId)
     where
         T: 'static,
@@ -652,7 +1027,9 @@ impl<T> SparseSet<T> {
     pub(crate) fn actual_delete(&mut self, entity: EntityId) {
         if let Some(OldComponent::Owned(component)) = self.actual_remove(entity) {
             if let Pack::Update(pack) = &mut self.metadata.pack {
-                pack.deleted.push((entity, component));
+                if pack.tracking.removal() {
+                    pack.deleted.push((entity, component));
+                }
             }
         }
     }
@@ -679,6 +1056,7 @@ impl<T> SparseSet<T> {
     /// - Storage isn't update packed.
     #[cfg(feature = "panic")]
     #[cfg_attr(docsrs, doc(cfg(feature = "panic")))]
+    #[track_caller]
     pub fn inserted(&self) -> Window<'_, T> {
         self.try_inserted().unwrap()
     }
@@ -703,6 +1081,7 @@ impl<T> SparseSet<T> {
     /// - Storage isn't update packed.
     #[cfg(feature = "panic")]
     #[cfg_attr(docsrs, doc(cfg(feature = "panic")))]
+    #[track_caller]
     pub fn inserted_mut(&mut self) -> WindowMut<'_, T> {
         self.try_inserted_mut().unwrap()
     }
@@ -729,6 +1108,7 @@ impl<T> SparseSet<T> {
     /// - Storage isn't update packed.
     #[cfg(feature = "panic")]
     #[cfg_attr(docsrs, doc(cfg(feature = "panic")))]
+    #[track_caller]
     pub fn modified(&self) -> Window<'_, T> {
         self.try_modified().unwrap()
     }
@@ -753,6 +1133,7 @@ impl<T> SparseSet<T> {
     /// - Storage isn't update packed.
     #[cfg(feature = "panic")]
     #[cfg_attr(docsrs, doc(cfg(feature = "panic")))]
+    #[track_caller]
     pub fn modified_mut(&mut self) -> WindowMut<'_, T> {
         self.try_modified_mut().unwrap()
     }
@@ -776,6 +1157,7 @@ impl<T> SparseSet<T> {
     /// - Storage isn't update packed.
     #[cfg(feature = "panic")]
     #[cfg_attr(docsrs, doc(cfg(feature = "panic")))]
+    #[track_caller]
     pub fn inserted_or_modified(&self) -> Window<'_, T> {
         self.try_inserted_or_modified().unwrap()
     }
@@ -802,6 +1184,7 @@ impl<T> SparseSet<T> {
     /// - Storage isn't update packed.
     #[cfg(feature = "panic")]
     #[cfg_attr(docsrs, doc(cfg(feature = "panic")))]
+    #[track_caller]
     pub fn inserted_or_modified_mut(&mut self) -> WindowMut<'_, T> {
         self.try_inserted_or_modified_mut().unwrap()
     }
@@ -825,6 +1208,7 @@ impl<T> SparseSet<T> {
     /// - Storage isn't update packed.
     #[cfg(feature = "panic")]
     #[cfg_attr(docsrs, doc(cfg(feature = "panic")))]
+    #[track_caller]
     pub fn deleted(&self) -> &[(EntityId, T)] {
         self.try_deleted().unwrap()
     }
@@ -848,6 +1232,7 @@ impl<T> SparseSet<T> {
     /// - Storage isn't update packed.
     #[cfg(feature = "panic")]
     #[cfg_attr(docsrs, doc(cfg(feature = "panic")))]
+    #[track_caller]
     pub fn removed(&self) -> &[EntityId] {
         self.try_removed().unwrap()
     }
@@ -867,6 +1252,7 @@ impl<T> SparseSet<T> {
     /// - Storage isn't update packed.
     #[cfg(feature = "panic")]
     #[cfg_attr(docsrs, doc(cfg(feature = "panic")))]
+    #[track_caller]
     pub fn take_deleted(&mut self) -> Vec<(EntityId, T)> {
         self.try_take_deleted().unwrap()
     }
@@ -886,6 +1272,7 @@ impl<T> SparseSet<T> {
     /// - Storage isn't update packed.
     #[cfg(feature = "panic")]
     #[cfg_attr(docsrs, doc(cfg(feature = "panic")))]
+    #[track_caller]
     pub fn take_removed(&mut self) -> Vec<EntityId> {
         self.try_take_removed().unwrap()
     }
@@ -905,6 +1292,7 @@ impl<T> SparseSet<T> {
     /// - Storage isn't update packed.
     #[cfg(feature = "panic")]
     #[cfg_attr(docsrs, doc(cfg(feature = "panic")))]
+    #[track_caller]
     pub fn clear_inserted(&mut self) {
         self.try_clear_inserted().unwrap()
     }
@@ -924,6 +1312,7 @@ impl<T> SparseSet<T> {
     /// - Storage isn't update packed.
     #[cfg(feature = "panic")]
     #[cfg_attr(docsrs, doc(cfg(feature = "panic")))]
+    #[track_caller]
     pub fn clear_modified(&mut self) {
         self.try_clear_modified().unwrap()
     }
@@ -943,6 +1332,7 @@ impl<T> SparseSet<T> {
     /// - Storage isn't update packed.
     #[cfg(feature = "panic")]
     #[cfg_attr(docsrs, doc(cfg(feature = "panic")))]
+    #[track_caller]
     pub fn clear_inserted_and_modified(&mut self) {
         self.try_clear_inserted_and_modified().unwrap()
     }
@@ -1004,34 +1394,96 @@ impl<T> SparseSet<T> {
         T: 'static,
     {
         match self.metadata.pack {
+            Pack::Update(_) => Ok(()),
+            _ => self.try_update_pack_with(Tracking::All),
+        }
+    }
+    /// Update packs this storage making it track *inserted*, *modified* and *deleted* components.  
+    /// Does nothing if the storage is already update packed.
+    /// Unwraps errors.  
+    ///
+    /// ### Errors
+    ///
+    /// - Storage is already tightly or loosly packed.
+    #[cfg(feature = "panic")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "panic")))]
+    #[track_caller]
+    pub fn update_pack(&mut self)
+    where
+        T: 'static,
+    {
+        self.try_update_pack().unwrap()
+    }
+    /// Update packs this storage making it track what `tracking` asks for.  
+    /// If the storage is already update packed, its tracking is replaced.
+    /// Modified, removed or deleted components no longer tracked are cleared.
+    ///
+    /// ### Errors
+    ///
+    /// - Storage is already tightly or loosly packed.
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{EntitiesViewMut, Tracking, ViewMut, World};
+    ///
+    /// let world = World::new();
+    ///
+    /// world.run(|mut entities: EntitiesViewMut, mut u32s: ViewMut<u32>| {
+    ///     u32s.update_pack_with(Tracking::Insertion);
+    ///
+    ///     let entity = entities.add_entity(&mut u32s, 0);
+    ///     u32s.clear_inserted();
+    ///
+    ///     u32s[entity] += 1;
+    ///     assert_eq!(u32s.modified().len(), 0);
+    /// });
+    /// ```
+    pub fn try_update_pack_with(&mut self, tracking: Tracking) -> Result<(), error::Pack>
+    where
+        T: 'static,
+    {
+        match &mut self.metadata.pack {
             Pack::NoPack => {
                 self.metadata.pack = Pack::Update(UpdatePack {
                     inserted: self.len(),
                     modified: 0,
                     removed: Vec::new(),
                     deleted: Vec::new(),
+                    tracking,
                 });
                 Ok(())
             }
             Pack::Tight(_) => Err(error::Pack::AlreadyTightPack(type_name::<T>())),
             Pack::Loose(_) => Err(error::Pack::AlreadyLoosePack(type_name::<T>())),
-            Pack::Update(_) => Ok(()),
+            Pack::Update(pack) => {
+                if !tracking.modification() {
+                    pack.modified = 0;
+                }
+                if !tracking.removal() {
+                    pack.removed.clear();
+                    pack.deleted.clear();
+                }
+                pack.tracking = tracking;
+                Ok(())
+            }
         }
     }
-    /// Update packs this storage making it track *inserted*, *modified* and *deleted* components.  
-    /// Does nothing if the storage is already update packed.
-    /// Unwraps errors.  
+    /// Update packs this storage making it track what `tracking` asks for.  
+    /// If the storage is already update packed, its tracking is replaced.
+    /// Modified, removed or deleted components no longer tracked are cleared.  
+    /// Unwraps errors.
     ///
     /// ### Errors
     ///
     /// - Storage is already tightly or loosly packed.
     #[cfg(feature = "panic")]
     #[cfg_attr(docsrs, doc(cfg(feature = "panic")))]
-    pub fn update_pack(&mut self)
+    #[track_caller]
+    pub fn update_pack_with(&mut self, tracking: Tracking)
     where
         T: 'static,
     {
-        self.try_update_pack().unwrap()
+        self.try_update_pack_with(tracking).unwrap()
     }
 }
 
@@ -1041,6 +1493,237 @@ impl<T> SparseSet<T> {
         self.dense.reserve(additional);
         self.data.reserve(additional);
     }
+    /// Releases the memory this storage doesn't use.  
+    /// Components outside of packs are also sorted by entity index, restoring iteration locality after many removals.  
+    /// Storages that [preserve_order] aren't sorted and storages with a [fixed_capacity] keep their memory.
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{AllStoragesViewMut, EntitiesViewMut, ViewMut, World};
+    ///
+    /// let world = World::new();
+    ///
+    /// let entities = world.run(|mut entities: EntitiesViewMut, mut u32s: ViewMut<u32>| {
+    ///     (0..100)
+    ///         .map(|i| entities.add_entity(&mut u32s, i))
+    ///         .collect::<Vec<_>>()
+    /// });
+    ///
+    /// world.run(|mut all_storages: AllStoragesViewMut| {
+    ///     for &entity in &entities[..97] {
+    ///         all_storages.delete(entity);
+    ///     }
+    /// });
+    ///
+    /// world.run(|mut u32s: ViewMut<u32>| {
+    ///     u32s.compact();
+    ///     assert_eq!(u32s.as_slice(), &[97, 98, 99]);
+    /// });
+    /// ```
+    ///
+    /// [preserve_order]: struct.SparseSet.html#method.preserve_order
+    /// [fixed_capacity]: struct.SparseSet.html#method.fixed_capacity
+    pub fn compact(&mut self) {
+        if let Pack::NoPack = self.metadata.pack {
+            if !self.metadata.preserve_order {
+                let mut transform: Vec<usize> = (0..self.dense.len()).collect();
+                // SAFE transform only contains valid dense indices
+                transform.sort_unstable_by_key(|&i| unsafe { self.dense.get_unchecked(i) }.index());
+
+                self.sort().apply_transform(transform);
+            }
+        }
+
+        if self.metadata.fixed_capacity.is_none() {
+            self.dense.shrink_to_fit();
+            self.data.shrink_to_fit();
+        }
+    }
+    /// Returns the maximum number of components this storage can hold, if its [World] was created with a fixed capacity.
+    ///
+    /// [World]: struct.World.html
+    pub fn fixed_capacity(&self) -> Option<usize> {
+        self.metadata.fixed_capacity
+    }
+    /// Allocates room for exactly `capacity` components and the ids of `entity_capacity` entities.  
+    /// Adding more than `capacity` components will then panic instead of reallocating.
+    pub(crate) fn set_fixed_capacity(&mut self, entity_capacity: usize, capacity: usize) {
+        let capacity = capacity.max(self.len());
+
+        if self.dense.capacity() != capacity {
+            let mut dense = Vec::with_capacity(capacity);
+            dense.append(&mut self.dense);
+            self.dense = dense;
+        }
+        if self.data.capacity() != capacity {
+            let mut data = Vec::with_capacity(capacity);
+            data.append(&mut self.data);
+            self.data = data;
+        }
+
+        self.sparse
+            .allocate_buckets(entity_capacity.div_ceil(BUCKET_SIZE));
+        self.metadata.fixed_capacity = Some(capacity);
+    }
+    /// Panics if this storage has a fixed capacity and is full.
+    fn assert_capacity(&self) {
+        if let Some(capacity) = self.metadata.fixed_capacity {
+            assert!(
+                self.dense.len() < capacity,
+                "{} storage is full, its fixed capacity is {} components.",
+                type_name::<T>(),
+                capacity
+            );
+        }
+    }
+    /// When `true`, removing or deleting a component shifts the following ones instead of moving the last one in its place.  
+    /// Components outside of packs are then iterated in insertion order, whatever the removal order was.  
+    /// Removal becomes O(n), it's meant for lockstep simulations where all peers have to iterate in the same order.  
+    /// Packing and sorting still reorder components.
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{EntitiesViewMut, ViewMut, World};
+    ///
+    /// let world = World::new();
+    ///
+    /// let (mut entities, mut u32s) = world.borrow::<(EntitiesViewMut, ViewMut<u32>)>();
+    ///
+    /// u32s.preserve_order(true);
+    ///
+    /// let entity0 = entities.add_entity(&mut u32s, 0);
+    /// entities.add_entity(&mut u32s, 1);
+    /// entities.add_entity(&mut u32s, 2);
+    ///
+    /// u32s.remove(entity0);
+    ///
+    /// assert_eq!(u32s.as_slice(), &[1, 2]);
+    /// ```
+    pub fn preserve_order(&mut self, preserve: bool) {
+        self.metadata.preserve_order = preserve;
+    }
+    /// Returns `true` if removing components keeps the insertion order.
+    pub fn is_order_preserved(&self) -> bool {
+        self.metadata.preserve_order
+    }
+    /// Makes this storage part of the snapshots taken with [World::push_snapshot].  
+    /// Undoing or redoing a snapshot will restore it to its state at the time.
+    ///
+    /// [World::push_snapshot]: struct.World.html#method.push_snapshot
+    pub fn setup_snapshot(&mut self)
+    where
+        T: Clone + Send + Sync + 'static,
+    {
+        self.metadata.snapshot = Some(SnapshotInfos::new());
+    }
+    /// Makes this storage part of the copies made with [World::deep_clone].  
+    /// Packs aren't kept, the copy only keeps this setup, [SparseSet::setup_snapshot] and [SparseSet::setup_index].
+    ///
+    /// [World::deep_clone]: struct.World.html#method.deep_clone
+    /// [SparseSet::setup_snapshot]: struct.SparseSet.html#method.setup_snapshot
+    /// [SparseSet::setup_index]: struct.SparseSet.html#method.setup_index
+    pub fn setup_clone(&mut self)
+    where
+        T: Clone + Send + Sync + 'static,
+    {
+        self.metadata.clone = Some(|sparse_set| {
+            // packs refer to other storages that might not be cloned
+            let mut clone = SparseSet::new();
+            clone.sparse = sparse_set.sparse.clone();
+            clone.dense = sparse_set.dense.clone();
+            clone.data = sparse_set.data.clone();
+            clone.metadata.shared = sparse_set.metadata.shared.clone();
+            clone.metadata.preserve_order = sparse_set.metadata.preserve_order;
+            clone.metadata.clone = sparse_set.metadata.clone;
+            clone.metadata.reflect = sparse_set.metadata.reflect;
+            if sparse_set.metadata.snapshot.is_some() {
+                clone.setup_snapshot();
+            }
+            if let Some(index) = &sparse_set.metadata.index {
+                let mut index = index.empty();
+                index.rebuild(&clone.dense, &clone.data);
+                clone.metadata.index = Some(index);
+            }
+
+            Storage::from_sparse_set(clone)
+        });
+    }
+    /// Lets [World::set_field] and [World::field] access the fields of this storage's components by name.
+    ///
+    /// [World::set_field]: struct.World.html#method.set_field
+    /// [World::field]: struct.World.html#method.field
+    pub fn setup_reflect(&mut self)
+    where
+        T: Reflect,
+    {
+        self.metadata.reflect = Some(ReflectInfos::new());
+    }
+    /// Groups this storage's entities by the key `key` derives from their component.  
+    /// The index is kept up to date when components are added, removed or deleted, lookups are then done with [SparseSet::index].  
+    /// Replaces the previous index if there was one.
+    ///
+    /// The key is read when the component is added, if a component is modified in place [SparseSet::reindex] has to be called.
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{EntitiesViewMut, ViewMut, World};
+    ///
+    /// #[derive(Clone, Copy, PartialEq, Eq, Hash)]
+    /// enum Faction {
+    ///     Red,
+    ///     Blue,
+    /// }
+    ///
+    /// struct Soldier {
+    ///     faction: Faction,
+    /// }
+    ///
+    /// let world = World::new();
+    ///
+    /// world.run(|mut entities: EntitiesViewMut, mut soldiers: ViewMut<Soldier>| {
+    ///     soldiers.setup_index(|soldier| soldier.faction);
+    ///
+    ///     let red = entities.add_entity(&mut soldiers, Soldier { faction: Faction::Red });
+    ///     entities.add_entity(&mut soldiers, Soldier { faction: Faction::Blue });
+    ///
+    ///     assert_eq!(soldiers.index::<Faction>().unwrap().get(&Faction::Red), &[red]);
+    /// });
+    /// ```
+    ///
+    /// [SparseSet::index]: struct.SparseSet.html#method.index
+    /// [SparseSet::reindex]: struct.SparseSet.html#method.reindex
+    pub fn setup_index<K>(&mut self, key: fn(&T) -> K)
+    where
+        T: 'static,
+        K: core::hash::Hash + Eq + Clone + Send + Sync + 'static,
+    {
+        let mut index = IndexInfos::new(key);
+        for (&entity, component) in self.dense.iter().zip(&self.data) {
+            crate::index::UnknownIndex::insert(&mut index, entity, component);
+        }
+
+        self.metadata.index = Some(Box::new(index));
+    }
+    /// Returns the index created with [SparseSet::setup_index] if its key is `K`.
+    ///
+    /// [SparseSet::setup_index]: struct.SparseSet.html#method.setup_index
+    pub fn index<K: 'static>(&self) -> Option<&Index<K>>
+    where
+        T: 'static,
+    {
+        self.metadata.index()
+    }
+    /// Reads the key of `entity`'s component again, after it was modified in place.  
+    /// Does nothing if the storage has no index or `entity` doesn't own a component.
+    pub fn reindex(&mut self, entity: EntityId) {
+        if let Some(index) = &mut self.metadata.index {
+            if let Some(dense_index) = self.sparse.sparse_index(entity) {
+                if self.dense.get(dense_index) == Some(&entity) {
+                    index.insert(entity, &self.data[dense_index]);
+                }
+            }
+        }
+    }
     /// Deletes all components in this storage.
     pub fn clear(&mut self) {
         for &id in &self.dense {
@@ -1048,6 +1731,27 @@ impl<T> SparseSet<T> {
                 self.sparse.set_sparse_index_unchecked(id, core::usize::MAX);
             }
         }
+        #[cfg(feature = "serde1")]
+        {
+            if let Some(record) = &mut self.metadata.record {
+                record.extend(
+                    self.dense
+                        .iter()
+                        .map(|&id| (crate::recording::next_index(), Command::Remove(id))),
+                );
+            }
+        }
+        if let Some((storage_id, events)) = &mut self.metadata.events {
+            events.extend(self.dense.iter().map(|&id| {
+                (
+                    crate::event_log::next_index(),
+                    StructuralEvent::ComponentRemoved(id, *storage_id),
+                )
+            }));
+        }
+        if let Some(index) = &mut self.metadata.index {
+            index.clear();
+        }
         match &mut self.metadata.pack {
             Pack::Tight(tight) => tight.len = 0,
             Pack::Loose(loose) => loose.len = 0,
@@ -1058,11 +1762,18 @@ impl<T> SparseSet<T> {
         }
         self.dense.clear();
         self.data.clear();
+
+        // entities sharing a component keep sharing it
+        self.sync_signatures();
     }
     /// Shares `owned`'s component with `shared` entity.  
     /// Deleting `owned`'s component won't stop the sharing.  
     /// Trying to share an entity with itself won't do anything.
     ///
+    /// While `owned` doesn't have a component, `shared` doesn't have one either, it sees the next component added to `owned`.  
+    /// Removing or deleting the component of `shared` or deleting `shared` itself stops the sharing.  
+    /// `shared` can be shared in turn, the component is found at the end of the chain.
+    ///
     /// ### Errors
     ///
     /// - `entity` already had a owned component of this type.
@@ -1080,6 +1791,8 @@ impl<T> SparseSet<T> {
                         .set_sparse_index_unchecked(shared, owned);
                 }
 
+                self.update_signature(shared.index(), Some(false));
+
                 Ok(())
             } else {
                 Err(error::Share)
@@ -1093,11 +1806,16 @@ impl<T> SparseSet<T> {
     /// Trying to share an entity with itself won't do anything.  
     /// Unwraps errors.
     ///
+    /// While `owned` doesn't have a component, `shared` doesn't have one either, it sees the next component added to `owned`.  
+    /// Removing or deleting the component of `shared` or deleting `shared` itself stops the sharing.  
+    /// `shared` can be shared in turn, the component is found at the end of the chain.
+    ///
     /// ### Errors
     ///
     /// - `entity` already had a owned component of this type.
     #[cfg(feature = "panic")]
     #[cfg_attr(docsrs, doc(cfg(feature = "panic")))]
+    #[track_caller]
     pub fn share(&mut self, owned: EntityId, shared: EntityId) {
         self.try_share(owned, shared).unwrap()
     }
@@ -1116,6 +1834,8 @@ impl<T> SparseSet<T> {
                     .set_sparse_index_unchecked(entity, EntityId::dead());
             }
 
+            self.update_signature(entity.index(), None);
+
             Ok(())
         } else {
             Err(error::Unshare)
@@ -1129,6 +1849,7 @@ impl<T> SparseSet<T> {
     /// - `entity` was not observing any entity.
     #[cfg(feature = "panic")]
     #[cfg_attr(docsrs, doc(cfg(feature = "panic")))]
+    #[track_caller]
     pub fn unshare(&mut self, entity: EntityId) {
         self.try_unshare(entity).unwrap()
     }
@@ -1153,7 +1874,7 @@ impl<T> SparseSet<T> {
             .ok_or_else(|| error::Apply::MissingComponent(b))?;
 
         if a_index != b_index {
-            if let Pack::Update(update) = &mut self.metadata.pack {
+            if let Some(update) = self.metadata.pack.modification_tracking_mut() {
                 let non_mut = update.first_non_mut();
 
                 if a_index >= non_mut {
@@ -1199,6 +1920,7 @@ impl<T> SparseSet<T> {
     /// - IdenticalIds - if the two entities point to the same component.
     #[cfg(feature = "panic")]
     #[cfg_attr(docsrs, doc(cfg(feature = "panic")))]
+    #[track_caller]
     pub fn apply<R, F: FnOnce(&mut T, &T) -> R>(&mut self, a: EntityId, b: EntityId, f: F) -> R {
         self.try_apply(a, b, f).unwrap()
     }
@@ -1223,7 +1945,7 @@ impl<T> SparseSet<T> {
             .ok_or_else(|| error::Apply::MissingComponent(b))?;
 
         if a_index != b_index {
-            if let Pack::Update(update) = &mut self.metadata.pack {
+            if let Some(update) = self.metadata.pack.modification_tracking_mut() {
                 let mut non_mut = update.first_non_mut();
 
                 if a_index >= non_mut {
@@ -1285,6 +2007,7 @@ impl<T> SparseSet<T> {
     /// - IdenticalIds - if the two entities point to the same component.
     #[cfg(feature = "panic")]
     #[cfg_attr(docsrs, doc(cfg(feature = "panic")))]
+    #[track_caller]
     pub fn apply_mut<R, F: FnOnce(&mut T, &mut T) -> R>(
         &mut self,
         a: EntityId,
@@ -1298,10 +2021,70 @@ impl<T> SparseSet<T> {
 #[cfg(feature = "serde1")]
 impl<T: serde::Serialize + for<'de> serde::Deserialize<'de> + 'static> SparseSet<T> {
     /// Setup serialization for this storage.  
-    /// Needs to be called for a storage to be serialized.
+    /// Needs to be called for a storage to be serialized.  
+    /// An `identifier` is required for the storage to be part of a scene.
     #[cfg_attr(docsrs, doc(cfg(feature = "panic")))]
-    pub fn setup_serde(&mut self, _ser_config: SerConfig) {
-        self.metadata.serde = Some(SerdeInfos::new());
+    pub fn setup_serde(&mut self, ser_config: SerConfig) {
+        self.metadata.serde = Some(SerdeInfos::new(ser_config));
+    }
+}
+
+#[cfg(feature = "serde1")]
+impl<T: serde::Serialize + for<'de> serde::Deserialize<'de> + MapEntityIds + 'static> SparseSet<T> {
+    /// Setup serialization for this storage.  
+    /// Needs to be called for a storage to be serialized.  
+    /// When entities get a new `EntityId` during deserialization, the `EntityId`s inside the components are updated too.
+    #[cfg_attr(docsrs, doc(cfg(feature = "serde1")))]
+    pub fn setup_serde_with_entity_ids(&mut self, ser_config: SerConfig) {
+        self.metadata.serde = Some(SerdeInfos::with_entity_ids(ser_config));
+    }
+}
+
+#[cfg(feature = "serde1")]
+impl<T: serde::Serialize + for<'de> serde::Deserialize<'de> + Migrate + 'static> SparseSet<T> {
+    /// Setup serialization for this storage.  
+    /// Needs to be called for a storage to be serialized.  
+    /// Components saved with an older [Migrate::VERSION] are upgraded with [Migrate::migrate] when the [World] is deserialized.
+    ///
+    /// [Migrate::VERSION]: trait.Migrate.html#associatedconstant.VERSION
+    /// [Migrate::migrate]: trait.Migrate.html#tymethod.migrate
+    /// [World]: struct.World.html
+    #[cfg_attr(docsrs, doc(cfg(feature = "serde1")))]
+    pub fn setup_serde_with_migration(&mut self, ser_config: SerConfig) {
+        self.metadata.serde = Some(SerdeInfos::with_migration(ser_config));
+    }
+}
+
+#[cfg(feature = "serde1")]
+impl<T: Clone + serde::Serialize + for<'de> serde::Deserialize<'de> + Send + Sync + 'static>
+    SparseSet<T>
+{
+    /// Setup serialization and recording for this storage.  
+    /// Once a recording starts with [World::start_recording], all components added and removed are recorded.  
+    /// The `identifier` is required to replay the recording.
+    ///
+    /// [World::start_recording]: struct.World.html#method.start_recording
+    #[cfg_attr(docsrs, doc(cfg(feature = "serde1")))]
+    pub fn setup_recording(&mut self, ser_config: SerConfig) {
+        let mut serde_infos = SerdeInfos::new(ser_config);
+        serde_infos.recording = Some(RecordInfos::new());
+        self.metadata.serde = Some(serde_infos);
+    }
+}
+
+#[cfg(feature = "serde1")]
+impl<T> SparseSet<T> {
+    /// Records a command if a recording is in progress.
+    fn record<F: FnOnce(fn(&T) -> T) -> Command<T>>(&mut self, f: F) {
+        if let (Some(record), Some(recording)) = (
+            &mut self.metadata.record,
+            self.metadata
+                .serde
+                .as_ref()
+                .and_then(|serde| serde.recording.as_ref()),
+        ) {
+            record.push((crate::recording::next_index(), f(recording.clone)));
+        }
     }
 }
 
@@ -1337,6 +2120,31 @@ impl<T: 'static> UnknownStorage for SparseSet<T> {
     fn clear(&mut self) {
         <Self>::clear(self)
     }
+    fn snapshot(&self) -> Option<StorageSnapshot> {
+        self.metadata
+            .snapshot
+            .as_ref()
+            .map(|snapshot| (snapshot.take)(self))
+    }
+    fn restore(&mut self, storage_snapshot: Option<&StorageSnapshot>) {
+        if let Some(snapshot) = &self.metadata.snapshot {
+            match storage_snapshot {
+                Some(storage_snapshot) => {
+                    (snapshot.restore)(self, storage_snapshot);
+
+                    if let Some(index) = &mut self.metadata.index {
+                        index.rebuild(&self.dense, &self.data);
+                    }
+
+                    self.sync_signatures();
+                }
+                None => self.clear(),
+            }
+        }
+    }
+    fn clone_storage(&self) -> Option<Storage> {
+        self.metadata.clone.map(|clone| clone(self))
+    }
     fn unpack(&mut self, entity: EntityId) {
         Self::unpack(self, entity);
     }
@@ -1346,6 +2154,15 @@ impl<T: 'static> UnknownStorage for SparseSet<T> {
     fn any_mut(&mut self) -> &mut dyn Any {
         self
     }
+    fn name(&self) -> &'static str {
+        type_name::<T>()
+    }
+    fn set_fixed_capacity(&mut self, entity_capacity: usize, capacity: usize) {
+        Self::set_fixed_capacity(self, entity_capacity, capacity);
+    }
+    fn compact(&mut self) {
+        Self::compact(self);
+    }
     #[cfg(feature = "serde1")]
     fn is_serializable(&self) -> bool {
         self.metadata.serde.is_some()
@@ -1368,11 +2185,217 @@ impl<T: 'static> UnknownStorage for SparseSet<T> {
     ) -> Option<
         fn(
             GlobalDeConfig,
+            u32,
             &mut dyn crate::erased_serde::Deserializer<'_>,
-        ) -> Result<crate::storage::Storage, crate::erased_serde::Error>,
+        ) -> Result<Storage, crate::erased_serde::Error>,
     > {
         Some(self.metadata.serde.as_ref()?.deserialization)
     }
+    #[cfg(feature = "serde1")]
+    fn serde_version(&self) -> u32 {
+        match &self.metadata.serde {
+            Some(serde) => serde.version,
+            None => 0,
+        }
+    }
+    #[cfg(feature = "serde1")]
+    fn identifier(&self) -> Option<&str> {
+        self.metadata
+            .serde
+            .as_ref()?
+            .identifier
+            .as_ref()
+            .map(Identifier::as_str)
+    }
+    fn has_component(&self, entity: EntityId) -> bool {
+        self.contains_owned(entity)
+    }
+    fn references(&self, entity: EntityId) -> bool {
+        self.contains_owned(entity) || self.contains_shared(entity)
+    }
+    fn set_event_log(&mut self, enabled: bool) {
+        if !enabled {
+            self.metadata.events = None;
+        } else if self.metadata.events.is_none() {
+            self.metadata.events = Some((StorageId::of::<T>(), Vec::new()));
+        }
+    }
+    fn drain_events(&mut self, events: &mut Vec<(usize, StructuralEvent)>) {
+        if let Some((_, logged)) = &mut self.metadata.events {
+            events.append(logged);
+        }
+    }
+    fn track_signatures(&mut self, storage_id: StorageId, signatures: Arc<Signatures>) {
+        self.metadata.signatures = Some(signatures.members(storage_id));
+
+        // a storage created at the same time by another thread mustn't erase the entities of the one kept
+        if !self.dense.is_empty() || !self.shared_ids().is_empty() {
+            self.sync_signatures();
+        }
+    }
+    fn component(&self, entity: EntityId) -> Option<&dyn Any> {
+        let index = self.index_of_owned(entity)?;
+
+        Some(&self.data[index])
+    }
+    fn component_entities(&self) -> &[EntityId] {
+        &self.dense
+    }
+    fn pack_types(&self) -> Vec<TypeId> {
+        match &self.metadata.pack {
+            Pack::Tight(pack) => pack.types.to_vec(),
+            Pack::Loose(pack) => pack
+                .tight_types
+                .iter()
+                .chain(pack.loose_types.iter())
+                .copied()
+                .collect(),
+            Pack::Update(_) | Pack::NoPack => Vec::new(),
+        }
+    }
+    fn observer_types(&self) -> &[TypeId] {
+        &self.metadata.observer_types
+    }
+    fn repack(&mut self, entities: &[EntityId]) {
+        match &mut self.metadata.pack {
+            Pack::Tight(pack) => pack.len = 0,
+            Pack::Loose(pack) => pack.len = 0,
+            Pack::Update(_) | Pack::NoPack => return,
+        }
+
+        for &entity in entities {
+            self.pack(entity);
+        }
+    }
+    fn is_reflected(&self) -> bool {
+        self.metadata.reflect.is_some()
+    }
+    fn reflect(&self, entity: EntityId) -> Option<&dyn Reflect> {
+        let reflect = self.metadata.reflect?;
+
+        self.get(entity).map(reflect.get)
+    }
+    fn reflect_mut(&mut self, entity: EntityId) -> Option<&mut dyn Reflect> {
+        let reflect = self.metadata.reflect?;
+
+        self.get_mut(entity).map(reflect.get_mut)
+    }
+    #[cfg(feature = "serde1")]
+    fn serialize_component(
+        &self,
+        entity: EntityId,
+        serializer: &mut dyn crate::erased_serde::Serializer,
+    ) -> crate::erased_serde::Result<crate::erased_serde::Ok> {
+        let serde = self
+            .metadata
+            .serde
+            .as_ref()
+            .ok_or_else(|| serde::ser::Error::custom("This type isn't serializable."))?;
+        let component = self
+            .get(entity)
+            .ok_or_else(|| serde::ser::Error::custom("Entity doesn't have this component."))?;
+
+        (serde.component_serialization)(component, serializer)
+    }
+    #[cfg(feature = "serde1")]
+    fn deserialize_component(
+        &mut self,
+        entity: EntityId,
+        deserializer: &mut dyn crate::erased_serde::Deserializer<'_>,
+    ) -> Result<(), crate::erased_serde::Error> {
+        let serde = self
+            .metadata
+            .serde
+            .as_ref()
+            .ok_or_else(|| serde::de::Error::custom("This type isn't serializable."))?;
+        let component = (serde.component_deserialization)(deserializer)?;
+
+        self.insert(component, entity);
+
+        Ok(())
+    }
+    #[cfg(feature = "serde1")]
+    fn map_entity_ids(&mut self, entities: &[EntityId], map: &dyn Fn(EntityId) -> EntityId) {
+        if let Some(map_entity_ids) = self
+            .metadata
+            .serde
+            .as_ref()
+            .and_then(|serde| serde.map_entity_ids)
+        {
+            for &entity in entities {
+                if let Some(component) = self.get_mut(entity) {
+                    map_entity_ids(component, map);
+                }
+            }
+        }
+    }
+    #[cfg(feature = "serde1")]
+    fn remap_entities(&mut self, map: &dyn Fn(EntityId) -> EntityId) {
+        let shared = self.shared_ids();
+
+        for entity in &mut self.dense {
+            *entity = map(*entity);
+        }
+
+        self.sparse = sparse_from_dense(&self.dense);
+        self.metadata.shared = SparseArray::new();
+
+        for (shared, owned) in shared {
+            let _ = self.try_share(map(owned), map(shared));
+        }
+        if let Some(index) = &mut self.metadata.index {
+            index.rebuild(&self.dense, &self.data);
+        }
+
+        self.sync_signatures();
+    }
+    #[cfg(feature = "serde1")]
+    fn append(&mut self, other: &mut dyn UnknownStorage) {
+        if let Some(other) = other.any_mut().downcast_mut::<Self>() {
+            let shared = other.shared_ids();
+            let dense = core::mem::take(&mut other.dense);
+            let data = core::mem::take(&mut other.data);
+            other.sparse = SparseArray::new();
+            other.metadata.shared = SparseArray::new();
+
+            for (entity, component) in dense.into_iter().zip(data) {
+                self.insert(component, entity);
+            }
+
+            for (shared, owned) in shared {
+                let _ = self.try_share(owned, shared);
+            }
+        }
+    }
+    #[cfg(feature = "serde1")]
+    fn start_recording(&mut self) {
+        if self
+            .metadata
+            .serde
+            .as_ref()
+            .map(|serde| serde.recording.is_some())
+            .unwrap_or(false)
+        {
+            self.metadata.record = Some(Vec::new());
+        }
+    }
+    #[cfg(feature = "serde1")]
+    fn stop_recording(&mut self) -> Vec<(u64, RecordedCommand)> {
+        match (self.metadata.record.take(), &self.metadata.serde) {
+            (Some(record), Some(serde)) => {
+                let into_recorded = serde.recording.as_ref().unwrap().into_recorded;
+                // a command can't be replayed without identifier
+                match &serde.identifier {
+                    Some(identifier) => record
+                        .into_iter()
+                        .map(|(index, command)| (index, into_recorded(command, identifier)))
+                        .collect(),
+                    None => Vec::new(),
+                }
+            }
+            _ => Vec::new(),
+        }
+    }
 }
 
 #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
@@ -1387,6 +2410,7 @@ impl<T> OldComponent<T> {
     /// Extracts the value inside `OldComponent`.
     #[cfg(feature = "panic")]
     #[cfg_attr(docsrs, doc(cfg(feature = "panic")))]
+    #[track_caller]
     pub fn unwrap_owned(self) -> T {
         match self {
             Self::Owned(component) => component,
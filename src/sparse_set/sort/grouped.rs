@@ -0,0 +1,112 @@
+use super::IntoSortable;
+use crate::error;
+use crate::sparse_set::{EntityId, Pack, SparseSet};
+use alloc::vec::IntoIter;
+use alloc::vec::Vec;
+
+impl<T> SparseSet<T> {
+    /// Sorts the storage by `key` and returns its components grouped by key, in ascending key order.
+    /// Each group is yielded once with its key, ids and components. Components with the same key are contiguous,
+    /// the sort is stable so their relative order is kept.
+    /// The storage stays sorted, following calls only have to move the components whose key changed.
+    ///
+    /// ### Errors
+    ///
+    /// - The storage is packed.
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{EntitiesViewMut, ViewMut, World};
+    ///
+    /// #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Debug)]
+    /// enum Material {
+    ///     Wood,
+    ///     Stone,
+    /// }
+    ///
+    /// let world = World::new();
+    ///
+    /// world.run(|mut entities: EntitiesViewMut, mut materials: ViewMut<Material>| {
+    ///     entities.add_entity(&mut materials, Material::Stone);
+    ///     entities.add_entity(&mut materials, Material::Wood);
+    ///     entities.add_entity(&mut materials, Material::Stone);
+    ///
+    ///     let batches = materials
+    ///         .try_iter_grouped_by(|&material| material)
+    ///         .unwrap()
+    ///         .map(|(material, ids, _)| (material, ids.len()))
+    ///         .collect::<Vec<_>>();
+    ///
+    ///     assert_eq!(batches, vec![(Material::Wood, 1), (Material::Stone, 2)]);
+    /// });
+    /// ```
+    pub fn try_iter_grouped_by<K: Ord>(
+        &mut self,
+        mut key: impl FnMut(&T) -> K,
+    ) -> Result<GroupedBy<'_, T, K>, error::Sort> {
+        if core::mem::discriminant(&self.metadata.pack) != core::mem::discriminant(&Pack::NoPack) {
+            return Err(error::Sort::MissingPackStorage);
+        }
+
+        // each key is computed once, not for every comparison
+        let mut keyed: Vec<(K, usize)> = self.data.iter().map(&mut key).zip(0..).collect();
+
+        keyed.sort_by(|(a, _), (b, _)| a.cmp(b));
+
+        let (keys, transform): (Vec<K>, Vec<usize>) = keyed.into_iter().unzip();
+
+        if transform.iter().enumerate().any(|(i, &index)| i != index) {
+            self.sort().apply_transform(transform);
+        }
+
+        Ok(GroupedBy {
+            keys: keys.into_iter(),
+            ids: &self.dense,
+            data: &mut self.data,
+        })
+    }
+    /// Sorts the storage by `key` and returns its components grouped by key, in ascending key order.
+    /// Each group is yielded once with its key, ids and components. Components with the same key are contiguous,
+    /// the sort is stable so their relative order is kept.
+    /// The storage stays sorted, following calls only have to move the components whose key changed.
+    /// Unwraps errors.
+    ///
+    /// ### Errors
+    ///
+    /// - The storage is packed.
+    #[cfg(feature = "panic")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "panic")))]
+    #[track_caller]
+    pub fn iter_grouped_by<K: Ord>(&mut self, key: impl FnMut(&T) -> K) -> GroupedBy<'_, T, K> {
+        self.try_iter_grouped_by(key).unwrap()
+    }
+}
+
+/// Iterator over the groups of a storage sorted by key.
+/// Yields the key, ids and components of each group.
+pub struct GroupedBy<'a, T, K> {
+    keys: IntoIter<K>,
+    ids: &'a [EntityId],
+    data: &'a mut [T],
+}
+
+impl<'a, T, K: PartialEq> Iterator for GroupedBy<'a, T, K> {
+    type Item = (K, &'a [EntityId], &'a mut [T]);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let key = self.keys.next()?;
+
+        let mut len = 1;
+        while self.keys.as_slice().first() == Some(&key) {
+            self.keys.next();
+            len += 1;
+        }
+
+        let (ids, rest_ids) = self.ids.split_at(len);
+        self.ids = rest_ids;
+        let (data, rest_data) = core::mem::take(&mut self.data).split_at_mut(len);
+        self.data = rest_data;
+
+        Some((key, ids, data))
+    }
+}
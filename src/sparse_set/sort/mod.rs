@@ -1,6 +1,8 @@
+mod grouped;
 mod unstable;
 
 use super::SparseSet;
+pub use grouped::GroupedBy;
 pub use unstable::*;
 
 /// Trait used to sort storage(s).
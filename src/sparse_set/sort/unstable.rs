@@ -5,6 +5,10 @@ use crate::type_id::TypeId;
 use crate::view::ViewMut;
 use alloc::vec::Vec;
 use core::cmp::Ordering;
+#[cfg(feature = "parallel")]
+use rayon::slice::ParallelSliceMut;
+#[cfg(feature = "parallel")]
+use rayon::ThreadPool;
 
 /// Struct used to sort a single storage.
 pub struct Sort1<'tmp, T>(&'tmp mut SparseSet<T>);
@@ -30,24 +34,7 @@ impl<'tmp, T> Sort1<'tmp, T> {
                 })
             });
 
-            let mut pos;
-            for i in 0..transform.len() {
-                // SAFE we're in bound
-                pos = unsafe { *transform.get_unchecked(i) };
-                while pos < i {
-                    // SAFE we're in bound
-                    pos = unsafe { *transform.get_unchecked(pos) };
-                }
-                self.0.dense.swap(i, pos);
-                self.0.data.swap(i, pos);
-            }
-
-            for i in 0..self.0.dense.len() {
-                let dense = self.0.dense[i];
-                unsafe {
-                    self.0.sparse.set_sparse_index_unchecked(dense, i);
-                }
-            }
+            self.apply_transform(transform);
 
             Ok(())
         } else {
@@ -59,6 +46,93 @@ impl<'tmp, T> Sort1<'tmp, T> {
     pub fn unstable(self, cmp: impl FnMut(&T, &T) -> Ordering) {
         self.try_unstable(cmp).unwrap()
     }
+    /// Sorts the storage(s) in parallel on `thread_pool` using an unstable algorithm, it may reorder equal components.
+    /// The `World`'s thread pool can be borrowed with [Tasks].
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{EntitiesViewMut, IntoSortable, Tasks, ViewMut, World};
+    ///
+    /// let world = World::new();
+    ///
+    /// world.run(
+    ///     |mut entities: EntitiesViewMut, mut depths: ViewMut<u32>, tasks: Tasks| {
+    ///         for depth in (0..1000).rev() {
+    ///             entities.add_entity(&mut depths, depth);
+    ///         }
+    ///
+    ///         depths.sort().try_par_unstable(tasks.as_ref(), Ord::cmp).unwrap();
+    ///     },
+    /// );
+    /// ```
+    ///
+    /// [Tasks]: struct.Tasks.html
+    #[cfg(feature = "parallel")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "parallel")))]
+    pub fn try_par_unstable(
+        self,
+        thread_pool: &ThreadPool,
+        cmp: impl Fn(&T, &T) -> Ordering + Sync,
+    ) -> Result<(), error::Sort>
+    where
+        T: Send + Sync,
+    {
+        if core::mem::discriminant(&self.0.metadata.pack) == core::mem::discriminant(&Pack::NoPack)
+        {
+            let mut transform: Vec<usize> = (0..self.0.dense.len()).collect();
+
+            let data = &self.0.data;
+            thread_pool.install(|| {
+                transform.par_sort_unstable_by(|&i, &j| {
+                    // SAFE dense and data have the same length
+                    cmp(unsafe { data.get_unchecked(i) }, unsafe {
+                        data.get_unchecked(j)
+                    })
+                })
+            });
+
+            self.apply_transform(transform);
+
+            Ok(())
+        } else {
+            Err(error::Sort::MissingPackStorage)
+        }
+    }
+    /// Sorts the storage(s) in parallel on `thread_pool` using an unstable algorithm, it may reorder equal components.
+    /// The `World`'s thread pool can be borrowed with [Tasks].
+    /// Unwraps errors.
+    ///
+    /// [Tasks]: struct.Tasks.html
+    #[cfg(all(feature = "parallel", feature = "panic"))]
+    #[cfg_attr(docsrs, doc(cfg(all(feature = "parallel", feature = "panic"))))]
+    #[track_caller]
+    pub fn par_unstable(self, thread_pool: &ThreadPool, cmp: impl Fn(&T, &T) -> Ordering + Sync)
+    where
+        T: Send + Sync,
+    {
+        self.try_par_unstable(thread_pool, cmp).unwrap()
+    }
+    /// Moves components to their sorted position, `transform[i]` is the index of the component going to `i`.
+    pub(crate) fn apply_transform(self, transform: Vec<usize>) {
+        let mut pos;
+        for i in 0..transform.len() {
+            // SAFE we're in bound
+            pos = unsafe { *transform.get_unchecked(i) };
+            while pos < i {
+                // SAFE we're in bound
+                pos = unsafe { *transform.get_unchecked(pos) };
+            }
+            self.0.dense.swap(i, pos);
+            self.0.data.swap(i, pos);
+        }
+
+        for i in 0..self.0.dense.len() {
+            let dense = self.0.dense[i];
+            unsafe {
+                self.0.sparse.set_sparse_index_unchecked(dense, i);
+            }
+        }
+    }
 }
 
 macro_rules! impl_unstable_sort {
@@ -77,6 +151,68 @@ macro_rules! impl_unstable_sort {
         impl<'tmp, 'view, $($type: 'static),+> $sort<'tmp, $($type),+> {
             /// Sorts the storage(s) using an unstable algorithm, it may reorder equal components.
             pub fn try_unstable<Cmp: FnMut(($(&$type,)+), ($(&$type,)+)) -> Ordering>(self, mut cmp: Cmp) -> Result<(), error::Sort> {
+                let (len, packed) = self.pack_info()?;
+                let dense = self.packed_dense(packed);
+
+                let mut transform: Vec<usize> = (0..len).collect();
+
+                // SAFE i and j are in bound
+                transform.sort_unstable_by(|&i, &j| unsafe {
+                    cmp(self.components(dense, packed, i), self.components(dense, packed, j))
+                });
+
+                self.apply_transform(transform);
+
+                Ok(())
+            }
+            /// Sorts the storage(s) using an unstable algorithm, it may reorder equal components.
+            /// Unwraps errors.
+            pub fn unstable<Cmp: FnMut(($(&$type,)+), ($(&$type,)+)) -> Ordering>(self, cmp: Cmp) {
+                self.try_unstable(cmp).unwrap()
+            }
+            /// Sorts the storage(s) in parallel on `thread_pool` using an unstable algorithm, it may reorder equal components.
+            /// The `World`'s thread pool can be borrowed with [Tasks].
+            ///
+            /// [Tasks]: struct.Tasks.html
+            #[cfg(feature = "parallel")]
+            #[cfg_attr(docsrs, doc(cfg(feature = "parallel")))]
+            pub fn try_par_unstable<Cmp: Fn(($(&$type,)+), ($(&$type,)+)) -> Ordering + Sync>(self, thread_pool: &ThreadPool, cmp: Cmp) -> Result<(), error::Sort>
+            where
+                $($type: Send + Sync),+
+            {
+                let (len, packed) = self.pack_info()?;
+                let dense = self.packed_dense(packed);
+
+                let mut transform: Vec<usize> = (0..len).collect();
+
+                let sort = &self;
+                thread_pool.install(|| {
+                    // SAFE i and j are in bound
+                    transform.par_sort_unstable_by(|&i, &j| unsafe {
+                        cmp(sort.components(dense, packed, i), sort.components(dense, packed, j))
+                    })
+                });
+
+                self.apply_transform(transform);
+
+                Ok(())
+            }
+            /// Sorts the storage(s) in parallel on `thread_pool` using an unstable algorithm, it may reorder equal components.
+            /// The `World`'s thread pool can be borrowed with [Tasks].
+            /// Unwraps errors.
+            ///
+            /// [Tasks]: struct.Tasks.html
+            #[cfg(all(feature = "parallel", feature = "panic"))]
+            #[cfg_attr(docsrs, doc(cfg(all(feature = "parallel", feature = "panic"))))]
+            #[track_caller]
+            pub fn par_unstable<Cmp: Fn(($(&$type,)+), ($(&$type,)+)) -> Ordering + Sync>(self, thread_pool: &ThreadPool, cmp: Cmp)
+            where
+                $($type: Send + Sync),+
+            {
+                self.try_par_unstable(thread_pool, cmp).unwrap()
+            }
+            /// Returns how many components will be sorted and a mask of the storages holding them in sorted order.
+            fn pack_info(&self) -> Result<(usize, u32), error::Sort> {
                 enum PackSort {
                     Tight(usize),
                     Loose(usize),
@@ -123,117 +259,76 @@ macro_rules! impl_unstable_sort {
                 })+
 
                 match pack_sort {
-                    PackSort::Tight(len) => {
-                        let mut transform: Vec<usize> = (0..len).collect();
-
-                        // SAFE dense and data have the same length
-                        transform.sort_unstable_by(|&i, &j| cmp(
-                            ($(unsafe {self.$index.data.get_unchecked(i)},)+),
-                            ($(unsafe {self.$index.data.get_unchecked(j)},)+),
-                        ));
-
-                        let mut pos;
-                        $(
-                            for i in 0..transform.len() {
-                                // SAFE we're in bound
-                                pos = unsafe {*transform.get_unchecked(i)};
-                                while pos < i {
-                                    // SAFE we're in bound
-                                    pos = unsafe { *transform.get_unchecked(pos) };
-                                }
-                                self.$index.dense.swap(i, pos);
-                                self.$index.data.swap(i, pos);
-                            }
-
-                            for i in 0..self.$index.dense.len() {
-                                unsafe {
-                                    // SAFE i is in bound
-                                    let dense = *self.0.dense.get_unchecked(i);
-                                    // SAFE dense can always index into sparse
-                                    self.$index.sparse.set_sparse_index_unchecked(dense, i);
-                                }
-                            }
-                        )*
-
-                        Ok(())
-                    }
+                    // all storages are in the same order
+                    PackSort::Tight(len) => Ok((len, !0)),
                     PackSort::Loose(len) => {
-                        let mut dense: &[EntityId] = &[];
                         let mut packed = 0;
                         $(
                             if self.$index.metadata.pack.is_loose() {
-                                dense = &self.$index.dense;
                                 packed |= 1 << $index;
                             }
                         )+
 
-                        let mut transform: Vec<usize> = (0..len).collect();
-
-                        transform.sort_unstable_by(|&i, &j| cmp(
-                            ($(
-                                unsafe {
-                                    if packed & (1 << $index) != 0 {
-                                        // SAFE i is in bound
-                                        self.$index.data.get_unchecked(i)
-                                    } else {
-                                        // SAFE i is in bound
-                                        let id = *dense.get_unchecked(i);
-                                        // SAFE dense can always index into sparse
-                                        let index = self.$index.sparse.sparse_index(id).unwrap();
-                                        // SAFE sparse can always index into data
-                                        self.$index.data.get_unchecked(index)
-                                    }
-                                }
-                            ,)+),
-                            ($(
-                                unsafe {
-                                    if packed & (1 << $index) != 0 {
-                                        // SAFE j is in bound
-                                        self.$index.data.get_unchecked(j)
-                                    } else {
-                                        // SAFE j is in bound
-                                        let id = *dense.get_unchecked(j);
-                                        // SAFE dense can always index into sparse
-                                        let index = self.$index.sparse.sparse_index(id).unwrap();
-                                        // SAFE sparse can always index into data
-                                        self.$index.data.get_unchecked(index)
-                                    }
-                                }
-                            ,)+)
-                        ));
-
-                        let mut pos;
-                        $(
-                            for i in 0..transform.len() {
-                                // SAFE i is in bound
-                                pos = unsafe {*transform.get_unchecked(i)};
-                                while pos < i {
-                                    // SAFE pos is in bound
-                                    pos = unsafe { *transform.get_unchecked(pos) };
-                                }
-                                self.$index.dense.swap(i, pos);
-                                self.$index.data.swap(i, pos);
-                            }
-
-                            for i in 0..self.$index.dense.len() {
-                                unsafe {
-                                    // SAFE i is in bound
-                                    let dense = *self.0.dense.get_unchecked(i);
-                                    // SAFE dense can always index into sparse
-                                    self.$index.sparse.set_sparse_index_unchecked(dense, i);
-                                }
-                            }
-                        )*
-
-                        Ok(())
+                        Ok((len, packed))
                     }
                     PackSort::None => unreachable!(),
                 }
             }
-            /// Sorts the storage(s) using an unstable algorithm, it may reorder equal components.
-            /// Unwraps errors.
-            pub fn unstable<Cmp: FnMut(($(&$type,)+), ($(&$type,)+)) -> Ordering>(self, cmp: Cmp) {
-                self.try_unstable(cmp).unwrap()
+            /// Returns the entities of a storage in sorted order.
+            fn packed_dense(&self, packed: u32) -> &[EntityId] {
+                let mut dense: &[EntityId] = &[];
+                $(
+                    if packed & (1 << $index) != 0 {
+                        dense = &self.$index.dense;
+                    }
+                )+
+
+                dense
+            }
+            /// Returns the components of the `i`th entity to sort.
+            ///
+            /// ### Safety
+            ///
+            /// `i` has to be lower than the length returned by `pack_info`.
+            unsafe fn components(&self, dense: &[EntityId], packed: u32, i: usize) -> ($(&$type,)+) {
+                ($(
+                    if packed & (1 << $index) != 0 {
+                        // SAFE i is in bound
+                        self.$index.data.get_unchecked(i)
+                    } else {
+                        // SAFE i is in bound
+                        let id = *dense.get_unchecked(i);
+                        // SAFE dense can always index into sparse
+                        let index = self.$index.sparse.sparse_index(id).unwrap();
+                        // SAFE sparse can always index into data
+                        self.$index.data.get_unchecked(index)
+                    }
+                ,)+)
+            }
+            /// Moves components to their sorted position, `transform[i]` is the index of the components going to `i`.
+            fn apply_transform(self, transform: Vec<usize>) {
+                let mut pos;
+                $(
+                    for i in 0..transform.len() {
+                        // SAFE i is in bound
+                        pos = unsafe {*transform.get_unchecked(i)};
+                        while pos < i {
+                            // SAFE pos is in bound
+                            pos = unsafe { *transform.get_unchecked(pos) };
+                        }
+                        self.$index.dense.swap(i, pos);
+                        self.$index.data.swap(i, pos);
+                    }
+
+                    for i in 0..self.$index.dense.len() {
+                        unsafe {
+                            // SAFE i is in bound
+                            let dense = *self.0.dense.get_unchecked(i);
+                            // SAFE dense can always index into sparse
+                            self.$index.sparse.set_sparse_index_unchecked(dense, i);
+                        }
+                    }
+                )*
             }
         }
     }
@@ -249,7 +344,10 @@ macro_rules! unstable_sort {
     }
 }
 
+#[cfg(not(feature = "extended_tuple"))]
 unstable_sort![;Sort2 Sort3 Sort4 Sort5 Sort6 Sort7 Sort8 Sort9 Sort10;(A, 0) (B, 1); (C, 2) (D, 3) (E, 4) (F, 5) (G, 6) (H, 7) (I, 8) (J, 9)];
+#[cfg(feature = "extended_tuple")]
+unstable_sort![;Sort2 Sort3 Sort4 Sort5 Sort6 Sort7 Sort8 Sort9 Sort10 Sort11 Sort12 Sort13 Sort14 Sort15 Sort16;(A, 0) (B, 1); (C, 2) (D, 3) (E, 4) (F, 5) (G, 6) (H, 7) (I, 8) (J, 9) (K, 10) (L, 11) (M, 12) (N, 13) (O, 14) (P, 15)];
 
 #[test]
 fn unstable_sort() {
@@ -10,12 +10,17 @@ use alloc::boxed::Box;
 #[cfg(not(feature = "std"))]
 use alloc::vec::Vec;
 
+#[derive(Clone)]
 pub(crate) struct SparseArray<T>(Vec<Option<Box<T>>>);
 
 impl<T> SparseArray<T> {
     pub(super) fn new() -> Self {
         SparseArray(Vec::new())
     }
+    /// Reserves room for the buckets of `buckets` ids.
+    pub(super) fn reserve(&mut self, buckets: usize) {
+        self.0.reserve(buckets.saturating_sub(self.0.len()));
+    }
     pub(super) fn as_slice(&self) -> SparseSlice<'_, T> {
         SparseSlice(&*self.0)
     }
@@ -25,6 +30,18 @@ impl<T> SparseArray<T> {
 }
 
 impl SparseArray<[usize; crate::sparse_set::BUCKET_SIZE]> {
+    /// Allocates the first `buckets` buckets, adding ids in them won't allocate.
+    pub(super) fn allocate_buckets(&mut self, buckets: usize) {
+        if buckets > self.0.len() {
+            self.0.resize(buckets, None);
+        }
+
+        for bucket in &mut self.0[..buckets] {
+            if bucket.is_none() {
+                *bucket = Some(Box::new([core::usize::MAX; crate::sparse_set::BUCKET_SIZE]));
+            }
+        }
+    }
     pub(super) fn allocate_at(&mut self, entity: EntityId) {
         if entity.bucket() >= self.0.len() {
             self.0.resize(entity.bucket() + 1, None);
@@ -37,7 +54,7 @@ impl SparseArray<[usize; crate::sparse_set::BUCKET_SIZE]> {
             }
         }
     }
-    pub(super) fn sparse_index(&self, entity: EntityId) -> Option<usize> {
+    pub(crate) fn sparse_index(&self, entity: EntityId) -> Option<usize> {
         // SAFE bucket_index always returns a valid bucket index
         self.0
             .get(entity.bucket())?
@@ -66,6 +83,20 @@ impl SparseArray<[EntityId; crate::sparse_set::metadata::BUCKET_SIZE]> {
             }
         }
     }
+    /// Iterates the index of each shared entity with the entity it observes.
+    pub(super) fn iter_shared(&self) -> impl Iterator<Item = (u64, EntityId)> + '_ {
+        self.0
+            .iter()
+            .enumerate()
+            .filter_map(|(bucket_index, bucket)| Some((bucket_index, bucket.as_ref()?)))
+            .flat_map(|(bucket_index, bucket)| {
+                bucket
+                    .iter()
+                    .enumerate()
+                    .filter(|&(_, &owned)| owned != EntityId::dead())
+                    .map(move |(i, &owned)| ((bucket_index * bucket.len() + i) as u64, owned))
+            })
+    }
     pub(super) fn shared_index(&self, entity: EntityId) -> Option<EntityId> {
         self.0
             .get(entity.shared_bucket())?
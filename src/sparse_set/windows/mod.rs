@@ -106,6 +106,7 @@ impl<'w, T> Window<'w, T> {
     /// Unwraps errors.
     #[cfg(feature = "panic")]
     #[cfg_attr(docsrs, doc(cfg(feature = "panic")))]
+    #[track_caller]
     pub fn inserted(&self) -> Window<'_, T> {
         self.try_inserted().unwrap()
     }
@@ -133,6 +134,7 @@ impl<'w, T> Window<'w, T> {
     /// Unwraps errors.
     #[cfg(feature = "panic")]
     #[cfg_attr(docsrs, doc(cfg(feature = "panic")))]
+    #[track_caller]
     pub fn modified(&self) -> Window<'_, T> {
         self.try_modified().unwrap()
     }
@@ -158,6 +160,7 @@ impl<'w, T> Window<'w, T> {
     /// Unwraps errors.
     #[cfg(feature = "panic")]
     #[cfg_attr(docsrs, doc(cfg(feature = "panic")))]
+    #[track_caller]
     pub fn inserted_or_modified(&self) -> Window<'_, T> {
         self.try_inserted_or_modified().unwrap()
     }
@@ -173,6 +176,7 @@ impl<'w, T> Window<'w, T> {
     /// Unwraps errors.
     #[cfg(feature = "panic")]
     #[cfg_attr(docsrs, doc(cfg(feature = "panic")))]
+    #[track_caller]
     pub fn deleted(&self) -> &[(EntityId, T)] {
         self.try_deleted().unwrap()
     }
@@ -188,6 +192,7 @@ impl<'w, T> Window<'w, T> {
     /// Unwraps errors.
     #[cfg(feature = "panic")]
     #[cfg_attr(docsrs, doc(cfg(feature = "panic")))]
+    #[track_caller]
     pub fn removed(&self) -> &[EntityId] {
         self.try_removed().unwrap()
     }
@@ -199,6 +204,7 @@ impl<'w, T> Window<'w, T> {
     /// Unwraps errors.
     #[cfg(feature = "panic")]
     #[cfg_attr(docsrs, doc(cfg(feature = "panic")))]
+    #[track_caller]
     pub fn id_at(&self, index: usize) -> EntityId {
         self.try_id_at(index).unwrap()
     }
@@ -246,6 +252,117 @@ impl<'w, T> Window<'w, T> {
     pub fn as_slice(&self) -> &[T] {
         &self.data
     }
+    /// Returns the ids of the entities owning the components of this window, in the same order as [as_slice].
+    ///
+    /// [as_slice]: struct.Window.html#method.as_slice
+    pub fn ids(&self) -> &'w [EntityId] {
+        self.dense
+    }
+    /// Returns the index of the whole storage created with [SparseSet::setup_index] if its key is `K`.
+    ///
+    /// [SparseSet::setup_index]: struct.SparseSet.html#method.setup_index
+    pub fn index<K: 'static>(&self) -> Option<&'w crate::index::Index<K>>
+    where
+        T: 'static,
+    {
+        self.metadata.index()
+    }
+    /// Returns the components of this window as bytes, without copying them.
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{EntitiesViewMut, View, ViewMut, World};
+    ///
+    /// let world = World::new();
+    ///
+    /// world.run(|mut entities: EntitiesViewMut, mut colors: ViewMut<[u8; 4]>| {
+    ///     entities.add_entity(&mut colors, [255, 0, 0, 255]);
+    ///     entities.add_entity(&mut colors, [0, 0, 255, 255]);
+    /// });
+    ///
+    /// world.run(|colors: View<[u8; 4]>| {
+    ///     assert_eq!(colors.as_bytes(), &[255, 0, 0, 255, 0, 0, 255, 255]);
+    /// });
+    /// ```
+    #[cfg(feature = "bytemuck1")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "bytemuck1")))]
+    pub fn as_bytes(&self) -> &'w [u8]
+    where
+        T: bytemuck::Pod,
+    {
+        bytemuck::cast_slice(self.data)
+    }
+    /// Returns an iterator over chunks of at most `chunk_size` components and the ids of their entities, each serializable on its own.  
+    /// Serializing the chunks one at a time keeps peak memory bounded for very large storages,
+    /// even with formats building the whole value in memory. Nothing is copied, chunks borrow the window.
+    ///
+    /// ### Panics
+    ///
+    /// - `chunk_size` is 0.
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{View, World};
+    ///
+    /// let world = World::new();
+    /// world.spawn((0u32,));
+    /// world.spawn((1u32,));
+    /// world.spawn((2u32,));
+    ///
+    /// world.run(|u32s: View<u32>| {
+    ///     let mut output = Vec::new();
+    ///
+    ///     for chunk in u32s.serialize_chunks(2) {
+    ///         serde_json::to_writer(&mut output, &chunk).unwrap();
+    ///         output.push(b'\n');
+    ///     }
+    ///
+    ///     assert_eq!(output.split(|&byte| byte == b'\n').count(), 3);
+    /// });
+    /// ```
+    #[cfg(feature = "serde1")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "serde1")))]
+    pub fn serialize_chunks(
+        &self,
+        chunk_size: usize,
+    ) -> impl ExactSizeIterator<Item = super::SerializeChunk<'w, T>> + 'w {
+        self.dense
+            .chunks(chunk_size)
+            .zip(self.data.chunks(chunk_size))
+            .map(|(ids, data)| super::SerializeChunk { ids, data })
+    }
+    /// Returns the components of this window owned by entities having all components of its tight pack.  
+    /// They're in the same order in every storage of the pack, the matching ids are the start of [ids].
+    ///
+    /// ### Errors
+    ///
+    /// - Storage isn't tightly packed.
+    ///
+    /// [ids]: struct.Window.html#method.ids
+    pub fn try_tight_packed(&self) -> Result<&'w [T], error::NotTightPack> {
+        if let Pack::Tight(pack) = &self.metadata.pack {
+            let len = pack.len.saturating_sub(self.offset).min(self.len());
+
+            Ok(&self.data[..len])
+        } else {
+            Err(error::NotTightPack)
+        }
+    }
+    /// Returns the components of this window owned by entities having all components of its tight pack.  
+    /// They're in the same order in every storage of the pack, the matching ids are the start of [ids].  
+    /// Unwraps errors.
+    ///
+    /// ### Errors
+    ///
+    /// - Storage isn't tightly packed.
+    ///
+    /// [ids]: struct.Window.html#method.ids
+    #[cfg(feature = "panic")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "panic")))]
+    #[track_caller]
+    pub fn tight_packed(&self) -> &'w [T] {
+        self.try_tight_packed().unwrap()
+    }
     /// Returns a window over `range`.
     pub fn try_as_window<R: core::ops::RangeBounds<usize>>(
         &self,
@@ -281,6 +398,7 @@ impl<'w, T> Window<'w, T> {
     /// Unwraps errors.
     #[cfg(feature = "panic")]
     #[cfg_attr(docsrs, doc(cfg(feature = "panic")))]
+    #[track_caller]
     pub fn as_window<R: core::ops::RangeBounds<usize>>(&self, range: R) -> Window<'_, T> {
         self.try_as_window(range).unwrap()
     }
@@ -372,7 +490,7 @@ impl<'w, T> WindowMut<'w, T> {
     pub(crate) fn get_mut(&mut self, entity: EntityId) -> Option<&mut T> {
         match self.index_of(entity) {
             Some(mut index) => {
-                if let Pack::Update(pack) = &mut self.metadata.pack {
+                if let Some(pack) = self.metadata.pack.modification_tracking_mut() {
                     // index of the first element non modified
                     let non_mod = pack.inserted + pack.modified;
 
@@ -436,6 +554,7 @@ impl<'w, T> WindowMut<'w, T> {
     /// Unwraps errors.
     #[cfg(feature = "panic")]
     #[cfg_attr(docsrs, doc(cfg(feature = "panic")))]
+    #[track_caller]
     pub fn inserted(&self) -> Window<'_, T> {
         self.try_inserted().unwrap()
     }
@@ -461,6 +580,7 @@ impl<'w, T> WindowMut<'w, T> {
     /// Unwraps errors.
     #[cfg(feature = "panic")]
     #[cfg_attr(docsrs, doc(cfg(feature = "panic")))]
+    #[track_caller]
     pub fn inserted_mut(&mut self) -> WindowMut<'_, T> {
         self.try_inserted_mut().unwrap()
     }
@@ -488,6 +608,7 @@ impl<'w, T> WindowMut<'w, T> {
     /// Unwraps errors.
     #[cfg(feature = "panic")]
     #[cfg_attr(docsrs, doc(cfg(feature = "panic")))]
+    #[track_caller]
     pub fn modified(&self) -> Window<'_, T> {
         self.try_modified().unwrap()
     }
@@ -515,6 +636,7 @@ impl<'w, T> WindowMut<'w, T> {
     /// Unwraps errors.
     #[cfg(feature = "panic")]
     #[cfg_attr(docsrs, doc(cfg(feature = "panic")))]
+    #[track_caller]
     pub fn modified_mut(&mut self) -> WindowMut<'_, T> {
         self.try_modified_mut().unwrap()
     }
@@ -540,6 +662,7 @@ impl<'w, T> WindowMut<'w, T> {
     /// Unwraps errors.
     #[cfg(feature = "panic")]
     #[cfg_attr(docsrs, doc(cfg(feature = "panic")))]
+    #[track_caller]
     pub fn inserted_or_modified(&self) -> Window<'_, T> {
         self.try_inserted_or_modified().unwrap()
     }
@@ -567,6 +690,7 @@ impl<'w, T> WindowMut<'w, T> {
     /// Unwraps errors.
     #[cfg(feature = "panic")]
     #[cfg_attr(docsrs, doc(cfg(feature = "panic")))]
+    #[track_caller]
     pub fn inserted_or_modified_mut(&mut self) -> WindowMut<'_, T> {
         self.try_inserted_or_modified_mut().unwrap()
     }
@@ -582,6 +706,7 @@ impl<'w, T> WindowMut<'w, T> {
     /// Unwraps errors.
     #[cfg(feature = "panic")]
     #[cfg_attr(docsrs, doc(cfg(feature = "panic")))]
+    #[track_caller]
     pub fn deleted(&self) -> &[(EntityId, T)] {
         self.try_deleted().unwrap()
     }
@@ -597,6 +722,7 @@ impl<'w, T> WindowMut<'w, T> {
     /// Unwraps errors.
     #[cfg(feature = "panic")]
     #[cfg_attr(docsrs, doc(cfg(feature = "panic")))]
+    #[track_caller]
     pub fn removed(&self) -> &[EntityId] {
         self.try_removed().unwrap()
     }
@@ -614,6 +740,7 @@ impl<'w, T> WindowMut<'w, T> {
     /// Unwraps errors.
     #[cfg(feature = "panic")]
     #[cfg_attr(docsrs, doc(cfg(feature = "panic")))]
+    #[track_caller]
     pub fn take_deleted(&mut self) -> Vec<(EntityId, T)> {
         self.try_take_deleted().unwrap()
     }
@@ -631,6 +758,7 @@ impl<'w, T> WindowMut<'w, T> {
     /// Unwraps errors.
     #[cfg(feature = "panic")]
     #[cfg_attr(docsrs, doc(cfg(feature = "panic")))]
+    #[track_caller]
     pub fn take_removed(&mut self) -> Vec<EntityId> {
         self.try_take_removed().unwrap()
     }
@@ -666,6 +794,7 @@ impl<'w, T> WindowMut<'w, T> {
     /// Unwraps errors.
     #[cfg(feature = "panic")]
     #[cfg_attr(docsrs, doc(cfg(feature = "panic")))]
+    #[track_caller]
     pub fn clear_inserted(&mut self) {
         self.try_clear_inserted().unwrap()
     }
@@ -682,6 +811,7 @@ impl<'w, T> WindowMut<'w, T> {
     /// Unwraps errors.
     #[cfg(feature = "panic")]
     #[cfg_attr(docsrs, doc(cfg(feature = "panic")))]
+    #[track_caller]
     pub fn clear_modified(&mut self) {
         self.try_clear_modified().unwrap()
     }
@@ -699,6 +829,7 @@ impl<'w, T> WindowMut<'w, T> {
     /// Unwraps errors.
     #[cfg(feature = "panic")]
     #[cfg_attr(docsrs, doc(cfg(feature = "panic")))]
+    #[track_caller]
     pub fn clear_inserted_and_modified(&mut self) {
         self.try_clear_inserted_and_modified().unwrap()
     }
@@ -760,6 +891,7 @@ impl<'w, T> WindowMut<'w, T> {
     /// Unwraps errors.
     #[cfg(feature = "panic")]
     #[cfg_attr(docsrs, doc(cfg(feature = "panic")))]
+    #[track_caller]
     pub fn id_at(&self, index: usize) -> EntityId {
         self.try_id_at(index).unwrap()
     }
@@ -781,6 +913,47 @@ impl<'w, T> WindowMut<'w, T> {
     pub fn as_slice(&self) -> &[T] {
         &self.data
     }
+    /// Returns the ids of the entities owning the components of this window, in the same order as [as_slice].
+    ///
+    /// [as_slice]: struct.WindowMut.html#method.as_slice
+    pub fn ids(&self) -> &[EntityId] {
+        self.dense
+    }
+    /// Returns the components of this window as bytes, without copying them.
+    #[cfg(feature = "bytemuck1")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "bytemuck1")))]
+    pub fn as_bytes(&self) -> &[u8]
+    where
+        T: bytemuck::Pod,
+    {
+        bytemuck::cast_slice(self.data)
+    }
+    /// Returns the components of this window owned by entities having all components of its tight pack.  
+    /// They're in the same order in every storage of the pack, the matching ids are the start of [ids].
+    ///
+    /// ### Errors
+    ///
+    /// - Storage isn't tightly packed.
+    ///
+    /// [ids]: struct.WindowMut.html#method.ids
+    pub fn try_tight_packed(&self) -> Result<&[T], error::NotTightPack> {
+        self.as_non_mut().try_tight_packed()
+    }
+    /// Returns the components of this window owned by entities having all components of its tight pack.  
+    /// They're in the same order in every storage of the pack, the matching ids are the start of [ids].  
+    /// Unwraps errors.
+    ///
+    /// ### Errors
+    ///
+    /// - Storage isn't tightly packed.
+    ///
+    /// [ids]: struct.WindowMut.html#method.ids
+    #[cfg(feature = "panic")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "panic")))]
+    #[track_caller]
+    pub fn tight_packed(&self) -> &[T] {
+        self.try_tight_packed().unwrap()
+    }
     /// Returns a window over `range`.
     pub fn try_as_window<R: core::ops::RangeBounds<usize>>(
         &self,
@@ -816,6 +989,7 @@ impl<'w, T> WindowMut<'w, T> {
     /// Unwraps errors.
     #[cfg(feature = "panic")]
     #[cfg_attr(docsrs, doc(cfg(feature = "panic")))]
+    #[track_caller]
     pub fn as_window<R: core::ops::RangeBounds<usize>>(&self, range: R) -> Window<'_, T> {
         self.try_as_window(range).unwrap()
     }
@@ -861,6 +1035,7 @@ impl<'w, T> WindowMut<'w, T> {
     /// Unwraps errors.
     #[cfg(feature = "panic")]
     #[cfg_attr(docsrs, doc(cfg(feature = "panic")))]
+    #[track_caller]
     pub fn as_window_mut<R: core::ops::RangeBounds<usize>>(
         &mut self,
         range: R,
@@ -979,7 +1154,7 @@ impl<'w, T> RawWindowMut<'w, T> {
     /// `entity` must own a component in this storage.  
     /// No borrow must be in progress on `entity` nor `first_non_mod`.
     pub(crate) unsafe fn flag(&self, entity: EntityId) {
-        if let Pack::Update(pack) = &mut (*self.metadata).pack {
+        if let Some(pack) = (*self.metadata).pack.modification_tracking_mut() {
             let first_non_mod = pack.inserted + pack.modified;
             if self.index_of_unchecked(entity) >= first_non_mod {
                 pack.modified += 1;
@@ -988,7 +1163,7 @@ impl<'w, T> RawWindowMut<'w, T> {
     }
     pub(crate) fn flag_all(&mut self) {
         // SAFE we have exclusive access
-        if let Pack::Update(pack) = unsafe { &mut (*self.metadata).pack } {
+        if let Some(pack) = unsafe { (*self.metadata).pack.modification_tracking_mut() } {
             if self.offset + self.dense_len > pack.inserted + pack.modified {
                 pack.modified = self.offset + self.dense_len - pack.inserted;
             }
@@ -1000,7 +1175,7 @@ impl<'w, T> RawWindowMut<'w, T> {
     /// No other borrow should be in progress on `index`.  
     /// Only one call to this function can happen at a time.
     pub(crate) unsafe fn swap_with_last_non_modified(&self, mut index: usize) -> &'w mut T {
-        if let Pack::Update(pack) = &(*self.metadata).pack {
+        if let Some(pack) = (*self.metadata).pack.modification_tracking_mut() {
             let last_non_mut = pack.inserted + pack.modified;
             if self.offset + index >= last_non_mut {
                 ptr::swap(
@@ -0,0 +1,46 @@
+use crate::error;
+use crate::storage::{AllStorages, EntityId};
+use crate::view::{EntitiesViewMut, ViewMut};
+
+/// Trait used to create an entity with a set of components in a single call.
+pub trait Spawn {
+    /// Creates a new entity and adds the components to it.
+    /// Borrows `Entities` and the storage of each component.
+    fn try_spawn(self, all_storages: &AllStorages) -> Result<EntityId, error::GetStorage>;
+}
+
+impl Spawn for () {
+    fn try_spawn(self, all_storages: &AllStorages) -> Result<EntityId, error::GetStorage> {
+        Ok(all_storages
+            .try_borrow::<EntitiesViewMut<'_>>()?
+            .add_entity((), ()))
+    }
+}
+
+macro_rules! impl_spawn {
+    ($($type: ident)+) => {
+        impl<$($type: 'static + Send + Sync),+> Spawn for ($($type,)+) {
+            fn try_spawn(self, all_storages: &AllStorages) -> Result<EntityId, error::GetStorage> {
+                let mut entities = all_storages.try_borrow::<EntitiesViewMut<'_>>()?;
+                let storages = ($(all_storages.try_borrow::<ViewMut<'_, $type>>()?,)+);
+
+                Ok(entities.add_entity(storages, self))
+            }
+        }
+    }
+}
+
+macro_rules! spawn {
+    ($($type: ident)+; $type1: ident $($queue_type: ident)*) => {
+        impl_spawn![$($type)*];
+        spawn![$($type)* $type1; $($queue_type)*];
+    };
+    ($($type: ident)+;) => {
+        impl_spawn![$($type)*];
+    }
+}
+
+#[cfg(not(feature = "extended_tuple"))]
+spawn![A; B C D E F G H I J];
+#[cfg(feature = "extended_tuple")]
+spawn![A; B C D E F G H I J K L M N O P];
@@ -0,0 +1,113 @@
+use crate::storage::StorageId;
+use alloc::vec::Vec;
+use core::cell::Cell;
+use core::sync::atomic::{AtomicUsize, Ordering};
+use core::time::Duration;
+
+std::thread_local! {
+    // entities yielded to the system running on this thread
+    static ITERATED: Cell<usize> = const { Cell::new(0) };
+}
+
+/// Adds `count` to the entities iterated by the current system.
+/// Iterators call it as they yield components, parallel iterators when they're created.
+pub(crate) fn iterated(count: usize) {
+    ITERATED.with(|iterated| iterated.set(iterated.get().wrapping_add(count)));
+}
+
+/// Runs `f` and returns how many entities it iterated.
+// rayon can run another system on this thread while `f` waits,
+// it'll be nested so restoring the previous count is enough to not mix them
+pub(crate) fn count_iterated<R>(f: impl FnOnce() -> R) -> (R, usize) {
+    let previous = ITERATED.with(|iterated| iterated.replace(0));
+    let result = f();
+    let iterated = ITERATED.with(|iterated| iterated.replace(previous));
+
+    (result, iterated)
+}
+
+/// Counters of a system, systems can run in parallel so they have to be atomic.
+#[derive(Default)]
+pub(crate) struct SystemCounters {
+    runs: AtomicUsize,
+    iterated: AtomicUsize,
+}
+
+impl SystemCounters {
+    pub(crate) fn add_run(&self, iterated: usize) {
+        self.runs.fetch_add(1, Ordering::Relaxed);
+        self.iterated.fetch_add(iterated, Ordering::Relaxed);
+    }
+    pub(crate) fn stats(&self, name: &'static str) -> SystemStats {
+        SystemStats {
+            name,
+            runs: self.runs.load(Ordering::Relaxed),
+            iterated: self.iterated.load(Ordering::Relaxed),
+        }
+    }
+}
+
+/// Borrow counters of a storage since its creation.
+/// `len` is the number of components the storage holds, 0 for storages without components.
+#[cfg_attr(docsrs, doc(cfg(feature = "stats")))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct StorageStats {
+    pub name: &'static str,
+    pub len: usize,
+    pub shared_borrows: usize,
+    pub unique_borrows: usize,
+    pub failed_borrows: usize,
+}
+
+/// Counters of a system part of at least one workload.
+/// `iterated` is the total number of entities yielded by the system's iterators over all its runs.
+/// Parallel iterators count all entities they could yield, even if they stop early.
+#[cfg_attr(docsrs, doc(cfg(feature = "stats")))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SystemStats {
+    pub name: &'static str,
+    pub runs: usize,
+    pub iterated: usize,
+}
+
+/// Components removed by [AllStorages::delete_with_stats].
+/// `storages` are the names of the storages `components` were removed from, sorted alphabetically.
+///
+/// [AllStorages::delete_with_stats]: struct.AllStorages.html#method.delete_with_stats
+#[cfg_attr(docsrs, doc(cfg(feature = "stats")))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DeleteStats {
+    pub components: usize,
+    pub storages: Vec<&'static str>,
+}
+
+/// Counters of all storages and systems of a `World`, returned by [World::stats].
+/// `entities` is the number of alive entities.
+///
+/// [World::track_stats] adds it as a unique refreshed at the end of each workload,
+/// `last_workload` is then how long the last workload took.
+///
+/// [World::stats]: struct.World.html#method.stats
+/// [World::track_stats]: struct.World.html#method.track_stats
+#[cfg_attr(docsrs, doc(cfg(feature = "stats")))]
+#[derive(Clone, Debug)]
+pub struct WorldStats {
+    pub entities: usize,
+    pub storages: Vec<(StorageId, StorageStats)>,
+    pub systems: Vec<SystemStats>,
+    pub last_workload: Option<Duration>,
+}
+
+impl WorldStats {
+    /// Returns the stats of the storage named `name`, storages are named after their component type.
+    pub fn storage(&self, name: &str) -> Option<&StorageStats> {
+        self.storages
+            .iter()
+            .map(|(_, stats)| stats)
+            .find(|stats| stats.name == name)
+    }
+    /// Returns the stats of the system named `name`.
+    pub fn system(&self, name: &str) -> Option<&SystemStats> {
+        self.systems.iter().find(|stats| stats.name == name)
+    }
+}
@@ -12,9 +12,10 @@ pub trait DeleteAny {
 
 impl<T: 'static> DeleteAny for (T,) {
     fn delete_any(all_storages: &mut AllStorages) {
+        let type_id = TypeId::of::<T>().into();
         // we have an exclusive reference so it's ok to not lock and still get a reference
-        let storages = unsafe { &*all_storages.storages.get() };
-        if let Some(storage) = storages.get(&TypeId::of::<T>().into()) {
+        let storages = unsafe { &*all_storages.registry.shard_mut(type_id).storages.get() };
+        if let Some(storage) = storages.get(&type_id) {
             if let Ok(mut sparse_set) = storage.sparse_set_mut::<T>() {
                 let ids = sparse_set.dense.clone();
                 sparse_set.clear();
@@ -31,12 +32,13 @@ macro_rules! impl_delete_any {
     ($(($type: ident, $index: tt))+) => {
         impl<$($type: 'static),+> DeleteAny for ($($type,)+) {
             fn delete_any(all_storages: &mut AllStorages) {
-                // we have an exclusive reference so it's ok to not lock and still get a reference
-                let storages = unsafe { &*all_storages.storages.get() };
                 let mut ids: HashSet<EntityId, BuildHasherDefault<TypeIdHasher>> = HashSet::default();
 
                 $(
-                    if let Some(storage) = storages.get(&TypeId::of::<$type>().into()) {
+                    let type_id = TypeId::of::<$type>().into();
+                    // we have an exclusive reference so it's ok to not lock and still get a reference
+                    let storages = unsafe { &*all_storages.registry.shard_mut(type_id).storages.get() };
+                    if let Some(storage) = storages.get(&type_id) {
                         if let Ok(mut sparse_set) = storage.sparse_set_mut::<$type>() {
                             ids.extend(&sparse_set.dense);
                             sparse_set.clear();
@@ -62,4 +64,7 @@ macro_rules! delete_any {
     }
 }
 
+#[cfg(not(feature = "extended_tuple"))]
 delete_any![(A, 0) (B, 1); (C, 2) (D, 3) (E, 4) (F, 5) (G, 6) (H, 7) (I, 8) (J, 9)];
+#[cfg(feature = "extended_tuple")]
+delete_any![(A, 0) (B, 1); (C, 2) (D, 3) (E, 4) (F, 5) (G, 6) (H, 7) (I, 8) (J, 9) (K, 10) (L, 11) (M, 12) (N, 13) (O, 14) (P, 15)];
@@ -0,0 +1,31 @@
+use crate::storage::EntityId;
+use crate::unknown_storage::UnknownStorage;
+
+/// Type-erased access to a storage, given by [AllStorages::for_each_storage].
+/// Only component storages have components, `Entities` and unique storages are always empty.
+///
+/// [AllStorages::for_each_storage]: struct.AllStorages.html#method.for_each_storage
+pub struct ErasedStorage<'a>(pub(super) &'a dyn UnknownStorage);
+
+impl ErasedStorage<'_> {
+    /// Returns the type name of the storage.
+    pub fn name(&self) -> &'static str {
+        self.0.name()
+    }
+    /// Returns the number of components in the storage.
+    pub fn len(&self) -> usize {
+        self.0.component_entities().len()
+    }
+    /// Returns `true` if the storage doesn't contain any component.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+    /// Returns `true` if `entity` owns a component in this storage.
+    pub fn contains(&self, entity: EntityId) -> bool {
+        self.0.has_component(entity)
+    }
+    /// Returns the entities owning a component in this storage.
+    pub fn entities(&self) -> &[EntityId] {
+        self.0.component_entities()
+    }
+}
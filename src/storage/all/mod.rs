@@ -1,33 +1,69 @@
 mod delete_any;
+mod erased_storage;
+mod register_storages;
+mod registry;
+mod take_entity;
 
 pub use delete_any::DeleteAny;
+pub use erased_storage::ErasedStorage;
+pub use register_storages::RegisterStorages;
+pub use take_entity::TakeEntity;
 
-use super::{Entities, EntityId, Storage, StorageId, Unique};
+use super::{Entities, EntityId, Signature, Signatures, Storage, StorageId, Unique};
 use crate::atomic_refcell::{AtomicRefCell, Borrow, Ref, RefMut};
 use crate::borrow::AllStoragesBorrow;
 use crate::entity_builder::EntityBuilder;
 use crate::error;
-use crate::sparse_set::SparseSet;
+use crate::event_log::StructuralEvent;
+use crate::messages::Messages;
+use crate::relation::Relation;
+use crate::snapshot::StorageSnapshot;
+use crate::sparse_set::{SparseSet, Tracking};
+#[cfg(feature = "stats")]
+use crate::stats::DeleteStats;
+use crate::tags::Tags;
 use crate::type_id::TypeId;
+use crate::unknown_storage::UnknownStorage;
 use alloc::boxed::Box;
+use alloc::sync::Arc;
 use alloc::vec::Vec;
-use core::cell::UnsafeCell;
-use hashbrown::{hash_map::Entry, HashMap};
-use parking_lot::{lock_api::RawRwLock as _, RawRwLock};
+use core::any::Any;
+#[cfg(feature = "parallel")]
+use core::sync::atomic::AtomicBool;
+use core::sync::atomic::{AtomicUsize, Ordering};
+use hashbrown::{hash_map::Entry, HashMap, HashSet};
+#[cfg(not(loom))]
+use parking_lot::lock_api::RawRwLock as _;
+use registry::{Registry, Shard};
 
 /// Contains all components present in the World.
-// The lock is held very briefly:
-// - shared: when trying to find a storage
-// - unique: when adding a storage
+// Storages are split in shards, each with its own lock
+// The lock of a shard is held very briefly:
+// - shared: when trying to find or create a storage
+// - unique: when adding or removing a unique storage
 // once the storage is found or created the lock is released
 // this is safe since World is still borrowed and there is no way to delete a storage
 // so any access to storages are valid as long as the World exists
 // we use a HashMap, it can reallocate, but even in this case the storages won't move since they are boxed
 pub struct AllStorages {
-    lock: RawRwLock,
-    storages: UnsafeCell<HashMap<StorageId, Storage>>,
+    registry: Registry,
+    // borrowing a storage that doesn't exist is an error instead of creating it
+    strict: bool,
+    // number of entities new storages can hold before growing their sparse array
+    entity_capacity: usize,
+    // storages are allocated once for `entity_capacity` entities and can't grow
+    fixed_capacity: bool,
+    // new storages log structural events, see World::enable_event_log
+    event_log: bool,
     #[cfg(feature = "non_send")]
-    thread_id: std::thread::ThreadId,
+    thread_id: crate::sync::thread::ThreadId,
+    // number of workloads run, messages are dropped two workloads after being sent
+    workloads: AtomicUsize,
+    // systems are running in parallel, creating a storage is an error
+    #[cfg(feature = "parallel")]
+    parallel_batch: AtomicBool,
+    // storages referencing each entity, shared with Entities and all storages
+    signatures: Arc<Signatures>,
 }
 
 #[cfg(not(feature = "non_send"))]
@@ -37,64 +73,248 @@ unsafe impl Sync for AllStorages {}
 
 impl AllStorages {
     pub(crate) fn new() -> Self {
-        let mut storages = HashMap::default();
-
-        let entities = Entities::new();
+        AllStorages::with_capacity(0)
+    }
+    /// Creates an `AllStorages` able to hold `entity_capacity` entities without reallocating.
+    pub(crate) fn with_capacity(entity_capacity: usize) -> Self {
+        AllStorages::with_entities(Entities::with_capacity(entity_capacity), entity_capacity)
+    }
+    /// Creates an `AllStorages` where `Entities` and all storages are allocated once for `entity_capacity` entities.
+    pub(crate) fn with_fixed_capacity(entity_capacity: usize) -> Self {
+        let mut all_storages = AllStorages::with_entities(
+            Entities::with_fixed_capacity(entity_capacity),
+            entity_capacity,
+        );
+        all_storages.fixed_capacity = true;
+        all_storages
+    }
+    fn with_entities(mut entities: Entities, entity_capacity: usize) -> Self {
+        let mut registry = Registry::default();
+        let signatures = Arc::new(Signatures::default());
+        entities.track_signatures(TypeId::of::<Entities>().into(), signatures.clone());
 
         #[cfg(feature = "std")]
         {
-            storages.insert(
-                TypeId::of::<Entities>().into(),
-                Storage(Box::new(AtomicRefCell::new(entities, None, true))),
-            );
+            registry
+                .entry(TypeId::of::<Entities>().into())
+                .or_insert(Storage(Box::new(AtomicRefCell::new(entities, None, true))));
         }
         #[cfg(not(feature = "std"))]
         {
-            storages.insert(
-                TypeId::of::<Entities>().into(),
-                Storage(Box::new(AtomicRefCell::new(entities))),
-            );
+            registry
+                .entry(TypeId::of::<Entities>().into())
+                .or_insert(Storage(Box::new(AtomicRefCell::new(entities))));
         }
+        registry.set_name(TypeId::of::<Entities>().into(), "Entities");
 
         AllStorages {
-            storages: UnsafeCell::new(storages),
-            lock: RawRwLock::INIT,
+            registry,
+            strict: false,
+            entity_capacity,
+            fixed_capacity: false,
+            event_log: false,
             #[cfg(feature = "non_send")]
-            thread_id: std::thread::current().id(),
+            thread_id: crate::sync::thread::current().id(),
+            workloads: AtomicUsize::new(0),
+            #[cfg(feature = "parallel")]
+            parallel_batch: AtomicBool::new(false),
+            signatures,
         }
     }
+    /// Creates an `AllStorages` with the state of `shared`.  
+    /// Component storages are only cloned the first time they're modified, `Entities` is cloned right away.
+    pub(crate) fn from_shared(shared: HashMap<StorageId, StorageSnapshot>) -> Self {
+        let mut all_storages = AllStorages::new();
+
+        for (storage_id, storage_snapshot) in shared {
+            all_storages
+                .registry
+                .set_name(storage_id, storage_snapshot.name());
+            all_storages
+                .registry
+                .entry(storage_id)
+                .insert(storage_snapshot.fork());
+        }
+
+        all_storages.track_signatures();
+
+        all_storages
+    }
+    /// Returns an independent copy of `Entities` and all storages set up to be cloned.  
+    /// Storages a fork didn't modify keep sharing the state it was forked from.
+    pub(crate) fn deep_clone(&mut self) -> Result<AllStorages, error::Borrow> {
+        let mut clone = AllStorages::with_capacity(self.entity_capacity);
+
+        for (shard, clone_shard) in self.registry.shards_mut().zip(clone.registry.shards_mut()) {
+            let clone_storages = clone_shard.storages.get_mut();
+
+            for (&storage_id, storage) in shard.storages.get_mut().iter() {
+                if let Some(storage) = storage.0.try_borrow()?.clone_storage() {
+                    clone_storages.insert(storage_id, storage);
+                }
+            }
+        }
+
+        for (storage_id, name) in self.registry.names() {
+            clone.registry.set_name(storage_id, name);
+        }
+
+        clone.track_signatures();
+
+        Ok(clone)
+    }
+    /// Makes all storages keep the signature table up to date, when they were added in bulk instead of created one by one.
+    pub(crate) fn track_signatures(&mut self) {
+        let signatures = &self.signatures;
+
+        for (&storage_id, storage) in self.registry.iter_mut() {
+            storage
+                .0
+                .get_mut()
+                .track_signatures(storage_id, signatures.clone());
+        }
+    }
+    /// Returns the storage of `storage_id`, outside of strict mode it's created with `f` if it doesn't exist.  
+    /// Storages can't be created while a parallel batch is running.
+    // the lock of `shard` has to be held
+    fn get_or_create<'s, T: 'static>(
+        &self,
+        shard: &'s Shard,
+        storage_id: StorageId,
+        f: impl FnOnce() -> Storage,
+    ) -> Result<&'s Storage, error::GetStorage> {
+        self.cached(storage_id, || {
+            if shard.get(storage_id).is_none() {
+                if self.strict {
+                    return None;
+                }
+
+                #[cfg(feature = "parallel")]
+                {
+                    if self.parallel_batch.load(Ordering::Acquire) {
+                        return None;
+                    }
+                }
+            }
+
+            Some(self.get_or_insert_with::<T>(shard, storage_id, self.entity_capacity, f))
+        })
+        .ok_or_else(|| {
+            #[cfg(feature = "parallel")]
+            {
+                if !self.strict {
+                    return error::GetStorage::CreationInBatch(core::any::type_name::<T>());
+                }
+            }
+
+            error::GetStorage::MissingStorage(core::any::type_name::<T>())
+        })
+    }
+    /// Marks the start or the end of a batch of systems running in parallel.
+    #[cfg(feature = "parallel")]
+    pub(crate) fn set_parallel_batch(&self, parallel_batch: bool) {
+        self.parallel_batch.store(parallel_batch, Ordering::Release);
+    }
+    /// Orders the messages sent during the last parallel batch, if `storage_id` is a messages storage.
+    #[cfg(feature = "parallel")]
+    pub(crate) fn sort_messages(&self, storage_id: StorageId) {
+        let shard = self.registry.shard(storage_id);
+        shard.lock.lock_shared();
+        if let Some(Ok(storage)) = shard.get(storage_id).map(|storage| storage.0.try_borrow()) {
+            storage.sort_messages();
+        }
+        unsafe { shard.lock.unlock_shared() };
+    }
+    /// Returns the storage of `storage_id` from the cache of the running system, or finds it with `get`.
+    // the lock of the storage's shard has to be held
+    fn cached<'s>(
+        &self,
+        storage_id: StorageId,
+        get: impl FnOnce() -> Option<&'s Storage>,
+    ) -> Option<&'s Storage> {
+        #[cfg(feature = "std")]
+        {
+            crate::storage_cache::get_or_cache(self.registry.generation(), storage_id, get)
+        }
+        #[cfg(not(feature = "std"))]
+        {
+            let _ = storage_id;
+            get()
+        }
+    }
+    /// Returns the storage of `storage_id`, if it doesn't exist it's created with `f`.  
+    /// New storages are named after `T`. With a fixed capacity, new storages are allocated for `capacity` components.
+    // the lock of `shard` has to be held
+    fn get_or_insert_with<'s, T>(
+        &self,
+        shard: &'s Shard,
+        storage_id: StorageId,
+        capacity: usize,
+        f: impl FnOnce() -> Storage,
+    ) -> &'s Storage {
+        // another thread might create the storage at the same time, only one of them is kept
+        shard.get_or_insert_with(storage_id, || {
+            let mut storage = f();
+
+            storage
+                .0
+                .get_mut()
+                .track_signatures(storage_id, self.signatures.clone());
+
+            self.registry
+                .set_name(storage_id, core::any::type_name::<T>());
+
+            if self.fixed_capacity {
+                // the storage was just created, no one else can borrow it
+                storage
+                    .0
+                    .try_borrow_mut()
+                    .unwrap()
+                    .set_fixed_capacity(self.entity_capacity, capacity);
+            }
+
+            if self.event_log {
+                // the storage was just created, no one else can borrow it
+                storage.0.try_borrow_mut().unwrap().set_event_log(true);
+            }
+
+            storage
+        })
+    }
     pub(crate) fn entities(&self) -> Result<Ref<'_, Entities>, error::Borrow> {
         let type_id = TypeId::of::<Entities>().into();
-        self.lock.lock_shared();
+        let shard = self.registry.shard(type_id);
+        shard.lock.lock_shared();
         // SAFE we locked
-        let storages = unsafe { &*self.storages.get() };
+        let storages = unsafe { &*shard.storages.get() };
         // AllStorages is always created with Entities so there's no way to not find it
-        let storage = &storages[&type_id];
+        let storage = self.cached(type_id, || Some(&storages[&type_id])).unwrap();
         match storage.entities() {
             Ok(entities) => {
-                unsafe { self.lock.unlock_shared() };
+                unsafe { shard.lock.unlock_shared() };
                 Ok(entities)
             }
             Err(err) => {
-                unsafe { self.lock.unlock_shared() };
+                unsafe { shard.lock.unlock_shared() };
                 Err(err)
             }
         }
     }
     pub(crate) fn entities_mut(&self) -> Result<RefMut<'_, Entities>, error::Borrow> {
         let type_id = TypeId::of::<Entities>().into();
-        self.lock.lock_shared();
+        let shard = self.registry.shard(type_id);
+        shard.lock.lock_shared();
         // SAFE we locked
-        let storages = unsafe { &*self.storages.get() };
+        let storages = unsafe { &*shard.storages.get() };
         // AllStorages is always created with Entities so there's no way to not find it
-        let storage = &storages[&type_id];
+        let storage = self.cached(type_id, || Some(&storages[&type_id])).unwrap();
         match storage.entities_mut() {
             Ok(entities) => {
-                unsafe { self.lock.unlock_shared() };
+                unsafe { shard.lock.unlock_shared() };
                 Ok(entities)
             }
             Err(err) => {
-                unsafe { self.lock.unlock_shared() };
+                unsafe { shard.lock.unlock_shared() };
                 Err(err)
             }
         }
@@ -103,52 +323,24 @@ impl AllStorages {
         &self,
     ) -> Result<Ref<'_, SparseSet<T>>, error::GetStorage> {
         let type_id = TypeId::of::<T>().into();
-        {
-            self.lock.lock_shared();
-            // SAFE we locked
-            let storages = unsafe { &*self.storages.get() };
-            if let Some(storage) = storages.get(&type_id) {
-                let sparse_set = storage.sparse_set::<T>();
-                unsafe { self.lock.unlock_shared() };
-                return sparse_set;
-            }
-        }
-        unsafe { self.lock.unlock_shared() };
-        self.lock.lock_exclusive();
-        // SAFE we locked
-        let storages = unsafe { &mut *self.storages.get() };
-        // another thread might have initialized the storage before this thread so we use entry
-        let sparse_set = storages
-            .entry(type_id)
-            .or_insert_with(Storage::new::<T>)
-            .sparse_set::<T>();
-        unsafe { self.lock.unlock_exclusive() };
+        let shard = self.registry.shard(type_id);
+        shard.lock.lock_shared();
+        let sparse_set = self
+            .get_or_create::<T>(shard, type_id, || Storage::new::<T>(self.entity_capacity))
+            .and_then(|storage| storage.sparse_set::<T>());
+        unsafe { shard.lock.unlock_shared() };
         sparse_set
     }
     pub(crate) fn sparse_set_mut<T: 'static + Send + Sync>(
         &self,
     ) -> Result<RefMut<'_, SparseSet<T>>, error::GetStorage> {
         let type_id = TypeId::of::<T>().into();
-        {
-            self.lock.lock_shared();
-            // SAFE we locked
-            let storages = unsafe { &*self.storages.get() };
-            if let Some(storage) = storages.get(&type_id) {
-                let sparse_set = storage.sparse_set_mut::<T>();
-                unsafe { self.lock.unlock_shared() };
-                return sparse_set;
-            }
-        }
-        unsafe { self.lock.unlock_shared() };
-        self.lock.lock_exclusive();
-        // SAFE we locked
-        let storages = unsafe { &mut *self.storages.get() };
-        // another thread might have initialized the storage before this thread so we use entry
-        let sparse_set = storages
-            .entry(type_id)
-            .or_insert_with(Storage::new::<T>)
-            .sparse_set_mut::<T>();
-        unsafe { self.lock.unlock_exclusive() };
+        let shard = self.registry.shard(type_id);
+        shard.lock.lock_shared();
+        let sparse_set = self
+            .get_or_create::<T>(shard, type_id, || Storage::new::<T>(self.entity_capacity))
+            .and_then(|storage| storage.sparse_set_mut::<T>());
+        unsafe { shard.lock.unlock_shared() };
         sparse_set
     }
     #[cfg(feature = "non_send")]
@@ -157,26 +349,14 @@ impl AllStorages {
     ) -> Result<Ref<'_, SparseSet<T>>, error::GetStorage> {
         // Sync components can be accessed by any thread with a shared access
         let type_id = TypeId::of::<T>().into();
-        {
-            self.lock.lock_shared();
-            // SAFE we locked
-            let storages = unsafe { &*self.storages.get() };
-            if let Some(storage) = storages.get(&type_id) {
-                let sparse_set = storage.sparse_set::<T>();
-                unsafe { self.lock.unlock_shared() };
-                return sparse_set;
-            }
-        }
-        unsafe { self.lock.unlock_shared() };
-        self.lock.lock_exclusive();
-        // SAFE we locked
-        let storages = unsafe { &mut *self.storages.get() };
-        // another thread might have initialized the storage before this thread so we use entry
-        let sparse_set = storages
-            .entry(type_id)
-            .or_insert_with(|| Storage::new_non_send::<T>(self.thread_id))
-            .sparse_set::<T>();
-        unsafe { self.lock.unlock_exclusive() };
+        let shard = self.registry.shard(type_id);
+        shard.lock.lock_shared();
+        let sparse_set = self
+            .get_or_create::<T>(shard, type_id, || {
+                Storage::new_non_send::<T>(self.entity_capacity, self.thread_id)
+            })
+            .and_then(|storage| storage.sparse_set::<T>());
+        unsafe { shard.lock.unlock_shared() };
         sparse_set
     }
     #[cfg(feature = "non_send")]
@@ -185,26 +365,14 @@ impl AllStorages {
     ) -> Result<RefMut<'_, SparseSet<T>>, error::GetStorage> {
         // Sync components can only be accessed by the thread they were created in with a unique access
         let type_id = TypeId::of::<T>().into();
-        {
-            self.lock.lock_shared();
-            // SAFE we locked
-            let storages = unsafe { &*self.storages.get() };
-            if let Some(storage) = storages.get(&type_id) {
-                let sparse_set = storage.sparse_set_mut::<T>();
-                unsafe { self.lock.unlock_shared() };
-                return sparse_set;
-            }
-        }
-        unsafe { self.lock.unlock_shared() };
-        self.lock.lock_exclusive();
-        // SAFE we locked
-        let storages = unsafe { &mut *self.storages.get() };
-        // another thread might have initialized the storage before this thread so we use entry
-        let sparse_set = storages
-            .entry(type_id)
-            .or_insert_with(|| Storage::new_non_send::<T>(self.thread_id))
-            .sparse_set_mut::<T>();
-        unsafe { self.lock.unlock_exclusive() };
+        let shard = self.registry.shard(type_id);
+        shard.lock.lock_shared();
+        let sparse_set = self
+            .get_or_create::<T>(shard, type_id, || {
+                Storage::new_non_send::<T>(self.entity_capacity, self.thread_id)
+            })
+            .and_then(|storage| storage.sparse_set_mut::<T>());
+        unsafe { shard.lock.unlock_shared() };
         sparse_set
     }
     #[cfg(feature = "non_sync")]
@@ -213,26 +381,14 @@ impl AllStorages {
     ) -> Result<Ref<'_, SparseSet<T>>, error::GetStorage> {
         // Send components can be accessed by one thread at a time
         let type_id = TypeId::of::<T>().into();
-        {
-            self.lock.lock_shared();
-            // SAFE we locked
-            let storages = unsafe { &*self.storages.get() };
-            if let Some(storage) = storages.get(&type_id) {
-                let sparse_set = storage.sparse_set::<T>();
-                unsafe { self.lock.unlock_shared() };
-                return sparse_set;
-            }
-        }
-        unsafe { self.lock.unlock_shared() };
-        self.lock.lock_exclusive();
-        // SAFE we locked
-        let storages = unsafe { &mut *self.storages.get() };
-        // another thread might have initialized the storage before this thread so we use entry
-        let sparse_set = storages
-            .entry(type_id)
-            .or_insert_with(Storage::new_non_sync::<T>)
-            .sparse_set::<T>();
-        unsafe { self.lock.unlock_exclusive() };
+        let shard = self.registry.shard(type_id);
+        shard.lock.lock_shared();
+        let sparse_set = self
+            .get_or_create::<T>(shard, type_id, || {
+                Storage::new_non_sync::<T>(self.entity_capacity)
+            })
+            .and_then(|storage| storage.sparse_set::<T>());
+        unsafe { shard.lock.unlock_shared() };
         sparse_set
     }
     #[cfg(feature = "non_sync")]
@@ -241,26 +397,14 @@ impl AllStorages {
     ) -> Result<RefMut<'_, SparseSet<T>>, error::GetStorage> {
         // Send components can be accessed by one thread at a time
         let type_id = TypeId::of::<T>().into();
-        {
-            self.lock.lock_shared();
-            // SAFE we locked
-            let storages = unsafe { &*self.storages.get() };
-            if let Some(storage) = storages.get(&type_id) {
-                let sparse_set = storage.sparse_set_mut::<T>();
-                unsafe { self.lock.unlock_shared() };
-                return sparse_set;
-            }
-        }
-        unsafe { self.lock.unlock_shared() };
-        self.lock.lock_exclusive();
-        // SAFE we locked
-        let storages = unsafe { &mut *self.storages.get() };
-        // another thread might have initialized the storage before this thread so we use entry
-        let sparse_set = storages
-            .entry(type_id)
-            .or_insert_with(Storage::new_non_sync::<T>)
-            .sparse_set_mut::<T>();
-        unsafe { self.lock.unlock_exclusive() };
+        let shard = self.registry.shard(type_id);
+        shard.lock.lock_shared();
+        let sparse_set = self
+            .get_or_create::<T>(shard, type_id, || {
+                Storage::new_non_sync::<T>(self.entity_capacity)
+            })
+            .and_then(|storage| storage.sparse_set_mut::<T>());
+        unsafe { shard.lock.unlock_shared() };
         sparse_set
     }
     #[cfg(all(feature = "non_send", feature = "non_sync"))]
@@ -269,26 +413,14 @@ impl AllStorages {
     ) -> Result<Ref<'_, SparseSet<T>>, error::GetStorage> {
         // !Send + !Sync components can only be accessed by the thread they were created in
         let type_id = TypeId::of::<T>().into();
-        {
-            self.lock.lock_shared();
-            // SAFE we locked
-            let storages = unsafe { &*self.storages.get() };
-            if let Some(storage) = storages.get(&type_id) {
-                let sparse_set = storage.sparse_set::<T>();
-                unsafe { self.lock.unlock_shared() };
-                return sparse_set;
-            }
-        }
-        unsafe { self.lock.unlock_shared() };
-        self.lock.lock_exclusive();
-        // SAFE we locked
-        let storages = unsafe { &mut *self.storages.get() };
-        // another thread might have initialized the storage before this thread so we use entry
-        let sparse_set = storages
-            .entry(type_id)
-            .or_insert_with(|| Storage::new_non_send_sync::<T>(self.thread_id))
-            .sparse_set::<T>();
-        unsafe { self.lock.unlock_exclusive() };
+        let shard = self.registry.shard(type_id);
+        shard.lock.lock_shared();
+        let sparse_set = self
+            .get_or_create::<T>(shard, type_id, || {
+                Storage::new_non_send_sync::<T>(self.entity_capacity, self.thread_id)
+            })
+            .and_then(|storage| storage.sparse_set::<T>());
+        unsafe { shard.lock.unlock_shared() };
         sparse_set
     }
     #[cfg(all(feature = "non_send", feature = "non_sync"))]
@@ -297,53 +429,124 @@ impl AllStorages {
     ) -> Result<RefMut<'_, SparseSet<T>>, error::GetStorage> {
         // !Send + !Sync components can only be accessed by the thread they were created in
         let type_id = TypeId::of::<T>().into();
-        {
-            self.lock.lock_shared();
-            // SAFE we locked
-            let storages = unsafe { &*self.storages.get() };
-            if let Some(storage) = storages.get(&type_id) {
-                let sparse_set = storage.sparse_set_mut::<T>();
-                unsafe { self.lock.unlock_shared() };
-                return sparse_set;
-            }
-        }
-        unsafe { self.lock.unlock_shared() };
-        self.lock.lock_exclusive();
-        // SAFE we locked
-        let storages = unsafe { &mut *self.storages.get() };
-        // another thread might have initialized the storage before this thread so we use entry
-        let sparse_set = storages
-            .entry(type_id)
-            .or_insert_with(|| Storage::new_non_send_sync::<T>(self.thread_id))
-            .sparse_set_mut::<T>();
-        unsafe { self.lock.unlock_exclusive() };
+        let shard = self.registry.shard(type_id);
+        shard.lock.lock_shared();
+        let sparse_set = self
+            .get_or_create::<T>(shard, type_id, || {
+                Storage::new_non_send_sync::<T>(self.entity_capacity, self.thread_id)
+            })
+            .and_then(|storage| storage.sparse_set_mut::<T>());
+        unsafe { shard.lock.unlock_shared() };
         sparse_set
     }
-    pub(crate) fn unique<T: 'static>(&self) -> Result<Ref<'_, T>, error::GetStorage> {
+    pub(crate) fn relation<T: 'static + Send + Sync>(
+        &self,
+    ) -> Result<Ref<'_, Relation<T>>, error::GetStorage> {
+        let type_id = TypeId::of::<Relation<T>>().into();
+        let shard = self.registry.shard(type_id);
+        shard.lock.lock_shared();
+        let relation = self
+            .get_or_create::<Relation<T>>(shard, type_id, Storage::new_relation::<T>)
+            .and_then(|storage| storage.relation::<T>());
+        unsafe { shard.lock.unlock_shared() };
+        relation
+    }
+    pub(crate) fn relation_mut<T: 'static + Send + Sync>(
+        &self,
+    ) -> Result<RefMut<'_, Relation<T>>, error::GetStorage> {
+        let type_id = TypeId::of::<Relation<T>>().into();
+        let shard = self.registry.shard(type_id);
+        shard.lock.lock_shared();
+        let relation = self
+            .get_or_create::<Relation<T>>(shard, type_id, Storage::new_relation::<T>)
+            .and_then(|storage| storage.relation_mut::<T>());
+        unsafe { shard.lock.unlock_shared() };
+        relation
+    }
+    pub(crate) fn messages<T: 'static + Send + Sync>(
+        &self,
+    ) -> Result<Ref<'_, Messages<T>>, error::GetStorage> {
+        let type_id = TypeId::of::<Messages<T>>().into();
+        let shard = self.registry.shard(type_id);
+        shard.lock.lock_shared();
+        let messages = self
+            .get_or_create::<Messages<T>>(shard, type_id, || {
+                Storage::new_messages::<T>(self.workloads())
+            })
+            .and_then(|storage| storage.messages::<T>());
+        unsafe { shard.lock.unlock_shared() };
+        messages
+    }
+    pub(crate) fn messages_mut<T: 'static + Send + Sync>(
+        &self,
+    ) -> Result<RefMut<'_, Messages<T>>, error::GetStorage> {
+        let type_id = TypeId::of::<Messages<T>>().into();
+        let shard = self.registry.shard(type_id);
+        shard.lock.lock_shared();
+        let messages = self
+            .get_or_create::<Messages<T>>(shard, type_id, || {
+                Storage::new_messages::<T>(self.workloads())
+            })
+            .and_then(|storage| storage.messages_mut::<T>());
+        unsafe { shard.lock.unlock_shared() };
+        messages
+    }
+    /// Returns the number of workloads run.
+    pub(crate) fn workloads(&self) -> usize {
+        self.workloads.load(Ordering::Relaxed)
+    }
+    /// Called by the `World` at the end of each workload.
+    pub(crate) fn end_workload(&self) {
+        self.workloads.fetch_add(1, Ordering::Relaxed);
+    }
+    pub(crate) fn tags(&self) -> Result<Ref<'_, Tags>, error::GetStorage> {
+        let type_id = TypeId::of::<Tags>().into();
+        let shard = self.registry.shard(type_id);
+        shard.lock.lock_shared();
+        let tags = self
+            .get_or_create::<Tags>(shard, type_id, Storage::new_tags)
+            .and_then(|storage| storage.tags());
+        unsafe { shard.lock.unlock_shared() };
+        tags
+    }
+    pub(crate) fn tags_mut(&self) -> Result<RefMut<'_, Tags>, error::GetStorage> {
+        let type_id = TypeId::of::<Tags>().into();
+        let shard = self.registry.shard(type_id);
+        shard.lock.lock_shared();
+        let tags = self
+            .get_or_create::<Tags>(shard, type_id, Storage::new_tags)
+            .and_then(|storage| storage.tags_mut());
+        unsafe { shard.lock.unlock_shared() };
+        tags
+    }
+    /// Returns the unique and its version.
+    pub(crate) fn unique<T: 'static>(&self) -> Result<(Ref<'_, T>, usize), error::GetStorage> {
         let type_id = TypeId::of::<Unique<T>>().into();
-        self.lock.lock_shared();
+        let shard = self.registry.shard(type_id);
+        shard.lock.lock_shared();
         // SAFE we locked
-        let storages = unsafe { &*self.storages.get() };
-        if let Some(storage) = storages.get(&type_id) {
+        let storages = unsafe { &*shard.storages.get() };
+        if let Some(storage) = self.cached(type_id, || storages.get(&type_id)) {
             let unique = storage.unique::<T>();
-            unsafe { self.lock.unlock_shared() };
+            unsafe { shard.lock.unlock_shared() };
             unique
         } else {
-            unsafe { self.lock.unlock_shared() };
+            unsafe { shard.lock.unlock_shared() };
             Err(error::GetStorage::MissingUnique(core::any::type_name::<T>()))
         }
     }
     pub(crate) fn unique_mut<T: 'static>(&self) -> Result<RefMut<'_, T>, error::GetStorage> {
         let type_id = TypeId::of::<Unique<T>>().into();
-        self.lock.lock_shared();
+        let shard = self.registry.shard(type_id);
+        shard.lock.lock_shared();
         // SAFE we locked
-        let storages = unsafe { &*self.storages.get() };
-        if let Some(storage) = storages.get(&type_id) {
+        let storages = unsafe { &*shard.storages.get() };
+        if let Some(storage) = self.cached(type_id, || storages.get(&type_id)) {
             let unique = storage.unique_mut::<T>();
-            unsafe { self.lock.unlock_shared() };
+            unsafe { shard.lock.unlock_shared() };
             unique
         } else {
-            unsafe { self.lock.unlock_shared() };
+            unsafe { shard.lock.unlock_shared() };
             Err(error::GetStorage::MissingUnique(core::any::type_name::<T>()))
         }
     }
@@ -359,13 +562,14 @@ impl AllStorages {
     /// - `T` storage did not exist.
     pub fn try_remove_unique<T: 'static>(&self) -> Result<T, error::UniqueRemove> {
         let type_id = TypeId::of::<Unique<T>>().into();
-        self.lock.lock_exclusive();
+        let shard = self.registry.shard(type_id);
+        shard.lock.lock_exclusive();
         // SAFE we locked
-        let storages = unsafe { &mut *self.storages.get() };
+        let storages = unsafe { &mut *shard.storages.get() };
         if let Entry::Occupied(entry) = storages.entry(type_id) {
             // `.err()` to avoid borrowing `entry` in the `Ok` case
             if let Some(get_storage) = entry.get().unique_mut::<T>().err() {
-                unsafe { self.lock.unlock_exclusive() };
+                unsafe { shard.lock.unlock_exclusive() };
                 match get_storage {
                     error::GetStorage::StorageBorrow(infos) => {
                         Err(error::UniqueRemove::StorageBorrow(infos))
@@ -376,12 +580,13 @@ impl AllStorages {
                 // We were able to lock the storage, we've still got exclusive access even though
                 // we released that lock as we're still holding the `AllStorages` lock.
                 let storage = entry.remove();
-                unsafe { self.lock.unlock_exclusive() };
+                self.registry.invalidate();
+                unsafe { shard.lock.unlock_exclusive() };
                 // SAFE T is a unique storage
                 unsafe { Ok(AtomicRefCell::into_unique::<T>(storage.0)) }
             }
         } else {
-            unsafe { self.lock.unlock_exclusive() };
+            unsafe { shard.lock.unlock_exclusive() };
             Err(error::UniqueRemove::MissingUnique(
                 core::any::type_name::<T>(),
             ))
@@ -409,14 +614,64 @@ impl AllStorages {
     /// [UniqueViewMut]: struct.UniqueViewMut.html
     pub fn add_unique<T: 'static + Send + Sync>(&self, component: T) {
         let type_id = TypeId::of::<Unique<T>>().into();
-        self.lock.lock_exclusive();
+        let shard = self.registry.shard(type_id);
+        shard.lock.lock_exclusive();
         // SAFE we locked
-        let storages = unsafe { &mut *self.storages.get() };
+        let storages = unsafe { &mut *shard.storages.get() };
         // another thread might have initialized the storage before this thread so we use entry
         storages
             .entry(type_id)
             .or_insert_with(|| Storage::new_unique::<T>(component));
-        unsafe { self.lock.unlock_exclusive() };
+        self.registry.set_name(type_id, core::any::type_name::<T>());
+        self.registry.invalidate();
+        unsafe { shard.lock.unlock_exclusive() };
+    }
+    /// Adds a new unique storage, unique storages store exactly one `T` at any time.  
+    /// Unlike [AllStorages::add_unique], the storage is copied by [World::deep_clone].  
+    /// Does nothing if the storage already exists.
+    ///
+    /// [AllStorages::add_unique]: struct.AllStorages.html#method.add_unique
+    /// [World::deep_clone]: struct.World.html#method.deep_clone
+    pub fn add_unique_cloneable<T: 'static + Clone + Send + Sync>(&self, component: T) {
+        let type_id = TypeId::of::<Unique<T>>().into();
+        let shard = self.registry.shard(type_id);
+        shard.lock.lock_exclusive();
+        // SAFE we locked
+        let storages = unsafe { &mut *shard.storages.get() };
+        // another thread might have initialized the storage before this thread so we use entry
+        storages
+            .entry(type_id)
+            .or_insert_with(|| Storage::new_unique_cloneable::<T>(component));
+        self.registry.set_name(type_id, core::any::type_name::<T>());
+        self.registry.invalidate();
+        unsafe { shard.lock.unlock_exclusive() };
+    }
+    /// Adds a new unique storage, unique storages store exactly one `T` at any time.  
+    /// Unlike [AllStorages::add_unique], the storage is included in [World::serialize].  
+    /// Does nothing if the storage already exists.
+    ///
+    /// [AllStorages::add_unique]: struct.AllStorages.html#method.add_unique
+    /// [World::serialize]: struct.World.html#method.serialize
+    #[cfg(feature = "serde1")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "serde1")))]
+    pub fn add_unique_serializable<
+        T: 'static + serde::Serialize + for<'de> serde::Deserialize<'de> + Send + Sync,
+    >(
+        &self,
+        component: T,
+    ) {
+        let type_id = TypeId::of::<Unique<T>>().into();
+        let shard = self.registry.shard(type_id);
+        shard.lock.lock_exclusive();
+        // SAFE we locked
+        let storages = unsafe { &mut *shard.storages.get() };
+        // another thread might have initialized the storage before this thread so we use entry
+        storages
+            .entry(type_id)
+            .or_insert_with(|| Storage::new_unique_serializable::<T>(component));
+        self.registry.set_name(type_id, core::any::type_name::<T>());
+        self.registry.invalidate();
+        unsafe { shard.lock.unlock_exclusive() };
     }
     /// Adds a new unique storage, unique storages store exactly one `T` at any time.  
     /// To access a unique storage value, use [NonSend] and [UniqueViewMut] or [UniqueViewMut].  
@@ -428,14 +683,17 @@ impl AllStorages {
     #[cfg(feature = "non_send")]
     pub fn add_unique_non_send<T: 'static + Sync>(&self, component: T) {
         let type_id = TypeId::of::<Unique<T>>().into();
-        self.lock.lock_exclusive();
+        let shard = self.registry.shard(type_id);
+        shard.lock.lock_exclusive();
         // SAFE we locked
-        let storages = unsafe { &mut *self.storages.get() };
+        let storages = unsafe { &mut *shard.storages.get() };
         // another thread might have initialized the storage before this thread so we use entry
         storages
             .entry(type_id)
             .or_insert_with(|| Storage::new_unique_non_send::<T>(component, self.thread_id));
-        unsafe { self.lock.unlock_exclusive() };
+        self.registry.set_name(type_id, core::any::type_name::<T>());
+        self.registry.invalidate();
+        unsafe { shard.lock.unlock_exclusive() };
     }
     /// Adds a new unique storage, unique storages store exactly one `T` at any time.  
     /// To access a unique storage value, use [NonSync] and [UniqueViewMut] or [UniqueViewMut].  
@@ -447,14 +705,17 @@ impl AllStorages {
     #[cfg(feature = "non_sync")]
     pub fn add_unique_non_sync<T: 'static + Send>(&self, component: T) {
         let type_id = TypeId::of::<Unique<T>>().into();
-        self.lock.lock_exclusive();
+        let shard = self.registry.shard(type_id);
+        shard.lock.lock_exclusive();
         // SAFE we locked
-        let storages = unsafe { &mut *self.storages.get() };
+        let storages = unsafe { &mut *shard.storages.get() };
         // another thread might have initialized the storage before this thread so we use entry
         storages
             .entry(type_id)
             .or_insert_with(|| Storage::new_unique_non_sync::<T>(component));
-        unsafe { self.lock.unlock_exclusive() };
+        self.registry.set_name(type_id, core::any::type_name::<T>());
+        self.registry.invalidate();
+        unsafe { shard.lock.unlock_exclusive() };
     }
     /// Adds a new unique storage, unique storages store exactly one `T` at any time.  
     /// To access a unique storage value, use [NonSync] and [UniqueViewMut] or [UniqueViewMut].  
@@ -466,14 +727,166 @@ impl AllStorages {
     #[cfg(all(feature = "non_send", feature = "non_sync"))]
     pub fn add_unique_non_send_sync<T: 'static>(&self, component: T) {
         let type_id = TypeId::of::<Unique<T>>().into();
-        self.lock.lock_exclusive();
+        let shard = self.registry.shard(type_id);
+        shard.lock.lock_exclusive();
         // SAFE we locked
-        let storages = unsafe { &mut *self.storages.get() };
+        let storages = unsafe { &mut *shard.storages.get() };
         // another thread might have initialized the storage before this thread so we use entry
         storages
             .entry(type_id)
             .or_insert_with(|| Storage::new_unique_non_send_sync::<T>(component, self.thread_id));
-        unsafe { self.lock.unlock_exclusive() };
+        self.registry.set_name(type_id, core::any::type_name::<T>());
+        self.registry.invalidate();
+        unsafe { shard.lock.unlock_exclusive() };
+    }
+    /// Creates the storage of `T` if it doesn't exist.  
+    /// In strict mode storages have to be registered before they can be borrowed.
+    pub fn register_storage<T: 'static + Send + Sync>(&self) {
+        let type_id = TypeId::of::<T>().into();
+        let shard = self.registry.shard(type_id);
+        shard.lock.lock_shared();
+        self.get_or_insert_with::<T>(shard, type_id, self.entity_capacity, || {
+            Storage::new::<T>(self.entity_capacity)
+        });
+        unsafe { shard.lock.unlock_shared() };
+    }
+    /// Creates the storage of `T` if it doesn't exist and reserves memory for at least `capacity` components.  
+    /// With a fixed capacity, the storage is allocated for exactly `capacity` components and can't hold more.
+    ///
+    /// ### Borrows
+    ///
+    /// - `T` storage (exclusive)
+    ///
+    /// ### Errors
+    ///
+    /// - `T` storage borrow failed.
+    pub fn try_register_storage_with_capacity<T: 'static + Send + Sync>(
+        &self,
+        capacity: usize,
+    ) -> Result<(), error::GetStorage> {
+        let type_id = TypeId::of::<T>().into();
+        let shard = self.registry.shard(type_id);
+        shard.lock.lock_shared();
+        self.get_or_insert_with::<T>(shard, type_id, capacity, || {
+            Storage::new::<T>(self.entity_capacity)
+        });
+        unsafe { shard.lock.unlock_shared() };
+
+        let mut sparse_set = self.sparse_set_mut::<T>()?;
+        if self.fixed_capacity {
+            sparse_set.set_fixed_capacity(self.entity_capacity, capacity);
+        } else {
+            sparse_set.reserve(capacity);
+        }
+
+        Ok(())
+    }
+    /// Creates the storage of `T` if it doesn't exist and reserves memory for at least `capacity` components.  
+    /// With a fixed capacity, the storage is allocated for exactly `capacity` components and can't hold more.  
+    /// Unwraps errors.
+    ///
+    /// ### Borrows
+    ///
+    /// - `T` storage (exclusive)
+    ///
+    /// ### Errors
+    ///
+    /// - `T` storage borrow failed.
+    #[cfg(feature = "panic")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "panic")))]
+    #[track_caller]
+    pub fn register_storage_with_capacity<T: 'static + Send + Sync>(&self, capacity: usize) {
+        self.try_register_storage_with_capacity::<T>(capacity)
+            .unwrap()
+    }
+    /// Creates the storage of `T` if it doesn't exist and update packs it, see [Tracking].
+    ///
+    /// ### Borrows
+    ///
+    /// - `T` storage (exclusive)
+    ///
+    /// ### Errors
+    ///
+    /// - `T` storage borrow failed.
+    /// - `T` storage is already tightly or loosly packed.
+    ///
+    /// [Tracking]: ../enum.Tracking.html
+    pub fn try_register_storage_tracked<T: 'static + Send + Sync>(
+        &self,
+        tracking: Tracking,
+    ) -> Result<(), error::Pack> {
+        self.register_storage::<T>();
+        self.sparse_set_mut::<T>()?.try_update_pack_with(tracking)
+    }
+    /// Creates the storage of `T` if it doesn't exist and update packs it, see [Tracking].  
+    /// Unwraps errors.
+    ///
+    /// ### Borrows
+    ///
+    /// - `T` storage (exclusive)
+    ///
+    /// ### Errors
+    ///
+    /// - `T` storage borrow failed.
+    /// - `T` storage is already tightly or loosly packed.
+    ///
+    /// [Tracking]: ../enum.Tracking.html
+    #[cfg(feature = "panic")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "panic")))]
+    #[track_caller]
+    pub fn register_storage_tracked<T: 'static + Send + Sync>(&self, tracking: Tracking) {
+        self.try_register_storage_tracked::<T>(tracking).unwrap()
+    }
+    /// Creates the storage of `T` if it doesn't exist.  
+    /// In strict mode storages have to be registered before they can be borrowed.
+    #[cfg(feature = "non_send")]
+    pub fn register_storage_non_send<T: 'static + Sync>(&self) {
+        let type_id = TypeId::of::<T>().into();
+        let shard = self.registry.shard(type_id);
+        shard.lock.lock_shared();
+        self.get_or_insert_with::<T>(shard, type_id, self.entity_capacity, || {
+            Storage::new_non_send::<T>(self.entity_capacity, self.thread_id)
+        });
+        unsafe { shard.lock.unlock_shared() };
+    }
+    /// Creates the storage of `T` if it doesn't exist.  
+    /// In strict mode storages have to be registered before they can be borrowed.
+    #[cfg(feature = "non_sync")]
+    pub fn register_storage_non_sync<T: 'static + Send>(&self) {
+        let type_id = TypeId::of::<T>().into();
+        let shard = self.registry.shard(type_id);
+        shard.lock.lock_shared();
+        self.get_or_insert_with::<T>(shard, type_id, self.entity_capacity, || {
+            Storage::new_non_sync::<T>(self.entity_capacity)
+        });
+        unsafe { shard.lock.unlock_shared() };
+    }
+    /// Creates the storage of `T` if it doesn't exist.  
+    /// In strict mode storages have to be registered before they can be borrowed.
+    #[cfg(all(feature = "non_send", feature = "non_sync"))]
+    pub fn register_storage_non_send_sync<T: 'static>(&self) {
+        let type_id = TypeId::of::<T>().into();
+        let shard = self.registry.shard(type_id);
+        shard.lock.lock_shared();
+        self.get_or_insert_with::<T>(shard, type_id, self.entity_capacity, || {
+            Storage::new_non_send_sync::<T>(self.entity_capacity, self.thread_id)
+        });
+        unsafe { shard.lock.unlock_shared() };
+    }
+    /// Creates the storages of all types in `T` if they don't exist.  
+    /// In strict mode storages have to be registered before they can be borrowed.
+    ///
+    /// `T` has to be a tuple even for a single type.  
+    /// In this case use (T,).
+    pub fn register_storages<T: RegisterStorages>(&self) {
+        T::register_storages(self);
+    }
+    /// In strict mode, borrowing a storage that was never registered returns [GetStorage::MissingStorage] instead of creating it.  
+    /// Storages that already exist and unique storages are not affected.
+    ///
+    /// [GetStorage::MissingStorage]: error/enum.GetStorage.html#variant.MissingStorage
+    pub fn set_strict(&mut self, strict: bool) {
+        self.strict = strict;
     }
     /// Delete an entity and all its components.
     /// Returns `true` if `entity` was alive.
@@ -518,17 +931,201 @@ impl AllStorages {
             false
         }
     }
+    /// Deletes an entity and all its components, like [AllStorages::delete].  
+    /// Returns how many components were removed and from which storages, `None` if `entity` wasn't alive.  
+    /// Useful to find entities accumulating component types they shouldn't have.
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{AllStoragesViewMut, World};
+    ///
+    /// let world = World::new();
+    /// let entity = world.spawn((0usize, 1u32));
+    ///
+    /// world.run(|mut all_storages: AllStoragesViewMut| {
+    ///     let stats = all_storages.delete_with_stats(entity).unwrap();
+    ///
+    ///     assert_eq!(stats.components, 2);
+    ///     assert_eq!(stats.storages, vec!["u32", "usize"]);
+    ///     assert!(all_storages.delete_with_stats(entity).is_none());
+    /// });
+    /// ```
+    ///
+    /// [AllStorages::delete]: struct.AllStorages.html#method.delete
+    #[cfg(feature = "stats")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "stats")))]
+    pub fn delete_with_stats(&mut self, entity: EntityId) -> Option<DeleteStats> {
+        // we have unique access so the borrow can't fail
+        if !self.entities().unwrap().is_alive(entity) {
+            return None;
+        }
+
+        // we have unique access so the borrows can't fail
+        let storages = self.try_entity_components(entity).unwrap();
+        self.delete(entity);
+
+        Some(DeleteStats {
+            components: storages.len(),
+            storages,
+        })
+    }
+    /// Deletes an entity and all its components without exclusive access to all storages.  
+    /// Returns `true` if `entity` was alive.
+    ///
+    /// `Entities` keeps track of the storages each entity is part of, only these storages are borrowed.  
+    /// Outside workloads, storages `entity` isn't part of can be used at the same time, even exclusively.  
+    /// The scheduler doesn't know which storages `entity` is part of, like any `AllStoragesView` this conflicts with all exclusive borrows.
+    ///
+    /// ### Borrows
+    ///
+    /// - Entities (exclusive)
+    /// - Storages where `entity` has or shares a component, tags or relations (exclusive)
+    ///
+    /// ### Errors
+    ///
+    /// - Entities already borrowed.
+    /// - A storage `entity` is part of already borrowed, nothing is deleted.
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{AllStoragesViewMut, World};
+    ///
+    /// let world = World::new();
+    /// let entity = world.spawn((0usize, 1u32));
+    ///
+    /// world.run(|all_storages: AllStoragesViewMut| {
+    ///     assert_eq!(all_storages.try_delete_entity(entity), Ok(true));
+    ///     assert_eq!(all_storages.try_delete_entity(entity), Ok(false));
+    /// });
+    /// ```
+    pub fn try_delete_entity(&self, entity: EntityId) -> Result<bool, error::GetStorage> {
+        let mut entities = self.entities_mut().map_err(error::GetStorage::Entities)?;
+
+        if !entities.is_alive(entity) {
+            return Ok(false);
+        }
+
+        let mut storages = Vec::new();
+
+        // all borrows are acquired before modifying anything
+        let mut storage_ids = Vec::new();
+        entities
+            .signatures()
+            .references(entity, |storage_id| storage_ids.push(storage_id));
+
+        for storage_id in storage_ids {
+            let shard = self.registry.shard(storage_id);
+            shard.lock.lock_shared();
+
+            let borrowed = shard
+                .get(storage_id)
+                .map(|storage| storage.0.try_borrow_mut());
+
+            unsafe { shard.lock.unlock_shared() };
+
+            match borrowed {
+                Some(Ok(storage)) => storages.push((storage_id, storage)),
+                Some(Err(borrow)) => {
+                    return Err(error::GetStorage::StorageBorrow((
+                        self.registry.name(storage_id).unwrap_or_default(),
+                        borrow,
+                    )))
+                }
+                // the table is only filled by the storages of this World
+                None => {}
+            }
+        }
+
+        entities.delete(entity);
+        drop(entities);
+
+        let mut storage_to_unpack = Vec::new();
+        for (_, storage) in &mut storages {
+            storage.delete(entity, &mut storage_to_unpack);
+        }
+
+        // storages not borrowed don't have a component for `entity` so they don't need to be unpacked
+        for type_id in storage_to_unpack {
+            if let Some((_, storage)) = storages
+                .iter_mut()
+                .find(|(storage_id, _)| *storage_id == StorageId::TypeId(type_id))
+            {
+                storage.unpack(entity);
+            }
+        }
+
+        Ok(true)
+    }
+    /// Deletes an entity and all its components without exclusive access to all storages.  
+    /// Returns `true` if `entity` was alive.  
+    /// Unwraps errors.
+    ///
+    /// `Entities` keeps track of the storages each entity is part of, only these storages are borrowed.  
+    /// Outside workloads, storages `entity` isn't part of can be used at the same time, even exclusively.  
+    /// The scheduler doesn't know which storages `entity` is part of, like any `AllStoragesView` this conflicts with all exclusive borrows.
+    ///
+    /// ### Borrows
+    ///
+    /// - Entities (exclusive)
+    /// - Storages where `entity` has or shares a component, tags or relations (exclusive)
+    ///
+    /// ### Errors
+    ///
+    /// - Entities already borrowed.
+    /// - A storage `entity` is part of already borrowed, nothing is deleted.
+    #[cfg(feature = "panic")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "panic")))]
+    #[track_caller]
+    pub fn delete_entity(&self, entity: EntityId) -> bool {
+        self.try_delete_entity(entity).unwrap()
+    }
+    /// Deletes `entity` and returns its `T` components by value.  
+    /// Returns `None` if `entity` isn't alive.
+    ///
+    /// `T` has to be a tuple even for a single type.  
+    /// In this case use (T,).
+    ///
+    /// Components the entity doesn't own are returned as `None` and components of types not in `T` are dropped.
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{AllStoragesViewMut, World};
+    ///
+    /// let world = World::new();
+    /// let entity = world.spawn((0usize, 1u32));
+    ///
+    /// world.run(|mut all_storages: AllStoragesViewMut| {
+    ///     assert_eq!(
+    ///         all_storages.take_entity::<(usize, u32, u64)>(entity),
+    ///         Some((Some(0), Some(1), None))
+    ///     );
+    ///     assert_eq!(all_storages.take_entity::<(usize,)>(entity), None);
+    /// });
+    /// ```
+    pub fn take_entity<T: TakeEntity>(&mut self, entity: EntityId) -> Option<T::Out> {
+        // we have unique access so the borrow can't fail
+        if !self.entities().unwrap().is_alive(entity) {
+            return None;
+        }
+
+        let components = T::take_entity(self, entity);
+        self.delete(entity);
+
+        Some(components)
+    }
     /// Deletes all components from an entity without deleting it.
     pub fn strip(&mut self, entity: EntityId) {
         // no need to lock here since we have a unique access
         let mut storage_to_unpack = Vec::new();
-        // SAFE we have unique access
-        let storages = unsafe { &mut *self.storages.get() };
+        let storages = &mut self.registry;
 
-        for storage in storages.values_mut() {
-            // we have unique access to all storages so we can unwrap
-            storage.delete(entity, &mut storage_to_unpack).unwrap();
-        }
+        // only the storages referencing `entity` have something to delete
+        self.signatures.references(entity, |storage_id| {
+            if let Some(storage) = storages.get_mut(&storage_id) {
+                // we have unique access to all storages so we can unwrap
+                storage.delete(entity, &mut storage_to_unpack).unwrap();
+            }
+        });
 
         for storage in storage_to_unpack {
             storages
@@ -538,12 +1135,410 @@ impl AllStorages {
                 .unwrap();
         }
     }
+    /// Returns the type name of all components `entity` owns, sorted alphabetically.  
+    /// Useful to log what an entity is made of.
+    ///
+    /// ### Borrows
+    ///
+    /// - All storages (shared, while checking)
+    ///
+    /// ### Errors
+    ///
+    /// - A storage is already borrowed exclusively.
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{AllStoragesViewMut, World};
+    ///
+    /// let world = World::new();
+    /// let entity = world.spawn((0usize, 1u32));
+    ///
+    /// world.run(|all_storages: AllStoragesViewMut| {
+    ///     assert_eq!(
+    ///         all_storages.try_entity_components(entity),
+    ///         Ok(vec!["u32", "usize"])
+    ///     );
+    /// });
+    /// ```
+    pub fn try_entity_components(
+        &self,
+        entity: EntityId,
+    ) -> Result<Vec<&'static str>, error::GetStorage> {
+        let mut names = Vec::new();
+
+        for shard in self.registry.shards() {
+            shard.lock.lock_shared();
+
+            let borrowed = shard.iter().try_for_each(|(&storage_id, storage)| {
+                let storage = storage
+                    .0
+                    .try_borrow()
+                    .map_err(|borrow| (storage_id, borrow))?;
+
+                // forks check the state they share with the original World without cloning it
+                if storage.has_component(entity) {
+                    names.push(storage.name());
+                }
+
+                Ok(())
+            });
+
+            unsafe { shard.lock.unlock_shared() };
+
+            if let Err((storage_id, borrow)) = borrowed {
+                return Err(error::GetStorage::StorageBorrow((
+                    self.registry.name(storage_id).unwrap_or_default(),
+                    borrow,
+                )));
+            }
+        }
+
+        names.sort_unstable();
+
+        Ok(names)
+    }
+    /// Returns the type name of all components `entity` owns, sorted alphabetically.  
+    /// Useful to log what an entity is made of.  
+    /// Unwraps errors.
+    ///
+    /// ### Borrows
+    ///
+    /// - All storages (shared, while checking)
+    ///
+    /// ### Errors
+    ///
+    /// - A storage is already borrowed exclusively.
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{AllStoragesViewMut, World};
+    ///
+    /// let world = World::new();
+    /// let entity = world.spawn((0usize, 1u32));
+    ///
+    /// world.run(|all_storages: AllStoragesViewMut| {
+    ///     assert_eq!(all_storages.entity_components(entity), vec!["u32", "usize"]);
+    /// });
+    /// ```
+    #[cfg(feature = "panic")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "panic")))]
+    #[track_caller]
+    pub fn entity_components(&self, entity: EntityId) -> Vec<&'static str> {
+        self.try_entity_components(entity).unwrap()
+    }
+    /// Returns the [Signature] of `entity`, the ids of all storages where it has a component.
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{AllStoragesViewMut, Signature, StorageId, World};
+    ///
+    /// let world = World::new();
+    /// let entity = world.spawn((0usize, 1u32));
+    ///
+    /// world.run(|mut all_storages: AllStoragesViewMut| {
+    ///     assert_eq!(
+    ///         all_storages.signature(entity),
+    ///         Signature::new(&[StorageId::of::<usize>(), StorageId::of::<u32>()])
+    ///     );
+    /// });
+    /// ```
+    ///
+    /// [Signature]: struct.Signature.html
+    pub fn signature(&mut self, entity: EntityId) -> Signature {
+        self.registry
+            .iter()
+            .filter(|(_, storage)| {
+                // we have unique access to all storages so we can unwrap
+                storage.0.try_borrow().unwrap().has_component(entity)
+            })
+            .map(|(&storage_id, _)| storage_id)
+            .collect()
+    }
+    /// Returns the entities with exactly the components of `signature`, no more no less.  
+    /// Each storage is visited once, instead of checking every storage for every entity.
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{AllStoragesViewMut, Signature, StorageId, World};
+    ///
+    /// let world = World::new();
+    /// let entity = world.spawn((0usize, 1u32));
+    /// world.spawn((2u32,));
+    /// world.spawn((3usize, 4u32, 5u64));
+    ///
+    /// world.run(|mut all_storages: AllStoragesViewMut| {
+    ///     let signature = all_storages.signature(entity);
+    ///
+    ///     assert_eq!(all_storages.entities_matching_signature(&signature), vec![entity]);
+    /// });
+    /// ```
+    pub fn entities_matching_signature(&mut self, signature: &Signature) -> Vec<EntityId> {
+        let mut storages = Vec::with_capacity(signature.len());
+        let mut excluded = HashSet::new();
+        let mut alive = Vec::new();
+        for (&storage_id, storage) in self.registry.iter() {
+            // we have unique access to all storages so we can unwrap
+            let storage = storage.0.try_borrow().unwrap();
+
+            if let Some(entities) = storage.entities() {
+                // entities without any component
+                if signature.is_empty() {
+                    alive.extend(entities.iter());
+                }
+            } else if signature.contains(storage_id) {
+                storages.push(storage);
+            } else {
+                excluded.extend(storage.component_entities().iter().copied());
+            }
+        }
+
+        // one of the storages doesn't exist, no entity can have a component in it
+        if storages.len() != signature.len() {
+            return Vec::new();
+        }
+
+        let is_match = |entity: &EntityId| {
+            !excluded.contains(entity)
+                && storages
+                    .iter()
+                    .all(|storage| storage.has_component(*entity))
+        };
+
+        // iterating the smallest storage visits the fewest entities
+        match storages
+            .iter()
+            .min_by_key(|storage| storage.component_entities().len())
+        {
+            Some(smallest) => smallest
+                .component_entities()
+                .iter()
+                .copied()
+                .filter(is_match)
+                .collect(),
+            None => alive.into_iter().filter(is_match).collect(),
+        }
+    }
+    /// Returns the type name of the storage of `storage_id`, `None` if it was never created.  
+    /// Unlike borrowing the storage, this works while it's borrowed and after it's removed.
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{AllStoragesViewMut, StorageId, World};
+    ///
+    /// let world = World::new();
+    /// world.spawn((0u32,));
+    ///
+    /// world.run(|all_storages: AllStoragesViewMut| {
+    ///     assert_eq!(all_storages.storage_name(StorageId::of::<u32>()), Some("u32"));
+    ///     assert_eq!(all_storages.storage_name(StorageId::of::<u64>()), None);
+    /// });
+    /// ```
+    pub fn storage_name(&self, storage_id: StorageId) -> Option<&'static str> {
+        self.registry.name(storage_id)
+    }
+    /// Returns the id and type name of all storages ever created, sorted by id.  
+    /// Useful to print human-readable storage names in logs and debug dumps.
+    pub fn storage_names(&self) -> Vec<(StorageId, &'static str)> {
+        let mut names = self.registry.names().into_iter().collect::<Vec<_>>();
+
+        names.sort_unstable();
+
+        names
+    }
+    /// Calls `f` with each entity owning a component in all storages of `storage_ids`, along with these components in the same order.  
+    /// Lets tools filter entities with types only known at runtime, components can be downcast with `Any`.  
+    /// No entity matches if `storage_ids` is empty or one of the storages doesn't exist.
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{AllStoragesViewMut, StorageId, World};
+    ///
+    /// let world = World::new();
+    /// let entity = world.spawn((0usize, 1u32));
+    /// world.spawn((2u32,));
+    ///
+    /// world.run(|mut all_storages: AllStoragesViewMut| {
+    ///     let mut matches = Vec::new();
+    ///
+    ///     all_storages.query_dynamic(
+    ///         &[StorageId::of::<u32>(), StorageId::of::<usize>()],
+    ///         |id, components| {
+    ///             matches.push((id, *components[0].downcast_ref::<u32>().unwrap()));
+    ///         },
+    ///     );
+    ///
+    ///     assert_eq!(matches, vec![(entity, 1)]);
+    /// });
+    /// ```
+    pub fn query_dynamic(
+        &mut self,
+        storage_ids: &[StorageId],
+        mut f: impl FnMut(EntityId, &[&dyn Any]),
+    ) {
+        let mut storages = Vec::with_capacity(storage_ids.len());
+        storages.resize_with(storage_ids.len(), || None);
+        for (storage_id, storage) in self.registry.iter() {
+            for (i, id) in storage_ids.iter().enumerate() {
+                if id == storage_id {
+                    // we have unique access to all storages so we can unwrap
+                    storages[i] = Some(storage.0.try_borrow().unwrap());
+                }
+            }
+        }
+
+        let storages = match storages.into_iter().collect::<Option<Vec<_>>>() {
+            Some(storages) => storages,
+            None => return,
+        };
+
+        // iterating the smallest storage visits the fewest entities
+        let smallest = match storages
+            .iter()
+            .min_by_key(|storage| storage.component_entities().len())
+        {
+            Some(smallest) => smallest,
+            None => return,
+        };
+
+        let mut components = Vec::with_capacity(storages.len());
+        'entities: for &entity in smallest.component_entities() {
+            components.clear();
+
+            for storage in &storages {
+                match storage.component(entity) {
+                    Some(component) => components.push(component),
+                    None => continue 'entities,
+                }
+            }
+
+            f(entity, &components);
+        }
+    }
+    /// Calls `f` with the id of each storage and type-erased access to it, in no particular order.  
+    /// Lets tools like leak detectors or statistics inspect storages without knowing their types.
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{AllStoragesViewMut, StorageId, World};
+    ///
+    /// let world = World::new();
+    /// let entity = world.spawn((0usize, 1u32));
+    /// world.spawn((2u32,));
+    ///
+    /// world.run(|mut all_storages: AllStoragesViewMut| {
+    ///     let mut lens = Vec::new();
+    ///
+    ///     all_storages.for_each_storage(|storage_id, storage| {
+    ///         if storage.contains(entity) {
+    ///             lens.push((storage.name(), storage.len()));
+    ///         }
+    ///
+    ///         if storage_id == StorageId::of::<u32>() {
+    ///             assert_eq!(storage.len(), 2);
+    ///         }
+    ///     });
+    ///
+    ///     lens.sort_unstable();
+    ///     assert_eq!(lens, vec![("u32", 2), ("usize", 1)]);
+    /// });
+    /// ```
+    pub fn for_each_storage(&mut self, mut f: impl FnMut(StorageId, ErasedStorage<'_>)) {
+        for (&storage_id, storage) in self.registry.iter() {
+            // we have unique access to all storages so we can unwrap
+            let storage = storage.0.try_borrow().unwrap();
+
+            f(storage_id, ErasedStorage(&*storage));
+        }
+    }
+    /// Returns the id of the storage set up for reflection named `name`, with or without its module path.
+    fn reflected_storage(&mut self, name: &str) -> Result<StorageId, error::Reflect> {
+        let names = self.registry.names();
+
+        names
+            .into_iter()
+            .filter(|&(_, storage_name)| {
+                storage_name == name || storage_name.rsplit("::").next() == Some(name)
+            })
+            .find(|(storage_id, _)| match self.registry.get_mut(storage_id) {
+                // we have unique access to all storages so we can unwrap
+                Some(storage) => storage.0.try_borrow().unwrap().is_reflected(),
+                None => false,
+            })
+            .map(|(storage_id, _)| storage_id)
+            .ok_or(error::Reflect::MissingStorage)
+    }
+    /// Sets the field at `path` of `entity`'s component to `value`.
+    pub(crate) fn set_field<V: 'static>(
+        &mut self,
+        entity: EntityId,
+        path: &str,
+        value: V,
+    ) -> Result<(), error::Reflect> {
+        let mut segments = path.splitn(2, '.');
+        let storage_id = self.reflected_storage(segments.next().unwrap_or_default())?;
+        // we have unique access to all storages so we can unwrap
+        let mut storage = self
+            .registry
+            .get_mut(&storage_id)
+            .unwrap()
+            .0
+            .try_borrow_mut()
+            .unwrap();
+
+        let field = storage
+            .reflect_mut(entity)
+            .ok_or(error::Reflect::MissingComponent(entity))?
+            .path_mut(segments.next().unwrap_or_default())
+            .ok_or(error::Reflect::MissingField)?;
+
+        *field
+            .as_any_mut()
+            .downcast_mut::<V>()
+            .ok_or_else(|| error::Reflect::WrongType(core::any::type_name::<V>()))? = value;
+
+        Ok(())
+    }
+    /// Returns a copy of the field at `path` of `entity`'s component.
+    pub(crate) fn field<V: 'static + Clone>(
+        &mut self,
+        entity: EntityId,
+        path: &str,
+    ) -> Result<V, error::Reflect> {
+        let mut segments = path.splitn(2, '.');
+        let storage_id = self.reflected_storage(segments.next().unwrap_or_default())?;
+        // we have unique access to all storages so we can unwrap
+        let storage = self
+            .registry
+            .get_mut(&storage_id)
+            .unwrap()
+            .0
+            .try_borrow()
+            .unwrap();
+
+        storage
+            .reflect(entity)
+            .ok_or(error::Reflect::MissingComponent(entity))?
+            .path(segments.next().unwrap_or_default())
+            .ok_or(error::Reflect::MissingField)?
+            .as_any()
+            .downcast_ref::<V>()
+            .cloned()
+            .ok_or_else(|| error::Reflect::WrongType(core::any::type_name::<V>()))
+    }
+    /// Calls [compact] on all component storages.  
+    /// Storages shared with a [fork] are left untouched.
+    ///
+    /// [compact]: struct.SparseSet.html#method.compact
+    /// [fork]: struct.World.html#method.fork
+    pub fn compact_all(&mut self) {
+        for storage in self.registry.values_mut() {
+            // we have unique access to all storages so we can unwrap
+            storage.0.try_borrow_mut().unwrap().compact();
+        }
+    }
     /// Deletes all entities and their components.
     pub fn clear(&mut self) {
-        // SAFE we have unique access
-        let storages = unsafe { &mut *self.storages.get() };
-
-        for storage in storages.values_mut() {
+        for storage in self.registry.values_mut() {
             // we have unique access to all storages so we can unwrap
             storage.clear().unwrap()
         }
@@ -764,6 +1759,7 @@ world.run(|all_storages: AllStoragesViewMut| {
     )]
     #[cfg(feature = "panic")]
     #[cfg_attr(docsrs, doc(cfg(feature = "panic")))]
+    #[track_caller]
     pub fn borrow<'s, V: AllStoragesBorrow<'s>>(&'s self) -> V {
         self.try_borrow::<V>().unwrap()
     }
@@ -966,6 +1962,7 @@ You can use:
     )]
     #[cfg(feature = "panic")]
     #[cfg_attr(docsrs, doc(cfg(feature = "panic")))]
+    #[track_caller]
     pub fn run_with_data<'s, Data, B, R, S: crate::system::AllSystem<'s, (Data,), B, R>>(
         &'s self,
         s: S,
@@ -1204,6 +2201,7 @@ let i = all_storages.run(sys1);
     )]
     #[cfg(feature = "panic")]
     #[cfg_attr(docsrs, doc(cfg(feature = "panic")))]
+    #[track_caller]
     pub fn run<'s, B, R, S: crate::system::AllSystem<'s, (), B, R>>(&'s self, s: S) -> R {
         self.try_run(s).unwrap()
     }
@@ -1226,10 +2224,29 @@ let i = all_storages.run(sys1);
             borrow: Borrow::None,
         })
     }
-    #[cfg(feature = "serde1")]
-    pub(crate) fn storages(&mut self) -> &mut HashMap<StorageId, Storage> {
-        // SAFE we have exclusive access
-        unsafe { &mut *self.storages.get() }
+    /// Starts or stops logging structural events in all storages, including the ones created later.
+    pub(crate) fn set_event_log(&mut self, enabled: bool) -> Result<(), error::Borrow> {
+        self.event_log = enabled;
+
+        for storage in self.storages().values_mut() {
+            storage.0.try_borrow_mut()?.set_event_log(enabled);
+        }
+
+        Ok(())
+    }
+    /// Returns the structural events logged since the last call, in the order they happened.
+    pub(crate) fn drain_events(&mut self) -> Result<Vec<StructuralEvent>, error::Borrow> {
+        let mut events = Vec::new();
+        for storage in self.storages().values_mut() {
+            storage.0.try_borrow_mut()?.drain_events(&mut events);
+        }
+
+        events.sort_unstable_by_key(|(index, _)| *index);
+
+        Ok(events.into_iter().map(|(_, event)| event).collect())
+    }
+    pub(crate) fn storages(&mut self) -> &mut Registry {
+        &mut self.registry
     }
 }
 
@@ -1245,23 +2262,42 @@ impl serde::Serialize for AllStoragesSerializer<'_> {
     where
         S: serde::Serializer,
     {
-        use serde::ser::SerializeStruct;
+        use serde::ser::{SerializeStruct, SerializeTuple};
 
-        let all_storages = unsafe { &*self.all_storages.storages.get() };
+        // SAFE the RefMut gives us exclusive access
+        let all_storages = unsafe { self.all_storages.registry.iter_unchecked() };
 
-        let mut storages = Vec::with_capacity(all_storages.len());
+        let mut storages = Vec::new();
 
         let metadata = all_storages
-            .iter()
             .filter_map(|(type_id, storage)| {
+                #[cfg(any(feature = "non_send", feature = "non_sync"))]
+                if storage.0.is_thread_local() {
+                    match self.ser_config.thread_local {
+                        crate::serde_setup::ThreadLocalStorages::Skip => return None,
+                        crate::serde_setup::ThreadLocalStorages::OwningThread => {
+                            if storage.0.is_wrong_thread() {
+                                return Some(Err(serde::ser::Error::custom(format_args!(
+                                    "{} can only be serialized from the thread owning the World.",
+                                    self.all_storages
+                                        .registry
+                                        .name(*type_id)
+                                        .unwrap_or("Storage")
+                                ))));
+                            }
+                        }
+                    }
+                }
+
                 let storage = storage.0.try_borrow().unwrap();
 
                 if storage.is_serializable() && !storage.skip_serialization(self.ser_config) {
                     let result = match storage.deserialize() {
-                        Some(deserialize) => Ok((
-                            type_id,
-                            crate::unknown_storage::deserialize_ptr(deserialize),
-                        )),
+                        Some(deserialize) => Ok(crate::unknown_storage::StorageMetadata {
+                            storage_id: *type_id,
+                            deserialize_ptr: crate::unknown_storage::deserialize_ptr(deserialize),
+                            version: storage.serde_version(),
+                        }),
                         None => Err(serde::ser::Error::custom(
                             "Unknown storage's implementation is incorrect.",
                         )),
@@ -1276,20 +2312,27 @@ impl serde::Serialize for AllStoragesSerializer<'_> {
             })
             .collect::<Result<Vec<_>, S::Error>>()?;
 
-        let mut state = serializer.serialize_struct("AllStorages", 2)?;
+        let storages = storages
+            .iter()
+            .map(|storage| crate::unknown_storage::StorageSerializer {
+                unknown_storage: &**storage,
+                ser_config: self.ser_config,
+            })
+            .collect::<Vec<_>>();
 
-        state.serialize_field("metadata", &metadata)?;
-        state.serialize_field(
-            "storages",
-            &storages
-                .iter()
-                .map(|storage| crate::unknown_storage::StorageSerializer {
-                    unknown_storage: &**storage,
-                    ser_config: self.ser_config,
-                })
-                .collect::<Vec<_>>(),
-        )?;
-
-        state.end()
+        // named fields are easier to read, binary formats don't need them
+        if serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("AllStorages", 2)?;
+
+            state.serialize_field("metadata", &metadata)?;
+            state.serialize_field("storages", &storages)?;
+            state.end()
+        } else {
+            let mut state = serializer.serialize_tuple(2)?;
+
+            state.serialize_element(&metadata)?;
+            state.serialize_element(&storages)?;
+            state.end()
+        }
     }
 }
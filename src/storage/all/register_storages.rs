@@ -0,0 +1,37 @@
+use crate::storage::AllStorages;
+
+/// Trait used as a bound for AllStorages::register_storages.
+pub trait RegisterStorages {
+    fn register_storages(all_storages: &AllStorages);
+}
+
+impl RegisterStorages for () {
+    fn register_storages(_: &AllStorages) {}
+}
+
+macro_rules! impl_register_storages {
+    ($(($type: ident, $index: tt))+) => {
+        impl<$($type: 'static + Send + Sync),+> RegisterStorages for ($($type,)+) {
+            fn register_storages(all_storages: &AllStorages) {
+                $(
+                    all_storages.register_storage::<$type>();
+                )+
+            }
+        }
+    }
+}
+
+macro_rules! register_storages {
+    ($(($type: ident, $index: tt))+; ($type1: ident, $index1: tt) $(($queue_type: ident, $queue_index: tt))*) => {
+        impl_register_storages![$(($type, $index))*];
+        register_storages![$(($type, $index))* ($type1, $index1); $(($queue_type, $queue_index))*];
+    };
+    ($(($type: ident, $index: tt))+;) => {
+        impl_register_storages![$(($type, $index))*];
+    }
+}
+
+#[cfg(not(feature = "extended_tuple"))]
+register_storages![(A, 0); (B, 1) (C, 2) (D, 3) (E, 4) (F, 5) (G, 6) (H, 7) (I, 8) (J, 9)];
+#[cfg(feature = "extended_tuple")]
+register_storages![(A, 0); (B, 1) (C, 2) (D, 3) (E, 4) (F, 5) (G, 6) (H, 7) (I, 8) (J, 9) (K, 10) (L, 11) (M, 12) (N, 13) (O, 14) (P, 15)];
@@ -0,0 +1,309 @@
+use crate::storage::{Storage, StorageId};
+use crate::sync::{AtomicPtr, AtomicUsize, Ordering, RawRwLock};
+use alloc::boxed::Box;
+use core::cell::UnsafeCell;
+use core::ptr;
+use core::sync::atomic::{AtomicUsize as StaticAtomicUsize, Ordering as StaticOrdering};
+use hashbrown::hash_map::{DefaultHashBuilder, Entry};
+use hashbrown::HashMap;
+#[cfg(not(loom))]
+use parking_lot::lock_api::RawRwLock as _;
+use parking_lot::Mutex;
+
+/// Number of shards, a power of two.
+const SHARDS: usize = 16;
+
+/// Source of registry generations, no two registries ever share one.
+static NEXT_GENERATION: StaticAtomicUsize = StaticAtomicUsize::new(0);
+
+/// Storages split in shards each with their own lock.
+/// Systems borrowing storages in different shards don't contend on the same lock.
+// A storage always lives in the same shard, picked from its id
+pub(crate) struct Registry {
+    shards: [Shard; SHARDS],
+    // changes every time a storage is added, moved or removed
+    // references to storages stay valid as long as it doesn't change
+    generation: AtomicUsize,
+    // type name of every storage ever created, kept when the storage is removed
+    names: Mutex<HashMap<StorageId, &'static str>>,
+}
+
+/// Part of the storages and the lock protecting them.
+// The lock is only taken in exclusive mode to add or remove unique storages
+// storages created when borrowed are pushed to `created` without waiting on anything
+// and moved to `storages` the next time the shard is accessed exclusively
+pub(super) struct Shard {
+    pub(super) lock: RawRwLock,
+    pub(super) storages: UnsafeCell<HashMap<StorageId, Storage>>,
+    // lock-free list of storages created while the lock was held in shared mode
+    created: AtomicPtr<Created>,
+}
+
+/// Node of the list of created storages.
+struct Created {
+    storage_id: StorageId,
+    storage: Storage,
+    next: *mut Created,
+}
+
+impl Default for Shard {
+    fn default() -> Self {
+        Shard {
+            #[cfg(not(loom))]
+            lock: RawRwLock::INIT,
+            #[cfg(loom)]
+            lock: RawRwLock::new(),
+            storages: UnsafeCell::new(HashMap::new()),
+            created: AtomicPtr::new(ptr::null_mut()),
+        }
+    }
+}
+
+impl Default for Registry {
+    fn default() -> Self {
+        Registry {
+            shards: Default::default(),
+            generation: AtomicUsize::new(Registry::next_generation()),
+            names: Mutex::new(HashMap::new()),
+        }
+    }
+}
+
+impl Shard {
+    /// Returns the storage of `storage_id` if it exists.
+    // the lock has to be held, in shared or exclusive mode
+    pub(super) fn get(&self, storage_id: StorageId) -> Option<&Storage> {
+        // SAFE the caller holds the lock, `storages` is only modified with an exclusive access
+        unsafe { (&*self.storages.get()).get(&storage_id) }.or_else(|| {
+            Self::find(
+                self.created.load(Ordering::Acquire),
+                ptr::null_mut(),
+                storage_id,
+            )
+        })
+    }
+    /// Returns the storage of `storage_id`, creating it with `f` if it doesn't exist.  
+    /// Multiple threads can create the same storage at the same time, only the first one to finish is kept.
+    // the lock has to be held, in shared or exclusive mode
+    pub(super) fn get_or_insert_with(
+        &self,
+        storage_id: StorageId,
+        f: impl FnOnce() -> Storage,
+    ) -> &Storage {
+        // SAFE the caller holds the lock, `storages` is only modified with an exclusive access
+        if let Some(storage) = unsafe { (&*self.storages.get()).get(&storage_id) } {
+            return storage;
+        }
+
+        let mut head = self.created.load(Ordering::Acquire);
+        if let Some(storage) = Self::find(head, ptr::null_mut(), storage_id) {
+            return storage;
+        }
+
+        let created = Box::into_raw(Box::new(Created {
+            storage_id,
+            storage: f(),
+            next: head,
+        }));
+
+        loop {
+            match self.created.compare_exchange_weak(
+                head,
+                created,
+                Ordering::AcqRel,
+                Ordering::Acquire,
+            ) {
+                // SAFE nodes are only freed with an exclusive access
+                Ok(_) => return unsafe { &(*created).storage },
+                Err(new_head) => {
+                    // only the nodes pushed since the last try have to be checked
+                    if let Some(storage) = Self::find(new_head, head, storage_id) {
+                        // SAFE `created` was never shared
+                        drop(unsafe { Box::from_raw(created) });
+
+                        return storage;
+                    }
+
+                    head = new_head;
+                    // SAFE `created` was never shared
+                    unsafe { (*created).next = head };
+                }
+            }
+        }
+    }
+    /// Looks for `storage_id` in the created storages from `node` to `until`.
+    fn find<'a>(
+        mut node: *mut Created,
+        until: *mut Created,
+        storage_id: StorageId,
+    ) -> Option<&'a Storage> {
+        while node != until {
+            // SAFE nodes are only freed with an exclusive access
+            let created = unsafe { &*node };
+
+            if created.storage_id == storage_id {
+                return Some(&created.storage);
+            }
+
+            node = created.next;
+        }
+
+        None
+    }
+    /// Iterates the storages of this shard, including the ones created since the last flush.
+    // the lock has to be held, in shared or exclusive mode
+    pub(super) fn iter(&self) -> impl Iterator<Item = (&StorageId, &Storage)> {
+        let mut node = self.created.load(Ordering::Acquire);
+        let created = core::iter::from_fn(move || {
+            if node.is_null() {
+                None
+            } else {
+                // SAFE nodes are only freed with an exclusive access
+                let created = unsafe { &*node };
+                node = created.next;
+
+                Some((&created.storage_id, &created.storage))
+            }
+        });
+
+        // SAFE the caller holds the lock, `storages` is only modified with an exclusive access
+        unsafe { (&*self.storages.get()).iter() }.chain(created)
+    }
+    /// Moves the created storages to `storages`, returns `true` if there was any.
+    fn flush(&mut self) -> bool {
+        // most of the time nothing was created, reading is enough
+        if self.created.load(Ordering::Acquire).is_null() {
+            return false;
+        }
+
+        let mut node = self.created.swap(ptr::null_mut(), Ordering::Acquire);
+        let flushed = !node.is_null();
+        let storages = self.storages.get_mut();
+
+        while !node.is_null() {
+            // SAFE we have exclusive access, no one else can have a reference to the node
+            let created = unsafe { Box::from_raw(node) };
+            node = created.next;
+
+            // the storage is boxed, moving it doesn't invalidate previous references
+            storages.insert(created.storage_id, created.storage);
+        }
+
+        flushed
+    }
+}
+
+impl Drop for Shard {
+    fn drop(&mut self) {
+        self.flush();
+    }
+}
+
+impl Registry {
+    fn next_generation() -> usize {
+        NEXT_GENERATION.fetch_add(1, StaticOrdering::Relaxed)
+    }
+    /// Returns the current generation, references to storages obtained in the same generation are still valid.
+    pub(crate) fn generation(&self) -> usize {
+        self.generation.load(Ordering::Acquire)
+    }
+    /// Changes the generation, has to be called when a storage is added to, moved in or removed from the `HashMap`s.
+    // the lock of the modified shard has to be held in exclusive mode
+    pub(crate) fn invalidate(&self) {
+        self.generation
+            .store(Registry::next_generation(), Ordering::Release);
+    }
+    /// Records `name` as the type name of the storage of `storage_id`.
+    pub(crate) fn set_name(&self, storage_id: StorageId, name: &'static str) {
+        self.names.lock().insert(storage_id, name);
+    }
+    /// Returns the type name of the storage of `storage_id` if it was ever created.
+    pub(crate) fn name(&self, storage_id: StorageId) -> Option<&'static str> {
+        self.names.lock().get(&storage_id).copied()
+    }
+    /// Returns the type names of all storages ever created.
+    pub(crate) fn names(&self) -> HashMap<StorageId, &'static str> {
+        self.names.lock().clone()
+    }
+    /// Returns the index of the shard `storage_id` lives in.
+    fn shard_index(storage_id: StorageId) -> usize {
+        let hash = match storage_id {
+            StorageId::TypeId(type_id) => type_id.as_u64(),
+            StorageId::Custom(id) => id,
+        };
+
+        // type ids are already hashes, custom ids are usually small
+        (hash ^ (hash >> 32)) as usize & (SHARDS - 1)
+    }
+    pub(super) fn shard(&self, storage_id: StorageId) -> &Shard {
+        &self.shards[Self::shard_index(storage_id)]
+    }
+    pub(super) fn shard_mut(&mut self, storage_id: StorageId) -> &mut Shard {
+        let index = Self::shard_index(storage_id);
+        if self.shards[index].flush() {
+            self.invalidate();
+        }
+
+        &mut self.shards[index]
+    }
+    pub(super) fn shards_mut(&mut self) -> impl Iterator<Item = &mut Shard> {
+        let mut flushed = false;
+        for shard in &mut self.shards {
+            flushed |= shard.flush();
+        }
+
+        if flushed {
+            self.invalidate();
+        }
+
+        self.shards.iter_mut()
+    }
+    /// Iterates all storages without locking.
+    ///
+    /// # Safety
+    ///
+    /// No other thread can access the storages during the iteration.
+    #[cfg(feature = "serde1")]
+    pub(super) unsafe fn iter_unchecked(&self) -> impl Iterator<Item = (&StorageId, &Storage)> {
+        self.shards.iter().flat_map(Shard::iter)
+    }
+    pub(super) fn shards(&self) -> impl Iterator<Item = &Shard> {
+        self.shards.iter()
+    }
+    pub(crate) fn get_mut(&mut self, storage_id: &StorageId) -> Option<&mut Storage> {
+        self.shard_mut(*storage_id)
+            .storages
+            .get_mut()
+            .get_mut(storage_id)
+    }
+    pub(crate) fn entry(
+        &mut self,
+        storage_id: StorageId,
+    ) -> Entry<'_, StorageId, Storage, DefaultHashBuilder> {
+        // the entry can be used to insert or remove storages
+        self.invalidate();
+        self.shard_mut(storage_id)
+            .storages
+            .get_mut()
+            .entry(storage_id)
+    }
+    pub(crate) fn iter(&mut self) -> impl Iterator<Item = (&StorageId, &Storage)> {
+        self.shards_mut()
+            .map(|shard| shard.storages.get_mut())
+            .filter(|storages| !storages.is_empty())
+            .flat_map(|storages| storages.iter())
+    }
+    pub(crate) fn iter_mut(&mut self) -> impl Iterator<Item = (&StorageId, &mut Storage)> {
+        self.shards_mut()
+            .map(|shard| shard.storages.get_mut())
+            .filter(|storages| !storages.is_empty())
+            .flat_map(|storages| storages.iter_mut())
+    }
+    #[cfg(feature = "serde1")]
+    pub(crate) fn values(&mut self) -> impl Iterator<Item = &Storage> {
+        self.iter().map(|(_, storage)| storage)
+    }
+    pub(crate) fn values_mut(&mut self) -> impl Iterator<Item = &mut Storage> {
+        self.iter_mut().map(|(_, storage)| storage)
+    }
+}
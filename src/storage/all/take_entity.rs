@@ -0,0 +1,56 @@
+use crate::sparse_set::{OldComponent, Pack};
+use crate::storage::AllStorages;
+use crate::storage::EntityId;
+use crate::type_id::TypeId;
+
+/// Trait used as a bound for AllStorages::take_entity.
+pub trait TakeEntity {
+    type Out;
+    fn take_entity(all_storages: &mut AllStorages, entity: EntityId) -> Self::Out;
+}
+
+/// Removes `entity`'s `T` component and returns it if the entity owned one.
+fn take<T: 'static>(all_storages: &mut AllStorages, entity: EntityId) -> Option<T> {
+    let type_id = TypeId::of::<T>().into();
+    let storage = all_storages.registry.get_mut(&type_id)?;
+    // we have an exclusive reference so the borrow can't fail
+    let mut sparse_set = storage.sparse_set_mut::<T>().ok()?;
+
+    match sparse_set.actual_remove(entity)? {
+        OldComponent::Owned(component) => {
+            if let Pack::Update(update) = &mut sparse_set.metadata.pack {
+                update.removed.push(entity);
+            }
+
+            Some(component)
+        }
+        _ => None,
+    }
+}
+
+macro_rules! impl_take_entity {
+    ($(($type: ident, $index: tt))+) => {
+        impl<$($type: 'static + Send + Sync),+> TakeEntity for ($($type,)+) {
+            type Out = ($(Option<$type>,)+);
+
+            fn take_entity(all_storages: &mut AllStorages, entity: EntityId) -> Self::Out {
+                ($(take::<$type>(all_storages, entity),)+)
+            }
+        }
+    }
+}
+
+macro_rules! take_entity {
+    ($(($type: ident, $index: tt))+; ($type1: ident, $index1: tt) $(($queue_type: ident, $queue_index: tt))*) => {
+        impl_take_entity![$(($type, $index))*];
+        take_entity![$(($type, $index))* ($type1, $index1); $(($queue_type, $queue_index))*];
+    };
+    ($(($type: ident, $index: tt))+;) => {
+        impl_take_entity![$(($type, $index))*];
+    }
+}
+
+#[cfg(not(feature = "extended_tuple"))]
+take_entity![(A, 0); (B, 1) (C, 2) (D, 3) (E, 4) (F, 5) (G, 6) (H, 7) (I, 8) (J, 9)];
+#[cfg(feature = "extended_tuple")]
+take_entity![(A, 0); (B, 1) (C, 2) (D, 3) (E, 4) (F, 5) (G, 6) (H, 7) (I, 8) (J, 9) (K, 10) (L, 11) (M, 12) (N, 13) (O, 14) (P, 15)];
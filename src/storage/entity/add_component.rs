@@ -53,8 +53,9 @@ impl<T: 'static> AddComponent<T> for &mut ViewMut<'_, T> {
         }
     }
     #[cfg(feature = "panic")]
+    #[track_caller]
     fn add_component(self, component: T, entity: EntityId, entities: &Entities) {
-        self.try_add_component(component, entity, entities).unwrap()
+        AddComponent::try_add_component(self, component, entity, entities).unwrap()
     }
 }
 
@@ -123,6 +124,7 @@ macro_rules! impl_add_component {
                 }
             }
             #[cfg(feature = "panic")]
+            #[track_caller]
             fn add_component(self, component: ($($type,)+), entity: EntityId, entities: &Entities) {
                 self.try_add_component(component, entity, entities).unwrap()
             }
@@ -145,4 +147,7 @@ macro_rules! add_component {
     }
 }
 
+#[cfg(not(feature = "extended_tuple"))]
 add_component![(A, 0);; (B, 1) (C, 2) (D, 3) (E, 4) (F, 5) (G, 6) (H, 7) (I, 8) (J, 9)];
+#[cfg(feature = "extended_tuple")]
+add_component![(A, 0);; (B, 1) (C, 2) (D, 3) (E, 4) (F, 5) (G, 6) (H, 7) (I, 8) (J, 9) (K, 10) (L, 11) (M, 12) (N, 13) (O, 14) (P, 15)];
@@ -0,0 +1,15 @@
+use super::EntityId;
+use arbitrary::{size_hint, Arbitrary, Result, Unstructured};
+
+impl<'a> Arbitrary<'a> for EntityId {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let index = u.int_in_range(0..=EntityId::INDEX_MASK - 1)?;
+        let gen = u16::arbitrary(u)?;
+
+        // SAFE index fits in 48 bits, the id isn't tied to any World
+        Ok(unsafe { EntityId::from_parts(index, gen) })
+    }
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        size_hint::and(u64::size_hint(depth), u16::size_hint(depth))
+    }
+}
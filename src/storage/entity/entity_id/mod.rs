@@ -1,3 +1,7 @@
+#[cfg(feature = "arbitrary1")]
+mod arbitrary;
+#[cfg(feature = "proptest1")]
+mod proptest;
 #[cfg(feature = "serde1")]
 mod serde;
 
@@ -28,7 +32,8 @@ impl EntityId {
     pub fn uindex(self) -> usize {
         self.index() as usize
     }
-    /// Returns the generation part of the EntityId.
+    /// Returns the generation part of the EntityId.  
+    /// It's incremented each time the index is reused, it always fits in an `u16`.
     #[inline]
     pub fn gen(self) -> u64 {
         (self.0.get() & Self::GEN_MASK) >> (64 - Self::GEN_LEN)
@@ -41,16 +46,46 @@ impl EntityId {
         EntityId(unsafe { NonZeroU64::new_unchecked(index + 1) })
     }
 
+    /// Makes an `EntityId` from its index and generation, the opposite of [index] and [gen].  
+    /// Useful to get back an `EntityId` stored outside of shipyard, in a savegame or on the GPU for example.
+    ///
+    /// ### Safety
+    ///
+    /// Nothing checks the resulting `EntityId` matches an entity from the `World` it is used with.  
+    /// If the entity was deleted and its index reused with the same generation it will silently refer to the new entity.
+    ///
+    /// ### Panics
+    ///
+    /// `index` doesn't fit in 48 bits.
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{EntityId, World};
+    ///
+    /// let world = World::new();
+    ///
+    /// let entity = world.spawn(());
+    /// let (index, gen) = (entity.index(), entity.gen());
+    ///
+    /// assert_eq!(unsafe { EntityId::from_parts(index, gen as u16) }, entity);
+    /// ```
+    ///
+    /// [gen]: struct.EntityId.html#method.gen
+    /// [index]: struct.EntityId.html#method.index
+    #[inline]
+    pub unsafe fn from_parts(index: u64, gen: u16) -> Self {
+        assert!(index < Self::INDEX_MASK);
+        // SAFE never zero
+        EntityId(NonZeroU64::new_unchecked(
+            (index + 1) | ((gen as u64) << (64 - Self::GEN_LEN)),
+        ))
+    }
     /// Make a new `EntityId` with the given generation and index.  
     /// It must be alive in the `World` it is used with.
-    #[cfg(feature = "serde1")]
     #[inline]
     pub(crate) fn new_from_pair_unchecked(index: u64, gen: u16) -> Self {
-        assert!(index < Self::INDEX_MASK);
-        // SAFE never zero
-        EntityId(unsafe {
-            NonZeroU64::new_unchecked((index + 1) | ((gen as u64) << (64 - Self::GEN_LEN)))
-        })
+        // SAFE callers check the entity is alive
+        unsafe { Self::from_parts(index, gen) }
     }
 
     /// Modify the index.
@@ -133,4 +168,5 @@ fn entity_id() {
     entity_id.set_index(554);
     assert_eq!(entity_id.index(), 554);
     assert_eq!(entity_id.gen(), 3);
+    assert_eq!(unsafe { EntityId::from_parts(554, 3) }, entity_id);
 }
@@ -0,0 +1,15 @@
+use super::EntityId;
+use proptest::arbitrary::{any, Arbitrary};
+use proptest::strategy::{BoxedStrategy, Strategy};
+
+impl Arbitrary for EntityId {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        (0..EntityId::INDEX_MASK, any::<u16>())
+            // SAFE index fits in 48 bits, the id isn't tied to any World
+            .prop_map(|(index, gen)| unsafe { EntityId::from_parts(index, gen) })
+            .boxed()
+    }
+}
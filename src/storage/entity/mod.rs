@@ -1,23 +1,35 @@
 mod add_component;
 mod entity_id;
 mod iterator;
+mod recycling;
+mod weak_entity;
 
 pub use entity_id::EntityId;
 pub use iterator::EntitiesIter;
+pub use recycling::Recycling;
+pub use weak_entity::WeakEntity;
 
-#[cfg(feature = "serde1")]
 use crate::atomic_refcell::AtomicRefCell;
+use crate::deferred_entity_builder::{current_position, DeferredEntity, DeferredEntityBuilder};
 use crate::error;
+use crate::event_log::StructuralEvent;
 #[cfg(feature = "serde1")]
-use crate::serde_setup::{GlobalDeConfig, GlobalSerConfig};
-use crate::sparse_set::ViewAddEntity;
+use crate::recording::RecordedCommand;
 #[cfg(feature = "serde1")]
-use crate::storage::Storage;
+use crate::serde_setup::{GlobalDeConfig, GlobalSerConfig};
+use crate::snapshot::{StorageSnapshot, StorageState};
+use crate::sparse_set::{Pack, ViewAddEntity};
+use crate::storage::{Signatures, Storage, StorageId};
 use crate::type_id::TypeId;
 use crate::unknown_storage::UnknownStorage;
+use crate::view::ViewMut;
 use add_component::AddComponent;
+use alloc::boxed::Box;
+use alloc::sync::Arc;
 use alloc::vec::Vec;
-use core::any::Any;
+use core::any::{type_name, Any};
+use hashbrown::HashSet;
+use parking_lot::Mutex;
 
 /// Entities holds the EntityIds to all entities: living, removed and dead.
 ///
@@ -37,21 +49,81 @@ use core::any::Any;
 // Removed entities form a linked list inside the vector, using their index part to point to the next.
 // Removed entities are added to one end and removed from the other.
 // Dead entities are simply never added to the linked list.
+// Entities with a WeakEntity are tracked to report them once deleted.
+// Depending on the recycling policy, removed entities can wait in pending before joining the list.
 pub struct Entities {
     data: Vec<EntityId>,
     list: Option<(usize, usize)>,
+    recycling: Recycling,
+    // index of removed entities and how many frames they still have to wait
+    pending: Vec<(usize, u32)>,
+    weak: HashSet<EntityId>,
+    invalidated: Vec<WeakEntity>,
+    // maximum number of entities, set when the World has a fixed capacity
+    fixed_capacity: Option<usize>,
+    // entities created at the next sync point, filled through shared borrows
+    // tagged with the position in its batch of the system that built them
+    deferred: Mutex<Vec<(usize, DeferredEntity)>>,
+    // events logged since the last drain, see World::enable_event_log
+    events: Option<Vec<(usize, StructuralEvent)>>,
+    #[cfg(feature = "serde1")]
+    record: Option<Vec<(u64, RecordedCommand)>>,
+    // storages referencing each entity, kept up to date by the storages
+    signatures: Arc<Signatures>,
 }
 
 impl Entities {
     pub(crate) fn new() -> Self {
+        Entities::with_capacity(0)
+    }
+    /// Creates an `Entities` that can't hold more than `capacity` entities and won't allocate when adding them.
+    pub(crate) fn with_fixed_capacity(capacity: usize) -> Self {
+        let mut entities = Entities::with_capacity(capacity);
+        entities.pending.reserve(capacity);
+        entities.fixed_capacity = Some(capacity);
+        entities
+    }
+    /// Creates an `Entities` able to hold `capacity` entities without reallocating.
+    pub(crate) fn with_capacity(capacity: usize) -> Self {
         Entities {
-            data: Vec::new(),
+            data: Vec::with_capacity(capacity),
             list: None,
+            recycling: Recycling::Immediate,
+            pending: Vec::new(),
+            weak: HashSet::new(),
+            invalidated: Vec::new(),
+            fixed_capacity: None,
+            deferred: Mutex::new(Vec::new()),
+            events: None,
+            #[cfg(feature = "serde1")]
+            record: None,
+            signatures: Arc::default(),
+        }
+    }
+    #[cfg(feature = "serde1")]
+    fn from_parts(data: Vec<EntityId>, list: Option<(usize, usize)>) -> Self {
+        Entities {
+            data,
+            list,
+            recycling: Recycling::Immediate,
+            pending: Vec::new(),
+            weak: HashSet::new(),
+            invalidated: Vec::new(),
+            fixed_capacity: None,
+            deferred: Mutex::new(Vec::new()),
+            events: None,
+            #[cfg(feature = "serde1")]
+            record: None,
+            signatures: Arc::default(),
         }
     }
     pub(super) fn delete(&mut self, entity: EntityId) -> bool {
         self.delete_unchecked(entity)
     }
+    /// Returns the table of the storages referencing each entity.
+    pub(crate) fn signatures(&self) -> &Signatures {
+        &self.signatures
+    }
     /// Returns true if `entity` matches a living entity.
     pub fn is_alive(&self, entity: EntityId) -> bool {
         // SAFE we're in bound
@@ -109,7 +181,155 @@ impl Entities {
             .try_add_component(component, entity, &self)
             .unwrap()
     }
+    /// Adds each component of `components` to the entity at the same index in `entities`.  
+    /// All entities are checked before any component is added and memory is reserved once for all of them.  
+    /// `Entities` is only borrowed immutably.
+    ///
+    /// ### Errors
+    ///
+    /// - One of `entities` isn't alive, no component is added.
+    /// - The storage is packed, use [try_add_component] with all packed storages instead.
+    /// - `entities` and `components` don't have the same length, no component is added.
+    /// - The storage has a fixed capacity and not enough room left for the new components, no component is added.
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{EntitiesView, EntitiesViewMut, View, ViewMut, World};
+    ///
+    /// let world = World::new();
+    ///
+    /// let entities = {
+    ///     let mut all_entities = world.borrow::<EntitiesViewMut>();
+    ///     [all_entities.add_entity((), ()), all_entities.add_entity((), ())]
+    /// };
+    ///
+    /// world.run(|all_entities: EntitiesView, mut u32s: ViewMut<u32>| {
+    ///     all_entities
+    ///         .try_add_components(&mut u32s, &entities, vec![0, 1])
+    ///         .unwrap();
+    /// });
+    ///
+    /// assert_eq!(world.borrow::<View<u32>>()[entities[1]], 1);
+    /// ```
+    ///
+    /// [try_add_component]: struct.Entities.html#method.try_add_component
+    pub fn try_add_components<T: 'static>(
+        &self,
+        storage: &mut ViewMut<'_, T>,
+        entities: &[EntityId],
+        components: impl IntoIterator<Item = T>,
+    ) -> Result<(), error::AddComponent> {
+        match storage.metadata.pack {
+            Pack::Tight(_) | Pack::Loose(_) => {
+                return Err(error::AddComponent::MissingPackStorage(type_name::<T>()))
+            }
+            Pack::Update(_) | Pack::NoPack => {
+                if !storage.metadata.observer_types.is_empty() {
+                    return Err(error::AddComponent::MissingPackStorage(type_name::<T>()));
+                }
+            }
+        }
+
+        if !entities.iter().all(|&entity| self.is_alive(entity)) {
+            return Err(error::AddComponent::EntityIsNotAlive);
+        }
+
+        let components: Vec<T> = components.into_iter().collect();
+        if components.len() != entities.len() {
+            return Err(error::AddComponent::LengthMismatch {
+                entities: entities.len(),
+                components: components.len(),
+            });
+        }
+
+        match storage.metadata.fixed_capacity {
+            Some(capacity) => {
+                // replacing a component doesn't take any room
+                let added = entities
+                    .iter()
+                    .filter(|&&entity| !storage.contains(entity))
+                    .count();
+
+                if storage.len() + added > capacity {
+                    return Err(error::AddComponent::StorageFull(type_name::<T>()));
+                }
+            }
+            None => storage.reserve(entities.len()),
+        }
+
+        for (&entity, component) in entities.iter().zip(components) {
+            storage.insert(component, entity);
+        }
+
+        Ok(())
+    }
+    /// Adds each component of `components` to the entity at the same index in `entities`.  
+    /// All entities are checked before any component is added and memory is reserved once for all of them.  
+    /// `Entities` is only borrowed immutably.  
+    /// Unwraps errors.
+    ///
+    /// ### Errors
+    ///
+    /// - One of `entities` isn't alive, no component is added.
+    /// - The storage is packed, use [add_component] with all packed storages instead.
+    /// - `entities` and `components` don't have the same length, no component is added.
+    /// - The storage has a fixed capacity and not enough room left for the new components, no component is added.
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{EntitiesView, EntitiesViewMut, View, ViewMut, World};
+    ///
+    /// let world = World::new();
+    ///
+    /// let entities = {
+    ///     let mut all_entities = world.borrow::<EntitiesViewMut>();
+    ///     [all_entities.add_entity((), ()), all_entities.add_entity((), ())]
+    /// };
+    ///
+    /// world.run(|all_entities: EntitiesView, mut u32s: ViewMut<u32>| {
+    ///     all_entities.add_components(&mut u32s, &entities, vec![0, 1]);
+    /// });
+    ///
+    /// assert_eq!(world.borrow::<View<u32>>()[entities[1]], 1);
+    /// ```
+    ///
+    /// [add_component]: struct.Entities.html#method.add_component
+    #[cfg(feature = "panic")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "panic")))]
+    #[track_caller]
+    pub fn add_components<T: 'static>(
+        &self,
+        storage: &mut ViewMut<'_, T>,
+        entities: &[EntityId],
+        components: impl IntoIterator<Item = T>,
+    ) {
+        self.try_add_components(storage, entities, components)
+            .unwrap()
+    }
     pub(super) fn generate(&mut self) -> EntityId {
+        let entity_id = self.generate_untracked();
+
+        self.log_event(StructuralEvent::EntityCreated(entity_id));
+
+        #[cfg(feature = "serde1")]
+        {
+            if let Some(record) = &mut self.record {
+                record.push((
+                    crate::recording::next_index(),
+                    RecordedCommand::Spawn(entity_id),
+                ));
+            }
+        }
+
+        entity_id
+    }
+    /// Logs `event` if the event log is enabled.
+    fn log_event(&mut self, event: StructuralEvent) {
+        if let Some(events) = &mut self.events {
+            events.push((crate::event_log::next_index(), event));
+        }
+    }
+    fn generate_untracked(&mut self) -> EntityId {
         let index = self.list.map(|(_, old)| old);
         if let Some((new, ref mut old)) = self.list {
             if new == *old {
@@ -124,6 +344,14 @@ impl Entities {
             unsafe { self.data.get_unchecked_mut(index).set_index(index as u64) };
             unsafe { *self.data.get_unchecked(index) }
         } else {
+            if let Some(capacity) = self.fixed_capacity {
+                assert!(
+                    self.data.len() < capacity,
+                    "Entities is full, the World's fixed capacity is {} entities.",
+                    capacity
+                );
+            }
+
             let entity_id = EntityId::new(self.data.len() as u64);
             self.data.push(entity_id);
             entity_id
@@ -133,6 +361,22 @@ impl Entities {
     /// If the entity has components, they will not be deleted and still be accessible using this id.
     pub fn delete_unchecked(&mut self, entity_id: EntityId) -> bool {
         if self.is_alive(entity_id) {
+            if self.weak.remove(&entity_id) {
+                self.invalidated.push(WeakEntity(entity_id));
+            }
+
+            self.log_event(StructuralEvent::EntityDeleted(entity_id));
+
+            #[cfg(feature = "serde1")]
+            {
+                if let Some(record) = &mut self.record {
+                    record.push((
+                        crate::recording::next_index(),
+                        RecordedCommand::Despawn(entity_id),
+                    ));
+                }
+            }
+
             // SAFE we checked for OOB
             if unsafe {
                 self.data
@@ -140,26 +384,22 @@ impl Entities {
                     .bump_gen()
                     .is_ok()
             } {
-                if let Some((ref mut new, _)) = self.list {
-                    // SAFE new is always in bound
-                    unsafe {
-                        self.data
-                            .get_unchecked_mut(*new)
-                            .set_index(entity_id.index())
-                    };
-                    unsafe {
-                        self.data
-                            .get_unchecked_mut(entity_id.uindex())
-                            .set_index(EntityId::INDEX_MASK - 1)
-                    };
-                    *new = entity_id.uindex();
-                } else {
-                    unsafe {
-                        self.data
-                            .get_unchecked_mut(entity_id.uindex())
-                            .set_index(EntityId::INDEX_MASK - 1)
-                    };
-                    self.list = Some((entity_id.uindex(), entity_id.uindex()));
+                match self.recycling {
+                    Recycling::Immediate | Recycling::Delayed(0) => {
+                        self.recycle(entity_id.uindex())
+                    }
+                    Recycling::Never | Recycling::Delayed(_) => {
+                        // the entity isn't alive anymore but isn't part of the list either
+                        unsafe {
+                            self.data
+                                .get_unchecked_mut(entity_id.uindex())
+                                .set_index(EntityId::INDEX_MASK - 1)
+                        };
+
+                        if let Recycling::Delayed(frames) = self.recycling {
+                            self.pending.push((entity_id.uindex(), frames));
+                        }
+                    }
                 }
             }
             true
@@ -167,6 +407,73 @@ impl Entities {
             false
         }
     }
+    /// Adds the removed entity at `index` to the list, it'll be reused by a future entity.
+    fn recycle(&mut self, index: usize) {
+        if let Some((ref mut new, _)) = self.list {
+            // SAFE new is always in bound
+            unsafe { self.data.get_unchecked_mut(*new).set_index(index as u64) };
+            unsafe {
+                self.data
+                    .get_unchecked_mut(index)
+                    .set_index(EntityId::INDEX_MASK - 1)
+            };
+            *new = index;
+        } else {
+            unsafe {
+                self.data
+                    .get_unchecked_mut(index)
+                    .set_index(EntityId::INDEX_MASK - 1)
+            };
+            self.list = Some((index, index));
+        }
+    }
+    /// Returns the maximum number of entities, if the [World] was created with a fixed capacity.
+    ///
+    /// [World]: struct.World.html
+    pub fn fixed_capacity(&self) -> Option<usize> {
+        self.fixed_capacity
+    }
+    /// Returns how the index of deleted entities is reused.
+    pub fn recycling(&self) -> Recycling {
+        self.recycling
+    }
+    /// Sets how the index of deleted entities is reused.  
+    /// Entities already waiting for a delayed recycling keep their remaining delay.
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{EntitiesViewMut, Recycling, World};
+    ///
+    /// let world = World::new();
+    ///
+    /// world.run(|mut entities: EntitiesViewMut| {
+    ///     entities.set_recycling(Recycling::Delayed(1));
+    ///
+    ///     let entity = entities.add_entity((), ());
+    ///     entities.delete_unchecked(entity);
+    ///
+    ///     assert_ne!(entities.add_entity((), ()).index(), entity.index());
+    /// });
+    ///
+    /// world.maintain();
+    ///
+    /// world.run(|mut entities: EntitiesViewMut| {
+    ///     assert_eq!(entities.add_entity((), ()).index(), 0);
+    /// });
+    /// ```
+    pub fn set_recycling(&mut self, recycling: Recycling) {
+        self.recycling = recycling;
+    }
+    /// Counts down the delay of entities waiting to be recycled, called by `World::maintain`.
+    pub(crate) fn advance_frame(&mut self) {
+        for (index, frames) in core::mem::take(&mut self.pending) {
+            if frames > 1 {
+                self.pending.push((index, frames - 1));
+            } else {
+                self.recycle(index);
+            }
+        }
+    }
     /// Stores `component` in a new entity, the `EntityId` to this entity is returned.  
     /// Multiple components can be added at the same time using a tuple.
     /// ### Example:
@@ -195,14 +502,182 @@ impl Entities {
     pub fn iter(&self) -> EntitiesIter<'_> {
         self.into_iter()
     }
+    /// Returns a [WeakEntity] to `entity`.  
+    /// Once `entity` is deleted the handle will be returned by [drain_invalidated].
+    ///
+    /// [WeakEntity]: struct.WeakEntity.html
+    /// [drain_invalidated]: struct.Entities.html#method.drain_invalidated
+    pub fn downgrade(&mut self, entity: EntityId) -> WeakEntity {
+        if self.is_alive(entity) {
+            self.weak.insert(entity);
+        }
+
+        WeakEntity(entity)
+    }
+    /// Returns the `EntityId` of `weak` if the entity is still alive.  
+    /// An entity reusing the same index with a newer generation doesn't match.
+    pub fn upgrade(&self, weak: WeakEntity) -> Option<EntityId> {
+        if self.is_alive(weak.0) {
+            Some(weak.0)
+        } else {
+            None
+        }
+    }
+    /// Returns a builder for an entity created at the next [World::maintain].
+    /// Only needs a shared borrow, systems running in parallel can use it.
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{EntitiesView, World};
+    ///
+    /// let world = World::new();
+    ///
+    /// world.run(|entities: EntitiesView| {
+    ///     entities.deferred_entity_builder().with(0u32).build();
+    ///     assert_eq!(entities.iter().count(), 0);
+    /// });
+    ///
+    /// world.maintain();
+    ///
+    /// world.run(|entities: EntitiesView| assert_eq!(entities.iter().count(), 1));
+    /// ```
+    ///
+    /// [World::maintain]: struct.World.html#method.maintain
+    pub fn deferred_entity_builder(&self) -> DeferredEntityBuilder<'_, ()> {
+        DeferredEntityBuilder::new(self)
+    }
+    pub(crate) fn defer(&self, entity: DeferredEntity) {
+        self.deferred.lock().push((current_position(), entity));
+    }
+    /// Returns the number of entities waiting for a sync point.
+    #[cfg(feature = "parallel")]
+    pub(crate) fn deferred_len(&self) -> usize {
+        self.deferred.lock().len()
+    }
+    /// Orders the entities deferred since `start` by the position of the system that built them in its batch.  
+    /// Entities built by the same system keep their order.
+    #[cfg(feature = "parallel")]
+    pub(crate) fn sort_deferred(&self, start: usize) {
+        self.deferred.lock()[start..].sort_by_key(|&(position, _)| position);
+    }
+    /// Returns the entities waiting for a sync point, in the order they were built.
+    pub(crate) fn take_deferred(&mut self) -> Vec<DeferredEntity> {
+        core::mem::take(self.deferred.get_mut())
+            .into_iter()
+            .map(|(_, entity)| entity)
+            .collect()
+    }
+    /// Returns all [WeakEntity] whose entity was deleted since the last call.  
+    /// Should be called regularly, once per frame for example.
+    ///
+    /// [WeakEntity]: struct.WeakEntity.html
+    pub fn drain_invalidated(&mut self) -> alloc::vec::Drain<'_, WeakEntity> {
+        self.invalidated.drain(..)
+    }
+}
+
+struct EntitiesSnapshot {
+    data: Vec<EntityId>,
+    list: Option<(usize, usize)>,
+    recycling: Recycling,
+    pending: Vec<(usize, u32)>,
+}
+
+impl EntitiesSnapshot {
+    fn to_storage(&self) -> Storage {
+        let mut entities = Entities::new();
+        entities.data = self.data.clone();
+        entities.list = self.list;
+        entities.recycling = self.recycling;
+        entities.pending = self.pending.clone();
+
+        #[cfg(feature = "std")]
+        {
+            Storage(Box::new(AtomicRefCell::new(entities, None, true)))
+        }
+        #[cfg(not(feature = "std"))]
+        {
+            Storage(Box::new(AtomicRefCell::new(entities)))
+        }
+    }
+}
+
+impl StorageState for EntitiesSnapshot {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+    fn fork(self: Arc<Self>) -> Storage {
+        self.to_storage()
+    }
+    fn name(&self) -> &'static str {
+        "Entities"
+    }
 }
 
 impl UnknownStorage for Entities {
     fn delete(&mut self, _entity: EntityId, _: &mut Vec<TypeId>) {}
+    fn set_event_log(&mut self, enabled: bool) {
+        if !enabled {
+            self.events = None;
+        } else if self.events.is_none() {
+            self.events = Some(Vec::new());
+        }
+    }
+    fn drain_events(&mut self, events: &mut Vec<(usize, StructuralEvent)>) {
+        if let Some(logged) = &mut self.events {
+            events.append(logged);
+        }
+    }
+    fn track_signatures(&mut self, _: StorageId, signatures: Arc<Signatures>) {
+        self.signatures = signatures;
+    }
     fn clear(&mut self) {
         if self.data.is_empty() {
             return;
         }
+
+        if self.recycling != Recycling::Immediate {
+            // deleted entities have to follow the recycling policy
+            let alive: Vec<EntityId> = self.iter().collect();
+            for entity in alive {
+                self.delete_unchecked(entity);
+            }
+
+            return;
+        }
+
+        // all entities are about to join the list
+        self.pending.clear();
+        self.invalidated.extend(self.weak.drain().map(WeakEntity));
+        if let Some(events) = &mut self.events {
+            events.extend(
+                self.data
+                    .iter()
+                    .enumerate()
+                    .filter(|(i, id)| id.uindex() == *i)
+                    .map(|(_, &id)| {
+                        (
+                            crate::event_log::next_index(),
+                            StructuralEvent::EntityDeleted(id),
+                        )
+                    }),
+            );
+        }
+        #[cfg(feature = "serde1")]
+        {
+            if let Some(record) = &mut self.record {
+                record.extend(
+                    self.data
+                        .iter()
+                        .enumerate()
+                        .filter(|(i, id)| id.uindex() == *i)
+                        .map(|(_, &id)| {
+                            (crate::recording::next_index(), RecordedCommand::Despawn(id))
+                        }),
+                );
+            }
+        }
+
         let mut last_alive = self.data.len() as u64 - 1;
         for (i, id) in self.data.iter_mut().enumerate().rev() {
             let target = last_alive;
@@ -228,17 +703,61 @@ impl UnknownStorage for Entities {
         self.list = Some((self.data.len() - end - 1, begin));
     }
     fn unpack(&mut self, _entity: EntityId) {}
+    fn snapshot(&self) -> Option<StorageSnapshot> {
+        Some(Arc::new(EntitiesSnapshot {
+            data: self.data.clone(),
+            list: self.list,
+            recycling: self.recycling,
+            pending: self.pending.clone(),
+        }))
+    }
+    fn restore(&mut self, snapshot: Option<&StorageSnapshot>) {
+        // Entities always exists
+        let snapshot = snapshot
+            .unwrap()
+            .as_any()
+            .downcast_ref::<EntitiesSnapshot>()
+            .unwrap();
+
+        self.data = snapshot.data.clone();
+        self.list = snapshot.list;
+        self.recycling = snapshot.recycling;
+        self.pending = snapshot.pending.clone();
+    }
+    fn clone_storage(&self) -> Option<Storage> {
+        // weak entities stay tied to this World
+        Some(
+            EntitiesSnapshot {
+                data: self.data.clone(),
+                list: self.list,
+                recycling: self.recycling,
+                pending: self.pending.clone(),
+            }
+            .to_storage(),
+        )
+    }
     fn any(&self) -> &dyn Any {
         self
     }
     fn any_mut(&mut self) -> &mut dyn Any {
         self
     }
+    fn name(&self) -> &'static str {
+        "Entities"
+    }
     #[cfg(feature = "serde1")]
     fn is_serializable(&self) -> bool {
         true
     }
     #[cfg(feature = "serde1")]
+    fn start_recording(&mut self) {
+        self.record = Some(Vec::new());
+    }
+    #[cfg(feature = "serde1")]
+    fn stop_recording(&mut self) -> Vec<(u64, RecordedCommand)> {
+        self.record.take().unwrap_or_default()
+    }
+    #[cfg(feature = "serde1")]
     fn skip_serialization(&self, ser_config: GlobalSerConfig) -> bool {
         !ser_config.with_entities
     }
@@ -258,11 +777,13 @@ impl UnknownStorage for Entities {
     ) -> Option<
         fn(
             GlobalDeConfig,
+            u32,
             &mut dyn crate::erased_serde::Deserializer<'_>,
         ) -> Result<Storage, crate::erased_serde::Error>,
     > {
         Some(
             |de_config: GlobalDeConfig,
+             _version: u32,
              deserializer: &mut dyn crate::erased_serde::Deserializer<'_>| {
                 #[cfg(feature = "std")]
                 {
@@ -289,13 +810,21 @@ impl serde::Serialize for Entities {
     where
         S: serde::Serializer,
     {
-        use serde::ser::SerializeStruct;
+        use serde::ser::{SerializeStruct, SerializeTuple};
 
-        let mut state = serializer.serialize_struct("Entities", 2)?;
+        if serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("Entities", 2)?;
 
-        state.serialize_field("data", &self.data)?;
-        state.serialize_field("list", &self.list)?;
-        state.end()
+            state.serialize_field("data", &self.data)?;
+            state.serialize_field("list", &self.list)?;
+            state.end()
+        } else {
+            let mut state = serializer.serialize_tuple(2)?;
+
+            state.serialize_element(&self.data)?;
+            state.serialize_element(&self.list)?;
+            state.end()
+        }
     }
 }
 
@@ -384,7 +913,7 @@ impl<'de> serde::Deserialize<'de> for Entities {
                     .ok_or_else(|| {
                         serde::de::Error::invalid_length(1, &"struct Entities with 2 elements")
                     })?;
-                Ok(Entities { data, list })
+                Ok(Entities::from_parts(data, list))
             }
             #[inline]
             fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
@@ -412,11 +941,15 @@ impl<'de> serde::Deserialize<'de> for Entities {
                 let data = data.ok_or_else(|| serde::de::Error::missing_field("data"))?;
                 let list = list.ok_or_else(|| serde::de::Error::missing_field("list"))?;
 
-                Ok(Entities { data, list })
+                Ok(Entities::from_parts(data, list))
             }
         }
 
-        deserializer.deserialize_struct("Entities", FIELDS, Visitor)
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_struct("Entities", FIELDS, Visitor)
+        } else {
+            deserializer.deserialize_tuple(2, Visitor)
+        }
     }
 }
 
@@ -500,3 +1033,30 @@ fn iterator() {
 
     assert!(iter.next().is_none());
 }
+
+#[test]
+fn weak() {
+    let mut entities = Entities::new();
+
+    let key00 = entities.generate();
+    let key10 = entities.generate();
+
+    let weak00 = entities.downgrade(key00);
+    let weak10 = entities.downgrade(key10);
+    // downgrading twice doesn't report the entity twice
+    entities.downgrade(key00);
+
+    assert!(entities.delete_unchecked(key00));
+    let key01 = entities.generate();
+
+    assert_eq!(key01.index(), key00.index());
+    assert_eq!(entities.upgrade(weak00), None);
+    assert_eq!(entities.upgrade(weak10), Some(key10));
+    assert_eq!(entities.drain_invalidated().collect::<Vec<_>>(), [weak00]);
+    assert_eq!(entities.drain_invalidated().count(), 0);
+
+    UnknownStorage::clear(&mut entities);
+
+    assert_eq!(entities.upgrade(weak10), None);
+    assert_eq!(entities.drain_invalidated().collect::<Vec<_>>(), [weak10]);
+}
@@ -0,0 +1,16 @@
+/// How [Entities] reuses the index of deleted entities, set with [Entities::set_recycling].
+///
+/// A reused index gets a new generation so stale `EntityId`s never match the new entity,
+/// but bugs storing ids across deletions are easier to spot when indices aren't reused right away.
+///
+/// [Entities]: struct.Entities.html
+/// [Entities::set_recycling]: struct.Entities.html#method.set_recycling
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Recycling {
+    /// Indices are reused as soon as their entity is deleted, the default.
+    Immediate,
+    /// Indices are never reused, new entities always get a new index.
+    Never,
+    /// Indices are reused once `World::maintain` has been called this many times after their entity was deleted.
+    Delayed(u32),
+}
@@ -0,0 +1,12 @@
+use super::EntityId;
+
+/// Handle to an entity that doesn't assume it's alive.  
+/// Created with [Entities::downgrade] and turned back into an `EntityId` with [Entities::upgrade].  
+/// Once the entity is deleted the handle is reported by [Entities::drain_invalidated],
+/// even if the index is reused by a new entity the handle won't upgrade to it.
+///
+/// [Entities::downgrade]: struct.Entities.html#method.downgrade
+/// [Entities::drain_invalidated]: struct.Entities.html#method.drain_invalidated
+/// [Entities::upgrade]: struct.Entities.html#method.upgrade
+#[derive(Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord, Debug)]
+pub struct WeakEntity(pub(super) EntityId);
@@ -1,10 +1,13 @@
 mod all;
 mod entity;
+mod signature;
 mod storage_id;
 mod unique;
 
-pub use all::{AllStorages, DeleteAny};
-pub use entity::{Entities, EntitiesIter, EntityId};
+pub use all::{AllStorages, DeleteAny, ErasedStorage, RegisterStorages, TakeEntity};
+pub use entity::{Entities, EntitiesIter, EntityId, Recycling, WeakEntity};
+pub use signature::Signature;
+pub(crate) use signature::{Members, Signatures};
 pub use storage_id::StorageId;
 
 pub(crate) use crate::type_id::TypeIdHasher;
@@ -13,9 +16,12 @@ pub(crate) use all::AllStoragesSerializer;
 
 use crate::atomic_refcell::{AtomicRefCell, Ref, RefMut};
 use crate::error;
+use crate::messages::Messages;
+use crate::relation::Relation;
 #[cfg(feature = "serde1")]
 use crate::serde_setup::GlobalDeConfig;
 use crate::sparse_set::SparseSet;
+use crate::tags::Tags;
 use crate::type_id::TypeId;
 use crate::unknown_storage::UnknownStorage;
 use alloc::boxed::Box;
@@ -33,9 +39,13 @@ unsafe impl Send for Storage {}
 unsafe impl Sync for Storage {}
 
 impl Storage {
-    /// Creates a new `Storage` storing elements of type T.
-    pub(crate) fn new<T: 'static + Send + Sync>() -> Self {
-        let sparse_set = SparseSet::<T>::new();
+    /// Creates a new `Storage` storing elements of type T.  
+    /// The storage can hold `entity_capacity` entities before growing its sparse array.
+    pub(crate) fn new<T: 'static + Send + Sync>(entity_capacity: usize) -> Self {
+        Storage::from_sparse_set(SparseSet::<T>::with_entity_capacity(entity_capacity))
+    }
+    /// Creates a new `Storage` from an existing `SparseSet`.
+    pub(crate) fn from_sparse_set<T: 'static + Send + Sync>(sparse_set: SparseSet<T>) -> Self {
         #[cfg(feature = "std")]
         {
             Storage(Box::new(AtomicRefCell::new(sparse_set, None, true)))
@@ -46,8 +56,11 @@ impl Storage {
         }
     }
     #[cfg(feature = "non_send")]
-    pub(crate) fn new_non_send<T: 'static + Sync>(world_thread_id: std::thread::ThreadId) -> Self {
-        let sparse_set = SparseSet::<T>::new();
+    pub(crate) fn new_non_send<T: 'static + Sync>(
+        entity_capacity: usize,
+        world_thread_id: crate::sync::thread::ThreadId,
+    ) -> Self {
+        let sparse_set = SparseSet::<T>::with_entity_capacity(entity_capacity);
         Storage(Box::new(AtomicRefCell::new(
             sparse_set,
             Some(world_thread_id),
@@ -55,13 +68,16 @@ impl Storage {
         )))
     }
     #[cfg(feature = "non_sync")]
-    pub(crate) fn new_non_sync<T: 'static + Send>() -> Self {
-        let sparse_set = SparseSet::<T>::new();
+    pub(crate) fn new_non_sync<T: 'static + Send>(entity_capacity: usize) -> Self {
+        let sparse_set = SparseSet::<T>::with_entity_capacity(entity_capacity);
         Storage(Box::new(AtomicRefCell::new(sparse_set, None, false)))
     }
     #[cfg(all(feature = "non_send", feature = "non_sync"))]
-    pub(crate) fn new_non_send_sync<T: 'static>(world_thread_id: std::thread::ThreadId) -> Self {
-        let sparse_set = SparseSet::<T>::new();
+    pub(crate) fn new_non_send_sync<T: 'static>(
+        entity_capacity: usize,
+        world_thread_id: crate::sync::thread::ThreadId,
+    ) -> Self {
+        let sparse_set = SparseSet::<T>::with_entity_capacity(entity_capacity);
         Storage(Box::new(AtomicRefCell::new(
             sparse_set,
             Some(world_thread_id),
@@ -71,39 +87,130 @@ impl Storage {
     pub(crate) fn new_unique<T: 'static + Send + Sync>(component: T) -> Self {
         #[cfg(feature = "std")]
         {
-            Storage(Box::new(AtomicRefCell::new(Unique(component), None, true)))
+            Storage(Box::new(AtomicRefCell::new(
+                Unique::new(component),
+                None,
+                true,
+            )))
+        }
+        #[cfg(not(feature = "std"))]
+        {
+            Storage(Box::new(AtomicRefCell::new(Unique::new(component))))
+        }
+    }
+    /// Creates a new unique storage copied by `World::deep_clone`.
+    pub(crate) fn new_unique_cloneable<T: 'static + Clone + Send + Sync>(component: T) -> Self {
+        let unique = Unique {
+            value: component,
+            version: unique::next_version(),
+            clone: Some(|component| Storage::new_unique_cloneable(component.clone())),
+            #[cfg(feature = "serde1")]
+            serde: None,
+        };
+
+        #[cfg(feature = "std")]
+        {
+            Storage(Box::new(AtomicRefCell::new(unique, None, true)))
+        }
+        #[cfg(not(feature = "std"))]
+        {
+            Storage(Box::new(AtomicRefCell::new(unique)))
+        }
+    }
+    /// Creates a new unique storage included in `World::serialize`.
+    #[cfg(feature = "serde1")]
+    pub(crate) fn new_unique_serializable<
+        T: 'static + serde::Serialize + for<'de> serde::Deserialize<'de> + Send + Sync,
+    >(
+        component: T,
+    ) -> Self {
+        let unique = Unique {
+            value: component,
+            version: unique::next_version(),
+            clone: None,
+            serde: Some(unique::UniqueSerdeInfos::new()),
+        };
+
+        #[cfg(feature = "std")]
+        {
+            Storage(Box::new(AtomicRefCell::new(unique, None, true)))
         }
         #[cfg(not(feature = "std"))]
         {
-            Storage(Box::new(AtomicRefCell::new(Unique(component))))
+            Storage(Box::new(AtomicRefCell::new(unique)))
         }
     }
     #[cfg(feature = "non_send")]
     pub(crate) fn new_unique_non_send<T: 'static + Sync>(
         component: T,
-        world_thread_id: std::thread::ThreadId,
+        world_thread_id: crate::sync::thread::ThreadId,
     ) -> Self {
         Storage(Box::new(AtomicRefCell::new(
-            Unique(component),
+            Unique::new(component),
             Some(world_thread_id),
             true,
         )))
     }
     #[cfg(feature = "non_sync")]
     pub(crate) fn new_unique_non_sync<T: 'static + Send>(component: T) -> Self {
-        Storage(Box::new(AtomicRefCell::new(Unique(component), None, false)))
+        Storage(Box::new(AtomicRefCell::new(
+            Unique::new(component),
+            None,
+            false,
+        )))
     }
     #[cfg(all(feature = "non_send", feature = "non_sync"))]
     pub(crate) fn new_unique_non_send_sync<T: 'static>(
         component: T,
-        world_thread_id: std::thread::ThreadId,
+        world_thread_id: crate::sync::thread::ThreadId,
     ) -> Self {
         Storage(Box::new(AtomicRefCell::new(
-            Unique(component),
+            Unique::new(component),
             Some(world_thread_id),
             false,
         )))
     }
+    /// Creates an empty relation storage.
+    pub(crate) fn new_relation<T: 'static + Send + Sync>() -> Self {
+        #[cfg(feature = "std")]
+        {
+            Storage(Box::new(AtomicRefCell::new(
+                Relation::<T>::new(),
+                None,
+                true,
+            )))
+        }
+        #[cfg(not(feature = "std"))]
+        {
+            Storage(Box::new(AtomicRefCell::new(Relation::<T>::new())))
+        }
+    }
+    /// Creates an empty messages storage, `workload` is the number of workloads already run.
+    pub(crate) fn new_messages<T: 'static + Send + Sync>(workload: usize) -> Self {
+        #[cfg(feature = "std")]
+        {
+            Storage(Box::new(AtomicRefCell::new(
+                Messages::<T>::new(workload),
+                None,
+                true,
+            )))
+        }
+        #[cfg(not(feature = "std"))]
+        {
+            Storage(Box::new(AtomicRefCell::new(Messages::<T>::new(workload))))
+        }
+    }
+    /// Creates an empty tag storage.
+    pub(crate) fn new_tags() -> Self {
+        #[cfg(feature = "std")]
+        {
+            Storage(Box::new(AtomicRefCell::new(Tags::new(), None, true)))
+        }
+        #[cfg(not(feature = "std"))]
+        {
+            Storage(Box::new(AtomicRefCell::new(Tags::new())))
+        }
+    }
     /// Immutably borrows the component container.
     pub(crate) fn sparse_set<T: 'static>(
         &self,
@@ -138,20 +245,84 @@ impl Storage {
             unknown.entities_mut().unwrap()
         }))
     }
-    pub(crate) fn unique<T: 'static>(&self) -> Result<Ref<'_, T>, error::GetStorage> {
-        Ref::try_map(
+    /// Immutably borrows the unique and returns its version.
+    pub(crate) fn unique<T: 'static>(&self) -> Result<(Ref<'_, T>, usize), error::GetStorage> {
+        let mut version = 0;
+        let unique = Ref::map(
             self.0.try_borrow().map_err(|borrow| {
                 error::GetStorage::StorageBorrow((core::any::type_name::<T>(), borrow))
             })?,
-            |unknown| Ok(unknown.unique::<T>().unwrap()),
-        )
+            |unknown| {
+                version = unknown.version();
+                unknown.unique::<T>().unwrap()
+            },
+        );
+
+        Ok((unique, version))
     }
+    /// Mutably borrows the unique, changing its version.
     pub(crate) fn unique_mut<T: 'static>(&self) -> Result<RefMut<'_, T>, error::GetStorage> {
         RefMut::try_map(
             self.0.try_borrow_mut().map_err(|borrow| {
                 error::GetStorage::StorageBorrow((core::any::type_name::<T>(), borrow))
             })?,
-            |unknown| Ok(unknown.unique_mut::<T>().unwrap()),
+            |unknown| {
+                // no one can observe the version before the borrow ends
+                unknown.bump_version();
+                Ok(unknown.unique_mut::<T>().unwrap())
+            },
+        )
+    }
+    pub(crate) fn relation<T: 'static>(&self) -> Result<Ref<'_, Relation<T>>, error::GetStorage> {
+        Ref::try_map(
+            self.0.try_borrow().map_err(|borrow| {
+                error::GetStorage::StorageBorrow((core::any::type_name::<Relation<T>>(), borrow))
+            })?,
+            |unknown| Ok(unknown.relation::<T>().unwrap()),
+        )
+    }
+    pub(crate) fn relation_mut<T: 'static>(
+        &self,
+    ) -> Result<RefMut<'_, Relation<T>>, error::GetStorage> {
+        RefMut::try_map(
+            self.0.try_borrow_mut().map_err(|borrow| {
+                error::GetStorage::StorageBorrow((core::any::type_name::<Relation<T>>(), borrow))
+            })?,
+            |unknown| Ok(unknown.relation_mut::<T>().unwrap()),
+        )
+    }
+    pub(crate) fn messages<T: 'static>(&self) -> Result<Ref<'_, Messages<T>>, error::GetStorage> {
+        Ref::try_map(
+            self.0.try_borrow().map_err(|borrow| {
+                error::GetStorage::StorageBorrow((core::any::type_name::<Messages<T>>(), borrow))
+            })?,
+            |unknown| Ok(unknown.messages::<T>().unwrap()),
+        )
+    }
+    pub(crate) fn messages_mut<T: 'static>(
+        &self,
+    ) -> Result<RefMut<'_, Messages<T>>, error::GetStorage> {
+        RefMut::try_map(
+            self.0.try_borrow_mut().map_err(|borrow| {
+                error::GetStorage::StorageBorrow((core::any::type_name::<Messages<T>>(), borrow))
+            })?,
+            |unknown| Ok(unknown.messages_mut::<T>().unwrap()),
+        )
+    }
+    pub(crate) fn tags(&self) -> Result<Ref<'_, Tags>, error::GetStorage> {
+        Ref::try_map(
+            self.0.try_borrow().map_err(|borrow| {
+                error::GetStorage::StorageBorrow((core::any::type_name::<Tags>(), borrow))
+            })?,
+            |unknown| Ok(unknown.tags().unwrap()),
+        )
+    }
+    pub(crate) fn tags_mut(&self) -> Result<RefMut<'_, Tags>, error::GetStorage> {
+        RefMut::try_map(
+            self.0.try_borrow_mut().map_err(|borrow| {
+                error::GetStorage::StorageBorrow((core::any::type_name::<Tags>(), borrow))
+            })?,
+            |unknown| Ok(unknown.tags_mut().unwrap()),
         )
     }
     /// Mutably borrows the container and delete `index`.
@@ -177,6 +348,7 @@ impl Storage {
 pub(crate) struct StorageDeserializer<'a> {
     pub(crate) storage: &'a mut Storage,
     pub(crate) de_config: GlobalDeConfig,
+    pub(crate) version: u32,
 }
 
 #[cfg(feature = "serde1")]
@@ -200,7 +372,8 @@ impl<'de> serde::de::DeserializeSeed<'de> for StorageDeserializer<'_> {
             .ok_or_else(|| serde::de::Error::custom("Type isn't serializable."))?;
         drop(storage);
 
-        *self.storage = (de)(self.de_config, deserializer).map_err(serde::de::Error::custom)?;
+        *self.storage =
+            (de)(self.de_config, self.version, deserializer).map_err(serde::de::Error::custom)?;
 
         Ok(())
     }
@@ -208,7 +381,7 @@ impl<'de> serde::de::DeserializeSeed<'de> for StorageDeserializer<'_> {
 
 #[test]
 fn delete() {
-    let mut storage = Storage::new::<&'static str>();
+    let mut storage = Storage::new::<&'static str>(0);
     let mut entity_id = EntityId::zero();
     let mut storage_to_unpack = Vec::new();
     entity_id.set_index(5);
@@ -0,0 +1,234 @@
+use super::{EntityId, StorageId};
+use alloc::boxed::Box;
+use alloc::sync::Arc;
+use alloc::vec::Vec;
+use core::ptr;
+use core::sync::atomic::{AtomicPtr, AtomicUsize, Ordering};
+use hashbrown::HashMap;
+use parking_lot::RwLock;
+
+/// Exact component composition of an entity, the sorted ids of the storages it has a component in.
+/// Two entities with the same signature have the same component types.
+///
+/// ### Example
+/// ```
+/// use shipyard::{Signature, StorageId, World};
+///
+/// let world = World::new();
+/// let entity = world.spawn((0usize, 1u32));
+///
+/// let signature = Signature::new(&[StorageId::of::<u32>(), StorageId::of::<usize>()]);
+///
+/// assert_eq!(world.signature(entity), signature);
+/// assert!(signature.contains(StorageId::of::<u32>()));
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Signature(Vec<StorageId>);
+
+impl Signature {
+    /// Creates the signature of an entity with a component in each storage of `storage_ids`.
+    /// The order of `storage_ids` doesn't matter and duplicates are ignored.
+    pub fn new(storage_ids: &[StorageId]) -> Self {
+        storage_ids.iter().copied().collect()
+    }
+    /// Returns `true` if the signature includes `storage_id`.
+    pub fn contains(&self, storage_id: StorageId) -> bool {
+        self.0.binary_search(&storage_id).is_ok()
+    }
+    /// Returns the storage ids of the signature, sorted.
+    pub fn storage_ids(&self) -> &[StorageId] {
+        &self.0
+    }
+    /// Returns the number of components in the signature.
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+    /// Returns `true` if the signature doesn't have any component.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+impl core::iter::FromIterator<StorageId> for Signature {
+    fn from_iter<I: IntoIterator<Item = StorageId>>(iter: I) -> Self {
+        let mut storage_ids = iter.into_iter().collect::<Vec<_>>();
+        storage_ids.sort_unstable();
+        storage_ids.dedup();
+
+        Signature(storage_ids)
+    }
+}
+
+/// Storages referencing each entity, each storage keeps its own `Members`.
+/// `Entities` and all storages share the same table, storages update their members whenever they add or remove an entity.
+///
+/// Deleting an entity or checking a component only needs the table instead of borrowing every storage.
+#[derive(Default)]
+pub(crate) struct Signatures {
+    // only locked exclusively when a storage starts being tracked
+    storages: RwLock<HashMap<StorageId, Arc<Members>>>,
+}
+
+impl Signatures {
+    /// Returns the members of `storage_id`, a storage replacing another one gets the same members.
+    pub(crate) fn members(&self, storage_id: StorageId) -> Arc<Members> {
+        if let Some(members) = self.storages.read().get(&storage_id) {
+            return members.clone();
+        }
+
+        self.storages.write().entry(storage_id).or_default().clone()
+    }
+    /// Returns `true` if `entity`'s index owns a component in `storage_id`.
+    pub(crate) fn contains(&self, entity: EntityId, storage_id: StorageId) -> bool {
+        self.storages
+            .read()
+            .get(&storage_id)
+            .map(|members| members.owns(entity.index() as usize))
+            .unwrap_or(false)
+    }
+    /// Calls `f` with each storage `entity`'s index is referenced in, with or without owning a component.
+    pub(crate) fn references(&self, entity: EntityId, mut f: impl FnMut(StorageId)) {
+        let index = entity.index() as usize;
+
+        for (&storage_id, members) in self.storages.read().iter() {
+            if members.references(index) {
+                f(storage_id);
+            }
+        }
+    }
+}
+
+/// Number of entity indices in a word, each index takes two bits.
+const INDICES: usize = usize::BITS as usize / 2;
+/// The storage references the entity.
+const REFERENCED: usize = 0b01;
+/// The storage references the entity and owns one of its components.
+const OWNED: usize = 0b11;
+/// Number of buckets, enough to cover all entity indices.
+const BUCKETS: usize = usize::BITS as usize;
+
+/// Entity indices a storage references, two bits per index.
+///
+/// Only the storage modifies its members, with its exclusive borrow.
+/// Readers don't borrow the storage, buckets are never moved or freed while the members are alive so they can read without lock.
+pub(crate) struct Members {
+    // bucket `n` holds `2^n` words, it's allocated the first time one of its words is set
+    buckets: [AtomicPtr<AtomicUsize>; BUCKETS],
+}
+
+impl Default for Members {
+    fn default() -> Self {
+        Members {
+            buckets: [(); BUCKETS].map(|_| AtomicPtr::new(ptr::null_mut())),
+        }
+    }
+}
+
+impl Drop for Members {
+    fn drop(&mut self) {
+        for (bucket, words) in self.buckets.iter_mut().enumerate() {
+            let words = *words.get_mut();
+
+            if !words.is_null() {
+                // SAFE the bucket was allocated in `Members::word` with this length
+                drop(unsafe { Box::from_raw(ptr::slice_from_raw_parts_mut(words, 1 << bucket)) });
+            }
+        }
+    }
+}
+
+impl Members {
+    /// Returns the bucket and the position in this bucket of the word of `index`.
+    fn locate(index: usize) -> (usize, usize) {
+        let word = index / INDICES + 1;
+        let bucket = (usize::BITS - 1 - word.leading_zeros()) as usize;
+
+        (bucket, word - (1 << bucket))
+    }
+    /// Returns the word of `index`, allocating its bucket if `allocate` is `true`.
+    fn word(&self, index: usize, allocate: bool) -> Option<&AtomicUsize> {
+        let (bucket, position) = Self::locate(index);
+        let mut words = self.buckets[bucket].load(Ordering::Acquire);
+
+        if words.is_null() {
+            if !allocate {
+                return None;
+            }
+
+            let new = Box::into_raw(
+                (0..1usize << bucket)
+                    .map(|_| AtomicUsize::new(0))
+                    .collect::<Box<[_]>>(),
+            )
+            .cast::<AtomicUsize>();
+
+            words = match self.buckets[bucket].compare_exchange(
+                ptr::null_mut(),
+                new,
+                Ordering::AcqRel,
+                Ordering::Acquire,
+            ) {
+                Ok(_) => new,
+                Err(words) => {
+                    // SAFE `new` was never shared
+                    drop(unsafe { Box::from_raw(ptr::slice_from_raw_parts_mut(new, 1 << bucket)) });
+
+                    words
+                }
+            };
+        }
+
+        // SAFE buckets are never freed before the members and `position` is in bound
+        Some(unsafe { &*words.add(position) })
+    }
+    /// Replaces what the storage references at `index`.
+    /// `Some(true)` when it owns a component, `Some(false)` when it only references the entity.
+    // only the storage owning the members can call it
+    pub(crate) fn set(&self, index: usize, entry: Option<bool>) {
+        let bits = match entry {
+            Some(true) => OWNED,
+            Some(false) => REFERENCED,
+            None => 0,
+        };
+
+        if let Some(word) = self.word(index, bits != 0) {
+            let shift = (index % INDICES) * 2;
+
+            // there is a single writer, no need for a read-modify-write
+            let value = word.load(Ordering::Relaxed) & !(OWNED << shift) | bits << shift;
+            word.store(value, Ordering::Release);
+        }
+    }
+    /// Forgets everything the storage referenced and replaces it with `entries`.
+    // only the storage owning the members can call it
+    pub(crate) fn reset(&self, entries: impl IntoIterator<Item = (usize, bool)>) {
+        for (bucket, words) in self.buckets.iter().enumerate() {
+            let words = words.load(Ordering::Acquire);
+
+            if !words.is_null() {
+                for position in 0..1 << bucket {
+                    // SAFE `position` is in bound
+                    unsafe { &*words.add(position) }.store(0, Ordering::Release);
+                }
+            }
+        }
+
+        for (index, owned) in entries {
+            self.set(index, Some(owned));
+        }
+    }
+    /// Returns the two bits of `index`.
+    fn get(&self, index: usize) -> usize {
+        self.word(index, false)
+            .map(|word| word.load(Ordering::Acquire) >> ((index % INDICES) * 2) & OWNED)
+            .unwrap_or(0)
+    }
+    /// Returns `true` if the storage references `index`, with or without owning a component.
+    fn references(&self, index: usize) -> bool {
+        self.get(index) != 0
+    }
+    /// Returns `true` if the storage owns a component at `index`.
+    fn owns(&self, index: usize) -> bool {
+        self.get(index) == OWNED
+    }
+}
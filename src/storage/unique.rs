@@ -1,19 +1,125 @@
-use crate::storage::EntityId;
+#[cfg(feature = "serde1")]
+use crate::serde_setup::{GlobalDeConfig, GlobalSerConfig};
+use crate::storage::{EntityId, Storage};
 use crate::type_id::TypeId;
 use crate::unknown_storage::UnknownStorage;
 use alloc::vec::Vec;
 use core::any::Any;
+use core::sync::atomic::{AtomicUsize as StaticAtomicUsize, Ordering as StaticOrdering};
 
-pub(super) struct Unique<T>(pub(crate) T);
+/// Source of unique versions, no two uniques ever share one, even after being removed and added again.
+static NEXT_VERSION: StaticAtomicUsize = StaticAtomicUsize::new(0);
+
+pub(super) fn next_version() -> usize {
+    NEXT_VERSION.fetch_add(1, StaticOrdering::Relaxed)
+}
+
+pub(super) struct Unique<T> {
+    pub(crate) value: T,
+    // changes each time the unique is borrowed exclusively
+    pub(crate) version: usize,
+    // copies the storage for World::deep_clone, requires T: Clone
+    pub(crate) clone: Option<fn(&T) -> Storage>,
+    // includes the storage in World::serialize, requires T: Serialize + Deserialize
+    #[cfg(feature = "serde1")]
+    pub(crate) serde: Option<UniqueSerdeInfos<T>>,
+}
+
+#[cfg(feature = "serde1")]
+pub(crate) struct UniqueSerdeInfos<T> {
+    pub(crate) serialization: fn(
+        &T,
+        &mut dyn crate::erased_serde::Serializer,
+    )
+        -> Result<crate::erased_serde::Ok, crate::erased_serde::Error>,
+    pub(crate) deserialization: fn(
+        GlobalDeConfig,
+        u32,
+        &mut dyn crate::erased_serde::Deserializer<'_>,
+    ) -> Result<Storage, crate::erased_serde::Error>,
+}
+
+#[cfg(feature = "serde1")]
+impl<T: serde::Serialize + for<'de> serde::Deserialize<'de> + Send + Sync + 'static>
+    UniqueSerdeInfos<T>
+{
+    pub(super) fn new() -> Self {
+        UniqueSerdeInfos {
+            serialization: |value: &T, serializer: &mut dyn crate::erased_serde::Serializer| {
+                crate::erased_serde::Serialize::erased_serialize(value, serializer)
+            },
+            deserialization:
+                |_: GlobalDeConfig,
+                 _: u32,
+                 deserializer: &mut dyn crate::erased_serde::Deserializer<'_>| {
+                    Ok(Storage::new_unique_serializable::<T>(
+                        crate::erased_serde::deserialize(deserializer)?,
+                    ))
+                },
+        }
+    }
+}
+
+impl<T> Unique<T> {
+    pub(super) fn new(value: T) -> Self {
+        Unique {
+            value,
+            version: next_version(),
+            clone: None,
+            #[cfg(feature = "serde1")]
+            serde: None,
+        }
+    }
+}
 
 impl<T: 'static> UnknownStorage for Unique<T> {
     fn delete(&mut self, _: EntityId, _: &mut Vec<TypeId>) {}
     fn clear(&mut self) {}
     fn unpack(&mut self, _: EntityId) {}
     fn any(&self) -> &dyn Any {
-        &self.0
+        &self.value
     }
     fn any_mut(&mut self) -> &mut dyn Any {
-        &mut self.0
+        &mut self.value
+    }
+    fn clone_storage(&self) -> Option<Storage> {
+        self.clone.map(|clone| clone(&self.value))
+    }
+    fn name(&self) -> &'static str {
+        core::any::type_name::<T>()
+    }
+    fn version(&self) -> usize {
+        self.version
+    }
+    fn bump_version(&mut self) {
+        self.version = next_version();
+    }
+    #[cfg(feature = "serde1")]
+    fn is_serializable(&self) -> bool {
+        self.serde.is_some()
+    }
+    #[cfg(feature = "serde1")]
+    fn skip_serialization(&self, _: GlobalSerConfig) -> bool {
+        false
+    }
+    #[cfg(feature = "serde1")]
+    fn serialize(
+        &self,
+        _: GlobalSerConfig,
+        serializer: &mut dyn crate::erased_serde::Serializer,
+    ) -> crate::erased_serde::Result<crate::erased_serde::Ok> {
+        (self.serde.as_ref().unwrap().serialization)(&self.value, serializer)
+    }
+    #[cfg(feature = "serde1")]
+    fn deserialize(
+        &self,
+    ) -> Option<
+        fn(
+            GlobalDeConfig,
+            u32,
+            &mut dyn crate::erased_serde::Deserializer<'_>,
+        ) -> Result<Storage, crate::erased_serde::Error>,
+    > {
+        Some(self.serde.as_ref()?.deserialization)
     }
 }
@@ -0,0 +1,82 @@
+use crate::storage::{Storage, StorageId};
+use alloc::vec::Vec;
+use core::cell::Cell;
+use core::ptr;
+use parking_lot::Mutex;
+
+std::thread_local! {
+    // cache of the system running on this thread, if any
+    #[allow(clippy::missing_const_for_thread_local)]
+    static CURRENT_CACHE: Cell<*mut Cached> = Cell::new(ptr::null_mut());
+}
+
+/// Storages a system borrowed during its previous runs.
+/// Borrowing them again skips the registry as long as no storage was added or removed in between.
+#[derive(Default)]
+pub(crate) struct StorageCache(Mutex<Cached>);
+
+#[derive(Default)]
+struct Cached {
+    // registry generation `storages` were found in
+    generation: usize,
+    storages: Vec<(StorageId, *const Storage)>,
+}
+
+// SAFE the pointers are only dereferenced when the generation proves they're still valid
+unsafe impl Send for Cached {}
+
+impl StorageCache {
+    /// Runs `f` with this cache, storages it borrows are looked up here first.
+    /// If the system is already running on another thread `f` runs without cache.
+    pub(crate) fn run<R>(&self, f: impl FnOnce() -> R) -> R {
+        // restores the previous cache even if `f` panics
+        struct Restore(*mut Cached);
+
+        impl Drop for Restore {
+            fn drop(&mut self) {
+                CURRENT_CACHE.with(|current| current.set(self.0));
+            }
+        }
+
+        match self.0.try_lock() {
+            Some(mut cached) => {
+                let cached: *mut Cached = &mut *cached;
+                let _restore = Restore(CURRENT_CACHE.with(|current| current.replace(cached)));
+
+                f()
+            }
+            None => f(),
+        }
+    }
+}
+
+/// Returns the storage of `storage_id` from the cache of the running system.
+/// If it isn't cached or was cached in a different `generation` it's found with `get` and cached.
+// the lock of the storage's shard has to be held
+pub(crate) fn get_or_cache<'s>(
+    generation: usize,
+    storage_id: StorageId,
+    get: impl FnOnce() -> Option<&'s Storage>,
+) -> Option<&'s Storage> {
+    let cached = CURRENT_CACHE.with(Cell::get);
+
+    if cached.is_null() {
+        return get();
+    }
+
+    // SAFE the cache is locked by the system running on this thread
+    let cached = unsafe { &mut *cached };
+
+    if cached.generation != generation {
+        cached.generation = generation;
+        cached.storages.clear();
+    } else if let Some(&(_, storage)) = cached.storages.iter().find(|(id, _)| *id == storage_id) {
+        // SAFE no storage was added, moved or removed since it was cached
+        return Some(unsafe { &*storage });
+    }
+
+    let storage = get()?;
+    cached.storages.push((storage_id, storage));
+
+    Some(storage)
+}
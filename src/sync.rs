@@ -0,0 +1,66 @@
+//! Synchronization primitives used by `AtomicRefCell` and `AllStorages`.
+//! When compiled with `--cfg loom` they're replaced by [loom](https://github.com/tokio-rs/loom)'s so they can be model checked.
+
+#[cfg(not(loom))]
+pub(crate) use core::sync::atomic::{AtomicPtr, AtomicUsize, Ordering};
+#[cfg(loom)]
+pub(crate) use loom::sync::atomic::{AtomicPtr, AtomicUsize, Ordering};
+
+#[cfg(all(feature = "std", loom))]
+pub(crate) use loom::thread;
+#[cfg(all(feature = "std", not(loom)))]
+pub(crate) use std::thread;
+
+#[cfg(not(loom))]
+pub(crate) use parking_lot::RawRwLock;
+
+/// Reader-writer spin lock with the same interface as parking_lot's `RawRwLock`.
+// loom can't model parking_lot, it has to see every atomic operation
+#[cfg(loom)]
+pub(crate) struct RawRwLock(AtomicUsize);
+
+#[cfg(loom)]
+impl RawRwLock {
+    const EXCLUSIVE: usize = core::usize::MAX;
+
+    pub(crate) fn new() -> Self {
+        RawRwLock(AtomicUsize::new(0))
+    }
+    pub(crate) fn lock_shared(&self) {
+        loop {
+            let state = self.0.load(Ordering::Relaxed);
+
+            if state != Self::EXCLUSIVE
+                && self
+                    .0
+                    .compare_exchange(state, state + 1, Ordering::Acquire, Ordering::Relaxed)
+                    .is_ok()
+            {
+                return;
+            }
+
+            loom::thread::yield_now();
+        }
+    }
+    /// # Safety
+    ///
+    /// The lock has to be held in shared mode.
+    pub(crate) unsafe fn unlock_shared(&self) {
+        self.0.fetch_sub(1, Ordering::Release);
+    }
+    pub(crate) fn lock_exclusive(&self) {
+        while self
+            .0
+            .compare_exchange(0, Self::EXCLUSIVE, Ordering::Acquire, Ordering::Relaxed)
+            .is_err()
+        {
+            loom::thread::yield_now();
+        }
+    }
+    /// # Safety
+    ///
+    /// The lock has to be held in exclusive mode.
+    pub(crate) unsafe fn unlock_exclusive(&self) {
+        self.0.store(0, Ordering::Release);
+    }
+}
@@ -22,6 +22,8 @@ pub trait System<'s, Data, B, R> {
     fn borrow_infos(infos: &mut Vec<(TypeId, Mutation)>);
 
     fn is_send_sync() -> bool;
+
+    fn create_storages(all_storages: &AllStorages);
 }
 
 // Nothing has to be used and not () to not conflict where A = ()
@@ -44,6 +46,8 @@ where
     fn is_send_sync() -> bool {
         true
     }
+
+    fn create_storages(_: &AllStorages) {}
 }
 
 // Nothing has to be used and not () to not conflict where A = ()
@@ -66,6 +70,8 @@ where
     fn is_send_sync() -> bool {
         true
     }
+
+    fn create_storages(_: &AllStorages) {}
 }
 
 macro_rules! impl_system {
@@ -97,6 +103,11 @@ macro_rules! impl_system {
                     $type::is_send_sync()
                 )&&+
             }
+            fn create_storages(all_storages: &AllStorages) {
+                $(
+                    $type::create_storages(all_storages);
+                )+
+            }
         }
 
         impl<'s, Data, $($type: Borrow<'s>,)+ R, Func> System<'s, (Data,), ($($type,)+), R> for Func where Func: FnOnce(Data, $($type,)+) -> R {
@@ -126,6 +137,11 @@ macro_rules! impl_system {
                     $type::is_send_sync()
                 )&&+
             }
+            fn create_storages(all_storages: &AllStorages) {
+                $(
+                    $type::create_storages(all_storages);
+                )+
+            }
         }
     }
 }
@@ -140,4 +156,7 @@ macro_rules! system {
     }
 }
 
+#[cfg(not(feature = "extended_tuple"))]
 system![(A, 0); (B, 1) (C, 2) (D, 3) (E, 4) (F, 5) (G, 6) (H, 7) (I, 8) (J, 9)];
+#[cfg(feature = "extended_tuple")]
+system![(A, 0); (B, 1) (C, 2) (D, 3) (E, 4) (F, 5) (G, 6) (H, 7) (I, 8) (J, 9) (K, 10) (L, 11) (M, 12) (N, 13) (O, 14) (P, 15)];
@@ -1,5 +1,8 @@
 /// Reduce boilerplace to add a system to a workload and make it less error prone.
 ///
+/// Closures capturing state can be used as long as it is `Clone + Send + Sync + 'static`,
+/// each run gets a clone of the closure.
+///
 /// ### Example
 /// ```
 /// use shipyard::{system, EntitiesViewMut, IntoIter, Shiperator, View, ViewMut, World};
@@ -35,19 +38,57 @@
 ///
 /// world.run_default();
 /// ```
+///
+/// With a capturing closure:
+/// ```
+/// use shipyard::{system, IntoIter, Shiperator, ViewMut, World};
+///
+/// #[derive(Clone)]
+/// struct Config {
+///     gravity: f32,
+/// }
+///
+/// fn fall(config: Config) -> impl Fn(ViewMut<f32>) + Clone + Send + Sync {
+///     move |mut heights: ViewMut<f32>| {
+///         for height in (&mut heights).iter() {
+///             *height -= config.gravity;
+///         }
+///     }
+/// }
+///
+/// let world = World::new();
+///
+/// world
+///     .add_workload("Earth")
+///     .with_system(system!(fall(Config { gravity: 9.8 })))
+///     .build();
+/// world
+///     .add_workload("Moon")
+///     .with_system(system!(fall(Config { gravity: 1.6 })))
+///     .build();
+///
+/// world.run_workload("Moon");
+/// ```
 #[macro_export]
 macro_rules! system {
     ($function: expr) => {{
+        let function = $function;
         (
-            |world: &$crate::World| world.try_run($function).map(drop),
-            $function,
+            {
+                let function = function.clone();
+                move |world: &$crate::World| world.try_run(function.clone()).map(drop)
+            },
+            function,
         )
     }};
 }
 
 /// Reduce boilerplace to add a fallible system to a workload and make it less error prone.  
 ///
-/// This macro only works with systems returning a `Result`.
+/// This macro only works with systems returning a `Result`.  
+/// With the std feature the error can be any `Error + Send + Sync` type, a `String` or a `&str`.
+/// It is returned as is by `try_run_workload` and can be downcast from [RunWorkload::custom_error].  
+/// Like [system], it accepts closures capturing `Clone + Send + Sync + 'static` state.
 ///
 /// ### Example
 /// ```
@@ -86,16 +127,23 @@ macro_rules! system {
 /// }
 /// }
 /// ```
+///
+/// [system]: macro.system.html
+/// [RunWorkload::custom_error]: error/enum.RunWorkload.html#method.custom_error
 #[macro_export]
 macro_rules! try_system {
     ($function: expr) => {{
+        let function = $function;
         (
-            |world: &$crate::World| {
-                world
-                    .try_run($function)?
-                    .map_err($crate::error::Run::from_custom)
+            {
+                let function = function.clone();
+                move |world: &$crate::World| {
+                    world
+                        .try_run(function.clone())?
+                        .map_err($crate::error::Run::from_custom)
+                }
             },
-            $function,
+            function,
         )
     }};
 }
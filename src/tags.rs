@@ -0,0 +1,217 @@
+use crate::storage::{EntityId, Members, Signatures, StorageId};
+use crate::type_id::TypeId;
+use crate::unknown_storage::UnknownStorage;
+use alloc::boxed::Box;
+use alloc::sync::Arc;
+use alloc::vec::Vec;
+use core::any::Any;
+use hashbrown::HashMap;
+
+const BITS: usize = 64;
+
+/// String tags attached to entities, to filter entities without defining a marker component per tag.
+/// Added with [World::add_tag] or borrowed with [TagsView] and [TagsViewMut].
+///
+/// Tag names are interned, each tag is a bitset over entity indices.
+/// Deleting an entity removes all its tags.
+///
+/// ### Example
+/// ```
+/// use shipyard::{TagsView, World};
+///
+/// let world = World::new();
+/// let [orc, goblin, villager] = [world.spawn(()), world.spawn(()), world.spawn(())];
+///
+/// world.add_tag(orc, "enemy");
+/// world.add_tag(goblin, "enemy");
+/// world.add_tag(villager, "friendly");
+///
+/// assert_eq!(world.tagged("enemy"), vec![orc, goblin]);
+///
+/// world.run(|tags: TagsView| {
+///     assert!(tags.contains(villager, "friendly"));
+///     assert_eq!(tags.tags_of(orc).collect::<Vec<_>>(), vec!["enemy"]);
+/// });
+/// ```
+///
+/// [World::add_tag]: struct.World.html#method.add_tag
+/// [TagsView]: struct.TagsView.html
+/// [TagsViewMut]: struct.TagsViewMut.html
+pub struct Tags {
+    // interned names, the value is the position of the tag's bitset
+    names: HashMap<Box<str>, usize>,
+    // one bit per entity index for each tag
+    sets: Vec<Vec<u64>>,
+    // entity owning each index, to give back full ids
+    ids: Vec<EntityId>,
+    // entities this storage references in the table of the entities' storages, see AllStorages::delete
+    signatures: Option<Arc<Members>>,
+}
+
+impl Tags {
+    pub(crate) fn new() -> Self {
+        Tags {
+            names: HashMap::new(),
+            sets: Vec::new(),
+            ids: Vec::new(),
+            signatures: None,
+        }
+    }
+    /// Tags `entity` with `tag`.
+    /// Returns `false` if `entity` already had this tag.
+    pub fn insert(&mut self, entity: EntityId, tag: &str) -> bool {
+        let set = match self.names.get(tag) {
+            Some(&set) => set,
+            None => {
+                self.names.insert(tag.into(), self.sets.len());
+                self.sets.push(Vec::new());
+                self.sets.len() - 1
+            }
+        };
+
+        let index = entity.index() as usize;
+        if index >= self.ids.len() {
+            self.ids.resize(index + 1, EntityId::dead());
+        }
+
+        // a previous entity with the same index didn't have its tags removed
+        if self.ids[index] != entity {
+            self.remove_index(index);
+            self.ids[index] = entity;
+            self.update_signature(index, true);
+        }
+
+        let words = &mut self.sets[set];
+        if index / BITS >= words.len() {
+            words.resize(index / BITS + 1, 0);
+        }
+
+        let mask = 1 << (index % BITS);
+        let inserted = words[index / BITS] & mask == 0;
+        words[index / BITS] |= mask;
+
+        inserted
+    }
+    /// Removes `tag` from `entity`.
+    /// Returns `false` if `entity` didn't have this tag.
+    pub fn remove(&mut self, entity: EntityId, tag: &str) -> bool {
+        if !self.contains(entity, tag) {
+            return false;
+        }
+
+        let index = entity.index() as usize;
+        self.sets[self.names[tag]][index / BITS] &= !(1 << (index % BITS));
+
+        true
+    }
+    /// Removes all tags of `entity`.
+    pub fn remove_entity(&mut self, entity: EntityId) {
+        let index = entity.index() as usize;
+
+        if self.ids.get(index) == Some(&entity) {
+            self.remove_index(index);
+            self.ids[index] = EntityId::dead();
+            self.update_signature(index, false);
+        }
+    }
+    /// Returns `true` if `entity` has `tag`.
+    pub fn contains(&self, entity: EntityId, tag: &str) -> bool {
+        let index = entity.index() as usize;
+
+        let word = self
+            .names
+            .get(tag)
+            .and_then(|&set| self.sets[set].get(index / BITS));
+
+        self.ids.get(index) == Some(&entity)
+            && matches!(word, Some(word) if word & (1 << (index % BITS)) != 0)
+    }
+    /// Returns the entities with `tag`, ordered by index.
+    pub fn tagged<'a>(&'a self, tag: &str) -> impl Iterator<Item = EntityId> + 'a {
+        let words = self
+            .names
+            .get(tag)
+            .map(|&set| self.sets[set].as_slice())
+            .unwrap_or(&[]);
+
+        words
+            .iter()
+            .enumerate()
+            .filter(|(_, &word)| word != 0)
+            .flat_map(move |(word_index, &word)| {
+                (0..BITS)
+                    .filter(move |bit| word & (1 << bit) != 0)
+                    .map(move |bit| self.ids[word_index * BITS + bit])
+            })
+    }
+    /// Returns the tags of `entity`, in no particular order.
+    pub fn tags_of(&self, entity: EntityId) -> impl Iterator<Item = &str> {
+        self.names
+            .keys()
+            .map(|tag| &**tag)
+            .filter(move |tag| self.contains(entity, tag))
+    }
+    /// Removes all tags from all entities.
+    /// Tag names stay interned.
+    pub fn clear(&mut self) {
+        for words in &mut self.sets {
+            words.clear();
+        }
+
+        self.ids.clear();
+
+        if let Some(members) = &self.signatures {
+            members.reset(None);
+        }
+    }
+    fn update_signature(&self, index: usize, tagged: bool) {
+        if let Some(members) = &self.signatures {
+            members.set(index, if tagged { Some(false) } else { None });
+        }
+    }
+    fn remove_index(&mut self, index: usize) {
+        for words in &mut self.sets {
+            if let Some(word) = words.get_mut(index / BITS) {
+                *word &= !(1 << (index % BITS));
+            }
+        }
+    }
+}
+
+impl UnknownStorage for Tags {
+    fn delete(&mut self, entity: EntityId, _: &mut Vec<TypeId>) {
+        self.remove_entity(entity);
+    }
+    fn clear(&mut self) {
+        Tags::clear(self);
+    }
+    fn unpack(&mut self, _: EntityId) {}
+    fn any(&self) -> &dyn Any {
+        self
+    }
+    fn any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+    fn name(&self) -> &'static str {
+        core::any::type_name::<Self>()
+    }
+    fn references(&self, entity: EntityId) -> bool {
+        self.ids.get(entity.index() as usize) == Some(&entity)
+    }
+    fn track_signatures(&mut self, storage_id: StorageId, signatures: Arc<Signatures>) {
+        let members = signatures.members(storage_id);
+        let mut tagged = self
+            .ids
+            .iter()
+            .filter(|&&id| id != EntityId::dead())
+            .map(|&id| (id.index() as usize, false))
+            .peekable();
+
+        // a storage created at the same time by another thread mustn't erase the entities of the one kept
+        if tagged.peek().is_some() {
+            members.reset(tagged);
+        }
+
+        self.signatures = Some(members);
+    }
+}
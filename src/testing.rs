@@ -0,0 +1,537 @@
+//! Helpers to test systems without borrowing storages by hand.
+
+#[cfg(feature = "serde1")]
+use crate::error;
+#[cfg(feature = "serde1")]
+use crate::storage::EntityId;
+#[cfg(feature = "serde1")]
+use crate::world::World;
+#[cfg(feature = "serde1")]
+use alloc::collections::BTreeMap;
+#[cfg(feature = "serde1")]
+use alloc::string::{String, ToString};
+#[cfg(feature = "serde1")]
+use alloc::vec::Vec;
+
+/// Asserts `entity` has a `T` component equal to the expected value.
+/// Can be used with [World] or [AllStorages].
+///
+/// The component type has to be a path without generics, it's borrowed with a [View].
+///
+/// ### Example
+/// ```
+/// use shipyard::{assert_component, World};
+///
+/// #[derive(PartialEq, Debug)]
+/// struct Pos(u32, u32);
+///
+/// let world = World::new();
+/// let entity = world.spawn((Pos(0, 1),));
+///
+/// assert_component!(world, entity, Pos == Pos(0, 1));
+/// ```
+///
+/// [World]: struct.World.html
+/// [AllStorages]: struct.AllStorages.html
+/// [View]: struct.View.html
+#[macro_export]
+macro_rules! assert_component {
+    ($world: expr, $entity: expr, $($component: ident)::+ == $expected: expr) => {{
+        let entity = $entity;
+
+        match $world.try_borrow::<$crate::View<'_, $($component)::+>>() {
+            Ok(view) => match $crate::Get::get(&view, entity) {
+                Ok(component) => assert_eq!(
+                    *component,
+                    $expected,
+                    "{:?}'s {} isn't the expected one",
+                    entity,
+                    core::any::type_name::<$($component)::+>(),
+                ),
+                Err(_) => panic!(
+                    "{:?} has no {} component",
+                    entity,
+                    core::any::type_name::<$($component)::+>(),
+                ),
+            },
+            Err(err) => panic!("{:?}", err),
+        }
+    }};
+}
+
+/// Asserts two [World]s have the same entities with the same components.
+/// Only storages set up with an identifier are compared, components are compared by their serialized form.
+///
+/// ### Example
+/// ```
+/// use shipyard::{assert_world_eq, Identifier, SerConfig, ViewMut, World};
+///
+/// fn setup(world: &World) {
+///     world.run(|mut u32s: ViewMut<u32>| {
+///         u32s.setup_serde(SerConfig {
+///             identifier: Some(Identifier::new("u32")),
+///             ..Default::default()
+///         });
+///     });
+/// }
+///
+/// let left = World::new();
+/// let right = World::new();
+/// setup(&left);
+/// setup(&right);
+///
+/// left.spawn((0u32,));
+/// right.spawn((0u32, 1usize));
+///
+/// assert_world_eq!(left, right);
+/// ```
+///
+/// [World]: struct.World.html
+#[cfg(feature = "serde1")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde1")))]
+#[macro_export]
+macro_rules! assert_world_eq {
+    ($left: expr, $right: expr) => {{
+        match (
+            $crate::testing::world_state(&$left),
+            $crate::testing::world_state(&$right),
+        ) {
+            (Ok(left), Ok(right)) => assert_eq!(left, right, "Worlds are not equal"),
+            (Err(err), _) | (_, Err(err)) => panic!("{:?}", err),
+        }
+    }};
+}
+
+/// Components of all entities, by storage identifier, in their serialized form.
+#[cfg(feature = "serde1")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde1")))]
+pub type WorldState = BTreeMap<EntityId, BTreeMap<String, String>>;
+
+/// Returns the components of all entities from storages set up with an identifier, in their serialized form.
+/// Used by [assert_world_eq].
+///
+/// ### Borrows
+///
+/// - [AllStorages] (exclusive)
+/// - Storages with an identifier (shared)
+///
+/// ### Errors
+///
+/// - [AllStorages] borrow failed.
+/// - Storage borrow failed.
+///
+/// [assert_world_eq]: ../macro.assert_world_eq.html
+/// [AllStorages]: ../struct.AllStorages.html
+#[cfg(feature = "serde1")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde1")))]
+pub fn world_state(world: &World) -> Result<WorldState, error::Borrow> {
+    let mut all_storages = world.all_storages.try_borrow_mut()?;
+
+    let entities = all_storages.entities()?.iter().collect::<Vec<_>>();
+
+    let mut state = WorldState::new();
+
+    for storage in all_storages.storages().values() {
+        let storage = storage.0.try_borrow()?;
+
+        if let Some(identifier) = storage.identifier() {
+            for &entity in &entities {
+                if storage.has_component(entity) {
+                    let mut serializer = text::TextSerializer::default();
+                    let component = match storage.serialize_component(
+                        entity,
+                        &mut <dyn crate::erased_serde::Serializer>::erase(&mut serializer),
+                    ) {
+                        Ok(_) => serializer.output,
+                        Err(err) => err.to_string(),
+                    };
+
+                    state
+                        .entry(entity)
+                        .or_default()
+                        .insert(identifier.to_string(), component);
+                }
+            }
+        }
+    }
+
+    Ok(state)
+}
+
+/// Returns a text listing the components of all entities from storages set up with an identifier.  
+/// Entities are sorted by index then generation and their components by identifier,
+/// the same [World] content always gives the same text, making it suitable for snapshot tests.
+///
+/// ### Borrows
+///
+/// - [AllStorages] (exclusive)
+/// - Storages with an identifier (shared)
+///
+/// ### Errors
+///
+/// - [AllStorages] borrow failed.
+/// - Storage borrow failed.
+///
+/// ### Example
+/// ```
+/// use shipyard::{testing, Identifier, SerConfig, ViewMut, World};
+///
+/// let world = World::new();
+///
+/// world.run(|mut u32s: ViewMut<u32>| {
+///     u32s.setup_serde(SerConfig {
+///         identifier: Some(Identifier::new("Health")),
+///         ..Default::default()
+///     });
+/// });
+///
+/// world.spawn((100u32,));
+///
+/// assert_eq!(
+///     testing::dump(&world).unwrap(),
+///     "EntityId { index: 0, gen: 0 } {\n    Health: 100,\n}\n"
+/// );
+/// ```
+///
+/// [AllStorages]: ../struct.AllStorages.html
+/// [World]: ../struct.World.html
+#[cfg(feature = "serde1")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde1")))]
+pub fn dump(world: &World) -> Result<String, error::Borrow> {
+    use core::fmt::Write;
+
+    let mut state = world_state(world)?.into_iter().collect::<Vec<_>>();
+    // the generation is stored in the high bits, sorting by id would list it first
+    state.sort_unstable_by_key(|(entity, _)| (entity.index(), entity.gen()));
+
+    let mut dump = String::new();
+    for (entity, components) in state {
+        // writing to a String can't fail
+        writeln!(dump, "{:?} {{", entity).unwrap();
+        for (identifier, component) in components {
+            writeln!(dump, "    {}: {},", identifier, component).unwrap();
+        }
+        dump.push_str("}\n");
+    }
+
+    Ok(dump)
+}
+
+#[cfg(feature = "serde1")]
+mod text {
+    use crate::erased_serde::Error;
+    use alloc::string::String;
+    use core::fmt::{Display, Write};
+    use serde::ser::{
+        Serialize, SerializeMap, SerializeSeq, SerializeStruct, SerializeStructVariant,
+        SerializeTuple, SerializeTupleStruct, SerializeTupleVariant, Serializer,
+    };
+
+    /// Serializes a value to a Rust-like text, the same value always gives the same text.
+    #[derive(Default)]
+    pub(super) struct TextSerializer {
+        pub(super) output: String,
+    }
+
+    impl TextSerializer {
+        fn write(&mut self, value: impl Display) -> Result<(), Error> {
+            write!(self.output, "{}", value).map_err(serde::ser::Error::custom)
+        }
+    }
+
+    /// Serializes the elements of a sequence, map or struct and writes `close` at the end.
+    pub(super) struct Compound<'a> {
+        serializer: &'a mut TextSerializer,
+        first: bool,
+        close: &'static str,
+    }
+
+    impl Compound<'_> {
+        fn separator(&mut self) -> Result<(), Error> {
+            if self.first {
+                self.first = false;
+                Ok(())
+            } else {
+                self.serializer.write(", ")
+            }
+        }
+        fn element<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), Error> {
+            self.separator()?;
+            value.serialize(&mut *self.serializer)
+        }
+        fn field<T: ?Sized + Serialize>(&mut self, key: &str, value: &T) -> Result<(), Error> {
+            self.separator()?;
+            self.serializer.write(key)?;
+            self.serializer.write(": ")?;
+            value.serialize(&mut *self.serializer)
+        }
+        fn end(self) -> Result<(), Error> {
+            self.serializer.write(self.close)
+        }
+    }
+
+    impl<'a> Serializer for &'a mut TextSerializer {
+        type Ok = ();
+        type Error = Error;
+        type SerializeSeq = Compound<'a>;
+        type SerializeTuple = Compound<'a>;
+        type SerializeTupleStruct = Compound<'a>;
+        type SerializeTupleVariant = Compound<'a>;
+        type SerializeMap = Compound<'a>;
+        type SerializeStruct = Compound<'a>;
+        type SerializeStructVariant = Compound<'a>;
+
+        fn serialize_bool(self, v: bool) -> Result<(), Error> {
+            self.write(v)
+        }
+        fn serialize_i8(self, v: i8) -> Result<(), Error> {
+            self.write(v)
+        }
+        fn serialize_i16(self, v: i16) -> Result<(), Error> {
+            self.write(v)
+        }
+        fn serialize_i32(self, v: i32) -> Result<(), Error> {
+            self.write(v)
+        }
+        fn serialize_i64(self, v: i64) -> Result<(), Error> {
+            self.write(v)
+        }
+        fn serialize_u8(self, v: u8) -> Result<(), Error> {
+            self.write(v)
+        }
+        fn serialize_u16(self, v: u16) -> Result<(), Error> {
+            self.write(v)
+        }
+        fn serialize_u32(self, v: u32) -> Result<(), Error> {
+            self.write(v)
+        }
+        fn serialize_u64(self, v: u64) -> Result<(), Error> {
+            self.write(v)
+        }
+        fn serialize_f32(self, v: f32) -> Result<(), Error> {
+            self.write(format_args!("{:?}", v))
+        }
+        fn serialize_f64(self, v: f64) -> Result<(), Error> {
+            self.write(format_args!("{:?}", v))
+        }
+        fn serialize_char(self, v: char) -> Result<(), Error> {
+            self.write(format_args!("{:?}", v))
+        }
+        fn serialize_str(self, v: &str) -> Result<(), Error> {
+            self.write(format_args!("{:?}", v))
+        }
+        fn serialize_bytes(self, v: &[u8]) -> Result<(), Error> {
+            self.write(format_args!("{:?}", v))
+        }
+        fn serialize_none(self) -> Result<(), Error> {
+            self.write("None")
+        }
+        fn serialize_some<T: ?Sized + Serialize>(self, value: &T) -> Result<(), Error> {
+            self.write("Some(")?;
+            value.serialize(&mut *self)?;
+            self.write(")")
+        }
+        fn serialize_unit(self) -> Result<(), Error> {
+            self.write("()")
+        }
+        fn serialize_unit_struct(self, name: &'static str) -> Result<(), Error> {
+            self.write(name)
+        }
+        fn serialize_unit_variant(
+            self,
+            name: &'static str,
+            _: u32,
+            variant: &'static str,
+        ) -> Result<(), Error> {
+            self.write(format_args!("{}::{}", name, variant))
+        }
+        fn serialize_newtype_struct<T: ?Sized + Serialize>(
+            self,
+            name: &'static str,
+            value: &T,
+        ) -> Result<(), Error> {
+            self.write(format_args!("{}(", name))?;
+            value.serialize(&mut *self)?;
+            self.write(")")
+        }
+        fn serialize_newtype_variant<T: ?Sized + Serialize>(
+            self,
+            name: &'static str,
+            _: u32,
+            variant: &'static str,
+            value: &T,
+        ) -> Result<(), Error> {
+            self.write(format_args!("{}::{}(", name, variant))?;
+            value.serialize(&mut *self)?;
+            self.write(")")
+        }
+        fn serialize_seq(self, _: Option<usize>) -> Result<Compound<'a>, Error> {
+            self.write("[")?;
+            Ok(Compound {
+                serializer: self,
+                first: true,
+                close: "]",
+            })
+        }
+        fn serialize_tuple(self, _: usize) -> Result<Compound<'a>, Error> {
+            self.write("(")?;
+            Ok(Compound {
+                serializer: self,
+                first: true,
+                close: ")",
+            })
+        }
+        fn serialize_tuple_struct(
+            self,
+            name: &'static str,
+            _: usize,
+        ) -> Result<Compound<'a>, Error> {
+            self.write(format_args!("{}(", name))?;
+            Ok(Compound {
+                serializer: self,
+                first: true,
+                close: ")",
+            })
+        }
+        fn serialize_tuple_variant(
+            self,
+            name: &'static str,
+            _: u32,
+            variant: &'static str,
+            _: usize,
+        ) -> Result<Compound<'a>, Error> {
+            self.write(format_args!("{}::{}(", name, variant))?;
+            Ok(Compound {
+                serializer: self,
+                first: true,
+                close: ")",
+            })
+        }
+        fn serialize_map(self, _: Option<usize>) -> Result<Compound<'a>, Error> {
+            self.write("{")?;
+            Ok(Compound {
+                serializer: self,
+                first: true,
+                close: "}",
+            })
+        }
+        fn serialize_struct(self, name: &'static str, _: usize) -> Result<Compound<'a>, Error> {
+            self.write(format_args!("{} {{ ", name))?;
+            Ok(Compound {
+                serializer: self,
+                first: true,
+                close: " }",
+            })
+        }
+        fn serialize_struct_variant(
+            self,
+            name: &'static str,
+            _: u32,
+            variant: &'static str,
+            _: usize,
+        ) -> Result<Compound<'a>, Error> {
+            self.write(format_args!("{}::{} {{ ", name, variant))?;
+            Ok(Compound {
+                serializer: self,
+                first: true,
+                close: " }",
+            })
+        }
+    }
+
+    impl SerializeSeq for Compound<'_> {
+        type Ok = ();
+        type Error = Error;
+
+        fn serialize_element<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), Error> {
+            self.element(value)
+        }
+        fn end(self) -> Result<(), Error> {
+            Compound::end(self)
+        }
+    }
+
+    impl SerializeTuple for Compound<'_> {
+        type Ok = ();
+        type Error = Error;
+
+        fn serialize_element<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), Error> {
+            self.element(value)
+        }
+        fn end(self) -> Result<(), Error> {
+            Compound::end(self)
+        }
+    }
+
+    impl SerializeTupleStruct for Compound<'_> {
+        type Ok = ();
+        type Error = Error;
+
+        fn serialize_field<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), Error> {
+            self.element(value)
+        }
+        fn end(self) -> Result<(), Error> {
+            Compound::end(self)
+        }
+    }
+
+    impl SerializeTupleVariant for Compound<'_> {
+        type Ok = ();
+        type Error = Error;
+
+        fn serialize_field<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), Error> {
+            self.element(value)
+        }
+        fn end(self) -> Result<(), Error> {
+            Compound::end(self)
+        }
+    }
+
+    impl SerializeMap for Compound<'_> {
+        type Ok = ();
+        type Error = Error;
+
+        fn serialize_key<T: ?Sized + Serialize>(&mut self, key: &T) -> Result<(), Error> {
+            self.element(key)
+        }
+        fn serialize_value<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), Error> {
+            self.serializer.write(": ")?;
+            value.serialize(&mut *self.serializer)
+        }
+        fn end(self) -> Result<(), Error> {
+            Compound::end(self)
+        }
+    }
+
+    impl SerializeStruct for Compound<'_> {
+        type Ok = ();
+        type Error = Error;
+
+        fn serialize_field<T: ?Sized + Serialize>(
+            &mut self,
+            key: &'static str,
+            value: &T,
+        ) -> Result<(), Error> {
+            self.field(key, value)
+        }
+        fn end(self) -> Result<(), Error> {
+            Compound::end(self)
+        }
+    }
+
+    impl SerializeStructVariant for Compound<'_> {
+        type Ok = ();
+        type Error = Error;
+
+        fn serialize_field<T: ?Sized + Serialize>(
+            &mut self,
+            key: &'static str,
+            value: &T,
+        ) -> Result<(), Error> {
+            self.field(key, value)
+        }
+        fn end(self) -> Result<(), Error> {
+            Compound::end(self)
+        }
+    }
+}
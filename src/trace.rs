@@ -0,0 +1,116 @@
+use alloc::string::String;
+use alloc::vec::Vec;
+use std::io::{self, Write};
+use std::sync::Mutex;
+use std::thread::{self, ThreadId};
+use std::time::{Duration, Instant};
+
+/// Records when each system ran and on which thread, in the chrome://tracing format.
+pub(crate) struct Trace {
+    origin: Instant,
+    state: Mutex<State>,
+}
+
+#[derive(Default)]
+struct State {
+    events: Vec<Event>,
+    // index in this list is used as the thread's id in the trace
+    threads: Vec<(ThreadId, Option<String>)>,
+}
+
+struct Event {
+    name: &'static str,
+    thread: usize,
+    start: Duration,
+    duration: Duration,
+}
+
+impl Default for Trace {
+    fn default() -> Self {
+        Trace {
+            origin: Instant::now(),
+            state: Mutex::new(State::default()),
+        }
+    }
+}
+
+impl Trace {
+    /// Runs `f` and records it as `name` on the current thread.
+    pub(crate) fn record<R>(&self, name: &'static str, f: impl FnOnce() -> R) -> R {
+        let start = Instant::now();
+        let result = f();
+        let end = Instant::now();
+
+        let current = thread::current();
+        let mut state = self.state.lock().unwrap();
+
+        let thread = match state.threads.iter().position(|&(id, _)| id == current.id()) {
+            Some(thread) => thread,
+            None => {
+                state
+                    .threads
+                    .push((current.id(), current.name().map(String::from)));
+                state.threads.len() - 1
+            }
+        };
+
+        state.events.push(Event {
+            name,
+            thread,
+            start: start - self.origin,
+            duration: end - start,
+        });
+
+        result
+    }
+    /// Writes all events recorded so far as a chrome://tracing JSON object.
+    pub(crate) fn write(&self, mut writer: impl Write) -> io::Result<()> {
+        let state = self.state.lock().unwrap();
+
+        writer.write_all(b"{\"traceEvents\":[")?;
+
+        let mut first = true;
+        for (thread, (_, name)) in state.threads.iter().enumerate() {
+            if let Some(name) = name {
+                if !first {
+                    writer.write_all(b",")?;
+                }
+                first = false;
+
+                write!(
+                    writer,
+                    "\n{{\"name\":\"thread_name\",\"ph\":\"M\",\"pid\":0,\"tid\":{},\"args\":{{\"name\":\"{}\"}}}}",
+                    thread,
+                    escape(name)
+                )?;
+            }
+        }
+
+        for event in &state.events {
+            if !first {
+                writer.write_all(b",")?;
+            }
+            first = false;
+
+            write!(
+                writer,
+                "\n{{\"name\":\"{}\",\"cat\":\"system\",\"ph\":\"X\",\"pid\":0,\"tid\":{},\"ts\":{},\"dur\":{}}}",
+                escape(event.name),
+                event.thread,
+                micros(event.start),
+                micros(event.duration)
+            )?;
+        }
+
+        writer.write_all(b"\n]}\n")?;
+        writer.flush()
+    }
+}
+
+fn micros(duration: Duration) -> f64 {
+    duration.as_nanos() as f64 / 1000.0
+}
+
+fn escape(s: &str) -> String {
+    s.replace('\\', "\\\\").replace('"', "\\\"")
+}
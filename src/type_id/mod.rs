@@ -5,13 +5,21 @@ pub(crate) use hasher::TypeIdHasher;
 use core::hash::{Hash, Hasher};
 
 /// We have to make our own `TypeId` to be able to deserialize it.
+///
+/// Used by [Borrow::borrow_infos] to tell the scheduler which storages a system borrows.
+///
+/// [Borrow::borrow_infos]: trait.Borrow.html#tymethod.borrow_infos
 #[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, PartialOrd, Ord)]
 pub struct TypeId(u64);
 
 impl TypeId {
-    pub(crate) fn of<T: ?Sized + 'static>() -> Self {
+    /// Returns the `TypeId` of `T`, the same as `T`'s storage one.
+    pub fn of<T: ?Sized + 'static>() -> Self {
         core::any::TypeId::of::<T>().into()
     }
+    pub(crate) fn as_u64(self) -> u64 {
+        self.0
+    }
 }
 
 impl From<core::any::TypeId> for TypeId {
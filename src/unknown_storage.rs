@@ -1,10 +1,17 @@
+use crate::event_log::StructuralEvent;
+use crate::messages::Messages;
+#[cfg(feature = "serde1")]
+use crate::recording::RecordedCommand;
+use crate::reflect::Reflect;
+use crate::relation::Relation;
 #[cfg(feature = "serde1")]
 use crate::serde_setup::{GlobalDeConfig, GlobalSerConfig, ANCHOR};
+use crate::snapshot::StorageSnapshot;
 use crate::sparse_set::SparseSet;
-#[cfg(feature = "serde1")]
-use crate::storage::Storage;
-use crate::storage::{Entities, EntityId};
+use crate::storage::{Entities, EntityId, Signatures, Storage, StorageId};
+use crate::tags::Tags;
 use crate::type_id::TypeId;
+use alloc::sync::Arc;
 use alloc::vec::Vec;
 use core::any::Any;
 
@@ -14,6 +21,20 @@ pub(super) trait UnknownStorage {
     fn unpack(&mut self, entity: EntityId);
     fn any(&self) -> &dyn Any;
     fn any_mut(&mut self) -> &mut dyn Any;
+    fn name(&self) -> &'static str;
+    /// Preallocates the storage and prevents it from growing, only component storages are affected.
+    fn set_fixed_capacity(&mut self, _entity_capacity: usize, _capacity: usize) {}
+    /// Releases unused memory, only component storages are affected.
+    fn compact(&mut self) {}
+    fn snapshot(&self) -> Option<StorageSnapshot> {
+        None
+    }
+    /// `None` means the storage didn't exist when the snapshot was taken.
+    fn restore(&mut self, _: Option<&StorageSnapshot>) {}
+    /// Returns an independent copy of this storage if it can be cloned.
+    fn clone_storage(&self) -> Option<Storage> {
+        None
+    }
     #[cfg(feature = "serde1")]
     fn is_serializable(&self) -> bool {
         false
@@ -40,6 +61,7 @@ pub(super) trait UnknownStorage {
     ) -> Option<
         fn(
             GlobalDeConfig,
+            u32,
             &mut dyn crate::erased_serde::Deserializer<'_>,
         ) -> Result<Storage, crate::erased_serde::Error>,
     > {
@@ -49,6 +71,96 @@ pub(super) trait UnknownStorage {
         );
         None
     }
+    /// Returns the layout version of the components, saved next to the storage.
+    #[cfg(feature = "serde1")]
+    fn serde_version(&self) -> u32 {
+        0
+    }
+    #[cfg(feature = "serde1")]
+    fn identifier(&self) -> Option<&str> {
+        None
+    }
+    fn has_component(&self, _: EntityId) -> bool {
+        false
+    }
+    /// Starts or stops logging structural events, only `Entities` and component storages log events.
+    fn set_event_log(&mut self, _: bool) {}
+    /// Moves the logged events to `events` with their index.
+    fn drain_events(&mut self, _: &mut Vec<(usize, StructuralEvent)>) {}
+    /// Returns the version of a unique storage, it changes each time the unique is borrowed exclusively.
+    fn version(&self) -> usize {
+        0
+    }
+    fn bump_version(&mut self) {}
+    /// Returns `true` if deleting `entity` modifies this storage.
+    fn references(&self, _: EntityId) -> bool {
+        false
+    }
+    /// Keeps `signatures` up to date with the entities referenced by this storage, starting with the current ones.  
+    /// `Unique` and `Messages` storages don't reference entities.
+    fn track_signatures(&mut self, _: StorageId, _: Arc<Signatures>) {}
+    /// Orders the messages sent during a parallel batch by the position of their sender in the batch.
+    /// Only messages storages are affected.
+    #[cfg(feature = "parallel")]
+    fn sort_messages(&self) {}
+    /// Returns the component owned by `entity`, only component storages have components.
+    fn component(&self, _: EntityId) -> Option<&dyn Any> {
+        None
+    }
+    /// Returns the entities owning a component in this storage.
+    fn component_entities(&self) -> &[EntityId] {
+        &[]
+    }
+    /// Returns the storages tight or loose packed with this one, itself included.
+    fn pack_types(&self) -> Vec<TypeId> {
+        Vec::new()
+    }
+    /// Returns the tight storages of the loose packs this storage is part of.
+    fn observer_types(&self) -> &[TypeId] {
+        &[]
+    }
+    /// Empties the tight or loose pack of this storage then packs `entities` in order.
+    fn repack(&mut self, _: &[EntityId]) {}
+    /// Returns `true` if the components can be accessed with `reflect` and `reflect_mut`.
+    fn is_reflected(&self) -> bool {
+        false
+    }
+    fn reflect(&self, _: EntityId) -> Option<&dyn Reflect> {
+        None
+    }
+    fn reflect_mut(&mut self, _: EntityId) -> Option<&mut dyn Reflect> {
+        None
+    }
+    #[cfg(feature = "serde1")]
+    fn serialize_component(
+        &self,
+        _: EntityId,
+        _: &mut dyn crate::erased_serde::Serializer,
+    ) -> crate::erased_serde::Result<crate::erased_serde::Ok> {
+        Err(serde::ser::Error::custom("This type isn't serializable."))
+    }
+    #[cfg(feature = "serde1")]
+    fn deserialize_component(
+        &mut self,
+        _: EntityId,
+        _: &mut dyn crate::erased_serde::Deserializer<'_>,
+    ) -> Result<(), crate::erased_serde::Error> {
+        Err(serde::de::Error::custom("This type isn't serializable."))
+    }
+    #[cfg(feature = "serde1")]
+    fn map_entity_ids(&mut self, _: &[EntityId], _: &dyn Fn(EntityId) -> EntityId) {}
+    /// Replaces the `EntityId` owning each component by the one `map` returns.
+    #[cfg(feature = "serde1")]
+    fn remap_entities(&mut self, _: &dyn Fn(EntityId) -> EntityId) {}
+    /// Moves all components of `other`, a storage of the same type, to this storage.
+    #[cfg(feature = "serde1")]
+    fn append(&mut self, _: &mut dyn UnknownStorage) {}
+    #[cfg(feature = "serde1")]
+    fn start_recording(&mut self) {}
+    #[cfg(feature = "serde1")]
+    fn stop_recording(&mut self) -> Vec<(u64, RecordedCommand)> {
+        Vec::new()
+    }
 }
 
 impl dyn UnknownStorage {
@@ -70,17 +182,37 @@ impl dyn UnknownStorage {
     pub(crate) fn unique_mut<T: 'static>(&mut self) -> Option<&mut T> {
         self.any_mut().downcast_mut()
     }
+    pub(crate) fn relation<T: 'static>(&self) -> Option<&Relation<T>> {
+        self.any().downcast_ref()
+    }
+    pub(crate) fn relation_mut<T: 'static>(&mut self) -> Option<&mut Relation<T>> {
+        self.any_mut().downcast_mut()
+    }
+    pub(crate) fn messages<T: 'static>(&self) -> Option<&Messages<T>> {
+        self.any().downcast_ref()
+    }
+    pub(crate) fn messages_mut<T: 'static>(&mut self) -> Option<&mut Messages<T>> {
+        self.any_mut().downcast_mut()
+    }
+    pub(crate) fn tags(&self) -> Option<&Tags> {
+        self.any().downcast_ref()
+    }
+    pub(crate) fn tags_mut(&mut self) -> Option<&mut Tags> {
+        self.any_mut().downcast_mut()
+    }
 }
 
 #[cfg(feature = "serde1")]
 pub(crate) fn deserialize_ptr(
     func: fn(
         GlobalDeConfig,
+        u32,
         &mut dyn crate::erased_serde::Deserializer<'_>,
     ) -> Result<Storage, crate::erased_serde::Error>,
 ) -> usize {
     let anchor: *const () = &ANCHOR;
-    anchor as usize - func as usize
+    // the function can be on either side of the anchor
+    (anchor as usize).wrapping_sub(func as usize)
 }
 
 #[cfg(feature = "serde1")]
@@ -88,14 +220,79 @@ pub(crate) unsafe fn deserialize_fn(
     ptr: usize,
 ) -> fn(
     GlobalDeConfig,
+    u32,
     &mut dyn crate::erased_serde::Deserializer<'_>,
 ) -> Result<Storage, crate::erased_serde::Error> {
     let anchor: *const () = &ANCHOR;
-    let deserialize_ptr = anchor as usize - ptr;
+    let deserialize_ptr = (anchor as usize).wrapping_sub(ptr);
     let deserialize: *const () = deserialize_ptr as *const _;
     core::mem::transmute(deserialize)
 }
 
+/// Describes how to deserialize a storage, the `World` serializes one for each storage.
+#[cfg(feature = "serde1")]
+pub(crate) struct StorageMetadata {
+    pub(crate) storage_id: StorageId,
+    pub(crate) deserialize_ptr: usize,
+    pub(crate) version: u32,
+}
+
+#[cfg(feature = "serde1")]
+impl serde::Serialize for StorageMetadata {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeTuple;
+
+        let mut state = serializer.serialize_tuple(3)?;
+        state.serialize_element(&self.storage_id)?;
+        state.serialize_element(&self.deserialize_ptr)?;
+        state.serialize_element(&self.version)?;
+        state.end()
+    }
+}
+
+#[cfg(feature = "serde1")]
+impl<'de> serde::Deserialize<'de> for StorageMetadata {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        struct Visitor;
+
+        impl<'de> serde::de::Visitor<'de> for Visitor {
+            type Value = StorageMetadata;
+
+            fn expecting(&self, formatter: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                formatter.write_str("storage metadata")
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: serde::de::SeqAccess<'de>,
+            {
+                let storage_id = seq.next_element()?.ok_or_else(|| {
+                    serde::de::Error::invalid_length(0, &"storage metadata with 3 elements")
+                })?;
+                let deserialize_ptr = seq.next_element()?.ok_or_else(|| {
+                    serde::de::Error::invalid_length(1, &"storage metadata with 3 elements")
+                })?;
+                // saves made before storages had a version don't have it
+                let version = seq.next_element()?.unwrap_or(0);
+
+                Ok(StorageMetadata {
+                    storage_id,
+                    deserialize_ptr,
+                    version,
+                })
+            }
+        }
+
+        deserializer.deserialize_tuple(3, Visitor)
+    }
+}
+
 #[cfg(feature = "serde1")]
 pub(crate) struct StorageSerializer<'a> {
     pub(crate) unknown_storage: &'a dyn UnknownStorage,
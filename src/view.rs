@@ -1,12 +1,32 @@
 use crate::atomic_refcell::{AtomicRefCell, Borrow};
 use crate::atomic_refcell::{Ref, RefMut};
 use crate::error;
-use crate::sparse_set::{SparseSet, Window};
+use crate::relation::Relation;
+use crate::sparse_set::{AddComponentUnchecked, SparseSet, Window};
+use crate::storage::{EntityId, StorageId};
+use crate::tags::Tags;
 use crate::{AllStorages, Entities};
+use core::any::type_name;
 use core::convert::TryFrom;
 use core::ops::{Deref, DerefMut};
 
-struct AllStoragesView<'a>(Ref<'a, AllStorages>);
+/// Shared view over `AllStorages`.
+/// Systems only reading storages can run alongside it, systems borrowing a storage exclusively can't.
+/// Useful to inspect storages (counts, presence, erased reads) without blocking the whole workload.
+///
+/// ### Example
+/// ```
+/// use shipyard::{AllStoragesView, World};
+///
+/// let world = World::new();
+/// let entity = world.spawn((0u32,));
+///
+/// world.run(|all_storages: AllStoragesView| {
+///     assert_eq!(all_storages.borrow::<shipyard::View<u32>>().len(), 1);
+///     assert!(all_storages.borrow::<shipyard::EntitiesView>().is_alive(entity));
+/// });
+/// ```
+pub struct AllStoragesView<'a>(Ref<'a, AllStorages>);
 
 impl<'a> TryFrom<&'a AtomicRefCell<AllStorages>> for AllStoragesView<'a> {
     type Error = error::GetStorage;
@@ -278,6 +298,8 @@ impl<'a, T> AsRef<Window<'a, T>> for View<'a, T> {
 /// Exclusive view over a component storage.
 pub struct ViewMut<'a, T> {
     sparse_set: RefMut<'a, SparseSet<T>>,
+    // used to check entities are alive without borrowing Entities for the whole view's lifetime
+    all_storages: &'a AllStorages,
     _all_borrow: Borrow<'a>,
 }
 
@@ -288,6 +310,7 @@ impl<'a, T: 'static + Send + Sync> TryFrom<Ref<'a, AllStorages>> for ViewMut<'a,
         let (all_storages, all_borrow) = unsafe { Ref::destructure(all_storages) };
         Ok(ViewMut {
             sparse_set: all_storages.sparse_set_mut::<T>()?,
+            all_storages,
             _all_borrow: all_borrow,
         })
     }
@@ -298,6 +321,7 @@ impl<'a, T: 'static + Send + Sync> TryFrom<&'a AllStorages> for ViewMut<'a, T> {
     fn try_from(all_storages: &'a AllStorages) -> Result<Self, Self::Error> {
         Ok(ViewMut {
             sparse_set: all_storages.sparse_set_mut::<T>()?,
+            all_storages,
             _all_borrow: Borrow::None,
         })
     }
@@ -312,6 +336,7 @@ impl<'a, T: 'static + Sync> ViewMut<'a, T> {
         let (all_storages, all_borrow) = unsafe { Ref::destructure(all_storages) };
         Ok(ViewMut {
             sparse_set: all_storages.sparse_set_non_send_mut::<T>()?,
+            all_storages,
             _all_borrow: all_borrow,
         })
     }
@@ -320,6 +345,7 @@ impl<'a, T: 'static + Sync> ViewMut<'a, T> {
     ) -> Result<Self, error::GetStorage> {
         Ok(ViewMut {
             sparse_set: all_storages.sparse_set_non_send_mut::<T>()?,
+            all_storages,
             _all_borrow: Borrow::None,
         })
     }
@@ -334,6 +360,7 @@ impl<'a, T: 'static + Send> ViewMut<'a, T> {
         let (all_storages, all_borrow) = unsafe { Ref::destructure(all_storages) };
         Ok(ViewMut {
             sparse_set: all_storages.sparse_set_non_sync_mut::<T>()?,
+            all_storages,
             _all_borrow: all_borrow,
         })
     }
@@ -342,6 +369,7 @@ impl<'a, T: 'static + Send> ViewMut<'a, T> {
     ) -> Result<Self, error::GetStorage> {
         Ok(ViewMut {
             sparse_set: all_storages.sparse_set_non_sync_mut::<T>()?,
+            all_storages,
             _all_borrow: Borrow::None,
         })
     }
@@ -356,6 +384,7 @@ impl<'a, T: 'static> ViewMut<'a, T> {
         let (all_storages, all_borrow) = unsafe { Ref::destructure(all_storages) };
         Ok(ViewMut {
             sparse_set: all_storages.sparse_set_non_send_sync_mut::<T>()?,
+            all_storages,
             _all_borrow: all_borrow,
         })
     }
@@ -364,11 +393,212 @@ impl<'a, T: 'static> ViewMut<'a, T> {
     ) -> Result<Self, error::GetStorage> {
         Ok(ViewMut {
             sparse_set: all_storages.sparse_set_non_send_sync_mut::<T>()?,
+            all_storages,
             _all_borrow: Borrow::None,
         })
     }
 }
 
+impl<T: 'static> ViewMut<'_, T> {
+    /// Adds `component` to `entity` if it's alive.  
+    /// Unlike [Entities::try_add_component], `Entities` doesn't have to be borrowed next to this view,
+    /// it's only borrowed immutably while checking `entity` and adding the component.
+    ///
+    /// ### Borrows
+    ///
+    /// - Entities (shared) for the duration of the call
+    ///
+    /// ### Errors
+    ///
+    /// - Entities borrow failed, it's already exclusively borrowed, by `EntitiesViewMut` for example.
+    /// - `entity` isn't alive.
+    /// - The storage is packed, use [Entities::try_add_component] with all packed storages instead.
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{EntitiesViewMut, ViewMut, World};
+    ///
+    /// let world = World::new();
+    ///
+    /// let entity = world.borrow::<EntitiesViewMut>().add_entity((), ());
+    ///
+    /// world.run(|mut u32s: ViewMut<u32>| {
+    ///     u32s.try_add_component(0, entity).unwrap();
+    /// });
+    /// ```
+    ///
+    /// [Entities::try_add_component]: struct.Entities.html#method.try_add_component
+    pub fn try_add_component(
+        &mut self,
+        component: T,
+        entity: EntityId,
+    ) -> Result<(), error::AddComponent> {
+        let all_storages = self.all_storages;
+        // held until the component is added, `entity` can't be deleted in between
+        let entities = all_storages
+            .entities()
+            .map_err(error::AddComponent::Entities)?;
+
+        if entities.is_alive(entity) {
+            self.try_add_component_unchecked(component, entity)
+        } else {
+            Err(error::AddComponent::EntityIsNotAlive)
+        }
+    }
+    /// Adds `component` to `entity` if it's alive.  
+    /// Unlike [Entities::add_component], `Entities` doesn't have to be borrowed next to this view,
+    /// it's only borrowed immutably while checking `entity` and adding the component.  
+    /// Unwraps errors.
+    ///
+    /// ### Borrows
+    ///
+    /// - Entities (shared) for the duration of the call
+    ///
+    /// ### Errors
+    ///
+    /// - Entities borrow failed, it's already exclusively borrowed, by `EntitiesViewMut` for example.
+    /// - `entity` isn't alive.
+    /// - The storage is packed, use [Entities::add_component] with all packed storages instead.
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{EntitiesViewMut, ViewMut, World};
+    ///
+    /// let world = World::new();
+    ///
+    /// let entity = world.borrow::<EntitiesViewMut>().add_entity((), ());
+    ///
+    /// world.run(|mut u32s: ViewMut<u32>| {
+    ///     u32s.add_component(0, entity);
+    /// });
+    /// ```
+    ///
+    /// [Entities::add_component]: struct.Entities.html#method.add_component
+    #[cfg(feature = "panic")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "panic")))]
+    #[track_caller]
+    pub fn add_component(&mut self, component: T, entity: EntityId) {
+        self.try_add_component(component, entity).unwrap();
+    }
+    /// Returns a mutable reference to `entity`'s component, inserting the result of `f` first if it doesn't have one.
+    /// `f` is only called when the component is missing, `entity` has to be alive in this case.
+    /// Like [ViewMut::try_add_component], `Entities` is only borrowed when inserting.
+    ///
+    /// ### Borrows
+    ///
+    /// - Entities (shared) for the duration of the call, only when the component is missing
+    ///
+    /// ### Errors
+    ///
+    /// - Entities borrow failed, it's already exclusively borrowed, by `EntitiesViewMut` for example.
+    /// - `entity` isn't alive.
+    /// - The storage is packed, use [Entities::try_add_component] with all packed storages instead.
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{EntitiesViewMut, ViewMut, World};
+    ///
+    /// let world = World::new();
+    ///
+    /// let entity = world.borrow::<EntitiesViewMut>().add_entity((), ());
+    ///
+    /// world.run(|mut u32s: ViewMut<u32>| {
+    ///     *u32s.try_get_or_insert_with(entity, || 0).unwrap() += 1;
+    ///     *u32s.try_get_or_insert_with(entity, || 0).unwrap() += 1;
+    ///
+    ///     assert_eq!(u32s[entity], 2);
+    /// });
+    /// ```
+    ///
+    /// [ViewMut::try_add_component]: struct.ViewMut.html#method.try_add_component
+    /// [Entities::try_add_component]: struct.Entities.html#method.try_add_component
+    pub fn try_get_or_insert_with<F: FnOnce() -> T>(
+        &mut self,
+        entity: EntityId,
+        f: F,
+    ) -> Result<&mut T, error::AddComponent> {
+        if !self.contains(entity) {
+            self.try_add_component(f(), entity)?;
+        }
+
+        // the component was either present or just inserted
+        Ok(self.get_mut(entity).unwrap())
+    }
+    /// Returns a mutable reference to `entity`'s component, inserting the result of `f` first if it doesn't have one.
+    /// `f` is only called when the component is missing, `entity` has to be alive in this case.
+    /// Like [ViewMut::add_component], `Entities` is only borrowed when inserting.
+    /// Unwraps errors.
+    ///
+    /// ### Borrows
+    ///
+    /// - Entities (shared) for the duration of the call, only when the component is missing
+    ///
+    /// ### Errors
+    ///
+    /// - Entities borrow failed, it's already exclusively borrowed, by `EntitiesViewMut` for example.
+    /// - `entity` isn't alive.
+    /// - The storage is packed, use [Entities::add_component] with all packed storages instead.
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{EntitiesViewMut, ViewMut, World};
+    ///
+    /// let world = World::new();
+    ///
+    /// let entity = world.borrow::<EntitiesViewMut>().add_entity((), ());
+    ///
+    /// world.run(|mut u32s: ViewMut<u32>| {
+    ///     *u32s.get_or_insert_with(entity, || 0) += 1;
+    ///     *u32s.get_or_insert_with(entity, || 0) += 1;
+    ///
+    ///     assert_eq!(u32s[entity], 2);
+    /// });
+    /// ```
+    ///
+    /// [ViewMut::add_component]: struct.ViewMut.html#method.add_component
+    /// [Entities::add_component]: struct.Entities.html#method.add_component
+    #[cfg(feature = "panic")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "panic")))]
+    #[track_caller]
+    pub fn get_or_insert_with<F: FnOnce() -> T>(&mut self, entity: EntityId, f: F) -> &mut T {
+        self.try_get_or_insert_with(entity, f).unwrap()
+    }
+    /// Returns a mutable reference to `entity`'s component without flagging it as modified.  
+    /// Writes made through it won't show up in [SparseSet::modified], systems reacting to modifications will skip them.  
+    /// Useful for bookkeeping writes like interpolation.
+    ///
+    /// ### Errors
+    ///
+    /// - `entity` doesn't have a component in this storage.
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{EntitiesViewMut, ViewMut, World};
+    ///
+    /// let world = World::new();
+    ///
+    /// world.run(|mut entities: EntitiesViewMut, mut u32s: ViewMut<u32>| {
+    ///     let entity = entities.add_entity(&mut u32s, 0);
+    ///     u32s.update_pack();
+    ///     u32s.clear_inserted();
+    ///
+    ///     *u32s.get_untracked(entity).unwrap() += 1;
+    ///
+    ///     assert_eq!(u32s[entity], 1);
+    ///     assert_eq!(u32s.modified().len(), 0);
+    /// });
+    /// ```
+    ///
+    /// [SparseSet::modified]: struct.SparseSet.html#method.modified
+    pub fn get_untracked(&mut self, entity: EntityId) -> Result<&mut T, error::MissingComponent> {
+        self.get_mut_untracked(entity)
+            .ok_or_else(|| error::MissingComponent {
+                id: entity,
+                name: type_name::<T>(),
+            })
+    }
+}
+
 impl<T> Deref for ViewMut<'_, T> {
     type Target = SparseSet<T>;
     fn deref(&self) -> &Self::Target {
@@ -394,9 +624,12 @@ impl<'a, T> AsMut<SparseSet<T>> for ViewMut<'a, T> {
     }
 }
 
-/// Shared view over a unique component storage.
+/// Shared view over a unique component storage.  
+/// Borrow `Option<UniqueView<T>>` when the unique might not have been added,
+/// it will be `None` in this case and no storage is created.
 pub struct UniqueView<'a, T> {
     unique: Ref<'a, T>,
+    version: usize,
     _all_borrow: Borrow<'a>,
 }
 
@@ -406,8 +639,11 @@ impl<'a, T: 'static> TryFrom<Ref<'a, AllStorages>> for UniqueView<'a, T> {
         // SAFE all_storages and unique are dropped before all_borrow
         let (all_storages, all_borrow) = unsafe { Ref::destructure(all_storages) };
 
+        let (unique, version) = all_storages.unique::<T>()?;
+
         Ok(UniqueView {
-            unique: all_storages.unique::<T>()?,
+            unique,
+            version,
             _all_borrow: all_borrow,
         })
     }
@@ -416,13 +652,39 @@ impl<'a, T: 'static> TryFrom<Ref<'a, AllStorages>> for UniqueView<'a, T> {
 impl<'a, T: 'static> TryFrom<&'a AllStorages> for UniqueView<'a, T> {
     type Error = error::GetStorage;
     fn try_from(all_storages: &'a AllStorages) -> Result<Self, Self::Error> {
+        let (unique, version) = all_storages.unique::<T>()?;
+
         Ok(UniqueView {
-            unique: all_storages.unique::<T>()?,
+            unique,
+            version,
             _all_borrow: Borrow::None,
         })
     }
 }
 
+impl<T> UniqueView<'_, T> {
+    /// Returns the version of the unique, it changes each time the unique is borrowed exclusively.  
+    /// Versions are never reused, even when the unique is removed and added again,
+    /// data derived from the unique can be cached along its version.
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{UniqueView, UniqueViewMut, World};
+    ///
+    /// let world = World::new();
+    /// world.add_unique(0u32);
+    ///
+    /// let version = world.run(|unique: UniqueView<u32>| unique.version());
+    /// world.run(|unique: UniqueView<u32>| assert_eq!(unique.version(), version));
+    ///
+    /// world.run(|mut unique: UniqueViewMut<u32>| *unique += 1);
+    /// world.run(|unique: UniqueView<u32>| assert_ne!(unique.version(), version));
+    /// ```
+    pub fn version(&self) -> usize {
+        self.version
+    }
+}
+
 impl<T> Deref for UniqueView<'_, T> {
     type Target = T;
     fn deref(&self) -> &Self::Target {
@@ -430,7 +692,9 @@ impl<T> Deref for UniqueView<'_, T> {
     }
 }
 
-/// Exclusive view over a unique component storage.
+/// Exclusive view over a unique component storage.  
+/// Borrow `Option<UniqueViewMut<T>>` when the unique might not have been added,
+/// it will be `None` in this case and no storage is created.
 pub struct UniqueViewMut<'a, T> {
     unique: RefMut<'a, T>,
     _all_borrow: Borrow<'a>,
@@ -472,12 +736,245 @@ impl<T> DerefMut for UniqueViewMut<'_, T> {
     }
 }
 
+/// Shared view over a [Relation] storage.
+///
+/// [Relation]: struct.Relation.html
+pub struct RelationView<'a, T> {
+    relation: Ref<'a, Relation<T>>,
+    _all_borrow: Borrow<'a>,
+}
+
+impl<'a, T: 'static + Send + Sync> TryFrom<Ref<'a, AllStorages>> for RelationView<'a, T> {
+    type Error = error::GetStorage;
+    fn try_from(all_storages: Ref<'a, AllStorages>) -> Result<Self, Self::Error> {
+        // SAFE all_storages and relation are dropped before all_borrow
+        let (all_storages, all_borrow) = unsafe { Ref::destructure(all_storages) };
+
+        Ok(RelationView {
+            relation: all_storages.relation::<T>()?,
+            _all_borrow: all_borrow,
+        })
+    }
+}
+
+impl<'a, T: 'static + Send + Sync> TryFrom<&'a AllStorages> for RelationView<'a, T> {
+    type Error = error::GetStorage;
+    fn try_from(all_storages: &'a AllStorages) -> Result<Self, Self::Error> {
+        Ok(RelationView {
+            relation: all_storages.relation::<T>()?,
+            _all_borrow: Borrow::None,
+        })
+    }
+}
+
+impl<T> Deref for RelationView<'_, T> {
+    type Target = Relation<T>;
+    fn deref(&self) -> &Self::Target {
+        &self.relation
+    }
+}
+
+/// Exclusive view over a [Relation] storage.
+///
+/// [Relation]: struct.Relation.html
+pub struct RelationViewMut<'a, T> {
+    relation: RefMut<'a, Relation<T>>,
+    _all_borrow: Borrow<'a>,
+}
+
+impl<'a, T: 'static + Send + Sync> TryFrom<Ref<'a, AllStorages>> for RelationViewMut<'a, T> {
+    type Error = error::GetStorage;
+    fn try_from(all_storages: Ref<'a, AllStorages>) -> Result<Self, Self::Error> {
+        // SAFE all_storages and relation are dropped before all_borrow
+        let (all_storages, all_borrow) = unsafe { Ref::destructure(all_storages) };
+
+        Ok(RelationViewMut {
+            relation: all_storages.relation_mut::<T>()?,
+            _all_borrow: all_borrow,
+        })
+    }
+}
+
+impl<'a, T: 'static + Send + Sync> TryFrom<&'a AllStorages> for RelationViewMut<'a, T> {
+    type Error = error::GetStorage;
+    fn try_from(all_storages: &'a AllStorages) -> Result<Self, Self::Error> {
+        Ok(RelationViewMut {
+            relation: all_storages.relation_mut::<T>()?,
+            _all_borrow: Borrow::None,
+        })
+    }
+}
+
+impl<T> Deref for RelationViewMut<'_, T> {
+    type Target = Relation<T>;
+    fn deref(&self) -> &Self::Target {
+        &self.relation
+    }
+}
+
+impl<T> DerefMut for RelationViewMut<'_, T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.relation
+    }
+}
+
+/// Shared view to test which components entities have, without the views of these components.  
+/// Useful when a system branches on the presence of components but doesn't read them.
+///
+/// Checks only read the storages each entity is part of, kept up to date by `Entities`.  
+/// Only `Entities` is borrowed, systems borrowing component storages exclusively can run alongside it.
+///
+/// ### Example
+/// ```
+/// use shipyard::{MaskView, World};
+///
+/// struct Frozen;
+///
+/// let world = World::new();
+/// let frozen = world.spawn((0u32, Frozen));
+/// let moving = world.spawn((1u32,));
+///
+/// world.run(|mask: MaskView| {
+///     assert!(mask.has::<Frozen>(frozen));
+///     assert!(!mask.has::<Frozen>(moving));
+///     assert!(mask.has::<u32>(moving));
+/// });
+/// ```
+pub struct MaskView<'a> {
+    entities: Ref<'a, Entities>,
+    _all_borrow: Borrow<'a>,
+}
+
+impl<'a> TryFrom<Ref<'a, AllStorages>> for MaskView<'a> {
+    type Error = error::GetStorage;
+    fn try_from(all_storages: Ref<'a, AllStorages>) -> Result<Self, Self::Error> {
+        // SAFE all_storages and entities are dropped before all_borrow
+        let (all_storages, all_borrow) = unsafe { Ref::destructure(all_storages) };
+
+        Ok(MaskView {
+            entities: all_storages
+                .entities()
+                .map_err(error::GetStorage::Entities)?,
+            _all_borrow: all_borrow,
+        })
+    }
+}
+
+impl<'a> TryFrom<&'a AllStorages> for MaskView<'a> {
+    type Error = error::GetStorage;
+    fn try_from(all_storages: &'a AllStorages) -> Result<Self, Self::Error> {
+        Ok(MaskView {
+            entities: all_storages
+                .entities()
+                .map_err(error::GetStorage::Entities)?,
+            _all_borrow: Borrow::None,
+        })
+    }
+}
+
+impl MaskView<'_> {
+    /// Returns `true` if `entity` has a `T` component.  
+    /// Returns `false` if `entity` isn't alive or the storage of `T` doesn't exist, it isn't created.
+    pub fn has<T: 'static>(&self, entity: EntityId) -> bool {
+        // the table is indexed by entity index, an old generation could match the entity reusing its index
+        self.entities.is_alive(entity)
+            && self
+                .entities
+                .signatures()
+                .contains(entity, StorageId::of::<T>())
+    }
+}
+
+/// Shared view over the [Tags] storage.
+///
+/// [Tags]: struct.Tags.html
+pub struct TagsView<'a> {
+    tags: Ref<'a, Tags>,
+    _all_borrow: Borrow<'a>,
+}
+
+impl<'a> TryFrom<Ref<'a, AllStorages>> for TagsView<'a> {
+    type Error = error::GetStorage;
+    fn try_from(all_storages: Ref<'a, AllStorages>) -> Result<Self, Self::Error> {
+        // SAFE all_storages and tags are dropped before all_borrow
+        let (all_storages, all_borrow) = unsafe { Ref::destructure(all_storages) };
+
+        Ok(TagsView {
+            tags: all_storages.tags()?,
+            _all_borrow: all_borrow,
+        })
+    }
+}
+
+impl<'a> TryFrom<&'a AllStorages> for TagsView<'a> {
+    type Error = error::GetStorage;
+    fn try_from(all_storages: &'a AllStorages) -> Result<Self, Self::Error> {
+        Ok(TagsView {
+            tags: all_storages.tags()?,
+            _all_borrow: Borrow::None,
+        })
+    }
+}
+
+impl Deref for TagsView<'_> {
+    type Target = Tags;
+    fn deref(&self) -> &Self::Target {
+        &self.tags
+    }
+}
+
+/// Exclusive view over the [Tags] storage.
+///
+/// [Tags]: struct.Tags.html
+pub struct TagsViewMut<'a> {
+    tags: RefMut<'a, Tags>,
+    _all_borrow: Borrow<'a>,
+}
+
+impl<'a> TryFrom<Ref<'a, AllStorages>> for TagsViewMut<'a> {
+    type Error = error::GetStorage;
+    fn try_from(all_storages: Ref<'a, AllStorages>) -> Result<Self, Self::Error> {
+        // SAFE all_storages and tags are dropped before all_borrow
+        let (all_storages, all_borrow) = unsafe { Ref::destructure(all_storages) };
+
+        Ok(TagsViewMut {
+            tags: all_storages.tags_mut()?,
+            _all_borrow: all_borrow,
+        })
+    }
+}
+
+impl<'a> TryFrom<&'a AllStorages> for TagsViewMut<'a> {
+    type Error = error::GetStorage;
+    fn try_from(all_storages: &'a AllStorages) -> Result<Self, Self::Error> {
+        Ok(TagsViewMut {
+            tags: all_storages.tags_mut()?,
+            _all_borrow: Borrow::None,
+        })
+    }
+}
+
+impl Deref for TagsViewMut<'_> {
+    type Target = Tags;
+    fn deref(&self) -> &Self::Target {
+        &self.tags
+    }
+}
+
+impl DerefMut for TagsViewMut<'_> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.tags
+    }
+}
+
 #[cfg(feature = "parallel")]
 #[cfg_attr(docsrs, doc(cfg(feature = "parallel")))]
+#[deprecated(note = "Use Tasks, ThreadPool::install inside nested parallel iteration can deadlock")]
 /// Shared view over the thread_pool.
 pub struct ThreadPoolView<'a>(pub(crate) &'a rayon::ThreadPool);
 
 #[cfg(feature = "parallel")]
+#[allow(deprecated)]
 impl AsRef<rayon::ThreadPool> for ThreadPoolView<'_> {
     fn as_ref(&self) -> &rayon::ThreadPool {
         &self.0
@@ -485,9 +982,69 @@ impl AsRef<rayon::ThreadPool> for ThreadPoolView<'_> {
 }
 
 #[cfg(feature = "parallel")]
+#[allow(deprecated)]
 impl Deref for ThreadPoolView<'_> {
     type Target = rayon::ThreadPool;
     fn deref(&self) -> &Self::Target {
         &self.0
     }
 }
+
+/// Spawns sub-jobs on the `World`'s thread pool from a system.  
+/// Jobs can borrow from the system, they all finish before [Tasks::scope] or [Tasks::join] returns.
+///
+/// Unlike [ThreadPoolView], the system keeps running on its thread while the jobs are spread over the pool.
+///
+/// ### Example
+/// ```
+/// use shipyard::{IntoIter, Tasks, View, World};
+///
+/// let world = World::new();
+///
+/// world.run(|u32s: View<u32>, u64s: View<u64>, tasks: Tasks| {
+///     let (small, big) = tasks.join(
+///         || u32s.iter().into_iter().sum::<u32>(),
+///         || u64s.iter().into_iter().sum::<u64>(),
+///     );
+///
+///     let mut totals = [0; 4];
+///     tasks.scope(|scope| {
+///         for (i, total) in totals.iter_mut().enumerate() {
+///             scope.spawn(move |_| *total = i as u64 * (small as u64 + big));
+///         }
+///     });
+/// });
+/// ```
+///
+/// [Tasks::scope]: struct.Tasks.html#method.scope
+/// [Tasks::join]: struct.Tasks.html#method.join
+/// [ThreadPoolView]: struct.ThreadPoolView.html
+#[cfg(feature = "parallel")]
+#[cfg_attr(docsrs, doc(cfg(feature = "parallel")))]
+pub struct Tasks<'a>(pub(crate) &'a rayon::ThreadPool);
+
+#[cfg(feature = "parallel")]
+impl Tasks<'_> {
+    /// Creates a scope in which jobs can be spawned on the `World`'s thread pool.  
+    /// `op` runs on the current thread, `scope` returns once all spawned jobs are done.
+    pub fn scope<'scope, R>(&self, op: impl FnOnce(&rayon::Scope<'scope>) -> R) -> R {
+        self.0.in_place_scope(op)
+    }
+    /// Runs `a` and `b` potentially in parallel on the `World`'s thread pool and returns both results.
+    pub fn join<A, B, RA, RB>(&self, a: A, b: B) -> (RA, RB)
+    where
+        A: FnOnce() -> RA + Send,
+        B: FnOnce() -> RB + Send,
+        RA: Send,
+        RB: Send,
+    {
+        self.0.join(a, b)
+    }
+}
+
+#[cfg(feature = "parallel")]
+impl AsRef<rayon::ThreadPool> for Tasks<'_> {
+    fn as_ref(&self) -> &rayon::ThreadPool {
+        self.0
+    }
+}
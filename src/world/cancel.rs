@@ -0,0 +1,58 @@
+use alloc::sync::Arc;
+use core::sync::atomic::{AtomicBool, Ordering};
+
+/// Shared flag aborting a workload run with [World::run_workload_cancellable].
+/// Clones share the same flag, one can be kept by the host application and another given to the systems
+/// as a unique storage so long systems can stop early.
+///
+/// ### Example
+/// ```
+/// use shipyard::{system, CancelToken, UniqueView, UniqueViewMut, World};
+///
+/// fn long(token: UniqueView<CancelToken>) {
+///     // the host is shutting down
+///     token.cancel();
+/// }
+///
+/// fn never(_: UniqueViewMut<CancelToken>) {
+///     unreachable!();
+/// }
+///
+/// let world = World::new();
+/// let token = CancelToken::new();
+/// world.add_unique(token.clone());
+///
+/// world
+///     .add_workload("Frame")
+///     .with_system(system!(long))
+///     .with_system(system!(never))
+///     .build();
+///
+/// assert!(!world.run_workload_cancellable("Frame", &token));
+/// assert!(token.is_cancelled());
+/// ```
+///
+/// [World::run_workload_cancellable]: struct.World.html#method.run_workload_cancellable
+#[derive(Clone, Debug, Default)]
+pub struct CancelToken(Arc<AtomicBool>);
+
+impl CancelToken {
+    /// Creates a token that isn't cancelled.
+    pub fn new() -> Self {
+        CancelToken::default()
+    }
+    /// Asks the workloads run with this token to stop before their next batch.
+    pub fn cancel(&self) {
+        self.0.store(true, Ordering::Release);
+    }
+    /// Returns `true` if [cancel] was called since the token was created or reset.
+    ///
+    /// [cancel]: struct.CancelToken.html#method.cancel
+    pub fn is_cancelled(&self) -> bool {
+        self.0.load(Ordering::Acquire)
+    }
+    /// Clears the cancellation, workloads run with this token will complete again.
+    pub fn reset(&self) {
+        self.0.store(false, Ordering::Release);
+    }
+}
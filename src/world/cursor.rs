@@ -0,0 +1,46 @@
+use alloc::borrow::Cow;
+#[cfg(feature = "stats")]
+use core::time::Duration;
+
+/// Position in a workload run in several steps with [World::resume_workload].
+/// The cursor doesn't borrow the `World`, it can be stored in a callback and resumed on the next frame.
+///
+/// [World::resume_workload]: struct.World.html#method.resume_workload
+#[derive(Clone, Debug)]
+pub struct WorkloadCursor {
+    pub(super) name: Cow<'static, str>,
+    // index of the next batch, relative to the start of the workload
+    pub(super) batch: usize,
+    // time spent running the workload so far
+    #[cfg(feature = "stats")]
+    pub(super) elapsed: Duration,
+}
+
+impl WorkloadCursor {
+    /// Returns a cursor at the start of the `name` workload.
+    pub fn new(name: impl Into<Cow<'static, str>>) -> Self {
+        WorkloadCursor {
+            name: name.into(),
+            batch: 0,
+            #[cfg(feature = "stats")]
+            elapsed: Duration::default(),
+        }
+    }
+    /// Returns the name of the workload.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+    /// Returns `true` if the workload has started and has batches left to run.
+    pub fn is_suspended(&self) -> bool {
+        self.batch != 0
+    }
+    /// Moves the cursor back to the start of the workload, the remaining batches are skipped.
+    pub fn reset(&mut self) {
+        self.batch = 0;
+
+        #[cfg(feature = "stats")]
+        {
+            self.elapsed = Duration::default();
+        }
+    }
+}
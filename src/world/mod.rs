@@ -1,72 +1,489 @@
+mod cancel;
+mod cursor;
+#[cfg(feature = "std")]
+mod report;
+#[cfg(feature = "async")]
+mod run_async;
 mod scheduler;
+mod world_id;
 
-pub use scheduler::WorkloadBuilder;
+pub use cancel::CancelToken;
+pub use cursor::WorkloadCursor;
+#[cfg(feature = "std")]
+pub use report::{SystemTiming, WorkloadReport};
+#[cfg(feature = "async")]
+pub use run_async::RunWorkloadAsync;
+pub use scheduler::{SystemInfo, WorkloadBuilder, WorkloadDiagnostic, WorkloadSystem};
+pub use world_id::{GlobalEntityId, WorldId};
 
-use crate::atomic_refcell::AtomicRefCell;
 #[cfg(feature = "serde1")]
 use crate::atomic_refcell::RefMut;
+use crate::atomic_refcell::{AtomicRefCell, Ref};
 use crate::borrow::Borrow;
 use crate::entity_builder::EntityBuilder;
 use crate::error;
+use crate::event_log::StructuralEvent;
+use crate::frame_alloc::FrameAlloc;
+use crate::pack::TightPack;
 #[cfg(feature = "serde1")]
-use crate::serde_setup::{ExistingEntities, GlobalDeConfig, GlobalSerConfig, WithShared};
-use crate::storage::AllStorages;
+use crate::recording::Recording;
+#[cfg(feature = "savegame")]
+use crate::serde_setup::SerFormat;
 #[cfg(feature = "serde1")]
-use crate::storage::{Storage, StorageId};
+use crate::serde_setup::{ExistingEntities, GlobalDeConfig, GlobalSerConfig};
+use crate::snapshot::Snapshots;
+use crate::sparse_set::Tracking;
+use crate::spawn::Spawn;
+#[cfg(feature = "stats")]
+use crate::stats::{StorageStats, WorldStats};
+use crate::storage::{AllStorages, EntityId, RegisterStorages, Signature, StorageId, WeakEntity};
+#[cfg(feature = "serde1")]
+use crate::storage::{Entities, Storage};
+use crate::type_id::TypeId;
+#[cfg(feature = "serde1")]
+use crate::unknown_storage::StorageMetadata;
 use alloc::borrow::Cow;
-use core::ops::Range;
+use alloc::string::String;
+#[cfg(feature = "parallel")]
+use alloc::sync::Arc;
+use alloc::vec::Vec;
+#[cfg(feature = "std")]
+use core::time::Duration;
+#[cfg(feature = "serde1")]
+use hashbrown::hash_map::Entry;
+use hashbrown::HashMap;
 #[cfg(feature = "parallel")]
 use rayon::{ThreadPool, ThreadPoolBuilder};
 use scheduler::Scheduler;
+#[cfg(any(feature = "trace", feature = "savegame"))]
+use std::fs::File;
+#[cfg(any(feature = "trace", feature = "savegame"))]
+use std::io::BufWriter;
+#[cfg(feature = "savegame")]
+use std::io::{BufRead, BufReader, Write};
+#[cfg(any(feature = "trace", feature = "savegame"))]
+use std::path::Path;
 
 /// Holds all components and keeps track of entities and what they own.
 pub struct World {
+    id: WorldId,
     pub(crate) all_storages: AtomicRefCell<AllStorages>,
+    // shared with forks
     #[cfg(feature = "parallel")]
-    pub(crate) thread_pool: ThreadPool,
+    pub(crate) thread_pool: Arc<ThreadPool>,
     scheduler: AtomicRefCell<Scheduler>,
+    snapshots: AtomicRefCell<Snapshots>,
 }
 
 impl Default for World {
     /// Create an empty `World`.
+    ///
+    /// ### Panics
+    ///
+    /// - Creating the thread pool failed, [try_new] returns an error instead.
+    ///
+    /// [try_new]: struct.World.html#method.try_new
     fn default() -> Self {
+        World::with_capacity(0)
+    }
+}
+
+impl World {
+    /// Create an empty `World`.
+    ///
+    /// ### Panics
+    ///
+    /// - Creating the thread pool failed, [try_new] returns an error instead.
+    ///
+    /// [try_new]: struct.World.html#method.try_new
+    pub fn new() -> Self {
+        Default::default()
+    }
+    /// Create an empty `World`.  
+    /// Unlike [new] it doesn't panic when the thread pool can't be created.
+    ///
+    /// ### Errors
+    ///
+    /// - Creating the thread pool failed.
+    ///
+    /// [new]: struct.World.html#method.new
+    pub fn try_new() -> Result<Self, error::NewWorld> {
+        World::try_with_capacity(0)
+    }
+    /// Creates an empty `World` able to hold `entity_capacity` entities without reallocating.  
+    /// Storages can be given their own capacity with [register_storage_with_capacity].
+    ///
+    /// ### Panics
+    ///
+    /// - Creating the thread pool failed, [try_with_capacity] returns an error instead.
+    ///
+    /// [register_storage_with_capacity]: struct.World.html#method.register_storage_with_capacity
+    /// [try_with_capacity]: struct.World.html#method.try_with_capacity
+    #[track_caller]
+    pub fn with_capacity(entity_capacity: usize) -> Self {
+        World::try_with_capacity(entity_capacity).unwrap()
+    }
+    /// Creates an empty `World` able to hold `entity_capacity` entities without reallocating.  
+    /// Storages can be given their own capacity with [register_storage_with_capacity].
+    ///
+    /// ### Errors
+    ///
+    /// - Creating the thread pool failed.
+    ///
+    /// [register_storage_with_capacity]: struct.World.html#method.register_storage_with_capacity
+    pub fn try_with_capacity(entity_capacity: usize) -> Result<Self, error::NewWorld> {
+        World::try_from_all_storages(AllStorages::with_capacity(entity_capacity))
+    }
+    /// Creates an empty `World` that can't hold more than `entity_capacity` entities.  
+    /// Entities and storages are allocated once, adding entities and components won't allocate.  
+    /// Each storage can hold a component for every entity, [register_storage_with_capacity] allocates less memory for storages that don't need it.  
+    /// Worlds made from this one with [deep_clone] or [fork] don't have a fixed capacity.
+    ///
+    /// Update packs, sorting, snapshots and serialization still allocate.
+    ///
+    /// ### Panics
+    ///
+    /// - Adding an entity when there are already `entity_capacity` entities.
+    /// - Adding a component to a full storage.
+    /// - Creating the thread pool failed, [try_with_fixed_capacity] returns an error instead.
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{View, ViewMut, World};
+    ///
+    /// let world = World::with_fixed_capacity(100);
+    /// world.register_storage_with_capacity::<u32>(10);
+    /// world.set_strict(true);
+    ///
+    /// let entity = world.spawn((0u32,));
+    /// assert_eq!(world.borrow::<View<u32>>()[entity], 0);
+    /// assert_eq!(world.borrow::<ViewMut<u32>>().fixed_capacity(), Some(10));
+    /// ```
+    ///
+    /// [deep_clone]: struct.World.html#method.deep_clone
+    /// [fork]: struct.World.html#method.fork
+    /// [register_storage_with_capacity]: struct.World.html#method.register_storage_with_capacity
+    /// [try_with_fixed_capacity]: struct.World.html#method.try_with_fixed_capacity
+    #[track_caller]
+    pub fn with_fixed_capacity(entity_capacity: usize) -> Self {
+        World::try_with_fixed_capacity(entity_capacity).unwrap()
+    }
+    /// Creates an empty `World` that can't hold more than `entity_capacity` entities.  
+    /// See [with_fixed_capacity] for what isn't allocated up front.
+    ///
+    /// ### Errors
+    ///
+    /// - Creating the thread pool failed.
+    ///
+    /// ### Panics
+    ///
+    /// - Adding an entity when there are already `entity_capacity` entities.
+    /// - Adding a component to a full storage.
+    ///
+    /// [with_fixed_capacity]: struct.World.html#method.with_fixed_capacity
+    pub fn try_with_fixed_capacity(entity_capacity: usize) -> Result<Self, error::NewWorld> {
+        World::try_from_all_storages(AllStorages::with_fixed_capacity(entity_capacity))
+    }
+    fn try_from_all_storages(all_storages: AllStorages) -> Result<Self, error::NewWorld> {
+        #[cfg(feature = "parallel")]
+        let thread_pool = Arc::new(
+            ThreadPoolBuilder::new()
+                .build()
+                .map_err(error::NewWorld::ThreadPool)?,
+        );
+
         #[cfg(feature = "std")]
         {
-            World {
-                all_storages: AtomicRefCell::new(AllStorages::new(), None, true),
+            Ok(World {
+                id: WorldId::new(),
+                all_storages: AtomicRefCell::new(all_storages, None, true),
                 #[cfg(feature = "parallel")]
-                thread_pool: ThreadPoolBuilder::new().build().unwrap(),
+                thread_pool,
                 scheduler: AtomicRefCell::new(Default::default(), None, true),
-            }
+                snapshots: AtomicRefCell::new(Default::default(), None, true),
+            })
         }
         #[cfg(not(feature = "std"))]
         {
-            World {
-                all_storages: AtomicRefCell::new(AllStorages::new()),
+            Ok(World {
+                id: WorldId::new(),
+                all_storages: AtomicRefCell::new(all_storages),
                 #[cfg(feature = "parallel")]
-                thread_pool: ThreadPoolBuilder::new().build().unwrap(),
+                thread_pool,
                 scheduler: AtomicRefCell::new(Default::default()),
-            }
+                snapshots: AtomicRefCell::new(Default::default()),
+            })
         }
     }
-}
-
-impl World {
-    /// Create an empty `World`.
-    pub fn new() -> Self {
-        Default::default()
-    }
     /// Returns a new `World` with custom threads.  
     /// Custom threads can be useful when working with wasm for example.
     #[cfg(feature = "parallel")]
     #[cfg_attr(docsrs, doc(cfg(feature = "parallel")))]
     pub fn new_with_custom_thread_pool(thread_pool: ThreadPool) -> Self {
         World {
+            id: WorldId::new(),
             all_storages: AtomicRefCell::new(AllStorages::new(), None, true),
-            thread_pool,
+            thread_pool: Arc::new(thread_pool),
             scheduler: AtomicRefCell::new(Default::default(), None, true),
+            snapshots: AtomicRefCell::new(Default::default(), None, true),
+        }
+    }
+    /// Returns the [WorldId] of this [World].
+    ///
+    /// [World]: struct.World.html
+    /// [WorldId]: struct.WorldId.html
+    pub fn id(&self) -> WorldId {
+        self.id
+    }
+    /// Returns a handle to `entity` that remembers it belongs to this [World].
+    ///
+    /// [World]: struct.World.html
+    pub fn global_id(&self, entity: EntityId) -> GlobalEntityId {
+        GlobalEntityId {
+            world: self.id,
+            entity,
+        }
+    }
+    /// Returns the `EntityId` of a [GlobalEntityId] if it belongs to this [World] and is alive.
+    ///
+    /// ### Borrows
+    ///
+    /// - [AllStorages] (shared)
+    /// - [Entities] (shared)
+    ///
+    /// ### Errors
+    ///
+    /// - The entity belongs to another [World].
+    /// - [AllStorages] borrow failed.
+    /// - [Entities] borrow failed.
+    /// - The entity is not alive.
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{error, World};
+    ///
+    /// let server = World::new();
+    /// let ui = World::new();
+    ///
+    /// let entity = server.spawn(());
+    /// let handle = server.global_id(entity);
+    ///
+    /// assert_eq!(server.try_resolve(handle), Ok(entity));
+    /// assert_eq!(ui.try_resolve(handle), Err(error::Resolve::WrongWorld));
+    /// ```
+    ///
+    /// [AllStorages]: struct.AllStorages.html
+    /// [Entities]: struct.Entities.html
+    /// [GlobalEntityId]: struct.GlobalEntityId.html
+    /// [World]: struct.World.html
+    pub fn try_resolve(&self, global_id: GlobalEntityId) -> Result<EntityId, error::Resolve> {
+        if global_id.world != self.id {
+            return Err(error::Resolve::WrongWorld);
+        }
+
+        let is_alive = self
+            .all_storages
+            .try_borrow()
+            .map_err(error::Resolve::AllStoragesBorrow)?
+            .entities()
+            .map_err(error::Resolve::Entities)?
+            .is_alive(global_id.entity);
+
+        if is_alive {
+            Ok(global_id.entity)
+        } else {
+            Err(error::Resolve::DeadEntity)
+        }
+    }
+    /// Returns the `EntityId` of a [GlobalEntityId] if it belongs to this [World] and is alive.  
+    /// Unwraps borrow errors.
+    ///
+    /// ### Borrows
+    ///
+    /// - [AllStorages] (shared)
+    /// - [Entities] (shared)
+    ///
+    /// ### Errors
+    ///
+    /// - [AllStorages] borrow failed.
+    /// - [Entities] borrow failed.
+    ///
+    /// [AllStorages]: struct.AllStorages.html
+    /// [Entities]: struct.Entities.html
+    /// [GlobalEntityId]: struct.GlobalEntityId.html
+    /// [World]: struct.World.html
+    #[cfg(feature = "panic")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "panic")))]
+    #[track_caller]
+    pub fn resolve(&self, global_id: GlobalEntityId) -> Option<EntityId> {
+        match self.try_resolve(global_id) {
+            Ok(entity) => Some(entity),
+            Err(error::Resolve::WrongWorld) | Err(error::Resolve::DeadEntity) => None,
+            Err(err) => panic!("{:?}", err),
         }
     }
+    /// Returns a [WeakEntity] to `entity`.  
+    /// Once `entity` is deleted the handle will be returned by [try_drain_invalidated].
+    ///
+    /// ### Borrows
+    ///
+    /// - [AllStorages] (shared)
+    /// - [Entities] (exclusive)
+    ///
+    /// ### Errors
+    ///
+    /// - [AllStorages] borrow failed.
+    /// - [Entities] borrow failed.
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{AllStoragesViewMut, World};
+    ///
+    /// let world = World::new();
+    ///
+    /// let entity = world.spawn(());
+    /// let weak = world.try_downgrade(entity).unwrap();
+    ///
+    /// assert_eq!(world.try_upgrade(weak), Ok(Some(entity)));
+    ///
+    /// world.borrow::<AllStoragesViewMut>().delete(entity);
+    ///
+    /// assert_eq!(world.try_upgrade(weak), Ok(None));
+    /// assert_eq!(world.try_drain_invalidated(), Ok(vec![weak]));
+    /// ```
+    ///
+    /// [AllStorages]: struct.AllStorages.html
+    /// [Entities]: struct.Entities.html
+    /// [try_drain_invalidated]: struct.World.html#method.try_drain_invalidated
+    /// [WeakEntity]: struct.WeakEntity.html
+    pub fn try_downgrade(&self, entity: EntityId) -> Result<WeakEntity, error::GetStorage> {
+        Ok(self
+            .all_storages
+            .try_borrow()
+            .map_err(error::GetStorage::AllStoragesBorrow)?
+            .entities_mut()
+            .map_err(error::GetStorage::Entities)?
+            .downgrade(entity))
+    }
+    /// Returns a [WeakEntity] to `entity`.  
+    /// Once `entity` is deleted the handle will be returned by [drain_invalidated].  
+    /// Unwraps errors.
+    ///
+    /// ### Borrows
+    ///
+    /// - [AllStorages] (shared)
+    /// - [Entities] (exclusive)
+    ///
+    /// ### Errors
+    ///
+    /// - [AllStorages] borrow failed.
+    /// - [Entities] borrow failed.
+    ///
+    /// [AllStorages]: struct.AllStorages.html
+    /// [drain_invalidated]: struct.World.html#method.drain_invalidated
+    /// [Entities]: struct.Entities.html
+    /// [WeakEntity]: struct.WeakEntity.html
+    #[cfg(feature = "panic")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "panic")))]
+    #[track_caller]
+    pub fn downgrade(&self, entity: EntityId) -> WeakEntity {
+        self.try_downgrade(entity).unwrap()
+    }
+    /// Returns the `EntityId` of `weak` if its entity is still alive.  
+    /// An entity reusing the same index with a newer generation doesn't match.
+    ///
+    /// ### Borrows
+    ///
+    /// - [AllStorages] (shared)
+    /// - [Entities] (shared)
+    ///
+    /// ### Errors
+    ///
+    /// - [AllStorages] borrow failed.
+    /// - [Entities] borrow failed.
+    ///
+    /// [AllStorages]: struct.AllStorages.html
+    /// [Entities]: struct.Entities.html
+    pub fn try_upgrade(&self, weak: WeakEntity) -> Result<Option<EntityId>, error::GetStorage> {
+        Ok(self
+            .all_storages
+            .try_borrow()
+            .map_err(error::GetStorage::AllStoragesBorrow)?
+            .entities()
+            .map_err(error::GetStorage::Entities)?
+            .upgrade(weak))
+    }
+    /// Returns the `EntityId` of `weak` if its entity is still alive.  
+    /// An entity reusing the same index with a newer generation doesn't match.  
+    /// Unwraps errors.
+    ///
+    /// ### Borrows
+    ///
+    /// - [AllStorages] (shared)
+    /// - [Entities] (shared)
+    ///
+    /// ### Errors
+    ///
+    /// - [AllStorages] borrow failed.
+    /// - [Entities] borrow failed.
+    ///
+    /// [AllStorages]: struct.AllStorages.html
+    /// [Entities]: struct.Entities.html
+    #[cfg(feature = "panic")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "panic")))]
+    #[track_caller]
+    pub fn upgrade(&self, weak: WeakEntity) -> Option<EntityId> {
+        self.try_upgrade(weak).unwrap()
+    }
+    /// Returns all [WeakEntity] whose entity was deleted since the last call.  
+    /// Meant to be called once per frame.
+    ///
+    /// ### Borrows
+    ///
+    /// - [AllStorages] (shared)
+    /// - [Entities] (exclusive)
+    ///
+    /// ### Errors
+    ///
+    /// - [AllStorages] borrow failed.
+    /// - [Entities] borrow failed.
+    ///
+    /// [AllStorages]: struct.AllStorages.html
+    /// [Entities]: struct.Entities.html
+    /// [WeakEntity]: struct.WeakEntity.html
+    pub fn try_drain_invalidated(&self) -> Result<Vec<WeakEntity>, error::GetStorage> {
+        Ok(self
+            .all_storages
+            .try_borrow()
+            .map_err(error::GetStorage::AllStoragesBorrow)?
+            .entities_mut()
+            .map_err(error::GetStorage::Entities)?
+            .drain_invalidated()
+            .collect())
+    }
+    /// Returns all [WeakEntity] whose entity was deleted since the last call.  
+    /// Meant to be called once per frame.  
+    /// Unwraps errors.
+    ///
+    /// ### Borrows
+    ///
+    /// - [AllStorages] (shared)
+    /// - [Entities] (exclusive)
+    ///
+    /// ### Errors
+    ///
+    /// - [AllStorages] borrow failed.
+    /// - [Entities] borrow failed.
+    ///
+    /// [AllStorages]: struct.AllStorages.html
+    /// [Entities]: struct.Entities.html
+    /// [WeakEntity]: struct.WeakEntity.html
+    #[cfg(feature = "panic")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "panic")))]
+    #[track_caller]
+    pub fn drain_invalidated(&self) -> Vec<WeakEntity> {
+        self.try_drain_invalidated().unwrap()
+    }
     /// Adds a new unique storage, unique storages store exactly one `T`.  
     /// To access a unique storage value, use [UniqueView] or [UniqueViewMut].  
     /// Does nothing if the storage already exists.  
@@ -85,6 +502,7 @@ impl World {
     /// [UniqueViewMut]: struct.UniqueViewMut.html
     #[cfg(feature = "panic")]
     #[cfg_attr(docsrs, doc(cfg(feature = "panic")))]
+    #[track_caller]
     pub fn add_unique<T: 'static + Send + Sync>(&self, component: T) {
         self.try_add_unique(component).unwrap();
     }
@@ -111,7 +529,7 @@ impl World {
         Ok(())
     }
     /// Adds a new unique storage, unique storages store exactly one `T`.  
-    /// To access a unique storage value, use [NonSend] and [UniqueViewMut] or [UniqueViewMut].  
+    /// Unlike [World::add_unique], the storage is copied by [World::deep_clone].  
     /// Does nothing if the storage already exists.
     ///
     /// ### Borrows
@@ -123,22 +541,19 @@ impl World {
     /// - [AllStorages] borrow failed.
     ///
     /// [AllStorages]: struct.AllStorages.html
-    /// [NonSend]: struct.NonSend.html
-    /// [UniqueView]: struct.UniqueView.html
-    /// [UniqueViewMut]: struct.UniqueViewMut.html
-    #[cfg(feature = "non_send")]
-    #[cfg_attr(docsrs, doc(cfg(feature = "non_send")))]
-    pub fn try_add_unique_non_send<T: 'static + Sync>(
+    /// [World::add_unique]: struct.World.html#method.add_unique
+    /// [World::deep_clone]: struct.World.html#method.deep_clone
+    pub fn try_add_unique_cloneable<T: 'static + Clone + Send + Sync>(
         &self,
         component: T,
     ) -> Result<(), error::Borrow> {
         self.all_storages
             .try_borrow()?
-            .add_unique_non_send(component);
+            .add_unique_cloneable(component);
         Ok(())
     }
     /// Adds a new unique storage, unique storages store exactly one `T`.  
-    /// To access a unique storage value, use [NonSend] and [UniqueViewMut] or [UniqueViewMut].  
+    /// Unlike [World::add_unique], the storage is copied by [World::deep_clone].  
     /// Does nothing if the storage already exists.  
     /// Unwraps errors.
     ///
@@ -151,16 +566,16 @@ impl World {
     /// - [AllStorages] borrow failed.
     ///
     /// [AllStorages]: struct.AllStorages.html
-    /// [NonSend]: struct.NonSend.html
-    /// [UniqueView]: struct.UniqueView.html
-    /// [UniqueViewMut]: struct.UniqueViewMut.html
-    #[cfg(all(feature = "non_send", feature = "panic"))]
-    #[cfg_attr(docsrs, doc(cfg(all(feature = "non_send", feature = "panic"))))]
-    pub fn add_unique_non_send<T: 'static + Sync>(&self, component: T) {
-        self.try_add_unique_non_send::<T>(component).unwrap()
+    /// [World::add_unique]: struct.World.html#method.add_unique
+    /// [World::deep_clone]: struct.World.html#method.deep_clone
+    #[cfg(feature = "panic")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "panic")))]
+    #[track_caller]
+    pub fn add_unique_cloneable<T: 'static + Clone + Send + Sync>(&self, component: T) {
+        self.try_add_unique_cloneable(component).unwrap();
     }
     /// Adds a new unique storage, unique storages store exactly one `T`.  
-    /// To access a unique storage value, use [NonSync] and [UniqueViewMut] or [UniqueViewMut].  
+    /// Unlike [World::add_unique], the storage is included in [World::serialize].  
     /// Does nothing if the storage already exists.
     ///
     /// ### Borrows
@@ -172,22 +587,23 @@ impl World {
     /// - [AllStorages] borrow failed.
     ///
     /// [AllStorages]: struct.AllStorages.html
-    /// [NonSync]: struct.NonSync.html
-    /// [UniqueView]: struct.UniqueView.html
-    /// [UniqueViewMut]: struct.UniqueViewMut.html
-    #[cfg(feature = "non_sync")]
-    #[cfg_attr(docsrs, doc(cfg(feature = "non_sync")))]
-    pub fn try_add_unique_non_sync<T: 'static + Send>(
+    /// [World::add_unique]: struct.World.html#method.add_unique
+    /// [World::serialize]: struct.World.html#method.serialize
+    #[cfg(feature = "serde1")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "serde1")))]
+    pub fn try_add_unique_serializable<
+        T: 'static + serde::Serialize + for<'de> serde::Deserialize<'de> + Send + Sync,
+    >(
         &self,
         component: T,
     ) -> Result<(), error::Borrow> {
         self.all_storages
             .try_borrow()?
-            .add_unique_non_sync(component);
+            .add_unique_serializable(component);
         Ok(())
     }
     /// Adds a new unique storage, unique storages store exactly one `T`.  
-    /// To access a unique storage value, use [NonSync] and [UniqueViewMut] or [UniqueViewMut].  
+    /// Unlike [World::add_unique], the storage is included in [World::serialize].  
     /// Does nothing if the storage already exists.  
     /// Unwraps errors.
     ///
@@ -200,16 +616,21 @@ impl World {
     /// - [AllStorages] borrow failed.
     ///
     /// [AllStorages]: struct.AllStorages.html
-    /// [NonSync]: struct.NonSync.html
-    /// [UniqueView]: struct.UniqueView.html
-    /// [UniqueViewMut]: struct.UniqueViewMut.html
-    #[cfg(all(feature = "non_sync", feature = "panic"))]
-    #[cfg_attr(docsrs, doc(cfg(all(feature = "non_sync", feature = "panic"))))]
-    pub fn add_unique_non_sync<T: 'static + Send>(&self, component: T) {
-        self.try_add_unique_non_sync::<T>(component).unwrap()
+    /// [World::add_unique]: struct.World.html#method.add_unique
+    /// [World::serialize]: struct.World.html#method.serialize
+    #[cfg(all(feature = "serde1", feature = "panic"))]
+    #[cfg_attr(docsrs, doc(cfg(all(feature = "serde1", feature = "panic"))))]
+    #[track_caller]
+    pub fn add_unique_serializable<
+        T: 'static + serde::Serialize + for<'de> serde::Deserialize<'de> + Send + Sync,
+    >(
+        &self,
+        component: T,
+    ) {
+        self.try_add_unique_serializable(component).unwrap();
     }
     /// Adds a new unique storage, unique storages store exactly one `T`.  
-    /// To access a unique storage value, use [NonSendSync] and [UniqueViewMut] or [UniqueViewMut].  
+    /// To access a unique storage value, use [NonSend] and [UniqueViewMut] or [UniqueViewMut].  
     /// Does nothing if the storage already exists.
     ///
     /// ### Borrows
@@ -221,22 +642,22 @@ impl World {
     /// - [AllStorages] borrow failed.
     ///
     /// [AllStorages]: struct.AllStorages.html
-    /// [NonSendSync]: struct.NonSendSync.html
+    /// [NonSend]: struct.NonSend.html
     /// [UniqueView]: struct.UniqueView.html
     /// [UniqueViewMut]: struct.UniqueViewMut.html
-    #[cfg(all(feature = "non_send", feature = "non_sync"))]
-    #[cfg_attr(docsrs, doc(cfg(all(feature = "non_send", feature = "non_sync"))))]
-    pub fn try_add_unique_non_send_sync<T: 'static>(
+    #[cfg(feature = "non_send")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "non_send")))]
+    pub fn try_add_unique_non_send<T: 'static + Sync>(
         &self,
         component: T,
     ) -> Result<(), error::Borrow> {
         self.all_storages
             .try_borrow()?
-            .add_unique_non_send_sync(component);
+            .add_unique_non_send(component);
         Ok(())
     }
     /// Adds a new unique storage, unique storages store exactly one `T`.  
-    /// To access a unique storage value, use [NonSendSync] and [UniqueViewMut] or [UniqueViewMut].  
+    /// To access a unique storage value, use [NonSend] and [UniqueViewMut] or [UniqueViewMut].  
     /// Does nothing if the storage already exists.  
     /// Unwraps errors.
     ///
@@ -249,13 +670,113 @@ impl World {
     /// - [AllStorages] borrow failed.
     ///
     /// [AllStorages]: struct.AllStorages.html
-    /// [NonSendSync]: struct.NonSendSync.html
+    /// [NonSend]: struct.NonSend.html
     /// [UniqueView]: struct.UniqueView.html
     /// [UniqueViewMut]: struct.UniqueViewMut.html
-    #[cfg(all(feature = "non_send", feature = "non_sync", feature = "panic"))]
-    #[cfg_attr(
-        docsrs,
-        doc(cfg(all(feature = "non_send", feature = "non_sync", feature = "panic")))
+    #[cfg(all(feature = "non_send", feature = "panic"))]
+    #[cfg_attr(docsrs, doc(cfg(all(feature = "non_send", feature = "panic"))))]
+    #[track_caller]
+    pub fn add_unique_non_send<T: 'static + Sync>(&self, component: T) {
+        self.try_add_unique_non_send::<T>(component).unwrap()
+    }
+    /// Adds a new unique storage, unique storages store exactly one `T`.  
+    /// To access a unique storage value, use [NonSync] and [UniqueViewMut] or [UniqueViewMut].  
+    /// Does nothing if the storage already exists.
+    ///
+    /// ### Borrows
+    ///
+    /// - [AllStorages] (shared)
+    ///
+    /// ### Errors
+    ///
+    /// - [AllStorages] borrow failed.
+    ///
+    /// [AllStorages]: struct.AllStorages.html
+    /// [NonSync]: struct.NonSync.html
+    /// [UniqueView]: struct.UniqueView.html
+    /// [UniqueViewMut]: struct.UniqueViewMut.html
+    #[cfg(feature = "non_sync")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "non_sync")))]
+    pub fn try_add_unique_non_sync<T: 'static + Send>(
+        &self,
+        component: T,
+    ) -> Result<(), error::Borrow> {
+        self.all_storages
+            .try_borrow()?
+            .add_unique_non_sync(component);
+        Ok(())
+    }
+    /// Adds a new unique storage, unique storages store exactly one `T`.  
+    /// To access a unique storage value, use [NonSync] and [UniqueViewMut] or [UniqueViewMut].  
+    /// Does nothing if the storage already exists.  
+    /// Unwraps errors.
+    ///
+    /// ### Borrows
+    ///
+    /// - [AllStorages] (shared)
+    ///
+    /// ### Errors
+    ///
+    /// - [AllStorages] borrow failed.
+    ///
+    /// [AllStorages]: struct.AllStorages.html
+    /// [NonSync]: struct.NonSync.html
+    /// [UniqueView]: struct.UniqueView.html
+    /// [UniqueViewMut]: struct.UniqueViewMut.html
+    #[cfg(all(feature = "non_sync", feature = "panic"))]
+    #[cfg_attr(docsrs, doc(cfg(all(feature = "non_sync", feature = "panic"))))]
+    #[track_caller]
+    pub fn add_unique_non_sync<T: 'static + Send>(&self, component: T) {
+        self.try_add_unique_non_sync::<T>(component).unwrap()
+    }
+    /// Adds a new unique storage, unique storages store exactly one `T`.  
+    /// To access a unique storage value, use [NonSendSync] and [UniqueViewMut] or [UniqueViewMut].  
+    /// Does nothing if the storage already exists.
+    ///
+    /// ### Borrows
+    ///
+    /// - [AllStorages] (shared)
+    ///
+    /// ### Errors
+    ///
+    /// - [AllStorages] borrow failed.
+    ///
+    /// [AllStorages]: struct.AllStorages.html
+    /// [NonSendSync]: struct.NonSendSync.html
+    /// [UniqueView]: struct.UniqueView.html
+    /// [UniqueViewMut]: struct.UniqueViewMut.html
+    #[cfg(all(feature = "non_send", feature = "non_sync"))]
+    #[cfg_attr(docsrs, doc(cfg(all(feature = "non_send", feature = "non_sync"))))]
+    pub fn try_add_unique_non_send_sync<T: 'static>(
+        &self,
+        component: T,
+    ) -> Result<(), error::Borrow> {
+        self.all_storages
+            .try_borrow()?
+            .add_unique_non_send_sync(component);
+        Ok(())
+    }
+    /// Adds a new unique storage, unique storages store exactly one `T`.  
+    /// To access a unique storage value, use [NonSendSync] and [UniqueViewMut] or [UniqueViewMut].  
+    /// Does nothing if the storage already exists.  
+    /// Unwraps errors.
+    ///
+    /// ### Borrows
+    ///
+    /// - [AllStorages] (shared)
+    ///
+    /// ### Errors
+    ///
+    /// - [AllStorages] borrow failed.
+    ///
+    /// [AllStorages]: struct.AllStorages.html
+    /// [NonSendSync]: struct.NonSendSync.html
+    /// [UniqueView]: struct.UniqueView.html
+    /// [UniqueViewMut]: struct.UniqueViewMut.html
+    #[cfg(all(feature = "non_send", feature = "non_sync", feature = "panic"))]
+    #[cfg_attr(
+        docsrs,
+        doc(cfg(all(feature = "non_send", feature = "non_sync", feature = "panic")))
     )]
     pub fn add_unique_non_send_sync<T: 'static>(&self, component: T) {
         self.try_add_unique_non_send_sync::<T>(component).unwrap()
@@ -297,221 +818,533 @@ impl World {
     /// [AllStorages]: struct.AllStorages.html
     #[cfg(feature = "panic")]
     #[cfg_attr(docsrs, doc(cfg(feature = "panic")))]
+    #[track_caller]
     pub fn remove_unique<T: 'static>(&self) -> T {
         self.try_remove_unique().unwrap()
     }
-    #[doc = "Borrows the requested storage(s), if it doesn't exist it'll get created.  
-You can use a tuple to get multiple storages at once.
-
-You can use:
-* [View]\\<T\\> for a shared access to `T` storage
-* [ViewMut]\\<T\\> for an exclusive access to `T` storage
-* [EntitiesView] for a shared access to the entity storage
-* [EntitiesViewMut] for an exclusive reference to the entity storage
-* [AllStoragesViewMut] for an exclusive access to the storage of all components, ⚠️ can't coexist with any other storage borrow
-* [UniqueView]\\<T\\> for a shared access to a `T` unique storage
-* [UniqueViewMut]\\<T\\> for an exclusive access to a `T` unique storage
-* `Option<V>` with one or multiple views for fallible access to one or more storages"]
-    #[cfg_attr(
-        all(feature = "parallel", docsrs),
-        doc = "* <span style=\"display: table;color: #2f2f2f;background-color: #C4ECFF;border-width: 1px;border-style: solid;border-color: #7BA5DB;padding: 3px;margin-bottom: 5px; font-size: 90%\">This is supported on <strong><code style=\"background-color: #C4ECFF\">feature=\"parallel\"</code></strong> only:</span>"
-    )]
-    #[cfg_attr(
-        all(feature = "parallel", docsrs),
-        doc = "    * [ThreadPoolView] for a shared access to the `ThreadPool` used by the [World]"
-    )]
-    #[cfg_attr(
-        all(feature = "parallel", not(docsrs)),
-        doc = "* [ThreadPoolView] for a shared access to the `ThreadPool` used by the [World]"
-    )]
-    #[cfg_attr(
-        not(feature = "parallel"),
-        doc = "* ThreadPool: must activate the *parallel* feature"
-    )]
-    #[cfg_attr(
-        all(feature = "non_send", docsrs),
-        doc = "* <span style=\"display: table;color: #2f2f2f;background-color: #C4ECFF;border-width: 1px;border-style: solid;border-color: #7BA5DB;padding: 3px;margin-bottom: 5px; font-size: 90%\">This is supported on <strong><code style=\"background-color: #C4ECFF\">feature=\"non_send\"</code></strong> only:</span>"
-    )]
-    #[cfg_attr(
-        all(feature = "non_send", docsrs),
-        doc = "    * [NonSend]<[View]\\<T\\>> for a shared access to a `T` storage where `T` isn't `Send`
-    * [NonSend]<[ViewMut]\\<T\\>> for an exclusive access to a `T` storage where `T` isn't `Send`  
-[NonSend] and [UniqueView]/[UniqueViewMut] can be used together to access a `!Send` unique storage."
-    )]
-    #[cfg_attr(
-        all(feature = "non_send", not(docsrs)),
-        doc = "* [NonSend]<[View]\\<T\\>> for a shared access to a `T` storage where `T` isn't `Send`
-* [NonSend]<[ViewMut]\\<T\\>> for an exclusive access to a `T` storage where `T` isn't `Send`  
-[NonSend] and [UniqueView]/[UniqueViewMut] can be used together to access a `!Send` unique storage."
-    )]
-    #[cfg_attr(
-        not(feature = "non_send"),
-        doc = "* NonSend: must activate the *non_send* feature"
-    )]
-    #[cfg_attr(
-        all(feature = "non_sync", docsrs),
-        doc = "* <span style=\"display: table;color: #2f2f2f;background-color: #C4ECFF;border-width: 1px;border-style: solid;border-color: #7BA5DB;padding: 3px;margin-bottom: 5px; font-size: 90%\">This is supported on <strong><code style=\"background-color: #C4ECFF\">feature=\"non_sync\"</code></strong> only:</span>"
-    )]
-    #[cfg_attr(
-        all(feature = "non_sync", docsrs),
-        doc = "    * [NonSync]<[View]\\<T\\>> for a shared access to a `T` storage where `T` isn't `Sync`
-    * [NonSync]<[ViewMut]\\<T\\>> for an exclusive access to a `T` storage where `T` isn't `Sync`  
-[NonSync] and [UniqueView]/[UniqueViewMut] can be used together to access a `!Sync` unique storage."
-    )]
-    #[cfg_attr(
-        all(feature = "non_sync", not(docsrs)),
-        doc = "* [NonSync]<[View]\\<T\\>> for a shared access to a `T` storage where `T` isn't `Sync`
-* [NonSync]<[ViewMut]\\<T\\>> for an exclusive access to a `T` storage where `T` isn't `Sync`  
-[NonSync] and [UniqueView]/[UniqueViewMut] can be used together to access a `!Sync` unique storage."
-    )]
-    #[cfg_attr(
-        not(feature = "non_sync"),
-        doc = "* NonSync: must activate the *non_sync* feature"
-    )]
-    #[cfg_attr(
-        all(feature = "non_sync", docsrs),
-        doc = "* <span style=\"display: table;color: #2f2f2f;background-color: #C4ECFF;border-width: 1px;border-style: solid;border-color: #7BA5DB;padding: 3px;margin-bottom: 5px; font-size: 90%\">This is supported on <strong><code style=\"background-color: #C4ECFF\">feature=\"non_send\"</code> and <code style=\"background-color: #C4ECFF\">feature=\"non_sync\"</code></strong> only:</span>"
-    )]
-    #[cfg_attr(
-        all(feature = "non_send", feature = "non_sync", docsrs),
-        doc = "    * [NonSendSync]<[View]\\<T\\>> for a shared access to a `T` storage where `T` isn't `Send` nor `Sync`
-    * [NonSendSync]<[ViewMut]\\<T\\>> for an exclusive access to a `T` storage where `T` isn't `Send` nor `Sync`  
-[NonSendSync] and [UniqueView]/[UniqueViewMut] can be used together to access a `!Send + !Sync` unique storage."
-    )]
-    #[cfg_attr(
-        all(feature = "non_send", feature = "non_sync", not(docsrs)),
-        doc = "* [NonSendSync]<[View]\\<T\\>> for a shared access to a `T` storage where `T` isn't `Send` nor `Sync`
-* [NonSendSync]<[ViewMut]\\<T\\>> for an exclusive access to a `T` storage where `T` isn't `Send` nor `Sync`  
-[NonSendSync] and [UniqueView]/[UniqueViewMut] can be used together to access a `!Send + !Sync` unique storage."
-    )]
-    #[cfg_attr(
-        not(all(feature = "non_send", feature = "non_sync")),
-        doc = "* NonSendSync: must activate the *non_send* and *non_sync* features"
-    )]
-    #[doc = "
-### Borrows
-
-- [AllStorages] (exclusive) when requesting [AllStoragesViewMut]
-- [AllStorages] (shared) + storage (exclusive or shared) for all other views
-
-### Errors
-
-- [AllStorages] borrow failed.
-- Storage borrow failed.
-- Unique storage did not exist.
-
-### Example
-```
-use shipyard::{EntitiesView, View, ViewMut, World};
-
-let world = World::new();
-
-let u32s = world.try_borrow::<View<u32>>().unwrap();
-let (entities, mut usizes) = world
-    .try_borrow::<(EntitiesView, ViewMut<usize>)>()
-    .unwrap();
-```
-[AllStorages]: struct.AllStorages.html
-[EntitiesView]: struct.Entities.html
-[EntitiesViewMut]: struct.Entities.html
-[AllStoragesViewMut]: struct.AllStorages.html
-[World]: struct.World.html
-[View]: struct.View.html
-[ViewMut]: struct.ViewMut.html
-[UniqueView]: struct.UniqueView.html
-[UniqueViewMut]: struct.UniqueViewMut.html"]
-    #[cfg_attr(
-        feature = "parallel",
-        doc = "[ThreadPoolView]: struct.ThreadPoolView.html"
-    )]
-    #[cfg_attr(feature = "non_send", doc = "[NonSend]: struct.NonSend.html")]
-    #[cfg_attr(feature = "non_sync", doc = "[NonSync]: struct.NonSync.html")]
-    #[cfg_attr(
-        all(feature = "non_send", feature = "non_sync"),
-        doc = "[NonSendSync]: struct.NonSendSync.html"
-    )]
-    pub fn try_borrow<'s, V: Borrow<'s>>(&'s self) -> Result<V, error::GetStorage> {
-        #[cfg(feature = "parallel")]
-        {
-            V::try_borrow(&self.all_storages, &self.thread_pool)
-        }
-        #[cfg(not(feature = "parallel"))]
-        {
-            V::try_borrow(&self.all_storages)
-        }
+    /// Creates the storage of `T` if it doesn't exist.  
+    /// In strict mode storages have to be registered before they can be borrowed.
+    ///
+    /// ### Borrows
+    ///
+    /// - [AllStorages] (shared)
+    ///
+    /// ### Errors
+    ///
+    /// - [AllStorages] borrow failed.
+    ///
+    /// [AllStorages]: struct.AllStorages.html
+    pub fn try_register_storage<T: 'static + Send + Sync>(&self) -> Result<(), error::Borrow> {
+        self.all_storages.try_borrow()?.register_storage::<T>();
+        Ok(())
     }
-    #[doc = "Borrows the requested storage(s), if it doesn't exist it'll get created.  
-You can use a tuple to get multiple storages at once.  
-Unwraps errors.
-
-You can use:
-* [View]\\<T\\> for a shared access to `T` storage
-* [ViewMut]\\<T\\> for an exclusive access to `T` storage
-* [EntitiesView] for a shared access to the entity storage
-* [EntitiesViewMut] for an exclusive reference to the entity storage
-* [AllStoragesViewMut] for an exclusive access to the storage of all components, ⚠️ can't coexist with any other storage borrow
-* [UniqueView]\\<T\\> for a shared access to a `T` unique storage
-* [UniqueViewMut]\\<T\\> for an exclusive access to a `T` unique storage
-* `Option<V>` with one or multiple views for fallible access to one or more storages"]
-    #[cfg_attr(
-        all(feature = "parallel", docsrs),
-        doc = "* <span style=\"display: table;color: #2f2f2f;background-color: #C4ECFF;border-width: 1px;border-style: solid;border-color: #7BA5DB;padding: 3px;margin-bottom: 5px; font-size: 90%\">This is supported on <strong><code style=\"background-color: #C4ECFF\">feature=\"parallel\"</code></strong> only:</span>"
-    )]
-    #[cfg_attr(
-        all(feature = "parallel", docsrs),
-        doc = "    * [ThreadPoolView] for a shared access to the `ThreadPool` used by the [World]"
-    )]
-    #[cfg_attr(
-        all(feature = "parallel", not(docsrs)),
-        doc = "* [ThreadPoolView] for a shared access to the `ThreadPool` used by the [World]"
-    )]
-    #[cfg_attr(
-        not(feature = "parallel"),
-        doc = "* ThreadPool: must activate the *parallel* feature"
-    )]
-    #[cfg_attr(
-        all(feature = "non_send", docsrs),
-        doc = "* <span style=\"display: table;color: #2f2f2f;background-color: #C4ECFF;border-width: 1px;border-style: solid;border-color: #7BA5DB;padding: 3px;margin-bottom: 5px; font-size: 90%\">This is supported on <strong><code style=\"background-color: #C4ECFF\">feature=\"non_send\"</code></strong> only:</span>"
-    )]
-    #[cfg_attr(
-        all(feature = "non_send", docsrs),
-        doc = "    * [NonSend]<[View]\\<T\\>> for a shared access to a `T` storage where `T` isn't `Send`
-    * [NonSend]<[ViewMut]\\<T\\>> for an exclusive access to a `T` storage where `T` isn't `Send`  
-[NonSend] and [UniqueView]/[UniqueViewMut] can be used together to access a `!Send` unique storage."
-    )]
-    #[cfg_attr(
-        all(feature = "non_send", not(docsrs)),
-        doc = "* [NonSend]<[View]\\<T\\>> for a shared access to a `T` storage where `T` isn't `Send`
-* [NonSend]<[ViewMut]\\<T\\>> for an exclusive access to a `T` storage where `T` isn't `Send`  
-[NonSend] and [UniqueView]/[UniqueViewMut] can be used together to access a `!Send` unique storage."
-    )]
-    #[cfg_attr(
-        not(feature = "non_send"),
-        doc = "* NonSend: must activate the *non_send* feature"
-    )]
-    #[cfg_attr(
-        all(feature = "non_sync", docsrs),
-        doc = "* <span style=\"display: table;color: #2f2f2f;background-color: #C4ECFF;border-width: 1px;border-style: solid;border-color: #7BA5DB;padding: 3px;margin-bottom: 5px; font-size: 90%\">This is supported on <strong><code style=\"background-color: #C4ECFF\">feature=\"non_sync\"</code></strong> only:</span>"
-    )]
-    #[cfg_attr(
-        all(feature = "non_sync", docsrs),
-        doc = "    * [NonSync]<[View]\\<T\\>> for a shared access to a `T` storage where `T` isn't `Sync`
-    * [NonSync]<[ViewMut]\\<T\\>> for an exclusive access to a `T` storage where `T` isn't `Sync`  
-[NonSync] and [UniqueView]/[UniqueViewMut] can be used together to access a `!Sync` unique storage."
-    )]
-    #[cfg_attr(
-        all(feature = "non_sync", not(docsrs)),
-        doc = "* [NonSync]<[View]\\<T\\>> for a shared access to a `T` storage where `T` isn't `Sync`
-* [NonSync]<[ViewMut]\\<T\\>> for an exclusive access to a `T` storage where `T` isn't `Sync`  
-[NonSync] and [UniqueView]/[UniqueViewMut] can be used together to access a `!Sync` unique storage."
-    )]
-    #[cfg_attr(
-        not(feature = "non_sync"),
-        doc = "* NonSync: must activate the *non_sync* feature"
-    )]
+    /// Creates the storage of `T` if it doesn't exist.  
+    /// In strict mode storages have to be registered before they can be borrowed.  
+    /// Unwraps errors.
+    ///
+    /// ### Borrows
+    ///
+    /// - [AllStorages] (shared)
+    ///
+    /// ### Errors
+    ///
+    /// - [AllStorages] borrow failed.
+    ///
+    /// [AllStorages]: struct.AllStorages.html
+    #[cfg(feature = "panic")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "panic")))]
+    #[track_caller]
+    pub fn register_storage<T: 'static + Send + Sync>(&self) {
+        self.try_register_storage::<T>().unwrap()
+    }
+    /// Creates the storage of `T` if it doesn't exist and reserves memory for at least `capacity` components.  
+    /// With a fixed capacity, the storage is allocated for exactly `capacity` components and can't hold more.
+    ///
+    /// ### Borrows
+    ///
+    /// - [AllStorages] (shared)
+    /// - `T` storage (exclusive)
+    ///
+    /// ### Errors
+    ///
+    /// - [AllStorages] borrow failed.
+    /// - `T` storage borrow failed.
+    ///
+    /// [AllStorages]: struct.AllStorages.html
+    pub fn try_register_storage_with_capacity<T: 'static + Send + Sync>(
+        &self,
+        capacity: usize,
+    ) -> Result<(), error::GetStorage> {
+        self.all_storages
+            .try_borrow()
+            .map_err(error::GetStorage::AllStoragesBorrow)?
+            .try_register_storage_with_capacity::<T>(capacity)
+    }
+    /// Creates the storage of `T` if it doesn't exist and reserves memory for at least `capacity` components.  
+    /// With a fixed capacity, the storage is allocated for exactly `capacity` components and can't hold more.  
+    /// Unwraps errors.
+    ///
+    /// ### Borrows
+    ///
+    /// - [AllStorages] (shared)
+    /// - `T` storage (exclusive)
+    ///
+    /// ### Errors
+    ///
+    /// - [AllStorages] borrow failed.
+    /// - `T` storage borrow failed.
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{View, World};
+    ///
+    /// let world = World::with_capacity(10_000);
+    /// world.register_storage_with_capacity::<u32>(10_000);
+    ///
+    /// let entity = world.spawn((0u32,));
+    /// assert_eq!(world.borrow::<View<u32>>()[entity], 0);
+    /// ```
+    ///
+    /// [AllStorages]: struct.AllStorages.html
+    #[cfg(feature = "panic")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "panic")))]
+    #[track_caller]
+    pub fn register_storage_with_capacity<T: 'static + Send + Sync>(&self, capacity: usize) {
+        self.try_register_storage_with_capacity::<T>(capacity)
+            .unwrap()
+    }
+    /// Creates the storage of `T` if it doesn't exist and update packs it, keeping track of what `tracking` asks for.  
+    /// If the storage is already update packed, its tracking is replaced.
+    ///
+    /// ### Borrows
+    ///
+    /// - [AllStorages] (shared)
+    /// - `T` storage (exclusive)
+    ///
+    /// ### Errors
+    ///
+    /// - [AllStorages] borrow failed.
+    /// - `T` storage borrow failed.
+    /// - `T` storage is already tightly or loosly packed.
+    ///
+    /// [AllStorages]: struct.AllStorages.html
+    pub fn try_register_storage_tracked<T: 'static + Send + Sync>(
+        &self,
+        tracking: Tracking,
+    ) -> Result<(), error::Pack> {
+        self.all_storages
+            .try_borrow()
+            .map_err(error::GetStorage::AllStoragesBorrow)?
+            .try_register_storage_tracked::<T>(tracking)
+    }
+    /// Creates the storage of `T` if it doesn't exist and update packs it, keeping track of what `tracking` asks for.  
+    /// If the storage is already update packed, its tracking is replaced.  
+    /// Unwraps errors.
+    ///
+    /// ### Borrows
+    ///
+    /// - [AllStorages] (shared)
+    /// - `T` storage (exclusive)
+    ///
+    /// ### Errors
+    ///
+    /// - [AllStorages] borrow failed.
+    /// - `T` storage borrow failed.
+    /// - `T` storage is already tightly or loosly packed.
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{Tracking, ViewMut, World};
+    ///
+    /// let world = World::new();
+    /// world.register_storage_tracked::<u32>(Tracking::Modification);
+    ///
+    /// let entity = world.spawn((0u32,));
+    ///
+    /// world.run(|mut u32s: ViewMut<u32>| {
+    ///     u32s.clear_inserted();
+    ///     u32s[entity] += 1;
+    ///     assert_eq!(u32s.modified().len(), 1);
+    ///
+    ///     u32s.delete(entity);
+    ///     assert!(u32s.deleted().is_empty());
+    /// });
+    /// ```
+    ///
+    /// [AllStorages]: struct.AllStorages.html
+    #[cfg(feature = "panic")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "panic")))]
+    #[track_caller]
+    pub fn register_storage_tracked<T: 'static + Send + Sync>(&self, tracking: Tracking) {
+        self.try_register_storage_tracked::<T>(tracking).unwrap()
+    }
+    /// Creates the storage of `T` if it doesn't exist, to access it use [NonSend].  
+    /// In strict mode storages have to be registered before they can be borrowed.
+    ///
+    /// ### Borrows
+    ///
+    /// - [AllStorages] (shared)
+    ///
+    /// ### Errors
+    ///
+    /// - [AllStorages] borrow failed.
+    ///
+    /// [AllStorages]: struct.AllStorages.html
+    /// [NonSend]: struct.NonSend.html
+    #[cfg(feature = "non_send")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "non_send")))]
+    pub fn try_register_storage_non_send<T: 'static + Sync>(&self) -> Result<(), error::Borrow> {
+        self.all_storages
+            .try_borrow()?
+            .register_storage_non_send::<T>();
+        Ok(())
+    }
+    /// Creates the storage of `T` if it doesn't exist, to access it use [NonSend].  
+    /// In strict mode storages have to be registered before they can be borrowed.  
+    /// Unwraps errors.
+    ///
+    /// ### Borrows
+    ///
+    /// - [AllStorages] (shared)
+    ///
+    /// ### Errors
+    ///
+    /// - [AllStorages] borrow failed.
+    ///
+    /// [AllStorages]: struct.AllStorages.html
+    /// [NonSend]: struct.NonSend.html
+    #[cfg(all(feature = "non_send", feature = "panic"))]
+    #[cfg_attr(docsrs, doc(cfg(all(feature = "non_send", feature = "panic"))))]
+    #[track_caller]
+    pub fn register_storage_non_send<T: 'static + Sync>(&self) {
+        self.try_register_storage_non_send::<T>().unwrap()
+    }
+    /// Creates the storage of `T` if it doesn't exist, to access it use [NonSync].  
+    /// In strict mode storages have to be registered before they can be borrowed.
+    ///
+    /// ### Borrows
+    ///
+    /// - [AllStorages] (shared)
+    ///
+    /// ### Errors
+    ///
+    /// - [AllStorages] borrow failed.
+    ///
+    /// [AllStorages]: struct.AllStorages.html
+    /// [NonSync]: struct.NonSync.html
+    #[cfg(feature = "non_sync")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "non_sync")))]
+    pub fn try_register_storage_non_sync<T: 'static + Send>(&self) -> Result<(), error::Borrow> {
+        self.all_storages
+            .try_borrow()?
+            .register_storage_non_sync::<T>();
+        Ok(())
+    }
+    /// Creates the storage of `T` if it doesn't exist, to access it use [NonSync].  
+    /// In strict mode storages have to be registered before they can be borrowed.  
+    /// Unwraps errors.
+    ///
+    /// ### Borrows
+    ///
+    /// - [AllStorages] (shared)
+    ///
+    /// ### Errors
+    ///
+    /// - [AllStorages] borrow failed.
+    ///
+    /// [AllStorages]: struct.AllStorages.html
+    /// [NonSync]: struct.NonSync.html
+    #[cfg(all(feature = "non_sync", feature = "panic"))]
+    #[cfg_attr(docsrs, doc(cfg(all(feature = "non_sync", feature = "panic"))))]
+    #[track_caller]
+    pub fn register_storage_non_sync<T: 'static + Send>(&self) {
+        self.try_register_storage_non_sync::<T>().unwrap()
+    }
+    /// Creates the storage of `T` if it doesn't exist, to access it use [NonSendSync].  
+    /// In strict mode storages have to be registered before they can be borrowed.
+    ///
+    /// ### Borrows
+    ///
+    /// - [AllStorages] (shared)
+    ///
+    /// ### Errors
+    ///
+    /// - [AllStorages] borrow failed.
+    ///
+    /// [AllStorages]: struct.AllStorages.html
+    /// [NonSendSync]: struct.NonSendSync.html
+    #[cfg(all(feature = "non_send", feature = "non_sync"))]
+    #[cfg_attr(docsrs, doc(cfg(all(feature = "non_send", feature = "non_sync"))))]
+    pub fn try_register_storage_non_send_sync<T: 'static>(&self) -> Result<(), error::Borrow> {
+        self.all_storages
+            .try_borrow()?
+            .register_storage_non_send_sync::<T>();
+        Ok(())
+    }
+    /// Creates the storage of `T` if it doesn't exist, to access it use [NonSendSync].  
+    /// In strict mode storages have to be registered before they can be borrowed.  
+    /// Unwraps errors.
+    ///
+    /// ### Borrows
+    ///
+    /// - [AllStorages] (shared)
+    ///
+    /// ### Errors
+    ///
+    /// - [AllStorages] borrow failed.
+    ///
+    /// [AllStorages]: struct.AllStorages.html
+    /// [NonSendSync]: struct.NonSendSync.html
+    #[cfg(all(feature = "non_send", feature = "non_sync", feature = "panic"))]
     #[cfg_attr(
-        all(feature = "non_sync", docsrs),
-        doc = "* <span style=\"display: table;color: #2f2f2f;background-color: #C4ECFF;border-width: 1px;border-style: solid;border-color: #7BA5DB;padding: 3px;margin-bottom: 5px; font-size: 90%\">This is supported on <strong><code style=\"background-color: #C4ECFF\">feature=\"non_send\"</code> and <code style=\"background-color: #C4ECFF\">feature=\"non_sync\"</code></strong> only:</span>"
+        docsrs,
+        doc(cfg(all(feature = "non_send", feature = "non_sync", feature = "panic")))
+    )]
+    #[track_caller]
+    pub fn register_storage_non_send_sync<T: 'static>(&self) {
+        self.try_register_storage_non_send_sync::<T>().unwrap()
+    }
+    /// Creates the storages of all types in `T` if they don't exist.  
+    /// In strict mode storages have to be registered before they can be borrowed.
+    ///
+    /// `T` has to be a tuple even for a single type.  
+    /// In this case use (T,).
+    ///
+    /// ### Borrows
+    ///
+    /// - [AllStorages] (shared)
+    ///
+    /// ### Errors
+    ///
+    /// - [AllStorages] borrow failed.
+    ///
+    /// [AllStorages]: struct.AllStorages.html
+    pub fn try_register_storages<T: RegisterStorages>(&self) -> Result<(), error::Borrow> {
+        self.all_storages.try_borrow()?.register_storages::<T>();
+        Ok(())
+    }
+    /// Creates the storages of all types in `T` if they don't exist.  
+    /// In strict mode storages have to be registered before they can be borrowed.  
+    /// Unwraps errors.
+    ///
+    /// `T` has to be a tuple even for a single type.  
+    /// In this case use (T,).
+    ///
+    /// ### Borrows
+    ///
+    /// - [AllStorages] (shared)
+    ///
+    /// ### Errors
+    ///
+    /// - [AllStorages] borrow failed.
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{View, World};
+    ///
+    /// let world = World::new();
+    /// world.register_storages::<(u32, usize)>();
+    /// world.set_strict(true);
+    ///
+    /// assert!(world.try_borrow::<(View<u32>, View<usize>)>().is_ok());
+    /// ```
+    ///
+    /// [AllStorages]: struct.AllStorages.html
+    #[cfg(feature = "panic")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "panic")))]
+    #[track_caller]
+    pub fn register_storages<T: RegisterStorages>(&self) {
+        self.try_register_storages::<T>().unwrap()
+    }
+    /// Declares a tight pack between the storages borrowed by `V`, a tuple of [ViewMut].  
+    /// Components of entities present in all storages are kept at the start of each storage, in the same order.
+    /// Iterating the whole pack is then a straight zip over the components, without sparse lookups.
+    /// The pack is maintained when components are added, removed or deleted.
+    ///
+    /// ### Borrows
+    ///
+    /// - [AllStorages] (shared)
+    /// - `V` storages (exclusive)
+    ///
+    /// ### Errors
+    ///
+    /// - [AllStorages] borrow failed.
+    /// - Storage borrow failed.
+    /// - A storage is already packed.
+    ///
+    /// [AllStorages]: struct.AllStorages.html
+    /// [ViewMut]: struct.ViewMut.html
+    pub fn try_tight_pack<'s, V: Borrow<'s> + TightPack>(&'s self) -> Result<(), error::Pack> {
+        self.try_borrow::<V>()?.try_tight_pack()
+    }
+    /// Declares a tight pack between the storages borrowed by `V`, a tuple of [ViewMut].  
+    /// Components of entities present in all storages are kept at the start of each storage, in the same order.
+    /// Iterating the whole pack is then a straight zip over the components, without sparse lookups.
+    /// The pack is maintained when components are added, removed or deleted.  
+    /// Unwraps errors.
+    ///
+    /// ### Borrows
+    ///
+    /// - [AllStorages] (shared)
+    /// - `V` storages (exclusive)
+    ///
+    /// ### Errors
+    ///
+    /// - [AllStorages] borrow failed.
+    /// - Storage borrow failed.
+    /// - A storage is already packed.
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{iterators::Iter2, IntoIter, View, ViewMut, World};
+    ///
+    /// struct Position(f32);
+    /// struct Velocity(f32);
+    ///
+    /// let world = World::new();
+    /// world.tight_pack::<(ViewMut<Position>, ViewMut<Velocity>)>();
+    ///
+    /// world.spawn((Position(0.0),));
+    /// world.spawn((Position(0.0), Velocity(1.0)));
+    ///
+    /// world.run(|positions: View<Position>, velocities: View<Velocity>| {
+    ///     assert_eq!(positions.tight_packed().len(), 1);
+    ///     assert!(matches!((&positions, &velocities).iter(), Iter2::Tight(_)));
+    /// });
+    /// ```
+    ///
+    /// [AllStorages]: struct.AllStorages.html
+    /// [ViewMut]: struct.ViewMut.html
+    #[cfg(feature = "panic")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "panic")))]
+    #[track_caller]
+    pub fn tight_pack<'s, V: Borrow<'s> + TightPack>(&'s self) {
+        self.try_tight_pack::<V>().unwrap()
+    }
+    /// In strict mode, borrowing a storage that was never registered returns [GetStorage::MissingStorage] instead of creating it.  
+    /// Storages that already exist and unique storages are not affected.
+    ///
+    /// ### Borrows
+    ///
+    /// - [AllStorages] (exclusive)
+    ///
+    /// ### Errors
+    ///
+    /// - [AllStorages] borrow failed.
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{error, View, World};
+    ///
+    /// let world = World::new();
+    /// world.set_strict(true);
+    ///
+    /// assert!(matches!(
+    ///     world.try_borrow::<View<u32>>(),
+    ///     Err(error::GetStorage::MissingStorage(_))
+    /// ));
+    ///
+    /// world.register_storage::<u32>();
+    /// assert!(world.try_borrow::<View<u32>>().is_ok());
+    /// ```
+    ///
+    /// [AllStorages]: struct.AllStorages.html
+    /// [GetStorage::MissingStorage]: error/enum.GetStorage.html#variant.MissingStorage
+    pub fn try_set_strict(&self, strict: bool) -> Result<(), error::Borrow> {
+        self.all_storages.try_borrow_mut()?.set_strict(strict);
+        Ok(())
+    }
+    /// In strict mode, borrowing a storage that was never registered returns [GetStorage::MissingStorage] instead of creating it.  
+    /// Storages that already exist and unique storages are not affected.  
+    /// Unwraps errors.
+    ///
+    /// ### Borrows
+    ///
+    /// - [AllStorages] (exclusive)
+    ///
+    /// ### Errors
+    ///
+    /// - [AllStorages] borrow failed.
+    ///
+    /// [AllStorages]: struct.AllStorages.html
+    /// [GetStorage::MissingStorage]: error/enum.GetStorage.html#variant.MissingStorage
+    #[cfg(feature = "panic")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "panic")))]
+    #[track_caller]
+    pub fn set_strict(&self, strict: bool) {
+        self.try_set_strict(strict).unwrap()
+    }
+    #[doc = "Borrows the requested storage(s), if it doesn't exist it'll get created.  
+You can use a tuple to get multiple storages at once.
+
+You can use:
+* [View]\\<T\\> for a shared access to `T` storage
+* [ViewMut]\\<T\\> for an exclusive access to `T` storage
+* [EntitiesView] for a shared access to the entity storage
+* [EntitiesViewMut] for an exclusive reference to the entity storage
+* [AllStoragesViewMut] for an exclusive access to the storage of all components, ⚠️ can't coexist with any other storage borrow
+* [UniqueView]\\<T\\> for a shared access to a `T` unique storage
+* [UniqueViewMut]\\<T\\> for an exclusive access to a `T` unique storage
+* `Option<V>` with one or multiple views for fallible access to one or more storages"]
+    #[cfg_attr(
+        all(feature = "parallel", docsrs),
+        doc = "* <span style=\"display: table;color: #2f2f2f;background-color: #C4ECFF;border-width: 1px;border-style: solid;border-color: #7BA5DB;padding: 3px;margin-bottom: 5px; font-size: 90%\">This is supported on <strong><code style=\"background-color: #C4ECFF\">feature=\"parallel\"</code></strong> only:</span>"
+    )]
+    #[cfg_attr(
+        all(feature = "parallel", docsrs),
+        doc = "    * [ThreadPoolView] for a shared access to the `ThreadPool` used by the [World]"
+    )]
+    #[cfg_attr(
+        all(feature = "parallel", not(docsrs)),
+        doc = "* [ThreadPoolView] for a shared access to the `ThreadPool` used by the [World]"
+    )]
+    #[cfg_attr(
+        not(feature = "parallel"),
+        doc = "* ThreadPool: must activate the *parallel* feature"
+    )]
+    #[cfg_attr(
+        all(feature = "non_send", docsrs),
+        doc = "* <span style=\"display: table;color: #2f2f2f;background-color: #C4ECFF;border-width: 1px;border-style: solid;border-color: #7BA5DB;padding: 3px;margin-bottom: 5px; font-size: 90%\">This is supported on <strong><code style=\"background-color: #C4ECFF\">feature=\"non_send\"</code></strong> only:</span>"
+    )]
+    #[cfg_attr(
+        all(feature = "non_send", docsrs),
+        doc = "    * [NonSend]<[View]\\<T\\>> for a shared access to a `T` storage where `T` isn't `Send`
+    * [NonSend]<[ViewMut]\\<T\\>> for an exclusive access to a `T` storage where `T` isn't `Send`  
+[NonSend] and [UniqueView]/[UniqueViewMut] can be used together to access a `!Send` unique storage."
+    )]
+    #[cfg_attr(
+        all(feature = "non_send", not(docsrs)),
+        doc = "* [NonSend]<[View]\\<T\\>> for a shared access to a `T` storage where `T` isn't `Send`
+* [NonSend]<[ViewMut]\\<T\\>> for an exclusive access to a `T` storage where `T` isn't `Send`  
+[NonSend] and [UniqueView]/[UniqueViewMut] can be used together to access a `!Send` unique storage."
+    )]
+    #[cfg_attr(
+        not(feature = "non_send"),
+        doc = "* NonSend: must activate the *non_send* feature"
+    )]
+    #[cfg_attr(
+        all(feature = "non_sync", docsrs),
+        doc = "* <span style=\"display: table;color: #2f2f2f;background-color: #C4ECFF;border-width: 1px;border-style: solid;border-color: #7BA5DB;padding: 3px;margin-bottom: 5px; font-size: 90%\">This is supported on <strong><code style=\"background-color: #C4ECFF\">feature=\"non_sync\"</code></strong> only:</span>"
+    )]
+    #[cfg_attr(
+        all(feature = "non_sync", docsrs),
+        doc = "    * [NonSync]<[View]\\<T\\>> for a shared access to a `T` storage where `T` isn't `Sync`
+    * [NonSync]<[ViewMut]\\<T\\>> for an exclusive access to a `T` storage where `T` isn't `Sync`  
+[NonSync] and [UniqueView]/[UniqueViewMut] can be used together to access a `!Sync` unique storage."
+    )]
+    #[cfg_attr(
+        all(feature = "non_sync", not(docsrs)),
+        doc = "* [NonSync]<[View]\\<T\\>> for a shared access to a `T` storage where `T` isn't `Sync`
+* [NonSync]<[ViewMut]\\<T\\>> for an exclusive access to a `T` storage where `T` isn't `Sync`  
+[NonSync] and [UniqueView]/[UniqueViewMut] can be used together to access a `!Sync` unique storage."
+    )]
+    #[cfg_attr(
+        not(feature = "non_sync"),
+        doc = "* NonSync: must activate the *non_sync* feature"
+    )]
+    #[cfg_attr(
+        all(feature = "non_sync", docsrs),
+        doc = "* <span style=\"display: table;color: #2f2f2f;background-color: #C4ECFF;border-width: 1px;border-style: solid;border-color: #7BA5DB;padding: 3px;margin-bottom: 5px; font-size: 90%\">This is supported on <strong><code style=\"background-color: #C4ECFF\">feature=\"non_send\"</code> and <code style=\"background-color: #C4ECFF\">feature=\"non_sync\"</code></strong> only:</span>"
     )]
     #[cfg_attr(
         all(feature = "non_send", feature = "non_sync", docsrs),
@@ -532,1034 +1365,4377 @@ You can use:
     #[doc = "
 ### Borrows
 
-- [AllStorages] (exclusive) when requesting [AllStoragesViewMut]
-- [AllStorages] (shared) + storage (exclusive or shared) for all other views
+- [AllStorages] (exclusive) when requesting [AllStoragesViewMut]
+- [AllStorages] (shared) + storage (exclusive or shared) for all other views
+
+### Errors
+
+- [AllStorages] borrow failed.
+- Storage borrow failed.
+- Unique storage did not exist.
+
+### Example
+```
+use shipyard::{EntitiesView, View, ViewMut, World};
+
+let world = World::new();
+
+let u32s = world.try_borrow::<View<u32>>().unwrap();
+let (entities, mut usizes) = world
+    .try_borrow::<(EntitiesView, ViewMut<usize>)>()
+    .unwrap();
+```
+[AllStorages]: struct.AllStorages.html
+[EntitiesView]: struct.Entities.html
+[EntitiesViewMut]: struct.Entities.html
+[AllStoragesViewMut]: struct.AllStorages.html
+[World]: struct.World.html
+[View]: struct.View.html
+[ViewMut]: struct.ViewMut.html
+[UniqueView]: struct.UniqueView.html
+[UniqueViewMut]: struct.UniqueViewMut.html"]
+    #[cfg_attr(
+        feature = "parallel",
+        doc = "[ThreadPoolView]: struct.ThreadPoolView.html"
+    )]
+    #[cfg_attr(feature = "non_send", doc = "[NonSend]: struct.NonSend.html")]
+    #[cfg_attr(feature = "non_sync", doc = "[NonSync]: struct.NonSync.html")]
+    #[cfg_attr(
+        all(feature = "non_send", feature = "non_sync"),
+        doc = "[NonSendSync]: struct.NonSendSync.html"
+    )]
+    pub fn try_borrow<'s, V: Borrow<'s>>(&'s self) -> Result<V, error::GetStorage> {
+        #[cfg(feature = "parallel")]
+        {
+            V::try_borrow(&self.all_storages, &self.thread_pool)
+        }
+        #[cfg(not(feature = "parallel"))]
+        {
+            V::try_borrow(&self.all_storages)
+        }
+    }
+    #[doc = "Borrows the requested storage(s), if it doesn't exist it'll get created.  
+You can use a tuple to get multiple storages at once.  
+Unwraps errors.
+
+You can use:
+* [View]\\<T\\> for a shared access to `T` storage
+* [ViewMut]\\<T\\> for an exclusive access to `T` storage
+* [EntitiesView] for a shared access to the entity storage
+* [EntitiesViewMut] for an exclusive reference to the entity storage
+* [AllStoragesViewMut] for an exclusive access to the storage of all components, ⚠️ can't coexist with any other storage borrow
+* [UniqueView]\\<T\\> for a shared access to a `T` unique storage
+* [UniqueViewMut]\\<T\\> for an exclusive access to a `T` unique storage
+* `Option<V>` with one or multiple views for fallible access to one or more storages"]
+    #[cfg_attr(
+        all(feature = "parallel", docsrs),
+        doc = "* <span style=\"display: table;color: #2f2f2f;background-color: #C4ECFF;border-width: 1px;border-style: solid;border-color: #7BA5DB;padding: 3px;margin-bottom: 5px; font-size: 90%\">This is supported on <strong><code style=\"background-color: #C4ECFF\">feature=\"parallel\"</code></strong> only:</span>"
+    )]
+    #[cfg_attr(
+        all(feature = "parallel", docsrs),
+        doc = "    * [ThreadPoolView] for a shared access to the `ThreadPool` used by the [World]"
+    )]
+    #[cfg_attr(
+        all(feature = "parallel", not(docsrs)),
+        doc = "* [ThreadPoolView] for a shared access to the `ThreadPool` used by the [World]"
+    )]
+    #[cfg_attr(
+        not(feature = "parallel"),
+        doc = "* ThreadPool: must activate the *parallel* feature"
+    )]
+    #[cfg_attr(
+        all(feature = "non_send", docsrs),
+        doc = "* <span style=\"display: table;color: #2f2f2f;background-color: #C4ECFF;border-width: 1px;border-style: solid;border-color: #7BA5DB;padding: 3px;margin-bottom: 5px; font-size: 90%\">This is supported on <strong><code style=\"background-color: #C4ECFF\">feature=\"non_send\"</code></strong> only:</span>"
+    )]
+    #[cfg_attr(
+        all(feature = "non_send", docsrs),
+        doc = "    * [NonSend]<[View]\\<T\\>> for a shared access to a `T` storage where `T` isn't `Send`
+    * [NonSend]<[ViewMut]\\<T\\>> for an exclusive access to a `T` storage where `T` isn't `Send`  
+[NonSend] and [UniqueView]/[UniqueViewMut] can be used together to access a `!Send` unique storage."
+    )]
+    #[cfg_attr(
+        all(feature = "non_send", not(docsrs)),
+        doc = "* [NonSend]<[View]\\<T\\>> for a shared access to a `T` storage where `T` isn't `Send`
+* [NonSend]<[ViewMut]\\<T\\>> for an exclusive access to a `T` storage where `T` isn't `Send`  
+[NonSend] and [UniqueView]/[UniqueViewMut] can be used together to access a `!Send` unique storage."
+    )]
+    #[cfg_attr(
+        not(feature = "non_send"),
+        doc = "* NonSend: must activate the *non_send* feature"
+    )]
+    #[cfg_attr(
+        all(feature = "non_sync", docsrs),
+        doc = "* <span style=\"display: table;color: #2f2f2f;background-color: #C4ECFF;border-width: 1px;border-style: solid;border-color: #7BA5DB;padding: 3px;margin-bottom: 5px; font-size: 90%\">This is supported on <strong><code style=\"background-color: #C4ECFF\">feature=\"non_sync\"</code></strong> only:</span>"
+    )]
+    #[cfg_attr(
+        all(feature = "non_sync", docsrs),
+        doc = "    * [NonSync]<[View]\\<T\\>> for a shared access to a `T` storage where `T` isn't `Sync`
+    * [NonSync]<[ViewMut]\\<T\\>> for an exclusive access to a `T` storage where `T` isn't `Sync`  
+[NonSync] and [UniqueView]/[UniqueViewMut] can be used together to access a `!Sync` unique storage."
+    )]
+    #[cfg_attr(
+        all(feature = "non_sync", not(docsrs)),
+        doc = "* [NonSync]<[View]\\<T\\>> for a shared access to a `T` storage where `T` isn't `Sync`
+* [NonSync]<[ViewMut]\\<T\\>> for an exclusive access to a `T` storage where `T` isn't `Sync`  
+[NonSync] and [UniqueView]/[UniqueViewMut] can be used together to access a `!Sync` unique storage."
+    )]
+    #[cfg_attr(
+        not(feature = "non_sync"),
+        doc = "* NonSync: must activate the *non_sync* feature"
+    )]
+    #[cfg_attr(
+        all(feature = "non_sync", docsrs),
+        doc = "* <span style=\"display: table;color: #2f2f2f;background-color: #C4ECFF;border-width: 1px;border-style: solid;border-color: #7BA5DB;padding: 3px;margin-bottom: 5px; font-size: 90%\">This is supported on <strong><code style=\"background-color: #C4ECFF\">feature=\"non_send\"</code> and <code style=\"background-color: #C4ECFF\">feature=\"non_sync\"</code></strong> only:</span>"
+    )]
+    #[cfg_attr(
+        all(feature = "non_send", feature = "non_sync", docsrs),
+        doc = "    * [NonSendSync]<[View]\\<T\\>> for a shared access to a `T` storage where `T` isn't `Send` nor `Sync`
+    * [NonSendSync]<[ViewMut]\\<T\\>> for an exclusive access to a `T` storage where `T` isn't `Send` nor `Sync`  
+[NonSendSync] and [UniqueView]/[UniqueViewMut] can be used together to access a `!Send + !Sync` unique storage."
+    )]
+    #[cfg_attr(
+        all(feature = "non_send", feature = "non_sync", not(docsrs)),
+        doc = "* [NonSendSync]<[View]\\<T\\>> for a shared access to a `T` storage where `T` isn't `Send` nor `Sync`
+* [NonSendSync]<[ViewMut]\\<T\\>> for an exclusive access to a `T` storage where `T` isn't `Send` nor `Sync`  
+[NonSendSync] and [UniqueView]/[UniqueViewMut] can be used together to access a `!Send + !Sync` unique storage."
+    )]
+    #[cfg_attr(
+        not(all(feature = "non_send", feature = "non_sync")),
+        doc = "* NonSendSync: must activate the *non_send* and *non_sync* features"
+    )]
+    #[doc = "
+### Borrows
+
+- [AllStorages] (exclusive) when requesting [AllStoragesViewMut]
+- [AllStorages] (shared) + storage (exclusive or shared) for all other views
+
+### Errors
+
+- [AllStorages] borrow failed.
+- Storage borrow failed.
+- Unique storage did not exist.
+
+### Example
+```
+use shipyard::{EntitiesView, View, ViewMut, World};
+
+let world = World::new();
+
+let u32s = world.borrow::<View<u32>>();
+let (entities, mut usizes) = world.borrow::<(EntitiesView, ViewMut<usize>)>();
+```
+[AllStorages]: struct.AllStorages.html
+[EntitiesView]: struct.Entities.html
+[EntitiesViewMut]: struct.Entities.html
+[AllStoragesViewMut]: struct.AllStorages.html
+[World]: struct.World.html
+[View]: struct.View.html
+[ViewMut]: struct.ViewMut.html
+[UniqueView]: struct.UniqueView.html
+[UniqueViewMut]: struct.UniqueViewMut.html"]
+    #[cfg_attr(
+        feature = "parallel",
+        doc = "[ThreadPoolView]: struct.ThreadPoolView.html"
+    )]
+    #[cfg_attr(feature = "non_send", doc = "[NonSend]: struct.NonSend.html")]
+    #[cfg_attr(feature = "non_sync", doc = "[NonSync]: struct.NonSync.html")]
+    #[cfg_attr(
+        all(feature = "non_send", feature = "non_sync"),
+        doc = "[NonSendSync]: struct.NonSendSync.html"
+    )]
+    #[cfg(feature = "panic")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "panic")))]
+    #[track_caller]
+    pub fn borrow<'s, V: Borrow<'s>>(&'s self) -> V {
+        self.try_borrow::<V>().unwrap()
+    }
+    #[doc = "Borrows the requested storages and runs the function.  
+Data can be passed to the function, this always has to be a single type but you can use a tuple if needed.
+
+You can use:
+* [View]\\<T\\> for a shared access to `T` storage
+* [ViewMut]\\<T\\> for an exclusive access to `T` storage
+* [EntitiesView] for a shared access to the entity storage
+* [EntitiesViewMut] for an exclusive reference to the entity storage
+* [AllStoragesViewMut] for an exclusive access to the storage of all components, ⚠️ can't coexist with any other storage borrow
+* [UniqueView]\\<T\\> for a shared access to a `T` unique storage
+* [UniqueViewMut]\\<T\\> for an exclusive access to a `T` unique storage
+* `Option<V>` with one or multiple views for fallible access to one or more storages"]
+    #[cfg_attr(
+        all(feature = "parallel", docsrs),
+        doc = "* <span style=\"display: table;color: #2f2f2f;background-color: #C4ECFF;border-width: 1px;border-style: solid;border-color: #7BA5DB;padding: 3px;margin-bottom: 5px; font-size: 90%\">This is supported on <strong><code style=\"background-color: #C4ECFF\">feature=\"parallel\"</code></strong> only:</span>"
+    )]
+    #[cfg_attr(
+        all(feature = "parallel", docsrs),
+        doc = "    * [ThreadPoolView] for a shared access to the `ThreadPool` used by the [World]"
+    )]
+    #[cfg_attr(
+        all(feature = "parallel", not(docsrs)),
+        doc = "* [ThreadPoolView] for a shared access to the `ThreadPool` used by the [World]"
+    )]
+    #[cfg_attr(
+        not(feature = "parallel"),
+        doc = "* ThreadPool: must activate the *parallel* feature"
+    )]
+    #[cfg_attr(
+        all(feature = "non_send", docsrs),
+        doc = "* <span style=\"display: table;color: #2f2f2f;background-color: #C4ECFF;border-width: 1px;border-style: solid;border-color: #7BA5DB;padding: 3px;margin-bottom: 5px; font-size: 90%\">This is supported on <strong><code style=\"background-color: #C4ECFF\">feature=\"non_send\"</code></strong> only:</span>"
+    )]
+    #[cfg_attr(
+        all(feature = "non_send", docsrs),
+        doc = "    * [NonSend]<[View]\\<T\\>> for a shared access to a `T` storage where `T` isn't `Send`
+    * [NonSend]<[ViewMut]\\<T\\>> for an exclusive access to a `T` storage where `T` isn't `Send`  
+[NonSend] and [UniqueView]/[UniqueViewMut] can be used together to access a `!Send` unique storage."
+    )]
+    #[cfg_attr(
+        all(feature = "non_send", not(docsrs)),
+        doc = "* [NonSend]<[View]\\<T\\>> for a shared access to a `T` storage where `T` isn't `Send`
+* [NonSend]<[ViewMut]\\<T\\>> for an exclusive access to a `T` storage where `T` isn't `Send`  
+[NonSend] and [UniqueView]/[UniqueViewMut] can be used together to access a `!Send` unique storage."
+    )]
+    #[cfg_attr(
+        not(feature = "non_send"),
+        doc = "* NonSend: must activate the *non_send* feature"
+    )]
+    #[cfg_attr(
+        all(feature = "non_sync", docsrs),
+        doc = "* <span style=\"display: table;color: #2f2f2f;background-color: #C4ECFF;border-width: 1px;border-style: solid;border-color: #7BA5DB;padding: 3px;margin-bottom: 5px; font-size: 90%\">This is supported on <strong><code style=\"background-color: #C4ECFF\">feature=\"non_sync\"</code></strong> only:</span>"
+    )]
+    #[cfg_attr(
+        all(feature = "non_sync", docsrs),
+        doc = "    * [NonSync]<[View]\\<T\\>> for a shared access to a `T` storage where `T` isn't `Sync`
+    * [NonSync]<[ViewMut]\\<T\\>> for an exclusive access to a `T` storage where `T` isn't `Sync`  
+[NonSync] and [UniqueView]/[UniqueViewMut] can be used together to access a `!Sync` unique storage."
+    )]
+    #[cfg_attr(
+        all(feature = "non_sync", not(docsrs)),
+        doc = "* [NonSync]<[View]\\<T\\>> for a shared access to a `T` storage where `T` isn't `Sync`
+* [NonSync]<[ViewMut]\\<T\\>> for an exclusive access to a `T` storage where `T` isn't `Sync`  
+[NonSync] and [UniqueView]/[UniqueViewMut] can be used together to access a `!Sync` unique storage."
+    )]
+    #[cfg_attr(
+        not(feature = "non_sync"),
+        doc = "* NonSync: must activate the *non_sync* feature"
+    )]
+    #[cfg_attr(
+        all(feature = "non_sync", docsrs),
+        doc = "* <span style=\"display: table;color: #2f2f2f;background-color: #C4ECFF;border-width: 1px;border-style: solid;border-color: #7BA5DB;padding: 3px;margin-bottom: 5px; font-size: 90%\">This is supported on <strong><code style=\"background-color: #C4ECFF\">feature=\"non_send\"</code> and <code style=\"background-color: #C4ECFF\">feature=\"non_sync\"</code></strong> only:</span>"
+    )]
+    #[cfg_attr(
+        all(feature = "non_send", feature = "non_sync", docsrs),
+        doc = "    * [NonSendSync]<[View]\\<T\\>> for a shared access to a `T` storage where `T` isn't `Send` nor `Sync`
+    * [NonSendSync]<[ViewMut]\\<T\\>> for an exclusive access to a `T` storage where `T` isn't `Send` nor `Sync`  
+[NonSendSync] and [UniqueView]/[UniqueViewMut] can be used together to access a `!Send + !Sync` unique storage."
+    )]
+    #[cfg_attr(
+        all(feature = "non_send", feature = "non_sync", not(docsrs)),
+        doc = "* [NonSendSync]<[View]\\<T\\>> for a shared access to a `T` storage where `T` isn't `Send` nor `Sync`
+* [NonSendSync]<[ViewMut]\\<T\\>> for an exclusive access to a `T` storage where `T` isn't `Send` nor `Sync`  
+[NonSendSync] and [UniqueView]/[UniqueViewMut] can be used together to access a `!Send + !Sync` unique storage."
+    )]
+    #[cfg_attr(
+        not(all(feature = "non_send", feature = "non_sync")),
+        doc = "* NonSendSync: must activate the *non_send* and *non_sync* features"
+    )]
+    #[doc = "
+### Borrows
+
+- [AllStorages] (exclusive) when requesting [AllStoragesViewMut]
+- [AllStorages] (shared) + storage (exclusive or shared) for all other views
+
+### Errors
+
+- [AllStorages] borrow failed.
+- Storage borrow failed.
+- Unique storage did not exist.
+- Error returned by user.
+
+### Example
+```
+use shipyard::{EntityId, Get, ViewMut, World};
+
+fn sys1((entity, [x, y]): (EntityId, [f32; 2]), mut positions: ViewMut<[f32; 2]>) {
+    if let Ok(pos) = (&mut positions).get(entity) {
+        *pos = [x, y];
+    }
+}
+
+let world = World::new();
+
+world.try_run_with_data(sys1, (EntityId::dead(), [0., 0.])).unwrap();
+```
+[AllStorages]: struct.AllStorages.html
+[EntitiesView]: struct.Entities.html
+[EntitiesViewMut]: struct.Entities.html
+[AllStoragesViewMut]: struct.AllStorages.html
+[World]: struct.World.html
+[View]: struct.View.html
+[ViewMut]: struct.ViewMut.html
+[UniqueView]: struct.UniqueView.html
+[UniqueViewMut]: struct.UniqueViewMut.html"]
+    #[cfg_attr(
+        feature = "parallel",
+        doc = "[ThreadPoolView]: struct.ThreadPoolView.html"
+    )]
+    #[cfg_attr(feature = "non_send", doc = "[NonSend]: struct.NonSend.html")]
+    #[cfg_attr(feature = "non_sync", doc = "[NonSync]: struct.NonSync.html")]
+    #[cfg_attr(
+        all(feature = "non_send", feature = "non_sync"),
+        doc = "[NonSendSync]: struct.NonSendSync.html"
+    )]
+    pub fn try_run_with_data<'s, Data, B, R, S: crate::system::System<'s, (Data,), B, R>>(
+        &'s self,
+        s: S,
+        data: Data,
+    ) -> Result<R, error::Run> {
+        Ok(s.run((data,), {
+            #[cfg(feature = "parallel")]
+            {
+                S::try_borrow(&self.all_storages, &self.thread_pool).map_err(run_borrow_error)?
+            }
+            #[cfg(not(feature = "parallel"))]
+            {
+                S::try_borrow(&self.all_storages).map_err(run_borrow_error)?
+            }
+        }))
+    }
+    #[doc = "Borrows the requested storages and runs the function.  
+Data can be passed to the function, this always has to be a single type but you can use a tuple if needed.  
+Unwraps errors.
+
+You can use:
+* [View]\\<T\\> for a shared access to `T` storage
+* [ViewMut]\\<T\\> for an exclusive access to `T` storage
+* [EntitiesView] for a shared access to the entity storage
+* [EntitiesViewMut] for an exclusive reference to the entity storage
+* [AllStoragesViewMut] for an exclusive access to the storage of all components, ⚠️ can't coexist with any other storage borrow
+* [UniqueView]\\<T\\> for a shared access to a `T` unique storage
+* [UniqueViewMut]\\<T\\> for an exclusive access to a `T` unique storage
+* `Option<V>` with one or multiple views for fallible access to one or more storages"]
+    #[cfg_attr(
+        all(feature = "parallel", docsrs),
+        doc = "* <span style=\"display: table;color: #2f2f2f;background-color: #C4ECFF;border-width: 1px;border-style: solid;border-color: #7BA5DB;padding: 3px;margin-bottom: 5px; font-size: 90%\">This is supported on <strong><code style=\"background-color: #C4ECFF\">feature=\"parallel\"</code></strong> only:</span>"
+    )]
+    #[cfg_attr(
+        all(feature = "parallel", docsrs),
+        doc = "    * [ThreadPoolView] for a shared access to the `ThreadPool` used by the [World]"
+    )]
+    #[cfg_attr(
+        all(feature = "parallel", not(docsrs)),
+        doc = "* [ThreadPoolView] for a shared access to the `ThreadPool` used by the [World]"
+    )]
+    #[cfg_attr(
+        not(feature = "parallel"),
+        doc = "* ThreadPool: must activate the *parallel* feature"
+    )]
+    #[cfg_attr(
+        all(feature = "non_send", docsrs),
+        doc = "* <span style=\"display: table;color: #2f2f2f;background-color: #C4ECFF;border-width: 1px;border-style: solid;border-color: #7BA5DB;padding: 3px;margin-bottom: 5px; font-size: 90%\">This is supported on <strong><code style=\"background-color: #C4ECFF\">feature=\"non_send\"</code></strong> only:</span>"
+    )]
+    #[cfg_attr(
+        all(feature = "non_send", docsrs),
+        doc = "    * [NonSend]<[View]\\<T\\>> for a shared access to a `T` storage where `T` isn't `Send`
+    * [NonSend]<[ViewMut]\\<T\\>> for an exclusive access to a `T` storage where `T` isn't `Send`  
+[NonSend] and [UniqueView]/[UniqueViewMut] can be used together to access a `!Send` unique storage."
+    )]
+    #[cfg_attr(
+        all(feature = "non_send", not(docsrs)),
+        doc = "* [NonSend]<[View]\\<T\\>> for a shared access to a `T` storage where `T` isn't `Send`
+* [NonSend]<[ViewMut]\\<T\\>> for an exclusive access to a `T` storage where `T` isn't `Send`  
+[NonSend] and [UniqueView]/[UniqueViewMut] can be used together to access a `!Send` unique storage."
+    )]
+    #[cfg_attr(
+        not(feature = "non_send"),
+        doc = "* NonSend: must activate the *non_send* feature"
+    )]
+    #[cfg_attr(
+        all(feature = "non_sync", docsrs),
+        doc = "* <span style=\"display: table;color: #2f2f2f;background-color: #C4ECFF;border-width: 1px;border-style: solid;border-color: #7BA5DB;padding: 3px;margin-bottom: 5px; font-size: 90%\">This is supported on <strong><code style=\"background-color: #C4ECFF\">feature=\"non_sync\"</code></strong> only:</span>"
+    )]
+    #[cfg_attr(
+        all(feature = "non_sync", docsrs),
+        doc = "    * [NonSync]<[View]\\<T\\>> for a shared access to a `T` storage where `T` isn't `Sync`
+    * [NonSync]<[ViewMut]\\<T\\>> for an exclusive access to a `T` storage where `T` isn't `Sync`  
+[NonSync] and [UniqueView]/[UniqueViewMut] can be used together to access a `!Sync` unique storage."
+    )]
+    #[cfg_attr(
+        all(feature = "non_sync", not(docsrs)),
+        doc = "* [NonSync]<[View]\\<T\\>> for a shared access to a `T` storage where `T` isn't `Sync`
+* [NonSync]<[ViewMut]\\<T\\>> for an exclusive access to a `T` storage where `T` isn't `Sync`  
+[NonSync] and [UniqueView]/[UniqueViewMut] can be used together to access a `!Sync` unique storage."
+    )]
+    #[cfg_attr(
+        not(feature = "non_sync"),
+        doc = "* NonSync: must activate the *non_sync* feature"
+    )]
+    #[cfg_attr(
+        all(feature = "non_sync", docsrs),
+        doc = "* <span style=\"display: table;color: #2f2f2f;background-color: #C4ECFF;border-width: 1px;border-style: solid;border-color: #7BA5DB;padding: 3px;margin-bottom: 5px; font-size: 90%\">This is supported on <strong><code style=\"background-color: #C4ECFF\">feature=\"non_send\"</code> and <code style=\"background-color: #C4ECFF\">feature=\"non_sync\"</code></strong> only:</span>"
+    )]
+    #[cfg_attr(
+        all(feature = "non_send", feature = "non_sync", docsrs),
+        doc = "    * [NonSendSync]<[View]\\<T\\>> for a shared access to a `T` storage where `T` isn't `Send` nor `Sync`
+    * [NonSendSync]<[ViewMut]\\<T\\>> for an exclusive access to a `T` storage where `T` isn't `Send` nor `Sync`  
+[NonSendSync] and [UniqueView]/[UniqueViewMut] can be used together to access a `!Send + !Sync` unique storage."
+    )]
+    #[cfg_attr(
+        all(feature = "non_send", feature = "non_sync", not(docsrs)),
+        doc = "* [NonSendSync]<[View]\\<T\\>> for a shared access to a `T` storage where `T` isn't `Send` nor `Sync`
+* [NonSendSync]<[ViewMut]\\<T\\>> for an exclusive access to a `T` storage where `T` isn't `Send` nor `Sync`  
+[NonSendSync] and [UniqueView]/[UniqueViewMut] can be used together to access a `!Send + !Sync` unique storage."
+    )]
+    #[cfg_attr(
+        not(all(feature = "non_send", feature = "non_sync")),
+        doc = "* NonSendSync: must activate the *non_send* and *non_sync* features"
+    )]
+    #[doc = "
+### Borrows
+
+- [AllStorages] (exclusive) when requesting [AllStoragesViewMut]
+- [AllStorages] (shared) + storage (exclusive or shared) for all other views
+
+### Errors
+
+- [AllStorages] borrow failed.
+- Storage borrow failed.
+- Unique storage did not exist.
+- Error returned by user.
+
+### Example
+```
+use shipyard::{EntityId, Get, ViewMut, World};
+
+fn sys1((entity, [x, y]): (EntityId, [f32; 2]), mut positions: ViewMut<[f32; 2]>) {
+    if let Ok(pos) = (&mut positions).get(entity) {
+        *pos = [x, y];
+    }
+}
+
+let world = World::new();
+
+world.run_with_data(sys1, (EntityId::dead(), [0., 0.]));
+```
+[AllStorages]: struct.AllStorages.html
+[EntitiesView]: struct.Entities.html
+[EntitiesViewMut]: struct.Entities.html
+[AllStoragesViewMut]: struct.AllStorages.html
+[World]: struct.World.html
+[View]: struct.View.html
+[ViewMut]: struct.ViewMut.html
+[UniqueView]: struct.UniqueView.html
+[UniqueViewMut]: struct.UniqueViewMut.html"]
+    #[cfg_attr(
+        feature = "parallel",
+        doc = "[ThreadPoolView]: struct.ThreadPoolView.html"
+    )]
+    #[cfg_attr(feature = "non_send", doc = "[NonSend]: struct.NonSend.html")]
+    #[cfg_attr(feature = "non_sync", doc = "[NonSync]: struct.NonSync.html")]
+    #[cfg_attr(
+        all(feature = "non_send", feature = "non_sync"),
+        doc = "[NonSendSync]: struct.NonSendSync.html"
+    )]
+    #[cfg(feature = "panic")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "panic")))]
+    #[track_caller]
+    pub fn run_with_data<'s, Data, B, R, S: crate::system::System<'s, (Data,), B, R>>(
+        &'s self,
+        s: S,
+        data: Data,
+    ) -> R {
+        self.try_run_with_data(s, data).unwrap()
+    }
+    #[doc = "Borrows the requested storages and runs the function.
+
+You can use:
+* [View]\\<T\\> for a shared access to `T` storage
+* [ViewMut]\\<T\\> for an exclusive access to `T` storage
+* [EntitiesView] for a shared access to the entity storage
+* [EntitiesViewMut] for an exclusive reference to the entity storage
+* [AllStoragesViewMut] for an exclusive access to the storage of all components, ⚠️ can't coexist with any other storage borrow
+* [UniqueView]\\<T\\> for a shared access to a `T` unique storage
+* [UniqueViewMut]\\<T\\> for an exclusive access to a `T` unique storage
+* `Option<V>` with one or multiple views for fallible access to one or more storages"]
+    #[cfg_attr(
+        all(feature = "parallel", docsrs),
+        doc = "* <span style=\"display: table;color: #2f2f2f;background-color: #C4ECFF;border-width: 1px;border-style: solid;border-color: #7BA5DB;padding: 3px;margin-bottom: 5px; font-size: 90%\">This is supported on <strong><code style=\"background-color: #C4ECFF\">feature=\"parallel\"</code></strong> only:</span>"
+    )]
+    #[cfg_attr(
+        all(feature = "parallel", docsrs),
+        doc = "    * [ThreadPoolView] for a shared access to the `ThreadPool` used by the [World]"
+    )]
+    #[cfg_attr(
+        all(feature = "parallel", not(docsrs)),
+        doc = "* [ThreadPoolView] for a shared access to the `ThreadPool` used by the [World]"
+    )]
+    #[cfg_attr(
+        not(feature = "parallel"),
+        doc = "* ThreadPool: must activate the *parallel* feature"
+    )]
+    #[cfg_attr(
+        all(feature = "non_send", docsrs),
+        doc = "* <span style=\"display: table;color: #2f2f2f;background-color: #C4ECFF;border-width: 1px;border-style: solid;border-color: #7BA5DB;padding: 3px;margin-bottom: 5px; font-size: 90%\">This is supported on <strong><code style=\"background-color: #C4ECFF\">feature=\"non_send\"</code></strong> only:</span>"
+    )]
+    #[cfg_attr(
+        all(feature = "non_send", docsrs),
+        doc = "    * [NonSend]<[View]\\<T\\>> for a shared access to a `T` storage where `T` isn't `Send`
+    * [NonSend]<[ViewMut]\\<T\\>> for an exclusive access to a `T` storage where `T` isn't `Send`  
+[NonSend] and [UniqueView]/[UniqueViewMut] can be used together to access a `!Send` unique storage."
+    )]
+    #[cfg_attr(
+        all(feature = "non_send", not(docsrs)),
+        doc = "* [NonSend]<[View]\\<T\\>> for a shared access to a `T` storage where `T` isn't `Send`
+* [NonSend]<[ViewMut]\\<T\\>> for an exclusive access to a `T` storage where `T` isn't `Send`  
+[NonSend] and [UniqueView]/[UniqueViewMut] can be used together to access a `!Send` unique storage."
+    )]
+    #[cfg_attr(
+        not(feature = "non_send"),
+        doc = "* NonSend: must activate the *non_send* feature"
+    )]
+    #[cfg_attr(
+        all(feature = "non_sync", docsrs),
+        doc = "* <span style=\"display: table;color: #2f2f2f;background-color: #C4ECFF;border-width: 1px;border-style: solid;border-color: #7BA5DB;padding: 3px;margin-bottom: 5px; font-size: 90%\">This is supported on <strong><code style=\"background-color: #C4ECFF\">feature=\"non_sync\"</code></strong> only:</span>"
+    )]
+    #[cfg_attr(
+        all(feature = "non_sync", docsrs),
+        doc = "    * [NonSync]<[View]\\<T\\>> for a shared access to a `T` storage where `T` isn't `Sync`
+    * [NonSync]<[ViewMut]\\<T\\>> for an exclusive access to a `T` storage where `T` isn't `Sync`  
+[NonSync] and [UniqueView]/[UniqueViewMut] can be used together to access a `!Sync` unique storage."
+    )]
+    #[cfg_attr(
+        all(feature = "non_sync", not(docsrs)),
+        doc = "* [NonSync]<[View]\\<T\\>> for a shared access to a `T` storage where `T` isn't `Sync`
+* [NonSync]<[ViewMut]\\<T\\>> for an exclusive access to a `T` storage where `T` isn't `Sync`  
+[NonSync] and [UniqueView]/[UniqueViewMut] can be used together to access a `!Sync` unique storage."
+    )]
+    #[cfg_attr(
+        not(feature = "non_sync"),
+        doc = "* NonSync: must activate the *non_sync* feature"
+    )]
+    #[cfg_attr(
+        all(feature = "non_sync", docsrs),
+        doc = "* <span style=\"display: table;color: #2f2f2f;background-color: #C4ECFF;border-width: 1px;border-style: solid;border-color: #7BA5DB;padding: 3px;margin-bottom: 5px; font-size: 90%\">This is supported on <strong><code style=\"background-color: #C4ECFF\">feature=\"non_send\"</code> and <code style=\"background-color: #C4ECFF\">feature=\"non_sync\"</code></strong> only:</span>"
+    )]
+    #[cfg_attr(
+        all(feature = "non_send", feature = "non_sync", docsrs),
+        doc = "    * [NonSendSync]<[View]\\<T\\>> for a shared access to a `T` storage where `T` isn't `Send` nor `Sync`
+    * [NonSendSync]<[ViewMut]\\<T\\>> for an exclusive access to a `T` storage where `T` isn't `Send` nor `Sync`  
+[NonSendSync] and [UniqueView]/[UniqueViewMut] can be used together to access a `!Send + !Sync` unique storage."
+    )]
+    #[cfg_attr(
+        all(feature = "non_send", feature = "non_sync", not(docsrs)),
+        doc = "* [NonSendSync]<[View]\\<T\\>> for a shared access to a `T` storage where `T` isn't `Send` nor `Sync`
+* [NonSendSync]<[ViewMut]\\<T\\>> for an exclusive access to a `T` storage where `T` isn't `Send` nor `Sync`  
+[NonSendSync] and [UniqueView]/[UniqueViewMut] can be used together to access a `!Send + !Sync` unique storage."
+    )]
+    #[cfg_attr(
+        not(all(feature = "non_send", feature = "non_sync")),
+        doc = "* NonSendSync: must activate the *non_send* and *non_sync* features"
+    )]
+    #[doc = "
+### Borrows
+
+- [AllStorages] (exclusive) when requesting [AllStoragesViewMut]
+- [AllStorages] (shared) + storage (exclusive or shared) for all other views
+
+### Errors
+
+- [AllStorages] borrow failed.
+- Storage borrow failed.
+- Unique storage did not exist.
+- Error returned by user.
+
+### Example
+```
+use shipyard::{View, ViewMut, World};
+
+fn sys1(i32s: View<i32>) -> i32 {
+    0
+}
+
+let world = World::new();
+
+world
+    .try_run(|usizes: View<usize>, mut u32s: ViewMut<u32>| {
+        // -- snip --
+    })
+    .unwrap();
+
+let i = world.try_run(sys1).unwrap();
+```
+[AllStorages]: struct.AllStorages.html
+[EntitiesView]: struct.Entities.html
+[EntitiesViewMut]: struct.Entities.html
+[AllStoragesViewMut]: struct.AllStorages.html
+[World]: struct.World.html
+[View]: struct.View.html
+[ViewMut]: struct.ViewMut.html
+[UniqueView]: struct.UniqueView.html
+[UniqueViewMut]: struct.UniqueViewMut.html"]
+    #[cfg_attr(
+        feature = "parallel",
+        doc = "[ThreadPoolView]: struct.ThreadPoolView.html"
+    )]
+    #[cfg_attr(feature = "non_send", doc = "[NonSend]: struct.NonSend.html")]
+    #[cfg_attr(feature = "non_sync", doc = "[NonSync]: struct.NonSync.html")]
+    #[cfg_attr(
+        all(feature = "non_send", feature = "non_sync"),
+        doc = "[NonSendSync]: struct.NonSendSync.html"
+    )]
+    pub fn try_run<'s, B, R, S: crate::system::System<'s, (), B, R>>(
+        &'s self,
+        s: S,
+    ) -> Result<R, error::Run> {
+        Ok(s.run((), {
+            #[cfg(feature = "parallel")]
+            {
+                S::try_borrow(&self.all_storages, &self.thread_pool).map_err(run_borrow_error)?
+            }
+            #[cfg(not(feature = "parallel"))]
+            {
+                S::try_borrow(&self.all_storages).map_err(run_borrow_error)?
+            }
+        }))
+    }
+    #[doc = "Borrows the requested storages and runs the function.  
+Unwraps errors.
+
+You can use:
+* [View]\\<T\\> for a shared access to `T` storage
+* [ViewMut]\\<T\\> for an exclusive access to `T` storage
+* [EntitiesView] for a shared access to the entity storage
+* [EntitiesViewMut] for an exclusive reference to the entity storage
+* [AllStoragesViewMut] for an exclusive access to the storage of all components, ⚠️ can't coexist with any other storage borrow
+* [UniqueView]\\<T\\> for a shared access to a `T` unique storage
+* [UniqueViewMut]\\<T\\> for an exclusive access to a `T` unique storage
+* `Option<V>` with one or multiple views for fallible access to one or more storages"]
+    #[cfg_attr(
+        all(feature = "parallel", docsrs),
+        doc = "* <span style=\"display: table;color: #2f2f2f;background-color: #C4ECFF;border-width: 1px;border-style: solid;border-color: #7BA5DB;padding: 3px;margin-bottom: 5px; font-size: 90%\">This is supported on <strong><code style=\"background-color: #C4ECFF\">feature=\"parallel\"</code></strong> only:</span>"
+    )]
+    #[cfg_attr(
+        all(feature = "parallel", docsrs),
+        doc = "    * [ThreadPoolView] for a shared access to the `ThreadPool` used by the [World]"
+    )]
+    #[cfg_attr(
+        all(feature = "parallel", not(docsrs)),
+        doc = "* [ThreadPoolView] for a shared access to the `ThreadPool` used by the [World]"
+    )]
+    #[cfg_attr(
+        not(feature = "parallel"),
+        doc = "* ThreadPool: must activate the *parallel* feature"
+    )]
+    #[cfg_attr(
+        all(feature = "non_send", docsrs),
+        doc = "* <span style=\"display: table;color: #2f2f2f;background-color: #C4ECFF;border-width: 1px;border-style: solid;border-color: #7BA5DB;padding: 3px;margin-bottom: 5px; font-size: 90%\">This is supported on <strong><code style=\"background-color: #C4ECFF\">feature=\"non_send\"</code></strong> only:</span>"
+    )]
+    #[cfg_attr(
+        all(feature = "non_send", docsrs),
+        doc = "    * [NonSend]<[View]\\<T\\>> for a shared access to a `T` storage where `T` isn't `Send`
+    * [NonSend]<[ViewMut]\\<T\\>> for an exclusive access to a `T` storage where `T` isn't `Send`  
+[NonSend] and [UniqueView]/[UniqueViewMut] can be used together to access a `!Send` unique storage."
+    )]
+    #[cfg_attr(
+        all(feature = "non_send", not(docsrs)),
+        doc = "* [NonSend]<[View]\\<T\\>> for a shared access to a `T` storage where `T` isn't `Send`
+* [NonSend]<[ViewMut]\\<T\\>> for an exclusive access to a `T` storage where `T` isn't `Send`  
+[NonSend] and [UniqueView]/[UniqueViewMut] can be used together to access a `!Send` unique storage."
+    )]
+    #[cfg_attr(
+        not(feature = "non_send"),
+        doc = "* NonSend: must activate the *non_send* feature"
+    )]
+    #[cfg_attr(
+        all(feature = "non_sync", docsrs),
+        doc = "* <span style=\"display: table;color: #2f2f2f;background-color: #C4ECFF;border-width: 1px;border-style: solid;border-color: #7BA5DB;padding: 3px;margin-bottom: 5px; font-size: 90%\">This is supported on <strong><code style=\"background-color: #C4ECFF\">feature=\"non_sync\"</code></strong> only:</span>"
+    )]
+    #[cfg_attr(
+        all(feature = "non_sync", docsrs),
+        doc = "    * [NonSync]<[View]\\<T\\>> for a shared access to a `T` storage where `T` isn't `Sync`
+    * [NonSync]<[ViewMut]\\<T\\>> for an exclusive access to a `T` storage where `T` isn't `Sync`  
+[NonSync] and [UniqueView]/[UniqueViewMut] can be used together to access a `!Sync` unique storage."
+    )]
+    #[cfg_attr(
+        all(feature = "non_sync", not(docsrs)),
+        doc = "* [NonSync]<[View]\\<T\\>> for a shared access to a `T` storage where `T` isn't `Sync`
+* [NonSync]<[ViewMut]\\<T\\>> for an exclusive access to a `T` storage where `T` isn't `Sync`  
+[NonSync] and [UniqueView]/[UniqueViewMut] can be used together to access a `!Sync` unique storage."
+    )]
+    #[cfg_attr(
+        not(feature = "non_sync"),
+        doc = "* NonSync: must activate the *non_sync* feature"
+    )]
+    #[cfg_attr(
+        all(feature = "non_sync", docsrs),
+        doc = "* <span style=\"display: table;color: #2f2f2f;background-color: #C4ECFF;border-width: 1px;border-style: solid;border-color: #7BA5DB;padding: 3px;margin-bottom: 5px; font-size: 90%\">This is supported on <strong><code style=\"background-color: #C4ECFF\">feature=\"non_send\"</code> and <code style=\"background-color: #C4ECFF\">feature=\"non_sync\"</code></strong> only:</span>"
+    )]
+    #[cfg_attr(
+        all(feature = "non_send", feature = "non_sync", docsrs),
+        doc = "    * [NonSendSync]<[View]\\<T\\>> for a shared access to a `T` storage where `T` isn't `Send` nor `Sync`
+    * [NonSendSync]<[ViewMut]\\<T\\>> for an exclusive access to a `T` storage where `T` isn't `Send` nor `Sync`  
+[NonSendSync] and [UniqueView]/[UniqueViewMut] can be used together to access a `!Send + !Sync` unique storage."
+    )]
+    #[cfg_attr(
+        all(feature = "non_send", feature = "non_sync", not(docsrs)),
+        doc = "* [NonSendSync]<[View]\\<T\\>> for a shared access to a `T` storage where `T` isn't `Send` nor `Sync`
+* [NonSendSync]<[ViewMut]\\<T\\>> for an exclusive access to a `T` storage where `T` isn't `Send` nor `Sync`  
+[NonSendSync] and [UniqueView]/[UniqueViewMut] can be used together to access a `!Send + !Sync` unique storage."
+    )]
+    #[cfg_attr(
+        not(all(feature = "non_send", feature = "non_sync")),
+        doc = "* NonSendSync: must activate the *non_send* and *non_sync* features"
+    )]
+    #[doc = "
+### Borrows
+
+- [AllStorages] (exclusive) when requesting [AllStoragesViewMut]
+- [AllStorages] (shared) + storage (exclusive or shared) for all other views
+
+### Errors
+
+- [AllStorages] borrow failed.
+- Storage borrow failed.
+- Unique storage did not exist.
+- Error returned by user.
+
+### Example
+```
+use shipyard::{View, ViewMut, World};
+
+fn sys1(i32s: View<i32>) -> i32 {
+    0
+}
+
+let world = World::new();
+
+world.run(|usizes: View<usize>, mut u32s: ViewMut<u32>| {
+    // -- snip --
+});
+
+let i = world.run(sys1);
+```
+[AllStorages]: struct.AllStorages.html
+[EntitiesView]: struct.Entities.html
+[EntitiesViewMut]: struct.Entities.html
+[AllStoragesViewMut]: struct.AllStorages.html
+[World]: struct.World.html
+[View]: struct.View.html
+[ViewMut]: struct.ViewMut.html
+[UniqueView]: struct.UniqueView.html
+[UniqueViewMut]: struct.UniqueViewMut.html"]
+    #[cfg_attr(
+        feature = "parallel",
+        doc = "[ThreadPoolView]: struct.ThreadPoolView.html"
+    )]
+    #[cfg_attr(feature = "non_send", doc = "[NonSend]: struct.NonSend.html")]
+    #[cfg_attr(feature = "non_sync", doc = "[NonSync]: struct.NonSync.html")]
+    #[cfg_attr(
+        all(feature = "non_send", feature = "non_sync"),
+        doc = "[NonSendSync]: struct.NonSendSync.html"
+    )]
+    #[cfg(feature = "panic")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "panic")))]
+    #[track_caller]
+    pub fn run<'s, B, R, S: crate::system::System<'s, (), B, R>>(&'s self, s: S) -> R {
+        self.try_run(s).unwrap()
+    }
+    /// Modifies the current default workload to `name`.
+    ///
+    /// ### Borrows
+    ///
+    /// - Scheduler (exclusive)
+    ///
+    /// ### Errors
+    ///
+    /// - Scheduler borrow failed.
+    /// - Workload did not exist.
+    pub fn try_set_default_workload(
+        &self,
+        name: impl Into<Cow<'static, str>>,
+    ) -> Result<(), error::SetDefaultWorkload> {
+        if let Ok(mut scheduler) = self.scheduler.try_borrow_mut() {
+            if let Some(workload) = scheduler.workloads.get(&name.into()) {
+                scheduler.default = workload.clone();
+                Ok(())
+            } else {
+                Err(error::SetDefaultWorkload::MissingWorkload)
+            }
+        } else {
+            Err(error::SetDefaultWorkload::Borrow)
+        }
+    }
+    /// Modifies the current default workload to `name`.  
+    /// Unwraps errors.
+    ///
+    /// ### Borrows
+    ///
+    /// - Scheduler (exclusive)
+    ///
+    /// ### Errors
+    ///
+    /// - Scheduler borrow failed.
+    /// - Workload did not exist.
+    #[cfg(feature = "panic")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "panic")))]
+    #[track_caller]
+    pub fn set_default_workload(&self, name: impl Into<Cow<'static, str>>) {
+        let name = name.into();
+
+        if let Err(err) = self.try_set_default_workload(name.clone()) {
+            panic!("Cannot set {} as default workload: {:?}", name, err);
+        }
+    }
+    /// Enables or disables the `name` workload, workloads are enabled when they're built.  
+    /// [World::run_default] skips the batches of disabled workloads, [World::run_workload] still runs them.
+    ///
+    /// ### Borrows
+    ///
+    /// - Scheduler (exclusive)
+    ///
+    /// ### Errors
+    ///
+    /// - Scheduler borrow failed.
+    /// - Workload did not exist.
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{system, UniqueViewMut, World};
+    ///
+    /// fn simulate(mut steps: UniqueViewMut<u32>) {
+    ///     *steps += 1;
+    /// }
+    ///
+    /// let world = World::new();
+    /// world.add_unique(0u32);
+    ///
+    /// world
+    ///     .add_workload("Simulation")
+    ///     .with_system(system!(simulate))
+    ///     .build();
+    ///
+    /// world.set_workload_enabled("Simulation", false);
+    /// world.run_default();
+    /// assert_eq!(*world.borrow::<UniqueViewMut<u32>>(), 0);
+    ///
+    /// world.set_workload_enabled("Simulation", true);
+    /// world.run_default();
+    /// assert_eq!(*world.borrow::<UniqueViewMut<u32>>(), 1);
+    /// ```
+    ///
+    /// [World::run_default]: struct.World.html#method.run_default
+    /// [World::run_workload]: struct.World.html#method.run_workload
+    pub fn try_set_workload_enabled(
+        &self,
+        name: impl AsRef<str>,
+        enabled: bool,
+    ) -> Result<(), error::SetWorkloadEnabled> {
+        let mut scheduler = self
+            .scheduler
+            .try_borrow_mut()
+            .map_err(|_| error::SetWorkloadEnabled::Borrow)?;
+        let workload = scheduler
+            .workloads
+            .get(name.as_ref())
+            .cloned()
+            .ok_or(error::SetWorkloadEnabled::MissingWorkload)?;
+
+        for index in workload {
+            scheduler.enabled[index] = enabled;
+        }
+
+        Ok(())
+    }
+    /// Enables or disables the `name` workload, workloads are enabled when they're built.  
+    /// [World::run_default] skips the batches of disabled workloads, [World::run_workload] still runs them.  
+    /// Unwraps errors.
+    ///
+    /// ### Borrows
+    ///
+    /// - Scheduler (exclusive)
+    ///
+    /// ### Errors
+    ///
+    /// - Scheduler borrow failed.
+    /// - Workload did not exist.
+    ///
+    /// [World::run_default]: struct.World.html#method.run_default
+    /// [World::run_workload]: struct.World.html#method.run_workload
+    #[cfg(feature = "panic")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "panic")))]
+    #[track_caller]
+    pub fn set_workload_enabled(&self, name: impl AsRef<str>, enabled: bool) {
+        if let Err(err) = self.try_set_workload_enabled(name.as_ref(), enabled) {
+            panic!("Cannot enable or disable {}: {:?}", name.as_ref(), err);
+        }
+    }
+    /// Sets the seed of the systems' [Rng], it's 0 by default.  
+    /// Each system's sequence restarts from `seed` and its name.
+    ///
+    /// ### Borrows
+    ///
+    /// - Scheduler (exclusive)
+    ///
+    /// ### Errors
+    ///
+    /// - Scheduler borrow failed.
+    ///
+    /// [Rng]: struct.Rng.html
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn try_set_seed(&self, seed: u64) -> Result<(), error::Borrow> {
+        self.scheduler.try_borrow_mut()?.set_seed(seed);
+        Ok(())
+    }
+    /// Sets the seed of the systems' [Rng], it's 0 by default.  
+    /// Each system's sequence restarts from `seed` and its name.  
+    /// Unwraps errors.
+    ///
+    /// ### Borrows
+    ///
+    /// - Scheduler (exclusive)
+    ///
+    /// ### Errors
+    ///
+    /// - Scheduler borrow failed.
+    ///
+    /// [Rng]: struct.Rng.html
+    #[cfg(all(feature = "std", feature = "panic"))]
+    #[cfg_attr(docsrs, doc(cfg(all(feature = "std", feature = "panic"))))]
+    #[track_caller]
+    pub fn set_seed(&self, seed: u64) {
+        self.try_set_seed(seed).unwrap();
+    }
+    /// A workload is a collection of systems. They will execute as much in parallel as possible.  
+    /// They are evaluated first to last when they can't be parallelized.  
+    /// The default workload will automatically be set to the first workload added.
+    ///
+    /// ### Borrows
+    ///
+    /// - Scheduler (exclusive)
+    ///
+    /// ### Errors
+    ///
+    /// - Scheduler borrow failed.
+    /// - Workload with an identical name already present.
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{system, EntitiesViewMut, IntoIter, Shiperator, View, ViewMut, World};
+    ///
+    /// fn add(mut usizes: ViewMut<usize>, u32s: View<u32>) {
+    ///     for (x, &y) in (&mut usizes, &u32s).iter() {
+    ///         *x += y as usize;
+    ///     }
+    /// }
+    ///
+    /// fn check(usizes: View<usize>) {
+    ///     let mut iter = usizes.iter();
+    ///     assert_eq!(iter.next(), Some(&1));
+    ///     assert_eq!(iter.next(), Some(&5));
+    ///     assert_eq!(iter.next(), Some(&9));
+    /// }
+    ///
+    /// let world = World::new();
+    ///
+    /// world.run(
+    ///     |mut entities: EntitiesViewMut, mut usizes: ViewMut<usize>, mut u32s: ViewMut<u32>| {
+    ///         entities.add_entity((&mut usizes, &mut u32s), (0, 1));
+    ///         entities.add_entity((&mut usizes, &mut u32s), (2, 3));
+    ///         entities.add_entity((&mut usizes, &mut u32s), (4, 5));
+    ///     },
+    /// );
+    ///
+    /// world
+    ///     .try_add_workload("Add & Check")
+    ///     .unwrap()
+    ///     .with_system(system!(add))
+    ///     .with_system(system!(check))
+    ///     .build();
+    ///
+    /// world.run_default();
+    /// ```
+    pub fn try_add_workload(
+        &self,
+        name: impl Into<Cow<'static, str>>,
+    ) -> Result<WorkloadBuilder<'_>, error::AddWorkload> {
+        if let Ok(scheduler) = self.scheduler.try_borrow_mut() {
+            let name = name.into();
+
+            if scheduler.workloads.contains_key(&name) {
+                Err(error::AddWorkload::AlreadyExists)
+            } else {
+                Ok(WorkloadBuilder::new(scheduler, name))
+            }
+        } else {
+            Err(error::AddWorkload::Borrow)
+        }
+    }
+    /// A workload is a collection of systems. They will execute as much in parallel as possible.  
+    /// They are evaluated first to last when they can't be parallelized.  
+    /// The default workload will automatically be set to the first workload added.  
+    /// Unwraps errors.
+    ///
+    /// ### Borrows
+    ///
+    /// - Scheduler (exclusive)
+    ///
+    /// ### Errors
+    ///
+    /// - Scheduler borrow failed.
+    /// - Workload with an identical name already present.
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{system, EntitiesViewMut, IntoIter, Shiperator, View, ViewMut, World};
+    ///
+    /// fn add(mut usizes: ViewMut<usize>, u32s: View<u32>) {
+    ///     for (x, &y) in (&mut usizes, &u32s).iter() {
+    ///         *x += y as usize;
+    ///     }
+    /// }
+    ///
+    /// fn check(usizes: View<usize>) {
+    ///     let mut iter = usizes.iter();
+    ///     assert_eq!(iter.next(), Some(&1));
+    ///     assert_eq!(iter.next(), Some(&5));
+    ///     assert_eq!(iter.next(), Some(&9));
+    /// }
+    ///
+    /// let world = World::new();
+    ///
+    /// world.run(
+    ///     |mut entities: EntitiesViewMut, mut usizes: ViewMut<usize>, mut u32s: ViewMut<u32>| {
+    ///         entities.add_entity((&mut usizes, &mut u32s), (0, 1));
+    ///         entities.add_entity((&mut usizes, &mut u32s), (2, 3));
+    ///         entities.add_entity((&mut usizes, &mut u32s), (4, 5));
+    ///     },
+    /// );
+    ///
+    /// world
+    ///     .add_workload("Add & Check")
+    ///     .with_system(system!(add))
+    ///     .with_system(system!(check))
+    ///     .build();
+    ///
+    /// world.run_default();
+    /// ```
+    #[cfg(feature = "panic")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "panic")))]
+    #[track_caller]
+    pub fn add_workload(&self, name: impl Into<Cow<'static, str>>) -> WorkloadBuilder<'_> {
+        self.try_add_workload(name).unwrap()
+    }
+    /// Runs the `name` workload.
+    ///
+    /// ### Borrows
+    ///
+    /// - Scheduler (shared)
+    /// - Systems' borrow as they are executed
+    ///
+    /// ### Errors
+    ///
+    /// - Scheduler borrow failed.
+    /// - Workload did not exist.
+    /// - Storage borrow failed.
+    /// - User error returned by system.
+    /// - Called from inside a system.
+    pub fn try_run_workload(&self, name: impl AsRef<str> + Sync) -> Result<(), error::RunWorkload> {
+        let scheduler = self.borrow_scheduler()?;
+        if let Some(range) = scheduler.workloads.get(name.as_ref()) {
+            self.try_run_workload_index(&scheduler, range.clone())
+        } else {
+            Err(error::RunWorkload::MissingWorkload)
+        }
+    }
+    /// Runs the `name` workload.  
+    /// Unwraps error.
+    ///
+    /// ### Borrows
+    ///
+    /// - Scheduler (shared)
+    /// - Systems' borrow as they are executed
+    ///
+    /// ### Errors
+    ///
+    /// - Scheduler borrow failed.
+    /// - Workload did not exist.
+    /// - Storage borrow failed.
+    /// - User error returned by system.
+    /// - Called from inside a system.
+    #[cfg(feature = "panic")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "panic")))]
+    #[track_caller]
+    pub fn run_workload(&self, name: impl AsRef<str> + Sync) {
+        if let Err(err) = self.try_run_workload(name.as_ref()) {
+            panic!("Workload {} failed: {:?}", name.as_ref(), err);
+        }
+    }
+    /// Creates the storages borrowed by the systems of the `name` workload if they don't exist yet.  
+    /// The first run of the workload then doesn't have to create them while its systems run.  
+    /// Uniques are not created and storages of systems made with [WorkloadSystem::from_raw] are only created when they run.  
+    /// In strict mode only registered storages can be borrowed, this method won't create the others.
+    ///
+    /// ### Borrows
+    ///
+    /// - Scheduler (shared)
+    /// - [AllStorages] (shared)
+    ///
+    /// ### Errors
+    ///
+    /// - Scheduler borrow failed.
+    /// - [AllStorages] borrow failed.
+    /// - Workload did not exist.
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{system, AllStoragesViewMut, View, ViewMut, World};
+    ///
+    /// fn gravity(_: ViewMut<f32>, _: View<u32>) {}
+    ///
+    /// let world = World::new();
+    ///
+    /// world
+    ///     .add_workload("Physics")
+    ///     .with_system(system!(gravity))
+    ///     .build();
+    ///
+    /// world.prepare_workload("Physics");
+    ///
+    /// let names = world.borrow::<AllStoragesViewMut>().storage_names();
+    /// assert!(names.iter().any(|&(_, name)| name == "f32"));
+    /// assert!(names.iter().any(|&(_, name)| name == "u32"));
+    /// ```
+    ///
+    /// [AllStorages]: struct.AllStorages.html
+    /// [WorkloadSystem::from_raw]: struct.WorkloadSystem.html#method.from_raw
+    pub fn try_prepare_workload(
+        &self,
+        name: impl AsRef<str>,
+    ) -> Result<(), error::PrepareWorkload> {
+        let scheduler = self
+            .scheduler
+            .try_borrow()
+            .map_err(|_| error::PrepareWorkload::Scheduler)?;
+        let workload = scheduler
+            .workloads
+            .get(name.as_ref())
+            .cloned()
+            .ok_or(error::PrepareWorkload::MissingWorkload)?;
+        let all_storages = self
+            .all_storages
+            .try_borrow()
+            .map_err(error::PrepareWorkload::AllStoragesBorrow)?;
+
+        for index in workload {
+            for &system in scheduler.batch[index].iter() {
+                (scheduler.create_storages[system])(&all_storages);
+            }
+        }
+
+        Ok(())
+    }
+    /// Creates the storages borrowed by the systems of the `name` workload if they don't exist yet.  
+    /// The first run of the workload then doesn't have to create them while its systems run.  
+    /// Uniques are not created and storages of systems made with [WorkloadSystem::from_raw] are only created when they run.  
+    /// In strict mode only registered storages can be borrowed, this method won't create the others.  
+    /// Unwraps errors.
+    ///
+    /// ### Borrows
+    ///
+    /// - Scheduler (shared)
+    /// - [AllStorages] (shared)
+    ///
+    /// ### Errors
+    ///
+    /// - Scheduler borrow failed.
+    /// - [AllStorages] borrow failed.
+    /// - Workload did not exist.
+    ///
+    /// [AllStorages]: struct.AllStorages.html
+    /// [WorkloadSystem::from_raw]: struct.WorkloadSystem.html#method.from_raw
+    #[cfg(feature = "panic")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "panic")))]
+    #[track_caller]
+    pub fn prepare_workload(&self, name: impl AsRef<str>) {
+        if let Err(err) = self.try_prepare_workload(name.as_ref()) {
+            panic!("Cannot prepare workload {}: {:?}", name.as_ref(), err);
+        }
+    }
+    /// Runs the `name` workload, then runs it again as long as `condition` returns `true`.
+    /// `condition` is evaluated between runs, the workload always runs at least once.
+    /// Returns the number of runs.
+    ///
+    /// ### Borrows
+    ///
+    /// - Scheduler (shared)
+    /// - Systems' borrow as they are executed
+    ///
+    /// ### Errors
+    ///
+    /// - Scheduler borrow failed.
+    /// - Workload did not exist.
+    /// - Storage borrow failed.
+    /// - User error returned by system.
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{system, UniqueView, UniqueViewMut, World};
+    ///
+    /// fn relax(mut error: UniqueViewMut<f32>) {
+    ///     *error /= 2.;
+    /// }
+    ///
+    /// let world = World::new();
+    /// world.add_unique(1f32);
+    ///
+    /// world.add_workload("Solver").with_system(system!(relax)).build();
+    ///
+    /// let runs = world
+    ///     .try_run_workload_while("Solver", |world| *world.borrow::<UniqueView<f32>>() > 0.1)
+    ///     .unwrap();
+    ///
+    /// assert_eq!(runs, 4);
+    /// ```
+    pub fn try_run_workload_while(
+        &self,
+        name: impl AsRef<str> + Sync,
+        condition: impl FnMut(&World) -> bool,
+    ) -> Result<usize, error::RunWorkload> {
+        self.try_run_workload_while_max(name, usize::MAX, condition)
+    }
+    /// Runs the `name` workload, then runs it again as long as `condition` returns `true`.
+    /// `condition` is evaluated between runs, the workload always runs at least once.
+    /// Returns the number of runs.  
+    /// Unwraps error.
+    ///
+    /// ### Borrows
+    ///
+    /// - Scheduler (shared)
+    /// - Systems' borrow as they are executed
+    ///
+    /// ### Errors
+    ///
+    /// - Scheduler borrow failed.
+    /// - Workload did not exist.
+    /// - Storage borrow failed.
+    /// - User error returned by system.
+    #[cfg(feature = "panic")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "panic")))]
+    #[track_caller]
+    pub fn run_workload_while(
+        &self,
+        name: impl AsRef<str> + Sync,
+        condition: impl FnMut(&World) -> bool,
+    ) -> usize {
+        match self.try_run_workload_while(name.as_ref(), condition) {
+            Ok(runs) => runs,
+            Err(err) => panic!("Workload {} failed: {:?}", name.as_ref(), err),
+        }
+    }
+    /// Runs the `name` workload, then runs it again as long as `condition` returns `true`,
+    /// at most `max_runs` times in total.
+    /// `condition` is evaluated between runs.
+    /// Returns the number of runs, `max_runs` means the condition might still hold.
+    ///
+    /// ### Borrows
+    ///
+    /// - Scheduler (shared)
+    /// - Systems' borrow as they are executed
+    ///
+    /// ### Errors
+    ///
+    /// - Scheduler borrow failed.
+    /// - Workload did not exist.
+    /// - Storage borrow failed.
+    /// - User error returned by system.
+    pub fn try_run_workload_while_max(
+        &self,
+        name: impl AsRef<str> + Sync,
+        max_runs: usize,
+        mut condition: impl FnMut(&World) -> bool,
+    ) -> Result<usize, error::RunWorkload> {
+        let scheduler = self.borrow_scheduler()?;
+        let range = scheduler
+            .workloads
+            .get(name.as_ref())
+            .ok_or(error::RunWorkload::MissingWorkload)?
+            .clone();
+
+        let mut runs = 0;
+        while runs < max_runs {
+            self.try_run_workload_index(&scheduler, range.clone())?;
+            runs += 1;
+
+            if !condition(self) {
+                break;
+            }
+        }
+
+        Ok(runs)
+    }
+    /// Runs the `name` workload, then runs it again as long as `condition` returns `true`,
+    /// at most `max_runs` times in total.
+    /// `condition` is evaluated between runs.
+    /// Returns the number of runs, `max_runs` means the condition might still hold.  
+    /// Unwraps error.
+    ///
+    /// ### Borrows
+    ///
+    /// - Scheduler (shared)
+    /// - Systems' borrow as they are executed
+    ///
+    /// ### Errors
+    ///
+    /// - Scheduler borrow failed.
+    /// - Workload did not exist.
+    /// - Storage borrow failed.
+    /// - User error returned by system.
+    #[cfg(feature = "panic")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "panic")))]
+    #[track_caller]
+    pub fn run_workload_while_max(
+        &self,
+        name: impl AsRef<str> + Sync,
+        max_runs: usize,
+        condition: impl FnMut(&World) -> bool,
+    ) -> usize {
+        match self.try_run_workload_while_max(name.as_ref(), max_runs, condition) {
+            Ok(runs) => runs,
+            Err(err) => panic!("Workload {} failed: {:?}", name.as_ref(), err),
+        }
+    }
+    /// Runs the `name` workload, `token` is checked before each batch and the workload stops if it's cancelled.  
+    /// Returns `false` if the workload was cancelled before all its batches ran.  
+    /// The token can also be added as a unique storage for systems to check it, see [CancelToken].
+    ///
+    /// ### Borrows
+    ///
+    /// - Scheduler (shared)
+    /// - Systems' borrow as they are executed
+    ///
+    /// ### Errors
+    ///
+    /// - Scheduler borrow failed.
+    /// - Workload did not exist.
+    /// - Storage borrow failed.
+    /// - User error returned by system.
+    ///
+    /// [CancelToken]: struct.CancelToken.html
+    pub fn try_run_workload_cancellable(
+        &self,
+        name: impl AsRef<str>,
+        token: &CancelToken,
+    ) -> Result<bool, error::RunWorkload> {
+        self.try_run_workload_until(name.as_ref(), || token.is_cancelled())
+    }
+    /// Runs the `name` workload, `token` is checked before each batch and the workload stops if it's cancelled.  
+    /// Returns `false` if the workload was cancelled before all its batches ran.  
+    /// The token can also be added as a unique storage for systems to check it, see [CancelToken].  
+    /// Unwraps errors.
+    ///
+    /// ### Borrows
+    ///
+    /// - Scheduler (shared)
+    /// - Systems' borrow as they are executed
+    ///
+    /// ### Errors
+    ///
+    /// - Scheduler borrow failed.
+    /// - Workload did not exist.
+    /// - Storage borrow failed.
+    /// - User error returned by system.
+    ///
+    /// [CancelToken]: struct.CancelToken.html
+    #[cfg(feature = "panic")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "panic")))]
+    #[track_caller]
+    pub fn run_workload_cancellable(&self, name: impl AsRef<str>, token: &CancelToken) -> bool {
+        match self.try_run_workload_cancellable(name.as_ref(), token) {
+            Ok(completed) => completed,
+            Err(err) => panic!("Workload {} failed: {:?}", name.as_ref(), err),
+        }
+    }
+    /// Runs the `name` workload, no new batch starts once `timeout` has elapsed.  
+    /// Returns `false` if the workload timed out before all its batches ran.  
+    /// A batch already running isn't interrupted, the workload can run longer than `timeout`.
+    ///
+    /// ### Borrows
+    ///
+    /// - Scheduler (shared)
+    /// - Systems' borrow as they are executed
+    ///
+    /// ### Errors
+    ///
+    /// - Scheduler borrow failed.
+    /// - Workload did not exist.
+    /// - Storage borrow failed.
+    /// - User error returned by system.
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn try_run_workload_timeout(
+        &self,
+        name: impl AsRef<str>,
+        timeout: Duration,
+    ) -> Result<bool, error::RunWorkload> {
+        let start = std::time::Instant::now();
+
+        self.try_run_workload_until(name.as_ref(), || start.elapsed() >= timeout)
+    }
+    /// Runs the `name` workload, no new batch starts once `timeout` has elapsed.  
+    /// Returns `false` if the workload timed out before all its batches ran.  
+    /// A batch already running isn't interrupted, the workload can run longer than `timeout`.  
+    /// Unwraps errors.
+    ///
+    /// ### Borrows
+    ///
+    /// - Scheduler (shared)
+    /// - Systems' borrow as they are executed
+    ///
+    /// ### Errors
+    ///
+    /// - Scheduler borrow failed.
+    /// - Workload did not exist.
+    /// - Storage borrow failed.
+    /// - User error returned by system.
+    #[cfg(all(feature = "std", feature = "panic"))]
+    #[cfg_attr(docsrs, doc(cfg(all(feature = "std", feature = "panic"))))]
+    #[track_caller]
+    pub fn run_workload_timeout(&self, name: impl AsRef<str>, timeout: Duration) -> bool {
+        match self.try_run_workload_timeout(name.as_ref(), timeout) {
+            Ok(completed) => completed,
+            Err(err) => panic!("Workload {} failed: {:?}", name.as_ref(), err),
+        }
+    }
+    /// Runs the `name` workload and measures how long each of its systems took.  
+    /// The report lists the systems batch by batch, in the order they were scheduled.
+    ///
+    /// ### Borrows
+    ///
+    /// - Scheduler (shared)
+    /// - Systems' borrow as they are executed
+    ///
+    /// ### Errors
+    ///
+    /// - Scheduler borrow failed.
+    /// - Workload did not exist.
+    /// - Storage borrow failed.
+    /// - User error returned by system.
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn try_run_workload_timed(
+        &self,
+        name: impl AsRef<str>,
+    ) -> Result<WorkloadReport, error::RunWorkload> {
+        let scheduler = self.borrow_scheduler()?;
+        let workload = scheduler
+            .workloads
+            .get(name.as_ref())
+            .cloned()
+            .ok_or(error::RunWorkload::MissingWorkload)?;
+
+        let start = std::time::Instant::now();
+
+        let mut batches = Vec::with_capacity(workload.len());
+        let mut result = Ok(());
+        for index in workload {
+            let timings = parking_lot::Mutex::new(Vec::with_capacity(scheduler.batch[index].len()));
+
+            let batch_result = self.try_run_batch_with(&scheduler, index, |system| {
+                let system_start = std::time::Instant::now();
+                let result = scheduler.run(system, self);
+                timings.lock().push((system, system_start.elapsed()));
+
+                result
+            });
+
+            // systems running in parallel finish in any order
+            let mut timings = timings.into_inner();
+            timings.sort_unstable_by_key(|&(system, _)| {
+                scheduler.batch[index]
+                    .iter()
+                    .position(|&batch_system| batch_system == system)
+            });
+            batches.push(
+                timings
+                    .into_iter()
+                    .map(|(system, duration)| SystemTiming {
+                        name: scheduler.system_infos[system].name,
+                        duration,
+                    })
+                    .collect(),
+            );
+
+            if let Err(err) = batch_result {
+                result = Err(err);
+                break;
+            }
+        }
+
+        self.finish_workload();
+
+        let duration = start.elapsed();
+
+        #[cfg(feature = "stats")]
+        self.refresh_stats(duration);
+
+        result.map(|()| WorkloadReport { duration, batches })
+    }
+    /// Runs the `name` workload and measures how long each of its systems took.  
+    /// The report lists the systems batch by batch, in the order they were scheduled.  
+    /// Unwraps errors.
+    ///
+    /// ### Borrows
+    ///
+    /// - Scheduler (shared)
+    /// - Systems' borrow as they are executed
+    ///
+    /// ### Errors
+    ///
+    /// - Scheduler borrow failed.
+    /// - Workload did not exist.
+    /// - Storage borrow failed.
+    /// - User error returned by system.
+    #[cfg(all(feature = "std", feature = "panic"))]
+    #[cfg_attr(docsrs, doc(cfg(all(feature = "std", feature = "panic"))))]
+    #[track_caller]
+    pub fn run_workload_timed(&self, name: impl AsRef<str>) -> WorkloadReport {
+        match self.try_run_workload_timed(name.as_ref()) {
+            Ok(report) => report,
+            Err(err) => panic!("Workload {} failed: {:?}", name.as_ref(), err),
+        }
+    }
+    /// Runs the `name` workload until it's done or `should_stop` returns `true`, it's evaluated before each batch.
+    fn try_run_workload_until(
+        &self,
+        name: &str,
+        mut should_stop: impl FnMut() -> bool,
+    ) -> Result<bool, error::RunWorkload> {
+        let scheduler = self.borrow_scheduler()?;
+        let workload = scheduler
+            .workloads
+            .get(name)
+            .cloned()
+            .ok_or(error::RunWorkload::MissingWorkload)?;
+
+        #[cfg(feature = "stats")]
+        let start = std::time::Instant::now();
+
+        let mut result = Ok(true);
+        for index in workload {
+            if should_stop() {
+                result = Ok(false);
+                break;
+            }
+
+            if let Err(err) = self.try_run_batch(&scheduler, index) {
+                result = Err(err);
+                break;
+            }
+        }
+
+        self.finish_workload();
+
+        #[cfg(feature = "stats")]
+        self.refresh_stats(start.elapsed());
+
+        result
+    }
+    /// Runs the workload `cursor` points to, starting at its next batch, until the workload is done
+    /// or `should_yield` returns `true`.
+    /// `should_yield` is evaluated between batches, at least one batch runs each call.
+    /// Returns `true` when the workload is done, the cursor then points to the start of the workload again.  
+    /// Heavy workloads can be spread over multiple frames, for example resuming from a `requestAnimationFrame` callback on wasm.
+    ///
+    /// ### Borrows
+    ///
+    /// - Scheduler (shared)
+    /// - Systems' borrow as they are executed
+    ///
+    /// ### Errors
+    ///
+    /// - Scheduler borrow failed.
+    /// - Workload did not exist.
+    /// - Storage borrow failed.
+    /// - User error returned by system, the cursor points to the start of the workload again.
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{system, UniqueViewMut, World, WorkloadCursor};
+    ///
+    /// fn step(mut steps: UniqueViewMut<u32>) {
+    ///     *steps += 1;
+    /// }
+    ///
+    /// let world = World::new();
+    /// world.add_unique(0u32);
+    ///
+    /// world
+    ///     .add_workload("Heavy")
+    ///     .with_system(system!(step))
+    ///     .with_system(system!(step))
+    ///     .with_system(system!(step))
+    ///     .build();
+    ///
+    /// let mut cursor = WorkloadCursor::new("Heavy");
+    ///
+    /// // frame budget of two batches
+    /// let mut budget = 2;
+    /// assert!(!world.try_resume_workload(&mut cursor, || { budget -= 1; budget == 0 }).unwrap());
+    /// assert!(cursor.is_suspended());
+    ///
+    /// let mut budget = 2;
+    /// assert!(world.try_resume_workload(&mut cursor, || { budget -= 1; budget == 0 }).unwrap());
+    /// assert!(!cursor.is_suspended());
+    /// ```
+    pub fn try_resume_workload(
+        &self,
+        cursor: &mut WorkloadCursor,
+        mut should_yield: impl FnMut() -> bool,
+    ) -> Result<bool, error::RunWorkload> {
+        let scheduler = self.borrow_scheduler()?;
+        let range = scheduler
+            .workloads
+            .get(&*cursor.name)
+            .ok_or(error::RunWorkload::MissingWorkload)?
+            .clone();
+
+        #[cfg(feature = "stats")]
+        let start = std::time::Instant::now();
+
+        let mut result = Ok(true);
+        while range.start + cursor.batch < range.end {
+            if let Err(err) = self.try_run_batch(&scheduler, range.start + cursor.batch) {
+                result = Err(err);
+                break;
+            }
+
+            cursor.batch += 1;
+
+            if range.start + cursor.batch < range.end && should_yield() {
+                result = Ok(false);
+                break;
+            }
+        }
 
-### Errors
+        #[cfg(feature = "stats")]
+        {
+            cursor.elapsed += start.elapsed();
+        }
 
-- [AllStorages] borrow failed.
-- Storage borrow failed.
-- Unique storage did not exist.
+        if !matches!(result, Ok(false)) {
+            self.finish_workload();
 
-### Example
-```
-use shipyard::{EntitiesView, View, ViewMut, World};
+            #[cfg(feature = "stats")]
+            self.refresh_stats(cursor.elapsed);
 
-let world = World::new();
+            cursor.reset();
+        }
 
-let u32s = world.borrow::<View<u32>>();
-let (entities, mut usizes) = world.borrow::<(EntitiesView, ViewMut<usize>)>();
-```
-[AllStorages]: struct.AllStorages.html
-[EntitiesView]: struct.Entities.html
-[EntitiesViewMut]: struct.Entities.html
-[AllStoragesViewMut]: struct.AllStorages.html
-[World]: struct.World.html
-[View]: struct.View.html
-[ViewMut]: struct.ViewMut.html
-[UniqueView]: struct.UniqueView.html
-[UniqueViewMut]: struct.UniqueViewMut.html"]
-    #[cfg_attr(
-        feature = "parallel",
-        doc = "[ThreadPoolView]: struct.ThreadPoolView.html"
-    )]
-    #[cfg_attr(feature = "non_send", doc = "[NonSend]: struct.NonSend.html")]
-    #[cfg_attr(feature = "non_sync", doc = "[NonSync]: struct.NonSync.html")]
-    #[cfg_attr(
-        all(feature = "non_send", feature = "non_sync"),
-        doc = "[NonSendSync]: struct.NonSendSync.html"
-    )]
+        result
+    }
+    /// Runs the workload `cursor` points to, starting at its next batch, until the workload is done
+    /// or `should_yield` returns `true`.
+    /// `should_yield` is evaluated between batches, at least one batch runs each call.
+    /// Returns `true` when the workload is done, the cursor then points to the start of the workload again.  
+    /// Heavy workloads can be spread over multiple frames, for example resuming from a `requestAnimationFrame` callback on wasm.  
+    /// Unwraps error.
+    ///
+    /// ### Borrows
+    ///
+    /// - Scheduler (shared)
+    /// - Systems' borrow as they are executed
+    ///
+    /// ### Errors
+    ///
+    /// - Scheduler borrow failed.
+    /// - Workload did not exist.
+    /// - Storage borrow failed.
+    /// - User error returned by system.
+    #[cfg(feature = "panic")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "panic")))]
+    #[track_caller]
+    pub fn resume_workload(
+        &self,
+        cursor: &mut WorkloadCursor,
+        should_yield: impl FnMut() -> bool,
+    ) -> bool {
+        match self.try_resume_workload(cursor, should_yield) {
+            Ok(done) => done,
+            Err(err) => panic!("Workload {} failed: {:?}", cursor.name(), err),
+        }
+    }
+    /// Returns a future running the `name` workload.
+    /// The future runs one batch of systems each time it's polled and yields to the executor between batches,
+    /// ECS ticks can be interleaved with IO on the same runtime.  
+    /// Async systems aren't supported, systems are synchronous and each batch runs to completion when polled.  
+    /// Dropping the future before it completes skips the remaining batches, per workload state is still reset.
+    ///
+    /// ### Borrows
+    ///
+    /// - Scheduler (shared) each time the future is polled
+    /// - Systems' borrow as they are executed
+    ///
+    /// ### Errors
+    ///
+    /// - Scheduler borrow failed.
+    /// - Workload did not exist.
+    /// - Storage borrow failed.
+    /// - User error returned by system.
+    #[cfg(feature = "async")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "async")))]
+    pub fn try_run_workload_async(&self, name: impl AsRef<str>) -> RunWorkloadAsync<'_> {
+        RunWorkloadAsync::new(self, name.as_ref())
+    }
+    /// Returns a future running the `name` workload.
+    /// The future runs one batch of systems each time it's polled and yields to the executor between batches,
+    /// ECS ticks can be interleaved with IO on the same runtime.  
+    /// Async systems aren't supported, systems are synchronous and each batch runs to completion when polled.  
+    /// Dropping the future before it completes skips the remaining batches, per workload state is still reset.  
+    /// Unwraps error.
+    ///
+    /// ### Borrows
+    ///
+    /// - Scheduler (shared) each time the future is polled
+    /// - Systems' borrow as they are executed
+    ///
+    /// ### Errors
+    ///
+    /// - Scheduler borrow failed.
+    /// - Workload did not exist.
+    /// - Storage borrow failed.
+    /// - User error returned by system.
+    #[cfg(all(feature = "async", feature = "panic"))]
+    #[cfg_attr(docsrs, doc(cfg(all(feature = "async", feature = "panic"))))]
+    pub fn run_workload_async<'a>(
+        &'a self,
+        name: impl AsRef<str> + 'a,
+    ) -> impl core::future::Future<Output = ()> + 'a {
+        let workload = self.try_run_workload_async(name.as_ref());
+
+        async move {
+            if let Err(err) = workload.await {
+                panic!("Workload {} failed: {:?}", name.as_ref(), err);
+            }
+        }
+    }
+    fn try_run_workload_index(
+        &self,
+        scheduler: &Scheduler,
+        workload: impl IntoIterator<Item = usize>,
+    ) -> Result<(), error::RunWorkload> {
+        #[cfg(feature = "stats")]
+        let start = std::time::Instant::now();
+
+        let result = workload
+            .into_iter()
+            .try_for_each(|index| self.try_run_batch(scheduler, index));
+
+        self.finish_workload();
+
+        #[cfg(feature = "stats")]
+        self.refresh_stats(start.elapsed());
+
+        result
+    }
+    /// Borrows the scheduler to run a workload.  
+    /// Workloads can't be run from inside a system.
+    pub(super) fn borrow_scheduler(&self) -> Result<Ref<'_, Scheduler>, error::RunWorkload> {
+        #[cfg(feature = "std")]
+        {
+            if let Some(system) = crate::reentrancy::current_system() {
+                return Err(error::RunWorkload::Run((
+                    system,
+                    error::Run::Reentrant(system),
+                )));
+            }
+        }
+
+        self.scheduler
+            .try_borrow()
+            .map_err(|_| error::RunWorkload::Scheduler)
+    }
+    /// Resets per workload state once all batches ran, even if one of them failed.
+    fn finish_workload(&self) {
+        if let Ok(all_storages) = self.all_storages.try_borrow() {
+            // scratch allocations only live as long as the workload
+            if let Ok(mut frame_alloc) = all_storages.unique_mut::<FrameAlloc>() {
+                frame_alloc.reset();
+            }
+
+            all_storages.end_workload();
+        }
+    }
+    fn try_run_batch(&self, scheduler: &Scheduler, index: usize) -> Result<(), error::RunWorkload> {
+        self.try_run_batch_with(scheduler, index, |system| scheduler.run(system, self))
+    }
+    /// Runs the batch at `index`, calling `run` with the index of each of its systems.
+    fn try_run_batch_with(
+        &self,
+        scheduler: &Scheduler,
+        index: usize,
+        run: impl Fn(usize) -> Result<(), error::RunWorkload> + Sync,
+    ) -> Result<(), error::RunWorkload> {
+        let batch = &scheduler.batch[index];
+        let deterministic = scheduler.deterministic[index];
+
+        if batch.len() == 1 {
+            run(batch[0])?;
+        } else {
+            #[cfg(feature = "parallel")]
+            {
+                use core::sync::atomic::Ordering;
+                use rayon::prelude::*;
+
+                let ran = &scheduler.ran[index];
+
+                if !ran.load(Ordering::Acquire) {
+                    // the first run is sequential, storages borrowed by the systems can be created without contention
+                    if deterministic {
+                        let results = batch.iter().map(|&index| run(index)).collect::<Vec<_>>();
+
+                        for result in results {
+                            result?;
+                        }
+                    } else {
+                        batch.iter().try_for_each(|&index| run(index))?
+                    }
+
+                    ran.store(true, Ordering::Release);
+
+                    return Ok(());
+                }
+
+                // systems can't create storages while they run in parallel
+                // if AllStorages can't be borrowed the systems will report it
+                let all_storages = self.all_storages.try_borrow().ok();
+                if let Some(all_storages) = &all_storages {
+                    all_storages.set_parallel_batch(true);
+                }
+
+                let result = if deterministic {
+                    let deferred_start = all_storages
+                        .as_ref()
+                        .and_then(|all_storages| all_storages.entities().ok())
+                        .map(|entities| entities.deferred_len());
+
+                    // all systems run to completion and the first error in batch order is returned
+                    let results = self.thread_pool.install(|| {
+                        batch
+                            .into_par_iter()
+                            .enumerate()
+                            .map(|(position, &index)| {
+                                crate::deferred_entity_builder::run_at(position, || run(index))
+                            })
+                            .collect::<Vec<_>>()
+                    });
+
+                    // entities deferred by the systems are created in batch order, not in the order they finished
+                    if let (Some(all_storages), Some(deferred_start)) =
+                        (&all_storages, deferred_start)
+                    {
+                        if let Ok(entities) = all_storages.entities() {
+                            entities.sort_deferred(deferred_start);
+                        }
+                    }
+
+                    // same for the messages they sent
+                    if let Some(all_storages) = &all_storages {
+                        for &index in batch.iter() {
+                            for &(type_id, mutation) in &scheduler.system_infos[index].borrows {
+                                if mutation == crate::borrow::Mutation::Shared {
+                                    all_storages.sort_messages(type_id.into());
+                                }
+                            }
+                        }
+                    }
+
+                    results.into_iter().collect()
+                } else {
+                    self.thread_pool
+                        .install(|| batch.into_par_iter().try_for_each(|&index| run(index)))
+                };
+
+                if let Some(all_storages) = &all_storages {
+                    all_storages.set_parallel_batch(false);
+                }
+
+                result?
+            }
+            #[cfg(not(feature = "parallel"))]
+            {
+                // systems already run one after the other
+                let _ = deterministic;
+
+                batch.iter().try_for_each(|&index| run(index))?
+            }
+        }
+        Ok(())
+    }
+    /// Run the default workload if there is one.  
+    /// Batches of disabled workloads are skipped, see [World::set_workload_enabled].
+    ///
+    /// ### Borrows
+    ///
+    /// - Scheduler (shared)
+    /// - Systems' borrow as they are executed
+    ///
+    /// ### Errors
+    ///
+    /// - Scheduler borrow failed.
+    /// - Storage borrow failed.
+    /// - User error returned by system.
+    ///
+    /// [World::set_workload_enabled]: struct.World.html#method.set_workload_enabled
+    pub fn try_run_default(&self) -> Result<(), error::RunWorkload> {
+        let scheduler = self.borrow_scheduler()?;
+        if !scheduler.batch.is_empty() {
+            let enabled = &scheduler.enabled;
+
+            self.try_run_workload_index(
+                &scheduler,
+                scheduler.default.clone().filter(|&index| enabled[index]),
+            )?
+        }
+        Ok(())
+    }
+    /// Run the default workload if there is one.  
+    /// Batches of disabled workloads are skipped, see [World::set_workload_enabled].  
+    /// Unwraps error.
+    ///
+    /// ### Borrows
+    ///
+    /// - Scheduler (shared)
+    /// - Systems' borrow as they are executed
+    ///
+    /// ### Errors
+    ///
+    /// - Scheduler borrow failed.
+    /// - Storage borrow failed.
+    /// - User error returned by system.
+    ///
+    /// [World::set_workload_enabled]: struct.World.html#method.set_workload_enabled
+    #[cfg(feature = "panic")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "panic")))]
+    #[track_caller]
+    pub fn run_default(&self) {
+        self.try_run_default().unwrap();
+    }
+    /// Sync point for structural changes made outside of workloads.  
+    /// Nothing can be borrowed while it runs, it resets [FrameAlloc] if the `World` has one
+    /// and counts a frame for entities waiting for a [Recycling::Delayed] recycling.  
+    /// Entities built with a [DeferredEntityBuilder] are created.
+    ///
+    /// ### Borrows
+    ///
+    /// - [AllStorages] (exclusive)
+    ///
+    /// ### Errors
+    ///
+    /// - [AllStorages] borrow failed.
+    /// - A deferred entity couldn't be created, the other deferred entities are still created.
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{FrameAlloc, UniqueView, World};
+    ///
+    /// let world = World::new();
+    /// world.add_unique(FrameAlloc::new());
+    ///
+    /// world.run(|frame_alloc: UniqueView<FrameAlloc>| {
+    ///     frame_alloc.alloc(0u32);
+    /// });
+    ///
+    /// world.try_maintain().unwrap();
+    ///
+    /// world.run(|frame_alloc: UniqueView<FrameAlloc>| assert_eq!(frame_alloc.allocated_bytes(), 0));
+    /// ```
+    ///
+    /// [AllStorages]: struct.AllStorages.html
+    /// [DeferredEntityBuilder]: struct.DeferredEntityBuilder.html
+    /// [FrameAlloc]: struct.FrameAlloc.html
+    /// [Recycling::Delayed]: enum.Recycling.html#variant.Delayed
+    pub fn try_maintain(&self) -> Result<(), error::GetStorage> {
+        let all_storages = self
+            .all_storages
+            .try_borrow_mut()
+            .map_err(error::GetStorage::AllStoragesBorrow)?;
+
+        // no view can exist while AllStorages is exclusively borrowed
+        if let Ok(mut frame_alloc) = all_storages.unique_mut::<FrameAlloc>() {
+            frame_alloc.reset();
+        }
+
+        let deferred = {
+            let mut entities = all_storages
+                .entities_mut()
+                .map_err(error::GetStorage::Entities)?;
+            entities.advance_frame();
+            entities.take_deferred()
+        };
+
+        // one entity failing doesn't prevent the others from being created
+        let mut result = Ok(());
+        for entity in deferred {
+            if let Err(err) = entity(&all_storages) {
+                result = result.and(Err(err));
+            }
+        }
+
+        result
+    }
+    /// Sync point for structural changes made outside of workloads.  
+    /// Nothing can be borrowed while it runs, it resets [FrameAlloc] if the `World` has one
+    /// and counts a frame for entities waiting for a [Recycling::Delayed] recycling.  
+    /// Entities built with a [DeferredEntityBuilder] are created.  
+    /// Unwraps error.
+    ///
+    /// ### Borrows
+    ///
+    /// - [AllStorages] (exclusive)
+    ///
+    /// ### Errors
+    ///
+    /// - [AllStorages] borrow failed.
+    /// - A deferred entity couldn't be created, the other deferred entities are still created.
+    ///
+    /// [AllStorages]: struct.AllStorages.html
+    /// [DeferredEntityBuilder]: struct.DeferredEntityBuilder.html
+    /// [FrameAlloc]: struct.FrameAlloc.html
+    /// [Recycling::Delayed]: enum.Recycling.html#variant.Delayed
+    #[cfg(feature = "panic")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "panic")))]
+    #[track_caller]
+    pub fn maintain(&self) {
+        self.try_maintain().unwrap();
+    }
+    /// Starts logging structural changes: entities created and deleted, components added and removed.  
+    /// Events are kept until [World::drain_events] is called, usually once per frame.
+    ///
+    /// ### Borrows
+    ///
+    /// - [AllStorages] (exclusive)
+    ///
+    /// ### Errors
+    ///
+    /// - [AllStorages] borrow failed.
+    /// - Storage borrow failed.
+    ///
+    /// [AllStorages]: struct.AllStorages.html
+    /// [World::drain_events]: struct.World.html#method.drain_events
+    pub fn try_enable_event_log(&self) -> Result<(), error::Borrow> {
+        self.all_storages.try_borrow_mut()?.set_event_log(true)
+    }
+    /// Starts logging structural changes: entities created and deleted, components added and removed.  
+    /// Events are kept until [World::drain_events] is called, usually once per frame.  
+    /// Unwraps errors.
+    ///
+    /// ### Borrows
+    ///
+    /// - [AllStorages] (exclusive)
+    ///
+    /// ### Errors
+    ///
+    /// - [AllStorages] borrow failed.
+    /// - Storage borrow failed.
+    ///
+    /// [AllStorages]: struct.AllStorages.html
+    /// [World::drain_events]: struct.World.html#method.drain_events
+    #[cfg(feature = "panic")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "panic")))]
+    #[track_caller]
+    pub fn enable_event_log(&self) {
+        self.try_enable_event_log().unwrap()
+    }
+    /// Stops logging structural changes, events not drained yet are dropped.
+    ///
+    /// ### Borrows
+    ///
+    /// - [AllStorages] (exclusive)
+    ///
+    /// ### Errors
+    ///
+    /// - [AllStorages] borrow failed.
+    /// - Storage borrow failed.
+    ///
+    /// [AllStorages]: struct.AllStorages.html
+    pub fn try_disable_event_log(&self) -> Result<(), error::Borrow> {
+        self.all_storages.try_borrow_mut()?.set_event_log(false)
+    }
+    /// Stops logging structural changes, events not drained yet are dropped.  
+    /// Unwraps errors.
+    ///
+    /// ### Borrows
+    ///
+    /// - [AllStorages] (exclusive)
+    ///
+    /// ### Errors
+    ///
+    /// - [AllStorages] borrow failed.
+    /// - Storage borrow failed.
+    ///
+    /// [AllStorages]: struct.AllStorages.html
     #[cfg(feature = "panic")]
     #[cfg_attr(docsrs, doc(cfg(feature = "panic")))]
-    pub fn borrow<'s, V: Borrow<'s>>(&'s self) -> V {
-        self.try_borrow::<V>().unwrap()
+    #[track_caller]
+    pub fn disable_event_log(&self) {
+        self.try_disable_event_log().unwrap()
     }
-    #[doc = "Borrows the requested storages and runs the function.  
-Data can be passed to the function, this always has to be a single type but you can use a tuple if needed.
-
-You can use:
-* [View]\\<T\\> for a shared access to `T` storage
-* [ViewMut]\\<T\\> for an exclusive access to `T` storage
-* [EntitiesView] for a shared access to the entity storage
-* [EntitiesViewMut] for an exclusive reference to the entity storage
-* [AllStoragesViewMut] for an exclusive access to the storage of all components, ⚠️ can't coexist with any other storage borrow
-* [UniqueView]\\<T\\> for a shared access to a `T` unique storage
-* [UniqueViewMut]\\<T\\> for an exclusive access to a `T` unique storage
-* `Option<V>` with one or multiple views for fallible access to one or more storages"]
-    #[cfg_attr(
-        all(feature = "parallel", docsrs),
-        doc = "* <span style=\"display: table;color: #2f2f2f;background-color: #C4ECFF;border-width: 1px;border-style: solid;border-color: #7BA5DB;padding: 3px;margin-bottom: 5px; font-size: 90%\">This is supported on <strong><code style=\"background-color: #C4ECFF\">feature=\"parallel\"</code></strong> only:</span>"
-    )]
-    #[cfg_attr(
-        all(feature = "parallel", docsrs),
-        doc = "    * [ThreadPoolView] for a shared access to the `ThreadPool` used by the [World]"
-    )]
-    #[cfg_attr(
-        all(feature = "parallel", not(docsrs)),
-        doc = "* [ThreadPoolView] for a shared access to the `ThreadPool` used by the [World]"
-    )]
-    #[cfg_attr(
-        not(feature = "parallel"),
-        doc = "* ThreadPool: must activate the *parallel* feature"
-    )]
-    #[cfg_attr(
-        all(feature = "non_send", docsrs),
-        doc = "* <span style=\"display: table;color: #2f2f2f;background-color: #C4ECFF;border-width: 1px;border-style: solid;border-color: #7BA5DB;padding: 3px;margin-bottom: 5px; font-size: 90%\">This is supported on <strong><code style=\"background-color: #C4ECFF\">feature=\"non_send\"</code></strong> only:</span>"
-    )]
-    #[cfg_attr(
-        all(feature = "non_send", docsrs),
-        doc = "    * [NonSend]<[View]\\<T\\>> for a shared access to a `T` storage where `T` isn't `Send`
-    * [NonSend]<[ViewMut]\\<T\\>> for an exclusive access to a `T` storage where `T` isn't `Send`  
-[NonSend] and [UniqueView]/[UniqueViewMut] can be used together to access a `!Send` unique storage."
-    )]
-    #[cfg_attr(
-        all(feature = "non_send", not(docsrs)),
-        doc = "* [NonSend]<[View]\\<T\\>> for a shared access to a `T` storage where `T` isn't `Send`
-* [NonSend]<[ViewMut]\\<T\\>> for an exclusive access to a `T` storage where `T` isn't `Send`  
-[NonSend] and [UniqueView]/[UniqueViewMut] can be used together to access a `!Send` unique storage."
-    )]
-    #[cfg_attr(
-        not(feature = "non_send"),
-        doc = "* NonSend: must activate the *non_send* feature"
-    )]
-    #[cfg_attr(
-        all(feature = "non_sync", docsrs),
-        doc = "* <span style=\"display: table;color: #2f2f2f;background-color: #C4ECFF;border-width: 1px;border-style: solid;border-color: #7BA5DB;padding: 3px;margin-bottom: 5px; font-size: 90%\">This is supported on <strong><code style=\"background-color: #C4ECFF\">feature=\"non_sync\"</code></strong> only:</span>"
-    )]
-    #[cfg_attr(
-        all(feature = "non_sync", docsrs),
-        doc = "    * [NonSync]<[View]\\<T\\>> for a shared access to a `T` storage where `T` isn't `Sync`
-    * [NonSync]<[ViewMut]\\<T\\>> for an exclusive access to a `T` storage where `T` isn't `Sync`  
-[NonSync] and [UniqueView]/[UniqueViewMut] can be used together to access a `!Sync` unique storage."
-    )]
-    #[cfg_attr(
-        all(feature = "non_sync", not(docsrs)),
-        doc = "* [NonSync]<[View]\\<T\\>> for a shared access to a `T` storage where `T` isn't `Sync`
-* [NonSync]<[ViewMut]\\<T\\>> for an exclusive access to a `T` storage where `T` isn't `Sync`  
-[NonSync] and [UniqueView]/[UniqueViewMut] can be used together to access a `!Sync` unique storage."
-    )]
-    #[cfg_attr(
-        not(feature = "non_sync"),
-        doc = "* NonSync: must activate the *non_sync* feature"
-    )]
-    #[cfg_attr(
-        all(feature = "non_sync", docsrs),
-        doc = "* <span style=\"display: table;color: #2f2f2f;background-color: #C4ECFF;border-width: 1px;border-style: solid;border-color: #7BA5DB;padding: 3px;margin-bottom: 5px; font-size: 90%\">This is supported on <strong><code style=\"background-color: #C4ECFF\">feature=\"non_send\"</code> and <code style=\"background-color: #C4ECFF\">feature=\"non_sync\"</code></strong> only:</span>"
-    )]
-    #[cfg_attr(
-        all(feature = "non_send", feature = "non_sync", docsrs),
-        doc = "    * [NonSendSync]<[View]\\<T\\>> for a shared access to a `T` storage where `T` isn't `Send` nor `Sync`
-    * [NonSendSync]<[ViewMut]\\<T\\>> for an exclusive access to a `T` storage where `T` isn't `Send` nor `Sync`  
-[NonSendSync] and [UniqueView]/[UniqueViewMut] can be used together to access a `!Send + !Sync` unique storage."
-    )]
-    #[cfg_attr(
-        all(feature = "non_send", feature = "non_sync", not(docsrs)),
-        doc = "* [NonSendSync]<[View]\\<T\\>> for a shared access to a `T` storage where `T` isn't `Send` nor `Sync`
-* [NonSendSync]<[ViewMut]\\<T\\>> for an exclusive access to a `T` storage where `T` isn't `Send` nor `Sync`  
-[NonSendSync] and [UniqueView]/[UniqueViewMut] can be used together to access a `!Send + !Sync` unique storage."
-    )]
-    #[cfg_attr(
-        not(all(feature = "non_send", feature = "non_sync")),
-        doc = "* NonSendSync: must activate the *non_send* and *non_sync* features"
-    )]
-    #[doc = "
-### Borrows
+    /// Returns the structural changes logged since the last call, in the order they happened.  
+    /// Nothing is logged until [World::enable_event_log] is called.
+    ///
+    /// ### Borrows
+    ///
+    /// - [AllStorages] (exclusive)
+    ///
+    /// ### Errors
+    ///
+    /// - [AllStorages] borrow failed.
+    /// - Storage borrow failed.
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{StorageId, StructuralEvent, World};
+    ///
+    /// let world = World::new();
+    /// world.enable_event_log();
+    ///
+    /// let entity = world.spawn((0u32,));
+    /// world.delete_entity(entity);
+    ///
+    /// let u32s = StorageId::of::<u32>();
+    /// assert_eq!(
+    ///     world.drain_events(),
+    ///     vec![
+    ///         StructuralEvent::EntityCreated(entity),
+    ///         StructuralEvent::ComponentAdded(entity, u32s),
+    ///         StructuralEvent::EntityDeleted(entity),
+    ///         StructuralEvent::ComponentRemoved(entity, u32s),
+    ///     ]
+    /// );
+    /// assert!(world.drain_events().is_empty());
+    /// ```
+    ///
+    /// [AllStorages]: struct.AllStorages.html
+    /// [World::enable_event_log]: struct.World.html#method.enable_event_log
+    pub fn try_drain_events(&self) -> Result<Vec<StructuralEvent>, error::Borrow> {
+        self.all_storages.try_borrow_mut()?.drain_events()
+    }
+    /// Returns the structural changes logged since the last call, in the order they happened.  
+    /// Nothing is logged until [World::enable_event_log] is called.  
+    /// Unwraps errors.
+    ///
+    /// ### Borrows
+    ///
+    /// - [AllStorages] (exclusive)
+    ///
+    /// ### Errors
+    ///
+    /// - [AllStorages] borrow failed.
+    /// - Storage borrow failed.
+    ///
+    /// [AllStorages]: struct.AllStorages.html
+    /// [World::enable_event_log]: struct.World.html#method.enable_event_log
+    #[cfg(feature = "panic")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "panic")))]
+    #[track_caller]
+    pub fn drain_events(&self) -> Vec<StructuralEvent> {
+        self.try_drain_events().unwrap()
+    }
+    /// Returns the borrow counters of all storages and how many entities each system iterated in workloads.  
+    /// Entities are counted when an iterator is created, using the number of entities it'll go through.
+    ///
+    /// ### Borrows
+    ///
+    /// - Scheduler (shared)
+    /// - [AllStorages] (exclusive)
+    ///
+    /// ### Errors
+    ///
+    /// - Scheduler borrow failed.
+    /// - [AllStorages] borrow failed.
+    ///
+    /// [AllStorages]: struct.AllStorages.html
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{system, IntoIter, Shiperator, View, World};
+    ///
+    /// fn count(u32s: View<u32>) {
+    ///     u32s.iter().for_each(|_| {});
+    /// }
+    ///
+    /// let world = World::new();
+    /// world.spawn((0u32,));
+    /// world.spawn((1u32,));
+    /// world.spawn((2u32,));
+    ///
+    /// world.add_workload("Count").with_system(system!(count)).build();
+    /// world.run_default();
+    ///
+    /// let stats = world.stats();
+    /// assert_eq!(stats.systems[0].runs, 1);
+    /// assert_eq!(stats.systems[0].iterated, 3);
+    /// assert!(stats.storage("u32").unwrap().shared_borrows >= 1);
+    /// ```
+    #[cfg(feature = "stats")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "stats")))]
+    pub fn try_stats(&self) -> Result<WorldStats, error::Borrow> {
+        let scheduler = self.scheduler.try_borrow()?;
+        let mut all_storages = self.all_storages.try_borrow_mut()?;
 
-- [AllStorages] (exclusive) when requesting [AllStoragesViewMut]
-- [AllStorages] (shared) + storage (exclusive or shared) for all other views
+        let storages = all_storages
+            .storages()
+            .iter()
+            .map(|(&storage_id, storage)| {
+                let (shared_borrows, failed_borrows) = storage.0.borrow_stats();
+                let unique_borrows = storage.0.unique_borrows();
+                // the storage can't be borrowed since we have an exclusive access to AllStorages
+                let unknown = storage.0.try_borrow().unwrap();
 
-### Errors
+                let storage_stats = StorageStats {
+                    name: unknown.name(),
+                    len: unknown.component_entities().len(),
+                    shared_borrows,
+                    unique_borrows,
+                    failed_borrows,
+                };
 
-- [AllStorages] borrow failed.
-- Storage borrow failed.
-- Unique storage did not exist.
-- Error returned by user.
+                (storage_id, storage_stats)
+            })
+            .collect();
 
-### Example
-```
-use shipyard::{EntityId, Get, ViewMut, World};
+        // read after the counters to not count these borrows
+        let entities = all_storages.entities().unwrap().iter().count();
+        // the duration is only known at the end of a workload
+        let last_workload = all_storages
+            .unique::<WorldStats>()
+            .ok()
+            .and_then(|(stats, _)| stats.last_workload);
 
-fn sys1((entity, [x, y]): (EntityId, [f32; 2]), mut positions: ViewMut<[f32; 2]>) {
-    if let Ok(pos) = (&mut positions).get(entity) {
-        *pos = [x, y];
+        Ok(WorldStats {
+            entities,
+            storages,
+            systems: scheduler.system_stats(),
+            last_workload,
+        })
     }
-}
-
-let world = World::new();
+    /// Returns the borrow counters of all storages and how many entities each system iterated in workloads.  
+    /// Entities are counted when an iterator is created, using the number of entities it'll go through.  
+    /// Unwraps errors.
+    ///
+    /// ### Borrows
+    ///
+    /// - Scheduler (shared)
+    /// - [AllStorages] (exclusive)
+    ///
+    /// ### Errors
+    ///
+    /// - Scheduler borrow failed.
+    /// - [AllStorages] borrow failed.
+    ///
+    /// [AllStorages]: struct.AllStorages.html
+    #[cfg(all(feature = "stats", feature = "panic"))]
+    #[cfg_attr(docsrs, doc(cfg(all(feature = "stats", feature = "panic"))))]
+    #[track_caller]
+    pub fn stats(&self) -> WorldStats {
+        self.try_stats().unwrap()
+    }
+    /// Adds a [WorldStats] unique refreshed at the end of each workload.  
+    /// Systems and the rest of the program can then read the stats with `UniqueView<WorldStats>`
+    /// without borrowing [AllStorages] exclusively.  
+    /// Does nothing if the stats are already tracked.
+    ///
+    /// ### Borrows
+    ///
+    /// - Scheduler (shared)
+    /// - [AllStorages] (exclusive)
+    ///
+    /// ### Errors
+    ///
+    /// - Scheduler borrow failed.
+    /// - [AllStorages] borrow failed.
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{system, UniqueView, World, WorldStats};
+    ///
+    /// fn hud(stats: UniqueView<WorldStats>) {
+    ///     println!("{} entities", stats.entities);
+    /// }
+    ///
+    /// let world = World::new();
+    /// world.track_stats();
+    ///
+    /// world.add_workload("Hud").with_system(system!(hud)).build();
+    /// world.spawn((0u32,));
+    /// world.run_default();
+    ///
+    /// let stats = world.borrow::<UniqueView<WorldStats>>();
+    /// assert_eq!(stats.entities, 1);
+    /// assert!(stats.last_workload.is_some());
+    /// ```
+    ///
+    /// [AllStorages]: struct.AllStorages.html
+    /// [WorldStats]: struct.WorldStats.html
+    #[cfg(feature = "stats")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "stats")))]
+    pub fn try_track_stats(&self) -> Result<(), error::Borrow> {
+        let stats = self.try_stats()?;
+        self.all_storages.try_borrow()?.add_unique(stats);
+        Ok(())
+    }
+    /// Adds a [WorldStats] unique refreshed at the end of each workload.  
+    /// Systems and the rest of the program can then read the stats with `UniqueView<WorldStats>`
+    /// without borrowing [AllStorages] exclusively.  
+    /// Does nothing if the stats are already tracked.  
+    /// Unwraps errors.
+    ///
+    /// ### Borrows
+    ///
+    /// - Scheduler (shared)
+    /// - [AllStorages] (exclusive)
+    ///
+    /// ### Errors
+    ///
+    /// - Scheduler borrow failed.
+    /// - [AllStorages] borrow failed.
+    ///
+    /// [AllStorages]: struct.AllStorages.html
+    /// [WorldStats]: struct.WorldStats.html
+    #[cfg(all(feature = "stats", feature = "panic"))]
+    #[cfg_attr(docsrs, doc(cfg(all(feature = "stats", feature = "panic"))))]
+    #[track_caller]
+    pub fn track_stats(&self) {
+        self.try_track_stats().unwrap();
+    }
+    /// Replaces the [WorldStats] unique, if stats are tracked.
+    #[cfg(feature = "stats")]
+    fn refresh_stats(&self, duration: Duration) {
+        let is_tracked = match self.all_storages.try_borrow() {
+            Ok(all_storages) => all_storages.unique::<WorldStats>().is_ok(),
+            Err(_) => false,
+        };
 
-world.try_run_with_data(sys1, (EntityId::dead(), [0., 0.])).unwrap();
-```
-[AllStorages]: struct.AllStorages.html
-[EntitiesView]: struct.Entities.html
-[EntitiesViewMut]: struct.Entities.html
-[AllStoragesViewMut]: struct.AllStorages.html
-[World]: struct.World.html
-[View]: struct.View.html
-[ViewMut]: struct.ViewMut.html
-[UniqueView]: struct.UniqueView.html
-[UniqueViewMut]: struct.UniqueViewMut.html"]
-    #[cfg_attr(
-        feature = "parallel",
-        doc = "[ThreadPoolView]: struct.ThreadPoolView.html"
-    )]
-    #[cfg_attr(feature = "non_send", doc = "[NonSend]: struct.NonSend.html")]
-    #[cfg_attr(feature = "non_sync", doc = "[NonSync]: struct.NonSync.html")]
-    #[cfg_attr(
-        all(feature = "non_send", feature = "non_sync"),
-        doc = "[NonSendSync]: struct.NonSendSync.html"
-    )]
-    pub fn try_run_with_data<'s, Data, B, R, S: crate::system::System<'s, (Data,), B, R>>(
-        &'s self,
-        s: S,
-        data: Data,
-    ) -> Result<R, error::Run> {
-        Ok(s.run((data,), {
-            #[cfg(feature = "parallel")]
-            {
-                S::try_borrow(&self.all_storages, &self.thread_pool)?
-            }
-            #[cfg(not(feature = "parallel"))]
-            {
-                S::try_borrow(&self.all_storages)?
+        if is_tracked {
+            // the workload might run inside a borrow of AllStorages, the stats are skipped in this case
+            if let Ok(mut stats) = self.try_stats() {
+                stats.last_workload = Some(duration);
+
+                if let Ok(all_storages) = self.all_storages.try_borrow() {
+                    if let Ok(mut unique) = all_storages.unique_mut::<WorldStats>() {
+                        *unique = stats;
+                    }
+                }
             }
-        }))
+        }
     }
-    #[doc = "Borrows the requested storages and runs the function.  
-Data can be passed to the function, this always has to be a single type but you can use a tuple if needed.  
-Unwraps errors.
+    /// Returns the name, borrows and thread requirements of every system part of at least one workload.
+    ///
+    /// ### Borrows
+    ///
+    /// - Scheduler (shared)
+    ///
+    /// ### Errors
+    ///
+    /// - Scheduler borrow failed.
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{system, Mutation, TypeId, View, ViewMut, World};
+    ///
+    /// fn physics(_: ViewMut<f32>, _: View<u32>) {}
+    ///
+    /// let world = World::new();
+    ///
+    /// world.add_workload("Physics").with_system(system!(physics)).build();
+    ///
+    /// let systems = world.try_systems_info().unwrap();
+    /// assert_eq!(
+    ///     systems[0].borrows,
+    ///     vec![
+    ///         (TypeId::of::<f32>(), Mutation::Unique),
+    ///         (TypeId::of::<u32>(), Mutation::Shared)
+    ///     ]
+    /// );
+    /// assert!(systems[0].is_send_sync);
+    /// ```
+    pub fn try_systems_info(&self) -> Result<Vec<SystemInfo>, error::Borrow> {
+        Ok(self.scheduler.try_borrow()?.system_infos.clone())
+    }
+    /// Returns the name, borrows and thread requirements of every system part of at least one workload.  
+    /// Unwraps errors.
+    ///
+    /// ### Borrows
+    ///
+    /// - Scheduler (shared)
+    ///
+    /// ### Errors
+    ///
+    /// - Scheduler borrow failed.
+    #[cfg(feature = "panic")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "panic")))]
+    #[track_caller]
+    pub fn systems_info(&self) -> Vec<SystemInfo> {
+        self.try_systems_info().unwrap()
+    }
+    /// Returns the `name` workload in the DOT format, to be rendered with graphviz.  
+    /// Systems are grouped by batch, systems of the same batch can run in parallel.  
+    /// Each system is linked to the systems of previous batches it conflicts with, the edge lists the storages causing the conflict.  
+    /// Systems borrowing `!Send` or `!Sync` storages are dashed, they always run on the `World`'s thread.  
+    /// Storages that don't exist yet are named after their `TypeId`.
+    ///
+    /// ### Borrows
+    ///
+    /// - Scheduler (shared)
+    /// - [AllStorages] (shared) to name the storages
+    ///
+    /// ### Errors
+    ///
+    /// - Scheduler borrow failed.
+    /// - [AllStorages] borrow failed.
+    /// - Workload did not exist.
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{system, View, ViewMut, World};
+    ///
+    /// fn gravity(_: ViewMut<f32>) {}
+    /// fn render(_: View<f32>) {}
+    ///
+    /// let world = World::new();
+    ///
+    /// world
+    ///     .add_workload("Frame")
+    ///     .with_system(system!(gravity))
+    ///     .with_system(system!(render))
+    ///     .build();
+    ///
+    /// let dot = world.try_workload_dot("Frame").unwrap();
+    /// assert!(dot.starts_with("digraph \"Frame\""));
+    /// ```
+    ///
+    /// [AllStorages]: struct.AllStorages.html
+    pub fn try_workload_dot(&self, name: impl AsRef<str>) -> Result<String, error::WorkloadDot> {
+        let scheduler = self
+            .scheduler
+            .try_borrow()
+            .map_err(|_| error::WorkloadDot::Scheduler)?;
+        let range = scheduler
+            .workloads
+            .get(name.as_ref())
+            .cloned()
+            .ok_or(error::WorkloadDot::MissingWorkload)?;
+        let all_storages = self
+            .all_storages
+            .try_borrow()
+            .map_err(error::WorkloadDot::AllStoragesBorrow)?;
 
-You can use:
-* [View]\\<T\\> for a shared access to `T` storage
-* [ViewMut]\\<T\\> for an exclusive access to `T` storage
-* [EntitiesView] for a shared access to the entity storage
-* [EntitiesViewMut] for an exclusive reference to the entity storage
-* [AllStoragesViewMut] for an exclusive access to the storage of all components, ⚠️ can't coexist with any other storage borrow
-* [UniqueView]\\<T\\> for a shared access to a `T` unique storage
-* [UniqueViewMut]\\<T\\> for an exclusive access to a `T` unique storage
-* `Option<V>` with one or multiple views for fallible access to one or more storages"]
-    #[cfg_attr(
-        all(feature = "parallel", docsrs),
-        doc = "* <span style=\"display: table;color: #2f2f2f;background-color: #C4ECFF;border-width: 1px;border-style: solid;border-color: #7BA5DB;padding: 3px;margin-bottom: 5px; font-size: 90%\">This is supported on <strong><code style=\"background-color: #C4ECFF\">feature=\"parallel\"</code></strong> only:</span>"
-    )]
-    #[cfg_attr(
-        all(feature = "parallel", docsrs),
-        doc = "    * [ThreadPoolView] for a shared access to the `ThreadPool` used by the [World]"
-    )]
-    #[cfg_attr(
-        all(feature = "parallel", not(docsrs)),
-        doc = "* [ThreadPoolView] for a shared access to the `ThreadPool` used by the [World]"
-    )]
-    #[cfg_attr(
-        not(feature = "parallel"),
-        doc = "* ThreadPool: must activate the *parallel* feature"
-    )]
-    #[cfg_attr(
-        all(feature = "non_send", docsrs),
-        doc = "* <span style=\"display: table;color: #2f2f2f;background-color: #C4ECFF;border-width: 1px;border-style: solid;border-color: #7BA5DB;padding: 3px;margin-bottom: 5px; font-size: 90%\">This is supported on <strong><code style=\"background-color: #C4ECFF\">feature=\"non_send\"</code></strong> only:</span>"
-    )]
-    #[cfg_attr(
-        all(feature = "non_send", docsrs),
-        doc = "    * [NonSend]<[View]\\<T\\>> for a shared access to a `T` storage where `T` isn't `Send`
-    * [NonSend]<[ViewMut]\\<T\\>> for an exclusive access to a `T` storage where `T` isn't `Send`  
-[NonSend] and [UniqueView]/[UniqueViewMut] can be used together to access a `!Send` unique storage."
-    )]
-    #[cfg_attr(
-        all(feature = "non_send", not(docsrs)),
-        doc = "* [NonSend]<[View]\\<T\\>> for a shared access to a `T` storage where `T` isn't `Send`
-* [NonSend]<[ViewMut]\\<T\\>> for an exclusive access to a `T` storage where `T` isn't `Send`  
-[NonSend] and [UniqueView]/[UniqueViewMut] can be used together to access a `!Send` unique storage."
-    )]
-    #[cfg_attr(
-        not(feature = "non_send"),
-        doc = "* NonSend: must activate the *non_send* feature"
-    )]
-    #[cfg_attr(
-        all(feature = "non_sync", docsrs),
-        doc = "* <span style=\"display: table;color: #2f2f2f;background-color: #C4ECFF;border-width: 1px;border-style: solid;border-color: #7BA5DB;padding: 3px;margin-bottom: 5px; font-size: 90%\">This is supported on <strong><code style=\"background-color: #C4ECFF\">feature=\"non_sync\"</code></strong> only:</span>"
-    )]
-    #[cfg_attr(
-        all(feature = "non_sync", docsrs),
-        doc = "    * [NonSync]<[View]\\<T\\>> for a shared access to a `T` storage where `T` isn't `Sync`
-    * [NonSync]<[ViewMut]\\<T\\>> for an exclusive access to a `T` storage where `T` isn't `Sync`  
-[NonSync] and [UniqueView]/[UniqueViewMut] can be used together to access a `!Sync` unique storage."
-    )]
-    #[cfg_attr(
-        all(feature = "non_sync", not(docsrs)),
-        doc = "* [NonSync]<[View]\\<T\\>> for a shared access to a `T` storage where `T` isn't `Sync`
-* [NonSync]<[ViewMut]\\<T\\>> for an exclusive access to a `T` storage where `T` isn't `Sync`  
-[NonSync] and [UniqueView]/[UniqueViewMut] can be used together to access a `!Sync` unique storage."
-    )]
-    #[cfg_attr(
-        not(feature = "non_sync"),
-        doc = "* NonSync: must activate the *non_sync* feature"
-    )]
-    #[cfg_attr(
-        all(feature = "non_sync", docsrs),
-        doc = "* <span style=\"display: table;color: #2f2f2f;background-color: #C4ECFF;border-width: 1px;border-style: solid;border-color: #7BA5DB;padding: 3px;margin-bottom: 5px; font-size: 90%\">This is supported on <strong><code style=\"background-color: #C4ECFF\">feature=\"non_send\"</code> and <code style=\"background-color: #C4ECFF\">feature=\"non_sync\"</code></strong> only:</span>"
-    )]
-    #[cfg_attr(
-        all(feature = "non_send", feature = "non_sync", docsrs),
-        doc = "    * [NonSendSync]<[View]\\<T\\>> for a shared access to a `T` storage where `T` isn't `Send` nor `Sync`
-    * [NonSendSync]<[ViewMut]\\<T\\>> for an exclusive access to a `T` storage where `T` isn't `Send` nor `Sync`  
-[NonSendSync] and [UniqueView]/[UniqueViewMut] can be used together to access a `!Send + !Sync` unique storage."
-    )]
-    #[cfg_attr(
-        all(feature = "non_send", feature = "non_sync", not(docsrs)),
-        doc = "* [NonSendSync]<[View]\\<T\\>> for a shared access to a `T` storage where `T` isn't `Send` nor `Sync`
-* [NonSendSync]<[ViewMut]\\<T\\>> for an exclusive access to a `T` storage where `T` isn't `Send` nor `Sync`  
-[NonSendSync] and [UniqueView]/[UniqueViewMut] can be used together to access a `!Send + !Sync` unique storage."
-    )]
-    #[cfg_attr(
-        not(all(feature = "non_send", feature = "non_sync")),
-        doc = "* NonSendSync: must activate the *non_send* and *non_sync* features"
-    )]
-    #[doc = "
-### Borrows
+        let mut storage_names = HashMap::new();
+        storage_names.insert(TypeId::of::<AllStorages>(), "AllStorages");
+        for (storage_id, name) in all_storages.storage_names() {
+            if let StorageId::TypeId(type_id) = storage_id {
+                storage_names.insert(type_id, name);
+            }
+        }
 
-- [AllStorages] (exclusive) when requesting [AllStoragesViewMut]
-- [AllStorages] (shared) + storage (exclusive or shared) for all other views
+        Ok(scheduler.to_dot(name.as_ref(), range, &storage_names))
+    }
+    /// Returns the `name` workload in the DOT format, to be rendered with graphviz.  
+    /// Systems are grouped by batch, systems of the same batch can run in parallel.  
+    /// Each system is linked to the systems of previous batches it conflicts with, the edge lists the storages causing the conflict.  
+    /// Systems borrowing `!Send` or `!Sync` storages are dashed, they always run on the `World`'s thread.  
+    /// Storages that don't exist yet are named after their `TypeId`.  
+    /// Unwraps errors.
+    ///
+    /// ### Borrows
+    ///
+    /// - Scheduler (shared)
+    /// - [AllStorages] (shared) to name the storages
+    ///
+    /// ### Errors
+    ///
+    /// - Scheduler borrow failed.
+    /// - [AllStorages] borrow failed.
+    /// - Workload did not exist.
+    ///
+    /// [AllStorages]: struct.AllStorages.html
+    #[cfg(feature = "panic")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "panic")))]
+    #[track_caller]
+    pub fn workload_dot(&self, name: impl AsRef<str>) -> String {
+        self.try_workload_dot(name).unwrap()
+    }
+    /// Writes every system run recorded since the `World` was created to `path`, in the chrome://tracing JSON format.  
+    /// Each run lists the system's name, the thread it ran on, when it started and how long it took.  
+    /// Only systems run as part of a workload are recorded.
+    ///
+    /// ### Borrows
+    ///
+    /// - Scheduler (shared)
+    ///
+    /// ### Errors
+    ///
+    /// - Scheduler borrow failed.
+    /// - Creating or writing the file failed.
+    #[cfg(feature = "trace")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "trace")))]
+    pub fn try_write_trace(&self, path: impl AsRef<Path>) -> Result<(), error::WriteTrace> {
+        let scheduler = self
+            .scheduler
+            .try_borrow()
+            .map_err(|_| error::WriteTrace::Scheduler)?;
+        let file = File::create(path).map_err(error::WriteTrace::Io)?;
 
-### Errors
+        scheduler
+            .trace
+            .write(BufWriter::new(file))
+            .map_err(error::WriteTrace::Io)
+    }
+    /// Writes every system run recorded since the `World` was created to `path`, in the chrome://tracing JSON format.  
+    /// Each run lists the system's name, the thread it ran on, when it started and how long it took.  
+    /// Only systems run as part of a workload are recorded.  
+    /// Unwraps errors.
+    ///
+    /// ### Borrows
+    ///
+    /// - Scheduler (shared)
+    ///
+    /// ### Errors
+    ///
+    /// - Scheduler borrow failed.
+    /// - Creating or writing the file failed.
+    #[cfg(all(feature = "trace", feature = "panic"))]
+    #[cfg_attr(docsrs, doc(cfg(all(feature = "trace", feature = "panic"))))]
+    #[track_caller]
+    pub fn write_trace(&self, path: impl AsRef<Path>) {
+        self.try_write_trace(path).unwrap()
+    }
+    /// Used to create an entity without having to borrow its storage explicitly.  
+    /// The entity is only added when [EntityBuilder::try_build] or [EntityBuilder::build] is called.
+    ///
+    /// ### Borrows
+    ///
+    /// - [AllStorages] (shared)
+    ///
+    /// ### Errors
+    ///
+    /// - [AllStorages] borrow failed.
+    ///
+    /// [AllStorages]: struct.AllStorages.html
+    /// [EntityBuilder::build]: struct.EntityBuilder.html#method.build
+    /// [EntityBuilder::try_build]: struct.EntityBuilder.html#method.try_build
+    pub fn try_entity_builder(&self) -> Result<EntityBuilder<'_, (), ()>, error::Borrow> {
+        Ok(EntityBuilder::new(self.all_storages.try_borrow()?))
+    }
+    /// Used to create an entity without having to borrow its storage explicitly.  
+    /// The entity is only added when [EntityBuilder::try_build] or [EntityBuilder::build] is called.  
+    /// Unwraps error.
+    ///
+    /// ### Borrows
+    ///
+    /// - [AllStorages] (shared)
+    ///
+    /// ### Errors
+    ///
+    /// - [AllStorages] borrow failed.
+    ///
+    /// [AllStorages]: struct.AllStorages.html
+    /// [EntityBuilder::build]: struct.EntityBuilder.html#method.build
+    /// [EntityBuilder::try_build]: struct.EntityBuilder.html#method.try_build
+    #[cfg(feature = "panic")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "panic")))]
+    #[track_caller]
+    pub fn entity_builder(&self) -> EntityBuilder<'_, (), ()> {
+        self.try_entity_builder().unwrap()
+    }
+    /// Creates a new entity with the components passed as argument and returns its `EntityId`.  
+    /// `components` must always be a tuple, even for a single component.
+    ///
+    /// ### Borrows
+    ///
+    /// - [AllStorages] (shared)
+    /// - [Entities] (exclusive)
+    /// - Component storages (exclusive)
+    ///
+    /// ### Errors
+    ///
+    /// - [AllStorages] borrow failed.
+    /// - [Entities] borrow failed.
+    /// - Component storage borrow failed.
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{Get, View, World};
+    ///
+    /// let world = World::new();
+    ///
+    /// let entity = world.try_spawn((0usize, 1u32)).unwrap();
+    ///
+    /// world.run(|usizes: View<usize>, u32s: View<u32>| {
+    ///     assert_eq!((&usizes, &u32s).get(entity).unwrap(), (&0, &1));
+    /// });
+    /// ```
+    ///
+    /// [AllStorages]: struct.AllStorages.html
+    /// [Entities]: struct.Entities.html
+    pub fn try_spawn<C: Spawn>(&self, components: C) -> Result<EntityId, error::GetStorage> {
+        components.try_spawn(
+            &*self
+                .all_storages
+                .try_borrow()
+                .map_err(error::GetStorage::AllStoragesBorrow)?,
+        )
+    }
+    /// Creates a new entity with the components passed as argument and returns its `EntityId`.  
+    /// `components` must always be a tuple, even for a single component.  
+    /// Unwraps errors.
+    ///
+    /// ### Borrows
+    ///
+    /// - [AllStorages] (shared)
+    /// - [Entities] (exclusive)
+    /// - Component storages (exclusive)
+    ///
+    /// ### Errors
+    ///
+    /// - [AllStorages] borrow failed.
+    /// - [Entities] borrow failed.
+    /// - Component storage borrow failed.
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{Get, View, World};
+    ///
+    /// let world = World::new();
+    ///
+    /// let entity = world.spawn((0usize, 1u32));
+    ///
+    /// world.run(|usizes: View<usize>, u32s: View<u32>| {
+    ///     assert_eq!((&usizes, &u32s).get(entity).unwrap(), (&0, &1));
+    /// });
+    /// ```
+    ///
+    /// [AllStorages]: struct.AllStorages.html
+    /// [Entities]: struct.Entities.html
+    #[cfg(feature = "panic")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "panic")))]
+    #[track_caller]
+    pub fn spawn<C: Spawn>(&self, components: C) -> EntityId {
+        self.try_spawn(components).unwrap()
+    }
+    /// Deletes `entity` and all its components.  
+    /// Returns `true` if `entity` was alive.
+    ///
+    /// Only the storages where `entity` has a component are borrowed exclusively,
+    /// systems using other storages can run at the same time.
+    ///
+    /// ### Borrows
+    ///
+    /// - [AllStorages] (shared)
+    /// - [Entities] (exclusive)
+    /// - Storages `entity` is part of (exclusive)
+    ///
+    /// ### Errors
+    ///
+    /// - [AllStorages] borrow failed.
+    /// - [Entities] borrow failed.
+    /// - Component storage borrow failed, nothing is deleted.
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{ViewMut, World};
+    ///
+    /// let world = World::new();
+    /// let entity = world.spawn((0usize, 1u32));
+    ///
+    /// world.run(|u64s: ViewMut<u64>| {
+    ///     // `u64` storage is borrowed but `entity` doesn't have a component in it
+    ///     assert!(world.try_delete_entity(entity).unwrap());
+    /// });
+    /// ```
+    ///
+    /// [AllStorages]: struct.AllStorages.html
+    /// [Entities]: struct.Entities.html
+    pub fn try_delete_entity(&self, entity: EntityId) -> Result<bool, error::GetStorage> {
+        self.all_storages
+            .try_borrow()
+            .map_err(error::GetStorage::AllStoragesBorrow)?
+            .try_delete_entity(entity)
+    }
+    /// Deletes `entity` and all its components.  
+    /// Returns `true` if `entity` was alive.  
+    /// Unwraps errors.
+    ///
+    /// Only the storages where `entity` has a component are borrowed exclusively,
+    /// systems using other storages can run at the same time.
+    ///
+    /// ### Borrows
+    ///
+    /// - [AllStorages] (shared)
+    /// - [Entities] (exclusive)
+    /// - Storages `entity` is part of (exclusive)
+    ///
+    /// ### Errors
+    ///
+    /// - [AllStorages] borrow failed.
+    /// - [Entities] borrow failed.
+    /// - Component storage borrow failed, nothing is deleted.
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{ViewMut, World};
+    ///
+    /// let world = World::new();
+    /// let entity = world.spawn((0usize, 1u32));
+    ///
+    /// world.run(|u64s: ViewMut<u64>| {
+    ///     // `u64` storage is borrowed but `entity` doesn't have a component in it
+    ///     assert!(world.delete_entity(entity));
+    /// });
+    /// ```
+    ///
+    /// [AllStorages]: struct.AllStorages.html
+    /// [Entities]: struct.Entities.html
+    #[cfg(feature = "panic")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "panic")))]
+    #[track_caller]
+    pub fn delete_entity(&self, entity: EntityId) -> bool {
+        self.try_delete_entity(entity).unwrap()
+    }
+    /// Tags `entity` with `tag`, see [Tags].
+    ///
+    /// ### Borrows
+    ///
+    /// - [AllStorages] (shared)
+    /// - [Entities] (shared)
+    /// - [Tags] storage (exclusive)
+    ///
+    /// ### Errors
+    ///
+    /// - [AllStorages] borrow failed.
+    /// - [Entities] borrow failed.
+    /// - [Tags] storage borrow failed.
+    /// - `entity` isn't alive.
+    ///
+    /// [AllStorages]: struct.AllStorages.html
+    /// [Entities]: struct.Entities.html
+    /// [Tags]: struct.Tags.html
+    pub fn try_add_tag(&self, entity: EntityId, tag: &str) -> Result<(), error::AddTag> {
+        let all_storages = self
+            .all_storages
+            .try_borrow()
+            .map_err(error::GetStorage::AllStoragesBorrow)?;
 
-- [AllStorages] borrow failed.
-- Storage borrow failed.
-- Unique storage did not exist.
-- Error returned by user.
+        let is_alive = all_storages
+            .entities()
+            .map_err(error::GetStorage::Entities)?
+            .is_alive(entity);
 
-### Example
-```
-use shipyard::{EntityId, Get, ViewMut, World};
+        if is_alive {
+            all_storages.tags_mut()?.insert(entity, tag);
 
-fn sys1((entity, [x, y]): (EntityId, [f32; 2]), mut positions: ViewMut<[f32; 2]>) {
-    if let Ok(pos) = (&mut positions).get(entity) {
-        *pos = [x, y];
+            Ok(())
+        } else {
+            Err(error::AddTag::EntityIsNotAlive)
+        }
+    }
+    /// Tags `entity` with `tag`, see [Tags].
+    /// Unwraps errors.
+    ///
+    /// ### Borrows
+    ///
+    /// - [AllStorages] (shared)
+    /// - [Entities] (shared)
+    /// - [Tags] storage (exclusive)
+    ///
+    /// ### Errors
+    ///
+    /// - [AllStorages] borrow failed.
+    /// - [Entities] borrow failed.
+    /// - [Tags] storage borrow failed.
+    /// - `entity` isn't alive.
+    ///
+    /// [AllStorages]: struct.AllStorages.html
+    /// [Entities]: struct.Entities.html
+    /// [Tags]: struct.Tags.html
+    #[cfg(feature = "panic")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "panic")))]
+    #[track_caller]
+    pub fn add_tag(&self, entity: EntityId, tag: &str) {
+        self.try_add_tag(entity, tag).unwrap()
+    }
+    /// Removes `tag` from `entity`.
+    /// Returns `false` if `entity` didn't have this tag.
+    ///
+    /// ### Borrows
+    ///
+    /// - [AllStorages] (shared)
+    /// - [Tags] storage (exclusive)
+    ///
+    /// ### Errors
+    ///
+    /// - [AllStorages] borrow failed.
+    /// - [Tags] storage borrow failed.
+    ///
+    /// [AllStorages]: struct.AllStorages.html
+    /// [Tags]: struct.Tags.html
+    pub fn try_remove_tag(&self, entity: EntityId, tag: &str) -> Result<bool, error::GetStorage> {
+        Ok(self
+            .all_storages
+            .try_borrow()
+            .map_err(error::GetStorage::AllStoragesBorrow)?
+            .tags_mut()?
+            .remove(entity, tag))
+    }
+    /// Removes `tag` from `entity`.
+    /// Returns `false` if `entity` didn't have this tag.
+    /// Unwraps errors.
+    ///
+    /// ### Borrows
+    ///
+    /// - [AllStorages] (shared)
+    /// - [Tags] storage (exclusive)
+    ///
+    /// ### Errors
+    ///
+    /// - [AllStorages] borrow failed.
+    /// - [Tags] storage borrow failed.
+    ///
+    /// [AllStorages]: struct.AllStorages.html
+    /// [Tags]: struct.Tags.html
+    #[cfg(feature = "panic")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "panic")))]
+    #[track_caller]
+    pub fn remove_tag(&self, entity: EntityId, tag: &str) -> bool {
+        self.try_remove_tag(entity, tag).unwrap()
+    }
+    /// Returns the entities with `tag`, ordered by index.
+    ///
+    /// ### Borrows
+    ///
+    /// - [AllStorages] (shared)
+    /// - [Tags] storage (shared)
+    ///
+    /// ### Errors
+    ///
+    /// - [AllStorages] borrow failed.
+    /// - [Tags] storage borrow failed.
+    ///
+    /// [AllStorages]: struct.AllStorages.html
+    /// [Tags]: struct.Tags.html
+    pub fn try_tagged(&self, tag: &str) -> Result<Vec<EntityId>, error::GetStorage> {
+        Ok(self
+            .all_storages
+            .try_borrow()
+            .map_err(error::GetStorage::AllStoragesBorrow)?
+            .tags()?
+            .tagged(tag)
+            .collect())
+    }
+    /// Returns the entities with `tag`, ordered by index.
+    /// Unwraps errors.
+    ///
+    /// ### Borrows
+    ///
+    /// - [AllStorages] (shared)
+    /// - [Tags] storage (shared)
+    ///
+    /// ### Errors
+    ///
+    /// - [AllStorages] borrow failed.
+    /// - [Tags] storage borrow failed.
+    ///
+    /// [AllStorages]: struct.AllStorages.html
+    /// [Tags]: struct.Tags.html
+    #[cfg(feature = "panic")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "panic")))]
+    #[track_caller]
+    pub fn tagged(&self, tag: &str) -> Vec<EntityId> {
+        self.try_tagged(tag).unwrap()
+    }
+    /// Returns the [Signature] of `entity`, the ids of all storages where it has a component.
+    ///
+    /// ### Borrows
+    ///
+    /// - [AllStorages] (exclusive)
+    ///
+    /// ### Errors
+    ///
+    /// - [AllStorages] borrow failed.
+    ///
+    /// [AllStorages]: struct.AllStorages.html
+    /// [Signature]: struct.Signature.html
+    pub fn try_signature(&self, entity: EntityId) -> Result<Signature, error::Borrow> {
+        Ok(self.all_storages.try_borrow_mut()?.signature(entity))
+    }
+    /// Returns the [Signature] of `entity`, the ids of all storages where it has a component.  
+    /// Unwraps errors.
+    ///
+    /// ### Borrows
+    ///
+    /// - [AllStorages] (exclusive)
+    ///
+    /// ### Errors
+    ///
+    /// - [AllStorages] borrow failed.
+    ///
+    /// [AllStorages]: struct.AllStorages.html
+    /// [Signature]: struct.Signature.html
+    #[cfg(feature = "panic")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "panic")))]
+    #[track_caller]
+    pub fn signature(&self, entity: EntityId) -> Signature {
+        self.try_signature(entity).unwrap()
+    }
+    /// Returns the entities with exactly the components of `signature`, no more no less.
+    ///
+    /// ### Borrows
+    ///
+    /// - [AllStorages] (exclusive)
+    ///
+    /// ### Errors
+    ///
+    /// - [AllStorages] borrow failed.
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{Signature, StorageId, World};
+    ///
+    /// let world = World::new();
+    /// let entity0 = world.spawn((0usize, 1u32));
+    /// let entity1 = world.spawn((2u32, 3usize));
+    /// world.spawn((4u32,));
+    ///
+    /// let signature = Signature::new(&[StorageId::of::<u32>(), StorageId::of::<usize>()]);
+    ///
+    /// let mut matching = world.entities_matching_signature(&signature);
+    /// matching.sort_unstable();
+    ///
+    /// assert_eq!(matching, vec![entity0, entity1]);
+    /// ```
+    ///
+    /// [AllStorages]: struct.AllStorages.html
+    pub fn try_entities_matching_signature(
+        &self,
+        signature: &Signature,
+    ) -> Result<Vec<EntityId>, error::Borrow> {
+        Ok(self
+            .all_storages
+            .try_borrow_mut()?
+            .entities_matching_signature(signature))
+    }
+    /// Returns the entities with exactly the components of `signature`, no more no less.  
+    /// Unwraps errors.
+    ///
+    /// ### Borrows
+    ///
+    /// - [AllStorages] (exclusive)
+    ///
+    /// ### Errors
+    ///
+    /// - [AllStorages] borrow failed.
+    ///
+    /// [AllStorages]: struct.AllStorages.html
+    #[cfg(feature = "panic")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "panic")))]
+    #[track_caller]
+    pub fn entities_matching_signature(&self, signature: &Signature) -> Vec<EntityId> {
+        self.try_entities_matching_signature(signature).unwrap()
+    }
+    /// Sets the field at `path` of `entity`'s component to `value`.  
+    /// `path` starts with the component's type name, with or without its module path, followed by field names: `"Transform.position.x"`.  
+    /// The component's storage has to be set up with [SparseSet::setup_reflect].
+    ///
+    /// ### Borrows
+    ///
+    /// - [AllStorages] (exclusive)
+    ///
+    /// ### Errors
+    ///
+    /// - [AllStorages] borrow failed.
+    /// - No reflected storage is named like the component.
+    /// - `entity` doesn't have this component.
+    /// - The path doesn't lead to a field.
+    /// - The field isn't a `V`.
+    ///
+    /// ### Example
+    /// ```
+    /// use core::any::Any;
+    /// use shipyard::{Reflect, ViewMut, World};
+    ///
+    /// struct Transform {
+    ///     x: f32,
+    /// }
+    ///
+    /// impl Reflect for Transform {
+    ///     fn field(&self, name: &str) -> Option<&dyn Reflect> {
+    ///         match name {
+    ///             "x" => Some(&self.x),
+    ///             _ => None,
+    ///         }
+    ///     }
+    ///     fn field_mut(&mut self, name: &str) -> Option<&mut dyn Reflect> {
+    ///         match name {
+    ///             "x" => Some(&mut self.x),
+    ///             _ => None,
+    ///         }
+    ///     }
+    ///     fn as_any(&self) -> &dyn Any {
+    ///         self
+    ///     }
+    ///     fn as_any_mut(&mut self) -> &mut dyn Any {
+    ///         self
+    ///     }
+    /// }
+    ///
+    /// let world = World::new();
+    /// world.run(|mut transforms: ViewMut<Transform>| transforms.setup_reflect());
+    ///
+    /// let entity = world.spawn((Transform { x: 0.0 },));
+    ///
+    /// world.try_set_field(entity, "Transform.x", 3.0f32).unwrap();
+    /// assert_eq!(world.try_field::<f32>(entity, "Transform.x").unwrap(), 3.0);
+    /// ```
+    ///
+    /// [AllStorages]: struct.AllStorages.html
+    /// [SparseSet::setup_reflect]: struct.SparseSet.html#method.setup_reflect
+    pub fn try_set_field<V: 'static>(
+        &self,
+        entity: EntityId,
+        path: &str,
+        value: V,
+    ) -> Result<(), error::Reflect> {
+        self.all_storages
+            .try_borrow_mut()
+            .map_err(error::Reflect::AllStoragesBorrow)?
+            .set_field(entity, path, value)
+    }
+    /// Sets the field at `path` of `entity`'s component to `value`.  
+    /// `path` starts with the component's type name, with or without its module path, followed by field names: `"Transform.position.x"`.  
+    /// The component's storage has to be set up with [SparseSet::setup_reflect].  
+    /// Unwraps errors.
+    ///
+    /// ### Borrows
+    ///
+    /// - [AllStorages] (exclusive)
+    ///
+    /// ### Errors
+    ///
+    /// - [AllStorages] borrow failed.
+    /// - No reflected storage is named like the component.
+    /// - `entity` doesn't have this component.
+    /// - The path doesn't lead to a field.
+    /// - The field isn't a `V`.
+    ///
+    /// ### Example
+    /// ```
+    /// use core::any::Any;
+    /// use shipyard::{Reflect, ViewMut, World};
+    ///
+    /// struct Transform {
+    ///     x: f32,
+    /// }
+    ///
+    /// impl Reflect for Transform {
+    ///     fn field(&self, name: &str) -> Option<&dyn Reflect> {
+    ///         match name {
+    ///             "x" => Some(&self.x),
+    ///             _ => None,
+    ///         }
+    ///     }
+    ///     fn field_mut(&mut self, name: &str) -> Option<&mut dyn Reflect> {
+    ///         match name {
+    ///             "x" => Some(&mut self.x),
+    ///             _ => None,
+    ///         }
+    ///     }
+    ///     fn as_any(&self) -> &dyn Any {
+    ///         self
+    ///     }
+    ///     fn as_any_mut(&mut self) -> &mut dyn Any {
+    ///         self
+    ///     }
+    /// }
+    ///
+    /// let world = World::new();
+    /// world.run(|mut transforms: ViewMut<Transform>| transforms.setup_reflect());
+    ///
+    /// let entity = world.spawn((Transform { x: 0.0 },));
+    ///
+    /// world.set_field(entity, "Transform.x", 3.0f32);
+    /// assert_eq!(world.field::<f32>(entity, "Transform.x"), 3.0);
+    /// ```
+    ///
+    /// [AllStorages]: struct.AllStorages.html
+    /// [SparseSet::setup_reflect]: struct.SparseSet.html#method.setup_reflect
+    #[cfg(feature = "panic")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "panic")))]
+    #[track_caller]
+    pub fn set_field<V: 'static>(&self, entity: EntityId, path: &str, value: V) {
+        self.try_set_field(entity, path, value).unwrap()
     }
-}
-
-let world = World::new();
-
-world.run_with_data(sys1, (EntityId::dead(), [0., 0.]));
-```
-[AllStorages]: struct.AllStorages.html
-[EntitiesView]: struct.Entities.html
-[EntitiesViewMut]: struct.Entities.html
-[AllStoragesViewMut]: struct.AllStorages.html
-[World]: struct.World.html
-[View]: struct.View.html
-[ViewMut]: struct.ViewMut.html
-[UniqueView]: struct.UniqueView.html
-[UniqueViewMut]: struct.UniqueViewMut.html"]
-    #[cfg_attr(
-        feature = "parallel",
-        doc = "[ThreadPoolView]: struct.ThreadPoolView.html"
-    )]
-    #[cfg_attr(feature = "non_send", doc = "[NonSend]: struct.NonSend.html")]
-    #[cfg_attr(feature = "non_sync", doc = "[NonSync]: struct.NonSync.html")]
-    #[cfg_attr(
-        all(feature = "non_send", feature = "non_sync"),
-        doc = "[NonSendSync]: struct.NonSendSync.html"
-    )]
+    /// Returns a copy of the field at `path` of `entity`'s component.  
+    /// `path` starts with the component's type name, with or without its module path, followed by field names: `"Transform.position.x"`.  
+    /// The component's storage has to be set up with [SparseSet::setup_reflect].
+    ///
+    /// ### Borrows
+    ///
+    /// - [AllStorages] (exclusive)
+    ///
+    /// ### Errors
+    ///
+    /// - [AllStorages] borrow failed.
+    /// - No reflected storage is named like the component.
+    /// - `entity` doesn't have this component.
+    /// - The path doesn't lead to a field.
+    /// - The field isn't a `V`.
+    ///
+    /// ### Example
+    /// ```
+    /// use core::any::Any;
+    /// use shipyard::{Reflect, ViewMut, World};
+    ///
+    /// struct Transform {
+    ///     x: f32,
+    /// }
+    ///
+    /// impl Reflect for Transform {
+    ///     fn field(&self, name: &str) -> Option<&dyn Reflect> {
+    ///         match name {
+    ///             "x" => Some(&self.x),
+    ///             _ => None,
+    ///         }
+    ///     }
+    ///     fn field_mut(&mut self, name: &str) -> Option<&mut dyn Reflect> {
+    ///         match name {
+    ///             "x" => Some(&mut self.x),
+    ///             _ => None,
+    ///         }
+    ///     }
+    ///     fn as_any(&self) -> &dyn Any {
+    ///         self
+    ///     }
+    ///     fn as_any_mut(&mut self) -> &mut dyn Any {
+    ///         self
+    ///     }
+    /// }
+    ///
+    /// let world = World::new();
+    /// world.run(|mut transforms: ViewMut<Transform>| transforms.setup_reflect());
+    ///
+    /// let entity = world.spawn((Transform { x: 0.0 },));
+    ///
+    /// world.try_set_field(entity, "Transform.x", 3.0f32).unwrap();
+    /// assert_eq!(world.try_field::<f32>(entity, "Transform.x").unwrap(), 3.0);
+    /// ```
+    ///
+    /// [AllStorages]: struct.AllStorages.html
+    /// [SparseSet::setup_reflect]: struct.SparseSet.html#method.setup_reflect
+    pub fn try_field<V: 'static + Clone>(
+        &self,
+        entity: EntityId,
+        path: &str,
+    ) -> Result<V, error::Reflect> {
+        self.all_storages
+            .try_borrow_mut()
+            .map_err(error::Reflect::AllStoragesBorrow)?
+            .field(entity, path)
+    }
+    /// Returns a copy of the field at `path` of `entity`'s component.  
+    /// `path` starts with the component's type name, with or without its module path, followed by field names: `"Transform.position.x"`.  
+    /// The component's storage has to be set up with [SparseSet::setup_reflect].  
+    /// Unwraps errors.
+    ///
+    /// ### Borrows
+    ///
+    /// - [AllStorages] (exclusive)
+    ///
+    /// ### Errors
+    ///
+    /// - [AllStorages] borrow failed.
+    /// - No reflected storage is named like the component.
+    /// - `entity` doesn't have this component.
+    /// - The path doesn't lead to a field.
+    /// - The field isn't a `V`.
+    ///
+    /// ### Example
+    /// ```
+    /// use core::any::Any;
+    /// use shipyard::{Reflect, ViewMut, World};
+    ///
+    /// struct Transform {
+    ///     x: f32,
+    /// }
+    ///
+    /// impl Reflect for Transform {
+    ///     fn field(&self, name: &str) -> Option<&dyn Reflect> {
+    ///         match name {
+    ///             "x" => Some(&self.x),
+    ///             _ => None,
+    ///         }
+    ///     }
+    ///     fn field_mut(&mut self, name: &str) -> Option<&mut dyn Reflect> {
+    ///         match name {
+    ///             "x" => Some(&mut self.x),
+    ///             _ => None,
+    ///         }
+    ///     }
+    ///     fn as_any(&self) -> &dyn Any {
+    ///         self
+    ///     }
+    ///     fn as_any_mut(&mut self) -> &mut dyn Any {
+    ///         self
+    ///     }
+    /// }
+    ///
+    /// let world = World::new();
+    /// world.run(|mut transforms: ViewMut<Transform>| transforms.setup_reflect());
+    ///
+    /// let entity = world.spawn((Transform { x: 0.0 },));
+    ///
+    /// world.set_field(entity, "Transform.x", 3.0f32);
+    /// assert_eq!(world.field::<f32>(entity, "Transform.x"), 3.0);
+    /// ```
+    ///
+    /// [AllStorages]: struct.AllStorages.html
+    /// [SparseSet::setup_reflect]: struct.SparseSet.html#method.setup_reflect
     #[cfg(feature = "panic")]
     #[cfg_attr(docsrs, doc(cfg(feature = "panic")))]
-    pub fn run_with_data<'s, Data, B, R, S: crate::system::System<'s, (Data,), B, R>>(
-        &'s self,
-        s: S,
-        data: Data,
-    ) -> R {
-        self.try_run_with_data(s, data).unwrap()
+    #[track_caller]
+    pub fn field<V: 'static + Clone>(&self, entity: EntityId, path: &str) -> V {
+        self.try_field(entity, path).unwrap()
     }
-    #[doc = "Borrows the requested storages and runs the function.
+    /// Serializes the [World] the way `ser_config` defines it.  
+    /// Human-readable formats like JSON or RON get named fields, binary formats like bincode get compact sequences.
+    ///
+    /// ### Borrows
+    ///
+    /// - [AllStorages] (exclusively)
+    ///
+    /// ### Errors
+    ///
+    /// - [AllStorages] borrow failed.
+    /// - Serialization error.
+    /// - Config not implemented. (temporary)
+    ///
+    /// [AllStorages]: struct.AllStorages.html
+    /// [World]: struct.World.html
+    #[cfg(feature = "serde1")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "serde1")))]
+    pub fn serialize<S>(
+        &self,
+        ser_config: GlobalSerConfig,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+        <S as serde::Serializer>::Ok: 'static,
+    {
+        if ser_config.same_binary && ser_config.with_entities {
+            serializer.serialize_newtype_struct(
+                "World",
+                &crate::storage::AllStoragesSerializer {
+                    all_storages: self
+                        .all_storages
+                        .try_borrow_mut()
+                        .map_err(|err| serde::ser::Error::custom(err))?,
+                    ser_config,
+                },
+            )
+        } else {
+            Err(serde::ser::Error::custom(
+                "ser_config other than default isn't implemented yet",
+            ))
+        }
+    }
+    /// Creates a new [World] from a deserializer the way `de_config` defines it.  
+    /// Entities keep the `EntityId` they were serialized with.
+    ///
+    /// Each entity is reported to `de_config.entity_mapping` with the same old and new `EntityId`.
+    ///
+    /// ### Errors
+    ///
+    /// - Deserialization error.
+    /// - Config not implemented. (temporary)
+    ///
+    /// [World]: struct.World.html
+    #[cfg(feature = "serde1")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "serde1")))]
+    pub fn new_deserialized<'de, D>(
+        de_config: GlobalDeConfig,
+        deserializer: D,
+    ) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let entity_mapping = de_config.entity_mapping.clone();
+        let world = World::deserialize_as_is(de_config, deserializer)?;
 
-You can use:
-* [View]\\<T\\> for a shared access to `T` storage
-* [ViewMut]\\<T\\> for an exclusive access to `T` storage
-* [EntitiesView] for a shared access to the entity storage
-* [EntitiesViewMut] for an exclusive reference to the entity storage
-* [AllStoragesViewMut] for an exclusive access to the storage of all components, ⚠️ can't coexist with any other storage borrow
-* [UniqueView]\\<T\\> for a shared access to a `T` unique storage
-* [UniqueViewMut]\\<T\\> for an exclusive access to a `T` unique storage
-* `Option<V>` with one or multiple views for fallible access to one or more storages"]
-    #[cfg_attr(
-        all(feature = "parallel", docsrs),
-        doc = "* <span style=\"display: table;color: #2f2f2f;background-color: #C4ECFF;border-width: 1px;border-style: solid;border-color: #7BA5DB;padding: 3px;margin-bottom: 5px; font-size: 90%\">This is supported on <strong><code style=\"background-color: #C4ECFF\">feature=\"parallel\"</code></strong> only:</span>"
-    )]
-    #[cfg_attr(
-        all(feature = "parallel", docsrs),
-        doc = "    * [ThreadPoolView] for a shared access to the `ThreadPool` used by the [World]"
-    )]
-    #[cfg_attr(
-        all(feature = "parallel", not(docsrs)),
-        doc = "* [ThreadPoolView] for a shared access to the `ThreadPool` used by the [World]"
-    )]
-    #[cfg_attr(
-        not(feature = "parallel"),
-        doc = "* ThreadPool: must activate the *parallel* feature"
-    )]
-    #[cfg_attr(
-        all(feature = "non_send", docsrs),
-        doc = "* <span style=\"display: table;color: #2f2f2f;background-color: #C4ECFF;border-width: 1px;border-style: solid;border-color: #7BA5DB;padding: 3px;margin-bottom: 5px; font-size: 90%\">This is supported on <strong><code style=\"background-color: #C4ECFF\">feature=\"non_send\"</code></strong> only:</span>"
-    )]
-    #[cfg_attr(
-        all(feature = "non_send", docsrs),
-        doc = "    * [NonSend]<[View]\\<T\\>> for a shared access to a `T` storage where `T` isn't `Send`
-    * [NonSend]<[ViewMut]\\<T\\>> for an exclusive access to a `T` storage where `T` isn't `Send`  
-[NonSend] and [UniqueView]/[UniqueViewMut] can be used together to access a `!Send` unique storage."
-    )]
-    #[cfg_attr(
-        all(feature = "non_send", not(docsrs)),
-        doc = "* [NonSend]<[View]\\<T\\>> for a shared access to a `T` storage where `T` isn't `Send`
-* [NonSend]<[ViewMut]\\<T\\>> for an exclusive access to a `T` storage where `T` isn't `Send`  
-[NonSend] and [UniqueView]/[UniqueViewMut] can be used together to access a `!Send` unique storage."
-    )]
-    #[cfg_attr(
-        not(feature = "non_send"),
-        doc = "* NonSend: must activate the *non_send* feature"
-    )]
-    #[cfg_attr(
-        all(feature = "non_sync", docsrs),
-        doc = "* <span style=\"display: table;color: #2f2f2f;background-color: #C4ECFF;border-width: 1px;border-style: solid;border-color: #7BA5DB;padding: 3px;margin-bottom: 5px; font-size: 90%\">This is supported on <strong><code style=\"background-color: #C4ECFF\">feature=\"non_sync\"</code></strong> only:</span>"
-    )]
-    #[cfg_attr(
-        all(feature = "non_sync", docsrs),
-        doc = "    * [NonSync]<[View]\\<T\\>> for a shared access to a `T` storage where `T` isn't `Sync`
-    * [NonSync]<[ViewMut]\\<T\\>> for an exclusive access to a `T` storage where `T` isn't `Sync`  
-[NonSync] and [UniqueView]/[UniqueViewMut] can be used together to access a `!Sync` unique storage."
-    )]
-    #[cfg_attr(
-        all(feature = "non_sync", not(docsrs)),
-        doc = "* [NonSync]<[View]\\<T\\>> for a shared access to a `T` storage where `T` isn't `Sync`
-* [NonSync]<[ViewMut]\\<T\\>> for an exclusive access to a `T` storage where `T` isn't `Sync`  
-[NonSync] and [UniqueView]/[UniqueViewMut] can be used together to access a `!Sync` unique storage."
-    )]
-    #[cfg_attr(
-        not(feature = "non_sync"),
-        doc = "* NonSync: must activate the *non_sync* feature"
-    )]
-    #[cfg_attr(
-        all(feature = "non_sync", docsrs),
-        doc = "* <span style=\"display: table;color: #2f2f2f;background-color: #C4ECFF;border-width: 1px;border-style: solid;border-color: #7BA5DB;padding: 3px;margin-bottom: 5px; font-size: 90%\">This is supported on <strong><code style=\"background-color: #C4ECFF\">feature=\"non_send\"</code> and <code style=\"background-color: #C4ECFF\">feature=\"non_sync\"</code></strong> only:</span>"
-    )]
-    #[cfg_attr(
-        all(feature = "non_send", feature = "non_sync", docsrs),
-        doc = "    * [NonSendSync]<[View]\\<T\\>> for a shared access to a `T` storage where `T` isn't `Send` nor `Sync`
-    * [NonSendSync]<[ViewMut]\\<T\\>> for an exclusive access to a `T` storage where `T` isn't `Send` nor `Sync`  
-[NonSendSync] and [UniqueView]/[UniqueViewMut] can be used together to access a `!Send + !Sync` unique storage."
-    )]
-    #[cfg_attr(
-        all(feature = "non_send", feature = "non_sync", not(docsrs)),
-        doc = "* [NonSendSync]<[View]\\<T\\>> for a shared access to a `T` storage where `T` isn't `Send` nor `Sync`
-* [NonSendSync]<[ViewMut]\\<T\\>> for an exclusive access to a `T` storage where `T` isn't `Send` nor `Sync`  
-[NonSendSync] and [UniqueView]/[UniqueViewMut] can be used together to access a `!Send + !Sync` unique storage."
-    )]
-    #[cfg_attr(
-        not(all(feature = "non_send", feature = "non_sync")),
-        doc = "* NonSendSync: must activate the *non_send* and *non_sync* features"
-    )]
-    #[doc = "
-### Borrows
+        if let Some(entity_mapping) = entity_mapping {
+            // the World was just created, no one else can borrow its storages
+            let all_storages = world.all_storages.try_borrow_mut().unwrap();
+
+            for entity in all_storages.entities().unwrap().iter() {
+                entity_mapping(entity, entity);
+            }
+        }
+
+        Ok(world)
+    }
+    /// Creates a new [World] from a deserializer, entities keep the `EntityId` they were serialized with.
+    ///
+    /// [World]: struct.World.html
+    #[cfg(feature = "serde1")]
+    fn deserialize_as_is<'de, D>(
+        de_config: GlobalDeConfig,
+        deserializer: D,
+    ) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        if de_config.existing_entities == ExistingEntities::AsNew {
+            let world = World::new();
+            let visitor = WorldVisitor {
+                all_storages: world
+                    .all_storages
+                    .try_borrow_mut()
+                    .map_err(serde::de::Error::custom)?,
+                de_config,
+            };
+
+            if deserializer.is_human_readable() {
+                deserializer.deserialize_struct("World", &["metadata", "storages"], visitor)?;
+            } else {
+                deserializer.deserialize_tuple(2, visitor)?;
+            }
 
-- [AllStorages] (exclusive) when requesting [AllStoragesViewMut]
-- [AllStorages] (shared) + storage (exclusive or shared) for all other views
+            Ok(world)
+        } else {
+            Err(serde::de::Error::custom(
+                "de_config other than default isn't implemented yet",
+            ))
+        }
+    }
+    /// Adds the entities of a serialized [World] to this one, each of them gets a new `EntityId`.  
+    /// Returns the old and new `EntityId` of each added entity.  
+    /// Storages this [World] doesn't have yet are added to it.
+    ///
+    /// The new `EntityId`s are reported to `de_config.entity_mapping`.  
+    /// Components from storages set up with [SparseSet::setup_serde_with_entity_ids] get the `EntityId`s they contain updated.
+    ///
+    /// ### Borrows
+    ///
+    /// - [AllStorages] (exclusively)
+    ///
+    /// ### Errors
+    ///
+    /// - [AllStorages] borrow failed.
+    /// - Storage borrow failed.
+    /// - Deserialization error.
+    /// - Config not implemented. (temporary)
+    ///
+    /// [AllStorages]: struct.AllStorages.html
+    /// [SparseSet::setup_serde_with_entity_ids]: struct.SparseSet.html#method.setup_serde_with_entity_ids
+    /// [World]: struct.World.html
+    #[cfg(feature = "serde1")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "serde1")))]
+    pub fn try_deserialize_into<'de, D>(
+        &self,
+        de_config: GlobalDeConfig,
+        deserializer: D,
+    ) -> Result<Vec<(EntityId, EntityId)>, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let mut all_storages = self
+            .all_storages
+            .try_borrow_mut()
+            .map_err(serde::de::Error::custom)?;
 
-### Errors
+        let entity_mapping = de_config.entity_mapping.clone();
+        let loaded = World::deserialize_as_is(de_config, deserializer)?;
+        // the World was just created, no one else can borrow its storages
+        let mut loaded_storages = loaded.all_storages.try_borrow_mut().unwrap();
 
-- [AllStorages] borrow failed.
-- Storage borrow failed.
-- Unique storage did not exist.
-- Error returned by user.
+        let mut entities = all_storages
+            .entities_mut()
+            .map_err(serde::de::Error::custom)?;
+        let mapping: Vec<(EntityId, EntityId)> = loaded_storages
+            .entities()
+            .unwrap()
+            .iter()
+            .map(|old_id| (old_id, entities.add_entity((), ())))
+            .collect();
+        drop(entities);
 
-### Example
-```
-use shipyard::{View, ViewMut, World};
+        let ids: Vec<EntityId> = mapping.iter().map(|&(_, new_id)| new_id).collect();
+        let lookup: HashMap<EntityId, EntityId> = mapping.iter().copied().collect();
+        let map = |id| lookup.get(&id).copied().unwrap_or(id);
 
-fn sys1(i32s: View<i32>) -> i32 {
-    0
-}
+        let loaded_storages = loaded_storages.storages();
+        let storage_ids: Vec<StorageId> = loaded_storages
+            .iter()
+            .map(|(&storage_id, _)| storage_id)
+            .filter(|&storage_id| storage_id != StorageId::of::<Entities>())
+            .collect();
 
-let world = World::new();
+        let storages = all_storages.storages();
+        for storage_id in storage_ids {
+            let loaded_storage = match loaded_storages.entry(storage_id) {
+                Entry::Occupied(entry) => entry.remove(),
+                Entry::Vacant(_) => unreachable!(),
+            };
+            let name = {
+                let mut loaded_storage = loaded_storage.0.try_borrow_mut().unwrap();
+                loaded_storage.remap_entities(&map);
+                loaded_storage.name()
+            };
 
-world
-    .try_run(|usizes: View<usize>, mut u32s: ViewMut<u32>| {
-        // -- snip --
-    })
-    .unwrap();
+            match storages.entry(storage_id) {
+                Entry::Occupied(entry) => {
+                    let mut storage = entry
+                        .into_mut()
+                        .0
+                        .try_borrow_mut()
+                        .map_err(serde::de::Error::custom)?;
 
-let i = world.try_run(sys1).unwrap();
-```
-[AllStorages]: struct.AllStorages.html
-[EntitiesView]: struct.Entities.html
-[EntitiesViewMut]: struct.Entities.html
-[AllStoragesViewMut]: struct.AllStorages.html
-[World]: struct.World.html
-[View]: struct.View.html
-[ViewMut]: struct.ViewMut.html
-[UniqueView]: struct.UniqueView.html
-[UniqueViewMut]: struct.UniqueViewMut.html"]
-    #[cfg_attr(
-        feature = "parallel",
-        doc = "[ThreadPoolView]: struct.ThreadPoolView.html"
-    )]
-    #[cfg_attr(feature = "non_send", doc = "[NonSend]: struct.NonSend.html")]
-    #[cfg_attr(feature = "non_sync", doc = "[NonSync]: struct.NonSync.html")]
-    #[cfg_attr(
-        all(feature = "non_send", feature = "non_sync"),
-        doc = "[NonSendSync]: struct.NonSendSync.html"
-    )]
-    pub fn try_run<'s, B, R, S: crate::system::System<'s, (), B, R>>(
-        &'s self,
-        s: S,
-    ) -> Result<R, error::Run> {
-        Ok(s.run((), {
-            #[cfg(feature = "parallel")]
-            {
-                S::try_borrow(&self.all_storages, &self.thread_pool)?
+                    storage.append(&mut *loaded_storage.0.try_borrow_mut().unwrap());
+                    storage.map_entity_ids(&ids, &map);
+                }
+                Entry::Vacant(entry) => {
+                    entry
+                        .insert(loaded_storage)
+                        .0
+                        .try_borrow_mut()
+                        .unwrap()
+                        .map_entity_ids(&ids, &map);
+                }
             }
-            #[cfg(not(feature = "parallel"))]
-            {
-                S::try_borrow(&self.all_storages)?
+
+            storages.set_name(storage_id, name);
+        }
+
+        // storages added from the loaded World still update its table
+        all_storages.track_signatures();
+
+        if let Some(entity_mapping) = entity_mapping {
+            for &(old_id, new_id) in &mapping {
+                entity_mapping(old_id, new_id);
             }
-        }))
+        }
+
+        Ok(mapping)
     }
-    #[doc = "Borrows the requested storages and runs the function.  
-Unwraps errors.
+    /// Adds the entities of a serialized [World] to this one, each of them gets a new `EntityId`.  
+    /// Returns the old and new `EntityId` of each added entity.  
+    /// Storages this [World] doesn't have yet are added to it.  
+    /// Unwraps errors.
+    ///
+    /// The new `EntityId`s are reported to `de_config.entity_mapping`.  
+    /// Components from storages set up with [SparseSet::setup_serde_with_entity_ids] get the `EntityId`s they contain updated.
+    ///
+    /// ### Borrows
+    ///
+    /// - [AllStorages] (exclusively)
+    ///
+    /// ### Errors
+    ///
+    /// - [AllStorages] borrow failed.
+    /// - Storage borrow failed.
+    /// - Deserialization error.
+    /// - Config not implemented. (temporary)
+    ///
+    /// [AllStorages]: struct.AllStorages.html
+    /// [SparseSet::setup_serde_with_entity_ids]: struct.SparseSet.html#method.setup_serde_with_entity_ids
+    /// [World]: struct.World.html
+    #[cfg(all(feature = "serde1", feature = "panic"))]
+    #[cfg_attr(docsrs, doc(cfg(all(feature = "serde1", feature = "panic"))))]
+    #[track_caller]
+    pub fn deserialize_into<'de, D>(
+        &self,
+        de_config: GlobalDeConfig,
+        deserializer: D,
+    ) -> Vec<(EntityId, EntityId)>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        self.try_deserialize_into(de_config, deserializer).unwrap()
+    }
+    /// Serializes the [World] with the default [GlobalSerConfig] and writes it to `path`, compressed or not depending on `format`.
+    ///
+    /// ### Borrows
+    ///
+    /// - [AllStorages] (exclusively)
+    ///
+    /// ### Errors
+    ///
+    /// - Creating or writing the file failed.
+    /// - [AllStorages] borrow failed.
+    /// - Serialization error.
+    /// - Compression error.
+    ///
+    /// [AllStorages]: struct.AllStorages.html
+    /// [GlobalSerConfig]: struct.GlobalSerConfig.html
+    /// [World]: struct.World.html
+    #[cfg(feature = "savegame")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "savegame")))]
+    pub fn try_save_to(
+        &self,
+        path: impl AsRef<Path>,
+        format: SerFormat,
+    ) -> Result<(), error::Savegame> {
+        let mut file = BufWriter::new(File::create(path).map_err(error::Savegame::Io)?);
 
-You can use:
-* [View]\\<T\\> for a shared access to `T` storage
-* [ViewMut]\\<T\\> for an exclusive access to `T` storage
-* [EntitiesView] for a shared access to the entity storage
-* [EntitiesViewMut] for an exclusive reference to the entity storage
-* [AllStoragesViewMut] for an exclusive access to the storage of all components, ⚠️ can't coexist with any other storage borrow
-* [UniqueView]\\<T\\> for a shared access to a `T` unique storage
-* [UniqueViewMut]\\<T\\> for an exclusive access to a `T` unique storage
-* `Option<V>` with one or multiple views for fallible access to one or more storages"]
-    #[cfg_attr(
-        all(feature = "parallel", docsrs),
-        doc = "* <span style=\"display: table;color: #2f2f2f;background-color: #C4ECFF;border-width: 1px;border-style: solid;border-color: #7BA5DB;padding: 3px;margin-bottom: 5px; font-size: 90%\">This is supported on <strong><code style=\"background-color: #C4ECFF\">feature=\"parallel\"</code></strong> only:</span>"
-    )]
-    #[cfg_attr(
-        all(feature = "parallel", docsrs),
-        doc = "    * [ThreadPoolView] for a shared access to the `ThreadPool` used by the [World]"
-    )]
-    #[cfg_attr(
-        all(feature = "parallel", not(docsrs)),
-        doc = "* [ThreadPoolView] for a shared access to the `ThreadPool` used by the [World]"
-    )]
-    #[cfg_attr(
-        not(feature = "parallel"),
-        doc = "* ThreadPool: must activate the *parallel* feature"
-    )]
-    #[cfg_attr(
-        all(feature = "non_send", docsrs),
-        doc = "* <span style=\"display: table;color: #2f2f2f;background-color: #C4ECFF;border-width: 1px;border-style: solid;border-color: #7BA5DB;padding: 3px;margin-bottom: 5px; font-size: 90%\">This is supported on <strong><code style=\"background-color: #C4ECFF\">feature=\"non_send\"</code></strong> only:</span>"
-    )]
-    #[cfg_attr(
-        all(feature = "non_send", docsrs),
-        doc = "    * [NonSend]<[View]\\<T\\>> for a shared access to a `T` storage where `T` isn't `Send`
-    * [NonSend]<[ViewMut]\\<T\\>> for an exclusive access to a `T` storage where `T` isn't `Send`  
-[NonSend] and [UniqueView]/[UniqueViewMut] can be used together to access a `!Send` unique storage."
-    )]
-    #[cfg_attr(
-        all(feature = "non_send", not(docsrs)),
-        doc = "* [NonSend]<[View]\\<T\\>> for a shared access to a `T` storage where `T` isn't `Send`
-* [NonSend]<[ViewMut]\\<T\\>> for an exclusive access to a `T` storage where `T` isn't `Send`  
-[NonSend] and [UniqueView]/[UniqueViewMut] can be used together to access a `!Send` unique storage."
-    )]
-    #[cfg_attr(
-        not(feature = "non_send"),
-        doc = "* NonSend: must activate the *non_send* feature"
-    )]
-    #[cfg_attr(
-        all(feature = "non_sync", docsrs),
-        doc = "* <span style=\"display: table;color: #2f2f2f;background-color: #C4ECFF;border-width: 1px;border-style: solid;border-color: #7BA5DB;padding: 3px;margin-bottom: 5px; font-size: 90%\">This is supported on <strong><code style=\"background-color: #C4ECFF\">feature=\"non_sync\"</code></strong> only:</span>"
-    )]
-    #[cfg_attr(
-        all(feature = "non_sync", docsrs),
-        doc = "    * [NonSync]<[View]\\<T\\>> for a shared access to a `T` storage where `T` isn't `Sync`
-    * [NonSync]<[ViewMut]\\<T\\>> for an exclusive access to a `T` storage where `T` isn't `Sync`  
-[NonSync] and [UniqueView]/[UniqueViewMut] can be used together to access a `!Sync` unique storage."
-    )]
-    #[cfg_attr(
-        all(feature = "non_sync", not(docsrs)),
-        doc = "* [NonSync]<[View]\\<T\\>> for a shared access to a `T` storage where `T` isn't `Sync`
-* [NonSync]<[ViewMut]\\<T\\>> for an exclusive access to a `T` storage where `T` isn't `Sync`  
-[NonSync] and [UniqueView]/[UniqueViewMut] can be used together to access a `!Sync` unique storage."
-    )]
-    #[cfg_attr(
-        not(feature = "non_sync"),
-        doc = "* NonSync: must activate the *non_sync* feature"
-    )]
-    #[cfg_attr(
-        all(feature = "non_sync", docsrs),
-        doc = "* <span style=\"display: table;color: #2f2f2f;background-color: #C4ECFF;border-width: 1px;border-style: solid;border-color: #7BA5DB;padding: 3px;margin-bottom: 5px; font-size: 90%\">This is supported on <strong><code style=\"background-color: #C4ECFF\">feature=\"non_send\"</code> and <code style=\"background-color: #C4ECFF\">feature=\"non_sync\"</code></strong> only:</span>"
-    )]
-    #[cfg_attr(
-        all(feature = "non_send", feature = "non_sync", docsrs),
-        doc = "    * [NonSendSync]<[View]\\<T\\>> for a shared access to a `T` storage where `T` isn't `Send` nor `Sync`
-    * [NonSendSync]<[ViewMut]\\<T\\>> for an exclusive access to a `T` storage where `T` isn't `Send` nor `Sync`  
-[NonSendSync] and [UniqueView]/[UniqueViewMut] can be used together to access a `!Send + !Sync` unique storage."
-    )]
-    #[cfg_attr(
-        all(feature = "non_send", feature = "non_sync", not(docsrs)),
-        doc = "* [NonSendSync]<[View]\\<T\\>> for a shared access to a `T` storage where `T` isn't `Send` nor `Sync`
-* [NonSendSync]<[ViewMut]\\<T\\>> for an exclusive access to a `T` storage where `T` isn't `Send` nor `Sync`  
-[NonSendSync] and [UniqueView]/[UniqueViewMut] can be used together to access a `!Send + !Sync` unique storage."
-    )]
-    #[cfg_attr(
-        not(all(feature = "non_send", feature = "non_sync")),
-        doc = "* NonSendSync: must activate the *non_send* and *non_sync* features"
-    )]
-    #[doc = "
-### Borrows
+        match format {
+            SerFormat::Bincode => {
+                self.serialize(
+                    GlobalSerConfig::default(),
+                    &mut bincode::Serializer::new(&mut file, bincode::DefaultOptions::new()),
+                )
+                .map_err(error::Savegame::Bincode)?;
+            }
+            SerFormat::BincodeLz4 => {
+                let mut encoder = lz4_flex::frame::FrameEncoder::new(&mut file);
 
-- [AllStorages] (exclusive) when requesting [AllStoragesViewMut]
-- [AllStorages] (shared) + storage (exclusive or shared) for all other views
+                self.serialize(
+                    GlobalSerConfig::default(),
+                    &mut bincode::Serializer::new(&mut encoder, bincode::DefaultOptions::new()),
+                )
+                .map_err(error::Savegame::Bincode)?;
 
-### Errors
+                encoder.finish().map_err(error::Savegame::Compression)?;
+            }
+        }
 
-- [AllStorages] borrow failed.
-- Storage borrow failed.
-- Unique storage did not exist.
-- Error returned by user.
+        file.flush().map_err(error::Savegame::Io)
+    }
+    /// Serializes the [World] with the default [GlobalSerConfig] and writes it to `path`, compressed or not depending on `format`.  
+    /// Unwraps errors.
+    ///
+    /// ### Borrows
+    ///
+    /// - [AllStorages] (exclusively)
+    ///
+    /// ### Errors
+    ///
+    /// - Creating or writing the file failed.
+    /// - [AllStorages] borrow failed.
+    /// - Serialization error.
+    /// - Compression error.
+    ///
+    /// [AllStorages]: struct.AllStorages.html
+    /// [GlobalSerConfig]: struct.GlobalSerConfig.html
+    /// [World]: struct.World.html
+    #[cfg(all(feature = "savegame", feature = "panic"))]
+    #[cfg_attr(docsrs, doc(cfg(all(feature = "savegame", feature = "panic"))))]
+    #[track_caller]
+    pub fn save_to(&self, path: impl AsRef<Path>, format: SerFormat) {
+        self.try_save_to(path, format).unwrap()
+    }
+    /// Creates a new [World] from a file written by [World::save_to] the way `de_config` defines it.  
+    /// Compressed files are detected and decompressed.
+    ///
+    /// ### Errors
+    ///
+    /// - Opening or reading the file failed.
+    /// - Deserialization error.
+    /// - Decompression error.
+    ///
+    /// [World]: struct.World.html
+    /// [World::save_to]: struct.World.html#method.save_to
+    #[cfg(feature = "savegame")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "savegame")))]
+    pub fn try_load_from(
+        path: impl AsRef<Path>,
+        de_config: GlobalDeConfig,
+    ) -> Result<Self, error::Savegame> {
+        const LZ4_MAGIC: [u8; 4] = [0x04, 0x22, 0x4d, 0x18];
+
+        let mut file = BufReader::new(File::open(path).map_err(error::Savegame::Io)?);
+        let compressed = file
+            .fill_buf()
+            .map_err(error::Savegame::Io)?
+            .starts_with(&LZ4_MAGIC);
+
+        if compressed {
+            World::new_deserialized(
+                de_config,
+                &mut bincode::Deserializer::with_reader(
+                    lz4_flex::frame::FrameDecoder::new(file),
+                    bincode::DefaultOptions::new(),
+                ),
+            )
+            .map_err(error::Savegame::Bincode)
+        } else {
+            World::new_deserialized(
+                de_config,
+                &mut bincode::Deserializer::with_reader(file, bincode::DefaultOptions::new()),
+            )
+            .map_err(error::Savegame::Bincode)
+        }
+    }
+    /// Creates a new [World] from a file written by [World::save_to] the way `de_config` defines it.  
+    /// Compressed files are detected and decompressed.  
+    /// Unwraps errors.
+    ///
+    /// ### Errors
+    ///
+    /// - Opening or reading the file failed.
+    /// - Deserialization error.
+    /// - Decompression error.
+    ///
+    /// [World]: struct.World.html
+    /// [World::save_to]: struct.World.html#method.save_to
+    #[cfg(all(feature = "savegame", feature = "panic"))]
+    #[cfg_attr(docsrs, doc(cfg(all(feature = "savegame", feature = "panic"))))]
+    #[track_caller]
+    pub fn load_from(path: impl AsRef<Path>, de_config: GlobalDeConfig) -> Self {
+        Self::try_load_from(path, de_config).unwrap()
+    }
+    /// Serializes all entities with at least one component from a storage with an identifier into a scene.  
+    /// The scene is a sequence of entities, each entity is a map from its components' identifier to their value.  
+    /// The entity's `EntityId` is stored under the `"id"` key, it is used to update components referring to it when loading the scene.  
+    /// Using a human-readable format like JSON or RON makes the scene easy to edit by hand.  
+    /// Components from storages without identifier are not part of the scene.
+    ///
+    /// ### Borrows
+    ///
+    /// - [AllStorages] (exclusively)
+    ///
+    /// ### Errors
+    ///
+    /// - [AllStorages] borrow failed.
+    /// - Storage borrow failed.
+    /// - Serialization error.
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{Identifier, SerConfig, ViewMut, World};
+    ///
+    /// let world = World::new();
+    ///
+    /// world.run(|mut u32s: ViewMut<u32>| {
+    ///     u32s.setup_serde(SerConfig {
+    ///         identifier: Some(Identifier::new("Health")),
+    ///         ..Default::default()
+    ///     });
+    /// });
+    ///
+    /// world.spawn((100u32,));
+    ///
+    /// let mut scene = Vec::new();
+    /// world
+    ///     .serialize_scene(&mut serde_json::Serializer::new(&mut scene))
+    ///     .unwrap();
+    ///
+    /// assert_eq!(scene, br#"[{"id":[0,0],"Health":100}]"#);
+    /// ```
+    ///
+    /// [AllStorages]: struct.AllStorages.html
+    #[cfg(feature = "serde1")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "serde1")))]
+    pub fn serialize_scene<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let mut all_storages = self
+            .all_storages
+            .try_borrow_mut()
+            .map_err(serde::ser::Error::custom)?;
 
-### Example
-```
-use shipyard::{View, ViewMut, World};
+        let entities = all_storages
+            .entities()
+            .map_err(serde::ser::Error::custom)?
+            .iter()
+            .collect();
 
-fn sys1(i32s: View<i32>) -> i32 {
-    0
-}
+        let mut storages = all_storages
+            .storages()
+            .values()
+            .filter_map(|storage| match storage.0.try_borrow() {
+                Ok(storage) if storage.identifier().is_some() => Some(Ok(storage)),
+                Ok(_) => None,
+                Err(err) => Some(Err(serde::ser::Error::custom(err))),
+            })
+            .collect::<Result<Vec<_>, S::Error>>()?;
 
-let world = World::new();
+        // storages are stored in a HashMap, sorting them keeps the output the same for the same World
+        storages.sort_unstable_by(|storage1, storage2| {
+            storage1.identifier().cmp(&storage2.identifier())
+        });
 
-world.run(|usizes: View<usize>, mut u32s: ViewMut<u32>| {
-    // -- snip --
-});
+        let scene = crate::scene::SceneSerializer { entities, storages };
 
-let i = world.run(sys1);
-```
-[AllStorages]: struct.AllStorages.html
-[EntitiesView]: struct.Entities.html
-[EntitiesViewMut]: struct.Entities.html
-[AllStoragesViewMut]: struct.AllStorages.html
-[World]: struct.World.html
-[View]: struct.View.html
-[ViewMut]: struct.ViewMut.html
-[UniqueView]: struct.UniqueView.html
-[UniqueViewMut]: struct.UniqueViewMut.html"]
-    #[cfg_attr(
-        feature = "parallel",
-        doc = "[ThreadPoolView]: struct.ThreadPoolView.html"
-    )]
-    #[cfg_attr(feature = "non_send", doc = "[NonSend]: struct.NonSend.html")]
-    #[cfg_attr(feature = "non_sync", doc = "[NonSync]: struct.NonSync.html")]
-    #[cfg_attr(
-        all(feature = "non_send", feature = "non_sync"),
-        doc = "[NonSendSync]: struct.NonSendSync.html"
-    )]
-    #[cfg(feature = "panic")]
-    #[cfg_attr(docsrs, doc(cfg(feature = "panic")))]
-    pub fn run<'s, B, R, S: crate::system::System<'s, (), B, R>>(&'s self, s: S) -> R {
-        self.try_run(s).unwrap()
+        serde::Serialize::serialize(&scene, serializer)
     }
-    /// Modifies the current default workload to `name`.
+    /// Adds all entities from a scene to the [World], each of them gets a new `EntityId`.  
+    /// Returns the `"id"`, if any, and new `EntityId` of each entity in the order they appear in the scene.  
+    /// Storages have to be set up with an identifier matching the one in the scene beforehand.  
+    /// Entities deserialized before an error occurs are kept in the [World].
+    ///
+    /// Entities with an `"id"` have their new `EntityId` reported to `de_config.entity_mapping`.  
+    /// Components from storages set up with [SparseSet::setup_serde_with_entity_ids] get the `EntityId`s they contain updated.
     ///
     /// ### Borrows
     ///
-    /// - Scheduler (exclusive)
+    /// - [AllStorages] (exclusively)
     ///
     /// ### Errors
     ///
-    /// - Scheduler borrow failed.
-    /// - Workload did not exist.
-    pub fn try_set_default_workload(
+    /// - [AllStorages] borrow failed.
+    /// - Storage borrow failed.
+    /// - No storage matches an identifier.
+    /// - Deserialization error.
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{GlobalDeConfig, Identifier, SerConfig, View, ViewMut, World};
+    ///
+    /// let world = World::new();
+    ///
+    /// world.run(|mut u32s: ViewMut<u32>| {
+    ///     u32s.setup_serde(SerConfig {
+    ///         identifier: Some(Identifier::new("Health")),
+    ///         ..Default::default()
+    ///     });
+    /// });
+    ///
+    /// let entities = world
+    ///     .load_scene(
+    ///         GlobalDeConfig::default(),
+    ///         &mut serde_json::Deserializer::from_str(r#"[{ "Health": 100 }, { "Health": 50 }]"#),
+    ///     )
+    ///     .unwrap();
+    ///
+    /// world.run(|u32s: View<u32>| {
+    ///     assert_eq!(u32s[entities[0].1], 100);
+    ///     assert_eq!(u32s[entities[1].1], 50);
+    /// });
+    /// ```
+    ///
+    /// [AllStorages]: struct.AllStorages.html
+    /// [SparseSet::setup_serde_with_entity_ids]: struct.SparseSet.html#method.setup_serde_with_entity_ids
+    /// [World]: struct.World.html
+    #[cfg(feature = "serde1")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "serde1")))]
+    pub fn load_scene<'de, D>(
         &self,
-        name: impl Into<Cow<'static, str>>,
-    ) -> Result<(), error::SetDefaultWorkload> {
-        if let Ok(mut scheduler) = self.scheduler.try_borrow_mut() {
-            if let Some(workload) = scheduler.workloads.get(&name.into()) {
-                scheduler.default = workload.clone();
-                Ok(())
+        de_config: GlobalDeConfig,
+        deserializer: D,
+    ) -> Result<Vec<(Option<EntityId>, EntityId)>, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let mut all_storages = self
+            .all_storages
+            .try_borrow_mut()
+            .map_err(serde::de::Error::custom)?;
+
+        let mut entities = None;
+        let mut storages = Vec::new();
+
+        for (storage_id, storage) in all_storages.storages().iter_mut() {
+            if *storage_id == StorageId::of::<Entities>() {
+                entities = Some(storage.entities_mut().map_err(serde::de::Error::custom)?);
             } else {
-                Err(error::SetDefaultWorkload::MissingWorkload)
+                let storage = storage
+                    .0
+                    .try_borrow_mut()
+                    .map_err(serde::de::Error::custom)?;
+
+                if storage.identifier().is_some() {
+                    storages.push(storage);
+                }
             }
-        } else {
-            Err(error::SetDefaultWorkload::Borrow)
         }
+
+        serde::de::DeserializeSeed::deserialize(
+            crate::scene::SceneDeserializer {
+                // AllStorages is always created with Entities
+                entities: entities.unwrap(),
+                storages,
+                de_config,
+            },
+            deserializer,
+        )
     }
-    /// Modifies the current default workload to `name`.  
+    /// Starts recording all structural operations: entity creation and deletion, components added and removed.  
+    /// Only components from storages set up with [SparseSet::setup_recording] are recorded.  
+    /// Restarts the recording if one was already in progress.
+    ///
+    /// ### Borrows
+    ///
+    /// - [AllStorages] (exclusively)
+    ///
+    /// ### Errors
+    ///
+    /// - [AllStorages] borrow failed.
+    /// - Storage borrow failed.
+    ///
+    /// [AllStorages]: struct.AllStorages.html
+    /// [SparseSet::setup_recording]: struct.SparseSet.html#method.setup_recording
+    #[cfg(feature = "serde1")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "serde1")))]
+    pub fn try_start_recording(&self) -> Result<(), error::Borrow> {
+        let mut all_storages = self.all_storages.try_borrow_mut()?;
+
+        for storage in all_storages.storages().values_mut() {
+            storage.0.try_borrow_mut()?.start_recording();
+        }
+
+        Ok(())
+    }
+    /// Starts recording all structural operations: entity creation and deletion, components added and removed.  
+    /// Only components from storages set up with [SparseSet::setup_recording] are recorded.  
+    /// Restarts the recording if one was already in progress.  
     /// Unwraps errors.
     ///
     /// ### Borrows
     ///
-    /// - Scheduler (exclusive)
+    /// - [AllStorages] (exclusively)
     ///
     /// ### Errors
     ///
-    /// - Scheduler borrow failed.
-    /// - Workload did not exist.
-    #[cfg(feature = "panic")]
-    #[cfg_attr(docsrs, doc(cfg(feature = "panic")))]
-    pub fn set_default_workload(&self, name: impl Into<Cow<'static, str>>) {
-        self.try_set_default_workload(name).unwrap();
+    /// - [AllStorages] borrow failed.
+    /// - Storage borrow failed.
+    ///
+    /// [AllStorages]: struct.AllStorages.html
+    /// [SparseSet::setup_recording]: struct.SparseSet.html#method.setup_recording
+    #[cfg(all(feature = "serde1", feature = "panic"))]
+    #[cfg_attr(docsrs, doc(cfg(all(feature = "serde1", feature = "panic"))))]
+    #[track_caller]
+    pub fn start_recording(&self) {
+        self.try_start_recording().unwrap()
     }
-    /// A workload is a collection of systems. They will execute as much in parallel as possible.  
-    /// They are evaluated first to last when they can't be parallelized.  
-    /// The default workload will automatically be set to the first workload added.
+    /// Stops the recording and returns all commands recorded since [World::start_recording], in the order they happened.  
+    /// The [Recording] can then be serialized and replayed with [World::replay].
     ///
     /// ### Borrows
     ///
-    /// - Scheduler (exclusive)
+    /// - [AllStorages] (exclusively)
     ///
     /// ### Errors
     ///
-    /// - Scheduler borrow failed.
-    /// - Workload with an identical name already present.
+    /// - [AllStorages] borrow failed.
+    /// - Storage borrow failed.
     ///
     /// ### Example
     /// ```
-    /// use shipyard::{system, EntitiesViewMut, IntoIter, Shiperator, View, ViewMut, World};
+    /// use shipyard::{AllStoragesViewMut, Identifier, SerConfig, ViewMut, World};
     ///
-    /// fn add(mut usizes: ViewMut<usize>, u32s: View<u32>) {
-    ///     for (x, &y) in (&mut usizes, &u32s).iter() {
-    ///         *x += y as usize;
-    ///     }
-    /// }
+    /// let world = World::new();
     ///
-    /// fn check(usizes: View<usize>) {
-    ///     let mut iter = usizes.iter();
-    ///     assert_eq!(iter.next(), Some(&1));
-    ///     assert_eq!(iter.next(), Some(&5));
-    ///     assert_eq!(iter.next(), Some(&9));
-    /// }
+    /// world.run(|mut u32s: ViewMut<u32>| {
+    ///     u32s.setup_recording(SerConfig {
+    ///         identifier: Some(Identifier::new("Health")),
+    ///         ..Default::default()
+    ///     });
+    /// });
     ///
-    /// let world = World::new();
+    /// world.start_recording();
     ///
-    /// world.run(
-    ///     |mut entities: EntitiesViewMut, mut usizes: ViewMut<usize>, mut u32s: ViewMut<u32>| {
-    ///         entities.add_entity((&mut usizes, &mut u32s), (0, 1));
-    ///         entities.add_entity((&mut usizes, &mut u32s), (2, 3));
-    ///         entities.add_entity((&mut usizes, &mut u32s), (4, 5));
-    ///     },
-    /// );
+    /// let entity = world.spawn((100u32,));
+    /// world.borrow::<AllStoragesViewMut>().delete(entity);
     ///
-    /// world
-    ///     .try_add_workload("Add & Check")
-    ///     .unwrap()
-    ///     .with_system(system!(add))
-    ///     .with_system(system!(check))
-    ///     .build();
+    /// let recording = world.try_stop_recording().unwrap();
     ///
-    /// world.run_default();
+    /// assert_eq!(
+    ///     serde_json::to_string(&recording).unwrap(),
+    ///     r#"[["spawn",[0,0]],["add",[0,0],"Health",100],["despawn",[0,0]],["remove",[0,0],"Health"]]"#
+    /// );
     /// ```
-    pub fn try_add_workload(
-        &self,
-        name: impl Into<Cow<'static, str>>,
-    ) -> Result<WorkloadBuilder<'_>, error::AddWorkload> {
-        if let Ok(scheduler) = self.scheduler.try_borrow_mut() {
-            let name = name.into();
+    ///
+    /// [AllStorages]: struct.AllStorages.html
+    /// [Recording]: struct.Recording.html
+    /// [World::replay]: struct.World.html#method.replay
+    /// [World::start_recording]: struct.World.html#method.start_recording
+    #[cfg(feature = "serde1")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "serde1")))]
+    pub fn try_stop_recording(&self) -> Result<Recording, error::Borrow> {
+        let mut all_storages = self.all_storages.try_borrow_mut()?;
 
-            if scheduler.workloads.contains_key(&name) {
-                Err(error::AddWorkload::AlreadyExists)
-            } else {
-                Ok(WorkloadBuilder::new(scheduler, name))
-            }
-        } else {
-            Err(error::AddWorkload::Borrow)
+        let mut commands = Vec::new();
+        for storage in all_storages.storages().values_mut() {
+            commands.extend(storage.0.try_borrow_mut()?.stop_recording());
         }
+
+        commands.sort_unstable_by_key(|(index, _)| *index);
+
+        Ok(Recording { commands })
     }
-    /// A workload is a collection of systems. They will execute as much in parallel as possible.  
-    /// They are evaluated first to last when they can't be parallelized.  
-    /// The default workload will automatically be set to the first workload added.  
+    /// Stops the recording and returns all commands recorded since [World::start_recording], in the order they happened.  
+    /// The [Recording] can then be serialized and replayed with [World::replay].  
     /// Unwraps errors.
     ///
     /// ### Borrows
     ///
-    /// - Scheduler (exclusive)
+    /// - [AllStorages] (exclusively)
     ///
     /// ### Errors
     ///
-    /// - Scheduler borrow failed.
-    /// - Workload with an identical name already present.
+    /// - [AllStorages] borrow failed.
+    /// - Storage borrow failed.
+    ///
+    /// [AllStorages]: struct.AllStorages.html
+    /// [Recording]: struct.Recording.html
+    /// [World::replay]: struct.World.html#method.replay
+    /// [World::start_recording]: struct.World.html#method.start_recording
+    #[cfg(all(feature = "serde1", feature = "panic"))]
+    #[cfg_attr(docsrs, doc(cfg(all(feature = "serde1", feature = "panic"))))]
+    #[track_caller]
+    pub fn stop_recording(&self) -> Recording {
+        self.try_stop_recording().unwrap()
+    }
+    /// Applies all commands of a serialized [Recording], in order.  
+    /// Storages have to be set up with an identifier matching the one in the recording beforehand.  
+    /// Recorded entities get a new `EntityId`, reported to `de_config.entity_mapping` like [World::load_scene].  
+    /// The recorded and new `EntityId` of each of them are also returned.  
+    /// Replaying a recording started on an empty [World] in an empty [World] gives the same `EntityId`s.  
+    /// Commands can only target entities spawned during the recording, and only their components are mapped to the new `EntityId`s.
+    ///
+    /// ### Borrows
+    ///
+    /// - [AllStorages] (exclusively)
+    ///
+    /// ### Errors
+    ///
+    /// - [AllStorages] borrow failed.
+    /// - Storage borrow failed.
+    /// - No storage with a matching identifier.
+    /// - A command targets an entity the recording didn't spawn or already despawned.
+    /// - Deserialization error.
     ///
     /// ### Example
     /// ```
-    /// use shipyard::{system, EntitiesViewMut, IntoIter, Shiperator, View, ViewMut, World};
+    /// use shipyard::{Identifier, SerConfig, View, ViewMut, World};
     ///
-    /// fn add(mut usizes: ViewMut<usize>, u32s: View<u32>) {
-    ///     for (x, &y) in (&mut usizes, &u32s).iter() {
-    ///         *x += y as usize;
-    ///     }
+    /// fn setup(world: &World) {
+    ///     world.run(|mut u32s: ViewMut<u32>| {
+    ///         u32s.setup_recording(SerConfig {
+    ///             identifier: Some(Identifier::new("Health")),
+    ///             ..Default::default()
+    ///         });
+    ///     });
     /// }
     ///
-    /// fn check(usizes: View<usize>) {
-    ///     let mut iter = usizes.iter();
-    ///     assert_eq!(iter.next(), Some(&1));
-    ///     assert_eq!(iter.next(), Some(&5));
-    ///     assert_eq!(iter.next(), Some(&9));
-    /// }
+    /// let world = World::new();
+    /// setup(&world);
+    ///
+    /// world.start_recording();
+    /// let entity = world.spawn((100u32,));
+    /// let recording = serde_json::to_string(&world.stop_recording()).unwrap();
+    ///
+    /// let replay = World::new();
+    /// setup(&replay);
+    ///
+    /// replay
+    ///     .replay(Default::default(), &mut serde_json::Deserializer::from_str(&recording))
+    ///     .unwrap();
+    ///
+    /// replay.run(|u32s: View<u32>| assert_eq!(u32s[entity], 100));
+    /// ```
+    ///
+    /// [AllStorages]: struct.AllStorages.html
+    /// [Recording]: struct.Recording.html
+    /// [World]: struct.World.html
+    /// [World::load_scene]: struct.World.html#method.load_scene
+    #[cfg(feature = "serde1")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "serde1")))]
+    pub fn replay<'de, D>(
+        &self,
+        de_config: GlobalDeConfig,
+        deserializer: D,
+    ) -> Result<Vec<(EntityId, EntityId)>, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let mut all_storages = self
+            .all_storages
+            .try_borrow_mut()
+            .map_err(serde::de::Error::custom)?;
+
+        serde::de::DeserializeSeed::deserialize(
+            crate::recording::ReplayDeserializer {
+                all_storages: &mut all_storages,
+                de_config,
+            },
+            deserializer,
+        )
+    }
+    /// Saves the current state of all storages set up with [SparseSet::setup_snapshot] and `Entities`.  
+    /// Storages not modified since the previous snapshot share their state with it.  
+    /// Clears the redo stack and drops the oldest snapshot when over capacity.
+    ///
+    /// ### Borrows
+    ///
+    /// - Snapshots (exclusive)
+    /// - [AllStorages] (exclusive)
+    ///
+    /// ### Errors
+    ///
+    /// - Snapshots borrow failed.
+    /// - [AllStorages] borrow failed.
+    /// - Storage borrow failed.
+    ///
+    /// [AllStorages]: struct.AllStorages.html
+    /// [SparseSet::setup_snapshot]: struct.SparseSet.html#method.setup_snapshot
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{ViewMut, World};
     ///
     /// let world = World::new();
     ///
-    /// world.run(
-    ///     |mut entities: EntitiesViewMut, mut usizes: ViewMut<usize>, mut u32s: ViewMut<u32>| {
-    ///         entities.add_entity((&mut usizes, &mut u32s), (0, 1));
-    ///         entities.add_entity((&mut usizes, &mut u32s), (2, 3));
-    ///         entities.add_entity((&mut usizes, &mut u32s), (4, 5));
-    ///     },
-    /// );
+    /// world.run(|mut u32s: ViewMut<u32>| u32s.setup_snapshot());
     ///
-    /// world
-    ///     .add_workload("Add & Check")
-    ///     .with_system(system!(add))
-    ///     .with_system(system!(check))
-    ///     .build();
+    /// let entity = world.spawn((0u32,));
+    /// world.push_snapshot();
     ///
-    /// world.run_default();
+    /// world.run(|mut u32s: ViewMut<u32>| u32s[entity] = 1);
+    ///
+    /// assert!(world.undo());
+    /// world.run(|u32s: ViewMut<u32>| assert_eq!(u32s[entity], 0));
+    ///
+    /// assert!(world.redo());
+    /// world.run(|u32s: ViewMut<u32>| assert_eq!(u32s[entity], 1));
     /// ```
+    pub fn try_push_snapshot(&self) -> Result<(), error::Borrow> {
+        self.snapshots
+            .try_borrow_mut()?
+            .push(&mut *self.all_storages.try_borrow_mut()?)
+    }
+    /// Saves the current state of all storages set up with [SparseSet::setup_snapshot] and `Entities`.  
+    /// Storages not modified since the previous snapshot share their state with it.  
+    /// Clears the redo stack and drops the oldest snapshot when over capacity.  
+    /// Unwraps errors.
+    ///
+    /// ### Borrows
+    ///
+    /// - Snapshots (exclusive)
+    /// - [AllStorages] (exclusive)
+    ///
+    /// ### Errors
+    ///
+    /// - Snapshots borrow failed.
+    /// - [AllStorages] borrow failed.
+    /// - Storage borrow failed.
+    ///
+    /// [AllStorages]: struct.AllStorages.html
+    /// [SparseSet::setup_snapshot]: struct.SparseSet.html#method.setup_snapshot
     #[cfg(feature = "panic")]
     #[cfg_attr(docsrs, doc(cfg(feature = "panic")))]
-    pub fn add_workload(&self, name: impl Into<Cow<'static, str>>) -> WorkloadBuilder<'_> {
-        self.try_add_workload(name).unwrap()
+    #[track_caller]
+    pub fn push_snapshot(&self) {
+        self.try_push_snapshot().unwrap()
     }
-    /// Runs the `name` workload.
+    /// Restores the most recent snapshot, the current state can then be restored with [World::redo].  
+    /// Returns `false` if there is no snapshot to restore.  
+    /// Storages created after the snapshot are cleared if they're set up with [SparseSet::setup_snapshot].
     ///
     /// ### Borrows
     ///
-    /// - Scheduler (shared)
-    /// - Systems' borrow as they are executed
+    /// - Snapshots (exclusive)
+    /// - [AllStorages] (exclusive)
     ///
     /// ### Errors
     ///
-    /// - Scheduler borrow failed.
-    /// - Workload did not exist.
+    /// - Snapshots borrow failed.
+    /// - [AllStorages] borrow failed.
     /// - Storage borrow failed.
-    /// - User error returned by system.
-    pub fn try_run_workload(&self, name: impl AsRef<str> + Sync) -> Result<(), error::RunWorkload> {
-        let scheduler = self
-            .scheduler
-            .try_borrow()
-            .map_err(|_| error::RunWorkload::Scheduler)?;
-        if let Some(range) = scheduler.workloads.get(name.as_ref()) {
-            self.try_run_workload_index(&*scheduler, range.clone())
-        } else {
-            Err(error::RunWorkload::MissingWorkload)
-        }
+    ///
+    /// [AllStorages]: struct.AllStorages.html
+    /// [SparseSet::setup_snapshot]: struct.SparseSet.html#method.setup_snapshot
+    /// [World::redo]: struct.World.html#method.redo
+    pub fn try_undo(&self) -> Result<bool, error::Borrow> {
+        self.snapshots
+            .try_borrow_mut()?
+            .undo(&mut *self.all_storages.try_borrow_mut()?)
     }
-    /// Runs the `name` workload.  
-    /// Unwraps error.
+    /// Restores the most recent snapshot, the current state can then be restored with [World::redo].  
+    /// Returns `false` if there is no snapshot to restore.  
+    /// Storages created after the snapshot are cleared if they're set up with [SparseSet::setup_snapshot].  
+    /// Unwraps errors.
     ///
     /// ### Borrows
     ///
-    /// - Scheduler (shared)
-    /// - Systems' borrow as they are executed
+    /// - Snapshots (exclusive)
+    /// - [AllStorages] (exclusive)
     ///
     /// ### Errors
     ///
-    /// - Scheduler borrow failed.
-    /// - Workload did not exist.
+    /// - Snapshots borrow failed.
+    /// - [AllStorages] borrow failed.
     /// - Storage borrow failed.
-    /// - User error returned by system.
+    ///
+    /// [AllStorages]: struct.AllStorages.html
+    /// [SparseSet::setup_snapshot]: struct.SparseSet.html#method.setup_snapshot
+    /// [World::redo]: struct.World.html#method.redo
     #[cfg(feature = "panic")]
     #[cfg_attr(docsrs, doc(cfg(feature = "panic")))]
-    pub fn run_workload(&self, name: impl AsRef<str> + Sync) {
-        self.try_run_workload(name).unwrap();
-    }
-    fn try_run_workload_index(
-        &self,
-        scheduler: &Scheduler,
-        workload: Range<usize>,
-    ) -> Result<(), error::RunWorkload> {
-        for batch in &scheduler.batch[workload] {
-            if batch.len() == 1 {
-                scheduler.systems[batch[0]](self).map_err(|err| {
-                    error::RunWorkload::Run((scheduler.system_names[batch[0]], err))
-                })?;
-            } else {
-                #[cfg(feature = "parallel")]
-                {
-                    use rayon::prelude::*;
-
-                    self.thread_pool.install(|| {
-                        batch.into_par_iter().try_for_each(|&index| {
-                            (scheduler.systems[index])(self).map_err(|err| {
-                                error::RunWorkload::Run((scheduler.system_names[index], err))
-                            })
-                        })
-                    })?
-                }
-                #[cfg(not(feature = "parallel"))]
-                {
-                    batch.iter().try_for_each(|&index| {
-                        (scheduler.systems[index])(self).map_err(|err| {
-                            error::RunWorkload::Run((scheduler.system_names[index], err))
-                        })
-                    })?
-                }
-            }
-        }
-        Ok(())
+    #[track_caller]
+    pub fn undo(&self) -> bool {
+        self.try_undo().unwrap()
     }
-    /// Run the default workload if there is one.
+    /// Restores the state saved by the last [World::undo].  
+    /// Returns `false` if there is nothing to redo.  
+    /// Pushing a new snapshot clears the redo stack.
     ///
     /// ### Borrows
     ///
-    /// - Scheduler (shared)
-    /// - Systems' borrow as they are executed
+    /// - Snapshots (exclusive)
+    /// - [AllStorages] (exclusive)
     ///
     /// ### Errors
     ///
-    /// - Scheduler borrow failed.
+    /// - Snapshots borrow failed.
+    /// - [AllStorages] borrow failed.
     /// - Storage borrow failed.
-    /// - User error returned by system.
-    pub fn try_run_default(&self) -> Result<(), error::RunWorkload> {
-        let scheduler = self
-            .scheduler
-            .try_borrow()
-            .map_err(|_| error::RunWorkload::Scheduler)?;
-        if !scheduler.batch.is_empty() {
-            self.try_run_workload_index(&scheduler, scheduler.default.clone())?
-        }
-        Ok(())
+    ///
+    /// [AllStorages]: struct.AllStorages.html
+    /// [World::undo]: struct.World.html#method.undo
+    pub fn try_redo(&self) -> Result<bool, error::Borrow> {
+        self.snapshots
+            .try_borrow_mut()?
+            .redo(&mut *self.all_storages.try_borrow_mut()?)
     }
-    /// Run the default workload if there is one.  
-    /// Unwraps error.
+    /// Restores the state saved by the last [World::undo].  
+    /// Returns `false` if there is nothing to redo.  
+    /// Pushing a new snapshot clears the redo stack.  
+    /// Unwraps errors.
     ///
     /// ### Borrows
     ///
-    /// - Scheduler (shared)
-    /// - Systems' borrow as they are executed
+    /// - Snapshots (exclusive)
+    /// - [AllStorages] (exclusive)
     ///
     /// ### Errors
     ///
-    /// - Scheduler borrow failed.
+    /// - Snapshots borrow failed.
+    /// - [AllStorages] borrow failed.
     /// - Storage borrow failed.
-    /// - User error returned by system.
+    ///
+    /// [AllStorages]: struct.AllStorages.html
+    /// [World::undo]: struct.World.html#method.undo
     #[cfg(feature = "panic")]
     #[cfg_attr(docsrs, doc(cfg(feature = "panic")))]
-    pub fn run_default(&self) {
-        self.try_run_default().unwrap();
+    #[track_caller]
+    pub fn redo(&self) -> bool {
+        self.try_redo().unwrap()
     }
-    /// Used to create an entity without having to borrow its storage explicitly.  
-    /// The entity is only added when [EntityBuilder::try_build] or [EntityBuilder::build] is called.
+    /// Sets the maximum number of snapshots that can be undone, 32 by default.  
+    /// The oldest snapshots are dropped if there are more than `capacity`.
+    ///
+    /// ### Errors
+    ///
+    /// - Snapshots borrow failed.
+    pub fn try_set_snapshot_capacity(&self, capacity: usize) -> Result<(), error::Borrow> {
+        self.snapshots.try_borrow_mut()?.set_capacity(capacity);
+
+        Ok(())
+    }
+    /// Sets the maximum number of snapshots that can be undone, 32 by default.  
+    /// The oldest snapshots are dropped if there are more than `capacity`.  
+    /// Unwraps errors.
+    ///
+    /// ### Errors
+    ///
+    /// - Snapshots borrow failed.
+    #[cfg(feature = "panic")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "panic")))]
+    #[track_caller]
+    pub fn set_snapshot_capacity(&self, capacity: usize) {
+        self.try_set_snapshot_capacity(capacity).unwrap()
+    }
+    /// Returns a new [World] sharing the state of all storages set up with [SparseSet::setup_snapshot] and `Entities`.  
+    /// A shared storage is only cloned the first time the fork borrows it exclusively, other storages start empty.  
+    /// Storages not modified since the previous fork are shared with it, making repeated forks cheap.  
+    /// The fork has its own [WorldId], no workload and no snapshot. Packs aren't kept.
     ///
     /// ### Borrows
     ///
-    /// - [AllStorages] (shared)
+    /// - Snapshots (exclusive)
+    /// - [AllStorages] (exclusive)
     ///
     /// ### Errors
     ///
+    /// - Snapshots borrow failed.
     /// - [AllStorages] borrow failed.
+    /// - Storage borrow failed.
     ///
     /// [AllStorages]: struct.AllStorages.html
-    /// [EntityBuilder::build]: struct.EntityBuilder.html#method.build
-    /// [EntityBuilder::try_build]: struct.EntityBuilder.html#method.try_build
-    pub fn try_entity_builder(&self) -> Result<EntityBuilder<'_, (), ()>, error::Borrow> {
-        Ok(EntityBuilder::new(self.all_storages.try_borrow()?))
+    /// [SparseSet::setup_snapshot]: struct.SparseSet.html#method.setup_snapshot
+    /// [World]: struct.World.html
+    /// [WorldId]: struct.WorldId.html
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{View, ViewMut, World};
+    ///
+    /// let world = World::new();
+    ///
+    /// world.run(|mut u32s: ViewMut<u32>| u32s.setup_snapshot());
+    ///
+    /// let entity = world.spawn((0u32,));
+    ///
+    /// let fork = world.fork();
+    /// fork.run(|mut u32s: ViewMut<u32>| u32s[entity] = 1);
+    ///
+    /// world.run(|u32s: View<u32>| assert_eq!(u32s[entity], 0));
+    /// fork.run(|u32s: View<u32>| assert_eq!(u32s[entity], 1));
+    /// ```
+    pub fn try_fork(&self) -> Result<World, error::Borrow> {
+        let all_storages = self
+            .snapshots
+            .try_borrow_mut()?
+            .fork(&mut *self.all_storages.try_borrow_mut()?)?;
+
+        Ok(self.with_all_storages(all_storages))
     }
-    /// Used to create an entity without having to borrow its storage explicitly.  
-    /// The entity is only added when [EntityBuilder::try_build] or [EntityBuilder::build] is called.  
-    /// Unwraps error.
+    /// Returns a new [World] sharing the state of all storages set up with [SparseSet::setup_snapshot] and `Entities`.  
+    /// A shared storage is only cloned the first time the fork borrows it exclusively, other storages start empty.  
+    /// Storages not modified since the previous fork are shared with it, making repeated forks cheap.  
+    /// The fork has its own [WorldId], no workload and no snapshot. Packs aren't kept.  
+    /// Unwraps errors.
     ///
     /// ### Borrows
     ///
-    /// - [AllStorages] (shared)
+    /// - Snapshots (exclusive)
+    /// - [AllStorages] (exclusive)
     ///
     /// ### Errors
     ///
+    /// - Snapshots borrow failed.
     /// - [AllStorages] borrow failed.
+    /// - Storage borrow failed.
     ///
     /// [AllStorages]: struct.AllStorages.html
-    /// [EntityBuilder::build]: struct.EntityBuilder.html#method.build
-    /// [EntityBuilder::try_build]: struct.EntityBuilder.html#method.try_build
+    /// [SparseSet::setup_snapshot]: struct.SparseSet.html#method.setup_snapshot
+    /// [World]: struct.World.html
+    /// [WorldId]: struct.WorldId.html
     #[cfg(feature = "panic")]
     #[cfg_attr(docsrs, doc(cfg(feature = "panic")))]
-    pub fn entity_builder(&self) -> EntityBuilder<'_, (), ()> {
-        self.try_entity_builder().unwrap()
+    #[track_caller]
+    pub fn fork(&self) -> World {
+        self.try_fork().unwrap()
     }
-    /// Serializes the [World] the way `ser_config` defines it.
+    /// Returns an independent copy of this [World] with `Entities`, all storages set up with [SparseSet::setup_clone]
+    /// and all uniques added with [World::add_unique_cloneable].  
+    /// Other storages start empty.  
+    /// The copy has its own [WorldId], no workload and no snapshot. Packs aren't kept.
     ///
     /// ### Borrows
     ///
-    /// - [AllStorages] (exclusively)
+    /// - [AllStorages] (exclusive)
     ///
     /// ### Errors
     ///
     /// - [AllStorages] borrow failed.
-    /// - Serialization error.
-    /// - Config not implemented. (temporary)
+    /// - Storage borrow failed.
     ///
     /// [AllStorages]: struct.AllStorages.html
+    /// [SparseSet::setup_clone]: struct.SparseSet.html#method.setup_clone
     /// [World]: struct.World.html
-    #[cfg(feature = "serde1")]
-    #[cfg_attr(docsrs, doc(cfg(feature = "serde1")))]
-    pub fn serialize<S>(
-        &self,
-        ser_config: GlobalSerConfig,
-        serializer: S,
-    ) -> Result<S::Ok, S::Error>
-    where
-        S: serde::Serializer,
-        <S as serde::Serializer>::Ok: 'static,
-    {
-        if ser_config.same_binary == true
-            && ser_config.with_entities == true
-            && ser_config.with_shared == WithShared::PerStorage
-        {
-            serializer.serialize_newtype_struct(
-                "World",
-                &crate::storage::AllStoragesSerializer {
-                    all_storages: self
-                        .all_storages
-                        .try_borrow_mut()
-                        .map_err(|err| serde::ser::Error::custom(err))?,
-                    ser_config,
-                },
-            )
-        } else {
-            Err(serde::ser::Error::custom(
-                "ser_config other than default isn't implemented yet",
-            ))
-        }
+    /// [World::add_unique_cloneable]: struct.World.html#method.add_unique_cloneable
+    /// [WorldId]: struct.WorldId.html
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{UniqueView, View, ViewMut, World};
+    ///
+    /// let world = World::new();
+    ///
+    /// world.run(|mut u32s: ViewMut<u32>| u32s.setup_clone());
+    /// world.add_unique_cloneable(0usize);
+    ///
+    /// let entity = world.spawn((0u32,));
+    ///
+    /// let clone = world.try_deep_clone().unwrap();
+    /// clone.run(|mut u32s: ViewMut<u32>| u32s[entity] = 1);
+    ///
+    /// world.run(|u32s: View<u32>| assert_eq!(u32s[entity], 0));
+    /// clone.run(|u32s: View<u32>, usize: UniqueView<usize>| {
+    ///     assert_eq!(u32s[entity], 1);
+    ///     assert_eq!(*usize, 0);
+    /// });
+    /// ```
+    pub fn try_deep_clone(&self) -> Result<World, error::Borrow> {
+        let all_storages = self.all_storages.try_borrow_mut()?.deep_clone()?;
+
+        Ok(self.with_all_storages(all_storages))
     }
-    /// Creates a new [World] from a deserializer the way `de_config` defines it.
+    /// Returns an independent copy of this [World] with `Entities`, all storages set up with [SparseSet::setup_clone]
+    /// and all uniques added with [World::add_unique_cloneable].  
+    /// Other storages start empty.  
+    /// The copy has its own [WorldId], no workload and no snapshot. Packs aren't kept.  
+    /// Unwraps errors.
+    ///
+    /// ### Borrows
+    ///
+    /// - [AllStorages] (exclusive)
     ///
     /// ### Errors
     ///
-    /// - Deserialization error.
-    /// - Config not implemented. (temporary)
+    /// - [AllStorages] borrow failed.
+    /// - Storage borrow failed.
     ///
+    /// [AllStorages]: struct.AllStorages.html
+    /// [SparseSet::setup_clone]: struct.SparseSet.html#method.setup_clone
     /// [World]: struct.World.html
-    #[cfg(feature = "serde1")]
-    #[cfg_attr(docsrs, doc(cfg(feature = "serde1")))]
-    pub fn new_deserialized<'de, D>(
-        de_config: GlobalDeConfig,
-        deserializer: D,
-    ) -> Result<Self, D::Error>
-    where
-        D: serde::Deserializer<'de>,
-    {
-        if de_config.existing_entities == ExistingEntities::AsNew
-            && de_config.with_shared == WithShared::PerStorage
+    /// [World::add_unique_cloneable]: struct.World.html#method.add_unique_cloneable
+    /// [WorldId]: struct.WorldId.html
+    #[cfg(feature = "panic")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "panic")))]
+    #[track_caller]
+    pub fn deep_clone(&self) -> World {
+        self.try_deep_clone().unwrap()
+    }
+    /// Creates a [World] with its own [WorldId] around `all_storages`, sharing this one's thread pool.
+    ///
+    /// [World]: struct.World.html
+    /// [WorldId]: struct.WorldId.html
+    fn with_all_storages(&self, all_storages: AllStorages) -> World {
+        #[cfg(feature = "std")]
         {
-            let world = World::new();
-            deserializer.deserialize_struct(
-                "World",
-                &["metadata", "storages"],
-                WorldVisitor {
-                    all_storages: world
-                        .all_storages
-                        .try_borrow_mut()
-                        .map_err(serde::de::Error::custom)?,
-                    de_config,
-                },
-            )?;
-            Ok(world)
-        } else {
-            Err(serde::de::Error::custom(
-                "de_config other than default isn't implemented yet",
-            ))
+            World {
+                id: WorldId::new(),
+                all_storages: AtomicRefCell::new(all_storages, None, true),
+                #[cfg(feature = "parallel")]
+                thread_pool: self.thread_pool.clone(),
+                scheduler: AtomicRefCell::new(Default::default(), None, true),
+                snapshots: AtomicRefCell::new(Default::default(), None, true),
+            }
+        }
+        #[cfg(not(feature = "std"))]
+        {
+            World {
+                id: WorldId::new(),
+                all_storages: AtomicRefCell::new(all_storages),
+                #[cfg(feature = "parallel")]
+                thread_pool: self.thread_pool.clone(),
+                scheduler: AtomicRefCell::new(Default::default()),
+                snapshots: AtomicRefCell::new(Default::default()),
+            }
+        }
+    }
+}
+
+/// Borrows failing from inside a system name the system instead, the borrow conflicts with the running systems.
+fn run_borrow_error(err: error::GetStorage) -> error::Run {
+    #[cfg(feature = "std")]
+    {
+        if let Some(system) = crate::reentrancy::current_system() {
+            if matches!(
+                err,
+                error::GetStorage::AllStoragesBorrow(_)
+                    | error::GetStorage::StorageBorrow((_, error::Borrow::Unique(_)))
+                    | error::GetStorage::StorageBorrow((_, error::Borrow::Shared(_)))
+                    | error::GetStorage::Entities(_)
+            ) {
+                return error::Run::Reentrant(system);
+            }
         }
     }
+
+    err.into()
 }
 
 #[cfg(feature = "serde1")]
@@ -1576,11 +5752,26 @@ impl<'de, 'a> serde::de::Visitor<'de> for WorldVisitor<'a> {
         formatter.write_str("Could not format World")
     }
 
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: serde::de::SeqAccess<'de>,
+    {
+        let metadata: Vec<StorageMetadata> = seq
+            .next_element()?
+            .ok_or_else(|| serde::de::Error::invalid_length(0, &"World with 2 elements"))?;
+
+        seq.next_element_seed(StoragesSeed {
+            metadata,
+            all_storages: self.all_storages,
+            de_config: self.de_config,
+        })?
+        .ok_or_else(|| serde::de::Error::invalid_length(1, &"World with 2 elements"))
+    }
     fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
     where
         A: serde::de::MapAccess<'de>,
     {
-        let mut metadata: Vec<(StorageId, usize)> = Vec::new();
+        let mut metadata: Vec<StorageMetadata> = Vec::new();
 
         if let Some((name, types)) = map.next_entry()? {
             match name {
@@ -1608,7 +5799,7 @@ impl<'de, 'a> serde::de::Visitor<'de> for WorldVisitor<'a> {
 
 #[cfg(feature = "serde1")]
 struct StoragesSeed<'all> {
-    metadata: Vec<(StorageId, usize)>,
+    metadata: Vec<StorageMetadata>,
     all_storages: RefMut<'all, AllStorages>,
     de_config: GlobalDeConfig,
 }
@@ -1622,7 +5813,7 @@ impl<'de> serde::de::DeserializeSeed<'de> for StoragesSeed<'_> {
         D: serde::Deserializer<'de>,
     {
         struct StoragesVisitor<'all> {
-            metadata: Vec<(StorageId, usize)>,
+            metadata: Vec<StorageMetadata>,
             all_storages: RefMut<'all, AllStorages>,
             de_config: GlobalDeConfig,
         }
@@ -1640,7 +5831,15 @@ impl<'de> serde::de::DeserializeSeed<'de> for StoragesSeed<'_> {
             {
                 let storages = self.all_storages.storages();
 
-                for (i, (storage_id, deserialize_ptr)) in self.metadata.into_iter().enumerate() {
+                for (
+                    i,
+                    StorageMetadata {
+                        storage_id,
+                        deserialize_ptr,
+                        version,
+                    },
+                ) in self.metadata.into_iter().enumerate()
+                {
                     let storage: &mut Storage =
                         &mut storages.entry(storage_id).or_insert_with(|| {
                             let deserialize =
@@ -1661,7 +5860,7 @@ impl<'de> serde::de::DeserializeSeed<'de> for StoragesSeed<'_> {
                                         )
                                     },
                                 deserialization: deserialize,
-                                with_shared: true,
+                                ..crate::sparse_set::SerdeInfos::new(Default::default())
                             });
 
                             Storage(Box::new(AtomicRefCell::new(sparse_set, None, true)))
@@ -1670,14 +5869,28 @@ impl<'de> serde::de::DeserializeSeed<'de> for StoragesSeed<'_> {
                     if seq
                         .next_element_seed(crate::storage::StorageDeserializer {
                             storage,
-                            de_config: self.de_config,
+                            de_config: self.de_config.clone(),
+                            version,
                         })?
                         .is_none()
                     {
                         return Err(serde::de::Error::invalid_length(i, &"more storages"));
                     }
+
+                    // the storage was replaced by the deserialized one, no one else can borrow it
+                    let name = storages
+                        .get_mut(&storage_id)
+                        .unwrap()
+                        .0
+                        .try_borrow()
+                        .unwrap()
+                        .name();
+                    storages.set_name(storage_id, name);
                 }
 
+                // deserialized storages replaced the ones created above
+                self.all_storages.track_signatures();
+
                 Ok(())
             }
         }
@@ -0,0 +1,52 @@
+use alloc::vec::Vec;
+use core::time::Duration;
+
+/// Timings of a single workload run, returned by [World::run_workload_timed].
+/// Systems are listed batch by batch, systems in the same batch may have run in parallel.
+///
+/// ### Example
+/// ```
+/// use shipyard::{system, World};
+///
+/// fn physics() {}
+/// fn render() {}
+///
+/// let world = World::new();
+/// world
+///     .add_workload("Frame")
+///     .with_system(system!(physics))
+///     .with_system(system!(render))
+///     .build();
+///
+/// let report = world.run_workload_timed("Frame");
+///
+/// for system in report.systems() {
+///     assert!(system.duration <= report.duration);
+/// }
+/// assert!(report.batches[0][1].name.ends_with("render"));
+/// ```
+///
+/// [World::run_workload_timed]: struct.World.html#method.run_workload_timed
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+#[derive(Clone, Debug)]
+pub struct WorkloadReport {
+    pub duration: Duration,
+    pub batches: Vec<Vec<SystemTiming>>,
+}
+
+/// How long a system took during a workload run, see [WorkloadReport].
+///
+/// [WorkloadReport]: struct.WorkloadReport.html
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SystemTiming {
+    pub name: &'static str,
+    pub duration: Duration,
+}
+
+impl WorkloadReport {
+    /// Returns the timings of all systems, in the order they were scheduled.
+    pub fn systems(&self) -> impl Iterator<Item = &SystemTiming> {
+        self.batches.iter().flatten()
+    }
+}
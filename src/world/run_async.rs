@@ -0,0 +1,75 @@
+use super::{WorkloadCursor, World};
+use crate::error;
+use alloc::string::ToString;
+use core::future::Future;
+use core::pin::Pin;
+use core::task::{Context, Poll};
+
+/// Future returned by [World::try_run_workload_async].
+/// Runs one batch of systems each time it's polled and yields to the executor between batches.
+/// Systems are synchronous, the future never awaits anything itself.
+///
+/// Dropping the future before it completes skips the remaining batches,
+/// per workload state like [FrameAlloc] is still reset.
+///
+/// [FrameAlloc]: struct.FrameAlloc.html
+/// [World::try_run_workload_async]: struct.World.html#method.try_run_workload_async
+pub struct RunWorkloadAsync<'a> {
+    world: &'a World,
+    // `None` once the workload completed
+    cursor: Option<WorkloadCursor>,
+}
+
+impl<'a> RunWorkloadAsync<'a> {
+    pub(super) fn new(world: &'a World, name: &str) -> Self {
+        RunWorkloadAsync {
+            world,
+            cursor: Some(WorkloadCursor::new(name.to_string())),
+        }
+    }
+    /// Resets per workload state if the workload stopped halfway.
+    fn finish(&mut self) {
+        if let Some(cursor) = self.cursor.take() {
+            if cursor.is_suspended() {
+                self.world.finish_workload();
+
+                #[cfg(feature = "stats")]
+                self.world.refresh_stats(cursor.elapsed);
+            }
+        }
+    }
+}
+
+impl Future for RunWorkloadAsync<'_> {
+    type Output = Result<(), error::RunWorkload>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.get_mut();
+
+        let cursor = match &mut this.cursor {
+            Some(cursor) => cursor,
+            None => return Poll::Ready(Ok(())),
+        };
+
+        // the scheduler is only borrowed while the batch runs
+        match this.world.try_resume_workload(cursor, || true) {
+            Ok(false) => {
+                cx.waker().wake_by_ref();
+
+                Poll::Pending
+            }
+            result => {
+                // the cursor is still suspended when the scheduler couldn't be borrowed
+                this.finish();
+
+                Poll::Ready(result.map(drop))
+            }
+        }
+    }
+}
+
+impl Drop for RunWorkloadAsync<'_> {
+    fn drop(&mut self) {
+        self.finish();
+    }
+}
@@ -1,4 +1,4 @@
-use super::Scheduler;
+use super::{Scheduler, SystemInfo, WorkloadSystem};
 use crate::atomic_refcell::RefMut;
 use crate::borrow::Mutation;
 use crate::error;
@@ -10,24 +10,39 @@ use alloc::borrow::Cow;
 use alloc::boxed::Box;
 use alloc::vec;
 use alloc::vec::Vec;
-use core::any::type_name;
-use core::ops::Range;
-use hashbrown::hash_map::Entry;
+use core::fmt::{Display, Formatter};
+#[cfg(feature = "parallel")]
+use core::sync::atomic::AtomicBool;
 
 /// Keeps information to create a workload.
-#[allow(clippy::type_complexity)]
 #[must_use]
 pub struct WorkloadBuilder<'a> {
     scheduler: RefMut<'a, Scheduler>,
-    systems: Vec<(
-        TypeId,
-        &'static str,
-        Range<usize>,
-        bool,
-        Box<dyn Fn(&World) -> Result<(), error::Run> + Send + Sync + 'static>,
-    )>,
-    borrow_info: Vec<(TypeId, Mutation)>,
+    systems: Vec<WorkloadSystem>,
     name: Cow<'static, str>,
+    deterministic: bool,
+}
+
+/// Likely mistakes found when building a workload, they don't prevent it from running.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum WorkloadDiagnostic {
+    /// The workload doesn't have any system.
+    Empty,
+    /// The system was added multiple times, it will run each time.  
+    /// Closures are never reported since they don't have a name.
+    DuplicateSystem(&'static str),
+}
+
+impl Display for WorkloadDiagnostic {
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Empty => fmt.write_str("Workload doesn't have any system."),
+            Self::DuplicateSystem(name) => fmt.write_fmt(format_args!(
+                "{} was added multiple times to the workload.",
+                name
+            )),
+        }
+    }
 }
 
 impl<'a> WorkloadBuilder<'a> {
@@ -35,8 +50,8 @@ impl<'a> WorkloadBuilder<'a> {
         WorkloadBuilder {
             scheduler,
             systems: Vec::new(),
-            borrow_info: Vec::new(),
             name,
+            deterministic: false,
         }
     }
 }
@@ -93,44 +108,9 @@ impl<'a> WorkloadBuilder<'a> {
         S: Fn(&World) -> Result<(), error::Run> + Send + Sync + 'static,
     >(
         mut self,
-        (system, _): (S, F),
+        system: (S, F),
     ) -> Result<WorkloadBuilder<'a>, error::InvalidSystem> {
-        let old_len = self.borrow_info.len();
-        F::borrow_infos(&mut self.borrow_info);
-
-        let borrows = &self.borrow_info[old_len..];
-
-        if borrows.contains(&(TypeId::of::<AllStorages>(), Mutation::Unique)) && borrows.len() > 1 {
-            return Err(error::InvalidSystem::AllStorages);
-        }
-
-        let mid = borrows.len() / 2 + (borrows.len() % 2 != 0) as usize;
-
-        for (a_type_id, a_borrow) in &borrows[..mid] {
-            for (b_type_id, b_borrow) in &borrows[mid..] {
-                if a_type_id == b_type_id {
-                    match (a_borrow, b_borrow) {
-                        (Mutation::Unique, Mutation::Unique) => {
-                            return Err(error::InvalidSystem::MultipleViewsMut)
-                        }
-                        (Mutation::Unique, Mutation::Shared)
-                        | (Mutation::Shared, Mutation::Unique) => {
-                            return Err(error::InvalidSystem::MultipleViews)
-                        }
-                        (Mutation::Shared, Mutation::Shared) => {}
-                    }
-                }
-            }
-        }
-
-        let is_send_sync = F::is_send_sync();
-        self.systems.push((
-            TypeId::of::<S>(),
-            type_name::<F>(),
-            old_len..self.borrow_info.len(),
-            is_send_sync,
-            Box::new(system),
-        ));
+        self.systems.push(WorkloadSystem::try_new(system)?);
         Ok(self)
     }
     /// Adds a system to the workload been created.  
@@ -178,6 +158,7 @@ impl<'a> WorkloadBuilder<'a> {
     /// [try_system]: macro.try_system.html
     #[cfg(feature = "panic")]
     #[cfg_attr(docsrs, doc(cfg(feature = "panic")))]
+    #[track_caller]
     pub fn with_system<
         B,
         R,
@@ -189,6 +170,26 @@ impl<'a> WorkloadBuilder<'a> {
     ) -> WorkloadBuilder<'a> {
         self.try_with_system(system).unwrap()
     }
+    /// Adds systems assembled at runtime to the workload been created.
+    ///
+    /// ### Example:
+    /// ```
+    /// use shipyard::{system, View, WorkloadSystem, World};
+    ///
+    /// fn log(_: View<u32>) {}
+    ///
+    /// let world = World::new();
+    ///
+    /// let systems = vec![WorkloadSystem::new(system!(log))];
+    ///
+    /// world.add_workload("Log").with_systems(systems).build();
+    ///
+    /// world.run_workload("Log");
+    /// ```
+    pub fn with_systems<I: IntoIterator<Item = WorkloadSystem>>(mut self, systems: I) -> Self {
+        self.systems.extend(systems);
+        self
+    }
     /// Calls the given function on the builder.
     ///
     /// Can be useful to chain calls to functions that modify a `WorkloadBuilder`.
@@ -229,10 +230,84 @@ impl<'a> WorkloadBuilder<'a> {
     {
         f(self)
     }
-    /// Finishes the workload creation and store it in the `World`.
-    pub fn build(mut self) {
+    /// Makes the workload run the same way each time given the same `World` state.  
+    /// Systems of a batch still run in parallel but all of them run to completion, even if one fails.  
+    /// The error returned is then the one of the first failing system in the order they were added.
+    ///
+    /// Systems are already grouped in batches deterministically and entities can only be added by systems
+    /// borrowing [EntitiesViewMut] or [AllStoragesViewMut], which never share a batch.  
+    /// `EntityId`s are then allocated in the same order on every run.
+    /// Messages sent with [MessageSender] by systems of the same batch are received in the order the systems were added,
+    /// the messages of each system in the order they were sent.  
+    /// Messages sent from parallel iterators inside a system aren't ordered.
+    ///
+    /// ### Example:
+    /// ```
+    /// use shipyard::{system, EntitiesViewMut, View, ViewMut, World};
+    ///
+    /// fn spawn(mut entities: EntitiesViewMut, mut u32s: ViewMut<u32>) {
+    ///     entities.add_entity(&mut u32s, 0);
+    /// }
+    ///
+    /// fn count(u32s: View<u32>, mut usizes: ViewMut<usize>) {}
+    ///
+    /// fn log(u32s: View<u32>) {}
+    ///
+    /// let world = World::new();
+    ///
+    /// world
+    ///     .add_workload("Lockstep")
+    ///     .with_system(system!(spawn))
+    ///     .with_system(system!(count))
+    ///     .with_system(system!(log))
+    ///     .deterministic()
+    ///     .build();
+    ///
+    /// world.run_workload("Lockstep");
+    /// ```
+    ///
+    /// [AllStoragesViewMut]: struct.AllStoragesViewMut.html
+    /// [EntitiesViewMut]: struct.EntitiesViewMut.html
+    /// [MessageSender]: struct.MessageSender.html
+    pub fn deterministic(mut self) -> Self {
+        self.deterministic = true;
+        self
+    }
+    /// Finishes the workload creation and store it in the `World`.  
+    /// Returns the likely mistakes found in the workload, like a system added twice.
+    ///
+    /// ### Example:
+    /// ```
+    /// use shipyard::{system, View, World, WorkloadDiagnostic};
+    ///
+    /// fn log(_: View<u32>) {}
+    ///
+    /// let world = World::new();
+    ///
+    /// let diagnostics = world
+    ///     .add_workload("Log")
+    ///     .with_system(system!(log))
+    ///     .with_system(system!(log))
+    ///     .build();
+    ///
+    /// assert_eq!(diagnostics.len(), 1);
+    /// match diagnostics[0] {
+    ///     WorkloadDiagnostic::DuplicateSystem(name) => assert!(name.ends_with("log")),
+    ///     _ => panic!(),
+    /// }
+    /// ```
+    pub fn build(mut self) -> Vec<WorkloadDiagnostic> {
+        let diagnostics = self.diagnostics();
+
         if self.systems.len() == 1 {
-            let (type_id, system_name, _, _, system) = self.systems.pop().unwrap();
+            let WorkloadSystem {
+                type_id,
+                name: system_name,
+                borrow_info,
+                is_send_sync,
+                create_storages,
+                system,
+            } = self.systems.pop().unwrap();
 
             let mut name = "".into();
             core::mem::swap(&mut name, &mut self.name);
@@ -243,49 +318,58 @@ impl<'a> WorkloadBuilder<'a> {
             }
             self.scheduler.workloads.insert(name, range);
 
-            let len = self.scheduler.systems.len();
-            let system_index = match self.scheduler.lookup_table.entry(type_id) {
-                Entry::Vacant(vacant) => {
-                    vacant.insert(len);
-                    self.scheduler.systems.push(system);
-                    self.scheduler.system_names.push(system_name);
-                    self.scheduler.systems.len() - 1
-                }
-                Entry::Occupied(occupied) => *occupied.get(),
+            let info = SystemInfo {
+                name: system_name,
+                borrows: borrow_info,
+                is_send_sync,
             };
+            let system_index = self
+                .scheduler
+                .add_system(type_id, info, create_storages, system);
 
             self.scheduler.batch.push(Box::new([system_index]));
+            self.scheduler.deterministic.push(self.deterministic);
+            self.scheduler.enabled.push(true);
+            #[cfg(feature = "parallel")]
+            self.scheduler.ran.push(AtomicBool::new(false));
         } else {
             let batch_start = self.scheduler.batch.len();
             let mut new_batch = vec![Vec::new()];
             let mut batch_info = vec![Vec::new()];
 
-            for (type_id, name, info_range, is_send_sync, system) in self.systems.drain(..) {
-                let len = self.scheduler.systems.len();
-                let system_index = match self.scheduler.lookup_table.entry(type_id) {
-                    Entry::Vacant(vacant) => {
-                        vacant.insert(len);
-                        self.scheduler.systems.push(system);
-                        self.scheduler.system_names.push(name);
-                        self.scheduler.systems.len() - 1
-                    }
-                    Entry::Occupied(occupied) => *occupied.get(),
+            for WorkloadSystem {
+                type_id,
+                name,
+                borrow_info,
+                is_send_sync,
+                create_storages,
+                system,
+            } in self.systems.drain(..)
+            {
+                let info = SystemInfo {
+                    name,
+                    borrows: borrow_info.clone(),
+                    is_send_sync,
                 };
+                let system_index =
+                    self.scheduler
+                        .add_system(type_id, info, create_storages, system);
 
                 if is_send_sync {
                     let mut batch_index = new_batch.len();
                     for batch in batch_info.iter().rev() {
                         let mut conflict = false;
-                        for &(type_id, mutation) in &self.borrow_info[info_range.clone()] {
+                        for &(type_id, mutation) in &borrow_info {
                             match mutation {
                                 Mutation::Shared => {
                                     for &(batch_type_id, mutation) in batch.iter() {
                                         #[cfg(feature = "parallel")]
                                         {
-                                            if type_id == batch_type_id
-                                                && mutation == Mutation::Unique
+                                            // shared AllStorages borrows only conflict with exclusive borrows
+                                            if (type_id == batch_type_id
                                                 || type_id == TypeId::of::<AllStorages>()
-                                                || batch_type_id == TypeId::of::<AllStorages>()
+                                                || batch_type_id == TypeId::of::<AllStorages>())
+                                                && mutation == Mutation::Unique
                                             {
                                                 conflict = true;
                                                 break;
@@ -293,10 +377,11 @@ impl<'a> WorkloadBuilder<'a> {
                                         }
                                         #[cfg(not(feature = "parallel"))]
                                         {
-                                            if type_id == batch_type_id
-                                                && mutation == Mutation::Unique
+                                            // shared AllStorages borrows only conflict with exclusive borrows
+                                            if (type_id == batch_type_id
                                                 || type_id == TypeId::of::<AllStorages>()
-                                                || batch_type_id == TypeId::of::<AllStorages>()
+                                                || batch_type_id == TypeId::of::<AllStorages>())
+                                                && mutation == Mutation::Unique
                                             {
                                                 conflict = true;
                                                 break;
@@ -340,10 +425,10 @@ impl<'a> WorkloadBuilder<'a> {
 
                     if batch_index == batch_info.len() {
                         new_batch.push(vec![system_index]);
-                        batch_info.push(self.borrow_info[info_range].to_vec());
+                        batch_info.push(borrow_info);
                     } else {
                         new_batch[batch_index].push(system_index);
-                        batch_info[batch_index].extend_from_slice(&self.borrow_info[info_range]);
+                        batch_info[batch_index].extend(borrow_info);
                     }
                 } else {
                     let last = new_batch.last_mut().unwrap();
@@ -368,6 +453,17 @@ impl<'a> WorkloadBuilder<'a> {
                 new_batch.pop();
             }
 
+            let deterministic = self.deterministic;
+            self.scheduler
+                .deterministic
+                .extend(new_batch.iter().map(|_| deterministic));
+            self.scheduler
+                .enabled
+                .extend(new_batch.iter().map(|_| true));
+            #[cfg(feature = "parallel")]
+            self.scheduler
+                .ran
+                .extend(new_batch.iter().map(|_| AtomicBool::new(false)));
             self.scheduler
                 .batch
                 .extend(new_batch.into_iter().map(Vec::into_boxed_slice));
@@ -381,6 +477,31 @@ impl<'a> WorkloadBuilder<'a> {
             let len = self.scheduler.batch.len();
             self.scheduler.workloads.insert(name, batch_start..len);
         }
+
+        diagnostics
+    }
+    /// Lists the likely mistakes in the workload.
+    fn diagnostics(&self) -> Vec<WorkloadDiagnostic> {
+        let mut diagnostics = Vec::new();
+
+        if self.systems.is_empty() {
+            diagnostics.push(WorkloadDiagnostic::Empty);
+        }
+
+        for (index, system) in self.systems.iter().enumerate() {
+            let duplicate = WorkloadDiagnostic::DuplicateSystem(system.name);
+
+            if !system.name.ends_with("{{closure}}")
+                && self.systems[..index]
+                    .iter()
+                    .any(|other| other.name == system.name)
+                && !diagnostics.contains(&duplicate)
+            {
+                diagnostics.push(duplicate);
+            }
+        }
+
+        diagnostics
     }
 }
 
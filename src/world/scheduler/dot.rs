@@ -0,0 +1,125 @@
+use super::{Scheduler, SystemInfo};
+use crate::borrow::Mutation;
+use crate::storage::AllStorages;
+use crate::type_id::TypeId;
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt::Write;
+use core::ops::Range;
+use hashbrown::HashMap;
+
+impl Scheduler {
+    /// Writes the batches of `workload` in the DOT format.
+    /// Systems are grouped by batch and linked to the systems of previous batches they conflict with.
+    pub(crate) fn to_dot(
+        &self,
+        name: &str,
+        workload: Range<usize>,
+        storage_names: &HashMap<TypeId, &'static str>,
+    ) -> String {
+        let storage_name = |type_id: TypeId| {
+            storage_names
+                .get(&type_id)
+                .map(|&name| name.into())
+                .unwrap_or_else(|| format!("{:?}", type_id))
+        };
+
+        let mut dot = String::new();
+        // writing to a String can't fail
+        writeln!(dot, "digraph \"{}\" {{", escape(name)).unwrap();
+        writeln!(dot, "    node [shape=box];").unwrap();
+
+        let mut previous: Vec<(String, &SystemInfo)> = Vec::new();
+        for (batch_index, batch) in self.batch[workload].iter().enumerate() {
+            writeln!(dot, "    subgraph cluster_{} {{", batch_index).unwrap();
+            writeln!(dot, "        label=\"batch {}\";", batch_index).unwrap();
+
+            let mut current = Vec::with_capacity(batch.len());
+            for (position, &system_index) in batch.iter().enumerate() {
+                let info = &self.system_infos[system_index];
+                let node = format!("s{}_{}", batch_index, position);
+
+                let mut label = escape(info.name);
+                for &(type_id, mutation) in &info.borrows {
+                    let mutation = match mutation {
+                        Mutation::Shared => "",
+                        Mutation::Unique => "mut ",
+                    };
+                    write!(label, "\\n&{}{}", mutation, escape(&storage_name(type_id))).unwrap();
+                }
+
+                if info.is_send_sync {
+                    writeln!(dot, "        {} [label=\"{}\"];", node, label).unwrap();
+                } else {
+                    // !Send and !Sync systems run on the World's thread
+                    writeln!(dot, "        {} [label=\"{}\", style=dashed];", node, label).unwrap();
+                }
+
+                current.push((node, info));
+            }
+
+            writeln!(dot, "    }}").unwrap();
+
+            for (node, info) in &current {
+                for (previous_node, previous_info) in &previous {
+                    let conflicts = conflicts(previous_info, info);
+
+                    if !conflicts.is_empty() {
+                        let label = conflicts
+                            .into_iter()
+                            .map(|type_id| escape(&storage_name(type_id)))
+                            .collect::<Vec<_>>()
+                            .join(", ");
+
+                        writeln!(
+                            dot,
+                            "    {} -> {} [label=\"{}\"];",
+                            previous_node, node, label
+                        )
+                        .unwrap();
+                    }
+                }
+            }
+
+            previous.extend(current);
+        }
+
+        dot.push('}');
+        dot.push('\n');
+
+        dot
+    }
+}
+
+/// Returns the storages preventing `a` and `b` from running in parallel.
+fn conflicts(a: &SystemInfo, b: &SystemInfo) -> Vec<TypeId> {
+    let all_storages = TypeId::of::<AllStorages>();
+    let mut conflicts = Vec::new();
+
+    for &(a_type_id, a_mutation) in &a.borrows {
+        for &(b_type_id, b_mutation) in &b.borrows {
+            if a_mutation == Mutation::Shared && b_mutation == Mutation::Shared {
+                continue;
+            }
+
+            let type_id = if a_type_id == all_storages || b_type_id == all_storages {
+                all_storages
+            } else if a_type_id == b_type_id {
+                a_type_id
+            } else {
+                continue;
+            };
+
+            if !conflicts.contains(&type_id) {
+                conflicts.push(type_id);
+            }
+        }
+    }
+
+    conflicts
+}
+
+fn escape(s: &str) -> String {
+    s.replace('\\', "\\\\").replace('"', "\\\"")
+}
@@ -1,23 +1,62 @@
 mod builder;
+mod dot;
+mod system_info;
+mod workload_system;
 
-pub use builder::WorkloadBuilder;
+pub use builder::{WorkloadBuilder, WorkloadDiagnostic};
+pub use system_info::SystemInfo;
+pub use workload_system::WorkloadSystem;
 
 use crate::error;
+#[cfg(feature = "std")]
+use crate::rng::SystemRng;
+#[cfg(feature = "stats")]
+use crate::stats::{SystemCounters, SystemStats};
+use crate::storage::AllStorages;
+#[cfg(feature = "std")]
+use crate::storage_cache::StorageCache;
+#[cfg(feature = "trace")]
+use crate::trace::Trace;
 use crate::type_id::TypeId;
 use crate::World;
 use alloc::borrow::Cow;
 use alloc::boxed::Box;
 use alloc::vec::Vec;
 use core::ops::Range;
+#[cfg(feature = "parallel")]
+use core::sync::atomic::AtomicBool;
+use hashbrown::hash_map::Entry;
 use hashbrown::HashMap;
 
 #[allow(clippy::type_complexity)]
 pub(crate) struct Scheduler {
     pub(super) systems: Vec<Box<dyn Fn(&World) -> Result<(), error::Run> + Send + Sync + 'static>>,
-    pub(super) system_names: Vec<&'static str>,
+    pub(super) system_infos: Vec<SystemInfo>,
+    // creates the storages each system borrows, see World::prepare_workload
+    pub(super) create_storages: Vec<fn(&AllStorages)>,
+    // storages each system borrowed the last time it ran
+    #[cfg(feature = "std")]
+    pub(super) storage_caches: Vec<StorageCache>,
+    // random state of each system, see Rng
+    #[cfg(feature = "std")]
+    pub(super) rngs: Vec<SystemRng>,
+    #[cfg(feature = "std")]
+    pub(super) seed: u64,
+    #[cfg(feature = "stats")]
+    pub(super) system_stats: Vec<SystemCounters>,
+    #[cfg(feature = "trace")]
+    pub(super) trace: Trace,
     pub(super) lookup_table: HashMap<TypeId, usize>,
     // a batch lists systems that can run in parallel
     pub(super) batch: Vec<Box<[usize]>>,
+    // for each batch, whether it's part of a deterministic workload
+    pub(super) deterministic: Vec<bool>,
+    // for each batch, whether run_default runs it, see World::set_workload_enabled
+    pub(super) enabled: Vec<bool>,
+    // for each batch, whether it completed once
+    // the first run is sequential so the storages systems borrow can be created
+    #[cfg(feature = "parallel")]
+    pub(super) ran: Vec<AtomicBool>,
     pub(super) workloads: HashMap<Cow<'static, str>, Range<usize>>,
     pub(super) default: Range<usize>,
 }
@@ -27,11 +66,113 @@ impl Default for Scheduler {
     fn default() -> Self {
         Scheduler {
             systems: Vec::new(),
-            system_names: Vec::new(),
+            system_infos: Vec::new(),
+            create_storages: Vec::new(),
+            #[cfg(feature = "std")]
+            storage_caches: Vec::new(),
+            #[cfg(feature = "std")]
+            rngs: Vec::new(),
+            #[cfg(feature = "std")]
+            seed: 0,
+            #[cfg(feature = "stats")]
+            system_stats: Vec::new(),
+            #[cfg(feature = "trace")]
+            trace: Trace::default(),
             lookup_table: HashMap::new(),
             batch: Vec::new(),
+            deterministic: Vec::new(),
+            enabled: Vec::new(),
+            #[cfg(feature = "parallel")]
+            ran: Vec::new(),
             workloads: HashMap::new(),
             default: 0..0,
         }
     }
 }
+
+impl Scheduler {
+    /// Adds `system` if it isn't already present and returns its index.
+    /// Systems without `type_id` capture state and are always added.
+    #[allow(clippy::type_complexity)]
+    pub(super) fn add_system(
+        &mut self,
+        type_id: Option<TypeId>,
+        info: SystemInfo,
+        create_storages: fn(&AllStorages),
+        system: Box<dyn Fn(&World) -> Result<(), error::Run> + Send + Sync + 'static>,
+    ) -> usize {
+        let index = self.systems.len();
+
+        if let Some(type_id) = type_id {
+            match self.lookup_table.entry(type_id) {
+                Entry::Vacant(vacant) => {
+                    vacant.insert(index);
+                }
+                Entry::Occupied(occupied) => return *occupied.get(),
+            }
+        }
+
+        #[cfg(feature = "std")]
+        self.rngs.push(SystemRng::new(self.seed, info.name));
+        self.systems.push(system);
+        self.system_infos.push(info);
+        self.create_storages.push(create_storages);
+        #[cfg(feature = "std")]
+        self.storage_caches.push(StorageCache::default());
+        #[cfg(feature = "stats")]
+        self.system_stats.push(Default::default());
+
+        index
+    }
+    /// Runs the system at `index`, naming it in case of error.
+    pub(super) fn run(&self, index: usize, world: &World) -> Result<(), error::RunWorkload> {
+        let system = || {
+            #[cfg(feature = "borrow_owner")]
+            {
+                crate::borrow_owner::run_as(self.system_infos[index].name, || {
+                    (self.systems[index])(world)
+                })
+            }
+            #[cfg(not(feature = "borrow_owner"))]
+            {
+                (self.systems[index])(world)
+            }
+        };
+        #[cfg(feature = "std")]
+        let system = || crate::reentrancy::run_as(self.system_infos[index].name, system);
+        #[cfg(feature = "std")]
+        let system = || self.storage_caches[index].run(system);
+        #[cfg(feature = "std")]
+        let system = || self.rngs[index].run(system);
+        #[cfg(feature = "trace")]
+        let system = || self.trace.record(self.system_infos[index].name, system);
+
+        #[cfg(feature = "stats")]
+        let result = {
+            let (result, iterated) = crate::stats::count_iterated(system);
+            self.system_stats[index].add_run(iterated);
+            result
+        };
+        #[cfg(not(feature = "stats"))]
+        let result = system();
+
+        result.map_err(|err| error::RunWorkload::Run((self.system_infos[index].name, err)))
+    }
+    /// Sets the seed all systems' random state derive from and restarts their sequence.
+    #[cfg(feature = "std")]
+    pub(super) fn set_seed(&mut self, seed: u64) {
+        self.seed = seed;
+
+        for (rng, info) in self.rngs.iter().zip(&self.system_infos) {
+            rng.reseed(seed, info.name);
+        }
+    }
+    #[cfg(feature = "stats")]
+    pub(super) fn system_stats(&self) -> Vec<SystemStats> {
+        self.system_stats
+            .iter()
+            .zip(&self.system_infos)
+            .map(|(counters, info)| counters.stats(info.name))
+            .collect()
+    }
+}
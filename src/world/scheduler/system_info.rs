@@ -0,0 +1,29 @@
+use crate::borrow::Mutation;
+use crate::type_id::TypeId;
+use alloc::vec::Vec;
+
+/// What the scheduler knows about a system registered in a workload, returned by [World::systems_info].
+///
+/// `borrows` lists the storages the system borrows and whether the borrow is shared or exclusive,
+/// unique storages use the `TypeId` of their component like other storages.
+/// `is_send_sync` is `false` when the system borrows a `!Send` or `!Sync` storage,
+/// it then always runs on the `World`'s thread.
+///
+/// [World::systems_info]: struct.World.html#method.systems_info
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SystemInfo {
+    pub name: &'static str,
+    pub borrows: Vec<(TypeId, Mutation)>,
+    pub is_send_sync: bool,
+}
+
+impl SystemInfo {
+    /// Returns `true` if the system borrows the storage of `type_id`, shared or exclusively.
+    pub fn borrows(&self, type_id: TypeId) -> bool {
+        self.borrows.iter().any(|&(borrow, _)| borrow == type_id)
+    }
+    /// Returns `true` if the system borrows the storage of `type_id` exclusively.
+    pub fn borrows_mut(&self, type_id: TypeId) -> bool {
+        self.borrows.contains(&(type_id, Mutation::Unique))
+    }
+}
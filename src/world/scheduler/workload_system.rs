@@ -0,0 +1,192 @@
+use crate::borrow::Mutation;
+use crate::error;
+use crate::storage::AllStorages;
+use crate::system::System;
+use crate::type_id::TypeId;
+use crate::world::World;
+use alloc::alloc::Layout;
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+use core::any::type_name;
+
+/// A system with everything the scheduler needs to know about it, its type erased.
+///
+/// Workloads can be assembled at runtime from a collection of them, for example following an order read from a file.
+///
+/// ### Example
+/// ```
+/// use shipyard::{system, View, ViewMut, WorkloadSystem, World};
+///
+/// fn move_pos(_: ViewMut<u32>, _: View<usize>) {}
+/// fn render(_: View<u32>) {}
+///
+/// fn system_by_name(name: &str) -> WorkloadSystem {
+///     match name {
+///         "move_pos" => WorkloadSystem::new(system!(move_pos)),
+///         "render" => WorkloadSystem::new(system!(render)),
+///         _ => panic!("unknown system {}", name),
+///     }
+/// }
+///
+/// let config = "move_pos render";
+///
+/// let world = World::new();
+///
+/// world
+///     .add_workload("Frame")
+///     .with_systems(config.split_whitespace().map(system_by_name))
+///     .build();
+///
+/// world.run_workload("Frame");
+/// ```
+#[allow(clippy::type_complexity)]
+pub struct WorkloadSystem {
+    pub(super) type_id: Option<TypeId>,
+    pub(super) name: &'static str,
+    pub(super) borrow_info: Vec<(TypeId, Mutation)>,
+    pub(super) is_send_sync: bool,
+    pub(super) create_storages: fn(&AllStorages),
+    pub(super) system: Box<dyn Fn(&World) -> Result<(), error::Run> + Send + Sync + 'static>,
+}
+
+impl WorkloadSystem {
+    /// Erases a system, it is strongly recommanded to use the [system] and [try_system] macros.
+    /// If the two functions in the tuple don't match, the workload could fail to run every time.
+    ///
+    /// ### Errors
+    ///
+    /// - The system borrows the same storage multiple times with at least one exclusive borrow.
+    /// - The system borrows [AllStorages] and something else.
+    ///
+    /// [AllStorages]: struct.AllStorages.html
+    /// [system]: macro.system.html
+    /// [try_system]: macro.try_system.html
+    pub fn try_new<'s, B, R, F, S>((system, _): (S, F)) -> Result<Self, error::InvalidSystem>
+    where
+        F: System<'s, (), B, R>,
+        S: Fn(&World) -> Result<(), error::Run> + Send + Sync + 'static,
+    {
+        let mut borrow_info = Vec::new();
+        F::borrow_infos(&mut borrow_info);
+
+        check_borrows(&borrow_info)?;
+
+        // systems capturing state are never shared between workloads
+        let type_id = if Layout::new::<S>().size() == 0 {
+            Some(TypeId::of::<S>())
+        } else {
+            None
+        };
+
+        Ok(WorkloadSystem {
+            type_id,
+            name: type_name::<F>(),
+            borrow_info,
+            is_send_sync: F::is_send_sync(),
+            create_storages: F::create_storages,
+            system: Box::new(system),
+        })
+    }
+    /// Erases a system, it is strongly recommanded to use the [system] and [try_system] macros.
+    /// If the two functions in the tuple don't match, the workload could fail to run every time.
+    /// Unwraps errors.
+    ///
+    /// ### Errors
+    ///
+    /// - The system borrows the same storage multiple times with at least one exclusive borrow.
+    /// - The system borrows [AllStorages] and something else.
+    ///
+    /// [AllStorages]: struct.AllStorages.html
+    /// [system]: macro.system.html
+    /// [try_system]: macro.try_system.html
+    #[cfg(feature = "panic")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "panic")))]
+    #[track_caller]
+    pub fn new<'s, B, R, F, S>(system: (S, F)) -> Self
+    where
+        F: System<'s, (), B, R>,
+        S: Fn(&World) -> Result<(), error::Run> + Send + Sync + 'static,
+    {
+        Self::try_new(system).unwrap()
+    }
+    /// Creates a system from its parts, for systems whose borrows are only known at runtime.
+    /// `borrow_info` lists the storages `system` borrows, see [Borrow::borrow_infos].
+    /// `is_send_sync` is `false` if `system` borrows `!Send` or `!Sync` storages, it will then run on the `World`'s thread.
+    ///
+    /// The scheduler relies on `borrow_info` to run systems in parallel,
+    /// if it's incomplete a system could fail to borrow a storage.
+    ///
+    /// ### Errors
+    ///
+    /// - `borrow_info` borrows the same storage multiple times with at least one exclusive borrow.
+    /// - `borrow_info` borrows [AllStorages] and something else.
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{Mutation, TypeId, WorkloadSystem, World};
+    ///
+    /// let system = WorkloadSystem::from_raw(
+    ///     "count",
+    ///     vec![(TypeId::of::<u32>(), Mutation::Shared)],
+    ///     true,
+    ///     Box::new(|world: &World| world.try_run(|u32s: shipyard::View<u32>| drop(u32s))),
+    /// )
+    /// .unwrap();
+    ///
+    /// let world = World::new();
+    ///
+    /// world.add_workload("Count").with_systems(vec![system]).build();
+    /// world.run_workload("Count");
+    /// ```
+    ///
+    /// [AllStorages]: struct.AllStorages.html
+    /// [Borrow::borrow_infos]: trait.Borrow.html#tymethod.borrow_infos
+    #[allow(clippy::type_complexity)]
+    pub fn from_raw(
+        name: &'static str,
+        borrow_info: Vec<(TypeId, Mutation)>,
+        is_send_sync: bool,
+        system: Box<dyn Fn(&World) -> Result<(), error::Run> + Send + Sync + 'static>,
+    ) -> Result<Self, error::InvalidSystem> {
+        check_borrows(&borrow_info)?;
+
+        Ok(WorkloadSystem {
+            type_id: None,
+            name,
+            borrow_info,
+            is_send_sync,
+            // the storages are only known by their TypeId, they'll be created when the system runs
+            create_storages: |_| {},
+            system,
+        })
+    }
+    /// Returns the name of the system.
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+}
+
+/// Checks a system doesn't borrow a storage in a way that would always fail.
+fn check_borrows(borrows: &[(TypeId, Mutation)]) -> Result<(), error::InvalidSystem> {
+    if borrows.contains(&(TypeId::of::<AllStorages>(), Mutation::Unique)) && borrows.len() > 1 {
+        return Err(error::InvalidSystem::AllStorages);
+    }
+
+    for (index, (a_type_id, a_borrow)) in borrows.iter().enumerate() {
+        for (b_type_id, b_borrow) in &borrows[index + 1..] {
+            if a_type_id == b_type_id {
+                match (a_borrow, b_borrow) {
+                    (Mutation::Unique, Mutation::Unique) => {
+                        return Err(error::InvalidSystem::MultipleViewsMut)
+                    }
+                    (Mutation::Unique, Mutation::Shared) | (Mutation::Shared, Mutation::Unique) => {
+                        return Err(error::InvalidSystem::MultipleViews)
+                    }
+                    (Mutation::Shared, Mutation::Shared) => {}
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,29 @@
+use crate::storage::EntityId;
+use core::sync::atomic::{AtomicUsize, Ordering};
+
+// AtomicU64 isn't available on all targets
+static WORLD_ID: AtomicUsize = AtomicUsize::new(0);
+
+/// Unique id of a [World], no two [World]s share the same `WorldId`.
+///
+/// [World]: struct.World.html
+#[derive(Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord, Debug)]
+pub struct WorldId(u64);
+
+impl WorldId {
+    pub(super) fn new() -> Self {
+        WorldId(WORLD_ID.fetch_add(1, Ordering::Relaxed) as u64)
+    }
+}
+
+/// Handle to an entity and the [World] it lives in.  
+/// It can be stored in a [World] and refer to an entity from another one.  
+/// Use [World::try_resolve] to get back the `EntityId`.
+///
+/// [World]: struct.World.html
+/// [World::try_resolve]: struct.World.html#method.try_resolve
+#[derive(Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord, Debug)]
+pub struct GlobalEntityId {
+    pub world: WorldId,
+    pub entity: EntityId,
+}
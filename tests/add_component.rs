@@ -185,3 +185,193 @@ fn no_pack_unchecked() {
         .unwrap();
     assert_eq!((&usizes, &u32s).get(entity1).unwrap(), (&2, &3));
 }
+
+#[test]
+fn view_add_component() {
+    let world = World::new();
+    let entity = world
+        .try_borrow::<EntitiesViewMut>()
+        .unwrap()
+        .add_entity((), ());
+    let dead = world
+        .try_borrow::<EntitiesViewMut>()
+        .unwrap()
+        .add_entity((), ());
+    world
+        .try_borrow::<AllStoragesViewMut>()
+        .unwrap()
+        .delete(dead);
+
+    world
+        .try_run(|mut u32s: ViewMut<u32>| {
+            u32s.try_add_component(0, entity).unwrap();
+            assert_eq!(
+                u32s.try_add_component(1, dead),
+                Err(error::AddComponent::EntityIsNotAlive)
+            );
+            assert_eq!(u32s.get(entity), Ok(&0));
+        })
+        .unwrap();
+
+    world
+        .try_run(|_: EntitiesViewMut, mut u32s: ViewMut<u32>| {
+            assert_eq!(
+                u32s.try_add_component(1, entity),
+                Err(error::AddComponent::Entities(error::Borrow::Shared(Default::default())))
+            );
+        })
+        .unwrap();
+
+    let (mut usizes, mut u32s) = world
+        .try_borrow::<(ViewMut<usize>, ViewMut<u32>)>()
+        .unwrap();
+    (&mut usizes, &mut u32s).try_tight_pack().unwrap();
+    assert_eq!(
+        u32s.try_add_component(1, entity),
+        Err(error::AddComponent::MissingPackStorage(
+            core::any::type_name::<u32>()
+        ))
+    );
+}
+
+#[test]
+fn view_get_or_insert_with() {
+    let world = World::new();
+    let entity = world
+        .try_borrow::<EntitiesViewMut>()
+        .unwrap()
+        .add_entity((), ());
+    let dead = world
+        .try_borrow::<EntitiesViewMut>()
+        .unwrap()
+        .add_entity((), ());
+    world
+        .try_borrow::<AllStoragesViewMut>()
+        .unwrap()
+        .delete(dead);
+
+    world
+        .try_run(|mut u32s: ViewMut<u32>| {
+            *u32s.try_get_or_insert_with(entity, || 0).unwrap() += 1;
+            *u32s
+                .try_get_or_insert_with(entity, || panic!("component is present"))
+                .unwrap() += 1;
+            assert_eq!(u32s.get(entity), Ok(&2));
+            assert_eq!(
+                u32s.try_get_or_insert_with(dead, || 0).err(),
+                Some(error::AddComponent::EntityIsNotAlive)
+            );
+        })
+        .unwrap();
+
+    world
+        .try_run(|_: EntitiesViewMut, mut u32s: ViewMut<u32>| {
+            // Entities isn't needed when the component is present
+            assert_eq!(u32s.try_get_or_insert_with(entity, || 0), Ok(&mut 2));
+        })
+        .unwrap();
+
+    let mut u32s = world.try_borrow::<ViewMut<u32>>().unwrap();
+    u32s.try_update_pack().unwrap();
+    u32s.try_clear_inserted_and_modified().unwrap();
+    *u32s.try_get_or_insert_with(entity, || 0).unwrap() += 1;
+    assert_eq!(u32s.try_modified().unwrap().iter().count(), 1);
+}
+
+#[test]
+fn add_components() {
+    let world = World::new();
+    let dead = world
+        .try_borrow::<EntitiesViewMut>()
+        .unwrap()
+        .add_entity((), ());
+    world
+        .try_borrow::<AllStoragesViewMut>()
+        .unwrap()
+        .delete(dead);
+    let (mut entities, mut usizes, mut u32s) = world
+        .try_borrow::<(EntitiesViewMut, ViewMut<usize>, ViewMut<u32>)>()
+        .unwrap();
+
+    let entity0 = entities.add_entity(&mut u32s, 10);
+    let entity1 = entities.add_entity((), ());
+    let entity2 = entities.add_entity((), ());
+    entities
+        .try_add_components(&mut u32s, &[entity0, entity1, entity2], 0..3)
+        .unwrap();
+    assert_eq!(
+        u32s.iter().with_id().collect::<Vec<_>>(),
+        vec![(entity0, &0), (entity1, &1), (entity2, &2)]
+    );
+
+    assert_eq!(
+        entities.try_add_components(&mut usizes, &[entity0, dead], vec![0, 1]),
+        Err(error::AddComponent::EntityIsNotAlive)
+    );
+    assert!(usizes.is_empty());
+
+    (&mut usizes, &mut u32s).try_tight_pack().unwrap();
+    assert_eq!(
+        entities.try_add_components(&mut u32s, &[entity0], vec![0]),
+        Err(error::AddComponent::MissingPackStorage(
+            core::any::type_name::<u32>()
+        ))
+    );
+}
+
+#[test]
+fn add_components_length_mismatch() {
+    let world = World::new();
+    let (mut entities, mut u32s) = world
+        .try_borrow::<(EntitiesViewMut, ViewMut<u32>)>()
+        .unwrap();
+
+    let entity0 = entities.add_entity((), ());
+    let entity1 = entities.add_entity((), ());
+
+    assert_eq!(
+        entities.try_add_components(&mut u32s, &[entity0, entity1], vec![0]),
+        Err(error::AddComponent::LengthMismatch {
+            entities: 2,
+            components: 1
+        })
+    );
+    assert_eq!(
+        entities.try_add_components(&mut u32s, &[entity0], vec![0, 1]),
+        Err(error::AddComponent::LengthMismatch {
+            entities: 1,
+            components: 2
+        })
+    );
+    assert!(u32s.is_empty());
+}
+
+#[test]
+fn add_components_fixed_capacity() {
+    let world = World::try_with_fixed_capacity(4).unwrap();
+    world.try_register_storage_with_capacity::<u32>(2).unwrap();
+    let (mut entities, mut u32s) = world
+        .try_borrow::<(EntitiesViewMut, ViewMut<u32>)>()
+        .unwrap();
+
+    let entity0 = entities.add_entity(&mut u32s, 0);
+    let entity1 = entities.add_entity((), ());
+    let entity2 = entities.add_entity((), ());
+
+    assert_eq!(
+        entities.try_add_components(&mut u32s, &[entity1, entity2], vec![1, 2]),
+        Err(error::AddComponent::StorageFull(
+            core::any::type_name::<u32>()
+        ))
+    );
+    assert_eq!(u32s.len(), 1);
+
+    // replacing entity0's component doesn't take any room
+    entities
+        .try_add_components(&mut u32s, &[entity0, entity1], vec![10, 11])
+        .unwrap();
+    assert_eq!(
+        u32s.iter().with_id().collect::<Vec<_>>(),
+        vec![(entity0, &10), (entity1, &11)]
+    );
+}
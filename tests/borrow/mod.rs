@@ -58,7 +58,7 @@ fn invalid_borrow() {
         world.try_borrow::<ViewMut<u32>>().err(),
         Some(error::GetStorage::StorageBorrow((
             core::any::type_name::<u32>(),
-            error::Borrow::Unique
+            error::Borrow::Unique(Default::default())
         )))
     );
 }
@@ -73,7 +73,7 @@ fn all_storages_invalid_borrow() {
         all_storages.try_borrow::<ViewMut<u32>>().err(),
         Some(error::GetStorage::StorageBorrow((
             core::any::type_name::<u32>(),
-            error::Borrow::Unique
+            error::Borrow::Unique(Default::default())
         )))
     );
 }
@@ -220,3 +220,56 @@ fn unique_exhaustive_list() {
         )>();
     });
 }
+
+#[cfg(feature = "parallel")]
+#[cfg_attr(miri, ignore)]
+#[test]
+fn storages_from_many_threads() {
+    fn borrow<T: 'static + Send + Sync>(world: &World) {
+        for _ in 0..1000 {
+            // other threads only borrow other storages or share this one
+            let (_, _) = world.try_borrow::<(ViewMut<T>, View<u8>)>().unwrap();
+        }
+    }
+
+    let world = World::new();
+
+    rayon::scope(|scope| {
+        scope.spawn(|_| borrow::<u16>(&world));
+        scope.spawn(|_| borrow::<u32>(&world));
+        scope.spawn(|_| borrow::<u64>(&world));
+        scope.spawn(|_| borrow::<usize>(&world));
+    });
+
+    assert!(world
+        .try_borrow::<(ViewMut<u16>, ViewMut<u32>, ViewMut<u64>, ViewMut<usize>)>()
+        .is_ok());
+}
+
+#[cfg(feature = "parallel")]
+#[cfg_attr(miri, ignore)]
+#[test]
+fn create_storage_from_many_threads() {
+    let world = World::new();
+    let entity = world.borrow::<EntitiesViewMut>().add_entity((), ());
+
+    rayon::scope(|scope| {
+        for _ in 0..4 {
+            scope.spawn(|_| {
+                let (mut u64s, _, _) = loop {
+                    // the other threads might be using u64 storage
+                    if let Ok(views) = world.try_borrow::<(ViewMut<u64>, View<u16>, View<i8>)>() {
+                        break views;
+                    }
+                };
+
+                world
+                    .borrow::<EntitiesView>()
+                    .add_component(&mut u64s, 0, entity);
+            });
+        }
+    });
+
+    // all threads used the same storage
+    assert_eq!(world.borrow::<View<u64>>().len(), 1);
+}
@@ -0,0 +1,153 @@
+#![cfg(feature = "panic")]
+
+use shipyard::*;
+
+struct Pos(u32);
+struct Vel(u32);
+
+struct Physics<'a> {
+    pos: ViewMut<'a, Pos>,
+    vel: View<'a, Vel>,
+}
+
+impl<'a> BorrowBundle<'a> for Physics<'a> {
+    type Views = (ViewMut<'a, Pos>, View<'a, Vel>);
+
+    fn from_views((pos, vel): Self::Views) -> Self {
+        Physics { pos, vel }
+    }
+}
+
+fn movement(mut physics: Physics, mut entities: EntitiesViewMut) {
+    for (pos, vel) in (&mut physics.pos, &physics.vel).iter() {
+        pos.0 += vel.0;
+    }
+
+    entities.add_entity(&mut physics.pos, Pos(0));
+}
+
+#[test]
+fn system_parameter() {
+    let world = World::new();
+
+    let entity = world.run(
+        |mut entities: EntitiesViewMut, mut pos: ViewMut<Pos>, mut vel: ViewMut<Vel>| {
+            entities.add_entity((&mut pos, &mut vel), (Pos(1), Vel(2)))
+        },
+    );
+
+    world.run(movement);
+
+    world.run(|pos: View<Pos>| {
+        assert_eq!(pos.len(), 2);
+        assert_eq!(pos.get(entity).unwrap().0, 3);
+    });
+}
+
+#[test]
+fn borrow_conflict() {
+    let world = World::new();
+
+    let _pos = world.borrow::<View<Pos>>();
+
+    assert_eq!(
+        world.try_borrow::<Physics>().err(),
+        Some(error::GetStorage::StorageBorrow((
+            core::any::type_name::<Pos>(),
+            error::Borrow::Unique(Default::default())
+        )))
+    );
+}
+
+#[test]
+fn workload() {
+    let world = World::new();
+
+    world
+        .add_workload("Physics")
+        .with_system(system!(movement))
+        .with_system(system!(|_: View<Pos>| {}))
+        .build();
+
+    world.run_default();
+
+    world.run(|pos: View<Pos>| assert_eq!(pos.len(), 1));
+}
+
+#[test]
+fn all_storages() {
+    let world = World::new();
+
+    world.run(|all_storages: AllStoragesViewMut| {
+        let physics = all_storages.borrow::<Physics>();
+
+        assert_eq!(physics.pos.len(), 0);
+        assert_eq!(physics.vel.len(), 0);
+    });
+}
+
+#[cfg(feature = "proc")]
+mod derive {
+    use super::*;
+
+    #[derive(Borrow)]
+    struct Physics<'v> {
+        pos: ViewMut<'v, Pos>,
+        vel: View<'v, Vel>,
+    }
+
+    #[derive(Borrow)]
+    struct Tuple<'v>(View<'v, Pos>, UniqueView<'v, u32>);
+
+    #[derive(Borrow)]
+    struct Generic<'v, T: 'static + Send + Sync> {
+        storage: View<'v, T>,
+    }
+
+    #[derive(Borrow)]
+    struct Nested<'v> {
+        physics: Physics<'v>,
+        entities: EntitiesView<'v>,
+    }
+
+    #[derive(Borrow)]
+    struct NoLifetime {
+        _fake: FakeBorrow<Pos>,
+    }
+
+    #[test]
+    fn derive() {
+        let world = World::new();
+
+        world.add_unique(0u32);
+
+        world.run(
+            |mut entities: EntitiesViewMut, mut pos: ViewMut<Pos>, mut vel: ViewMut<Vel>| {
+                entities.add_entity((&mut pos, &mut vel), (Pos(1), Vel(2)));
+            },
+        );
+
+        world.run(|mut physics: Physics| {
+            for (pos, vel) in (&mut physics.pos, &physics.vel).iter() {
+                pos.0 += vel.0;
+            }
+        });
+
+        world.run(|tuple: Tuple, generic: Generic<Vel>| {
+            assert_eq!((&tuple.0).iter().next().unwrap().0, 3);
+            assert_eq!(*tuple.1, 0);
+            assert_eq!(generic.storage.len(), 1);
+        });
+
+        world.run(|nested: Nested| {
+            assert_eq!(nested.physics.pos.len(), 1);
+            assert_eq!(nested.entities.iter().count(), 1);
+        });
+
+        world
+            .add_workload("Fake")
+            .with_system(system!(|_: NoLifetime| {}))
+            .build();
+        world.run_default();
+    }
+}
@@ -0,0 +1,62 @@
+#![cfg(feature = "borrow_owner")]
+
+use shipyard::*;
+
+#[test]
+fn thread() {
+    let world = World::new();
+    let _u32s = world.borrow::<ViewMut<u32>>();
+
+    let message = format!("{:?}", world.try_borrow::<View<u32>>().err().unwrap());
+
+    assert!(message.contains("u32 is exclusively borrowed on thread"));
+    assert!(message.contains(&format!("{:?}", std::thread::current().id())));
+}
+
+#[test]
+fn system() {
+    fn holder(_: ViewMut<u32>) {}
+
+    let world = World::new();
+
+    world
+        .add_workload("Hold")
+        .with_system((
+            |world: &World| {
+                world.try_run(|_: ViewMut<u32>| {
+                    let message = format!("{:?}", world.try_borrow::<View<u32>>().err().unwrap());
+
+                    assert!(message.contains("u32 is exclusively borrowed by system '"));
+                    assert!(message.contains("holder' on thread"));
+                })
+            },
+            holder,
+        ))
+        .build();
+
+    world.run_default();
+
+    // the borrow is released with the system
+    assert!(world.try_borrow::<View<u32>>().is_ok());
+}
+
+#[test]
+fn captured_on_failure() {
+    let world = World::new();
+
+    let u32s = world.borrow::<ViewMut<u32>>();
+    let error = world.try_borrow::<View<u32>>().err().unwrap();
+    drop(u32s);
+
+    // another failure doesn't change the owner of the first one
+    let _usizes = world.borrow::<View<usize>>();
+    assert!(world.try_borrow::<ViewMut<usize>>().is_err());
+
+    // formatted on another thread, after the borrow was released
+    let message = std::thread::spawn(move || format!("{:?}", error))
+        .join()
+        .unwrap();
+
+    assert!(message.contains("u32 is exclusively borrowed on thread"));
+    assert!(message.contains(&format!("{:?}", std::thread::current().id())));
+}
@@ -0,0 +1,135 @@
+#![cfg(feature = "panic")]
+
+use shipyard::*;
+
+#[test]
+fn with_capacity() {
+    let world = World::with_capacity(100);
+
+    for i in 0..200u32 {
+        let entity = world.spawn((i,));
+        assert_eq!(world.borrow::<View<u32>>()[entity], i);
+    }
+}
+
+#[test]
+fn try_with_capacity() {
+    let world = World::try_with_capacity(10).unwrap();
+    let entity = world.spawn((0u32,));
+
+    assert_eq!(world.borrow::<View<u32>>()[entity], 0);
+    assert!(World::try_new().is_ok());
+}
+
+#[test]
+fn storage_capacity() {
+    let world = World::with_capacity(1000);
+    world.register_storage_with_capacity::<u32>(1000);
+    world.set_strict(true);
+
+    world.run(|mut entities: EntitiesViewMut, mut u32s: ViewMut<u32>| {
+        let first = entities.add_entity(&mut u32s, 0);
+        let address = &u32s[first] as *const u32;
+
+        for i in 1..1000 {
+            entities.add_entity(&mut u32s, i);
+        }
+
+        // the components didn't move
+        assert_eq!(&u32s[first] as *const u32, address);
+    });
+}
+
+#[test]
+fn storage_capacity_borrowed() {
+    let world = World::new();
+    let _u32s = world.borrow::<View<u32>>();
+
+    assert!(world.try_register_storage_with_capacity::<u32>(10).is_err());
+}
+
+#[test]
+fn fixed_capacity() {
+    let world = World::with_fixed_capacity(4);
+    world.register_storage_with_capacity::<u32>(2);
+
+    assert_eq!(world.borrow::<EntitiesView>().fixed_capacity(), Some(4));
+    assert_eq!(world.borrow::<ViewMut<u32>>().fixed_capacity(), Some(2));
+    // storages created on borrow can hold a component for each entity
+    assert_eq!(world.borrow::<ViewMut<u64>>().fixed_capacity(), Some(4));
+
+    let entity0 = world.spawn((0u32,));
+    let address = world.borrow::<ViewMut<u32>>().as_slice().as_ptr();
+    world.spawn((1u32,));
+    world.spawn(());
+    world.borrow::<AllStoragesViewMut>().delete(entity0);
+    // entity0's index is reused and no storage grew
+    let entity3 = world.spawn((3u32,));
+    world.spawn((4u64,));
+
+    let u32s = world.borrow::<ViewMut<u32>>();
+    assert_eq!(u32s.as_slice().as_ptr(), address);
+    assert_eq!(u32s.len(), 2);
+    assert_eq!(u32s[entity3], 3);
+}
+
+#[test]
+#[should_panic(expected = "Entities is full, the World's fixed capacity is 2 entities.")]
+fn fixed_capacity_entities_full() {
+    let world = World::with_fixed_capacity(2);
+
+    world.spawn(());
+    world.spawn(());
+    world.spawn(());
+}
+
+#[test]
+#[should_panic(expected = "u32 storage is full, its fixed capacity is 1 components.")]
+fn fixed_capacity_storage_full() {
+    let world = World::with_fixed_capacity(2);
+    world.register_storage_with_capacity::<u32>(1);
+
+    world.spawn((0u32,));
+    world.spawn((1u32,));
+}
+
+#[test]
+fn compact_all() {
+    let world = World::new();
+
+    let entities = world.run(
+        |mut entities: EntitiesViewMut, mut u32s: ViewMut<u32>, mut u64s: ViewMut<u64>| {
+            (0..10)
+                .map(|i| entities.add_entity((&mut u32s, &mut u64s), (i, i as u64)))
+                .collect::<Vec<_>>()
+        },
+    );
+    world.run(|mut u64s: ViewMut<u64>| u64s.preserve_order(true));
+    world.run(|mut all_storages: AllStoragesViewMut| {
+        all_storages.delete(entities[0]);
+        all_storages.delete(entities[4]);
+        all_storages.compact_all();
+    });
+
+    world.run(|u32s: ViewMut<u32>, u64s: ViewMut<u64>| {
+        assert_eq!(u32s.as_slice(), &[1, 2, 3, 5, 6, 7, 8, 9]);
+        assert_eq!(u64s.as_slice(), &[1, 2, 3, 5, 6, 7, 8, 9]);
+    });
+}
+
+#[test]
+fn compact_fixed_capacity() {
+    let world = World::with_fixed_capacity(4);
+
+    let entity0 = world.spawn((0u32,));
+    world.spawn((1u32,));
+    let address = world.borrow::<ViewMut<u32>>().as_slice().as_ptr();
+    world.borrow::<AllStoragesViewMut>().delete(entity0);
+    world.borrow::<AllStoragesViewMut>().compact_all();
+
+    let entity2 = world.spawn((2u32,));
+    let mut u32s = world.borrow::<ViewMut<u32>>();
+    u32s.compact();
+    assert_eq!(u32s.as_slice().as_ptr(), address);
+    assert_eq!(u32s[entity2], 2);
+}
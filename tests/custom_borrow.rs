@@ -0,0 +1,105 @@
+#![cfg(feature = "panic")]
+
+use shipyard::error::GetStorage;
+use shipyard::*;
+
+struct Assets<T>(Vec<T>);
+
+struct AssetsViewMut<'a, T: 'static + Send + Sync>(UniqueViewMut<'a, Assets<T>>);
+
+impl<T: 'static + Send + Sync> AssetsViewMut<'_, T> {
+    fn load(&mut self, asset: T) -> usize {
+        self.0 .0.push(asset);
+        self.0 .0.len() - 1
+    }
+}
+
+impl<'a, T: 'static + Send + Sync> Borrow<'a> for AssetsViewMut<'a, T> {
+    fn try_borrow(
+        all_storages: &'a AtomicRefCell<AllStorages>,
+        #[cfg(feature = "parallel")] thread_pool: &'a rayon::ThreadPool,
+    ) -> Result<Self, GetStorage> {
+        let assets = <UniqueViewMut<'a, Assets<T>> as Borrow<'a>>::try_borrow(
+            all_storages,
+            #[cfg(feature = "parallel")]
+            thread_pool,
+        )?;
+
+        Ok(AssetsViewMut(assets))
+    }
+
+    fn borrow_infos(infos: &mut Vec<(TypeId, Mutation)>) {
+        infos.push((TypeId::of::<Assets<T>>(), Mutation::Unique));
+    }
+
+    fn is_send_sync() -> bool {
+        true
+    }
+}
+
+impl<'a, T: 'static + Send + Sync> AllStoragesBorrow<'a> for AssetsViewMut<'a, T> {
+    fn try_borrow(all_storages: &'a AllStorages) -> Result<Self, GetStorage> {
+        let assets =
+            <UniqueViewMut<'a, Assets<T>> as AllStoragesBorrow<'a>>::try_borrow(all_storages)?;
+
+        Ok(AssetsViewMut(assets))
+    }
+}
+
+#[test]
+fn system_parameter() {
+    let world = World::new();
+
+    world.add_unique(Assets(vec![0u32]));
+
+    let handle = world.run(|mut assets: AssetsViewMut<u32>| assets.load(1));
+
+    assert_eq!(handle, 1);
+    world.run(|assets: UniqueView<Assets<u32>>| assert_eq!(assets.0, [0, 1]));
+}
+
+#[test]
+fn borrow_conflict() {
+    let world = World::new();
+
+    world.add_unique(Assets(Vec::<u32>::new()));
+
+    let _assets = world.borrow::<UniqueView<Assets<u32>>>();
+
+    assert!(world.try_borrow::<AssetsViewMut<u32>>().is_err());
+}
+
+#[test]
+fn workload() {
+    let world = World::new();
+
+    world.add_unique(Assets(Vec::<u32>::new()));
+
+    world
+        .add_workload("Load")
+        .with_system(system!(|mut assets: AssetsViewMut<u32>| {
+            assets.load(0);
+        }))
+        .with_system(system!(|mut assets: AssetsViewMut<u32>| {
+            assets.load(1);
+        }))
+        .build();
+
+    world.run_default();
+
+    // both systems borrow the storage uniquely so they run one after the other
+    world.run(|assets: UniqueView<Assets<u32>>| assert_eq!(assets.0, [0, 1]));
+}
+
+#[test]
+fn all_storages() {
+    let world = World::new();
+
+    world.add_unique(Assets(Vec::<u32>::new()));
+
+    world.run(|all_storages: AllStoragesViewMut| {
+        all_storages.borrow::<AssetsViewMut<u32>>().load(0);
+    });
+
+    world.run(|assets: UniqueView<Assets<u32>>| assert_eq!(assets.0, [0]));
+}
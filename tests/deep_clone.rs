@@ -0,0 +1,96 @@
+#![cfg(feature = "panic")]
+
+use shipyard::*;
+
+#[test]
+fn independent_copy() {
+    let world = World::new();
+
+    world.run(|mut u32s: ViewMut<u32>| u32s.setup_clone());
+
+    let entity1 = world.spawn((0u32, 1usize));
+    let entity2 = world.spawn((2u32,));
+
+    let clone = world.deep_clone();
+    assert_ne!(world.id(), clone.id());
+
+    let entity3 = clone.spawn((3u32,));
+    clone.run(|mut all_storages: AllStoragesViewMut| {
+        all_storages.delete(entity2);
+    });
+    clone.run(|mut u32s: ViewMut<u32>| u32s[entity1] = 10);
+
+    world.run(|entities: EntitiesView, u32s: View<u32>| {
+        assert!(entities.is_alive(entity2));
+        assert!(!entities.is_alive(entity3));
+        assert_eq!(u32s[entity1], 0);
+        assert_eq!(u32s[entity2], 2);
+        assert_eq!(u32s.len(), 2);
+    });
+    clone.run(
+        |entities: EntitiesView, u32s: View<u32>, usizes: View<usize>| {
+            assert!(!entities.is_alive(entity2));
+            assert!(entities.is_alive(entity3));
+            assert_eq!(u32s[entity1], 10);
+            assert_eq!(u32s[entity3], 3);
+            assert_eq!(u32s.len(), 2);
+            // not set up to be cloned
+            assert_eq!(usizes.len(), 0);
+        },
+    );
+}
+
+#[test]
+fn clone_of_clone() {
+    let world = World::new();
+
+    world.run(|mut u32s: ViewMut<u32>| u32s.setup_clone());
+
+    let entity = world.spawn((0u32,));
+
+    let clone = world.deep_clone().deep_clone();
+
+    clone.run(|u32s: View<u32>| assert_eq!(u32s[entity], 0));
+}
+
+#[test]
+fn uniques() {
+    let world = World::new();
+
+    world.add_unique_cloneable(0u32);
+    world.add_unique(0usize);
+
+    let clone = world.deep_clone();
+
+    *clone.borrow::<UniqueViewMut<u32>>() = 1;
+
+    assert_eq!(*world.borrow::<UniqueView<u32>>(), 0);
+    assert_eq!(*clone.borrow::<UniqueView<u32>>(), 1);
+    assert!(clone.try_borrow::<UniqueView<usize>>().is_err());
+}
+
+#[test]
+fn fork() {
+    let world = World::new();
+
+    world.run(|mut u32s: ViewMut<u32>| u32s.setup_snapshot());
+
+    let entity = world.spawn((0u32,));
+
+    let fork = world.fork();
+    let clone = fork.deep_clone();
+
+    clone.run(|mut u32s: ViewMut<u32>| u32s[entity] = 1);
+
+    fork.run(|u32s: View<u32>| assert_eq!(u32s[entity], 0));
+    clone.run(|u32s: View<u32>| assert_eq!(u32s[entity], 1));
+}
+
+#[test]
+fn borrow_conflict() {
+    let world = World::new();
+
+    let _all_storages = world.borrow::<AllStoragesViewMut>();
+
+    assert_eq!(world.try_deep_clone().err(), Some(error::Borrow::Unique(Default::default())));
+}
@@ -209,3 +209,71 @@ fn update() {
     assert_eq!(usizes.try_take_deleted().unwrap(), vec![(entity1, 0)]);
     assert_eq!(usizes.try_removed().unwrap().len(), 0);
 }
+
+#[test]
+fn shared_all_storages() {
+    let world = World::new();
+    let (mut entities, mut usizes, mut u32s) = world
+        .try_borrow::<(EntitiesViewMut, ViewMut<usize>, ViewMut<u32>)>()
+        .unwrap();
+
+    (&mut usizes, &mut u32s).try_tight_pack().unwrap();
+    let entity1 = entities.add_entity((&mut usizes, &mut u32s), (0usize, 1u32));
+    let entity2 = entities.add_entity((&mut usizes, &mut u32s), (2usize, 3u32));
+    let entity3 = entities.add_entity(&mut usizes, 4usize);
+    drop((entities, usizes, u32s));
+
+    let u64s = world.try_borrow::<View<u64>>().unwrap();
+    assert_eq!(world.try_delete_entity(entity1), Ok(true));
+    assert_eq!(world.try_delete_entity(entity1), Ok(false));
+    drop(u64s);
+
+    let u32s = world.try_borrow::<View<u32>>().unwrap();
+    assert_eq!(
+        world.try_delete_entity(entity2),
+        Err(error::GetStorage::StorageBorrow((
+            type_name::<u32>(),
+            error::Borrow::Unique(Default::default())
+        )))
+    );
+    assert_eq!(world.try_delete_entity(entity3), Ok(true));
+    drop(u32s);
+
+    let (usizes, u32s) = world.try_borrow::<(View<usize>, View<u32>)>().unwrap();
+    assert!(usizes.get(entity1).is_err());
+    assert!(u32s.get(entity1).is_err());
+    assert!(usizes.get(entity3).is_err());
+    assert_eq!((&usizes, &u32s).get(entity2), Ok((&2, &3)));
+    assert_eq!((&usizes, &u32s).iter().count(), 1);
+}
+
+#[cfg(feature = "panic")]
+#[test]
+fn unrelated_exclusive_borrow() {
+    let world: &'static World = Box::leak(Box::new(World::new()));
+    let entity = world.spawn((0usize,));
+    let other = world.spawn((1u32,));
+
+    let (borrowed_sender, borrowed) = std::sync::mpsc::channel();
+    let (deleted_sender, deleted) = std::sync::mpsc::channel();
+
+    let handle = std::thread::spawn(move || {
+        world.run(|mut u32s: ViewMut<u32>| {
+            borrowed_sender.send(()).unwrap();
+            // keeps the exclusive borrow until the entity is deleted
+            deleted.recv().unwrap();
+            u32s[other] += 1;
+        });
+    });
+
+    borrowed.recv().unwrap();
+    // only usize's storage is borrowed
+    assert_eq!(world.try_delete_entity(entity), Ok(true));
+    deleted_sender.send(()).unwrap();
+    handle.join().unwrap();
+
+    world.run(|usizes: View<usize>, u32s: View<u32>| {
+        assert!(usizes.get(entity).is_err());
+        assert_eq!(u32s.get(other), Ok(&2));
+    });
+}
@@ -0,0 +1,218 @@
+#![cfg(feature = "panic")]
+
+use shipyard::*;
+
+struct Position;
+
+#[test]
+fn entity_components() {
+    let world = World::new();
+
+    let entity = world.spawn((Position, 0u32, 1usize));
+    let empty = world.spawn(());
+    world.add_unique(0u64);
+
+    world.run(|all_storages: AllStoragesViewMut| {
+        assert_eq!(
+            all_storages.entity_components(entity),
+            vec!["entity_components::Position", "u32", "usize"]
+        );
+        assert!(all_storages.entity_components(empty).is_empty());
+    });
+
+    world.run(|mut u32s: ViewMut<u32>| {
+        u32s.remove(entity);
+    });
+
+    world.run(|mut all_storages: AllStoragesViewMut| {
+        assert_eq!(
+            all_storages.entity_components(entity),
+            vec!["entity_components::Position", "usize"]
+        );
+
+        all_storages.delete(entity);
+        assert!(all_storages.entity_components(entity).is_empty());
+    });
+}
+
+#[test]
+fn entity_components_borrowed() {
+    let world = World::new();
+
+    let entity = world.spawn((Position, 0u32));
+
+    world.run(|all_storages: AllStoragesViewMut| {
+        let u32s = all_storages.borrow::<View<u32>>();
+
+        assert_eq!(
+            all_storages.entity_components(entity),
+            vec!["entity_components::Position", "u32"]
+        );
+
+        drop(u32s);
+        let _u32s = all_storages.borrow::<ViewMut<u32>>();
+
+        assert!(matches!(
+            all_storages.try_entity_components(entity),
+            Err(error::GetStorage::StorageBorrow(("u32", _)))
+        ));
+    });
+}
+
+#[test]
+fn storage_names() {
+    let world = World::new();
+
+    world.run(|mut u32s: ViewMut<u32>| u32s.setup_snapshot());
+    world.spawn((Position, 0u32));
+    world.add_unique(0u64);
+
+    world.run(|all_storages: AllStoragesViewMut| {
+        let _u32s = all_storages.borrow::<ViewMut<u32>>();
+
+        // the storage is borrowed but its name is still available
+        assert_eq!(
+            all_storages.storage_name(StorageId::of::<u32>()),
+            Some("u32")
+        );
+        assert_eq!(all_storages.storage_name(StorageId::of::<u64>()), None);
+        assert_eq!(all_storages.storage_names().len(), 4);
+    });
+
+    world.remove_unique::<u64>();
+    let fork = world.fork();
+
+    // removed storages keep their name
+    assert!(world
+        .borrow::<AllStoragesViewMut>()
+        .storage_names()
+        .iter()
+        .any(|&(_, name)| name == "u64"));
+    assert_eq!(
+        fork.borrow::<AllStoragesViewMut>()
+            .storage_name(StorageId::of::<u32>()),
+        Some("u32")
+    );
+}
+
+#[test]
+fn query_dynamic() {
+    struct Health(u32);
+    struct Burning;
+
+    let world = World::new();
+
+    let entity0 = world.spawn((Health(10), Burning));
+    world.spawn((Health(20),));
+    let entity2 = world.spawn((Burning, Health(30), 0u32));
+    world.spawn((Burning,));
+
+    world.run(|mut all_storages: AllStoragesViewMut| {
+        let mut healths = Vec::new();
+
+        all_storages.query_dynamic(
+            &[StorageId::of::<Health>(), StorageId::of::<Burning>()],
+            |entity, components| {
+                assert_eq!(components.len(), 2);
+                assert!(components[1].is::<Burning>());
+
+                healths.push((entity, components[0].downcast_ref::<Health>().unwrap().0));
+            },
+        );
+
+        healths.sort_unstable();
+        assert_eq!(healths, vec![(entity0, 10), (entity2, 30)]);
+
+        let mut count = 0;
+        all_storages.query_dynamic(&[StorageId::of::<u64>()], |_, _| count += 1);
+        all_storages.query_dynamic(&[], |_, _| count += 1);
+        assert_eq!(count, 0);
+    });
+}
+
+#[test]
+fn for_each_storage() {
+    let world = World::new();
+
+    world.run(|mut u32s: ViewMut<u32>| u32s.setup_snapshot());
+    let entity = world.spawn((Position, 0u32));
+    world.spawn((1u32,));
+    world.add_unique(0u64);
+    let fork = world.fork();
+
+    world.run(|mut u32s: ViewMut<u32>| {
+        u32s.remove(entity);
+    });
+
+    world.run(|mut all_storages: AllStoragesViewMut| {
+        let mut storages = Vec::new();
+
+        all_storages.for_each_storage(|storage_id, storage| {
+            storages.push((
+                storage.name(),
+                storage.len(),
+                storage.contains(entity),
+                storage_id == StorageId::of::<u32>(),
+            ));
+        });
+
+        storages.sort_unstable();
+        assert_eq!(
+            storages,
+            vec![
+                ("Entities", 0, false, false),
+                ("entity_components::Position", 1, true, false),
+                ("u32", 1, false, true),
+                ("u64", 0, false, false),
+            ]
+        );
+    });
+
+    // storages shared with the original World are visited too
+    let mut count = 0;
+    fork.borrow::<AllStoragesViewMut>()
+        .for_each_storage(|_, storage| count += storage.len());
+    assert_eq!(count, 2);
+}
+
+#[test]
+fn signature() {
+    let world = World::new();
+
+    let entity0 = world.spawn((Position, 0u32));
+    let entity1 = world.spawn((1u32, Position));
+    let entity2 = world.spawn((Position, 2u32, 3usize));
+    let empty = world.spawn(());
+    world.add_unique(0u64);
+
+    let signature = world.signature(entity0);
+    assert_eq!(signature, world.signature(entity1));
+    assert_ne!(signature, world.signature(entity2));
+    assert_eq!(
+        signature,
+        Signature::new(&[
+            StorageId::of::<u32>(),
+            StorageId::of::<Position>(),
+            StorageId::of::<u32>()
+        ])
+    );
+    assert!(world.signature(empty).is_empty());
+
+    let mut matching = world.entities_matching_signature(&signature);
+    matching.sort_unstable();
+    assert_eq!(matching, vec![entity0, entity1]);
+
+    assert_eq!(
+        world.entities_matching_signature(&Signature::default()),
+        vec![empty]
+    );
+    assert!(world
+        .entities_matching_signature(&Signature::new(&[StorageId::of::<u16>()]))
+        .is_empty());
+
+    world.run(|mut u32s: ViewMut<u32>| {
+        u32s.remove(entity1);
+    });
+
+    assert_eq!(world.entities_matching_signature(&signature), vec![entity0]);
+}
@@ -0,0 +1,44 @@
+#![cfg(feature = "panic")]
+
+use shipyard::*;
+
+#[test]
+fn event_log() {
+    let world = World::new();
+    let entity0 = world.spawn((0u32,));
+    world.enable_event_log();
+
+    let u32s = StorageId::of::<u32>();
+    let usizes = StorageId::of::<usize>();
+
+    let entity1 = world.spawn((1u32, 2usize));
+    world.run(|entities: EntitiesView, mut u32s: ViewMut<u32>| {
+        // replacing a component isn't logged
+        entities.add_component(&mut u32s, 3, entity1);
+        u32s.try_remove(entity0).unwrap();
+    });
+    world.run(|mut all_storages: AllStoragesViewMut| {
+        all_storages.delete(entity1);
+    });
+
+    let events = world.drain_events();
+    assert_eq!(
+        events[..5],
+        [
+            StructuralEvent::EntityCreated(entity1),
+            StructuralEvent::ComponentAdded(entity1, u32s),
+            StructuralEvent::ComponentAdded(entity1, usizes),
+            StructuralEvent::ComponentRemoved(entity0, u32s),
+            StructuralEvent::EntityDeleted(entity1),
+        ]
+    );
+    // storages are visited in no particular order when an entity is deleted
+    assert_eq!(events.len(), 7);
+    assert!(events[5..].contains(&StructuralEvent::ComponentRemoved(entity1, u32s)));
+    assert!(events[5..].contains(&StructuralEvent::ComponentRemoved(entity1, usizes)));
+    assert!(world.drain_events().is_empty());
+
+    world.disable_event_log();
+    world.spawn((4u32,));
+    assert!(world.drain_events().is_empty());
+}
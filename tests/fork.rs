@@ -0,0 +1,96 @@
+#![cfg(feature = "panic")]
+
+use shipyard::*;
+use std::sync::Arc;
+
+#[test]
+fn fork() {
+    let world = World::new();
+
+    world.run(|mut u32s: ViewMut<u32>| u32s.setup_snapshot());
+
+    let entity0 = world.spawn((0u32, 0usize));
+    let fork = world.fork();
+
+    assert_ne!(world.id(), fork.id());
+
+    let entity1 = fork.spawn((1u32,));
+    fork.run(|mut u32s: ViewMut<u32>| u32s[entity0] = 10);
+
+    world.run(|entities: EntitiesView, u32s: View<u32>| {
+        assert!(!entities.is_alive(entity1));
+        assert_eq!(u32s.len(), 1);
+        assert_eq!(u32s[entity0], 0);
+    });
+    fork.run(
+        |entities: EntitiesView, u32s: View<u32>, usizes: View<usize>| {
+            assert!(entities.is_alive(entity0));
+            assert_eq!(u32s.len(), 2);
+            assert_eq!(u32s[entity0], 10);
+            assert_eq!(u32s[entity1], 1);
+            // storages without setup aren't shared
+            assert!(usizes.is_empty());
+        },
+    );
+}
+
+#[test]
+fn copy_on_write() {
+    let world = World::new();
+
+    world.run(|mut arcs: ViewMut<Arc<u32>>| arcs.setup_snapshot());
+
+    let component = Arc::new(0u32);
+    let entity = world.spawn((component.clone(),));
+    let fork = world.fork();
+
+    let strong_count = Arc::strong_count(&component);
+
+    // shared borrows read the state the fork was made from
+    fork.run(|arcs: View<Arc<u32>>| assert_eq!(*arcs[entity], 0));
+    assert_eq!(
+        fork.borrow::<AllStoragesView>().entity_components(entity),
+        vec!["alloc::sync::Arc<u32>"]
+    );
+    assert_eq!(Arc::strong_count(&component), strong_count);
+
+    // the storage is cloned on the first exclusive borrow only
+    fork.run(|_: ViewMut<Arc<u32>>| {});
+    assert_eq!(Arc::strong_count(&component), strong_count + 1);
+    fork.run(|_: ViewMut<Arc<u32>>| {});
+    assert_eq!(Arc::strong_count(&component), strong_count + 1);
+}
+
+#[test]
+fn delete() {
+    let world = World::new();
+
+    world.run(|mut u32s: ViewMut<u32>| u32s.setup_snapshot());
+
+    let entity = world.spawn((0u32,));
+    let fork = world.fork();
+
+    assert!(fork.borrow::<AllStoragesViewMut>().delete(entity));
+
+    fork.run(|u32s: View<u32>| assert!(u32s.is_empty()));
+    world.run(|u32s: View<u32>| assert_eq!(u32s.len(), 1));
+}
+
+#[test]
+fn repeated_forks() {
+    let world = World::new();
+
+    world.run(|mut u32s: ViewMut<u32>| u32s.setup_snapshot());
+
+    let entity = world.spawn((0u32,));
+    let fork0 = world.fork();
+
+    world.run(|mut u32s: ViewMut<u32>| u32s[entity] = 1);
+    let fork1 = world.fork();
+
+    fork0.run(|u32s: View<u32>| assert_eq!(u32s[entity], 0));
+    fork1.run(|u32s: View<u32>| assert_eq!(u32s[entity], 1));
+
+    let fork2 = fork1.fork();
+    fork2.run(|u32s: View<u32>| assert_eq!(u32s[entity], 1));
+}
@@ -0,0 +1,132 @@
+use shipyard::*;
+
+#[test]
+fn alloc() {
+    let frame_alloc = FrameAlloc::with_capacity(16);
+
+    let byte = frame_alloc.alloc(1u8);
+    let value = frame_alloc.alloc(2u64);
+    let slice = frame_alloc.alloc_slice_copy(&[3u32, 4, 5]);
+    // doesn't fit in the first chunk
+    let filled = frame_alloc.alloc_slice_fill(32, 6u16);
+
+    assert_eq!(*byte, 1);
+    assert_eq!(*value, 2);
+    assert_eq!(value as *mut u64 as usize % core::mem::align_of::<u64>(), 0);
+    assert_eq!(slice, &[3, 4, 5]);
+    assert_eq!(filled, &[6; 32][..]);
+    assert!(frame_alloc.allocated_bytes() >= 1 + 8 + 12 + 64);
+}
+
+#[test]
+fn live_allocations() {
+    let frame_alloc = FrameAlloc::with_capacity(8);
+
+    // both references are used after the other was handed out, Miri checks they don't invalidate each other
+    let first = frame_alloc.alloc(1u32);
+    let second = frame_alloc.alloc(2u32);
+    *first += 10;
+    *second += 20;
+    // doesn't fit in the first chunk
+    let third = frame_alloc.alloc_slice_fill(4, 3u32);
+    *first += 100;
+    *second += 200;
+    third[0] = *first + *second;
+
+    assert_eq!(*first, 111);
+    assert_eq!(*second, 222);
+    assert_eq!(third, &[333, 3, 3, 3]);
+}
+
+#[test]
+#[should_panic(expected = "FrameAlloc allocation size overflow")]
+fn huge_len() {
+    let frame_alloc = FrameAlloc::with_capacity(16);
+
+    frame_alloc.alloc_slice_fill(usize::MAX, 0u32);
+}
+
+#[test]
+#[should_panic(expected = "FrameAlloc allocation size overflow")]
+fn huge_len_padding() {
+    let frame_alloc = FrameAlloc::with_capacity(16);
+
+    // the size fits in a usize but not once padded for alignment
+    frame_alloc.alloc_slice_fill(usize::MAX, 0u8);
+}
+
+#[test]
+fn reset() {
+    let mut frame_alloc = FrameAlloc::with_capacity(4);
+
+    frame_alloc.alloc_slice_fill(100, 0u8);
+    assert_eq!(frame_alloc.allocated_bytes(), 100);
+
+    frame_alloc.reset();
+    assert_eq!(frame_alloc.allocated_bytes(), 0);
+
+    // the largest chunk is kept
+    frame_alloc.alloc_slice_fill(100, 0u8);
+    assert_eq!(frame_alloc.allocated_bytes(), 100);
+}
+
+#[cfg(feature = "panic")]
+#[test]
+fn workload_reset() {
+    fn scratch(frame_alloc: UniqueView<FrameAlloc>) {
+        let values = frame_alloc.alloc_slice_fill(4, 1u32);
+        assert_eq!(values.iter().sum::<u32>(), 4);
+    }
+
+    fn check(frame_alloc: UniqueView<FrameAlloc>) {
+        assert_eq!(frame_alloc.allocated_bytes(), 16);
+    }
+
+    let world = World::new();
+    world.add_unique(FrameAlloc::new());
+
+    world
+        .add_workload("Scratch")
+        .with_system(system!(scratch))
+        .with_system(system!(check))
+        .build();
+
+    world.run_default();
+    world.run(|frame_alloc: UniqueView<FrameAlloc>| {
+        assert_eq!(frame_alloc.allocated_bytes(), 0);
+    });
+
+    world.run_default();
+    world.run(|frame_alloc: UniqueView<FrameAlloc>| {
+        assert_eq!(frame_alloc.allocated_bytes(), 0);
+    });
+}
+
+#[cfg(feature = "panic")]
+#[test]
+fn maintain() {
+    let world = World::new();
+
+    // nothing to maintain
+    world.maintain();
+
+    world.add_unique(FrameAlloc::new());
+
+    world.run(|frame_alloc: UniqueView<FrameAlloc>| {
+        frame_alloc.alloc_slice_fill(10, 0u8);
+    });
+    world.run(|frame_alloc: UniqueView<FrameAlloc>| assert_eq!(frame_alloc.allocated_bytes(), 10));
+
+    {
+        let _frame_alloc = world.borrow::<UniqueView<FrameAlloc>>();
+        assert_eq!(
+            world.try_maintain().err(),
+            Some(error::GetStorage::AllStoragesBorrow(error::Borrow::Unique(
+                Default::default()
+            )))
+        );
+    }
+
+    world.maintain();
+    world.run(|frame_alloc: UniqueView<FrameAlloc>| assert_eq!(frame_alloc.allocated_bytes(), 0));
+}
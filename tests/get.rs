@@ -132,6 +132,39 @@ fn update() {
     assert_eq!((&u32s, &i16s).get(entity4), Ok((&4, &14)));
 }
 
+#[test]
+fn untracked() {
+    let world = World::new();
+
+    let (mut entities, mut u32s) = world
+        .try_borrow::<(EntitiesViewMut, ViewMut<u32>)>()
+        .unwrap();
+    u32s.try_update_pack().unwrap();
+    let entity0 = entities.add_entity(&mut u32s, 0);
+    let entity1 = entities.add_entity(&mut u32s, 1);
+    let entity2 = entities.add_entity((), ());
+    u32s.try_clear_inserted().unwrap();
+
+    *u32s.get_untracked(entity0).unwrap() += 10;
+    assert_eq!(u32s.get(entity0), Ok(&10));
+    assert_eq!(u32s.try_modified().unwrap().len(), 0);
+
+    *(&mut u32s).get(entity1).unwrap() += 10;
+    *u32s.get_untracked(entity0).unwrap() += 10;
+    assert_eq!(u32s.get(entity0), Ok(&20));
+    assert_eq!(u32s.get(entity1), Ok(&11));
+    assert_eq!(u32s.try_modified().unwrap().len(), 1);
+    assert!(u32s.try_modified().unwrap().contains(entity1));
+
+    assert_eq!(
+        u32s.get_untracked(entity2),
+        Err(error::MissingComponent {
+            id: entity2,
+            name: core::any::type_name::<u32>(),
+        })
+    );
+}
+
 #[test]
 fn off_by_one() {
     let world = World::new();
@@ -0,0 +1,100 @@
+#![cfg(feature = "panic")]
+
+use shipyard::*;
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+enum Faction {
+    Red,
+    Blue,
+}
+
+struct Soldier {
+    faction: Faction,
+}
+
+#[test]
+fn index() {
+    let world = World::new();
+    let (mut entities, mut soldiers) = world.borrow::<(EntitiesViewMut, ViewMut<Soldier>)>();
+
+    let red1 = entities.add_entity(
+        &mut soldiers,
+        Soldier {
+            faction: Faction::Red,
+        },
+    );
+    soldiers.setup_index(|soldier| soldier.faction);
+    let red2 = entities.add_entity(
+        &mut soldiers,
+        Soldier {
+            faction: Faction::Red,
+        },
+    );
+    let blue = entities.add_entity(
+        &mut soldiers,
+        Soldier {
+            faction: Faction::Blue,
+        },
+    );
+
+    let index = soldiers.index::<Faction>().unwrap();
+    let mut reds = index.get(&Faction::Red).to_vec();
+    reds.sort();
+    assert_eq!(reds, vec![red1, red2]);
+    assert_eq!(index.get(&Faction::Blue), &[blue]);
+    assert_eq!(index.len(), 2);
+    assert!(soldiers.index::<u32>().is_none());
+
+    // replacing the component moves the entity to its new key
+    entities.add_component(
+        &mut soldiers,
+        Soldier {
+            faction: Faction::Blue,
+        },
+        red1,
+    );
+    soldiers.remove(red2);
+    soldiers.delete(blue);
+
+    let index = soldiers.index::<Faction>().unwrap();
+    assert!(!index.contains_key(&Faction::Red));
+    assert_eq!(index.get(&Faction::Blue), &[red1]);
+    assert_eq!(index.key_of(red1), Some(&Faction::Blue));
+
+    soldiers[red1].faction = Faction::Red;
+    assert_eq!(
+        soldiers.index::<Faction>().unwrap().get(&Faction::Blue),
+        &[red1]
+    );
+    soldiers.reindex(red1);
+    assert_eq!(
+        soldiers.index::<Faction>().unwrap().get(&Faction::Red),
+        &[red1]
+    );
+
+    soldiers.clear();
+    assert!(soldiers.index::<Faction>().unwrap().is_empty());
+}
+
+#[test]
+fn delete_entity() {
+    let world = World::new();
+
+    let entity = world.run(
+        |mut entities: EntitiesViewMut, mut soldiers: ViewMut<Soldier>| {
+            soldiers.setup_index(|soldier| soldier.faction);
+            entities.add_entity(
+                &mut soldiers,
+                Soldier {
+                    faction: Faction::Red,
+                },
+            )
+        },
+    );
+
+    world.borrow::<AllStoragesViewMut>().delete(entity);
+
+    world.run(|soldiers: View<Soldier>| {
+        assert!(soldiers.index::<Faction>().unwrap().is_empty());
+    });
+}
@@ -1,4 +1,5 @@
 mod loose;
 mod non_packed;
+mod std_iter;
 mod tight;
 mod update;
@@ -0,0 +1,43 @@
+use shipyard::*;
+use std::collections::BTreeMap;
+
+fn even<'a>(u32s: &'a View<'_, u32>) -> impl Iterator<Item = &'a u32> + 'a {
+    u32s.iter().filter(|&&x| x % 2 == 0).into_iterator()
+}
+
+#[test]
+fn std_iter() {
+    let world = World::new();
+
+    let (mut entities, mut u32s) = world
+        .try_borrow::<(EntitiesViewMut, ViewMut<u32>)>()
+        .unwrap();
+    let entity0 = entities.add_entity(&mut u32s, 0);
+    let entity1 = entities.add_entity(&mut u32s, 1);
+    let entity2 = entities.add_entity(&mut u32s, 2);
+    drop((entities, u32s));
+
+    let u32s = world.try_borrow::<View<u32>>().unwrap();
+
+    let mut sum = 0;
+    for &x in u32s.iter() {
+        sum += x;
+    }
+    assert_eq!(sum, 3);
+
+    assert_eq!(even(&u32s).copied().collect::<Vec<_>>(), vec![0, 2]);
+
+    let by_id = u32s.iter().with_id().collect::<BTreeMap<_, _>>();
+    assert_eq!(by_id.len(), 3);
+    assert_eq!(by_id[&entity1], &1);
+
+    assert_eq!(
+        u32s.iter()
+            .with_id()
+            .into_iter()
+            .map(|(id, _)| id)
+            .zip(0..)
+            .collect::<Vec<_>>(),
+        vec![(entity0, 0), (entity1, 1), (entity2, 2)]
+    );
+}
@@ -226,3 +226,48 @@ fn off_by_one() {
     assert_eq!(iter.size_hint(), (1, Some(1)));
     assert_eq!(iter.collect::<Vec<_>>(), vec![&2]);
 }
+
+#[test]
+fn short_circuit() {
+    let world = World::new();
+
+    world
+        .try_run(
+            |(mut entities, mut u32s): (EntitiesViewMut, ViewMut<u32>)| {
+                entities.add_entity(&mut u32s, 0);
+                entities.add_entity(&mut u32s, 1);
+                entities.add_entity(&mut u32s, 2);
+            },
+        )
+        .unwrap();
+
+    world
+        .try_run(|u32s: View<u32>| {
+            let mut iter = u32s.iter();
+            assert_eq!(
+                iter.try_for_each(|&x| if x < 1 { Ok(()) } else { Err(x) }),
+                Err(1)
+            );
+            assert_eq!(iter.next(), Some(&2));
+
+            let mut iter = u32s.iter();
+            assert_eq!(
+                iter.find_map(|&x| if x > 0 { Some(x * 10) } else { None }),
+                Some(10)
+            );
+            assert_eq!(iter.next(), Some(&2));
+
+            let mut iter = u32s.iter();
+            assert!(iter.any(|&x| x == 0));
+            assert_eq!(iter.next(), Some(&1));
+            assert!(!u32s.iter().any(|&x| x > 2));
+
+            let mut iter = u32s.iter();
+            assert!(!iter.all(|&x| x < 1));
+            assert_eq!(iter.next(), Some(&2));
+            assert!(u32s.iter().all(|&x| x < 3));
+
+            assert_eq!(u32s.iter().fold(0, |acc, &x| acc + x), 3);
+        })
+        .unwrap();
+}
@@ -238,3 +238,54 @@ fn enumerate_filter_map_with_id() {
     assert_eq!(vec, vec![(0, key0, 10), (6, key2, 12)]);
     assert_eq!(modified, vec![10, 12]);
 }
+
+#[test]
+fn tracking() {
+    let world = World::new();
+    world
+        .try_register_storage_tracked::<u32>(Tracking::Insertion)
+        .unwrap();
+
+    let (mut entities, mut u32s, mut usizes) = world
+        .try_borrow::<(EntitiesViewMut, ViewMut<u32>, ViewMut<usize>)>()
+        .unwrap();
+
+    let entity0 = entities.add_entity((&mut u32s, &mut usizes), (0, 0));
+    let entity1 = entities.add_entity((&mut u32s, &mut usizes), (1, 1));
+    assert_eq!(u32s.try_inserted().unwrap().len(), 2);
+    u32s.try_clear_inserted().unwrap();
+
+    (&mut u32s).iter().for_each(|x| *x += 1);
+    (&mut u32s, &usizes).iter().for_each(|(x, _)| *x += 1);
+    *(&mut u32s).get(entity0).unwrap() += 1;
+    entities.add_component(&mut u32s, 5, entity1);
+    assert_eq!(u32s.try_modified().unwrap().len(), 0);
+    assert_eq!(u32s[entity0], 3);
+    assert_eq!(u32s[entity1], 5);
+
+    u32s.try_remove(entity0).unwrap();
+    u32s.try_delete(entity1).unwrap();
+    assert!(u32s.try_removed().unwrap().is_empty());
+    assert!(u32s.try_deleted().unwrap().is_empty());
+
+    u32s.try_update_pack_with(Tracking::Modification).unwrap();
+    let entity2 = entities.add_entity(&mut u32s, 2);
+    u32s.try_clear_inserted().unwrap();
+    (&mut u32s).iter().for_each(|x| *x += 1);
+    assert_eq!(u32s.try_modified().unwrap().len(), 1);
+
+    u32s.try_update_pack_with(Tracking::All).unwrap();
+    u32s.try_delete(entity2).unwrap();
+    assert_eq!(u32s.try_deleted().unwrap(), &[(entity2, 3)]);
+
+    drop((entities, u32s, usizes));
+    world
+        .try_register_storage_tracked::<usize>(Tracking::All)
+        .unwrap();
+    assert!(world
+        .try_borrow::<View<usize>>()
+        .unwrap()
+        .try_deleted()
+        .unwrap()
+        .is_empty());
+}
@@ -59,6 +59,7 @@ fn run() {
 
 #[cfg(feature = "parallel")]
 #[cfg_attr(miri, ignore)]
+#[allow(deprecated)]
 #[test]
 fn thread_pool() {
     let world = World::new();
@@ -74,6 +75,40 @@ fn thread_pool() {
         .unwrap();
 }
 
+#[cfg(feature = "parallel")]
+#[cfg_attr(miri, ignore)]
+#[test]
+fn tasks() {
+    let world = World::new();
+
+    world.run(|mut entities: EntitiesViewMut, mut u32s: ViewMut<u32>| {
+        for i in 0..100 {
+            entities.add_entity(&mut u32s, i);
+        }
+    });
+
+    world
+        .try_run(|mut u32s: ViewMut<u32>, tasks: Tasks| {
+            tasks.scope(|scope| {
+                for slice in (&mut u32s).iter().into_chunk(8).ok().unwrap() {
+                    scope.spawn(move |_| {
+                        for x in slice {
+                            *x *= 2;
+                        }
+                    });
+                }
+            });
+
+            let (even, odd) = tasks.join(
+                || u32s.iter().filter(|&&x| x % 4 == 0).count(),
+                || u32s.iter().filter(|&&x| x % 4 != 0).count(),
+            );
+            assert_eq!((even, odd), (50, 50));
+            assert_eq!(u32s.iter().into_iter().sum::<u32>(), 9900);
+        })
+        .unwrap();
+}
+
 #[test]
 fn system() {
     fn system1((mut usizes, u32s): (ViewMut<usize>, View<u32>)) {
@@ -164,6 +199,7 @@ fn systems() {
 
 #[cfg(feature = "parallel")]
 #[cfg_attr(miri, ignore)]
+#[allow(deprecated)]
 #[test]
 fn simple_parallel_sum() {
     use rayon::prelude::*;
@@ -195,6 +231,122 @@ fn simple_parallel_sum() {
 
 #[cfg(feature = "parallel")]
 #[cfg_attr(miri, ignore)]
+#[allow(deprecated)]
+#[test]
+fn rayon_adapters() {
+    use iterators::ParIter2;
+    use rayon::prelude::*;
+
+    let world = World::new();
+
+    world
+        .try_run(
+            |(mut entities, mut usizes, mut u32s): (
+                EntitiesViewMut,
+                ViewMut<usize>,
+                ViewMut<u32>,
+            )| {
+                (&mut usizes, &mut u32s).try_tight_pack().unwrap();
+                entities.add_entity((&mut usizes, &mut u32s), (1usize, 2u32));
+                entities.add_entity((&mut usizes, &mut u32s), (3usize, 4u32));
+                entities.add_entity(&mut usizes, 5usize);
+            },
+        )
+        .unwrap();
+
+    world
+        .try_run(
+            |(usizes, u32s, thread_pool): (View<usize>, View<u32>, ThreadPoolView)| {
+                thread_pool.install(|| {
+                    assert_eq!(
+                        usizes.par_iter().map(|&x| x * 2).reduce(|| 0, |a, b| a + b),
+                        18
+                    );
+
+                    let mut vec = Vec::new();
+                    usizes.par_iter().cloned().collect_into_vec(&mut vec);
+                    assert_eq!(vec, vec![1, 3, 5]);
+
+                    if let ParIter2::Tight(iter) = (&usizes, &u32s).par_iter() {
+                        let mut vec = Vec::new();
+                        iter.map(|(&x, &y)| x + y as usize)
+                            .collect_into_vec(&mut vec);
+                        assert_eq!(vec, vec![3, 7]);
+                    } else {
+                        panic!("not packed");
+                    }
+
+                    assert_eq!((&usizes, &u32s).par_iter().map(|(&x, _)| x).max(), Some(3));
+                });
+            },
+        )
+        .unwrap();
+}
+
+#[cfg(feature = "parallel")]
+#[cfg_attr(miri, ignore)]
+#[test]
+fn parallel_search() {
+    let world = World::new();
+
+    world.run(
+        |mut entities: EntitiesViewMut, mut usizes: ViewMut<usize>, mut u32s: ViewMut<u32>| {
+            for i in 0..1000 {
+                entities.add_entity(&mut usizes, i);
+            }
+            entities.add_entity((&mut usizes, &mut u32s), (1000, 3));
+        },
+    );
+
+    world.run(|usizes: View<usize>, u32s: View<u32>| {
+        assert_eq!((&usizes).par_find(|&&x| x == 500), Some(&500));
+        assert_eq!((&usizes).par_find(|&&x| x > 2000), None);
+        assert_eq!((&usizes, &u32s).par_find(|_| true), Some((&1000, &3)));
+
+        assert!((&usizes).par_any(|&x| x % 999 == 998));
+        assert!(!(&usizes, &u32s).par_any(|(&x, _)| x < 1000));
+    });
+}
+
+#[cfg(feature = "parallel")]
+#[cfg_attr(miri, ignore)]
+#[test]
+fn min_batch_size() {
+    use rayon::prelude::*;
+
+    let world = World::new();
+
+    world.run(
+        |mut entities: EntitiesViewMut, mut usizes: ViewMut<usize>, mut u32s: ViewMut<u32>| {
+            for i in 0..1000 {
+                entities.add_entity((&mut usizes, &mut u32s), (i, i as u32));
+            }
+        },
+    );
+
+    world.run(|usizes: View<usize>, u32s: View<u32>| {
+        // fold keeps one accumulator per task
+        let batches = (&usizes)
+            .par_iter()
+            .with_min_batch_size(1000)
+            .fold(|| 0, |len, _| len + 1)
+            .collect::<Vec<usize>>();
+        assert_eq!(batches, vec![1000]);
+
+        let batches = (&usizes, &u32s)
+            .par_iter()
+            .with_min_batch_size(400)
+            .fold(|| 0, |len, _| len + 1)
+            .collect::<Vec<usize>>();
+        assert!(batches.len() <= 2);
+        assert!(batches.iter().all(|&len| len >= 400));
+        assert_eq!(batches.iter().sum::<usize>(), 1000);
+    });
+}
+
+#[cfg(feature = "parallel")]
+#[cfg_attr(miri, ignore)]
+#[allow(deprecated)]
 #[test]
 fn tight_parallel_iterator() {
     use iterators::ParIter2;
@@ -242,6 +394,7 @@ fn tight_parallel_iterator() {
 
 #[cfg(feature = "parallel")]
 #[cfg_attr(miri, ignore)]
+#[allow(deprecated)]
 #[test]
 fn parallel_iterator() {
     use rayon::prelude::*;
@@ -283,6 +436,7 @@ fn parallel_iterator() {
 
 #[cfg(feature = "parallel")]
 #[cfg_attr(miri, ignore)]
+#[allow(deprecated)]
 #[test]
 fn loose_parallel_iterator() {
     use iterators::ParIter2;
@@ -440,6 +594,42 @@ fn par_update_pack() {
         .unwrap();
 }
 
+#[cfg(feature = "parallel")]
+#[cfg_attr(miri, ignore)]
+#[test]
+fn par_slices() {
+    use rayon::prelude::*;
+
+    let world = World::new();
+
+    world.run(
+        |(mut entities, mut usizes): (EntitiesViewMut, ViewMut<usize>)| {
+            usizes.update_pack();
+            let entities = (0..5)
+                .map(|i| entities.add_entity(&mut usizes, i))
+                .collect::<Vec<_>>();
+
+            usizes.clear_inserted();
+
+            let chunks = usizes
+                .par_slices(2)
+                .map(|(ids, usizes)| {
+                    for (&id, usize) in ids.iter().zip(usizes.iter_mut()) {
+                        assert_eq!(id, entities[*usize]);
+                        *usize += 10;
+                    }
+
+                    usizes.len()
+                })
+                .collect::<Vec<_>>();
+
+            assert_eq!(chunks, vec![2, 2, 1]);
+            assert_eq!(usizes[entities[4]], 14);
+            assert_eq!(usizes.modified().len(), 5);
+        },
+    );
+}
+
 #[cfg(feature = "parallel")]
 #[cfg_attr(miri, ignore)]
 #[test]
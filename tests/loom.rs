@@ -0,0 +1,87 @@
+//! Model checks borrows across threads.
+//! `RUSTFLAGS="--cfg loom" cargo test --test loom --release --no-default-features --features std,panic`
+#![cfg(loom)]
+
+use loom::sync::atomic::{AtomicBool, Ordering};
+use loom::sync::Arc;
+use loom::thread;
+use shipyard::*;
+
+#[test]
+fn unique_borrow() {
+    loom::model(|| {
+        let world = Arc::new(World::new());
+        let entity = world.spawn((0u32,));
+        let borrowed = Arc::new(AtomicBool::new(false));
+
+        let handles: Vec<_> = (0..2)
+            .map(|_| {
+                let world = world.clone();
+                let borrowed = borrowed.clone();
+
+                thread::spawn(move || {
+                    if let Ok(mut u32s) = world.try_borrow::<ViewMut<u32>>() {
+                        assert!(!borrowed.swap(true, Ordering::SeqCst));
+                        u32s[entity] += 1;
+                        borrowed.store(false, Ordering::SeqCst);
+
+                        true
+                    } else {
+                        false
+                    }
+                })
+            })
+            .collect();
+
+        let successes = handles
+            .into_iter()
+            .map(|handle| handle.join().unwrap())
+            .filter(|&success| success)
+            .count();
+
+        assert!(successes >= 1);
+        assert_eq!(world.borrow::<View<u32>>()[entity], successes as u32);
+    });
+}
+
+#[test]
+fn shared_and_unique_borrow() {
+    loom::model(|| {
+        let world = Arc::new(World::new());
+        let entity = world.spawn((0u32,));
+
+        let writer = {
+            let world = world.clone();
+
+            thread::spawn(move || {
+                if let Ok(mut u32s) = world.try_borrow::<ViewMut<u32>>() {
+                    u32s[entity] = 1;
+                }
+            })
+        };
+
+        // a shared borrow never sees a write in progress
+        if let Ok(u32s) = world.try_borrow::<View<u32>>() {
+            let value = u32s[entity];
+            assert_eq!(u32s[entity], value);
+        }
+
+        writer.join().unwrap();
+    });
+}
+
+#[test]
+fn storage_creation() {
+    loom::model(|| {
+        let world = Arc::new(World::new());
+
+        let handle = {
+            let world = world.clone();
+
+            thread::spawn(move || world.try_borrow::<View<u32>>().is_ok())
+        };
+
+        assert!(world.try_borrow::<View<u64>>().is_ok());
+        assert!(handle.join().unwrap());
+    });
+}
@@ -0,0 +1,88 @@
+#![cfg(feature = "panic")]
+
+use shipyard::*;
+
+#[test]
+fn has() {
+    struct Frozen;
+
+    let world = World::new();
+    let frozen = world.spawn((0u32, Frozen));
+    let moving = world.spawn((1u32,));
+
+    world.run(|mask: MaskView| {
+        assert!(mask.has::<u32>(frozen));
+        assert!(mask.has::<Frozen>(frozen));
+        assert!(mask.has::<u32>(moving));
+        assert!(!mask.has::<Frozen>(moving));
+        // the storage doesn't exist and isn't created
+        assert!(!mask.has::<u64>(moving));
+    });
+
+    assert!(!world
+        .borrow::<AllStoragesViewMut>()
+        .storage_names()
+        .iter()
+        .any(|&(_, name)| name == "u64"));
+
+    world.run(|mut frozens: ViewMut<Frozen>| {
+        frozens.remove(frozen);
+    });
+    world.run(|mask: MaskView| assert!(!mask.has::<Frozen>(frozen)));
+
+    world.delete_entity(moving);
+    world.run(|mask: MaskView| assert!(!mask.has::<u32>(moving)));
+}
+
+#[test]
+fn exclusive_borrow() {
+    let world = World::new();
+    let entity = world.spawn((0u32,));
+
+    world.run(|mask: MaskView, mut u32s: ViewMut<u32>| {
+        assert!(mask.has::<u32>(entity));
+        u32s.remove(entity);
+        assert!(!mask.has::<u32>(entity));
+        assert!(!mask.has::<usize>(entity));
+    });
+
+    // only Entities is borrowed
+    let u32s = world.borrow::<ViewMut<u32>>();
+    assert!(world.try_borrow::<MaskView>().is_ok());
+    drop(u32s);
+
+    let _entities = world.borrow::<EntitiesViewMut>();
+    assert!(matches!(
+        world.try_borrow::<MaskView>().err(),
+        Some(error::GetStorage::Entities(_))
+    ));
+}
+
+#[test]
+fn many_entities() {
+    let world = World::new();
+    let entities = (0..1000u32)
+        .map(|i| {
+            if i % 3 == 0 {
+                world.spawn((i,))
+            } else {
+                world.spawn((i as usize,))
+            }
+        })
+        .collect::<Vec<_>>();
+
+    world.delete_entity(entities[0]);
+    // reuses the index of the deleted entity
+    let recycled = world.spawn((0u32,));
+    assert_eq!(recycled.index(), entities[0].index());
+
+    world.run(|mask: MaskView| {
+        for (i, &entity) in entities.iter().enumerate().skip(1) {
+            assert_eq!(mask.has::<u32>(entity), i % 3 == 0);
+            assert_eq!(mask.has::<usize>(entity), i % 3 != 0);
+        }
+
+        assert!(mask.has::<u32>(recycled));
+        assert!(!mask.has::<u32>(entities[0]));
+    });
+}
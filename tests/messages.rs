@@ -0,0 +1,103 @@
+#![cfg(feature = "panic")]
+
+use shipyard::*;
+
+#[derive(PartialEq, Eq, Debug)]
+struct Hit(u32);
+
+#[test]
+fn send_receive() {
+    let world = World::new();
+
+    world.run(|hits: MessageSender<Hit>| {
+        hits.send(Hit(0));
+        hits.send_all(vec![Hit(1), Hit(2)]);
+    });
+
+    world.run(|mut hits: MessageReceiver<Hit>| {
+        assert_eq!(hits.len(), 3);
+        assert_eq!(
+            hits.drain().collect::<Vec<_>>(),
+            vec![Hit(0), Hit(1), Hit(2)]
+        );
+        assert!(hits.is_empty());
+    });
+}
+
+#[test]
+fn workload_buffering() {
+    fn receive(mut hits: MessageReceiver<Hit>, mut received: UniqueViewMut<Vec<u32>>) {
+        received.extend(hits.drain().map(|Hit(hit)| hit));
+    }
+    fn send(hits: MessageSender<Hit>) {
+        hits.send(Hit(1));
+    }
+
+    let world = World::new();
+    world.add_unique(Vec::<u32>::new());
+
+    // the receiver runs first and gets the messages sent in the previous workload
+    world
+        .add_workload("Hits")
+        .with_system(system!(receive))
+        .with_system(system!(send))
+        .build();
+
+    world.run_default();
+    assert!(world.borrow::<UniqueView<Vec<u32>>>().is_empty());
+
+    world.run_default();
+    assert_eq!(*world.borrow::<UniqueView<Vec<u32>>>(), vec![1]);
+}
+
+#[test]
+fn unread_messages_dropped() {
+    let world = World::new();
+    world
+        .add_workload("Empty")
+        .with_system(system!(|| {}))
+        .build();
+
+    world.run(|hits: MessageSender<Hit>| hits.send(Hit(0)));
+    world.run_default();
+    assert_eq!(world.borrow::<MessageReceiver<Hit>>().len(), 1);
+
+    world.run(|hits: MessageSender<Hit>| hits.send(Hit(1)));
+    world.run_default();
+    world.run_default();
+    assert!(world.borrow::<MessageReceiver<Hit>>().is_empty());
+}
+
+#[cfg(feature = "parallel")]
+#[test]
+fn parallel_senders() {
+    use rayon::prelude::*;
+
+    fn send_even(hits: MessageSender<Hit>) {
+        (0..100u32)
+            .into_par_iter()
+            .filter(|i| i % 2 == 0)
+            .for_each(|i| hits.send(Hit(i)));
+    }
+    fn send_odd(hits: MessageSender<Hit>) {
+        (0..100u32)
+            .filter(|i| i % 2 == 1)
+            .for_each(|i| hits.send(Hit(i)));
+    }
+    fn receive(mut hits: MessageReceiver<Hit>) {
+        let mut hits = hits.drain().map(|Hit(hit)| hit).collect::<Vec<_>>();
+        hits.sort_unstable();
+        assert_eq!(hits, (0..100).collect::<Vec<_>>());
+    }
+
+    let world = World::new();
+
+    world
+        .add_workload("Parallel")
+        .with_system(system!(send_even))
+        .with_system(system!(send_odd))
+        .with_system(system!(receive))
+        .build();
+
+    world.run_default();
+}
@@ -0,0 +1,119 @@
+#![cfg(feature = "panic")]
+
+use shipyard::*;
+
+#[test]
+fn apply() {
+    let world = World::new();
+
+    let spawned = world.run(|mut all_storages: AllStoragesViewMut| {
+        Operation::apply_all(
+            vec![
+                // refers to no entity
+                Operation::Remove(0),
+                Operation::Spawn(0u32),
+                Operation::Spawn(1),
+                Operation::Spawn(2),
+                Operation::Remove(4),
+                Operation::Despawn(2),
+                // refers to a despawned entity
+                Operation::Add(5, 3),
+                Operation::Add(0, 4),
+            ],
+            &mut all_storages,
+        )
+    });
+
+    assert_eq!(spawned.len(), 3);
+
+    world.run(|entities: EntitiesView, u32s: View<u32>| {
+        assert!(entities.is_alive(spawned[0]));
+        assert!(entities.is_alive(spawned[1]));
+        assert!(!entities.is_alive(spawned[2]));
+        assert_eq!(u32s.get(spawned[0]), Ok(&4));
+        assert!(u32s.get(spawned[1]).is_err());
+        assert_eq!(u32s.len(), 1);
+    });
+}
+
+#[test]
+fn packed() {
+    let world = World::new();
+
+    world.run(|mut u32s: ViewMut<u32>, mut usizes: ViewMut<usize>| {
+        (&mut u32s, &mut usizes).tight_pack();
+    });
+
+    world.run(|mut all_storages: AllStoragesViewMut| {
+        assert_eq!(
+            Operation::try_apply_all(
+                vec![Operation::Spawn(0u32), Operation::Remove(0)],
+                &mut all_storages
+            ),
+            Err(error::ApplyOperation::Remove(
+                error::Remove::MissingPackStorage(core::any::type_name::<u32>())
+            ))
+        );
+    });
+}
+
+#[cfg(feature = "arbitrary1")]
+#[test]
+fn arbitrary() {
+    use arbitrary::{Arbitrary, Unstructured};
+
+    let bytes = (0..=255u8).cycle().take(4096).collect::<Vec<_>>();
+    let mut u = Unstructured::new(&bytes);
+
+    let entity = EntityId::arbitrary(&mut u).unwrap();
+    assert!(entity.gen() <= u16::MAX as u64);
+
+    let operations = Vec::<Operation<u32>>::arbitrary(&mut u).unwrap();
+
+    let world = World::new();
+    let spawned = world.run(|mut all_storages: AllStoragesViewMut| {
+        Operation::apply_all(operations.clone(), &mut all_storages)
+    });
+
+    let spawn_count = operations
+        .iter()
+        .filter(|operation| matches!(operation, Operation::Spawn(_)))
+        .count();
+    assert_eq!(spawned.len(), spawn_count);
+}
+
+#[cfg(feature = "proptest1")]
+mod proptest {
+    use proptest::prelude::*;
+    use shipyard::*;
+
+    proptest! {
+        #[test]
+        fn components_belong_to_alive_entities(operations in any::<Vec<Operation<u32>>>()) {
+            let world = World::new();
+
+            let spawned = world.run(|mut all_storages: AllStoragesViewMut| {
+                Operation::apply_all(operations, &mut all_storages)
+            });
+
+            world.run(|entities: EntitiesView, u32s: View<u32>| {
+                for &entity in &spawned {
+                    if !entities.is_alive(entity) {
+                        assert!(u32s.get(entity).is_err());
+                    }
+                }
+
+                let alive = spawned.iter().filter(|&&entity| entities.is_alive(entity)).count();
+                assert!(u32s.len() <= alive);
+            });
+        }
+
+        #[test]
+        fn entity_id_parts(entity in any::<EntityId>()) {
+            assert_eq!(
+                unsafe { EntityId::from_parts(entity.index(), entity.gen() as u16) },
+                entity
+            );
+        }
+    }
+}
@@ -74,3 +74,73 @@ fn pack() {
         ),
     }
 }
+
+#[test]
+fn tight_packed() {
+    let world = World::new();
+    let (mut entities, mut usizes, mut u32s, mut u64s) = world
+        .try_borrow::<(EntitiesViewMut, ViewMut<usize>, ViewMut<u32>, ViewMut<u64>)>()
+        .unwrap();
+
+    assert_eq!(usizes.try_tight_packed(), Err(error::NotTightPack));
+
+    (&mut usizes, &mut u32s).try_tight_pack().unwrap();
+
+    let entity0 = entities.add_entity(&mut usizes, 0);
+    let entity1 = entities.add_entity((&mut usizes, &mut u32s), (1, 1));
+    let entity2 = entities.add_entity((&mut usizes, &mut u32s, &mut u64s), (2, 2, 2));
+
+    assert_eq!(usizes.try_tight_packed(), Ok(&[1, 2][..]));
+    assert_eq!(u32s.try_tight_packed(), Ok(&[1, 2][..]));
+    assert_eq!(&usizes.ids()[..2], &[entity1, entity2]);
+    assert_eq!(&usizes.ids()[..2], u32s.ids());
+    assert_eq!(usizes.ids()[2], entity0);
+    assert_eq!(u64s.try_tight_packed(), Err(error::NotTightPack));
+    assert_eq!(u64s.ids(), &[entity2]);
+
+    let window = usizes.try_as_window(1..).unwrap();
+    assert_eq!(window.try_tight_packed(), Ok(&[2][..]));
+    assert_eq!(window.ids(), &[entity2, entity0]);
+
+    let window = usizes.try_as_window_mut(2..).unwrap();
+    assert_eq!(window.try_tight_packed(), Ok(&[][..]));
+    assert_eq!(window.ids(), &[entity0]);
+}
+
+#[test]
+fn world_tight_pack() {
+    let world = World::new();
+
+    let entity0 = world.try_spawn((0usize, 0u32)).unwrap();
+    let entity1 = world.try_spawn((1usize,)).unwrap();
+
+    world
+        .try_tight_pack::<(ViewMut<usize>, ViewMut<u32>)>()
+        .unwrap();
+    assert!(matches!(
+        world.try_tight_pack::<(ViewMut<usize>, ViewMut<u64>)>(),
+        Err(error::Pack::AlreadyTightPack(_))
+    ));
+
+    let entity2 = world.try_spawn((2usize, 2u32)).unwrap();
+
+    world
+        .try_run(|mut all_storages: AllStoragesViewMut| {
+            all_storages.delete(entity0);
+        })
+        .unwrap();
+
+    world
+        .try_run(|usizes: View<usize>, u32s: View<u32>| {
+            assert_eq!(usizes.try_tight_packed(), Ok(&[2][..]));
+            assert_eq!(u32s.try_tight_packed(), Ok(&[2][..]));
+            assert_eq!(usizes.ids(), &[entity2, entity1]);
+
+            if let iterators::Iter2::Tight(iter) = (&usizes, &u32s).iter() {
+                assert_eq!(iter.collect::<Vec<_>>(), vec![(&2, &2)]);
+            } else {
+                panic!("not packed");
+            }
+        })
+        .unwrap();
+}
@@ -0,0 +1,104 @@
+#![cfg(feature = "panic")]
+
+use shipyard::*;
+
+fn set_recycling(world: &World, recycling: Recycling) {
+    world.borrow::<EntitiesViewMut>().set_recycling(recycling);
+}
+
+#[test]
+fn immediate() {
+    let world = World::new();
+
+    assert_eq!(
+        world.borrow::<EntitiesView>().recycling(),
+        Recycling::Immediate
+    );
+
+    let entity = world.spawn((0u32,));
+    world.borrow::<AllStoragesViewMut>().delete(entity);
+
+    assert_eq!(world.spawn((1u32,)).index(), entity.index());
+}
+
+#[test]
+fn never() {
+    let world = World::new();
+    set_recycling(&world, Recycling::Never);
+
+    let entity0 = world.spawn((0u32,));
+    let entity1 = world.spawn((1u32,));
+    world.borrow::<AllStoragesViewMut>().delete(entity0);
+    world.maintain();
+
+    let entity2 = world.spawn((2u32,));
+    assert_eq!(entity2.index(), 2);
+    assert!(!world.borrow::<EntitiesView>().is_alive(entity0));
+    assert_eq!(
+        world.borrow::<EntitiesView>().iter().collect::<Vec<_>>(),
+        vec![entity1, entity2]
+    );
+
+    world.borrow::<AllStoragesViewMut>().clear();
+    world.maintain();
+
+    assert_eq!(world.borrow::<EntitiesView>().iter().count(), 0);
+    assert_eq!(world.spawn(()).index(), 3);
+}
+
+#[test]
+fn delayed() {
+    let world = World::new();
+    set_recycling(&world, Recycling::Delayed(2));
+
+    let entity0 = world.spawn((0u32,));
+    let entity1 = world.spawn((1u32,));
+    world.borrow::<AllStoragesViewMut>().delete(entity0);
+
+    assert_eq!(world.spawn(()).index(), 2);
+    world.maintain();
+    assert_eq!(world.spawn(()).index(), 3);
+
+    world.borrow::<AllStoragesViewMut>().delete(entity1);
+    world.maintain();
+
+    // entity0's index waited two frames, entity1's only one
+    let entity4 = world.spawn(());
+    assert_eq!(entity4.index(), entity0.index());
+    assert_ne!(entity4, entity0);
+    assert_eq!(world.spawn(()).index(), 4);
+
+    world.maintain();
+    assert_eq!(world.spawn(()).index(), entity1.index());
+}
+
+#[test]
+fn delayed_zero() {
+    let world = World::new();
+    set_recycling(&world, Recycling::Delayed(0));
+
+    let entity = world.spawn(());
+    world.borrow::<AllStoragesViewMut>().delete(entity);
+
+    assert_eq!(world.spawn(()).index(), entity.index());
+}
+
+#[test]
+fn switch_to_immediate() {
+    let world = World::new();
+    set_recycling(&world, Recycling::Delayed(1));
+
+    let entity0 = world.spawn(());
+    let entity1 = world.spawn(());
+    world.borrow::<AllStoragesViewMut>().delete(entity0);
+
+    set_recycling(&world, Recycling::Immediate);
+    // entity0 is reused by clear, it mustn't be recycled a second time
+    world.borrow::<AllStoragesViewMut>().clear();
+    world.maintain();
+
+    let mut indices = vec![world.spawn(()).index(), world.spawn(()).index()];
+    indices.sort_unstable();
+    assert_eq!(indices, vec![entity0.index(), entity1.index()]);
+    assert_eq!(world.spawn(()).index(), 2);
+}
@@ -0,0 +1,118 @@
+#![cfg(feature = "panic")]
+
+use core::any::Any;
+use shipyard::*;
+
+struct Position {
+    x: f32,
+    y: f32,
+}
+
+struct Transform {
+    position: Position,
+    name: String,
+}
+
+impl Reflect for Position {
+    fn field(&self, name: &str) -> Option<&dyn Reflect> {
+        match name {
+            "x" => Some(&self.x),
+            "y" => Some(&self.y),
+            _ => None,
+        }
+    }
+    fn field_mut(&mut self, name: &str) -> Option<&mut dyn Reflect> {
+        match name {
+            "x" => Some(&mut self.x),
+            "y" => Some(&mut self.y),
+            _ => None,
+        }
+    }
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+}
+
+impl Reflect for Transform {
+    fn field(&self, name: &str) -> Option<&dyn Reflect> {
+        match name {
+            "position" => Some(&self.position),
+            "name" => Some(&self.name),
+            _ => None,
+        }
+    }
+    fn field_mut(&mut self, name: &str) -> Option<&mut dyn Reflect> {
+        match name {
+            "position" => Some(&mut self.position),
+            "name" => Some(&mut self.name),
+            _ => None,
+        }
+    }
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+}
+
+fn transform() -> Transform {
+    Transform {
+        position: Position { x: 0.0, y: 1.0 },
+        name: String::from("player"),
+    }
+}
+
+#[test]
+fn set_field() {
+    let world = World::new();
+    world.run(|mut transforms: ViewMut<Transform>| transforms.setup_reflect());
+
+    let entity = world.spawn((transform(),));
+
+    world.set_field(entity, "Transform.position.x", 3.0f32);
+    world.set_field(entity, "reflect::Transform.name", String::from("enemy"));
+
+    assert_eq!(world.field::<f32>(entity, "Transform.position.x"), 3.0);
+    assert_eq!(world.field::<f32>(entity, "Transform.position.y"), 1.0);
+    assert_eq!(world.field::<String>(entity, "Transform.name"), "enemy");
+
+    world.run(|transforms: View<Transform>| {
+        assert_eq!(transforms[entity].position.x, 3.0);
+        assert_eq!(transforms[entity].name, "enemy");
+    });
+}
+
+#[test]
+fn errors() {
+    let world = World::new();
+
+    let entity = world.spawn((transform(),));
+    assert_eq!(
+        world.try_set_field(entity, "Transform.position.x", 3.0f32),
+        Err(error::Reflect::MissingStorage)
+    );
+
+    world.run(|mut transforms: ViewMut<Transform>| transforms.setup_reflect());
+    let empty = world.spawn(());
+
+    assert_eq!(
+        world.try_field::<f32>(empty, "Transform.position.x"),
+        Err(error::Reflect::MissingComponent(empty))
+    );
+    assert_eq!(
+        world.try_field::<f32>(entity, "Transform.rotation"),
+        Err(error::Reflect::MissingField)
+    );
+    assert_eq!(
+        world.try_set_field(entity, "Transform.position.x", 3u32),
+        Err(error::Reflect::WrongType("u32"))
+    );
+    assert_eq!(
+        world.try_field::<f32>(entity, "Transform.position.x"),
+        Ok(0.0)
+    );
+}
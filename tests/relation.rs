@@ -0,0 +1,92 @@
+#![cfg(feature = "panic")]
+
+use shipyard::*;
+
+#[derive(PartialEq, Eq, Debug)]
+struct ChildOf(u32);
+
+#[test]
+fn relation() {
+    let world = World::new();
+    let [parent, child1, child2] = [world.spawn(()), world.spawn(()), world.spawn(())];
+
+    let mut children = world.borrow::<RelationViewMut<ChildOf>>();
+    assert_eq!(children.insert(child1, parent, ChildOf(0)), None);
+    assert_eq!(children.insert(child2, parent, ChildOf(1)), None);
+    assert_eq!(
+        children.insert(child2, parent, ChildOf(2)),
+        Some(ChildOf(1))
+    );
+
+    assert_eq!(children.len(), 2);
+    assert_eq!(children.get(child2, parent), Some(&ChildOf(2)));
+    assert_eq!(children.get(parent, child2), None);
+    assert_eq!(
+        children.targets(child1).collect::<Vec<_>>(),
+        vec![(parent, &ChildOf(0))]
+    );
+
+    let mut sources = children.sources(parent).collect::<Vec<_>>();
+    sources.sort_by_key(|(_, child_of)| child_of.0);
+    assert_eq!(sources, vec![(child1, &ChildOf(0)), (child2, &ChildOf(2))]);
+
+    children.get_mut(child1, parent).unwrap().0 = 3;
+    assert_eq!(children.remove(child2, parent), Some(ChildOf(2)));
+    assert_eq!(children.remove(child2, parent), None);
+    assert_eq!(
+        children.iter().collect::<Vec<_>>(),
+        vec![(child1, parent, &ChildOf(3))]
+    );
+    assert_eq!(children.sources(parent).count(), 1);
+}
+
+#[test]
+fn delete_entity() {
+    let world = World::new();
+    let [parent, child1, child2] = [world.spawn(()), world.spawn(()), world.spawn(())];
+
+    world.run(|mut children: RelationViewMut<ChildOf>| {
+        children.insert(child1, parent, ChildOf(0));
+        children.insert(child2, parent, ChildOf(1));
+        children.insert(child2, child1, ChildOf(2));
+    });
+
+    world.run(|mut all_storages: AllStoragesViewMut| {
+        all_storages.delete(child1);
+    });
+
+    world.run(|children: RelationView<ChildOf>| {
+        assert_eq!(children.len(), 1);
+        assert!(children.contains(child2, parent));
+        assert_eq!(children.targets(child1).count(), 0);
+        assert_eq!(children.sources(child1).count(), 0);
+    });
+
+    world.run(|mut all_storages: AllStoragesViewMut| {
+        all_storages.delete(parent);
+    });
+
+    assert!(world.borrow::<RelationView<ChildOf>>().is_empty());
+}
+
+#[test]
+fn workload() {
+    fn link(mut entities: EntitiesViewMut, mut children: RelationViewMut<ChildOf>) {
+        let parent = entities.add_entity((), ());
+        let child = entities.add_entity((), ());
+        children.insert(child, parent, ChildOf(0));
+    }
+    fn count(children: RelationView<ChildOf>) {
+        assert_eq!(children.len(), 1);
+    }
+
+    let world = World::new();
+
+    world
+        .add_workload("Relation")
+        .with_system(system!(link))
+        .with_system(system!(count))
+        .build();
+
+    world.run_default();
+}
@@ -215,3 +215,66 @@ fn newer_key() {
         )
         .unwrap();
 }
+
+#[cfg(feature = "panic")]
+#[test]
+fn preserve_order() {
+    let world = World::new();
+    let (mut entities, mut usizes, mut u32s) = world
+        .try_borrow::<(EntitiesViewMut, ViewMut<usize>, ViewMut<u32>)>()
+        .unwrap();
+
+    usizes.preserve_order(true);
+    u32s.update_pack();
+    u32s.preserve_order(true);
+
+    let entities = (0..5)
+        .map(|i| entities.add_entity((&mut usizes, &mut u32s), (i as usize, i as u32)))
+        .collect::<Vec<_>>();
+    u32s.clear_inserted();
+
+    usizes.remove(entities[3]);
+    usizes.remove(entities[0]);
+    u32s.remove(entities[3]);
+    u32s.remove(entities[0]);
+
+    assert_eq!(usizes.as_slice(), &[1, 2, 4]);
+    assert_eq!(u32s.as_slice(), &[1, 2, 4]);
+    assert_eq!(usizes.get(entities[4]), Ok(&4));
+    assert_eq!(u32s.get(entities[2]), Ok(&2));
+    assert_eq!(u32s.removed(), &[entities[3], entities[0]]);
+}
+
+#[test]
+fn remove_many() {
+    let world = World::new();
+    let (mut entities, mut usizes, mut u32s) = world
+        .try_borrow::<(EntitiesViewMut, ViewMut<usize>, ViewMut<u32>)>()
+        .unwrap();
+
+    usizes.try_update_pack().unwrap();
+
+    let entity0 = entities.add_entity(&mut usizes, 0);
+    let entity1 = entities.add_entity(&mut usizes, 1);
+    let entity2 = entities.add_entity(&mut usizes, 2);
+    let entity3 = entities.add_entity((), ());
+
+    assert_eq!(
+        usizes.try_remove_many(&[entity2, entity3, entity0]),
+        Ok(vec![
+            Some(OldComponent::Owned(2)),
+            None,
+            Some(OldComponent::Owned(0))
+        ])
+    );
+    assert_eq!(usizes.len(), 1);
+    assert_eq!(usizes.get(entity1), Ok(&1));
+    assert_eq!(usizes.try_removed().unwrap(), &[entity2, entity0]);
+
+    let mut u64s = world.try_borrow::<ViewMut<u64>>().unwrap();
+    (&mut u32s, &mut u64s).try_tight_pack().unwrap();
+    assert_eq!(
+        u32s.try_remove_many(&[entity1]),
+        Err(error::Remove::MissingPackStorage(type_name::<u32>()))
+    );
+}
@@ -0,0 +1,88 @@
+#![cfg(all(feature = "std", feature = "panic"))]
+
+use shipyard::*;
+
+struct Rolls1(Vec<u64>);
+struct Rolls2(Vec<u64>);
+
+fn roll1(mut rng: Rng, mut rolls: UniqueViewMut<Rolls1>) {
+    rolls.0.push(rng.next_u64());
+}
+
+fn roll2(mut rng: Rng, mut rolls: UniqueViewMut<Rolls2>) {
+    rolls.0.push(rng.gen_range(10..20));
+}
+
+fn rolls(seed: u64) -> (Vec<u64>, Vec<u64>) {
+    let world = World::new();
+    world.set_seed(seed);
+    world.add_unique(Rolls1(Vec::new()));
+    world.add_unique(Rolls2(Vec::new()));
+
+    world
+        .add_workload("Roll")
+        .with_system(system!(roll1))
+        .with_system(system!(roll2))
+        .build();
+
+    for _ in 0..10 {
+        world.run_default();
+    }
+
+    let (rolls1, rolls2) = world.borrow::<(UniqueView<Rolls1>, UniqueView<Rolls2>)>();
+    (rolls1.0.clone(), rolls2.0.clone())
+}
+
+#[test]
+fn deterministic() {
+    let (rolls1, rolls2) = rolls(42);
+
+    assert_eq!((rolls1.clone(), rolls2.clone()), rolls(42));
+    assert_ne!(rolls1, rolls(7).0);
+    assert!(rolls2.iter().all(|roll| (10..20).contains(roll)));
+
+    // the sequence continues from one run to the next
+    rolls1
+        .windows(2)
+        .for_each(|pair| assert_ne!(pair[0], pair[1]));
+}
+
+#[test]
+fn set_seed() {
+    let world = World::new();
+    world.add_unique(Rolls1(Vec::new()));
+    world
+        .add_workload("Roll")
+        .with_system(system!(roll1))
+        .build();
+
+    world.run_default();
+    world.set_seed(0);
+    world.run_default();
+
+    let rolls = world.borrow::<UniqueView<Rolls1>>();
+    assert_eq!(rolls.0[0], rolls.0[1]);
+}
+
+#[test]
+fn outside_workload() {
+    let world = World::new();
+
+    assert_eq!(
+        world.try_borrow::<Rng>().err(),
+        Some(error::GetStorage::MissingRng)
+    );
+}
+
+#[test]
+fn borrowed_twice() {
+    fn twice(_: Rng, _: Rng) {}
+
+    let world = World::new();
+    world
+        .add_workload("Twice")
+        .with_system(system!(twice))
+        .build();
+
+    assert!(world.try_run_default().is_err());
+}
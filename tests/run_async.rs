@@ -0,0 +1,132 @@
+#![cfg(feature = "async")]
+
+use core::future::Future;
+use core::pin::Pin;
+use core::task::{Context, Poll, RawWaker, RawWakerVTable, Waker};
+use shipyard::*;
+
+fn noop_waker() -> Waker {
+    fn clone(_: *const ()) -> RawWaker {
+        RawWaker::new(core::ptr::null(), &VTABLE)
+    }
+    fn noop(_: *const ()) {}
+
+    static VTABLE: RawWakerVTable = RawWakerVTable::new(clone, noop, noop, noop);
+
+    unsafe { Waker::from_raw(clone(core::ptr::null())) }
+}
+
+fn block_on<F: Future>(mut future: F) -> F::Output {
+    let waker = noop_waker();
+    let mut context = Context::from_waker(&waker);
+    // SAFE future is shadowed and never moved again
+    let mut future = unsafe { Pin::new_unchecked(&mut future) };
+
+    loop {
+        if let Poll::Ready(output) = future.as_mut().poll(&mut context) {
+            return output;
+        }
+    }
+}
+
+fn double(mut u32s: ViewMut<u32>) {
+    (&mut u32s).iter().for_each(|x| *x *= 2);
+}
+
+fn add_one(mut u32s: ViewMut<u32>) {
+    (&mut u32s).iter().for_each(|x| *x += 1);
+}
+
+#[test]
+fn yields_between_batches() {
+    let world = World::new();
+    world.spawn((1u32,));
+
+    world
+        .try_add_workload("Async")
+        .unwrap()
+        .try_with_system(system!(double))
+        .unwrap()
+        .try_with_system(system!(add_one))
+        .unwrap()
+        .build();
+
+    let waker = noop_waker();
+    let mut context = Context::from_waker(&waker);
+    let mut workload = world.try_run_workload_async("Async");
+
+    assert!(Pin::new(&mut workload).poll(&mut context).is_pending());
+    world
+        .try_run(|u32s: View<u32>| assert_eq!(u32s.iter().next(), Some(&2)))
+        .unwrap();
+
+    assert!(matches!(
+        Pin::new(&mut workload).poll(&mut context),
+        Poll::Ready(Ok(()))
+    ));
+    world
+        .try_run(|u32s: View<u32>| assert_eq!(u32s.iter().next(), Some(&3)))
+        .unwrap();
+
+    block_on(world.try_run_workload_async("Async")).unwrap();
+    world
+        .try_run(|u32s: View<u32>| assert_eq!(u32s.iter().next(), Some(&7)))
+        .unwrap();
+}
+
+#[test]
+fn dropped_workload_is_finished() {
+    fn scratch(frame_alloc: UniqueView<FrameAlloc>) {
+        frame_alloc.alloc(0u64);
+    }
+    fn reset(mut frame_alloc: UniqueViewMut<FrameAlloc>) {
+        frame_alloc.reset();
+    }
+
+    let world = World::new();
+    world.add_unique(FrameAlloc::new());
+
+    world
+        .try_add_workload("Async")
+        .unwrap()
+        .try_with_system(system!(scratch))
+        .unwrap()
+        .try_with_system(system!(reset))
+        .unwrap()
+        .build();
+
+    let waker = noop_waker();
+    let mut context = Context::from_waker(&waker);
+    let mut workload = world.try_run_workload_async("Async");
+
+    assert!(Pin::new(&mut workload).poll(&mut context).is_pending());
+    world
+        .try_run(|frame_alloc: UniqueView<FrameAlloc>| assert!(frame_alloc.allocated_bytes() > 0))
+        .unwrap();
+
+    drop(workload);
+    world
+        .try_run(|frame_alloc: UniqueView<FrameAlloc>| assert_eq!(frame_alloc.allocated_bytes(), 0))
+        .unwrap();
+
+    block_on(world.try_run_workload_async("Async")).unwrap();
+}
+
+#[test]
+fn missing_workload() {
+    let world = World::new();
+
+    assert!(matches!(
+        block_on(world.try_run_workload_async("Missing")),
+        Err(error::RunWorkload::MissingWorkload)
+    ));
+}
+
+#[cfg(feature = "panic")]
+#[test]
+#[should_panic(expected = "Workload Missing failed")]
+fn missing_workload_panic() {
+    let world = World::new();
+
+    block_on(world.run_workload_async("Missing"));
+}
@@ -1,4 +1,7 @@
 mod entity_id;
+mod recording;
+mod savegame;
+mod scene;
 
 use shipyard::*;
 
@@ -50,3 +53,566 @@ fn test() {
         },
     );
 }
+
+/// Replaces each struct by a sequence of its fields, the layout used by binary formats.
+fn to_compact(value: serde_json::Value) -> serde_json::Value {
+    use serde_json::Value;
+
+    const FIELDS: &[&[&str]] = &[
+        &["metadata", "storages"],
+        &["ids", "data"],
+        &["data", "list"],
+    ];
+
+    match value {
+        Value::Object(mut map) => match FIELDS
+            .iter()
+            .find(|fields| map.len() == fields.len() && fields.iter().all(|f| map.contains_key(*f)))
+        {
+            Some(fields) => Value::Array(
+                fields
+                    .iter()
+                    .map(|field| to_compact(map.remove(*field).unwrap()))
+                    .collect(),
+            ),
+            None => Value::Object(map),
+        },
+        Value::Array(array) => Value::Array(array.into_iter().map(to_compact).collect()),
+        value => value,
+    }
+}
+
+#[test]
+fn compact_layout() {
+    let world = World::new();
+
+    let entity = world.run(
+        |mut entities: EntitiesViewMut, mut u32s: ViewMut<u32>, mut strings: ViewMut<String>| {
+            strings.setup_serde(SerConfig::default());
+            u32s.setup_serde(SerConfig::default());
+
+            entities.add_entity(&mut strings, "Test1212".to_string());
+            entities.add_entity((&mut u32s, &mut strings), (545, "Test741".to_string()))
+        },
+    );
+
+    let mut output = Vec::new();
+    world
+        .serialize(
+            GlobalSerConfig::default(),
+            &mut serde_json::Serializer::new(&mut output),
+        )
+        .unwrap();
+
+    // human-readable formats get named fields
+    assert!(output.starts_with(br#"{"metadata":"#));
+
+    let compact = to_compact(serde_json::from_slice(&output).unwrap());
+    let world_copy = World::new_deserialized(GlobalDeConfig::default(), compact).unwrap();
+
+    world_copy.run(|strings: View<String>, u32s: View<u32>| {
+        assert_eq!(strings.get(entity).map(AsRef::as_ref), Ok("Test741"));
+        assert_eq!(u32s.get(entity), Ok(&545));
+        assert_eq!(u32s.len(), 1);
+    });
+}
+
+#[test]
+fn transient() {
+    let world = World::new();
+
+    let entity = world.run(
+        |mut entities: EntitiesViewMut, mut u32s: ViewMut<u32>, mut strings: ViewMut<String>| {
+            strings.setup_serde(SerConfig::default());
+            u32s.setup_serde(SerConfig {
+                transient: true,
+                ..Default::default()
+            });
+
+            entities.add_entity((&mut u32s, &mut strings), (545, "Test741".to_string()))
+        },
+    );
+
+    let mut output = Vec::new();
+    world
+        .serialize(
+            GlobalSerConfig::default(),
+            &mut serde_json::Serializer::new(&mut output),
+        )
+        .unwrap();
+
+    let world_copy = World::new_deserialized(
+        GlobalDeConfig::default(),
+        &mut serde_json::Deserializer::from_slice(&output),
+    )
+    .unwrap();
+
+    world_copy.run(|strings: View<String>, u32s: View<u32>| {
+        assert_eq!(strings.get(entity).map(AsRef::as_ref), Ok("Test741"));
+        assert!(u32s.is_empty());
+    });
+}
+
+#[test]
+fn serialize_chunks() {
+    #[derive(serde_derive::Deserialize)]
+    struct Chunk {
+        ids: Vec<EntityId>,
+        data: Vec<u32>,
+    }
+
+    let world = World::new();
+
+    let entities = (0..5u32).map(|i| world.spawn((i,))).collect::<Vec<_>>();
+
+    world.run(|u32s: View<u32>| {
+        let chunks = u32s
+            .serialize_chunks(2)
+            .map(|chunk| serde_json::to_string(&chunk).unwrap())
+            .collect::<Vec<_>>();
+
+        assert_eq!(chunks.len(), 3);
+
+        let chunks = chunks
+            .iter()
+            .map(|chunk| serde_json::from_str::<Chunk>(chunk).unwrap())
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            chunks
+                .iter()
+                .map(|chunk| chunk.data.len())
+                .collect::<Vec<_>>(),
+            vec![2, 2, 1]
+        );
+        for chunk in &chunks {
+            for (&id, data) in chunk.ids.iter().zip(&chunk.data) {
+                assert_eq!(u32s.get(id), Ok(data));
+            }
+        }
+        assert_eq!(
+            chunks
+                .iter()
+                .flat_map(|chunk| chunk.ids.iter().copied())
+                .collect::<Vec<_>>(),
+            entities
+        );
+    });
+}
+
+#[test]
+fn deserialize_into() {
+    let world = World::new();
+
+    world.run(
+        |mut entities: EntitiesViewMut, mut u32s: ViewMut<u32>, mut strings: ViewMut<String>| {
+            strings.setup_serde(SerConfig::default());
+            u32s.setup_serde(SerConfig::default());
+
+            entities.add_entity(&mut strings, "Test1212".to_string());
+            entities.add_entity((&mut u32s, &mut strings), (545, "Test741".to_string()));
+        },
+    );
+
+    let mut output = Vec::new();
+    world
+        .serialize(
+            GlobalSerConfig::default(),
+            &mut serde_json::Serializer::new(&mut output),
+        )
+        .unwrap();
+
+    let other_world = World::new();
+    let existing = other_world.run(|mut entities: EntitiesViewMut, mut u32s: ViewMut<u32>| {
+        u32s.setup_serde(SerConfig::default());
+
+        entities.add_entity(&mut u32s, 1)
+    });
+
+    let new_entities = other_world.deserialize_into(
+        GlobalDeConfig::default(),
+        &mut serde_json::Deserializer::from_slice(&output),
+    );
+
+    assert_eq!(new_entities.len(), 2);
+    let new_entities: Vec<EntityId> = new_entities.into_iter().map(|(_, new)| new).collect();
+    assert!(!new_entities.contains(&existing));
+
+    other_world.run(
+        |entities: EntitiesView, u32s: View<u32>, strings: View<String>| {
+            assert!(entities.is_alive(existing));
+            assert_eq!(u32s.get(existing), Ok(&1));
+            assert!(strings.get(existing).is_err());
+
+            assert_eq!(
+                strings.get(new_entities[0]).map(AsRef::as_ref),
+                Ok("Test1212")
+            );
+            assert!(u32s.get(new_entities[0]).is_err());
+            assert_eq!(
+                strings.get(new_entities[1]).map(AsRef::as_ref),
+                Ok("Test741")
+            );
+            assert_eq!(u32s.get(new_entities[1]), Ok(&545));
+
+            assert_eq!(u32s.len(), 2);
+            assert_eq!(strings.len(), 2);
+        },
+    );
+}
+
+#[test]
+fn new_deserialized_entity_ids() {
+    use std::sync::{Arc, Mutex};
+
+    #[derive(serde_derive::Serialize, serde_derive::Deserialize)]
+    struct Target(EntityId);
+
+    impl MapEntityIds for Target {
+        fn map_entity_ids(&mut self, map: &dyn Fn(EntityId) -> EntityId) {
+            self.0 = map(self.0);
+        }
+    }
+
+    let world = World::new();
+
+    let (deleted, turret, player) = world.run(
+        |mut entities: EntitiesViewMut, mut targets: ViewMut<Target>, mut u32s: ViewMut<u32>| {
+            targets.setup_serde_with_entity_ids(SerConfig::default());
+            u32s.setup_serde(SerConfig::default());
+
+            let deleted = entities.add_entity(&mut u32s, 0);
+            let player = entities.add_entity(&mut u32s, 1);
+            let turret = entities.add_entity(&mut targets, Target(player));
+
+            (deleted, turret, player)
+        },
+    );
+    world.delete_entity(deleted);
+
+    let mut output = Vec::new();
+    world
+        .serialize(
+            GlobalSerConfig::default(),
+            &mut serde_json::Serializer::new(&mut output),
+        )
+        .unwrap();
+
+    let mapping = Arc::new(Mutex::new(Vec::new()));
+    let reported = mapping.clone();
+
+    let world_copy = World::new_deserialized(
+        GlobalDeConfig {
+            entity_mapping: Some(Arc::new(move |old, new| {
+                reported.lock().unwrap().push((old, new))
+            })),
+            ..Default::default()
+        },
+        &mut serde_json::Deserializer::from_slice(&output),
+    )
+    .unwrap();
+
+    let mut mapping = mapping.lock().unwrap();
+    mapping.sort_by_key(|&(old, _)| old.index());
+
+    // entities keep their id, the mapping is the identity
+    assert_eq!(*mapping, vec![(player, player), (turret, turret)]);
+
+    world_copy.run(|targets: View<Target>, u32s: View<u32>| {
+        assert_eq!(u32s[player], 1);
+        assert_eq!(targets[turret].0, player);
+    });
+}
+
+#[test]
+fn new_deserialized_generation() {
+    let world = World::new();
+
+    let deleted = world.spawn(());
+    world.delete_entity(deleted);
+    let entity = world.run(|mut entities: EntitiesViewMut, mut u32s: ViewMut<u32>| {
+        u32s.setup_serde(SerConfig::default());
+
+        entities.add_entity(&mut u32s, 7)
+    });
+
+    // the index was recycled
+    assert_eq!(entity.index(), 0);
+    assert_eq!(entity.gen(), 1);
+
+    let mut output = Vec::new();
+    world
+        .serialize(
+            GlobalSerConfig::default(),
+            &mut serde_json::Serializer::new(&mut output),
+        )
+        .unwrap();
+
+    let world_copy = World::new_deserialized(
+        GlobalDeConfig::default(),
+        &mut serde_json::Deserializer::from_slice(&output),
+    )
+    .unwrap();
+
+    world_copy.run(|entities: EntitiesView, u32s: View<u32>| {
+        assert!(entities.is_alive(entity));
+        assert!(!entities.is_alive(deleted));
+        assert_eq!(u32s.get(entity), Ok(&7));
+    });
+}
+
+#[derive(serde_derive::Serialize, serde_derive::Deserialize, PartialEq, Debug)]
+struct Health {
+    current: u32,
+    max: u32,
+}
+
+impl Migrate for Health {
+    const VERSION: u32 = 1;
+
+    fn migrate<'de, D: serde::Deserializer<'de>>(
+        version: u32,
+        deserializer: D,
+    ) -> Result<Self, D::Error> {
+        match version {
+            0 => {
+                let current: u32 = serde::Deserialize::deserialize(deserializer)?;
+
+                Ok(Health {
+                    current,
+                    max: current,
+                })
+            }
+            _ => Err(serde::de::Error::custom("unknown Health version")),
+        }
+    }
+}
+
+#[test]
+fn migration() {
+    let world = World::new();
+
+    let entity = world.run(
+        |mut entities: EntitiesViewMut, mut healths: ViewMut<Health>| {
+            healths.setup_serde_with_migration(SerConfig::default());
+
+            entities.add_entity(
+                &mut healths,
+                Health {
+                    current: 10,
+                    max: 20,
+                },
+            )
+        },
+    );
+
+    let mut output = Vec::new();
+    world
+        .serialize(
+            GlobalSerConfig::default(),
+            &mut serde_json::Serializer::new(&mut output),
+        )
+        .unwrap();
+
+    let mut value: serde_json::Value = serde_json::from_slice(&output).unwrap();
+    let index = value["metadata"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .position(|metadata| metadata[2] == 1)
+        .unwrap();
+
+    // rewrite the save as if it was made with the first layout
+    value["metadata"][index][2] = 0.into();
+    value["storages"][index]["data"] = serde_json::json!([10]);
+
+    let world_copy = World::new_deserialized(GlobalDeConfig::default(), &value).unwrap();
+
+    world_copy.run(|healths: View<Health>| {
+        assert_eq!(
+            healths.get(entity),
+            Ok(&Health {
+                current: 10,
+                max: 10
+            })
+        );
+    });
+
+    let world_copy = World::new_deserialized(
+        GlobalDeConfig::default(),
+        &mut serde_json::Deserializer::from_slice(&output),
+    )
+    .unwrap();
+
+    world_copy.run(|healths: View<Health>| {
+        assert_eq!(
+            healths.get(entity),
+            Ok(&Health {
+                current: 10,
+                max: 20
+            })
+        );
+    });
+}
+
+#[test]
+fn with_shared() {
+    let world = World::new();
+
+    let (owned, shared) = world.run(|mut entities: EntitiesViewMut, mut u32s: ViewMut<u32>| {
+        u32s.setup_serde(SerConfig::default());
+
+        let owned = entities.add_entity(&mut u32s, 1);
+        let shared = entities.add_entity((), ());
+        u32s.try_share(owned, shared).unwrap();
+
+        (owned, shared)
+    });
+
+    for &with_shared in &[WithShared::All, WithShared::None, WithShared::PerStorage] {
+        let mut output = Vec::new();
+        world
+            .serialize(
+                GlobalSerConfig {
+                    with_shared,
+                    ..Default::default()
+                },
+                &mut serde_json::Serializer::new(&mut output),
+            )
+            .unwrap();
+
+        let world_copy = World::new_deserialized(
+            GlobalDeConfig::default(),
+            &mut serde_json::Deserializer::from_slice(&output),
+        )
+        .unwrap();
+
+        world_copy.run(|u32s: View<u32>| {
+            assert_eq!(u32s.get(owned), Ok(&1));
+
+            // the storage was set up without with_shared
+            if with_shared == WithShared::All {
+                assert_eq!(u32s.get(shared), Ok(&1));
+            } else {
+                assert!(u32s.get(shared).is_err());
+            }
+        });
+    }
+
+    let mut output = Vec::new();
+    world
+        .serialize(
+            GlobalSerConfig {
+                with_shared: WithShared::All,
+                ..Default::default()
+            },
+            &mut serde_json::Serializer::new(&mut output),
+        )
+        .unwrap();
+
+    let world_copy = World::new_deserialized(
+        GlobalDeConfig {
+            with_shared: WithShared::None,
+            ..Default::default()
+        },
+        &mut serde_json::Deserializer::from_slice(&output),
+    )
+    .unwrap();
+
+    world_copy.run(|u32s: View<u32>| {
+        assert!(u32s.get(shared).is_err());
+    });
+}
+
+#[test]
+fn unique() {
+    let world = World::new();
+
+    world.add_unique_serializable(7u32);
+    world.add_unique(String::from("not serialized"));
+
+    let mut output = Vec::new();
+    world
+        .serialize(
+            GlobalSerConfig::default(),
+            &mut serde_json::Serializer::new(&mut output),
+        )
+        .unwrap();
+
+    let world_copy = World::new_deserialized(
+        GlobalDeConfig::default(),
+        &mut serde_json::Deserializer::from_slice(&output),
+    )
+    .unwrap();
+
+    assert_eq!(*world_copy.borrow::<UniqueView<u32>>(), 7);
+    assert!(world_copy.try_borrow::<UniqueView<String>>().is_err());
+
+    // the deserialized unique can be serialized again
+    let mut second_output = Vec::new();
+    world_copy
+        .serialize(
+            GlobalSerConfig::default(),
+            &mut serde_json::Serializer::new(&mut second_output),
+        )
+        .unwrap();
+
+    assert_eq!(output, second_output);
+}
+
+#[cfg(all(feature = "non_send", feature = "parallel"))]
+#[test]
+fn thread_local() {
+    let world = World::new();
+
+    let entity = world.run(
+        |mut entities: EntitiesViewMut, mut u64s: NonSend<ViewMut<u64>>| {
+            u64s.setup_serde(SerConfig::default());
+
+            entities.add_entity(&mut *u64s, 5)
+        },
+    );
+
+    for &thread_local in &[ThreadLocalStorages::Skip, ThreadLocalStorages::OwningThread] {
+        let mut output = Vec::new();
+        world
+            .serialize(
+                GlobalSerConfig {
+                    thread_local,
+                    ..Default::default()
+                },
+                &mut serde_json::Serializer::new(&mut output),
+            )
+            .unwrap();
+
+        let world_copy = World::new_deserialized(
+            GlobalDeConfig::default(),
+            &mut serde_json::Deserializer::from_slice(&output),
+        )
+        .unwrap();
+
+        if thread_local == ThreadLocalStorages::Skip {
+            assert_eq!(world_copy.borrow::<View<u64>>().len(), 0);
+        } else {
+            assert_eq!(world_copy.borrow::<View<u64>>().get(entity), Ok(&5));
+        }
+    }
+
+    rayon::scope(|scope| {
+        scope.spawn(|_| {
+            let mut output = Vec::new();
+            let result = world.serialize(
+                GlobalSerConfig {
+                    thread_local: ThreadLocalStorages::OwningThread,
+                    ..Default::default()
+                },
+                &mut serde_json::Serializer::new(&mut output),
+            );
+
+            assert!(result
+                .unwrap_err()
+                .to_string()
+                .contains(core::any::type_name::<u64>()));
+        });
+    });
+}
@@ -0,0 +1,186 @@
+use serde_derive::{Deserialize, Serialize};
+use shipyard::*;
+
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+struct Position {
+    x: f32,
+    y: f32,
+}
+
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+struct Name(String);
+
+fn setup(world: &World) {
+    world.run(
+        |mut positions: ViewMut<Position>, mut names: ViewMut<Name>| {
+            positions.setup_recording(SerConfig {
+                identifier: Some(Identifier::new("Position")),
+                ..Default::default()
+            });
+            names.setup_recording(SerConfig {
+                identifier: Some(Identifier::new("Name")),
+                ..Default::default()
+            });
+        },
+    );
+}
+
+#[test]
+fn replay() {
+    let world = World::new();
+    setup(&world);
+
+    world.start_recording();
+
+    let player = world.spawn((Position { x: 1.0, y: 2.0 }, Name("player".to_string())));
+    let rock = world.spawn((Position { x: 5.0, y: 0.0 },));
+    let bird = world.spawn((Name("bird".to_string()),));
+    // not recorded
+    world.spawn((0u32,));
+
+    world.run(
+        |mut positions: ViewMut<Position>, mut names: ViewMut<Name>| {
+            positions[player].x = 3.0;
+            names.remove(player);
+            positions.remove(rock);
+        },
+    );
+    world.borrow::<AllStoragesViewMut>().delete(bird);
+
+    let recording = world.stop_recording();
+    // not recorded
+    world.spawn((Position { x: 0.0, y: 0.0 },));
+
+    let recording = serde_json::to_string(&recording).unwrap();
+
+    let replay = World::new();
+    setup(&replay);
+
+    let mapping = replay
+        .replay(
+            GlobalDeConfig::default(),
+            &mut serde_json::Deserializer::from_str(&recording),
+        )
+        .unwrap();
+    // recorded entities are spawned in the same order in an empty world
+    assert!(mapping.iter().all(|(old, new)| old == new));
+
+    replay.run(
+        |entities: EntitiesView, positions: View<Position>, names: View<Name>, u32s: View<u32>| {
+            // modifications aren't structural operations
+            assert_eq!(positions[player], Position { x: 1.0, y: 2.0 });
+            assert!(names.get(player).is_err());
+            assert!(positions.get(rock).is_err());
+            assert!(!entities.is_alive(bird));
+            assert_eq!(u32s.len(), 0);
+            assert_eq!(positions.len(), 1);
+        },
+    );
+}
+
+#[test]
+fn not_recording() {
+    let world = World::new();
+    setup(&world);
+
+    world.spawn((Position { x: 1.0, y: 2.0 },));
+
+    world.start_recording();
+    let recording = world.stop_recording();
+
+    assert!(recording.is_empty());
+    assert_eq!(serde_json::to_string(&recording).unwrap(), "[]");
+}
+
+#[test]
+fn unknown_identifier() {
+    let world = World::new();
+
+    let result = world.replay(
+        GlobalDeConfig::default(),
+        &mut serde_json::Deserializer::from_str(r#"[["spawn",[0,0]],["add",[0,0],"Name","a"]]"#),
+    );
+
+    assert!(result.is_err());
+}
+
+#[test]
+fn entity_not_spawned_by_the_recording() {
+    let world = World::new();
+    setup(&world);
+
+    let existing = world.spawn(());
+
+    world.start_recording();
+    world.spawn((Name("spawned".to_string()),));
+    world.run(|entities: EntitiesView, mut names: ViewMut<Name>| {
+        entities.add_component(&mut names, Name("existing".to_string()), existing);
+    });
+    let recording = serde_json::to_string(&world.stop_recording()).unwrap();
+
+    let replay = World::new();
+    setup(&replay);
+
+    let result = replay.replay(
+        GlobalDeConfig::default(),
+        &mut serde_json::Deserializer::from_str(&recording),
+    );
+
+    assert!(result.is_err());
+}
+
+#[test]
+fn despawned_entity() {
+    let replay = World::new();
+    setup(&replay);
+
+    let result = replay.replay(
+        GlobalDeConfig::default(),
+        &mut serde_json::Deserializer::from_str(
+            r#"[["spawn",[0,0]],["despawn",[0,0]],["add",[0,0],"Name","a"]]"#,
+        ),
+    );
+
+    assert!(result.is_err());
+}
+
+#[test]
+fn only_replayed_entities_are_mapped() {
+    #[derive(Serialize, Deserialize)]
+    struct Target(EntityId);
+
+    impl MapEntityIds for Target {
+        fn map_entity_ids(&mut self, map: &dyn Fn(EntityId) -> EntityId) {
+            self.0 = map(self.0);
+        }
+    }
+
+    let world = World::new();
+    setup(&world);
+
+    world.start_recording();
+    let spawned = world.spawn((Name("spawned".to_string()),));
+    let recording = serde_json::to_string(&world.stop_recording()).unwrap();
+
+    let replay = World::new();
+    setup(&replay);
+    replay.run(|mut targets: ViewMut<Target>| {
+        targets.setup_serde_with_entity_ids(SerConfig::default())
+    });
+    // has the id the recording spawned
+    let existing = replay.spawn(());
+    replay.run(|entities: EntitiesView, mut targets: ViewMut<Target>| {
+        entities.add_component(&mut targets, Target(existing), existing);
+    });
+    assert_eq!(existing, spawned);
+
+    let mapping = replay
+        .replay(
+            GlobalDeConfig::default(),
+            &mut serde_json::Deserializer::from_str(&recording),
+        )
+        .unwrap();
+    assert_ne!(mapping[0].1, spawned);
+
+    replay.run(|targets: View<Target>| assert_eq!(targets[existing].0, existing));
+}
@@ -0,0 +1,60 @@
+#![cfg(feature = "savegame")]
+
+use shipyard::*;
+
+fn save_and_load(format: SerFormat, file_name: &str) {
+    let world = World::new();
+
+    let [entity1, entity2] = world.run(
+        |mut entities: EntitiesViewMut, mut u32s: ViewMut<u32>, mut strings: ViewMut<String>| {
+            strings.setup_serde(SerConfig::default());
+            u32s.setup_serde(SerConfig::default());
+
+            [
+                entities.add_entity(&mut strings, "Test1212".to_string()),
+                entities.add_entity((&mut u32s, &mut strings), (545, "Test741".to_string())),
+            ]
+        },
+    );
+
+    let path = std::env::temp_dir().join(file_name);
+    world.save_to(&path, format);
+
+    let world_copy = World::load_from(&path, GlobalDeConfig::default());
+    std::fs::remove_file(&path).unwrap();
+
+    world_copy.run(
+        |entities: EntitiesView, strings: View<String>, u32s: View<u32>| {
+            assert!(entities.is_alive(entity1));
+            assert!(entities.is_alive(entity2));
+
+            assert_eq!(strings.get(entity1).map(AsRef::as_ref), Ok("Test1212"));
+            assert_eq!(strings.get(entity2).map(AsRef::as_ref), Ok("Test741"));
+
+            assert!(u32s.get(entity1).is_err());
+            assert_eq!(u32s.get(entity2), Ok(&545));
+        },
+    );
+}
+
+#[test]
+fn bincode() {
+    save_and_load(SerFormat::Bincode, "shipyard_savegame.bin");
+}
+
+#[test]
+fn bincode_lz4() {
+    save_and_load(SerFormat::BincodeLz4, "shipyard_savegame.bin.lz4");
+}
+
+#[test]
+fn missing_file() {
+    let path = std::env::temp_dir()
+        .join("shipyard_missing_directory")
+        .join("savegame.bin");
+
+    match World::try_load_from(path, GlobalDeConfig::default()) {
+        Err(error::Savegame::Io(_)) => {}
+        _ => panic!("expected an io error"),
+    }
+}
@@ -0,0 +1,211 @@
+use serde_derive::{Deserialize, Serialize};
+use shipyard::*;
+
+#[derive(Serialize, Deserialize, PartialEq, Debug)]
+struct Position {
+    x: f32,
+    y: f32,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug)]
+struct Name(String);
+
+fn setup(world: &World) {
+    world.run(
+        |mut positions: ViewMut<Position>, mut names: ViewMut<Name>| {
+            positions.setup_serde(SerConfig {
+                identifier: Some(Identifier::new("Position")),
+                ..Default::default()
+            });
+            names.setup_serde(SerConfig {
+                identifier: Some(Identifier::new("Name")),
+                ..Default::default()
+            });
+        },
+    );
+}
+
+#[test]
+fn round_trip() {
+    let world = World::new();
+    setup(&world);
+
+    let player = world.spawn((Position { x: 1.0, y: 2.0 }, Name("player".to_string())));
+    let rock = world.spawn((Position { x: 5.0, y: 0.0 },));
+    // not part of the scene
+    world.spawn((0u32,));
+
+    let mut scene = Vec::new();
+    world
+        .serialize_scene(&mut serde_json::Serializer::pretty(&mut scene))
+        .unwrap();
+
+    let level = World::new();
+    setup(&level);
+
+    let entities = level
+        .load_scene(
+            GlobalDeConfig::default(),
+            &mut serde_json::Deserializer::from_slice(&scene),
+        )
+        .unwrap();
+
+    assert_eq!(entities.len(), 2);
+
+    world.run(|positions: View<Position>, names: View<Name>| {
+        level.run(|level_positions: View<Position>, level_names: View<Name>| {
+            assert_eq!(level_positions[entities[0].1], positions[player]);
+            assert_eq!(level_names[entities[0].1], names[player]);
+            assert_eq!(level_positions[entities[1].1], positions[rock]);
+            assert!(level_names.get(entities[1].1).is_err());
+        });
+    });
+}
+
+#[test]
+fn stable_output() {
+    #[derive(Serialize, Deserialize)]
+    struct Velocity(f32, f32);
+
+    fn build(velocity_first: bool) -> World {
+        let world = World::new();
+
+        if velocity_first {
+            world.run(|mut velocities: ViewMut<Velocity>| {
+                velocities.setup_serde(SerConfig {
+                    identifier: Some(Identifier::new("Velocity")),
+                    ..Default::default()
+                });
+            });
+        }
+        setup(&world);
+        world.run(|mut velocities: ViewMut<Velocity>| {
+            velocities.setup_serde(SerConfig {
+                identifier: Some(Identifier::new("Velocity")),
+                ..Default::default()
+            });
+        });
+
+        world.spawn((
+            Position { x: 1.0, y: 2.0 },
+            Name("player".to_string()),
+            Velocity(0.0, 1.0),
+        ));
+        world.spawn((Velocity(1.0, 0.0), Name("bird".to_string())));
+
+        world
+    }
+
+    fn serialize(world: &World) -> String {
+        let mut scene = Vec::new();
+        world
+            .serialize_scene(&mut serde_json::Serializer::new(&mut scene))
+            .unwrap();
+
+        String::from_utf8(scene).unwrap()
+    }
+
+    let world = build(false);
+    let scene = serialize(&world);
+
+    // storages are sorted by identifier
+    assert_eq!(
+        scene,
+        r#"[{"id":[0,0],"Name":"player","Position":{"x":1.0,"y":2.0},"Velocity":[0.0,1.0]},{"id":[1,0],"Name":"bird","Velocity":[1.0,0.0]}]"#
+    );
+    assert_eq!(serialize(&world), scene);
+    // storages created in a different order
+    assert_eq!(serialize(&build(true)), scene);
+}
+
+#[test]
+fn hand_written() {
+    let world = World::new();
+    setup(&world);
+
+    let entities = world
+        .load_scene(
+            GlobalDeConfig::default(),
+            &mut serde_json::Deserializer::from_str(
+                r#"[
+                { "Name": "door", "Position": { "x": 3.0, "y": 4.0 } },
+                { "Name": "key" }
+            ]"#,
+            ),
+        )
+        .unwrap();
+
+    world.run(|positions: View<Position>, names: View<Name>| {
+        assert_eq!(names[entities[0].1], Name("door".to_string()));
+        assert_eq!(positions[entities[0].1], Position { x: 3.0, y: 4.0 });
+        assert_eq!(names[entities[1].1], Name("key".to_string()));
+        assert!(positions.get(entities[1].1).is_err());
+    });
+}
+
+#[test]
+fn unknown_identifier() {
+    let world = World::new();
+    setup(&world);
+
+    assert!(world
+        .load_scene(
+            GlobalDeConfig::default(),
+            &mut serde_json::Deserializer::from_str(r#"[{ "Velocity": [1.0, 0.0] }]"#,)
+        )
+        .is_err());
+}
+
+#[derive(Serialize, Deserialize)]
+struct Target(EntityId);
+
+impl MapEntityIds for Target {
+    fn map_entity_ids(&mut self, map: &dyn Fn(EntityId) -> EntityId) {
+        self.0 = map(self.0);
+    }
+}
+
+#[test]
+fn entity_ids() {
+    use std::sync::{Arc, Mutex};
+
+    let mapping = Arc::new(Mutex::new(Vec::new()));
+    let reported = mapping.clone();
+
+    let world = World::new();
+    setup(&world);
+    world.run(|mut targets: ViewMut<Target>| {
+        targets.setup_serde_with_entity_ids(SerConfig {
+            identifier: Some(Identifier::new("Target")),
+            ..Default::default()
+        });
+    });
+
+    // the target appears after the entity referring to it
+    let entities = world
+        .load_scene(
+            GlobalDeConfig {
+                entity_mapping: Some(Arc::new(move |old, new| {
+                    reported.lock().unwrap().push((old, new))
+                })),
+                ..Default::default()
+            },
+            &mut serde_json::Deserializer::from_str(
+                r#"[
+                    { "Name": "turret", "Target": [7, 2] },
+                    { "id": [7, 2], "Name": "player" }
+                ]"#,
+            ),
+        )
+        .unwrap();
+
+    let old_player: EntityId = serde_json::from_str("[7, 2]").unwrap();
+    assert_eq!(entities[0].0, None);
+    assert_eq!(entities[1].0, Some(old_player));
+    // only entities with an "id" are reported
+    assert_eq!(*mapping.lock().unwrap(), vec![(old_player, entities[1].1)]);
+
+    world.run(|targets: View<Target>| {
+        assert_eq!(targets[entities[0].1].0, entities[1].1);
+    });
+}
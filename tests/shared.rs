@@ -170,3 +170,31 @@ fn self_shared() {
         })
         .unwrap();
 }
+
+#[test]
+fn shared_ids() {
+    let world = World::new();
+
+    world
+        .try_run(|mut entities: EntitiesViewMut, mut u32s: ViewMut<u32>| {
+            let owned = entities.add_entity(&mut u32s, 0);
+            let shared1 = entities.add_entity((), ());
+            let shared2 = entities.add_entity((), ());
+
+            u32s.try_share(owned, shared1).unwrap();
+            u32s.try_share(shared1, shared2).unwrap();
+
+            assert_eq!(
+                u32s.shared_ids(),
+                vec![(shared1, owned), (shared2, shared1)]
+            );
+
+            u32s.try_unshare(shared1).unwrap();
+            assert_eq!(u32s.shared_ids(), vec![(shared2, shared1)]);
+
+            // owning a component overrides the sharing
+            entities.try_add_component(&mut u32s, 1, shared2).unwrap();
+            assert!(u32s.shared_ids().is_empty());
+        })
+        .unwrap();
+}
@@ -0,0 +1,129 @@
+#![cfg(feature = "panic")]
+
+use shipyard::*;
+
+#[test]
+fn undo_redo() {
+    let world = World::new();
+
+    world.run(|mut u32s: ViewMut<u32>| u32s.setup_snapshot());
+
+    let entity0 = world.spawn((0u32,));
+    world.push_snapshot();
+
+    let entity1 = world.spawn((1u32,));
+    world.run(|mut u32s: ViewMut<u32>| u32s[entity0] = 10);
+
+    assert!(world.undo());
+    world.run(|entities: EntitiesView, u32s: View<u32>| {
+        assert!(entities.is_alive(entity0));
+        assert!(!entities.is_alive(entity1));
+        assert_eq!(u32s.len(), 1);
+        assert_eq!(u32s[entity0], 0);
+    });
+    assert!(!world.undo());
+
+    assert!(world.redo());
+    world.run(|entities: EntitiesView, u32s: View<u32>| {
+        assert!(entities.is_alive(entity1));
+        assert_eq!(u32s.len(), 2);
+        assert_eq!(u32s[entity0], 10);
+        assert_eq!(u32s[entity1], 1);
+    });
+    assert!(!world.redo());
+}
+
+#[test]
+fn push_clears_redo() {
+    let world = World::new();
+
+    world.run(|mut u32s: ViewMut<u32>| u32s.setup_snapshot());
+
+    world.push_snapshot();
+    world.spawn((0u32,));
+    assert!(world.undo());
+
+    world.push_snapshot();
+    assert!(!world.redo());
+}
+
+#[test]
+fn capacity() {
+    let world = World::new();
+
+    world.run(|mut u32s: ViewMut<u32>| u32s.setup_snapshot());
+    world.set_snapshot_capacity(2);
+
+    let entity = world.spawn((0u32,));
+    for i in 1..4 {
+        world.push_snapshot();
+        world.run(|mut u32s: ViewMut<u32>| u32s[entity] = i);
+    }
+
+    assert!(world.undo());
+    assert!(world.undo());
+    assert!(!world.undo());
+    world.run(|u32s: View<u32>| assert_eq!(u32s[entity], 1));
+}
+
+#[test]
+fn without_setup() {
+    let world = World::new();
+
+    world.run(|mut u32s: ViewMut<u32>| u32s.setup_snapshot());
+
+    let entity = world.spawn((0u32, 0usize));
+    world.push_snapshot();
+
+    world.run(|mut u32s: ViewMut<u32>, mut usizes: ViewMut<usize>| {
+        u32s[entity] = 1;
+        usizes[entity] = 1;
+    });
+
+    assert!(world.undo());
+    world.run(|u32s: View<u32>, usizes: View<usize>| {
+        assert_eq!(u32s[entity], 0);
+        assert_eq!(usizes[entity], 1);
+    });
+}
+
+#[test]
+fn storage_created_after() {
+    let world = World::new();
+
+    world.push_snapshot();
+
+    world.run(|mut u32s: ViewMut<u32>| u32s.setup_snapshot());
+    world.spawn((0u32,));
+
+    assert!(world.undo());
+    world.run(|entities: EntitiesView, u32s: View<u32>| {
+        assert_eq!(entities.iter().count(), 0);
+        assert!(u32s.is_empty());
+    });
+}
+
+#[test]
+fn pack_without_setup() {
+    let world = World::new();
+
+    world.run(|mut u32s: ViewMut<u32>, mut usizes: ViewMut<usize>| {
+        u32s.setup_snapshot();
+        (&mut u32s, &mut usizes).tight_pack();
+    });
+
+    let entity0 = world.spawn((0u32, 0usize));
+    world.spawn((1u32, 1usize));
+    world.push_snapshot();
+
+    world.spawn((2u32, 2usize));
+    world.borrow::<AllStoragesViewMut>().delete(entity0);
+
+    assert!(world.undo());
+    // usizes isn't part of the snapshot, only the entity with both components is packed
+    world.run(|u32s: View<u32>, usizes: View<usize>| {
+        assert_eq!(u32s.tight_packed(), &[1]);
+        assert_eq!(usizes.tight_packed(), &[1]);
+        assert_eq!((&u32s, &usizes).iter().collect::<Vec<_>>(), vec![(&1, &1)]);
+    });
+}
@@ -179,3 +179,130 @@ fn update_sort() {
         Some(error::Sort::MissingPackStorage)
     );
 }
+
+#[cfg(feature = "parallel")]
+#[test]
+fn par_sort() {
+    let world = World::new();
+    let (mut entities, mut usizes, tasks) = world
+        .try_borrow::<(EntitiesViewMut, ViewMut<usize>, Tasks)>()
+        .unwrap();
+
+    let mut sorted = Vec::new();
+    for i in 0..10_000 {
+        // scrambles the insertion order
+        let value = (i * 7919) % 10_000;
+        sorted.push((entities.add_entity(&mut usizes, value), value));
+    }
+    sorted.sort_unstable_by_key(|&(_, value)| value);
+
+    usizes
+        .sort()
+        .try_par_unstable(tasks.as_ref(), Ord::cmp)
+        .unwrap();
+
+    assert_eq!(
+        (&usizes).iter().with_id().collect::<Vec<_>>(),
+        sorted
+            .iter()
+            .map(|&(id, ref value)| (id, value))
+            .collect::<Vec<_>>()
+    );
+}
+
+#[cfg(feature = "parallel")]
+#[test]
+fn par_tight_sort() {
+    let world = World::new();
+    let (mut entities, mut usizes, mut u32s, tasks) = world
+        .try_borrow::<(EntitiesViewMut, ViewMut<usize>, ViewMut<u32>, Tasks)>()
+        .unwrap();
+
+    (&mut usizes, &mut u32s).try_tight_pack().unwrap();
+    for i in 0..1000 {
+        entities.add_entity((&mut usizes, &mut u32s), (1000 - i, i as u32 % 3));
+    }
+
+    (&mut usizes, &mut u32s)
+        .sort()
+        .try_par_unstable(tasks.as_ref(), |(&x1, &y1), (&x2, &y2)| {
+            (y1, x1).cmp(&(y2, x2))
+        })
+        .unwrap();
+
+    let mut prev = (0, 0);
+    (&usizes, &u32s).iter().for_each(|(&x, &y)| {
+        assert!(prev <= (y, x));
+        prev = (y, x);
+    });
+}
+
+#[cfg(feature = "parallel")]
+#[test]
+fn par_sort_missing_storage() {
+    let world = World::new();
+    let (mut usizes, mut u64s, tasks) = world
+        .try_borrow::<(ViewMut<usize>, ViewMut<u64>, Tasks)>()
+        .unwrap();
+
+    (&mut usizes, &mut u64s).try_tight_pack().unwrap();
+
+    assert_eq!(
+        usizes.sort().try_par_unstable(tasks.as_ref(), Ord::cmp),
+        Err(error::Sort::MissingPackStorage)
+    );
+}
+
+#[cfg(feature = "panic")]
+#[test]
+fn grouped_by() {
+    let world = World::new();
+    let (mut entities, mut squads) = world.borrow::<(EntitiesViewMut, ViewMut<(u32, usize)>)>();
+
+    let e0 = entities.add_entity(&mut squads, (2, 0));
+    let e1 = entities.add_entity(&mut squads, (1, 1));
+    let e2 = entities.add_entity(&mut squads, (2, 2));
+    let e3 = entities.add_entity(&mut squads, (0, 3));
+
+    let groups = squads
+        .iter_grouped_by(|&(squad, _)| squad)
+        .map(|(squad, ids, members)| {
+            for member in members.iter_mut() {
+                member.1 *= 10;
+            }
+
+            (squad, ids.to_vec())
+        })
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        groups,
+        vec![(0, vec![e3]), (1, vec![e1]), (2, vec![e0, e2])]
+    );
+    assert_eq!(squads[e2], (2, 20));
+    assert_eq!(squads.as_slice(), &[(0, 30), (1, 10), (2, 0), (2, 20)]);
+
+    squads[e0].0 = 3;
+
+    let keys = squads
+        .iter_grouped_by(|&(squad, _)| squad)
+        .map(|(squad, ids, _)| (squad, ids.len()))
+        .collect::<Vec<_>>();
+    assert_eq!(keys, vec![(0, 1), (1, 1), (2, 1), (3, 1)]);
+    assert_eq!(squads.id_at(3), e0);
+}
+
+#[test]
+fn grouped_by_packed() {
+    let world = World::new();
+    let (mut usizes, mut u32s) = world
+        .try_borrow::<(ViewMut<usize>, ViewMut<u32>)>()
+        .unwrap();
+
+    (&mut usizes, &mut u32s).try_tight_pack().unwrap();
+
+    assert_eq!(
+        usizes.try_iter_grouped_by(|&x| x).err(),
+        Some(error::Sort::MissingPackStorage)
+    );
+}
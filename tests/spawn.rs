@@ -0,0 +1,96 @@
+#![cfg(feature = "panic")]
+
+use shipyard::error;
+use shipyard::*;
+
+#[test]
+fn spawn() {
+    let world = World::new();
+
+    let entity1 = world.try_spawn((0usize, 1u32)).unwrap();
+    let entity2 = world.try_spawn((2usize,)).unwrap();
+    let entity3 = world.try_spawn(()).unwrap();
+
+    let (entities, usizes, u32s) = world
+        .try_borrow::<(EntitiesView, View<usize>, View<u32>)>()
+        .unwrap();
+    assert!(entities.is_alive(entity3));
+    assert_eq!((&usizes, &u32s).get(entity1).unwrap(), (&0, &1));
+    assert_eq!(usizes.get(entity2), Ok(&2));
+    assert!(u32s.get(entity2).is_err());
+    assert_eq!(usizes.len(), 2);
+}
+
+#[test]
+fn tight_packed() {
+    let world = World::new();
+
+    world.run(|mut usizes: ViewMut<usize>, mut u32s: ViewMut<u32>| {
+        (&mut usizes, &mut u32s).tight_pack();
+    });
+
+    world.spawn((0usize, 1u32));
+    world.spawn((2usize,));
+
+    world.run(|usizes: View<usize>, u32s: View<u32>| {
+        assert_eq!((&usizes, &u32s).iter().count(), 1);
+    });
+}
+
+#[test]
+fn borrow_conflict() {
+    let world = World::new();
+
+    let _usizes = world.borrow::<ViewMut<usize>>();
+
+    assert_eq!(
+        world.try_spawn((0usize,)).err(),
+        Some(error::GetStorage::StorageBorrow((
+            core::any::type_name::<usize>(),
+            error::Borrow::Unique(Default::default())
+        )))
+    );
+}
+
+#[test]
+fn deferred() {
+    fn spawner(entities: EntitiesView, u32s: View<u32>) {
+        for &x in u32s.iter() {
+            entities
+                .deferred_entity_builder()
+                .with(x as usize)
+                .with(x)
+                .build();
+        }
+        entities.deferred_entity_builder().build();
+    }
+
+    let world = World::new();
+
+    world.run(|mut usizes: ViewMut<usize>, mut u32s: ViewMut<u32>| {
+        (&mut usizes, &mut u32s).tight_pack();
+    });
+    world.spawn((0u32,));
+    world.set_strict(true);
+
+    world
+        .add_workload("Spawn")
+        .with_system(system!(spawner))
+        .build();
+    world.run_default();
+
+    world.run(|entities: EntitiesView, usizes: View<usize>| {
+        assert_eq!(entities.iter().count(), 1);
+        assert_eq!(usizes.len(), 0);
+    });
+
+    world.try_maintain().unwrap();
+
+    world.run(
+        |entities: EntitiesView, usizes: View<usize>, u32s: View<u32>| {
+            assert_eq!(entities.iter().count(), 3);
+            assert_eq!((&usizes, &u32s).iter().collect::<Vec<_>>(), vec![(&0, &0)]);
+            assert_eq!(u32s.len(), 2);
+        },
+    );
+}
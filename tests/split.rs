@@ -0,0 +1,218 @@
+#![cfg(all(feature = "panic", feature = "proc"))]
+
+use shipyard::*;
+
+#[derive(Debug, PartialEq)]
+struct Position(f32, f32);
+#[derive(Debug, PartialEq)]
+struct Name(&'static str);
+
+#[derive(Split)]
+struct Body {
+    position: Position,
+    name: Name,
+}
+
+#[test]
+fn split() {
+    let world = World::new();
+
+    let entity = world.run(|mut entities: EntitiesViewMut, mut bodies: BodyViewMut| {
+        bodies.add_entity(
+            &mut entities,
+            Body {
+                position: Position(0.0, 1.0),
+                name: Name("player"),
+            },
+        )
+    });
+
+    // each field lives in the storage of its type
+    world.run(|positions: View<Position>, names: View<Name>| {
+        assert_eq!(positions.get(entity), Ok(&Position(0.0, 1.0)));
+        assert_eq!(names.get(entity), Ok(&Name("player")));
+    });
+
+    world.run(|mut bodies: BodyViewMut| {
+        bodies.iter_mut().for_each(|body| body.position.0 += 1.0);
+
+        let body = bodies.get_mut(entity).unwrap();
+        body.name.0 = "bird";
+    });
+
+    world.run(|bodies: BodyView| {
+        let body = bodies.get(entity).unwrap();
+        assert_eq!(body.position, &Position(1.0, 1.0));
+        assert_eq!(body.name, &Name("bird"));
+
+        assert_eq!(bodies.iter().count(), 1);
+    });
+}
+
+#[test]
+fn missing_field() {
+    let world = World::new();
+
+    let entity = world.run(
+        |mut entities: EntitiesViewMut, mut positions: ViewMut<Position>| {
+            entities.add_entity(&mut positions, Position(0.0, 0.0))
+        },
+    );
+
+    world.run(|bodies: BodyView| {
+        assert_eq!(
+            bodies.get(entity).err(),
+            Some(error::MissingComponent {
+                id: entity,
+                name: core::any::type_name::<Name>(),
+            })
+        );
+        assert_eq!(bodies.iter().count(), 0);
+    });
+
+    world.run(|entities: EntitiesView, mut bodies: BodyViewMut| {
+        bodies
+            .try_add_component(
+                Body {
+                    position: Position(2.0, 2.0),
+                    name: Name("player"),
+                },
+                entity,
+                &entities,
+            )
+            .unwrap();
+
+        assert_eq!(bodies.get(entity).unwrap().position, &Position(2.0, 2.0));
+
+        bodies.try_delete(entity).unwrap();
+
+        assert!(bodies.get(entity).is_err());
+        assert_eq!(bodies.position.len(), 0);
+        assert_eq!(bodies.name.len(), 0);
+    });
+}
+
+#[test]
+fn borrow_conflict() {
+    let world = World::new();
+
+    let _positions = world.borrow::<View<Position>>();
+
+    assert!(world.try_borrow::<BodyView>().is_ok());
+    assert_eq!(
+        world.try_borrow::<BodyViewMut>().err(),
+        Some(error::GetStorage::StorageBorrow((
+            core::any::type_name::<Position>(),
+            error::Borrow::Unique(Default::default())
+        )))
+    );
+}
+
+#[cfg(feature = "extended_tuple")]
+#[test]
+fn sixteen_fields() {
+    #[derive(Debug, PartialEq)]
+    struct F0(u8);
+    #[derive(Debug, PartialEq)]
+    struct F1(u8);
+    #[derive(Debug, PartialEq)]
+    struct F2(u8);
+    #[derive(Debug, PartialEq)]
+    struct F3(u8);
+    #[derive(Debug, PartialEq)]
+    struct F4(u8);
+    #[derive(Debug, PartialEq)]
+    struct F5(u8);
+    #[derive(Debug, PartialEq)]
+    struct F6(u8);
+    #[derive(Debug, PartialEq)]
+    struct F7(u8);
+    #[derive(Debug, PartialEq)]
+    struct F8(u8);
+    #[derive(Debug, PartialEq)]
+    struct F9(u8);
+    #[derive(Debug, PartialEq)]
+    struct F10(u8);
+    #[derive(Debug, PartialEq)]
+    struct F11(u8);
+    #[derive(Debug, PartialEq)]
+    struct F12(u8);
+    #[derive(Debug, PartialEq)]
+    struct F13(u8);
+    #[derive(Debug, PartialEq)]
+    struct F14(u8);
+    #[derive(Debug, PartialEq)]
+    struct F15(u8);
+
+    #[derive(Split)]
+    struct Big {
+        f0: F0,
+        f1: F1,
+        f2: F2,
+        f3: F3,
+        f4: F4,
+        f5: F5,
+        f6: F6,
+        f7: F7,
+        f8: F8,
+        f9: F9,
+        f10: F10,
+        f11: F11,
+        f12: F12,
+        f13: F13,
+        f14: F14,
+        f15: F15,
+    }
+
+    #[derive(Borrow)]
+    struct Views<'v> {
+        big: BigView<'v>,
+        f15: View<'v, F15>,
+        f14: View<'v, F14>,
+        f13: View<'v, F13>,
+        f12: View<'v, F12>,
+        f11: View<'v, F11>,
+        f10: View<'v, F10>,
+        f9: View<'v, F9>,
+        f8: View<'v, F8>,
+        f7: View<'v, F7>,
+        f6: View<'v, F6>,
+        f5: View<'v, F5>,
+        f4: View<'v, F4>,
+        f3: View<'v, F3>,
+        f2: View<'v, F2>,
+        f1: View<'v, F1>,
+    }
+
+    let world = World::new();
+
+    let entity = world.run(|mut entities: EntitiesViewMut, mut bigs: BigViewMut| {
+        bigs.add_entity(
+            &mut entities,
+            Big {
+                f0: F0(0),
+                f1: F1(1),
+                f2: F2(2),
+                f3: F3(3),
+                f4: F4(4),
+                f5: F5(5),
+                f6: F6(6),
+                f7: F7(7),
+                f8: F8(8),
+                f9: F9(9),
+                f10: F10(10),
+                f11: F11(11),
+                f12: F12(12),
+                f13: F13(13),
+                f14: F14(14),
+                f15: F15(15),
+            },
+        )
+    });
+
+    world.run(|views: Views| {
+        assert_eq!(views.big.get(entity).unwrap().f15, &F15(15));
+        assert_eq!(views.f15.get(entity), Ok(&F15(15)));
+        assert_eq!(views.f1.get(entity), Ok(&F1(1)));
+    });
+}
@@ -0,0 +1,162 @@
+#![cfg(feature = "stats")]
+
+use shipyard::*;
+
+#[test]
+fn borrows() {
+    let world = World::new();
+
+    world.run(|_: View<u32>| {});
+    world.run(|_: ViewMut<u32>| {});
+    world.run(|_: ViewMut<u32>| {
+        assert!(world.try_run(|_: View<u32>| {}).is_err());
+    });
+
+    let stats = world.stats();
+    let u32s = stats.storage(core::any::type_name::<u32>()).unwrap();
+
+    assert_eq!(u32s.shared_borrows, 1);
+    assert_eq!(u32s.unique_borrows, 2);
+    assert_eq!(u32s.failed_borrows, 1);
+}
+
+#[test]
+fn iterated() {
+    fn both(u32s: View<u32>, usizes: View<usize>) {
+        (&u32s, &usizes).iter().for_each(|_| {});
+    }
+
+    fn single(u32s: View<u32>) {
+        u32s.iter().for_each(|_| {});
+        u32s.iter().for_each(|_| {});
+    }
+
+    let world = World::new();
+
+    world.spawn((0u32, 0usize));
+    world.spawn((1u32,));
+    world.spawn((2u32,));
+
+    world
+        .add_workload("Iterate")
+        .with_system(system!(both))
+        .with_system(system!(single))
+        .build();
+
+    world.run_default();
+    world.run_default();
+
+    let stats = world.stats();
+    let both = stats
+        .systems
+        .iter()
+        .find(|system| system.name.ends_with("both"))
+        .unwrap();
+    let single = stats
+        .systems
+        .iter()
+        .find(|system| system.name.ends_with("single"))
+        .unwrap();
+
+    assert_eq!(both.runs, 2);
+    // the smallest storage is iterated
+    assert_eq!(both.iterated, 2);
+    assert_eq!(single.runs, 2);
+    assert_eq!(single.iterated, 12);
+}
+
+#[test]
+fn iterated_yielded() {
+    fn partial(u32s: View<u32>, usizes: View<usize>) {
+        u32s.iter().next();
+        (&u32s, !&usizes).iter().for_each(|_| {});
+        u32s.iter().into_chunk(2).ok().unwrap().for_each(|_| {});
+    }
+
+    let world = World::new();
+
+    world.spawn((0u32, 0usize));
+    world.spawn((1u32,));
+    world.spawn((2u32,));
+
+    world
+        .add_workload("Partial")
+        .with_system(system!(partial))
+        .build();
+
+    world.run_default();
+
+    let stats = world.stats();
+    let partial = stats
+        .systems
+        .iter()
+        .find(|system| system.name.ends_with("partial"))
+        .unwrap();
+
+    // only yielded entities are counted, not the length of the storages
+    assert_eq!(partial.iterated, 1 + 2 + 3);
+}
+
+#[test]
+fn tracked() {
+    fn count(stats: UniqueView<WorldStats>) {
+        // refreshed after the previous run
+        assert!(stats.entities >= 2);
+    }
+
+    let world = World::new();
+    world.spawn((0u32,));
+    world.spawn((1u32, 0usize));
+
+    world.track_stats();
+    world
+        .add_workload("Count")
+        .with_system(system!(count))
+        .build();
+
+    {
+        let stats = world.borrow::<UniqueView<WorldStats>>();
+        assert_eq!(stats.entities, 2);
+        assert_eq!(stats.last_workload, None);
+        assert_eq!(stats.storage(core::any::type_name::<u32>()).unwrap().len, 2);
+    }
+
+    world.spawn((2u32,));
+    world.run_default();
+
+    let stats = world.borrow::<UniqueView<WorldStats>>();
+    assert_eq!(stats.entities, 3);
+    assert!(stats.last_workload.is_some());
+    assert_eq!(stats.storage(core::any::type_name::<u32>()).unwrap().len, 3);
+    assert_eq!(
+        stats.storage(core::any::type_name::<usize>()).unwrap().len,
+        1
+    );
+    assert_eq!(stats.systems[0].runs, 1);
+}
+
+#[test]
+fn delete_with_stats() {
+    struct Stale;
+
+    let world = World::new();
+
+    let entity = world.spawn((0u32, 1usize));
+    let other = world.spawn((2u32,));
+    world.run(|mut stales: ViewMut<Stale>| stales.add_component(Stale, entity));
+
+    world.run(|mut all_storages: AllStoragesViewMut| {
+        let stats = all_storages.delete_with_stats(entity).unwrap();
+
+        assert_eq!(stats.components, 3);
+        assert_eq!(
+            stats.storages,
+            vec![core::any::type_name::<Stale>(), "u32", "usize"]
+        );
+        assert_eq!(all_storages.delete_with_stats(entity), None);
+
+        let stats = all_storages.delete_with_stats(other).unwrap();
+        assert_eq!(stats.components, 1);
+        assert_eq!(stats.storages, vec!["u32"]);
+    });
+}
@@ -0,0 +1,75 @@
+#![cfg(feature = "panic")]
+
+use shipyard::error;
+use shipyard::*;
+
+#[test]
+fn missing_storage() {
+    let world = World::new();
+    world.set_strict(true);
+
+    assert_eq!(
+        world.try_borrow::<View<u32>>().err(),
+        Some(error::GetStorage::MissingStorage(
+            core::any::type_name::<u32>()
+        ))
+    );
+    assert_eq!(
+        world.try_borrow::<ViewMut<u32>>().err(),
+        Some(error::GetStorage::MissingStorage(
+            core::any::type_name::<u32>()
+        ))
+    );
+    assert!(world.try_spawn((0u32,)).is_err());
+
+    // the failed borrows didn't create the storage
+    world.set_strict(false);
+    world.set_strict(true);
+    assert!(world.try_borrow::<View<u32>>().is_err());
+}
+
+#[test]
+fn registered_storage() {
+    let world = World::new();
+    world.set_strict(true);
+
+    world.register_storage::<u32>();
+    world.register_storage::<u32>();
+
+    let entity = world.spawn((0u32,));
+    assert_eq!(world.borrow::<View<u32>>()[entity], 0);
+}
+
+#[test]
+fn existing_storages() {
+    let world = World::new();
+
+    let entity = world.spawn((0u32,));
+    world.add_unique(0usize);
+    world.set_strict(true);
+
+    assert_eq!(world.borrow::<View<u32>>()[entity], 0);
+    assert_eq!(*world.borrow::<UniqueView<usize>>(), 0);
+    assert!(world.try_borrow::<EntitiesViewMut>().is_ok());
+}
+
+#[test]
+fn not_strict() {
+    let world = World::new();
+    world.set_strict(true);
+    world.set_strict(false);
+
+    assert!(world.try_borrow::<View<u32>>().is_ok());
+}
+
+#[test]
+fn register_storages() {
+    let world = World::new();
+    world.register_storages::<(u32,)>();
+    world.register_storages::<(u32, usize, i8)>();
+    world.set_strict(true);
+
+    let entity = world.spawn((0u32, 1usize, 2i8));
+    assert_eq!(world.borrow::<View<usize>>()[entity], 1);
+    assert!(world.try_borrow::<View<u8>>().is_err());
+}
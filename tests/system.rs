@@ -33,3 +33,102 @@ fn bad_systems() {
         Some(error::InvalidSystem::AllStorages)
     );
 }
+
+#[cfg(feature = "panic")]
+#[test]
+fn capturing_closure() {
+    use std::sync::atomic::{AtomicU32, Ordering};
+    use std::sync::Arc;
+
+    let world = World::new();
+    let total = Arc::new(AtomicU32::new(0));
+
+    // same closure type, different state for each workload
+    for (name, step) in [("One", 1), ("Ten", 10)].iter().copied() {
+        let total = total.clone();
+
+        world
+            .add_workload(name)
+            .with_system(system!(move || {
+                total.fetch_add(step, Ordering::Relaxed);
+            }))
+            .build();
+    }
+
+    world.run_workload("One");
+    world.run_workload("Ten");
+    world.run_workload("Ten");
+
+    assert_eq!(total.load(Ordering::Relaxed), 21);
+
+    let limit = 5u32;
+    world
+        .add_workload("Limit")
+        .with_system(try_system!(move |u32s: View<u32>| {
+            if u32s.len() as u32 > limit {
+                Err(error::NotInbound::View("u32"))
+            } else {
+                Ok(())
+            }
+        }))
+        .build();
+
+    world.run_workload("Limit");
+}
+
+#[cfg(all(feature = "panic", feature = "extended_tuple"))]
+#[test]
+fn extended_tuple() {
+    #[allow(clippy::too_many_arguments)]
+    fn fourteen_views(
+        u8s: View<u8>,
+        u16s: View<u16>,
+        mut u32s: ViewMut<u32>,
+        u64s: View<u64>,
+        u128s: View<u128>,
+        usizes: View<usize>,
+        i8s: View<i8>,
+        i16s: View<i16>,
+        i32s: View<i32>,
+        i64s: View<i64>,
+        i128s: View<i128>,
+        isizes: View<isize>,
+        f32s: View<f32>,
+        f64s: View<f64>,
+    ) {
+        (
+            &u8s, &u16s, &mut u32s, &u64s, &u128s, &usizes, &i8s, &i16s, &i32s, &i64s, &i128s,
+            &isizes, &f32s, &f64s,
+        )
+            .iter()
+            .for_each(|(&a, &b, c, &d, &e, &f, &g, &h, &i, &j, &k, &l, &m, &n)| {
+                *c += a as u32
+                    + b as u32
+                    + d as u32
+                    + e as u32
+                    + f as u32
+                    + g as u32
+                    + h as u32
+                    + i as u32
+                    + j as u32
+                    + k as u32
+                    + l as u32
+                    + m as u32
+                    + n as u32;
+            });
+    }
+
+    let world = World::new();
+    let entity = world.spawn((
+        1u8, 1u16, 1u32, 1u64, 1u128, 1usize, 1i8, 1i16, 1i32, 1i64, 1i128, 1isize, 1f32, 1f64,
+    ));
+    world.spawn((1u8, 1u16, 1u32));
+
+    world
+        .add_workload("Fourteen")
+        .with_system(system!(fourteen_views))
+        .build();
+    world.run_workload("Fourteen");
+
+    assert_eq!(world.borrow::<View<u32>>()[entity], 14);
+}
@@ -0,0 +1,71 @@
+#![cfg(feature = "panic")]
+
+use shipyard::*;
+
+#[test]
+fn tags() {
+    let world = World::new();
+    let [orc, goblin, villager] = [world.spawn(()), world.spawn(()), world.spawn(())];
+
+    world.add_tag(orc, "enemy");
+    world.add_tag(goblin, "enemy");
+    world.add_tag(goblin, "sneaky");
+    world.add_tag(villager, "friendly");
+
+    assert_eq!(world.tagged("enemy"), vec![orc, goblin]);
+    assert_eq!(world.tagged("sneaky"), vec![goblin]);
+    assert!(world.tagged("boss").is_empty());
+
+    assert!(world.remove_tag(goblin, "enemy"));
+    assert!(!world.remove_tag(goblin, "enemy"));
+    assert_eq!(world.tagged("enemy"), vec![orc]);
+
+    world.run(|mut tags: TagsViewMut| {
+        assert!(!tags.insert(orc, "enemy"));
+        assert!(tags.insert(villager, "enemy"));
+        assert!(tags.contains(villager, "friendly"));
+        assert!(!tags.contains(orc, "friendly"));
+
+        let mut villager_tags = tags.tags_of(villager).collect::<Vec<_>>();
+        villager_tags.sort_unstable();
+        assert_eq!(villager_tags, vec!["enemy", "friendly"]);
+    });
+}
+
+#[test]
+fn delete_entity() {
+    let world = World::new();
+    let orc = world.spawn(());
+    let goblin = world.spawn(());
+
+    world.add_tag(orc, "enemy");
+    world.add_tag(goblin, "enemy");
+
+    world.borrow::<AllStoragesViewMut>().delete(orc);
+    assert_eq!(world.tagged("enemy"), vec![goblin]);
+
+    // the new entity reuses orc's index
+    let troll = world.spawn(());
+    assert_eq!(troll.index(), orc.index());
+    assert!(!world.borrow::<TagsView>().contains(troll, "enemy"));
+
+    assert_eq!(
+        world.try_add_tag(orc, "enemy"),
+        Err(error::AddTag::EntityIsNotAlive)
+    );
+}
+
+#[test]
+fn many_entities() {
+    let world = World::new();
+
+    let entities = (0..200).map(|_| world.spawn(())).collect::<Vec<_>>();
+    for entity in entities.iter().step_by(3) {
+        world.add_tag(*entity, "third");
+    }
+
+    assert_eq!(
+        world.tagged("third"),
+        entities.iter().copied().step_by(3).collect::<Vec<_>>()
+    );
+}
@@ -0,0 +1,92 @@
+#![cfg(feature = "panic")]
+
+use shipyard::*;
+
+#[test]
+fn take_entity() {
+    let world = World::new();
+
+    let entity1 = world.spawn((0usize, 1u32));
+    let entity2 = world.spawn((2usize, 3u32));
+
+    world.run(|mut all_storages: AllStoragesViewMut| {
+        assert_eq!(
+            all_storages.take_entity::<(u32, usize)>(entity1),
+            Some((Some(1), Some(0)))
+        );
+        assert_eq!(all_storages.take_entity::<(u32,)>(entity1), None);
+    });
+
+    let (entities, usizes, u32s) = world.borrow::<(EntitiesView, View<usize>, View<u32>)>();
+    assert!(!entities.is_alive(entity1));
+    assert!(entities.is_alive(entity2));
+    assert_eq!(usizes.len(), 1);
+    assert_eq!(u32s.len(), 1);
+    assert_eq!((&usizes, &u32s).get(entity2).unwrap(), (&2, &3));
+}
+
+#[test]
+fn drops_other_components() {
+    let world = World::new();
+
+    let entity = world.spawn((0usize, 1u32));
+
+    world.run(|mut all_storages: AllStoragesViewMut| {
+        assert_eq!(
+            all_storages.take_entity::<(usize, u64)>(entity),
+            Some((Some(0), None))
+        );
+    });
+
+    assert_eq!(world.borrow::<View<u32>>().len(), 0);
+}
+
+#[test]
+fn tight_packed() {
+    let world = World::new();
+
+    world.run(|mut usizes: ViewMut<usize>, mut u32s: ViewMut<u32>| {
+        (&mut usizes, &mut u32s).tight_pack();
+    });
+
+    let entity1 = world.spawn((0usize, 1u32));
+    let entity2 = world.spawn((2usize, 3u32));
+
+    world.run(|mut all_storages: AllStoragesViewMut| {
+        assert_eq!(
+            all_storages.take_entity::<(usize,)>(entity1),
+            Some((Some(0),))
+        );
+    });
+
+    world.run(|usizes: View<usize>, u32s: View<u32>| {
+        assert_eq!(u32s.len(), 1);
+        let mut iter = (&usizes, &u32s).iter();
+        assert_eq!(iter.next(), Some((&2, &3)));
+        assert_eq!(iter.next(), None);
+        assert_eq!((&usizes, &u32s).get(entity2).unwrap(), (&2, &3));
+    });
+}
+
+#[test]
+fn respawn() {
+    let world = World::new();
+    let other = World::new();
+
+    let entity = world.spawn((String::from("name"), 1u32));
+
+    let components = world.run(|mut all_storages: AllStoragesViewMut| {
+        all_storages.take_entity::<(String, u32)>(entity)
+    });
+
+    if let Some((Some(name), Some(value))) = components {
+        let entity = other.spawn((name, value));
+
+        other.run(|strings: View<String>, u32s: View<u32>| {
+            assert_eq!(strings[entity], "name");
+            assert_eq!(u32s[entity], 1);
+        });
+    } else {
+        panic!("components weren't taken");
+    }
+}
@@ -0,0 +1,155 @@
+#![cfg(feature = "panic")]
+
+use shipyard::*;
+
+#[derive(PartialEq, Debug)]
+struct Pos(u32, u32);
+
+#[test]
+fn component() {
+    let world = World::new();
+
+    let entity = world.spawn((Pos(0, 1), 2u32, String::from("name")));
+
+    assert_component!(world, entity, Pos == Pos(0, 1));
+    assert_component!(world, entity, std::string::String == "name");
+
+    world.run(|all_storages: AllStoragesViewMut| {
+        assert_component!(all_storages, entity, u32 == 2);
+    });
+}
+
+#[test]
+#[should_panic(expected = "isn't the expected one")]
+fn wrong_component() {
+    let world = World::new();
+
+    let entity = world.spawn((Pos(0, 1),));
+
+    assert_component!(world, entity, Pos == Pos(1, 0));
+}
+
+#[test]
+#[should_panic(expected = "has no")]
+fn missing_component() {
+    let world = World::new();
+
+    let entity = world.spawn((0u32,));
+
+    assert_component!(world, entity, Pos == Pos(0, 1));
+}
+
+#[cfg(feature = "serde1")]
+mod world_eq {
+    use serde_derive::{Deserialize, Serialize};
+    use shipyard::*;
+
+    #[derive(Serialize, Deserialize)]
+    struct Velocity {
+        x: f32,
+        y: f32,
+    }
+
+    fn setup(world: &World) {
+        world.run(
+            |mut u32s: ViewMut<u32>, mut velocities: ViewMut<Velocity>| {
+                u32s.setup_serde(SerConfig {
+                    identifier: Some(Identifier::new("u32")),
+                    ..Default::default()
+                });
+                velocities.setup_serde(SerConfig {
+                    identifier: Some(Identifier::new("velocity")),
+                    ..Default::default()
+                });
+            },
+        );
+    }
+
+    #[test]
+    fn equal() {
+        let left = World::new();
+        let right = World::new();
+        setup(&left);
+        setup(&right);
+
+        left.spawn((0u32, Velocity { x: 1.0, y: 2.0 }));
+        left.spawn((1u32,));
+        // registered in the other order
+        right.spawn((Velocity { x: 1.0, y: 2.0 }, 0u32));
+        right.spawn((1u32,));
+
+        assert_world_eq!(left, right);
+    }
+
+    #[test]
+    #[should_panic(expected = "Worlds are not equal")]
+    fn different_component() {
+        let left = World::new();
+        let right = World::new();
+        setup(&left);
+        setup(&right);
+
+        left.spawn((Velocity { x: 1.0, y: 2.0 },));
+        right.spawn((Velocity { x: 1.0, y: 3.0 },));
+
+        assert_world_eq!(left, right);
+    }
+
+    #[test]
+    #[should_panic(expected = "Worlds are not equal")]
+    fn different_entities() {
+        let left = World::new();
+        let right = World::new();
+        setup(&left);
+        setup(&right);
+
+        left.spawn((0u32,));
+        right.spawn(());
+        right.spawn((0u32,));
+
+        assert_world_eq!(left, right);
+    }
+
+    #[test]
+    fn state() {
+        let world = World::new();
+        setup(&world);
+
+        let entity = world.spawn((0u32, Velocity { x: 1.0, y: 2.0 }));
+
+        let state = testing::world_state(&world).unwrap();
+
+        assert_eq!(state.len(), 1);
+        assert_eq!(state[&entity]["u32"], "0");
+        assert_eq!(state[&entity]["velocity"], "Velocity { x: 1.0, y: 2.0 }");
+    }
+
+    #[test]
+    fn dump() {
+        let world = World::new();
+        setup(&world);
+
+        let entity0 = world.spawn((0u32,));
+        world.spawn((Velocity { x: 1.0, y: 2.0 }, 1u32));
+        world.run(|mut all_storages: AllStoragesViewMut| {
+            all_storages.delete(entity0);
+        });
+        // reuses index 0 with a new generation
+        world.spawn((Velocity { x: 3.0, y: 4.0 },));
+
+        let dump = testing::dump(&world).unwrap();
+
+        assert_eq!(
+            dump,
+            "EntityId { index: 0, gen: 1 } {
+    velocity: Velocity { x: 3.0, y: 4.0 },
+}
+EntityId { index: 1, gen: 0 } {
+    u32: 1,
+    velocity: Velocity { x: 1.0, y: 2.0 },
+}
+"
+        );
+        assert_eq!(testing::dump(&world).unwrap(), dump);
+    }
+}
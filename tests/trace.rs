@@ -0,0 +1,55 @@
+#![cfg(all(feature = "trace", feature = "panic"))]
+
+use shipyard::*;
+
+fn increment(mut u32s: ViewMut<u32>) {
+    for i in (&mut u32s).iter() {
+        *i += 1;
+    }
+}
+
+fn read(_: View<u32>) {}
+
+#[test]
+fn write_trace() {
+    let world = World::new();
+
+    world.spawn((0u32,));
+
+    world
+        .add_workload("Frame")
+        .with_system(system!(increment))
+        .with_system(system!(read))
+        .build();
+
+    world.run_workload("Frame");
+    world.run_workload("Frame");
+    // systems run outside of workloads aren't recorded
+    world.run(read);
+
+    let path = std::env::temp_dir().join("shipyard_write_trace.json");
+    world.write_trace(&path);
+
+    let trace = std::fs::read_to_string(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+
+    assert!(trace.starts_with("{\"traceEvents\":["));
+    assert!(trace.ends_with("]}\n"));
+    assert_eq!(trace.matches("\"ph\":\"X\"").count(), 4);
+    assert_eq!(trace.matches("increment\",\"cat\":\"system\"").count(), 2);
+    assert_eq!(trace.matches("read\",\"cat\":\"system\"").count(), 2);
+}
+
+#[test]
+fn invalid_path() {
+    let world = World::new();
+
+    let path = std::env::temp_dir()
+        .join("shipyard_missing_directory")
+        .join("trace.json");
+
+    match world.try_write_trace(path) {
+        Err(error::WriteTrace::Io(_)) => {}
+        _ => panic!("expected an io error"),
+    }
+}
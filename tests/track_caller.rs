@@ -0,0 +1,37 @@
+#![cfg(feature = "panic")]
+
+use shipyard::*;
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::sync::{Arc, Mutex};
+
+#[test]
+fn location() {
+    let world = World::new();
+    let _u32s = world.borrow::<ViewMut<u32>>();
+
+    let location = Arc::new(Mutex::new(None));
+    let hook_location = location.clone();
+    // the hook is global but no other test of this file looks at it
+    std::panic::set_hook(Box::new(move |info| {
+        *hook_location.lock().unwrap() = info
+            .location()
+            .map(|location| (location.file().to_string(), location.line()));
+    }));
+
+    let line = line!() + 1;
+    let result = catch_unwind(AssertUnwindSafe(|| world.borrow::<View<u32>>()));
+
+    let _ = std::panic::take_hook();
+
+    assert!(result.is_err());
+    assert_eq!(*location.lock().unwrap(), Some((file!().to_string(), line)));
+}
+
+#[test]
+#[should_panic(expected = "Workload Missing failed")]
+fn workload_name() {
+    let world = World::new();
+
+    world.add_workload("Empty").build();
+    world.run_workload("Missing");
+}
@@ -187,6 +187,65 @@ fn macro_test() {
     assert_eq!(*world.try_borrow::<UniqueView<usize>>().unwrap(), 1);
 }
 
+#[test]
+fn option() {
+    struct Audio(u32);
+
+    fn play(audio: Option<UniqueView<Audio>>) -> Option<u32> {
+        audio.map(|audio| audio.0)
+    }
+
+    let world = World::new();
+
+    assert_eq!(world.try_run(play).unwrap(), None);
+    world
+        .try_run(|audio: Option<UniqueViewMut<Audio>>| assert!(audio.is_none()))
+        .unwrap();
+    world
+        .try_run(|all_storages: AllStoragesViewMut| {
+            assert!(all_storages
+                .try_borrow::<Option<UniqueView<Audio>>>()
+                .unwrap()
+                .is_none());
+        })
+        .unwrap();
+
+    // borrowing didn't create the storage
+    assert_eq!(
+        world.try_remove_unique::<Audio>().err(),
+        Some(error::UniqueRemove::MissingUnique(type_name::<Audio>()))
+    );
+
+    world.try_add_unique(Audio(1)).unwrap();
+
+    assert_eq!(world.try_run(play).unwrap(), Some(1));
+    world
+        .try_run(|audio: Option<UniqueViewMut<Audio>>| audio.unwrap().0 += 1)
+        .unwrap();
+    assert_eq!(world.try_run(play).unwrap(), Some(2));
+}
+
+#[test]
+fn version() {
+    let world = World::new();
+    world.try_add_unique(0u32).unwrap();
+
+    let version = world.try_borrow::<UniqueView<u32>>().unwrap().version();
+    let unique = world.try_borrow::<UniqueView<u32>>().unwrap();
+    assert_eq!(unique.version(), version);
+    drop(unique);
+
+    world
+        .try_run(|mut unique: UniqueViewMut<u32>| *unique += 1)
+        .unwrap();
+    let modified = world.try_borrow::<UniqueView<u32>>().unwrap().version();
+    assert!(modified > version);
+
+    world.try_remove_unique::<u32>().unwrap();
+    world.try_add_unique(1u32).unwrap();
+    assert!(world.try_borrow::<UniqueView<u32>>().unwrap().version() > modified);
+}
+
 #[cfg(all(feature = "non_send", feature = "non_sync", feature = "panic"))]
 #[test]
 fn macro_test_all_features() {
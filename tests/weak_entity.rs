@@ -0,0 +1,68 @@
+#![cfg(feature = "panic")]
+
+use shipyard::error;
+use shipyard::*;
+
+#[test]
+fn generation_reuse() {
+    let world = World::new();
+
+    let entity = world.spawn((0u32,));
+    let weak = world.downgrade(entity);
+
+    world.borrow::<AllStoragesViewMut>().delete(entity);
+    let new_entity = world.spawn((1u32,));
+
+    assert_eq!(new_entity.index(), entity.index());
+    assert_eq!(world.upgrade(weak), None);
+    assert_eq!(world.drain_invalidated(), vec![weak]);
+    assert!(world.drain_invalidated().is_empty());
+}
+
+#[test]
+fn clear() {
+    let world = World::new();
+
+    let entity0 = world.spawn(());
+    let entity1 = world.spawn(());
+    let weak0 = world.downgrade(entity0);
+    let weak1 = world.downgrade(entity1);
+
+    world.borrow::<AllStoragesViewMut>().clear();
+
+    assert_eq!(world.upgrade(weak0), None);
+    assert_eq!(world.upgrade(weak1), None);
+
+    let mut invalidated = world.drain_invalidated();
+    invalidated.sort();
+    assert_eq!(invalidated, vec![weak0, weak1]);
+}
+
+#[test]
+fn dead_entity() {
+    let world = World::new();
+
+    let entity = world.spawn(());
+    world.borrow::<AllStoragesViewMut>().delete(entity);
+
+    let weak = world.downgrade(entity);
+
+    assert_eq!(world.upgrade(weak), None);
+    assert!(world.drain_invalidated().is_empty());
+}
+
+#[test]
+fn borrow_conflict() {
+    let world = World::new();
+
+    let entity = world.spawn(());
+    let weak = world.downgrade(entity);
+
+    let _entities = world.borrow::<EntitiesView>();
+
+    assert_eq!(world.try_upgrade(weak), Ok(Some(entity)));
+    assert_eq!(
+        world.try_drain_invalidated(),
+        Err(error::GetStorage::Entities(error::Borrow::Unique(Default::default())))
+    );
+}
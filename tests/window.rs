@@ -236,3 +236,26 @@ fn simple_window_mut() {
         })
         .unwrap();
 }
+
+#[cfg(feature = "bytemuck1")]
+#[test]
+fn window_as_bytes() {
+    let world = World::new();
+
+    let (mut entities, mut u16s) = world
+        .try_borrow::<(EntitiesViewMut, ViewMut<u16>)>()
+        .unwrap();
+    entities.add_entity(&mut u16s, 0x0102);
+    entities.add_entity(&mut u16s, 0x0304);
+    entities.add_entity(&mut u16s, 0x0506);
+
+    let window = u16s.try_as_window(1..).unwrap();
+    assert_eq!(window.as_bytes().len(), 4);
+    assert_eq!(
+        window.as_bytes(),
+        &[0x0304u16.to_ne_bytes(), 0x0506u16.to_ne_bytes()].concat()[..]
+    );
+
+    let window_mut = u16s.try_as_window_mut(..1).unwrap();
+    assert_eq!(window_mut.as_bytes(), &0x0102u16.to_ne_bytes());
+}
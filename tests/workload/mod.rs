@@ -20,3 +20,770 @@ fn duplicate_name() {
 
     world.try_run_workload("").unwrap();
 }
+
+fn double(mut u32s: ViewMut<u32>) {
+    (&mut u32s).iter().for_each(|x| *x *= 2);
+}
+
+fn add_one(mut u32s: ViewMut<u32>) {
+    (&mut u32s).iter().for_each(|x| *x += 1);
+}
+
+// runs with --no-default-features, the scheduler doesn't need std
+#[test]
+fn sequential() {
+    let world = World::new();
+    world
+        .try_run(
+            |(mut entities, mut u32s): (EntitiesViewMut, ViewMut<u32>)| {
+                entities.add_entity(&mut u32s, 1);
+            },
+        )
+        .unwrap();
+
+    world
+        .try_add_workload("Sequential")
+        .unwrap()
+        .try_with_system(system!(double))
+        .unwrap()
+        .try_with_system(system!(add_one))
+        .unwrap()
+        .build();
+
+    world.try_run_workload("Sequential").unwrap();
+    world.try_run_default().unwrap();
+
+    world
+        .try_run(|u32s: View<u32>| assert_eq!((&u32s).iter().next(), Some(&7)))
+        .unwrap();
+}
+
+#[cfg(feature = "parallel")]
+#[test]
+fn deterministic() {
+    use core::sync::atomic::{AtomicBool, Ordering};
+
+    static SLOW_RAN: AtomicBool = AtomicBool::new(false);
+
+    fn slow(_: ViewMut<u32>) -> Result<(), error::NotUpdatePack> {
+        std::thread::sleep(std::time::Duration::from_millis(50));
+        SLOW_RAN.store(true, Ordering::SeqCst);
+        Err(error::NotUpdatePack)
+    }
+
+    fn fast(_: ViewMut<usize>) -> Result<(), error::NotUpdatePack> {
+        Err(error::NotUpdatePack)
+    }
+
+    let world = World::new();
+
+    world
+        .add_workload("")
+        .with_system(try_system!(slow))
+        .with_system(try_system!(fast))
+        .deterministic()
+        .build();
+
+    match world.try_run_workload("") {
+        Err(error::RunWorkload::Run((name, _))) => assert!(name.ends_with("slow")),
+        _ => panic!("workload should fail"),
+    }
+    assert!(SLOW_RAN.load(Ordering::SeqCst));
+}
+
+#[cfg(all(feature = "panic", feature = "parallel"))]
+#[test]
+fn deterministic_deferred_entities() {
+    fn slow(entities: EntitiesView) {
+        std::thread::sleep(std::time::Duration::from_millis(50));
+        entities.deferred_entity_builder().with(0u32).build();
+    }
+
+    fn fast(entities: EntitiesView) {
+        entities.deferred_entity_builder().with(1u32).build();
+    }
+
+    fn spawned() -> Vec<(EntityId, u32)> {
+        let world = World::new();
+
+        world
+            .add_workload("")
+            .with_system(system!(slow))
+            .with_system(system!(fast))
+            .deterministic()
+            .build();
+
+        for _ in 0..3 {
+            world.run_workload("");
+            world.maintain();
+        }
+
+        world.run(|u32s: View<u32>| {
+            let mut spawned: Vec<_> = (&u32s).iter().with_id().map(|(id, &i)| (id, i)).collect();
+            spawned.sort_by_key(|&(id, _)| id.index());
+            spawned
+        })
+    }
+
+    let first = spawned();
+    assert_eq!(first, spawned());
+    // entities are created in batch order even though fast finishes first
+    assert_eq!(
+        first.iter().map(|&(_, i)| i).collect::<Vec<_>>(),
+        vec![0, 1, 0, 1, 0, 1]
+    );
+}
+
+#[cfg(all(feature = "panic", feature = "parallel"))]
+#[test]
+fn deterministic_messages() {
+    struct Hit(u32);
+
+    fn slow(hits: MessageSender<Hit>) {
+        std::thread::sleep(std::time::Duration::from_millis(50));
+        hits.send(Hit(0));
+        hits.send(Hit(1));
+    }
+
+    fn fast(hits: MessageSender<Hit>) {
+        hits.send_all(vec![Hit(2), Hit(3)]);
+    }
+
+    fn receive(mut hits: MessageReceiver<Hit>) {
+        // messages are received in batch order even though fast finishes first
+        assert_eq!(
+            hits.drain().map(|Hit(hit)| hit).collect::<Vec<_>>(),
+            vec![0, 1, 2, 3]
+        );
+    }
+
+    let world = World::new();
+
+    world
+        .add_workload("")
+        .with_system(system!(slow))
+        .with_system(system!(fast))
+        .with_system(system!(receive))
+        .deterministic()
+        .build();
+
+    // the first run is sequential
+    for _ in 0..3 {
+        world.run_workload("");
+    }
+}
+
+#[cfg(all(feature = "panic", feature = "std"))]
+#[test]
+fn custom_error() {
+    #[derive(Debug, PartialEq)]
+    enum GameError {
+        OutOfHealth(u32),
+    }
+
+    impl core::fmt::Display for GameError {
+        fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            core::fmt::Debug::fmt(self, fmt)
+        }
+    }
+
+    impl std::error::Error for GameError {}
+
+    fn health(_: View<u32>) -> Result<(), GameError> {
+        Err(GameError::OutOfHealth(0))
+    }
+
+    fn message(_: View<u32>) -> Result<(), String> {
+        Err(format!("{} failed", "message"))
+    }
+
+    let world = World::new();
+    world
+        .add_workload("Health")
+        .with_system(try_system!(health))
+        .build();
+    world
+        .add_workload("Message")
+        .with_system(try_system!(message))
+        .build();
+    world
+        .add_workload("Str")
+        .with_system(try_system!(|_: View<u32>| Err("str failed")))
+        .build();
+
+    let error = world
+        .try_run_workload("Health")
+        .err()
+        .and_then(error::RunWorkload::custom_error)
+        .unwrap();
+    assert_eq!(
+        error.downcast::<GameError>().ok().as_deref(),
+        Some(&GameError::OutOfHealth(0))
+    );
+
+    let error = world
+        .try_run_workload("Message")
+        .err()
+        .and_then(error::RunWorkload::custom_error)
+        .unwrap();
+    assert_eq!(error.to_string(), "message failed");
+
+    let error = world
+        .try_run_workload("Str")
+        .err()
+        .and_then(error::RunWorkload::custom_error)
+        .unwrap();
+    assert_eq!(error.to_string(), "str failed");
+}
+
+#[cfg(feature = "panic")]
+#[test]
+fn diagnostics() {
+    fn log(_: View<u32>) {}
+    fn update(_: ViewMut<u32>) {}
+
+    let world = World::new();
+
+    assert_eq!(
+        world.add_workload("Empty").build(),
+        [WorkloadDiagnostic::Empty]
+    );
+    let diagnostics = world
+        .add_workload("Duplicate")
+        .with_system(system!(log))
+        .with_system(system!(update))
+        .with_system(system!(log))
+        .with_system(system!(log))
+        .build();
+    assert_eq!(diagnostics.len(), 1);
+    match diagnostics[0] {
+        WorkloadDiagnostic::DuplicateSystem(name) => assert!(name.ends_with("diagnostics::log")),
+        _ => panic!(),
+    }
+    assert_eq!(
+        world
+            .add_workload("Closures")
+            .with_system(system!(|_: View<u32>| {}))
+            .with_system(system!(|_: View<u32>| {}))
+            .with_system(system!(update))
+            .build(),
+        []
+    );
+
+    // duplicate systems still run each time
+    world.run(|mut entities: EntitiesViewMut, mut u32s: ViewMut<u32>| {
+        entities.add_entity(&mut u32s, 0);
+    });
+    world
+        .add_workload("Increment")
+        .with_system(system!(increment))
+        .with_system(system!(increment))
+        .build();
+    world.run_workload("Increment");
+    world.run_workload("Empty");
+
+    world.run(|u32s: View<u32>| assert_eq!((&u32s).iter().next(), Some(&2)));
+}
+
+#[cfg(feature = "panic")]
+fn increment(mut u32s: ViewMut<u32>) {
+    for x in (&mut u32s).iter() {
+        *x += 1;
+    }
+}
+
+#[cfg(feature = "panic")]
+#[test]
+fn runtime_systems() {
+    fn spawn(mut entities: EntitiesViewMut, mut u32s: ViewMut<u32>) {
+        entities.add_entity(&mut u32s, 0);
+    }
+
+    type Registry = Vec<(&'static str, fn() -> WorkloadSystem)>;
+
+    let mut registry: Registry = Vec::new();
+    registry.push(("spawn", || WorkloadSystem::new(system!(spawn))));
+    registry.push(("increment", || WorkloadSystem::new(system!(increment))));
+    registry.push(("double", || {
+        WorkloadSystem::from_raw(
+            "double",
+            vec![(TypeId::of::<u32>(), Mutation::Unique)],
+            true,
+            Box::new(|world: &World| {
+                world.try_run(|mut u32s: ViewMut<u32>| {
+                    for x in (&mut u32s).iter() {
+                        *x *= 2;
+                    }
+                })
+            }),
+        )
+        .unwrap()
+    }));
+
+    let order = "spawn increment double increment";
+    let systems = order.split(' ').map(|name| {
+        let (_, system) = registry.iter().find(|(n, _)| *n == name).unwrap();
+        system()
+    });
+
+    let world = World::new();
+
+    let diagnostics = world.add_workload("Data").with_systems(systems).build();
+    // increment is used twice
+    assert_eq!(diagnostics.len(), 1);
+
+    world.run_workload("Data");
+
+    world.run(|u32s: View<u32>| assert_eq!((&u32s).iter().next(), Some(&3)));
+}
+
+#[test]
+fn invalid_raw_system() {
+    let system = |_: &World| Ok(());
+
+    assert_eq!(
+        WorkloadSystem::from_raw(
+            "invalid",
+            vec![
+                (TypeId::of::<u32>(), Mutation::Unique),
+                (TypeId::of::<u32>(), Mutation::Shared),
+                (TypeId::of::<usize>(), Mutation::Shared),
+                (TypeId::of::<u64>(), Mutation::Shared),
+            ],
+            true,
+            Box::new(system),
+        )
+        .err(),
+        Some(error::InvalidSystem::MultipleViews)
+    );
+    assert_eq!(
+        WorkloadSystem::from_raw(
+            "all_storages",
+            vec![
+                (TypeId::of::<AllStorages>(), Mutation::Unique),
+                (TypeId::of::<u32>(), Mutation::Shared),
+            ],
+            true,
+            Box::new(system),
+        )
+        .err(),
+        Some(error::InvalidSystem::AllStorages)
+    );
+}
+
+#[cfg(feature = "panic")]
+#[test]
+fn systems_info() {
+    fn read(_: View<u32>, _: UniqueView<usize>) {}
+
+    let world = World::new();
+
+    world
+        .add_workload("Read & Write")
+        .with_system(system!(increment))
+        .with_system(system!(read))
+        .build();
+
+    let systems = world.systems_info();
+
+    assert_eq!(systems.len(), 2);
+    assert!(systems[0].name.ends_with("increment"));
+    assert_eq!(
+        systems[0].borrows,
+        vec![(TypeId::of::<u32>(), Mutation::Unique)]
+    );
+    assert!(systems[0].borrows_mut(TypeId::of::<u32>()));
+    assert!(systems[0].is_send_sync);
+    assert!(systems[1].name.ends_with("read"));
+    assert!(systems[1].borrows(TypeId::of::<u32>()));
+    assert!(systems[1].borrows(TypeId::of::<usize>()));
+    assert!(!systems[1].borrows_mut(TypeId::of::<u32>()));
+}
+
+#[cfg(feature = "panic")]
+#[test]
+fn dot() {
+    fn write(_: ViewMut<u32>) {}
+    fn read(_: View<u32>) {}
+    fn other(_: View<usize>) {}
+
+    let world = World::new();
+
+    world
+        .add_workload("Frame")
+        .with_system(system!(write))
+        .with_system(system!(read))
+        .with_system(system!(other))
+        .build();
+    // storages are named once they exist
+    world.run_workload("Frame");
+
+    let dot = world.workload_dot("Frame");
+
+    assert!(dot.starts_with("digraph \"Frame\" {\n"));
+    assert!(dot.contains("subgraph cluster_0"));
+    assert!(dot.contains("&mut u32"));
+    assert!(dot.contains("&usize"));
+    assert!(dot.contains("s0_0 -> s1_0 [label=\"u32\"];"));
+    assert!(!dot.contains("-> s0_1"));
+    assert!(dot.ends_with("}\n"));
+
+    assert_eq!(
+        world.try_workload_dot("Missing").err(),
+        Some(error::WorkloadDot::MissingWorkload)
+    );
+}
+
+#[cfg(feature = "panic")]
+#[test]
+fn storage_cache() {
+    fn read(count: UniqueView<u32>, usizes: View<usize>, mut sum: UniqueViewMut<u64>) {
+        *sum = *count as u64 + usizes.as_slice().iter().sum::<usize>() as u64;
+    }
+
+    let world = World::new();
+    world.add_unique(1u32);
+    world.add_unique(0u64);
+    world.spawn((1usize,));
+
+    world
+        .add_workload("Read")
+        .with_system(system!(read))
+        .build();
+
+    world.run_workload("Read");
+    assert_eq!(*world.borrow::<UniqueView<u64>>(), 2);
+
+    // the cached unique storage is removed and replaced
+    world.remove_unique::<u32>();
+    world.add_unique(10u32);
+    world.run_workload("Read");
+    assert_eq!(*world.borrow::<UniqueView<u64>>(), 11);
+
+    // a new storage is flushed next to the cached ones
+    let entity = world.spawn((0u16,));
+    world.borrow::<AllStoragesViewMut>().strip(entity);
+    world.spawn((2usize,));
+    world.run_workload("Read");
+    assert_eq!(*world.borrow::<UniqueView<u64>>(), 13);
+}
+
+#[cfg(feature = "panic")]
+#[test]
+fn run_while() {
+    let world = World::new();
+    world.run(|mut entities: EntitiesViewMut, mut u32s: ViewMut<u32>| {
+        entities.add_entity(&mut u32s, 1);
+    });
+
+    world
+        .add_workload("Double")
+        .with_system(system!(double))
+        .build();
+
+    let value = |world: &World| *world.borrow::<View<u32>>().iter().next().unwrap();
+
+    assert_eq!(
+        world.run_workload_while("Double", |world| value(world) < 100),
+        7
+    );
+    assert_eq!(value(&world), 128);
+
+    assert_eq!(world.run_workload_while_max("Double", 3, |_| true), 3);
+    assert_eq!(value(&world), 1024);
+
+    assert_eq!(world.run_workload_while_max("Double", 0, |_| true), 0);
+    assert_eq!(value(&world), 1024);
+
+    assert!(matches!(
+        world.try_run_workload_while("Missing", |_| true),
+        Err(error::RunWorkload::MissingWorkload)
+    ));
+}
+
+#[cfg(feature = "panic")]
+#[test]
+fn cursor() {
+    let world = World::new();
+    world.spawn((1u32,));
+
+    world
+        .add_workload("Sequential")
+        .with_system(system!(double))
+        .with_system(system!(add_one))
+        .build();
+
+    let value = |world: &World| *world.borrow::<View<u32>>().iter().next().unwrap();
+    let mut cursor = WorkloadCursor::new("Sequential");
+
+    assert!(!world.resume_workload(&mut cursor, || true));
+    assert!(cursor.is_suspended());
+    assert_eq!(value(&world), 2);
+
+    assert!(world.resume_workload(&mut cursor, || true));
+    assert!(!cursor.is_suspended());
+    assert_eq!(value(&world), 3);
+
+    assert!(world.resume_workload(&mut cursor, || false));
+    assert_eq!(value(&world), 7);
+
+    assert!(matches!(
+        world.try_resume_workload(&mut WorkloadCursor::new("Missing"), || true),
+        Err(error::RunWorkload::MissingWorkload)
+    ));
+}
+
+#[cfg(feature = "panic")]
+#[test]
+fn all_storages_view() {
+    fn read(_: View<u32>) {}
+    fn inspect(all_storages: AllStoragesView) {
+        assert_eq!(all_storages.borrow::<View<u32>>().len(), 1);
+    }
+    fn write(_: ViewMut<u32>) {}
+
+    let world = World::new();
+    world.spawn((0u32,));
+
+    world
+        .add_workload("Diagnostics")
+        .with_system(system!(read))
+        .with_system(system!(inspect))
+        .with_system(system!(write))
+        .build();
+    world.run_workload("Diagnostics");
+
+    let dot = world.workload_dot("Diagnostics");
+
+    // inspect runs alongside read but not write
+    assert!(dot.contains("s0_1"));
+    assert!(dot.contains("s1_0"));
+    assert!(!dot.contains("s1_1"));
+    assert!(dot.contains("s0_1 -> s1_0"));
+}
+
+#[cfg(all(feature = "panic", feature = "parallel"))]
+#[test]
+fn creation_in_parallel_batch() {
+    fn read(_: View<u32>) {}
+    fn inspect(all_storages: AllStoragesView) {
+        // only tries to create the storage after the first run
+        if !all_storages.borrow::<View<u32>>().is_empty() {
+            assert_eq!(
+                all_storages.try_borrow::<View<u64>>().err(),
+                Some(error::GetStorage::CreationInBatch("u64"))
+            );
+        }
+    }
+
+    let world = World::new();
+
+    world
+        .add_workload("Inspect")
+        .with_system(system!(read))
+        .with_system(system!(inspect))
+        .build();
+    world.run_workload("Inspect");
+
+    world.spawn((0u32,));
+    world.run_workload("Inspect");
+
+    // outside of parallel batches storages are still created
+    world.run(|u64s: View<u64>| assert!(u64s.is_empty()));
+}
+
+#[cfg(feature = "panic")]
+#[test]
+fn cancellable() {
+    fn stop(token: UniqueView<CancelToken>, _: ViewMut<u32>) {
+        token.cancel();
+    }
+
+    let world = World::new();
+    let token = CancelToken::new();
+    world.add_unique(token.clone());
+    let entity = world.spawn((0u32,));
+
+    world
+        .add_workload("Steps")
+        .with_system(system!(increment))
+        .with_system(system!(increment))
+        .with_system(system!(increment))
+        .build();
+
+    assert!(world.run_workload_cancellable("Steps", &token));
+    world.run(|u32s: View<u32>| assert_eq!(u32s[entity], 3));
+
+    token.cancel();
+    assert!(!world.run_workload_cancellable("Steps", &token));
+    world.run(|u32s: View<u32>| assert_eq!(u32s[entity], 3));
+
+    token.reset();
+    assert!(world.run_workload_cancellable("Steps", &token));
+    world.run(|u32s: View<u32>| assert_eq!(u32s[entity], 6));
+
+    // systems can cancel the workload they're part of
+    world
+        .add_workload("Stop")
+        .with_system(system!(increment))
+        .with_system(system!(stop))
+        .with_system(system!(increment))
+        .build();
+    token.reset();
+    assert!(!world.run_workload_cancellable("Stop", &token));
+    world.run(|u32s: View<u32>| assert_eq!(u32s[entity], 7));
+
+    #[cfg(feature = "std")]
+    {
+        assert!(!world.run_workload_timeout("Steps", core::time::Duration::from_secs(0)));
+        assert!(world.run_workload_timeout("Steps", core::time::Duration::from_secs(60)));
+        world.run(|u32s: View<u32>| assert_eq!(u32s[entity], 10));
+    }
+
+    assert!(matches!(
+        world.try_run_workload_cancellable("Missing", &token),
+        Err(error::RunWorkload::MissingWorkload)
+    ));
+}
+
+#[cfg(feature = "panic")]
+#[test]
+fn enabled() {
+    let world = World::new();
+    let entity = world.spawn((0u32,));
+
+    world
+        .add_workload("Simulation")
+        .with_system(system!(increment))
+        .build();
+    world
+        .add_workload("Render")
+        .with_system(system!(increment))
+        .build();
+
+    world.set_workload_enabled("Simulation", false);
+    world.run_default();
+    world.run(|u32s: View<u32>| assert_eq!(u32s[entity], 0));
+
+    // disabled workloads still run when asked by name
+    world.run_workload("Simulation");
+    world.run(|u32s: View<u32>| assert_eq!(u32s[entity], 1));
+
+    // other workloads aren't affected
+    world.set_default_workload("Render");
+    world.run_default();
+    world.run(|u32s: View<u32>| assert_eq!(u32s[entity], 2));
+
+    world.set_default_workload("Simulation");
+    world.set_workload_enabled("Simulation", true);
+    world.run_default();
+    world.run(|u32s: View<u32>| assert_eq!(u32s[entity], 3));
+
+    assert_eq!(
+        world.try_set_workload_enabled("Missing", false),
+        Err(error::SetWorkloadEnabled::MissingWorkload)
+    );
+}
+
+#[cfg(feature = "panic")]
+#[test]
+fn prepare() {
+    fn physics(_: ViewMut<u32>, _: Option<View<usize>>) {}
+    fn render(_: View<i8>, _: UniqueView<u64>) {}
+
+    let world = World::new();
+
+    world
+        .add_workload("Frame")
+        .with_system(system!(physics))
+        .with_system(system!(render))
+        .build();
+
+    world.prepare_workload("Frame");
+
+    let names = world.borrow::<AllStoragesViewMut>().storage_names();
+    assert!(names.iter().any(|&(_, name)| name == "u32"));
+    assert!(names.iter().any(|&(_, name)| name == "usize"));
+    assert!(names.iter().any(|&(_, name)| name == "i8"));
+    // uniques can't be created without a value
+    assert!(!names.iter().any(|&(_, name)| name.contains("u64")));
+
+    assert_eq!(
+        world.try_prepare_workload("Missing"),
+        Err(error::PrepareWorkload::MissingWorkload)
+    );
+}
+
+#[cfg(all(feature = "panic", feature = "std"))]
+#[test]
+fn timed() {
+    fn read(_: View<usize>) {}
+
+    let world = World::new();
+    let entity = world.spawn((0u32,));
+
+    world
+        .add_workload("Steps")
+        .with_system(system!(increment))
+        .with_system(system!(read))
+        .with_system(system!(increment))
+        .build();
+
+    let report = world.run_workload_timed("Steps");
+    world.run(|u32s: View<u32>| assert_eq!(u32s[entity], 2));
+
+    let names = report
+        .batches
+        .iter()
+        .map(|batch| batch.iter().map(|timing| timing.name).collect::<Vec<_>>())
+        .collect::<Vec<_>>();
+    assert_eq!(names.len(), 2);
+    assert_eq!(names[0].len(), 2);
+    assert_eq!(names[1].len(), 1);
+    assert!(names[0][1].contains("read"));
+    assert!(report
+        .systems()
+        .all(|timing| timing.duration <= report.duration));
+
+    assert!(matches!(
+        world.try_run_workload_timed("Missing"),
+        Err(error::RunWorkload::MissingWorkload)
+    ));
+}
+
+// World isn't Send with non_send, it can't be captured by a system
+#[cfg(all(feature = "panic", feature = "std", not(feature = "non_send")))]
+#[test]
+fn reentrant() {
+    use std::sync::Arc;
+
+    let world = Arc::new(World::new());
+    world.spawn((0u32,));
+
+    let nested = world.clone();
+    world
+        .add_workload("Nested")
+        .with_system(system!(move |_: ViewMut<u32>| {
+            // borrows that don't conflict still work
+            assert!(nested.try_run(|_: View<usize>| {}).is_ok());
+            assert!(matches!(
+                nested.try_run(|_: View<u32>| {}),
+                Err(error::Run::Reentrant(system)) if system.contains("reentrant")
+            ));
+            assert!(matches!(
+                nested.try_run_workload("Nested"),
+                Err(error::RunWorkload::Run((_, error::Run::Reentrant(_))))
+            ));
+        }))
+        .build();
+
+    world.run_workload("Nested");
+
+    // outside of systems, failed borrows are reported as such
+    world.run(|_: ViewMut<u32>| {
+        assert!(matches!(
+            world.try_run(|_: View<u32>| {}),
+            Err(error::Run::GetStorage(_))
+        ));
+    });
+}
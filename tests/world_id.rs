@@ -0,0 +1,53 @@
+#![cfg(feature = "panic")]
+
+use shipyard::error;
+use shipyard::*;
+
+#[test]
+fn unique_ids() {
+    let world1 = World::new();
+    let world2 = World::new();
+
+    assert_ne!(world1.id(), world2.id());
+}
+
+#[test]
+fn resolve() {
+    let server = World::new();
+    let ui = World::new();
+
+    let entity = server.spawn((0u32,));
+    let handle = server.global_id(entity);
+
+    // store the handle in the other world
+    let button = ui.spawn((handle,));
+
+    ui.run(|handles: View<GlobalEntityId>| {
+        assert_eq!(server.try_resolve(handles[button]), Ok(entity));
+        assert_eq!(
+            ui.try_resolve(handles[button]),
+            Err(error::Resolve::WrongWorld)
+        );
+    });
+
+    server.run(|mut all_storages: AllStoragesViewMut| {
+        all_storages.delete(entity);
+    });
+
+    assert_eq!(server.try_resolve(handle), Err(error::Resolve::DeadEntity));
+    assert_eq!(server.resolve(handle), None);
+}
+
+#[test]
+fn borrow_conflict() {
+    let world = World::new();
+
+    let handle = world.global_id(world.spawn(()));
+
+    let _all_storages = world.borrow::<AllStoragesViewMut>();
+
+    assert_eq!(
+        world.try_resolve(handle),
+        Err(error::Resolve::AllStoragesBorrow(error::Borrow::Shared(Default::default())))
+    );
+}